@@ -21,11 +21,73 @@ pub enum AnimationDescriptor {
     RefreshPulse,
 }
 
+pub const MAX_LAYER_ANIMATIONS: usize = 2;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum Easing {
+    Linear,
+    #[default]
+    EaseOut,
+    // Overshoots by about a tenth before settling, so the result can pass 1000.
+    Spring,
+}
+
+impl Easing {
+    pub const fn progress_permille(self, step: u8, total_steps: u8) -> i32 {
+        if total_steps == 0 || step >= total_steps {
+            return 1000;
+        }
+
+        let t = (step as i64 * 1000) / total_steps as i64;
+        let u = t - 1000;
+        let progress = match self {
+            Self::Linear => t,
+            Self::EaseOut => 1000 - (u * u) / 1000,
+            // easeOutBack: 1 + 2.70158 u^3 + 1.70158 u^2, with u = t - 1.
+            Self::Spring => {
+                1000 + (2_702 * u * u / 1000 * u) / 1_000_000 + (1_702 * u * u) / 1_000_000
+            }
+        };
+        progress as i32
+    }
+}
+
+// A band of the incoming screen that animates on its own, over the same steps as the plan.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AnimationLayer {
+    Header,
+    Stage,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LayerMotion {
+    SlideDown,
+    Fade,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LayerAnimation {
+    pub layer: AnimationLayer,
+    pub motion: LayerMotion,
+    pub easing: Easing,
+}
+
+impl LayerAnimation {
+    pub const fn new(layer: AnimationLayer, motion: LayerMotion, easing: Easing) -> Self {
+        Self {
+            layer,
+            motion,
+            easing,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct TransitionPlan {
     pub animation: AnimationDescriptor,
     pub steps: u8,
     pub frame_ms: u16,
+    pub layers: [Option<LayerAnimation>; MAX_LAYER_ANIMATIONS],
 }
 
 impl TransitionPlan {
@@ -34,6 +96,7 @@ impl TransitionPlan {
             animation: AnimationDescriptor::None,
             steps: 1,
             frame_ms: 0,
+            layers: [None; MAX_LAYER_ANIMATIONS],
         }
     }
 
@@ -42,7 +105,21 @@ impl TransitionPlan {
             animation,
             steps,
             frame_ms,
+            layers: [None; MAX_LAYER_ANIMATIONS],
+        }
+    }
+
+    // Runs `layer` alongside the plan's animation; a full plan keeps the layers it has.
+    pub const fn with_layer(mut self, layer: LayerAnimation) -> Self {
+        let mut index = 0;
+        while index < MAX_LAYER_ANIMATIONS {
+            if self.layers[index].is_none() {
+                self.layers[index] = Some(layer);
+                return self;
+            }
+            index += 1;
         }
+        self
     }
 }
 
@@ -51,3 +128,36 @@ impl Default for TransitionPlan {
         Self::none()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easings_start_at_zero_finish_at_one_and_only_spring_overshoots() {
+        for easing in [Easing::Linear, Easing::EaseOut, Easing::Spring] {
+            assert_eq!(easing.progress_permille(0, 4), 0);
+            assert_eq!(easing.progress_permille(4, 4), 1000);
+        }
+        assert_eq!(Easing::Linear.progress_permille(1, 4), 250);
+        assert_eq!(Easing::EaseOut.progress_permille(1, 4), 438);
+        assert!(Easing::Spring.progress_permille(3, 4) > 1000);
+        assert!((1..10).all(|step| Easing::EaseOut.progress_permille(step, 10) <= 1000));
+    }
+
+    #[test]
+    fn layers_fill_free_slots_in_order() {
+        let header = LayerAnimation::new(
+            AnimationLayer::Header,
+            LayerMotion::SlideDown,
+            Easing::Spring,
+        );
+        let stage = LayerAnimation::new(AnimationLayer::Stage, LayerMotion::Fade, Easing::Linear);
+        let plan = TransitionPlan::new(AnimationDescriptor::ReaderEnter, 3, 50)
+            .with_layer(header)
+            .with_layer(stage)
+            .with_layer(header);
+
+        assert_eq!(plan.layers, [Some(header), Some(stage)]);
+    }
+}
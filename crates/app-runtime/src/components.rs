@@ -1,23 +1,37 @@
 use crate::screens::Screen;
 use domain::{
     content::{CONTENT_META_MAX_BYTES, CONTENT_TITLE_MAX_BYTES},
-    formatter::{MAX_PARAGRAPH_PREVIEW_BYTES, MAX_STAGE_SEGMENT_BYTES, StageFont},
+    formatter::{FontStyle, MAX_PARAGRAPH_PREVIEW_BYTES, MAX_STAGE_SEGMENT_BYTES, StageFont},
+    onboarding::{ONBOARDING_STEP_COUNT, OnboardingStep},
+    pagination::{PAGE_LINE_MAX_BYTES, PAGE_LINES},
+    picker::PickerKey,
     selectors::{
-        ActiveScreenModel, ContentListScreenModel, DashboardScreenModel, ParagraphNavigationModel,
-        ReaderScreenModel, RecommendationBarModel, RecommendationTabModel, SettingsScreenModel,
-        StartupSplashScreenModel,
+        ActiveScreenModel, BOOK_DETAIL_ACTION_COUNT, BOOT_LABEL_MAX_BYTES, BookDetailScreenModel,
+        CONTENTS_VISIBLE_ROWS, ConfirmScreenModel, ContentListScreenModel, ContentsMarker,
+        ContentsScreenModel, CrashReportScreenModel, DEFINITION_LINE_MAX_BYTES,
+        DIAGNOSTICS_ROW_COUNT, DashboardScreenModel, DefinitionScreenModel, DiagnosticsRowModel,
+        DiagnosticsScreenModel, HISTORY_VISIBLE_ROWS, HistoryRowModel, HistoryScreenModel,
+        LowBatteryScreenModel, OnboardingScreenModel, PAUSE_ACTION_COUNT, PAUSE_STATUS_MAX_BYTES,
+        PageScreenModel, ParagraphNavigationModel, ReaderScreenModel, RecommendationBarModel,
+        RecommendationTabModel, SEARCH_VISIBLE_KEYS, STATUS_CLOCK_MAX_BYTES,
+        ScreensaverScreenModel, SearchScreenModel, SectionBreakScreenModel, SettingsScreenModel,
+        StartupSplashScreenModel, StatusBarModel, VISIBLE_LIST_ROWS,
     },
     settings::AppearanceMode,
-    ui::{SettingsMode, TopicRegion},
+    toast::{TOAST_QUEUE_CAPACITY, ToastQueue},
+    ui::{SEARCH_QUERY_MAX_BYTES, SettingsMode, TopicRegion},
 };
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct ComponentId(pub u16);
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub struct StatusCluster {
+pub struct StatusBar {
     pub battery_percent: u8,
+    pub charging: bool,
     pub wifi_online: bool,
+    pub sd_card_ready: bool,
+    pub clock: Option<domain::text::InlineText<STATUS_CLOCK_MAX_BYTES>>,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -47,7 +61,7 @@ pub struct SelectionBand {
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct DashboardShell {
     pub appearance: AppearanceMode,
-    pub status: StatusCluster,
+    pub status: StatusBar,
     pub sync_indicator: Option<SyncIndicator>,
     pub rail: VerticalRail,
     pub items: [DashboardItem; 3],
@@ -67,6 +81,8 @@ pub struct ContentRow {
     pub meta: domain::text::InlineText<CONTENT_META_MAX_BYTES>,
     pub title: domain::text::InlineText<CONTENT_TITLE_MAX_BYTES>,
     pub progress_badge: Option<domain::text::InlineText<8>>,
+    pub progress_percent: u8,
+    pub finished: bool,
     pub is_fetching: bool,
     pub selected: bool,
 }
@@ -95,13 +111,15 @@ pub struct RecommendationBar {
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct ContentListShell {
     pub appearance: AppearanceMode,
-    pub status: StatusCluster,
+    pub status: StatusBar,
     pub rail: VerticalRail,
     pub large_rail: bool,
     pub recommendations_bar: Option<RecommendationBar>,
     pub rows: [ContentRow; 3],
+    pub grid: Option<[ContentRow; domain::selectors::LIBRARY_GRID_CELLS]>,
     pub band: SelectionBand,
     pub help: HelpHint,
+    pub jump_letter: Option<char>,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -120,7 +138,10 @@ pub struct PauseModalRow {
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct PauseModal {
     pub title: &'static str,
-    pub rows: [PauseModalRow; 4],
+    pub rows: [PauseModalRow; PAUSE_ACTION_COUNT],
+    pub session_label: domain::text::InlineText<PAUSE_STATUS_MAX_BYTES>,
+    pub remaining_label: domain::text::InlineText<PAUSE_STATUS_MAX_BYTES>,
+    pub goal_permille: Option<u16>,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -130,6 +151,7 @@ pub struct LoadingModal {
     pub stripe_phase: u8,
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum ReaderModal {
     Pause(PauseModal),
@@ -144,6 +166,12 @@ pub struct RsvpStage {
     pub right_word: domain::text::InlineText<MAX_STAGE_SEGMENT_BYTES>,
     pub preview: domain::text::InlineText<MAX_PARAGRAPH_PREVIEW_BYTES>,
     pub font: StageFont,
+    pub wrap_index: u8,
+    pub highlight_anchor: bool,
+    pub pivot_style: FontStyle,
+    pub bold_lead_chars: u8,
+    pub rtl_word: bool,
+    pub rtl_paragraph: bool,
     pub progress_width: u16,
 }
 
@@ -176,6 +204,161 @@ pub struct ParagraphNavigationShell {
     pub rail: ParagraphMapRail,
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DefinitionShell {
+    pub appearance: AppearanceMode,
+    pub word: domain::text::InlineText<{ domain::content::DICTIONARY_WORD_MAX_BYTES }>,
+    pub part_of_speech:
+        domain::text::InlineText<{ domain::content::DICTIONARY_PART_OF_SPEECH_MAX_BYTES }>,
+    pub status_label: Option<&'static str>,
+    pub lines: [domain::text::InlineText<DEFINITION_LINE_MAX_BYTES>;
+        domain::reader::DEFINITION_VISIBLE_LINES],
+    pub show_scroll_up: bool,
+    pub show_scroll_down: bool,
+    pub help: HelpHint,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SectionBreakShell {
+    pub appearance: AppearanceMode,
+    pub title: domain::text::InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub label: &'static str,
+    pub heading: domain::text::InlineText<MAX_PARAGRAPH_PREVIEW_BYTES>,
+    pub help: HelpHint,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CrashReportShell {
+    pub appearance: AppearanceMode,
+    pub title: &'static str,
+    pub lines: [domain::text::InlineText<DEFINITION_LINE_MAX_BYTES>;
+        domain::reader::DEFINITION_VISIBLE_LINES],
+    pub boot_label: domain::text::InlineText<BOOT_LABEL_MAX_BYTES>,
+    pub help: HelpHint,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct OnboardingShell {
+    pub appearance: AppearanceMode,
+    pub step_number: u8,
+    pub step_count: u8,
+    pub title: &'static str,
+    pub prompt: &'static str,
+    pub value: &'static str,
+    pub demo_word: Option<&'static str>,
+    pub help: HelpHint,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DiagnosticsShell {
+    pub appearance: AppearanceMode,
+    pub title: &'static str,
+    pub rows: [DiagnosticsRowModel; DIAGNOSTICS_ROW_COUNT],
+    pub help: HelpHint,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct HistoryShell {
+    pub appearance: AppearanceMode,
+    pub title: &'static str,
+    pub streak_days: Option<u16>,
+    pub total_words: u32,
+    pub selected_index: u16,
+    pub total: u16,
+    pub first: u16,
+    pub rows: [HistoryRowModel; HISTORY_VISIBLE_ROWS],
+    pub help: HelpHint,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ScreensaverShell {
+    pub appearance: AppearanceMode,
+    pub clock: Option<domain::text::InlineText<STATUS_CLOCK_MAX_BYTES>>,
+    pub title: domain::text::InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub progress_percent: u8,
+    pub help: HelpHint,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LowBatteryShell {
+    pub appearance: AppearanceMode,
+    pub status: StatusBar,
+    pub title: &'static str,
+    pub body: domain::text::InlineText<PAUSE_STATUS_MAX_BYTES>,
+    pub help: HelpHint,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PageShell {
+    pub appearance: AppearanceMode,
+    pub title: domain::text::InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub lines: [domain::text::InlineText<PAGE_LINE_MAX_BYTES>; PAGE_LINES],
+    pub progress_width: u16,
+    pub help: HelpHint,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct ContentsRow {
+    pub number: u16,
+    pub preview: domain::text::InlineText<MAX_PARAGRAPH_PREVIEW_BYTES>,
+    pub minutes: u16,
+    pub marker: ContentsMarker,
+    pub selected: bool,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ContentsShell {
+    pub appearance: AppearanceMode,
+    pub title: domain::text::InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub selected_index: u16,
+    pub total: u16,
+    pub first: u16,
+    pub rows: [ContentsRow; CONTENTS_VISIBLE_ROWS],
+    pub help: HelpHint,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ConfirmShell {
+    pub appearance: AppearanceMode,
+    pub title: &'static str,
+    pub body: domain::text::InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub accept_label: &'static str,
+    pub cancel_label: &'static str,
+    pub accept_focused: bool,
+    pub help: HelpHint,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SearchShell {
+    pub appearance: AppearanceMode,
+    pub status: StatusBar,
+    pub rail: VerticalRail,
+    pub query: domain::text::InlineText<SEARCH_QUERY_MAX_BYTES>,
+    pub keys: [PickerKey; SEARCH_VISIBLE_KEYS],
+    pub match_label: domain::text::InlineText<16>,
+    pub matches: [domain::text::InlineText<CONTENT_TITLE_MAX_BYTES>; VISIBLE_LIST_ROWS],
+    pub help: HelpHint,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BookDetailActionRow {
+    pub label: &'static str,
+    pub selected: bool,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BookDetailShell {
+    pub appearance: AppearanceMode,
+    pub status: StatusBar,
+    pub title: domain::text::InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub meta: domain::text::InlineText<CONTENT_META_MAX_BYTES>,
+    pub state_label: &'static str,
+    pub progress_label: domain::text::InlineText<16>,
+    pub progress_percent: u8,
+    pub actions: [BookDetailActionRow; BOOK_DETAIL_ACTION_COUNT],
+    pub help: HelpHint,
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct SettingsRow {
     pub label: &'static str,
@@ -208,27 +391,84 @@ pub struct TopicPreferenceGrid {
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct SettingsShell {
     pub appearance: AppearanceMode,
+    pub status: StatusBar,
     pub title: &'static str,
     pub mode: SettingsMode,
     pub rows: [SettingsRow; 6],
     pub refresh_title: Option<&'static str>,
     pub refresh_body: Option<&'static str>,
     pub topic_preferences: Option<TopicPreferenceGrid>,
+    pub preview_word: Option<&'static str>,
 }
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum PreparedScreen {
+    CrashReport(CrashReportShell),
+    LowBattery(LowBatteryShell),
+    Onboarding(OnboardingShell),
+    Screensaver(ScreensaverShell),
     StartupSplash(StartupSplashShell),
     Dashboard(DashboardShell),
     Collection(ContentListShell),
+    Search(SearchShell),
+    BookDetail(BookDetailShell),
     Reader(ReaderShell),
     ParagraphNavigation(ParagraphNavigationShell),
+    Definition(DefinitionShell),
+    SectionBreak(SectionBreakShell),
+    Page(PageShell),
+    Contents(ContentsShell),
     Settings(SettingsShell),
+    Diagnostics(DiagnosticsShell),
+    History(HistoryShell),
+    Confirm(ConfirmShell),
+}
+
+// Drawn over whichever screen is prepared, oldest at the top of the stack.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct ToastStack {
+    pub labels: [Option<&'static str>; TOAST_QUEUE_CAPACITY],
+}
+
+impl ToastStack {
+    pub const fn empty() -> Self {
+        Self {
+            labels: [None; TOAST_QUEUE_CAPACITY],
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.iter().all(Option::is_none)
+    }
+}
+
+pub fn compose_toasts(queue: &ToastQueue) -> ToastStack {
+    let mut stack = ToastStack::empty();
+    for (slot, toast) in queue.iter().enumerate() {
+        stack.labels[slot] = Some(toast.kind.label());
+    }
+    stack
 }
 
 pub fn compose(model: ActiveScreenModel) -> (Screen, PreparedScreen) {
     match model {
+        ActiveScreenModel::CrashReport(model) => (
+            Screen::CrashReport,
+            PreparedScreen::CrashReport(compose_crash_report(model)),
+        ),
+        ActiveScreenModel::LowBattery(model) => (
+            Screen::LowBattery,
+            PreparedScreen::LowBattery(compose_low_battery(model)),
+        ),
+        ActiveScreenModel::Onboarding(model) => (
+            Screen::Onboarding,
+            PreparedScreen::Onboarding(compose_onboarding(model)),
+        ),
+        ActiveScreenModel::Screensaver(model) => (
+            Screen::Screensaver,
+            PreparedScreen::Screensaver(compose_screensaver(model)),
+        ),
         ActiveScreenModel::StartupSplash(model) => (
             Screen::StartupSplash,
             PreparedScreen::StartupSplash(compose_startup_splash(model)),
@@ -245,6 +485,14 @@ pub fn compose(model: ActiveScreenModel) -> (Screen, PreparedScreen) {
             },
             PreparedScreen::Collection(compose_collection(model)),
         ),
+        ActiveScreenModel::Search(model) => (
+            Screen::Search,
+            PreparedScreen::Search(compose_search(model)),
+        ),
+        ActiveScreenModel::BookDetail(model) => (
+            Screen::BookDetail,
+            PreparedScreen::BookDetail(compose_book_detail(model)),
+        ),
         ActiveScreenModel::Reader(model) => (
             Screen::Reader,
             PreparedScreen::Reader(compose_reader(model)),
@@ -253,10 +501,35 @@ pub fn compose(model: ActiveScreenModel) -> (Screen, PreparedScreen) {
             Screen::ParagraphNavigation,
             PreparedScreen::ParagraphNavigation(compose_paragraph_navigation(model)),
         ),
+        ActiveScreenModel::Definition(model) => (
+            Screen::Definition,
+            PreparedScreen::Definition(compose_definition(model)),
+        ),
+        ActiveScreenModel::SectionBreak(model) => (
+            Screen::SectionBreak,
+            PreparedScreen::SectionBreak(compose_section_break(model)),
+        ),
+        ActiveScreenModel::Page(model) => (Screen::Page, PreparedScreen::Page(compose_page(model))),
+        ActiveScreenModel::Contents(model) => (
+            Screen::Contents,
+            PreparedScreen::Contents(compose_contents(model)),
+        ),
         ActiveScreenModel::Settings(model) => (
             Screen::Settings,
             PreparedScreen::Settings(compose_settings(model)),
         ),
+        ActiveScreenModel::Diagnostics(model) => (
+            Screen::Diagnostics,
+            PreparedScreen::Diagnostics(compose_diagnostics(model)),
+        ),
+        ActiveScreenModel::History(model) => (
+            Screen::History,
+            PreparedScreen::History(compose_history(model)),
+        ),
+        ActiveScreenModel::Confirm(model) => (
+            Screen::Confirm,
+            PreparedScreen::Confirm(compose_confirm(model)),
+        ),
     }
 }
 
@@ -272,10 +545,7 @@ fn compose_startup_splash(model: StartupSplashScreenModel) -> StartupSplashShell
 fn compose_dashboard(model: DashboardScreenModel) -> DashboardShell {
     DashboardShell {
         appearance: model.appearance,
-        status: StatusCluster {
-            battery_percent: model.status.battery_percent,
-            wifi_online: model.status.network == domain::network::NetworkStatus::Online,
-        },
+        status: compose_status(model.status),
         sync_indicator: model.sync_indicator.map(|indicator| SyncIndicator {
             label: indicator.label,
             spinner_phase: indicator.spinner_phase,
@@ -308,10 +578,7 @@ fn compose_collection(model: ContentListScreenModel) -> ContentListShell {
     let recommendations_bar = model.recommendations_bar.map(compose_recommendation_bar);
     ContentListShell {
         appearance: model.appearance,
-        status: StatusCluster {
-            battery_percent: model.status.battery_percent,
-            wifi_online: model.status.network == domain::network::NetworkStatus::Online,
-        },
+        status: compose_status(model.status),
         rail: VerticalRail {
             text: model.rail_label,
         },
@@ -327,6 +594,8 @@ fn compose_collection(model: ContentListScreenModel) -> ContentListShell {
                 meta: model.rows[0].meta,
                 title: model.rows[0].title,
                 progress_badge: model.rows[0].progress_badge,
+                progress_percent: model.rows[0].progress_percent,
+                finished: model.rows[0].finished,
                 is_fetching: model.rows[0].is_fetching,
                 selected: false,
             },
@@ -334,6 +603,8 @@ fn compose_collection(model: ContentListScreenModel) -> ContentListShell {
                 meta: model.rows[1].meta,
                 title: model.rows[1].title,
                 progress_badge: model.rows[1].progress_badge,
+                progress_percent: model.rows[1].progress_percent,
+                finished: model.rows[1].finished,
                 is_fetching: model.rows[1].is_fetching,
                 selected: true,
             },
@@ -341,10 +612,23 @@ fn compose_collection(model: ContentListScreenModel) -> ContentListShell {
                 meta: model.rows[2].meta,
                 title: model.rows[2].title,
                 progress_badge: model.rows[2].progress_badge,
+                progress_percent: model.rows[2].progress_percent,
+                finished: model.rows[2].finished,
                 is_fetching: model.rows[2].is_fetching,
                 selected: false,
             },
         ],
+        grid: model.grid.map(|cells| {
+            cells.map(|cell| ContentRow {
+                meta: cell.meta,
+                title: cell.title,
+                progress_badge: cell.progress_badge,
+                progress_percent: cell.progress_percent,
+                finished: cell.finished,
+                is_fetching: cell.is_fetching,
+                selected: cell.selected,
+            })
+        }),
         band: if recommendations_bar.is_some() {
             SelectionBand { y: 100, height: 64 }
         } else {
@@ -353,6 +637,7 @@ fn compose_collection(model: ContentListScreenModel) -> ContentListShell {
         help: HelpHint {
             text: "long press_",
         },
+        jump_letter: model.jump_letter,
     }
 }
 
@@ -389,38 +674,27 @@ fn compose_reader(model: ReaderScreenModel) -> ReaderShell {
             right_word: model.right_word,
             preview: model.preview,
             font: model.font,
+            wrap_index: model.wrap_index,
+            highlight_anchor: model.highlight_anchor,
+            pivot_style: model.pivot_style,
+            bold_lead_chars: model.bold_lead_chars,
+            rtl_word: model.rtl_word,
+            rtl_paragraph: model.rtl_paragraph,
             progress_width: model.progress_width,
         },
-        badge: model.show_chat_badge.then_some(ModeBadge { label: "CHAT" }),
+        badge: model.badge_label.map(|label| ModeBadge { label }),
         modal: model.modal.map(|modal| match modal {
-            domain::selectors::ReaderModalModel::Pause(actions) => ReaderModal::Pause(PauseModal {
+            domain::selectors::ReaderModalModel::Pause(pause) => ReaderModal::Pause(PauseModal {
                 title: "PAUSED",
-                rows: [
-                    PauseModalRow {
-                        label: actions[0].label,
-                        action: actions[0].action,
-                        selected: actions[0].selected,
-                        enabled: actions[0].enabled,
-                    },
-                    PauseModalRow {
-                        label: actions[1].label,
-                        action: actions[1].action,
-                        selected: actions[1].selected,
-                        enabled: actions[1].enabled,
-                    },
-                    PauseModalRow {
-                        label: actions[2].label,
-                        action: actions[2].action,
-                        selected: actions[2].selected,
-                        enabled: actions[2].enabled,
-                    },
-                    PauseModalRow {
-                        label: actions[3].label,
-                        action: actions[3].action,
-                        selected: actions[3].selected,
-                        enabled: actions[3].enabled,
-                    },
-                ],
+                rows: pause.actions.map(|action| PauseModalRow {
+                    label: action.label,
+                    action: action.action,
+                    selected: action.selected,
+                    enabled: action.enabled,
+                }),
+                session_label: pause.session_label,
+                remaining_label: pause.remaining_label,
+                goal_permille: pause.goal_permille,
             }),
             domain::selectors::ReaderModalModel::Loading(loading) => {
                 ReaderModal::Loading(LoadingModal {
@@ -452,6 +726,248 @@ fn compose_paragraph_navigation(model: ParagraphNavigationModel) -> ParagraphNav
     }
 }
 
+fn compose_section_break(model: SectionBreakScreenModel) -> SectionBreakShell {
+    SectionBreakShell {
+        appearance: model.appearance,
+        title: model.title,
+        label: model.label,
+        heading: model.heading,
+        help: HelpHint {
+            text: "press to continue",
+        },
+    }
+}
+
+fn compose_crash_report(model: CrashReportScreenModel) -> CrashReportShell {
+    CrashReportShell {
+        appearance: model.appearance,
+        title: "PREVIOUS CRASH",
+        lines: model.lines,
+        boot_label: model.boot_label,
+        help: HelpHint {
+            text: "press to continue",
+        },
+    }
+}
+
+fn compose_onboarding(model: OnboardingScreenModel) -> OnboardingShell {
+    OnboardingShell {
+        appearance: model.appearance,
+        step_number: model.step.number(),
+        step_count: ONBOARDING_STEP_COUNT,
+        title: model.title,
+        prompt: model.prompt,
+        value: model.value,
+        demo_word: model.demo_word,
+        help: HelpHint {
+            text: match model.step {
+                OnboardingStep::Encoder | OnboardingStep::Wifi => "press to continue",
+                OnboardingStep::ReadingSpeed => "turn to adjust, press to continue",
+                OnboardingStep::StorageCard => "press to start reading",
+            },
+        },
+    }
+}
+
+fn compose_screensaver(model: ScreensaverScreenModel) -> ScreensaverShell {
+    ScreensaverShell {
+        appearance: model.appearance,
+        clock: model.clock,
+        title: model.title,
+        progress_percent: model.progress_percent,
+        help: HelpHint {
+            text: "press to wake",
+        },
+    }
+}
+
+fn compose_diagnostics(model: DiagnosticsScreenModel) -> DiagnosticsShell {
+    DiagnosticsShell {
+        appearance: model.appearance,
+        title: "DIAGNOSTICS",
+        rows: model.rows,
+        help: HelpHint {
+            text: "long press to go back",
+        },
+    }
+}
+
+fn compose_history(model: HistoryScreenModel) -> HistoryShell {
+    HistoryShell {
+        appearance: model.appearance,
+        title: "READING HISTORY",
+        streak_days: model.streak_days,
+        total_words: model.total_words,
+        selected_index: model.selected_index,
+        total: model.total,
+        first: model.first,
+        rows: model.rows,
+        help: HelpHint {
+            text: if model.total == 0 {
+                "long press to go back"
+            } else {
+                "turn to scroll"
+            },
+        },
+    }
+}
+
+fn compose_low_battery(model: LowBatteryScreenModel) -> LowBatteryShell {
+    LowBatteryShell {
+        appearance: model.appearance,
+        status: compose_status(model.status),
+        title: "LOW BATTERY",
+        body: model.body,
+        help: HelpHint {
+            text: "press to dismiss",
+        },
+    }
+}
+
+fn compose_status(model: StatusBarModel) -> StatusBar {
+    StatusBar {
+        battery_percent: model.battery_percent,
+        charging: model.charging,
+        wifi_online: model.network == domain::network::NetworkStatus::Online,
+        sd_card_ready: model.sd_card_ready,
+        clock: model.clock,
+    }
+}
+
+fn compose_search(model: SearchScreenModel) -> SearchShell {
+    let mut match_label = domain::text::InlineText::new();
+    let count = model.match_count.min(99) as u8;
+    if count >= 10 {
+        let _ = match_label.try_push_char((b'0' + count / 10) as char);
+    }
+    let _ = match_label.try_push_char((b'0' + count % 10) as char);
+    let _ = match_label.try_push_str(if count == 1 { " MATCH" } else { " MATCHES" });
+
+    SearchShell {
+        appearance: model.appearance,
+        status: compose_status(model.status),
+        rail: VerticalRail {
+            text: model.rail_label,
+        },
+        query: model.query,
+        keys: model.keys,
+        match_label,
+        matches: model.matches,
+        help: HelpHint {
+            text: "press to type_",
+        },
+    }
+}
+
+fn compose_book_detail(model: BookDetailScreenModel) -> BookDetailShell {
+    let mut progress_label = domain::text::InlineText::new();
+    if model.finished && model.reads >= 2 {
+        let reads = model.reads.min(99);
+        let _ = progress_label.try_push_str("READ ");
+        if reads >= 10 {
+            let _ = progress_label.try_push_char((b'0' + (reads / 10) as u8) as char);
+        }
+        let _ = progress_label.try_push_char((b'0' + (reads % 10) as u8) as char);
+        let _ = progress_label.try_push_str(" TIMES");
+    } else if model.finished {
+        let _ = progress_label.try_push_str("FINISHED");
+    } else if model.progress_percent == 0 {
+        let _ = progress_label.try_push_str("NOT STARTED");
+    } else {
+        let percent = model.progress_percent.min(100);
+        if percent >= 100 {
+            let _ = progress_label.try_push_char('1');
+        }
+        if percent >= 10 {
+            let _ = progress_label.try_push_char((b'0' + (percent / 10) % 10) as char);
+        }
+        let _ = progress_label.try_push_char((b'0' + percent % 10) as char);
+        let _ = progress_label.try_push_str("% READ");
+    }
+
+    BookDetailShell {
+        appearance: model.appearance,
+        status: compose_status(model.status),
+        title: model.title,
+        meta: model.meta,
+        state_label: model.state_label,
+        progress_label,
+        progress_percent: model.progress_percent,
+        actions: model.actions.map(|action| BookDetailActionRow {
+            label: action.label,
+            selected: action.selected,
+        }),
+        help: HelpHint {
+            text: "long press_",
+        },
+    }
+}
+
+fn compose_page(model: PageScreenModel) -> PageShell {
+    PageShell {
+        appearance: model.appearance,
+        title: model.title,
+        lines: model.lines,
+        progress_width: model.progress_width,
+        help: HelpHint {
+            text: "press to read",
+        },
+    }
+}
+
+fn compose_contents(model: ContentsScreenModel) -> ContentsShell {
+    ContentsShell {
+        appearance: model.appearance,
+        title: model.title,
+        selected_index: model.selected_index,
+        total: model.total,
+        first: model.first,
+        rows: model.rows.map(|row| ContentsRow {
+            number: row.number,
+            preview: row.preview,
+            minutes: row.minutes,
+            marker: row.marker,
+            selected: row.selected,
+        }),
+        help: HelpHint {
+            text: if model.bookmarks && model.total == 0 {
+                "hold while paused to mark"
+            } else {
+                "press to jump"
+            },
+        },
+    }
+}
+
+fn compose_confirm(model: ConfirmScreenModel) -> ConfirmShell {
+    ConfirmShell {
+        appearance: model.appearance,
+        title: model.title,
+        body: model.body,
+        accept_label: model.accept_label,
+        cancel_label: model.cancel_label,
+        accept_focused: model.accept_focused,
+        help: HelpHint {
+            text: "turn to choose",
+        },
+    }
+}
+
+fn compose_definition(model: DefinitionScreenModel) -> DefinitionShell {
+    DefinitionShell {
+        appearance: model.appearance,
+        word: model.word,
+        part_of_speech: model.part_of_speech,
+        status_label: model.status_label,
+        lines: model.lines,
+        show_scroll_up: model.show_scroll_up,
+        show_scroll_down: model.show_scroll_down,
+        help: HelpHint {
+            text: "press to return",
+        },
+    }
+}
+
 fn compose_settings(model: SettingsScreenModel) -> SettingsShell {
     let topic_preferences = model.topic_preferences.map(|topic| TopicPreferenceGrid {
         title: topic.title,
@@ -515,6 +1031,7 @@ fn compose_settings(model: SettingsScreenModel) -> SettingsShell {
 
     SettingsShell {
         appearance: model.appearance,
+        status: compose_status(model.status),
         title: model.title,
         mode: model.mode,
         rows: [
@@ -558,18 +1075,32 @@ fn compose_settings(model: SettingsScreenModel) -> SettingsShell {
         refresh_title: model.refresh_title,
         refresh_body: model.refresh_body,
         topic_preferences,
+        preview_word: model.preview_word,
     }
 }
 
 impl PreparedScreen {
     pub const fn appearance(self) -> AppearanceMode {
         match self {
+            PreparedScreen::CrashReport(shell) => shell.appearance,
+            PreparedScreen::LowBattery(shell) => shell.appearance,
+            PreparedScreen::Onboarding(shell) => shell.appearance,
+            PreparedScreen::Screensaver(shell) => shell.appearance,
             PreparedScreen::StartupSplash(shell) => shell.appearance,
             PreparedScreen::Dashboard(shell) => shell.appearance,
             PreparedScreen::Collection(shell) => shell.appearance,
+            PreparedScreen::Search(shell) => shell.appearance,
+            PreparedScreen::BookDetail(shell) => shell.appearance,
             PreparedScreen::Reader(shell) => shell.appearance,
             PreparedScreen::ParagraphNavigation(shell) => shell.appearance,
+            PreparedScreen::Definition(shell) => shell.appearance,
+            PreparedScreen::SectionBreak(shell) => shell.appearance,
+            PreparedScreen::Page(shell) => shell.appearance,
+            PreparedScreen::Contents(shell) => shell.appearance,
             PreparedScreen::Settings(shell) => shell.appearance,
+            PreparedScreen::Diagnostics(shell) => shell.appearance,
+            PreparedScreen::History(shell) => shell.appearance,
+            PreparedScreen::Confirm(shell) => shell.appearance,
         }
     }
 }
@@ -12,16 +12,25 @@ pub mod navigation;
 pub mod screens;
 pub mod view_models;
 
-pub use animation::{AnimationDescriptor, MotionDirection, TransitionPlan};
+pub use animation::{
+    AnimationDescriptor, AnimationLayer, Easing, LayerAnimation, LayerMotion, MotionDirection,
+    TransitionPlan,
+};
 pub use components::{
-    ComponentId, ContentListShell, DashboardShell, ParagraphNavigationShell, PreparedScreen,
-    ReaderShell, SettingsShell, StartupSplashShell,
+    BookDetailShell, ComponentId, ConfirmShell, ContentListShell, ContentsShell, CrashReportShell,
+    DashboardShell, DefinitionShell, DiagnosticsShell, HistoryShell, LowBatteryShell,
+    OnboardingShell, PageShell, ParagraphNavigationShell, PreparedScreen, ReaderShell,
+    ScreensaverShell, SearchShell, SectionBreakShell, SettingsShell, StartupSplashShell,
+    ToastStack,
 };
 pub use navigation::NavigationState;
 pub use screens::Screen;
 pub use view_models::{
-    ActiveScreenModel, ContentListScreenModel, DashboardScreenModel, ParagraphNavigationModel,
-    ReaderScreenModel, SettingsScreenModel, StartupSplashScreenModel,
+    ActiveScreenModel, BookDetailScreenModel, ConfirmScreenModel, ContentListScreenModel,
+    ContentsScreenModel, CrashReportScreenModel, DashboardScreenModel, DefinitionScreenModel,
+    DiagnosticsScreenModel, HistoryScreenModel, LowBatteryScreenModel, OnboardingScreenModel,
+    PageScreenModel, ParagraphNavigationModel, ReaderScreenModel, ScreensaverScreenModel,
+    SearchScreenModel, SectionBreakScreenModel, SettingsScreenModel, StartupSplashScreenModel,
 };
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -29,6 +38,7 @@ pub struct ScreenUpdate {
     pub screen: Screen,
     pub prepared: PreparedScreen,
     pub transition: TransitionPlan,
+    pub toasts: ToastStack,
 }
 
 #[derive(Debug, Default)]
@@ -52,6 +62,7 @@ impl AppRuntime {
             screen,
             prepared,
             transition,
+            toasts: components::compose_toasts(&store.ui.toasts),
         };
 
         self.previous = Some(Box::new(update));
@@ -73,7 +84,10 @@ fn plan_transition(
         return TransitionPlan::none();
     };
 
-    use animation::{AnimationDescriptor as A, MotionDirection as D, TransitionPlan as T};
+    use animation::{
+        AnimationDescriptor as A, AnimationLayer, Easing, LayerAnimation, LayerMotion,
+        MotionDirection as D, TransitionPlan as T,
+    };
 
     match (previous.screen, screen, &previous.prepared, prepared) {
         (
@@ -89,13 +103,27 @@ fn plan_transition(
             new_screen,
             PreparedScreen::Collection(old),
             PreparedScreen::Collection(new),
-        ) if old_screen == new_screen && old.rows[1].title != new.rows[1].title => {
+        ) if old_screen == new_screen
+            && old.rows[1].title != new.rows[1].title
+            && new.jump_letter.is_none() =>
+        {
             T::new(A::ListStep(direction_for_rows(old, new)), 4, 46)
         }
         (old_screen, Screen::Reader, _, PreparedScreen::Reader(_))
             if is_collection_screen(old_screen) =>
         {
+            // The title drops in while the first word fades up under it.
             T::new(A::ReaderEnter, 3, 50)
+                .with_layer(LayerAnimation::new(
+                    AnimationLayer::Header,
+                    LayerMotion::SlideDown,
+                    Easing::Spring,
+                ))
+                .with_layer(LayerAnimation::new(
+                    AnimationLayer::Stage,
+                    LayerMotion::Fade,
+                    Easing::Linear,
+                ))
         }
         (Screen::Reader, new_screen, PreparedScreen::Reader(_), _)
             if is_collection_screen(new_screen) =>
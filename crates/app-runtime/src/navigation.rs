@@ -1,7 +1,4 @@
-use domain::{
-    input::{InputGesture, RotationDirection},
-    runtime::{Command, UiCommand},
-};
+use domain::{input::InputGesture, runtime::Command};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct NavigationState;
@@ -12,16 +9,7 @@ impl NavigationState {
     }
 
     pub const fn command_for_gesture(gesture: InputGesture) -> Command {
-        match gesture {
-            InputGesture::Rotate {
-                direction: RotationDirection::Clockwise,
-            } => Command::Ui(UiCommand::FocusNext),
-            InputGesture::Rotate {
-                direction: RotationDirection::CounterClockwise,
-            } => Command::Ui(UiCommand::FocusPrevious),
-            InputGesture::Click => Command::Ui(UiCommand::Confirm),
-            InputGesture::LongPress => Command::Ui(UiCommand::Back),
-        }
+        Command::for_gesture(gesture)
     }
 }
 
@@ -2,11 +2,24 @@
 pub enum Screen {
     #[default]
     StartupSplash,
+    CrashReport,
+    LowBattery,
+    Onboarding,
+    Screensaver,
     Dashboard,
     Saved,
     Inbox,
     Recommendations,
+    Search,
+    BookDetail,
     Reader,
     ParagraphNavigation,
+    Definition,
+    SectionBreak,
+    Page,
+    Contents,
     Settings,
+    Diagnostics,
+    History,
+    Confirm,
 }
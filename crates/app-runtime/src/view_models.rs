@@ -1,4 +1,7 @@
 pub use domain::selectors::{
-    ActiveScreenModel, ContentListScreenModel, DashboardScreenModel, ParagraphNavigationModel,
-    ReaderScreenModel, SettingsScreenModel, StartupSplashScreenModel,
+    ActiveScreenModel, BookDetailScreenModel, ConfirmScreenModel, ContentListScreenModel,
+    ContentsScreenModel, CrashReportScreenModel, DashboardScreenModel, DefinitionScreenModel,
+    DiagnosticsScreenModel, HistoryScreenModel, LowBatteryScreenModel, OnboardingScreenModel,
+    PageScreenModel, ParagraphNavigationModel, ReaderScreenModel, ScreensaverScreenModel,
+    SearchScreenModel, SectionBreakScreenModel, SettingsScreenModel, StartupSplashScreenModel,
 };
@@ -0,0 +1,170 @@
+// Opt-in speed drift for the reader. A long stretch of reading with no pause or turn back moves
+// the speed up one step; a run of pauses and turns back moves it down one. A quick double turn
+// asks for a step directly. Steps go through `SettingsState::adjust_reading_speed`, so they stay
+// on the speed grid and inside its bounds.
+
+pub const ADAPTIVE_SPEED_UP_AFTER_MS: u64 = 180_000;
+pub const ADAPTIVE_SLOW_DOWN_INTERRUPTIONS: u8 = 3;
+pub const ADAPTIVE_INTERRUPTION_WINDOW_MS: u64 = 120_000;
+// A second turn the same way this soon after the first is a double turn.
+pub const DOUBLE_TURN_MS: u64 = 300;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SpeedDrift {
+    Faster,
+    Slower,
+}
+
+impl SpeedDrift {
+    pub const fn increases(self) -> bool {
+        matches!(self, Self::Faster)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct AdaptiveSpeed {
+    uninterrupted_ms: u64,
+    interruptions: u8,
+    first_interruption_at_ms: u64,
+    slow_down_due: bool,
+    // A reader turn held until the double-turn window closes: toward the previous paragraph, and
+    // when it came in.
+    held_turn: Option<(bool, u64)>,
+}
+
+impl AdaptiveSpeed {
+    pub const fn new() -> Self {
+        Self {
+            uninterrupted_ms: 0,
+            interruptions: 0,
+            first_interruption_at_ms: 0,
+            slow_down_due: false,
+            held_turn: None,
+        }
+    }
+
+    // Called with each reader tick's reading time; returns the step the reading so far has
+    // earned. A slow-down earned while paused lands once reading picks up again.
+    pub fn note_reading(&mut self, reading_ms: u64) -> Option<SpeedDrift> {
+        if reading_ms == 0 {
+            return None;
+        }
+        if self.slow_down_due {
+            self.slow_down_due = false;
+            return Some(SpeedDrift::Slower);
+        }
+
+        self.uninterrupted_ms = self.uninterrupted_ms.saturating_add(reading_ms);
+        if self.uninterrupted_ms < ADAPTIVE_SPEED_UP_AFTER_MS {
+            return None;
+        }
+        self.uninterrupted_ms = 0;
+        Some(SpeedDrift::Faster)
+    }
+
+    // A pause or a turn back.
+    pub fn note_interruption(&mut self, now_ms: u64) {
+        self.uninterrupted_ms = 0;
+        if self.interruptions == 0
+            || now_ms.saturating_sub(self.first_interruption_at_ms)
+                > ADAPTIVE_INTERRUPTION_WINDOW_MS
+        {
+            self.interruptions = 0;
+            self.first_interruption_at_ms = now_ms;
+        }
+        self.interruptions += 1;
+        if self.interruptions >= ADAPTIVE_SLOW_DOWN_INTERRUPTIONS {
+            self.interruptions = 0;
+            self.slow_down_due = true;
+        }
+    }
+
+    // A step the reader asked for says the speed is where they want it, so drift starts over.
+    pub fn restart(&mut self) {
+        let held_turn = self.held_turn;
+        *self = Self::new();
+        self.held_turn = held_turn;
+    }
+
+    // Completes a double turn with the held one; the second turn is the step's direction.
+    pub fn double_turn(&mut self, previous: bool, now_ms: u64) -> Option<SpeedDrift> {
+        let (held_previous, held_at_ms) = self.held_turn?;
+        if held_previous != previous || now_ms.saturating_sub(held_at_ms) > DOUBLE_TURN_MS {
+            return None;
+        }
+
+        self.held_turn = None;
+        Some(if previous {
+            SpeedDrift::Slower
+        } else {
+            SpeedDrift::Faster
+        })
+    }
+
+    // Holds a turn, handing back the one it replaces so that one still runs.
+    pub fn hold_turn(&mut self, previous: bool, now_ms: u64) -> Option<bool> {
+        self.held_turn
+            .replace((previous, now_ms))
+            .map(|(held_previous, _)| held_previous)
+    }
+
+    // The held turn once its window has closed without a second one.
+    pub fn due_turn(&mut self, now_ms: u64) -> Option<bool> {
+        let (held_previous, held_at_ms) = self.held_turn?;
+        if now_ms.saturating_sub(held_at_ms) <= DOUBLE_TURN_MS {
+            return None;
+        }
+
+        self.held_turn = None;
+        Some(held_previous)
+    }
+
+    pub fn drop_turn(&mut self) {
+        self.held_turn = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steady_reading_speeds_up_and_repeated_interruptions_slow_down() {
+        let mut adaptive = AdaptiveSpeed::new();
+        assert_eq!(adaptive.note_reading(ADAPTIVE_SPEED_UP_AFTER_MS - 1), None);
+        assert_eq!(adaptive.note_reading(1), Some(SpeedDrift::Faster));
+
+        adaptive.note_reading(ADAPTIVE_SPEED_UP_AFTER_MS - 1);
+        adaptive.note_interruption(1_000);
+        assert_eq!(adaptive.note_reading(1), None);
+
+        // Interruptions spread wider than the window never add up.
+        adaptive.note_interruption(10_000);
+        adaptive.note_interruption(10_000 + ADAPTIVE_INTERRUPTION_WINDOW_MS + 1);
+        adaptive.note_interruption(10_000 + 2 * ADAPTIVE_INTERRUPTION_WINDOW_MS + 2);
+        assert_eq!(adaptive.note_reading(1), None);
+
+        for at_ms in [500_000, 510_000, 520_000] {
+            adaptive.note_interruption(at_ms);
+        }
+        assert_eq!(adaptive.note_reading(0), None);
+        assert_eq!(adaptive.note_reading(1), Some(SpeedDrift::Slower));
+        assert_eq!(adaptive.note_reading(1), None);
+    }
+
+    #[test]
+    fn a_second_turn_inside_the_window_makes_a_double_turn() {
+        let mut adaptive = AdaptiveSpeed::new();
+        assert_eq!(adaptive.double_turn(false, 0), None);
+        assert_eq!(adaptive.hold_turn(false, 0), None);
+        assert_eq!(adaptive.double_turn(false, 200), Some(SpeedDrift::Faster));
+        assert_eq!(adaptive.due_turn(1_000), None);
+
+        adaptive.hold_turn(true, 1_000);
+        assert_eq!(adaptive.double_turn(false, 1_100), None);
+        assert_eq!(adaptive.hold_turn(false, 1_100), Some(true));
+        assert_eq!(adaptive.due_turn(1_100 + DOUBLE_TURN_MS), None);
+        assert_eq!(adaptive.due_turn(1_101 + DOUBLE_TURN_MS), Some(false));
+        assert_eq!(adaptive.double_turn(false, 1_200 + DOUBLE_TURN_MS), None);
+    }
+}
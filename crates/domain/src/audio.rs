@@ -0,0 +1,82 @@
+// Short piezo cues. The store queues one at a time and the platform plays it when a buzzer is
+// fitted; without one, or with the volume off, nothing is queued.
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AudioCue {
+    CountdownTick,
+    ChapterChime,
+    LowBattery,
+}
+
+// One note of a cue; a zero frequency is a rest.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Tone {
+    pub hz: u16,
+    pub ms: u16,
+}
+
+const fn tone(hz: u16, ms: u16) -> Tone {
+    Tone { hz, ms }
+}
+
+// Frequencies sit near a piezo disc's 2 to 4 kHz resonance, where it is loudest for the least
+// current.
+const COUNTDOWN_TICK: [Tone; 1] = [tone(3_000, 25)];
+const CHAPTER_CHIME: [Tone; 3] = [tone(2_093, 90), tone(0, 30), tone(2_637, 140)];
+const LOW_BATTERY: [Tone; 3] = [tone(2_500, 120), tone(0, 80), tone(2_000, 200)];
+
+impl AudioCue {
+    pub const fn tones(self) -> &'static [Tone] {
+        match self {
+            Self::CountdownTick => &COUNTDOWN_TICK,
+            Self::ChapterChime => &CHAPTER_CHIME,
+            Self::LowBattery => &LOW_BATTERY,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum AudioVolume {
+    #[default]
+    Off,
+    Low,
+    High,
+}
+
+impl AudioVolume {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Off => "OFF",
+            Self::Low => "LOW",
+            Self::High => "HIGH",
+        }
+    }
+
+    pub const fn cycled(self) -> Self {
+        match self {
+            Self::Off => Self::Low,
+            Self::Low => Self::High,
+            Self::High => Self::Off,
+        }
+    }
+
+    pub const fn is_off(self) -> bool {
+        matches!(self, Self::Off)
+    }
+
+    pub const fn to_byte(self) -> u8 {
+        match self {
+            Self::Off => 0,
+            Self::Low => 1,
+            Self::High => 2,
+        }
+    }
+
+    pub const fn from_byte(value: u8) -> Self {
+        match value {
+            1 => Self::Low,
+            2 => Self::High,
+            _ => Self::Off,
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{content::CONTENT_ID_MAX_BYTES, text::InlineText};
+
+// Per-book outcomes that outlive the reading position: whether the book is finished and how many
+// times it has been read to the end. Kept per profile and written whole, like the bookmarks.
+pub const BOOK_STATS_CAPACITY: usize = 64;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BookStat {
+    pub content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+    pub finished: bool,
+    // Times the reader reached the last word; past one, the book has been re-read.
+    pub reads: u16,
+}
+
+impl BookStat {
+    pub const fn empty() -> Self {
+        Self {
+            content_id: InlineText::new(),
+            finished: false,
+            reads: 0,
+        }
+    }
+}
+
+impl Default for BookStat {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BookStatsState {
+    pub entries: [BookStat; BOOK_STATS_CAPACITY],
+    len: u8,
+}
+
+impl BookStatsState {
+    pub const fn empty() -> Self {
+        Self {
+            entries: [BookStat::empty(); BOOK_STATS_CAPACITY],
+            len: 0,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, content_id: &InlineText<CONTENT_ID_MAX_BYTES>) -> Option<BookStat> {
+        self.entries[..self.len()]
+            .iter()
+            .find(|entry| entry.content_id == *content_id)
+            .copied()
+    }
+
+    pub fn is_finished(&self, content_id: &InlineText<CONTENT_ID_MAX_BYTES>) -> bool {
+        self.get(content_id).is_some_and(|entry| entry.finished)
+    }
+
+    // Replaces any entry for the same book; past capacity the oldest book is forgotten.
+    pub fn insert(&mut self, stat: BookStat) {
+        if stat.content_id.is_empty() {
+            return;
+        }
+        if let Some(index) = self.index_of(&stat.content_id) {
+            self.entries[index] = stat;
+            return;
+        }
+        if self.len() == BOOK_STATS_CAPACITY {
+            self.entries.copy_within(1.., 0);
+            self.len -= 1;
+        }
+        self.entries[self.len()] = stat;
+        self.len += 1;
+    }
+
+    // The reader reached the last word.
+    pub fn record_finish(&mut self, content_id: InlineText<CONTENT_ID_MAX_BYTES>) {
+        let mut stat = self.get(&content_id).unwrap_or(BookStat {
+            content_id,
+            ..BookStat::empty()
+        });
+        stat.finished = true;
+        stat.reads = stat.reads.saturating_add(1);
+        self.insert(stat);
+    }
+
+    // The detail screen's toggle; the read count only moves with real read-throughs.
+    pub fn toggle_finished(&mut self, content_id: InlineText<CONTENT_ID_MAX_BYTES>) -> bool {
+        let mut stat = self.get(&content_id).unwrap_or(BookStat {
+            content_id,
+            ..BookStat::empty()
+        });
+        stat.finished = !stat.finished;
+        self.insert(stat);
+        stat.finished
+    }
+
+    fn index_of(&self, content_id: &InlineText<CONTENT_ID_MAX_BYTES>) -> Option<usize> {
+        self.entries[..self.len()]
+            .iter()
+            .position(|entry| entry.content_id == *content_id)
+    }
+}
+
+impl Default for BookStatsState {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(value: &str) -> InlineText<CONTENT_ID_MAX_BYTES> {
+        InlineText::from_slice(value)
+    }
+
+    #[test]
+    fn finishing_counts_reads_and_the_toggle_leaves_them_alone() {
+        let mut stats = BookStatsState::empty();
+        assert!(!stats.is_finished(&id("a")));
+
+        stats.record_finish(id("a"));
+        stats.record_finish(id("a"));
+        assert_eq!(stats.get(&id("a")).map(|stat| stat.reads), Some(2));
+
+        assert!(!stats.toggle_finished(id("a")));
+        assert!(!stats.is_finished(&id("a")));
+        assert!(stats.toggle_finished(id("b")));
+        assert_eq!(stats.get(&id("b")).map(|stat| stat.reads), Some(0));
+        assert_eq!(stats.len(), 2);
+    }
+
+    #[test]
+    fn a_full_table_forgets_the_oldest_book() {
+        let mut stats = BookStatsState::empty();
+        let ids: [_; BOOK_STATS_CAPACITY + 1] =
+            core::array::from_fn(|index| id(&alloc::format!("book-{index}")));
+        for content_id in ids {
+            stats.record_finish(content_id);
+        }
+
+        assert_eq!(stats.len(), BOOK_STATS_CAPACITY);
+        assert!(!stats.is_finished(&ids[0]));
+        assert!(stats.is_finished(&ids[BOOK_STATS_CAPACITY]));
+    }
+}
@@ -0,0 +1,212 @@
+use core::fmt::{self, Write};
+
+use crate::{
+    content::{CONTENT_ID_MAX_BYTES, CONTENT_TITLE_MAX_BYTES},
+    formatter::MAX_PARAGRAPH_PREVIEW_BYTES,
+    text::InlineText,
+};
+
+// Shared by every book on the profile; a new mark past the limit drops the oldest one.
+pub const BOOKMARK_CAPACITY: usize = 64;
+// The preview and title at their longest plus the quotes, separator and paragraph number.
+pub const QUOTE_LINE_MAX_BYTES: usize = MAX_PARAGRAPH_PREVIEW_BYTES + CONTENT_TITLE_MAX_BYTES + 24;
+
+// A marked paragraph (1-based, like the contents list). Marks belong to one revision of a book,
+// since a re-synced package can number its paragraphs differently.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Bookmark {
+    pub content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+    pub remote_revision: u64,
+    pub paragraph_index: u16,
+}
+
+impl Bookmark {
+    pub const fn empty() -> Self {
+        Self {
+            content_id: InlineText::new(),
+            remote_revision: 0,
+            paragraph_index: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.content_id.is_empty() || self.paragraph_index == 0
+    }
+
+    fn belongs_to(
+        &self,
+        content_id: &InlineText<CONTENT_ID_MAX_BYTES>,
+        remote_revision: u64,
+    ) -> bool {
+        self.content_id == *content_id && self.remote_revision == remote_revision
+    }
+}
+
+impl Default for Bookmark {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+// Oldest first, so eviction and the on-card order agree.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BookmarkState {
+    pub entries: [Bookmark; BOOKMARK_CAPACITY],
+    len: u8,
+}
+
+impl BookmarkState {
+    pub const fn empty() -> Self {
+        Self {
+            entries: [Bookmark::empty(); BOOKMARK_CAPACITY],
+            len: 0,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn contains(&self, bookmark: &Bookmark) -> bool {
+        self.entries[..self.len()].contains(bookmark)
+    }
+
+    pub fn insert(&mut self, bookmark: Bookmark) {
+        if bookmark.is_empty() || self.contains(&bookmark) {
+            return;
+        }
+
+        if self.len() < BOOKMARK_CAPACITY {
+            self.entries[self.len()] = bookmark;
+            self.len += 1;
+        } else {
+            self.entries.copy_within(1..BOOKMARK_CAPACITY, 0);
+            self.entries[BOOKMARK_CAPACITY - 1] = bookmark;
+        }
+    }
+
+    // Adds the mark, or removes it when the paragraph was already marked. Returns whether the
+    // paragraph is marked afterwards.
+    pub fn toggle(&mut self, bookmark: Bookmark) -> bool {
+        if let Some(index) = self.entries[..self.len()]
+            .iter()
+            .position(|entry| *entry == bookmark)
+        {
+            let len = self.len();
+            self.entries.copy_within(index + 1..len, index);
+            self.entries[len - 1] = Bookmark::empty();
+            self.len -= 1;
+            return false;
+        }
+
+        self.insert(bookmark);
+        self.contains(&bookmark)
+    }
+
+    // The book's marked paragraphs in reading order.
+    pub fn paragraphs_for(
+        &self,
+        content_id: &InlineText<CONTENT_ID_MAX_BYTES>,
+        remote_revision: u64,
+    ) -> ([u16; BOOKMARK_CAPACITY], usize) {
+        let mut paragraphs = [0u16; BOOKMARK_CAPACITY];
+        let mut count = 0usize;
+        for entry in &self.entries[..self.len()] {
+            if entry.belongs_to(content_id, remote_revision) {
+                paragraphs[count] = entry.paragraph_index;
+                count += 1;
+            }
+        }
+        paragraphs[..count].sort_unstable();
+        (paragraphs, count)
+    }
+}
+
+impl Default for BookmarkState {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+// A newly marked paragraph as it goes into the card's quote file. The preview is the contents-list
+// line, not the whole paragraph, and the paragraph number is the only reference the packages
+// carry; there are no chapter titles.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Quote {
+    pub title: InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub paragraph_index: u16,
+    pub preview: InlineText<MAX_PARAGRAPH_PREVIEW_BYTES>,
+}
+
+impl Quote {
+    pub fn write_line(&self, out: &mut impl Write) -> fmt::Result {
+        writeln!(
+            out,
+            "\"{}\" - {}, paragraph {}",
+            self.preview.as_str(),
+            self.title.as_str(),
+            self.paragraph_index
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mark(content_id: &str, paragraph_index: u16) -> Bookmark {
+        Bookmark {
+            content_id: InlineText::from_slice(content_id),
+            remote_revision: 1,
+            paragraph_index,
+        }
+    }
+
+    #[test]
+    fn toggle_marks_lists_in_order_and_evicts_oldest() {
+        let mut bookmarks = BookmarkState::empty();
+        assert!(bookmarks.toggle(mark("book", 9)));
+        assert!(bookmarks.toggle(mark("book", 3)));
+        assert!(bookmarks.toggle(mark("other", 5)));
+
+        let (paragraphs, count) = bookmarks.paragraphs_for(&InlineText::from_slice("book"), 1);
+        assert_eq!(&paragraphs[..count], &[3, 9]);
+        assert_eq!(
+            bookmarks
+                .paragraphs_for(&InlineText::from_slice("book"), 2)
+                .1,
+            0
+        );
+
+        assert!(!bookmarks.toggle(mark("book", 9)));
+        assert_eq!(bookmarks.len(), 2);
+
+        for paragraph in 1..=BOOKMARK_CAPACITY as u16 {
+            bookmarks.toggle(mark("long", paragraph));
+        }
+        assert_eq!(bookmarks.len(), BOOKMARK_CAPACITY);
+        assert!(!bookmarks.contains(&mark("book", 3)));
+        assert!(bookmarks.contains(&mark("long", 1)));
+    }
+
+    #[test]
+    fn quote_line_names_the_book_and_paragraph() {
+        let quote = Quote {
+            title: InlineText::from_slice("Nineteen Eighty-Four"),
+            paragraph_index: 12,
+            preview: InlineText::from_slice("It was a bright cold day in April"),
+        };
+        let mut line = alloc::string::String::new();
+        quote.write_line(&mut line).unwrap();
+
+        assert_eq!(
+            line,
+            "\"It was a bright cold day in April\" - Nineteen Eighty-Four, paragraph 12\n"
+        );
+        assert!(line.len() <= QUOTE_LINE_MAX_BYTES);
+    }
+}
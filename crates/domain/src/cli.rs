@@ -0,0 +1,281 @@
+use core::fmt::Write;
+
+use crate::{
+    content::{CONTENT_ID_MAX_BYTES, CollectionKind, PackageState, PrepareContentRequest},
+    runtime::Effect,
+    settings::{MAX_READING_SPEED_WPM, MIN_READING_SPEED_WPM, READING_SPEED_STEP_WPM},
+    store::Store,
+    text::InlineText,
+};
+
+pub const CLI_LINE_MAX_BYTES: usize = 96;
+
+const HELP_TEXT: &str = "commands:\n  ls books\n  cat settings\n  set wpm <200-360>\n  screenshot\n  rescan [saved|inbox|foryou]\n  keep <content_id>\n";
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CliCommand {
+    Help,
+    ListBooks,
+    CatSettings,
+    SetWpm(u16),
+    Screenshot,
+    Rescan(CollectionKind),
+    Keep(InlineText<CONTENT_ID_MAX_BYTES>),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CliError {
+    Empty,
+    UnknownCommand,
+    MissingArgument,
+    InvalidArgument,
+}
+
+impl CliError {
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::Empty => "",
+            Self::UnknownCommand => "unknown command, try help",
+            Self::MissingArgument => "missing argument, try help",
+            Self::InvalidArgument => "invalid argument, try help",
+        }
+    }
+}
+
+pub fn parse_line(line: &str) -> Result<CliCommand, CliError> {
+    let mut words = line.split_ascii_whitespace();
+    let Some(verb) = words.next() else {
+        return Err(CliError::Empty);
+    };
+
+    let command = match verb {
+        "help" | "?" => CliCommand::Help,
+        "ls" => match words.next() {
+            None | Some("books") => CliCommand::ListBooks,
+            Some(_) => return Err(CliError::InvalidArgument),
+        },
+        "cat" => match words.next() {
+            Some("settings") => CliCommand::CatSettings,
+            Some(_) => return Err(CliError::InvalidArgument),
+            None => return Err(CliError::MissingArgument),
+        },
+        "set" => match (words.next(), words.next()) {
+            (Some("wpm"), Some(value)) => {
+                CliCommand::SetWpm(value.parse().map_err(|_| CliError::InvalidArgument)?)
+            }
+            (Some("wpm"), None) | (None, _) => return Err(CliError::MissingArgument),
+            (Some(_), _) => return Err(CliError::InvalidArgument),
+        },
+        "screenshot" => CliCommand::Screenshot,
+        "rescan" => CliCommand::Rescan(match words.next() {
+            None | Some("saved") => CollectionKind::Saved,
+            Some("inbox") => CollectionKind::Inbox,
+            Some("foryou") => CollectionKind::Recommendations,
+            Some(_) => return Err(CliError::InvalidArgument),
+        }),
+        "keep" => {
+            let content_id = words.next().ok_or(CliError::MissingArgument)?;
+            let mut id = InlineText::new();
+            if !id.try_push_str(content_id) {
+                return Err(CliError::InvalidArgument);
+            }
+            CliCommand::Keep(id)
+        }
+        _ => return Err(CliError::UnknownCommand),
+    };
+
+    if words.next().is_some() {
+        return Err(CliError::InvalidArgument);
+    }
+    Ok(command)
+}
+
+// Runs a parsed command against the store and writes the reply. Screenshots need the frame
+// buffer, so the transport answers those itself before they get here.
+pub fn execute(store: &mut Store, command: CliCommand, out: &mut impl Write) -> Effect {
+    match command {
+        CliCommand::Help => {
+            let _ = out.write_str(HELP_TEXT);
+            Effect::Noop
+        }
+        CliCommand::ListBooks => {
+            for kind in CollectionKind::ALL {
+                let collection = store.content().collection_state(kind);
+                for index in 0..collection.len() {
+                    let Some(item) = collection.item_at(index) else {
+                        continue;
+                    };
+                    let _ = writeln!(
+                        out,
+                        "{:<8} {:<24} {}",
+                        kind.dashboard_label(),
+                        item.content_id.as_str(),
+                        item.title.as_str()
+                    );
+                }
+            }
+            Effect::Noop
+        }
+        CliCommand::CatSettings => {
+            let settings = &store.settings;
+            let _ = writeln!(out, "profile {}", settings.profile_label());
+            let _ = writeln!(out, "wpm {}", settings.reading_speed_wpm);
+            let _ = writeln!(out, "adaptive_speed {}", settings.adaptive_speed_label());
+            let _ = writeln!(out, "appearance {}", settings.appearance.label());
+            let _ = writeln!(out, "night_mode {}", settings.night_mode_label());
+            let _ = writeln!(out, "light_sensor {}", settings.light_sensor_label());
+            let _ = writeln!(out, "section_pauses {}", settings.section_pauses_label());
+            let _ = writeln!(out, "dot_pause {}", settings.sentence_pause_label());
+            let _ = writeln!(out, "comma_pause {}", settings.clause_pause_label());
+            let _ = writeln!(out, "word_timing {}", settings.dwell_mode_label());
+            let _ = writeln!(out, "min_dwell_ms {}", settings.min_dwell_ms);
+            let _ = writeln!(out, "max_dwell_ms {}", settings.max_dwell_ms);
+            let _ = writeln!(out, "word_groups {}", settings.chunking_label());
+            let _ = writeln!(out, "references {}", settings.references_label());
+            let _ = writeln!(out, "digit_groups {}", settings.digit_grouping_label());
+            let _ = writeln!(out, "resume_rewind {}", settings.rewind_words_label());
+            let _ = writeln!(out, "countdown {}", settings.countdown_label());
+            let _ = writeln!(out, "anchor_highlight {}", settings.orp_highlight_label());
+            let _ = writeln!(out, "anchor_position {}", settings.orp_anchor_label());
+            let _ = writeln!(out, "bionic_reading {}", settings.bionic_reading_label());
+            let _ = writeln!(out, "reading_goal {}", settings.reading_goal_label());
+            let _ = writeln!(out, "screensaver {}", settings.screensaver_label());
+            let _ = writeln!(out, "sound {}", settings.audio_volume_label());
+            let _ = writeln!(
+                out,
+                "encoder_direction {}",
+                settings.encoder_direction_label()
+            );
+            let _ = writeln!(
+                out,
+                "encoder_sensitivity {}",
+                settings.encoder_sensitivity_label()
+            );
+            let _ = writeln!(out, "library_layout {}", settings.library_grid_label());
+            let _ = writeln!(out, "quote_export {}", settings.quote_export_label());
+            let _ = writeln!(
+                out,
+                "inactivity_timeout_ms {}",
+                settings.inactivity_timeout_ms
+            );
+            Effect::Noop
+        }
+        CliCommand::SetWpm(wpm) => {
+            if !(MIN_READING_SPEED_WPM..=MAX_READING_SPEED_WPM).contains(&wpm)
+                || !(wpm - MIN_READING_SPEED_WPM).is_multiple_of(READING_SPEED_STEP_WPM)
+            {
+                let _ = writeln!(
+                    out,
+                    "wpm must be {}-{} in steps of {}",
+                    MIN_READING_SPEED_WPM, MAX_READING_SPEED_WPM, READING_SPEED_STEP_WPM
+                );
+                return Effect::Noop;
+            }
+            store.settings.reading_speed_wpm = wpm;
+            let _ = writeln!(out, "wpm {}", wpm);
+            Effect::PersistSettings(store.settings.to_persisted())
+        }
+        CliCommand::Screenshot => {
+            let _ = writeln!(out, "screenshot unavailable");
+            Effect::Noop
+        }
+        CliCommand::Rescan(kind) => {
+            let _ = writeln!(out, "refreshing {}", kind.dashboard_label());
+            Effect::RefreshCollection(kind)
+        }
+        CliCommand::Keep(content_id) => {
+            let Some(request) = cached_content_request(store, &content_id) else {
+                let _ = writeln!(out, "{} is not cached on sd", content_id.as_str());
+                return Effect::Noop;
+            };
+            let _ = writeln!(out, "copying {} to flash", content_id.as_str());
+            Effect::KeepOnDevice(request)
+        }
+    }
+}
+
+fn cached_content_request(
+    store: &Store,
+    content_id: &InlineText<CONTENT_ID_MAX_BYTES>,
+) -> Option<PrepareContentRequest> {
+    CollectionKind::ALL.into_iter().find_map(|kind| {
+        let collection = store.content().collection_state(kind);
+        (0..collection.len())
+            .filter_map(|index| collection.item_at(index))
+            .find(|item| {
+                item.content_id == *content_id && item.package_state == PackageState::Cached
+            })
+            .map(|item| PrepareContentRequest::from_manifest(kind, item))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn parses_shell_lines() {
+        assert_eq!(parse_line("ls books"), Ok(CliCommand::ListBooks));
+        assert_eq!(parse_line("  cat settings \r"), Ok(CliCommand::CatSettings));
+        assert_eq!(parse_line("set wpm 300"), Ok(CliCommand::SetWpm(300)));
+        assert_eq!(
+            parse_line("rescan inbox"),
+            Ok(CliCommand::Rescan(CollectionKind::Inbox))
+        );
+        assert_eq!(
+            parse_line("keep abc-123"),
+            Ok(CliCommand::Keep(InlineText::from_slice("abc-123")))
+        );
+        assert_eq!(parse_line("keep"), Err(CliError::MissingArgument));
+        assert_eq!(parse_line(""), Err(CliError::Empty));
+        assert_eq!(parse_line("set wpm"), Err(CliError::MissingArgument));
+        assert_eq!(parse_line("set wpm fast"), Err(CliError::InvalidArgument));
+        assert_eq!(parse_line("ls books now"), Err(CliError::InvalidArgument));
+        assert_eq!(parse_line("reboot"), Err(CliError::UnknownCommand));
+    }
+
+    #[test]
+    fn cat_settings_prints_the_formatter_settings() {
+        let mut store = Store::new();
+        store.settings.toggle_dwell_mode();
+        store.settings.cycle_min_dwell_ms();
+        store.settings.toggle_chunking();
+        store.settings.toggle_references();
+        store.settings.toggle_digit_grouping();
+        let mut reply = String::new();
+
+        let effect = execute(&mut store, CliCommand::CatSettings, &mut reply);
+
+        assert_eq!(effect, Effect::Noop);
+        for line in [
+            "word_timing WEIGHTED\n",
+            "min_dwell_ms 120\n",
+            "max_dwell_ms 2000\n",
+            "word_groups GROUPED\n",
+            "references KEEP\n",
+            "digit_groups ON\n",
+        ] {
+            assert!(reply.contains(line), "missing {line:?} in {reply}");
+        }
+    }
+
+    #[test]
+    fn set_wpm_persists_only_valid_speeds() {
+        let mut store = Store::new();
+        let mut reply = String::new();
+
+        let effect = execute(&mut store, CliCommand::SetWpm(310), &mut reply);
+        assert_eq!(effect, Effect::Noop);
+        assert!(reply.starts_with("wpm must be"));
+
+        reply.clear();
+        let effect = execute(&mut store, CliCommand::SetWpm(300), &mut reply);
+        assert_eq!(store.settings.reading_speed_wpm, 300);
+        assert_eq!(
+            effect,
+            Effect::PersistSettings(store.settings.to_persisted())
+        );
+        assert_eq!(reply, "wpm 300\n");
+    }
+}
@@ -0,0 +1,182 @@
+use crate::text::InlineText;
+
+// Wall-clock time of day. The platform publishes `Event::LocalTimeChanged` with the full date from
+// an I2C RTC when one is fitted; without one nothing sets the time yet and everything here stays
+// blank.
+
+pub const LOCAL_TIME_LABEL_MAX_BYTES: usize = 5;
+// Night mode's window. There is no calendar or location to look sunset up by, so it is fixed.
+pub const NIGHT_STARTS_MINUTE: u16 = 20 * 60;
+pub const NIGHT_ENDS_MINUTE: u16 = 7 * 60;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LocalTime {
+    hour: u8,
+    minute: u8,
+}
+
+impl LocalTime {
+    pub const fn new(hour: u8, minute: u8) -> Option<Self> {
+        if hour > 23 || minute > 59 {
+            return None;
+        }
+
+        Some(Self { hour, minute })
+    }
+
+    pub const fn hour(self) -> u8 {
+        self.hour
+    }
+
+    pub const fn minute(self) -> u8 {
+        self.minute
+    }
+
+    pub const fn minute_of_day(self) -> u16 {
+        self.hour as u16 * 60 + self.minute as u16
+    }
+
+    // The window wraps midnight, so it is "after the start or before the end".
+    pub const fn is_night(self) -> bool {
+        let minute = self.minute_of_day();
+        minute >= NIGHT_STARTS_MINUTE || minute < NIGHT_ENDS_MINUTE
+    }
+
+    pub fn label(self) -> InlineText<LOCAL_TIME_LABEL_MAX_BYTES> {
+        let mut label = InlineText::new();
+        for value in [self.hour, self.minute] {
+            if !label.is_empty() {
+                let _ = label.try_push_char(':');
+            }
+            let _ = label.try_push_char((b'0' + value / 10) as char);
+            let _ = label.try_push_char((b'0' + value % 10) as char);
+        }
+        label
+    }
+}
+
+// A calendar date and time as the RTC keeps it: local, with no zone, in the 2000 to 2099 range both
+// supported chips count in.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DateTime {
+    year: u16,
+    month: u8,
+    day: u8,
+    time: LocalTime,
+    second: u8,
+}
+
+impl DateTime {
+    pub const fn new(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Option<Self> {
+        if year < 2000 || year > 2099 || month == 0 || month > 12 || second > 59 {
+            return None;
+        }
+        if day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        let Some(time) = LocalTime::new(hour, minute) else {
+            return None;
+        };
+
+        Some(Self {
+            year,
+            month,
+            day,
+            time,
+            second,
+        })
+    }
+
+    pub const fn year(self) -> u16 {
+        self.year
+    }
+
+    pub const fn month(self) -> u8 {
+        self.month
+    }
+
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+
+    pub const fn second(self) -> u8 {
+        self.second
+    }
+
+    pub const fn local_time(self) -> LocalTime {
+        self.time
+    }
+
+    // Days since 2000-01-01, so two dates compare and subtract as plain numbers.
+    pub const fn day_number(self) -> u32 {
+        let years = (self.year - 2000) as u32;
+        // Leap years before this one, 2000 included.
+        let mut days = years * 365 + years.div_ceil(4);
+        let mut month = 1;
+        while month < self.month {
+            days += days_in_month(self.year, month) as u32;
+            month += 1;
+        }
+        days + self.day as u32 - 1
+    }
+}
+
+// Every fourth year is a leap year across 2000 to 2099, so the century rule never applies.
+const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn night_window_wraps_midnight_and_labels_pad() {
+        let evening = LocalTime::new(20, 0).unwrap();
+        let small_hours = LocalTime::new(6, 59).unwrap();
+        let morning = LocalTime::new(7, 0).unwrap();
+
+        assert!(evening.is_night());
+        assert!(small_hours.is_night());
+        assert!(!morning.is_night());
+        assert_eq!(small_hours.label().as_str(), "06:59");
+        assert_eq!(LocalTime::new(24, 0), None);
+    }
+
+    #[test]
+    fn date_time_rejects_days_past_the_month_end() {
+        let leap = DateTime::new(2028, 2, 29, 23, 59, 59).unwrap();
+
+        assert_eq!(leap.local_time().label().as_str(), "23:59");
+        assert_eq!(DateTime::new(2027, 2, 29, 0, 0, 0), None);
+        assert_eq!(DateTime::new(2026, 4, 31, 0, 0, 0), None);
+        assert_eq!(DateTime::new(2100, 1, 1, 0, 0, 0), None);
+    }
+
+    #[test]
+    fn day_numbers_run_on_across_month_and_leap_year_ends() {
+        let day = |year, month, day| {
+            DateTime::new(year, month, day, 12, 0, 0)
+                .unwrap()
+                .day_number()
+        };
+
+        assert_eq!(day(2000, 1, 1), 0);
+        assert_eq!(day(2001, 1, 1), 366);
+        assert_eq!(day(2028, 3, 1) - day(2028, 2, 28), 2);
+        assert_eq!(day(2027, 1, 1) - day(2026, 12, 31), 1);
+        assert_eq!(day(2005, 1, 1), 5 * 365 + 2);
+    }
+}
@@ -2,6 +2,7 @@ use alloc::boxed::Box;
 
 use crate::{source::SourceKind, text::InlineText};
 
+#[cfg(any(test, feature = "testing"))]
 pub const ARTICLE_COUNT_PER_COLLECTION: usize = 5;
 pub const MANIFEST_ITEM_CAPACITY: usize = 16;
 pub const PARAGRAPH_COUNT_PER_SCRIPT: usize = 23;
@@ -15,6 +16,9 @@ pub const RECOMMENDATION_SUBTOPIC_CAPACITY: usize = 8;
 pub const RECOMMENDATION_SUBTOPIC_SLUG_MAX_BYTES: usize = 32;
 pub const RECOMMENDATION_SUBTOPIC_LABEL_MAX_BYTES: usize = 24;
 pub const READING_PROGRESS_CAPACITY: usize = 64;
+pub const DICTIONARY_WORD_MAX_BYTES: usize = 32;
+pub const DICTIONARY_PART_OF_SPEECH_MAX_BYTES: usize = 16;
+pub const DICTIONARY_DEFINITION_MAX_BYTES: usize = 240;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub enum CollectionKind {
@@ -58,6 +62,8 @@ pub enum ReaderScript {
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct ArticleId(pub u16);
 
+// Canned dashboard rows for tests and fixtures. The device only ever lists manifest items.
+#[cfg(any(test, feature = "testing"))]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct ArticleSummary {
     pub id: ArticleId,
@@ -73,6 +79,7 @@ pub struct ArticleSummary {
     pub has_chat: bool,
 }
 
+#[cfg(any(test, feature = "testing"))]
 impl ArticleSummary {
     #[allow(clippy::too_many_arguments)]
     pub const fn new(
@@ -104,6 +111,7 @@ impl ArticleSummary {
     }
 }
 
+#[cfg(any(test, feature = "testing"))]
 impl Default for ArticleSummary {
     fn default() -> Self {
         Self::new(
@@ -281,6 +289,50 @@ impl CollectionManifestState {
 
         false
     }
+
+    pub fn remove_content_id(&mut self, content_id: &InlineText<CONTENT_ID_MAX_BYTES>) -> bool {
+        let len = self.len();
+        let Some(index) = (0..len).find(|index| self.items[*index].content_id == *content_id)
+        else {
+            return false;
+        };
+
+        self.items.copy_within(index + 1..len, index);
+        self.items[len - 1] = CollectionManifestItem::empty();
+        self.len = self.len.saturating_sub(1);
+        true
+    }
+
+    // Indices of the items whose title contains `query`, ignoring ASCII case, in list order.
+    pub fn search<'a>(&'a self, query: &'a str) -> impl Iterator<Item = usize> + 'a {
+        let query = query.as_bytes();
+        (0..self.len()).filter(move |index| {
+            let title = self.items[*index].title.as_str().as_bytes();
+            query.is_empty()
+                || title
+                    .windows(query.len())
+                    .any(|window| window.eq_ignore_ascii_case(query))
+        })
+    }
+
+    // Appends the items of another source that this collection does not list yet. Content ids are
+    // the stable identity across sources, so a book on the card and in flash shows up once, with
+    // this collection's entry winning.
+    pub fn merge_missing(&mut self, other: &CollectionManifestState) -> usize {
+        let mut merged = 0;
+        for index in 0..other.len() {
+            let item = other.items[index];
+            if self.contains_content_id(&item.content_id) {
+                continue;
+            }
+            if !self.try_push(item) {
+                break;
+            }
+            merged += 1;
+        }
+
+        merged
+    }
 }
 
 impl Default for CollectionManifestState {
@@ -548,6 +600,40 @@ impl ReaderSubscriptionToggleRequest {
     }
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DictionaryLookupRequest {
+    pub word: InlineText<DICTIONARY_WORD_MAX_BYTES>,
+}
+
+impl DictionaryLookupRequest {
+    pub const fn new(word: InlineText<DICTIONARY_WORD_MAX_BYTES>) -> Self {
+        Self { word }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DictionaryDefinition {
+    pub word: InlineText<DICTIONARY_WORD_MAX_BYTES>,
+    pub part_of_speech: InlineText<DICTIONARY_PART_OF_SPEECH_MAX_BYTES>,
+    pub definition: InlineText<DICTIONARY_DEFINITION_MAX_BYTES>,
+}
+
+impl DictionaryDefinition {
+    pub const fn empty() -> Self {
+        Self {
+            word: InlineText::new(),
+            part_of_speech: InlineText::new(),
+            definition: InlineText::new(),
+        }
+    }
+}
+
+impl Default for DictionaryDefinition {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct ReadingProgressEntry {
     pub content_id: InlineText<CONTENT_ID_MAX_BYTES>,
@@ -835,6 +921,7 @@ impl ContentState {
             .update_package_state(remote_item_id, package_state)
     }
 
+    #[cfg(any(test, feature = "testing"))]
     pub fn article_at(&self, kind: CollectionKind, index: usize) -> ArticleSummary {
         match kind {
             CollectionKind::Saved => SAVED_ARTICLES[index % ARTICLE_COUNT_PER_COLLECTION],
@@ -845,6 +932,7 @@ impl ContentState {
         }
     }
 
+    #[cfg(any(test, feature = "testing"))]
     pub fn article_by_id(&self, kind: CollectionKind, article_id: ArticleId) -> ArticleSummary {
         let collection = match kind {
             CollectionKind::Saved => &SAVED_ARTICLES,
@@ -1057,6 +1145,7 @@ pub fn script_paragraph(script: ReaderScript, index: usize) -> &'static str {
     }
 }
 
+#[cfg(any(test, feature = "testing"))]
 const SAVED_ARTICLES: [ArticleSummary; ARTICLE_COUNT_PER_COLLECTION] = [
     ArticleSummary::new(
         ArticleId(101),
@@ -1125,6 +1214,7 @@ const SAVED_ARTICLES: [ArticleSummary; ARTICLE_COUNT_PER_COLLECTION] = [
     ),
 ];
 
+#[cfg(any(test, feature = "testing"))]
 const INBOX_ARTICLES: [ArticleSummary; ARTICLE_COUNT_PER_COLLECTION] = [
     ArticleSummary::new(
         ArticleId(201),
@@ -1193,6 +1283,7 @@ const INBOX_ARTICLES: [ArticleSummary; ARTICLE_COUNT_PER_COLLECTION] = [
     ),
 ];
 
+#[cfg(any(test, feature = "testing"))]
 const RECOMMENDATION_ARTICLES: [ArticleSummary; ARTICLE_COUNT_PER_COLLECTION] = [
     ArticleSummary::new(
         ArticleId(301),
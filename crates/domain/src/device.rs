@@ -1,3 +1,5 @@
+pub const CRASH_REPORT_MAX_BYTES: usize = 160;
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub enum PairingState {
     #[default]
@@ -16,6 +18,7 @@ pub enum BootState {
 pub struct DeviceState {
     pub pairing: PairingState,
     pub boot: BootState,
+    pub boot_count: u32,
 }
 
 impl DeviceState {
@@ -23,6 +26,7 @@ impl DeviceState {
         Self {
             pairing: PairingState::Unpaired,
             boot: BootState::ColdBoot,
+            boot_count: 0,
         }
     }
 
@@ -30,6 +34,12 @@ impl DeviceState {
         Self {
             pairing: PairingState::Unpaired,
             boot,
+            boot_count: 0,
         }
     }
+
+    pub const fn with_boot_count(mut self, boot_count: u32) -> Self {
+        self.boot_count = boot_count;
+        self
+    }
 }
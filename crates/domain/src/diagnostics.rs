@@ -0,0 +1,22 @@
+use crate::perf::PerfCounters;
+
+// Numbers for debugging a device in the field without a serial cable. The platform samples them
+// while the hidden diagnostics screen is open; a value the board cannot measure stays zero and the
+// screen shows it as a dash.
+
+pub const DIAGNOSTICS_SAMPLE_INTERVAL_MS: u64 = 1_000;
+// Extra turns past the last settings row that open the screen.
+pub const DIAGNOSTICS_UNLOCK_TURNS: u8 = 5;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct DiagnosticsSnapshot {
+    pub firmware_version: &'static str,
+    pub heap_free_bytes: u32,
+    pub heap_min_free_bytes: u32,
+    // The allocator reports per-region totals, not fragments, so this is the roomiest region.
+    pub largest_free_region_bytes: u32,
+    pub stack_peak_bytes: u32,
+    pub stack_size_bytes: u32,
+    pub sd_clock_hz: u32,
+    pub perf: PerfCounters,
+}
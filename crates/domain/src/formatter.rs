@@ -1,5 +1,5 @@
 use alloc::alloc::{Layout, alloc_zeroed, handle_alloc_error};
-use alloc::boxed::Box;
+use alloc::{boxed::Box, string::String};
 
 use crate::{
     content::{ArticleDocument, ReaderScript, script_paragraph, script_paragraph_count},
@@ -21,6 +21,49 @@ const SENTENCE_PAUSE_NUMERATOR: u32 = 1;
 const SENTENCE_PAUSE_DENOMINATOR: u32 = 1;
 const PARAGRAPH_PAUSE_NUMERATOR: u32 = 3;
 const PARAGRAPH_PAUSE_DENOMINATOR: u32 = 2;
+const STAGE_SPLIT_MIN_CHARS: usize = 24;
+const MAX_STAGE_PARTS: usize = 3;
+const HYPHEN_MIN_FRAGMENT_CHARS: usize = 3;
+pub const DEFAULT_MIN_DWELL_MS: u16 = 80;
+pub const DEFAULT_MAX_DWELL_MS: u16 = 2_000;
+pub const MAX_GROUPED_FUNCTION_WORDS: usize = 2;
+// Acronyms run to five capitals (NASA, UNESCO is already a word); longer runs are shouted words.
+const MAX_ACRONYM_CAPITALS: u32 = 5;
+// Four-digit numbers are mostly years and stay as written.
+const DIGIT_GROUPING_MIN_DIGITS: usize = 5;
+pub const DEFAULT_TOKEN_DELAYS: TokenDelays = TokenDelays {
+    number_percent: 25,
+    acronym_percent: 20,
+    all_caps_percent: 15,
+};
+pub const DEFAULT_FUNCTION_WORDS: &[&str] = &[
+    "a", "an", "the", "of", "to", "in", "on", "at", "by", "for", "and", "or", "but", "nor", "as",
+    "is", "if", "it", "its", "my", "our", "his", "her", "from", "with", "into",
+];
+pub const DEFAULT_ABBREVIATIONS: &[&str] = &[
+    "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "mt", "vs", "etc", "e.g", "i.e", "cf", "al",
+    "inc", "ltd", "co", "corp", "vol", "fig", "approx", "dept", "est", "gen", "gov", "sen", "rep",
+    "rev", "jan", "feb", "mar", "apr", "jun", "jul", "aug", "sep", "sept", "oct", "nov", "dec",
+    "a.m", "p.m", "u.s", "u.k",
+];
+pub const DEFAULT_PAUSE_RULES: PauseRules<'static> = PauseRules {
+    sentence_marks: &['!', '?', '。', '！', '？'],
+    clause_marks: &[',', ';', ':', '—', '、', '，', '；', '：'],
+};
+// Spanish dialogue opens and closes with the em dash (raya), so it reads like a quote mark. The
+// inverted `¿` and `¡` lead the word and never pause.
+const SPANISH_PAUSE_RULES: PauseRules<'static> = PauseRules {
+    sentence_marks: &['!', '?'],
+    clause_marks: &[',', ';', ':'],
+};
+// Greek asks with `;` (or U+037E) and uses the raised dot as its semicolon.
+const GREEK_PAUSE_RULES: PauseRules<'static> = PauseRules {
+    sentence_marks: &['!', ';', '\u{037E}'],
+    clause_marks: &[',', ':', '·', '\u{0387}', '—'],
+};
+// Keyed by the primary subtag of the book's language tag; anything else reads with the default.
+pub const LANGUAGE_PAUSE_RULES: &[(&str, PauseRules<'static>)] =
+    &[("es", SPANISH_PAUSE_RULES), ("el", GREEK_PAUSE_RULES)];
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub enum StageFont {
@@ -30,12 +73,22 @@ pub enum StageFont {
     Small,
 }
 
+// The face a stage letter is drawn in; every stage size has all three rasterized.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum FontStyle {
+    #[default]
+    Regular,
+    Bold,
+    Italic,
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct UnitFlags {
     pub clause_pause: bool,
     pub sentence_pause: bool,
     pub paragraph_start: bool,
     pub paragraph_end: bool,
+    pub section_start: bool,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
@@ -62,11 +115,105 @@ pub struct ReadingDocument {
     pub paragraphs: [ParagraphAnchor; MAX_READING_PARAGRAPHS],
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum DwellMode {
+    #[default]
+    Standard,
+    Weighted,
+}
+
+// `Weighted` adds extra time for long, numeric, and mixed-case tokens on top of
+// the standard length and punctuation weights; `Standard` adds the flat
+// `token_delays` for the token's kind instead. Both modes clamp to the bounds.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DwellTiming {
+    pub mode: DwellMode,
+    pub min_ms: u16,
+    pub max_ms: u16,
+    pub token_delays: TokenDelays,
+    // Fixed pause lengths for sentence and clause ends; 0 keeps the built-in weights, which scale
+    // with the reading speed.
+    pub sentence_pause_ms: u16,
+    pub clause_pause_ms: u16,
+}
+
+// The tokens readers most often miss at speed. A lead of function words does not change the
+// kind; the word after it decides.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum TokenKind {
+    #[default]
+    Word,
+    Number,
+    Acronym,
+    AllCaps,
+}
+
+// Extra time per token kind, as a percent of the base word time.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TokenDelays {
+    pub number_percent: u8,
+    pub acronym_percent: u8,
+    pub all_caps_percent: u8,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum ChunkingMode {
+    #[default]
+    SingleWord,
+    FunctionWords,
+}
+
+// The reader's own text options from settings; the package parser lays them over the book's
+// language rules.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct FormatPreferences {
+    pub chunking: ChunkingMode,
+    pub references: ReferenceMode,
+    pub digit_grouping: bool,
+}
+
+// What happens to bracketed reference markers (`[12]`, `[3-5]`, `[a]`) in running text. `Skip`
+// drops them, and drops whole paragraphs that are only footnote text (a marker, then the note).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum ReferenceMode {
+    Keep,
+    #[default]
+    Skip,
+}
+
+// Trailing marks that pause the reader. Full stops and ellipses are not listed: every language
+// sends them through the abbreviation and lowercase-continuation checks.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PauseRules<'a> {
+    pub sentence_marks: &'a [char],
+    pub clause_marks: &'a [char],
+}
+
+// Periods after `abbreviations` (compared case-insensitively, without the final
+// period) do not end a sentence. With `ChunkingMode::FunctionWords`, up to two
+// leading `function_words` flash together with the word that follows them.
+// `digit_grouping` shows long whole numbers in groups of three (`1 000 000`).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct FormatRules<'a> {
+    pub abbreviations: &'a [&'a str],
+    pub function_words: &'a [&'a str],
+    pub chunking: ChunkingMode,
+    pub references: ReferenceMode,
+    pub pauses: PauseRules<'a>,
+    pub digit_grouping: bool,
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct StageToken {
     pub left: InlineText<MAX_STAGE_SEGMENT_BYTES>,
     pub right: InlineText<MAX_STAGE_SEGMENT_BYTES>,
     pub font: StageFont,
+    // Character index where the token may continue on a second line when even the small stage
+    // face is too wide for it; 0 when it has no such break.
+    pub wrap_index: u8,
+    // Hebrew or Arabic: `left` and `right` stay in reading order around the anchor, and the
+    // renderer mirrors them.
+    pub rtl: bool,
 }
 
 impl ReadingDocument {
@@ -113,14 +260,57 @@ impl ReadingDocument {
     }
 
     pub fn push_paragraph_text(&mut self, paragraph: &str) -> bool {
+        self.push_paragraph_text_with_rules(paragraph, &FormatRules::default())
+    }
+
+    pub fn push_heading_text(&mut self, heading: &str) -> bool {
+        self.push_heading_text_with_rules(heading, &FormatRules::default())
+    }
+
+    // Headings open a new section; the reader can stop on their first unit.
+    pub fn push_heading_text_with_rules(&mut self, heading: &str, rules: &FormatRules<'_>) -> bool {
+        let start = self.unit_count as usize;
+        let complete = self.push_paragraph_text_with_rules(heading, rules);
+        if start < self.unit_count as usize {
+            self.units[start].flags.section_start = true;
+        }
+
+        complete
+    }
+
+    pub fn push_paragraph_text_with_rules(
+        &mut self,
+        paragraph: &str,
+        rules: &FormatRules<'_>,
+    ) -> bool {
         if paragraph.is_empty() || self.paragraph_count as usize >= MAX_READING_PARAGRAPHS {
             return false;
         }
 
-        self.push_paragraph(self.paragraph_count.saturating_add(1), paragraph)
+        if rules.references == ReferenceMode::Skip {
+            if is_footnote_text(paragraph) {
+                return true;
+            }
+            if let Some(stripped) = strip_references(paragraph) {
+                let stripped = stripped.trim();
+                return stripped.is_empty()
+                    || self.push_paragraph(
+                        self.paragraph_count.saturating_add(1),
+                        stripped,
+                        rules,
+                    );
+            }
+        }
+
+        self.push_paragraph(self.paragraph_count.saturating_add(1), paragraph, rules)
     }
 
-    fn push_paragraph(&mut self, paragraph_index: u8, paragraph: &str) -> bool {
+    fn push_paragraph(
+        &mut self,
+        paragraph_index: u8,
+        paragraph: &str,
+        rules: &FormatRules<'_>,
+    ) -> bool {
         if paragraph_index as usize > MAX_READING_PARAGRAPHS
             || self.unit_count as usize >= MAX_READING_UNITS
         {
@@ -137,18 +327,59 @@ impl ReadingDocument {
         let mut index = 0usize;
         let mut first_unit = true;
         let mut complete = true;
+        let mut lead = InlineText::<MAX_READING_TOKEN_BYTES>::new();
+        let mut lead_words = 0usize;
 
         while let Some((start, end)) = next_chunk_bounds(paragraph, index) {
             index = end;
             let chunk = &paragraph[start..end];
+            let next_chunk = next_chunk_bounds(paragraph, end)
+                .map(|(next_start, next_end)| &paragraph[next_start..next_end]);
+            let continues_lowercase = next_chunk.is_some_and(starts_lowercase);
 
             if !contains_word_content(chunk) {
-                self.attach_standalone_punctuation(chunk);
+                if !lead.is_empty() {
+                    self.push_chunk(paragraph_index, "", lead.as_str(), first_unit, rules, false);
+                    first_unit = false;
+                    lead.clear();
+                    lead_words = 0;
+                }
+                self.attach_standalone_punctuation(chunk, rules, continues_lowercase);
+                continue;
+            }
+
+            if rules.chunking == ChunkingMode::FunctionWords
+                && lead_words < MAX_GROUPED_FUNCTION_WORDS
+                && is_function_word(chunk, rules)
+                && next_chunk.is_some_and(contains_word_content)
+                && fits_grouped(lead.as_str(), chunk)
+            {
+                if !lead.is_empty() {
+                    let _ = lead.try_push_char(' ');
+                }
+                let _ = lead.try_push_str(chunk);
+                lead_words += 1;
                 continue;
             }
 
-            self.push_chunk(paragraph_index, chunk, first_unit);
+            if !lead.is_empty() && !fits_grouped(lead.as_str(), chunk) {
+                self.push_chunk(paragraph_index, "", lead.as_str(), first_unit, rules, false);
+                first_unit = false;
+                lead.clear();
+            }
+
+            let grouped = rules.digit_grouping.then(|| group_digits(chunk)).flatten();
+            self.push_chunk(
+                paragraph_index,
+                lead.as_str(),
+                grouped.as_ref().map_or(chunk, InlineText::as_str),
+                first_unit,
+                rules,
+                continues_lowercase,
+            );
             first_unit = false;
+            lead.clear();
+            lead_words = 0;
 
             if self.unit_count as usize >= MAX_READING_UNITS {
                 complete = false;
@@ -156,6 +387,10 @@ impl ReadingDocument {
             }
         }
 
+        if !lead.is_empty() && (self.unit_count as usize) < MAX_READING_UNITS {
+            self.push_chunk(paragraph_index, "", lead.as_str(), first_unit, rules, false);
+        }
+
         if self.unit_count > 0 {
             let last_index = self.unit_count as usize - 1;
             self.units[last_index].flags.paragraph_end = true;
@@ -164,18 +399,34 @@ impl ReadingDocument {
         complete && index >= paragraph.len()
     }
 
-    fn attach_standalone_punctuation(&mut self, chunk: &str) {
+    // Punctuation set off by a space (French `mot ;`, `vraiment ?`) pauses on the word before it.
+    fn attach_standalone_punctuation(
+        &mut self,
+        chunk: &str,
+        rules: &FormatRules<'_>,
+        continues_lowercase: bool,
+    ) {
         if self.unit_count == 0 {
             return;
         }
 
         let last_index = self.unit_count as usize - 1;
-        let flags = classify_trailing_punctuation(chunk, false);
+        let flags = classify_marks(chunk, false, &rules.pauses, continues_lowercase);
         self.units[last_index].flags.clause_pause |= flags.clause_pause;
         self.units[last_index].flags.sentence_pause |= flags.sentence_pause;
     }
 
-    fn push_chunk(&mut self, paragraph_index: u8, chunk: &str, paragraph_start: bool) {
+    // `lead` holds grouped function words shown before the first segment of
+    // `chunk`; the anchor stays on the content word.
+    fn push_chunk(
+        &mut self,
+        paragraph_index: u8,
+        lead: &str,
+        chunk: &str,
+        paragraph_start: bool,
+        rules: &FormatRules<'_>,
+        continues_lowercase: bool,
+    ) {
         let segments = split_for_stage(chunk);
         let mut segment_index = 0usize;
 
@@ -186,15 +437,33 @@ impl ReadingDocument {
                 continue;
             }
             let core = lexical_core(segment);
-            let display = InlineText::from_slice(segment);
+            let lead = if segment_index == 0 { lead } else { "" };
+            let mut display = InlineText::from_slice(lead);
+            if !lead.is_empty() {
+                let _ = display.try_push_char(' ');
+            }
+            let lead_chars = display.char_count() as u8;
+            let _ = display.try_push_str(segment);
+            let continues = segments[segment_index + 1..]
+                .iter()
+                .any(|next| !next.is_empty());
+            if continues && !segment.ends_with('-') {
+                let _ = display.try_push_char('-');
+            }
             let char_count = display.char_count().min(u8::MAX as usize) as u8;
             let leading_chars = segment[..core.start].chars().count() as u8;
             let core_chars = core.text.chars().count();
-            let anchor = leading_chars
+            let anchor = lead_chars
+                .saturating_add(leading_chars)
                 .saturating_add(preferred_anchor(core_chars) as u8)
                 .min(char_count.saturating_sub(1));
-            let mut flags = if segment_index + 1 == segments.len() {
-                classify_trailing_punctuation(segment, looks_like_abbreviation(segment))
+            let mut flags = if !continues {
+                classify_trailing_punctuation(
+                    segment,
+                    looks_like_abbreviation(segment, rules),
+                    &rules.pauses,
+                    continues_lowercase,
+                )
             } else {
                 UnitFlags::default()
             };
@@ -243,6 +512,7 @@ impl UnitFlags {
             sentence_pause: false,
             paragraph_start: false,
             paragraph_end: false,
+            section_start: false,
         }
     }
 }
@@ -269,8 +539,97 @@ impl ParagraphAnchor {
     }
 }
 
+impl DwellMode {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Standard => "STANDARD",
+            Self::Weighted => "WEIGHTED",
+        }
+    }
+
+    pub const fn to_byte(self) -> u8 {
+        match self {
+            Self::Standard => 0,
+            Self::Weighted => 1,
+        }
+    }
+
+    pub const fn from_byte(value: u8) -> Self {
+        match value {
+            1 => Self::Weighted,
+            _ => Self::Standard,
+        }
+    }
+}
+
+impl DwellTiming {
+    pub const fn new(mode: DwellMode) -> Self {
+        Self {
+            mode,
+            min_ms: DEFAULT_MIN_DWELL_MS,
+            max_ms: DEFAULT_MAX_DWELL_MS,
+            token_delays: DEFAULT_TOKEN_DELAYS,
+            sentence_pause_ms: 0,
+            clause_pause_ms: 0,
+        }
+    }
+}
+
+impl Default for DwellTiming {
+    fn default() -> Self {
+        Self::new(DwellMode::Standard)
+    }
+}
+
+impl Default for FormatRules<'_> {
+    fn default() -> Self {
+        Self {
+            abbreviations: DEFAULT_ABBREVIATIONS,
+            function_words: DEFAULT_FUNCTION_WORDS,
+            chunking: ChunkingMode::SingleWord,
+            references: ReferenceMode::Skip,
+            pauses: DEFAULT_PAUSE_RULES,
+            digit_grouping: false,
+        }
+    }
+}
+
+impl FormatRules<'static> {
+    // `language` is a BCP 47 tag as books declare it ("es", "fr-CA", "EL"); an empty or unknown
+    // tag gives the default rules.
+    pub fn for_language(language: &str) -> Self {
+        Self {
+            pauses: PauseRules::for_language(language),
+            ..Self::default()
+        }
+    }
+}
+
+impl FormatRules<'_> {
+    pub const fn with_preferences(mut self, preferences: FormatPreferences) -> Self {
+        self.chunking = preferences.chunking;
+        self.references = preferences.references;
+        self.digit_grouping = preferences.digit_grouping;
+        self
+    }
+}
+
+impl PauseRules<'static> {
+    pub fn for_language(language: &str) -> Self {
+        let primary = language.split(['-', '_']).next().unwrap_or_default();
+        LANGUAGE_PAUSE_RULES
+            .iter()
+            .find(|(tag, _)| tag.eq_ignore_ascii_case(primary))
+            .map_or(DEFAULT_PAUSE_RULES, |(_, rules)| *rules)
+    }
+}
+
 impl ReadingUnit {
     pub fn dwell_ms(&self, wpm: u16) -> u32 {
+        self.paced_dwell_ms(wpm, 0, 0)
+    }
+
+    fn paced_dwell_ms(&self, wpm: u16, sentence_pause_ms: u16, clause_pause_ms: u16) -> u32 {
         let base = 60_000u32 / wpm.max(1) as u32;
         let length_bonus = match self.char_count {
             0..=3 => 0,
@@ -280,15 +639,19 @@ impl ReadingUnit {
             11..=14 => base / 2,
             _ => (base * 3) / 4,
         };
-        let clause_bonus = if self.flags.clause_pause {
-            scaled_bonus(base, CLAUSE_PAUSE_NUMERATOR, CLAUSE_PAUSE_DENOMINATOR)
-        } else {
+        let clause_bonus = if !self.flags.clause_pause {
             0
-        };
-        let sentence_bonus = if self.flags.sentence_pause {
-            scaled_bonus(base, SENTENCE_PAUSE_NUMERATOR, SENTENCE_PAUSE_DENOMINATOR)
+        } else if clause_pause_ms > 0 {
+            clause_pause_ms as u32
         } else {
+            scaled_bonus(base, CLAUSE_PAUSE_NUMERATOR, CLAUSE_PAUSE_DENOMINATOR)
+        };
+        let sentence_bonus = if !self.flags.sentence_pause {
             0
+        } else if sentence_pause_ms > 0 {
+            sentence_pause_ms as u32
+        } else {
+            scaled_bonus(base, SENTENCE_PAUSE_NUMERATOR, SENTENCE_PAUSE_DENOMINATOR)
         };
         let paragraph_bonus = if self.flags.paragraph_end {
             scaled_bonus(base, PARAGRAPH_PAUSE_NUMERATOR, PARAGRAPH_PAUSE_DENOMINATOR)
@@ -299,6 +662,86 @@ impl ReadingUnit {
         base + length_bonus + clause_bonus + sentence_bonus + paragraph_bonus
     }
 
+    pub fn dwell_ms_with(&self, wpm: u16, timing: &DwellTiming) -> u32 {
+        let dwell = self.paced_dwell_ms(wpm, timing.sentence_pause_ms, timing.clause_pause_ms)
+            + match timing.mode {
+                DwellMode::Standard => self.token_delay(wpm, &timing.token_delays),
+                DwellMode::Weighted => self.complexity_bonus(wpm),
+            };
+
+        dwell.clamp(
+            timing.min_ms as u32,
+            timing.max_ms.max(timing.min_ms) as u32,
+        )
+    }
+
+    fn token_delay(&self, wpm: u16, delays: &TokenDelays) -> u32 {
+        let percent = match self.token_kind() {
+            TokenKind::Word => return 0,
+            TokenKind::Number => delays.number_percent,
+            TokenKind::Acronym => delays.acronym_percent,
+            TokenKind::AllCaps => delays.all_caps_percent,
+        };
+        (60_000u32 / wpm.max(1) as u32) * percent as u32 / 100
+    }
+
+    pub fn token_kind(&self) -> TokenKind {
+        let display = self.display.as_str();
+        let word = lexical_core(display.rsplit(' ').next().unwrap_or(display)).text;
+        let mut digits = 0u32;
+        let mut capitals = 0u32;
+        let mut lowercase = 0u32;
+        for ch in word.chars() {
+            if ch.is_numeric() {
+                digits += 1;
+            } else if ch.is_uppercase() {
+                capitals += 1;
+            } else if ch.is_lowercase() {
+                lowercase += 1;
+            }
+        }
+
+        // "3rd", "10km" and "5G" still read as numbers; "MP3" is an acronym.
+        if digits > 0 && capitals < 2 && lowercase <= 2 {
+            return TokenKind::Number;
+        }
+        // A plural "s" keeps an acronym one ("CDs", "URLs").
+        let plural = lowercase == 1 && word.ends_with('s');
+        if capitals < 2 || (lowercase > 0 && !plural) {
+            return TokenKind::Word;
+        }
+        if capitals <= MAX_ACRONYM_CAPITALS || digits > 0 {
+            TokenKind::Acronym
+        } else {
+            TokenKind::AllCaps
+        }
+    }
+
+    fn complexity_bonus(&self, wpm: u16) -> u32 {
+        let base = 60_000u32 / wpm.max(1) as u32;
+        let core = lexical_core(self.display.as_str()).text;
+        let mut digits = 0u32;
+        let mut capitals = 0u32;
+
+        for ch in core.chars() {
+            if ch.is_ascii_digit() {
+                digits += 1;
+            } else if ch.is_uppercase() {
+                capitals += 1;
+            }
+        }
+
+        let length_bonus = (self.char_count.saturating_sub(8) as u32 * base) / 20;
+        let digit_bonus = if digits > 0 {
+            base / 4 + (digits.min(8) * base) / 20
+        } else {
+            0
+        };
+        let capital_bonus = if capitals >= 2 { base / 5 } else { 0 };
+
+        length_bonus + digit_bonus + capital_bonus
+    }
+
     pub fn stage_token(&self) -> StageToken {
         let mut token = StageToken {
             font: self.font,
@@ -308,8 +751,46 @@ impl ReadingUnit {
         let split_byte = byte_index_for_char(self.display.as_str(), self.anchor_index as usize);
         token.left = InlineText::from_slice(&self.display.as_str()[..split_byte]);
         token.right = InlineText::from_slice(&self.display.as_str()[split_byte..]);
+        if self.font == StageFont::Small {
+            token.wrap_index = stage_wrap_index(self.display.as_str());
+        }
+        token.rtl = is_rtl_text(self.display.as_str());
         token
     }
+
+    // With `anchor_percent` above 0 the anchor sits that far into the word's letters instead of
+    // at the length-based default; a function-word lead and surrounding punctuation are skipped.
+    pub fn stage_token_at(&self, anchor_percent: u8) -> StageToken {
+        if anchor_percent == 0 {
+            return self.stage_token();
+        }
+
+        let display = self.display.as_str();
+        let word_start = display.rfind(' ').map_or(0, |space| space + 1);
+        let core = lexical_core(&display[word_start..]);
+        let core_chars = core.text.chars().count();
+        if core_chars == 0 {
+            return self.stage_token();
+        }
+
+        let offset = (core_chars * anchor_percent.min(100) as usize / 100).min(core_chars - 1);
+        let anchor = display[..word_start + core.start].chars().count() + offset;
+        ReadingUnit {
+            anchor_index: anchor.min(u8::MAX as usize) as u8,
+            ..*self
+        }
+        .stage_token()
+    }
+
+    pub fn lookup_word(&self) -> InlineText<MAX_READING_TOKEN_BYTES> {
+        let display = self.display.as_str();
+        if !contains_word_content(display) {
+            return InlineText::new();
+        }
+
+        let word = display.rsplit(' ').next().unwrap_or(display);
+        InlineText::from_slice(lexical_core(word).text)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -327,6 +808,7 @@ pub fn format_article_document(article: &ArticleDocument) -> ReadingDocument {
         document.push_paragraph(
             (paragraph_index + 1) as u8,
             script_paragraph(article.script, paragraph_index),
+            &FormatRules::default(),
         );
         paragraph_index += 1;
     }
@@ -361,6 +843,60 @@ pub fn article_document_from_script(
     ArticleDocument::new(source, script)
 }
 
+// Digits with list and range separators, or one or two lowercase letters for lettered notes.
+// Longer words stay, so editorial brackets such as `[sic]` or `[the author]` read as written.
+fn is_reference_marker(inner: &str) -> bool {
+    let numeric = inner.len() <= 16
+        && inner.chars().any(|ch| ch.is_ascii_digit())
+        && inner
+            .chars()
+            .all(|ch| ch.is_ascii_digit() || matches!(ch, ',' | ' ' | '-' | '\u{2013}'));
+    let lettered =
+        (1..=2).contains(&inner.len()) && inner.chars().all(|ch| ch.is_ascii_lowercase());
+    numeric || lettered
+}
+
+fn is_footnote_text(paragraph: &str) -> bool {
+    paragraph
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+        .is_some_and(|(inner, note)| is_reference_marker(inner) && !note.trim().is_empty())
+}
+
+// `None` when the paragraph has no marker, so the common case does not copy it. A space left in
+// front of a removed marker goes with it unless a word follows.
+fn strip_references(paragraph: &str) -> Option<String> {
+    let mut stripped: Option<String> = None;
+    let mut copied = 0usize;
+    let mut search = 0usize;
+
+    while let Some(open) = paragraph[search..].find('[').map(|offset| search + offset) {
+        let Some(close) = paragraph[open..].find(']').map(|offset| open + offset) else {
+            break;
+        };
+        search = close + 1;
+        if !is_reference_marker(&paragraph[open + 1..close]) {
+            continue;
+        }
+
+        let out = stripped.get_or_insert_with(|| String::with_capacity(paragraph.len()));
+        out.push_str(&paragraph[copied..open]);
+        let followed_by_word = paragraph[close + 1..]
+            .chars()
+            .next()
+            .is_some_and(char::is_alphanumeric);
+        if !followed_by_word {
+            let kept = out.trim_end().len();
+            out.truncate(kept);
+        }
+        copied = close + 1;
+    }
+
+    let mut out = stripped?;
+    out.push_str(&paragraph[copied..]);
+    Some(out)
+}
+
 fn preview_excerpt(paragraph: &str) -> InlineText<MAX_PARAGRAPH_PREVIEW_BYTES> {
     let mut preview = InlineText::new();
     let mut last_was_space = false;
@@ -384,39 +920,246 @@ fn preview_excerpt(paragraph: &str) -> InlineText<MAX_PARAGRAPH_PREVIEW_BYTES> {
 }
 
 fn next_chunk_bounds(text: &str, start: usize) -> Option<(usize, usize)> {
-    let bytes = text.as_bytes();
-    let mut head = start;
+    let head = start + text[start..].find(|ch: char| !is_breaking_space(ch))?;
+    let mut tail = head;
+    let mut previous: Option<char> = None;
 
-    while head < bytes.len() && bytes[head].is_ascii_whitespace() {
-        head += 1;
+    for ch in text[head..].chars() {
+        if is_breaking_space(ch) {
+            break;
+        }
+        if let Some(previous) = previous
+            && starts_new_chunk(previous, ch)
+        {
+            break;
+        }
+
+        tail += ch.len_utf8();
+        previous = Some(ch);
     }
 
-    if head >= bytes.len() {
-        return None;
+    Some((head, tail))
+}
+
+// Non-breaking spaces glue figures to their units ("10 km"), so they stay
+// inside one unit instead of splitting it like ordinary whitespace.
+fn is_breaking_space(ch: char) -> bool {
+    ch.is_whitespace() && !matches!(ch, '\u{00A0}' | '\u{2007}' | '\u{202F}')
+}
+
+fn starts_new_chunk(previous: char, next: char) -> bool {
+    if is_cjk_ideograph(next) || is_cjk_opening_punctuation(next) {
+        return !is_cjk_opening_punctuation(previous);
+    }
+    if is_cjk_ideograph(previous) {
+        return !is_cjk_punctuation(next);
     }
 
-    let mut tail = head;
-    while tail < bytes.len() && !bytes[tail].is_ascii_whitespace() {
-        tail += 1;
+    previous == '—' && next.is_alphanumeric()
+}
+
+// Scripts written without spaces read one character per unit; Hangul uses
+// spaces between words, so it keeps the normal whitespace tokenization.
+fn is_cjk_ideograph(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{3040}'..='\u{30FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{2FA1F}'
+    )
+}
+
+fn is_cjk_punctuation(ch: char) -> bool {
+    matches!(ch, '\u{3001}'..='\u{303F}' | '\u{FF01}'..='\u{FF0F}' | '\u{FF1A}'..='\u{FF20}')
+        && !is_cjk_opening_punctuation(ch)
+}
+
+fn is_cjk_opening_punctuation(ch: char) -> bool {
+    matches!(
+        ch,
+        '「' | '『' | '（' | '《' | '〈' | '【' | '〔' | '〖' | '〘' | '〚'
+    )
+}
+
+fn split_for_stage(chunk: &str) -> [&str; MAX_STAGE_PARTS] {
+    let mut parts = [""; MAX_STAGE_PARTS];
+    let mut rest = chunk;
+    let mut part_index = 0usize;
+
+    while part_index + 1 < MAX_STAGE_PARTS {
+        let char_count = rest.chars().count();
+        let parts_needed = stage_parts_needed(rest, char_count).min(MAX_STAGE_PARTS - part_index);
+        if parts_needed <= 1 {
+            break;
+        }
+
+        let existing_hyphen = if parts_needed == 2 {
+            hyphen_split_index(rest)
+        } else {
+            None
+        };
+        let Some(split_byte) =
+            existing_hyphen.or_else(|| syllable_split_index(rest, char_count / parts_needed))
+        else {
+            break;
+        };
+
+        parts[part_index] = &rest[..split_byte];
+        rest = &rest[split_byte..];
+        part_index += 1;
     }
 
-    Some((head, tail))
+    parts[part_index] = rest;
+    parts
+}
+
+// Where a token too wide for the stage breaks onto a second line: after a function-word lead,
+// at a hyphen, or else at the syllable boundary nearest the middle.
+fn stage_wrap_index(display: &str) -> u8 {
+    let char_count = display.chars().count();
+    let split_byte = display
+        .rfind(' ')
+        .map(|space| space + 1)
+        .or_else(|| hyphen_split_index(display))
+        .or_else(|| syllable_split_index(display, char_count / 2));
+
+    split_byte.map_or(0, |byte| {
+        display[..byte].chars().count().min(u8::MAX as usize) as u8
+    })
 }
 
-fn split_for_stage(chunk: &str) -> [&str; 2] {
-    let mut parts = [chunk, ""];
+// A word needs splitting when it is wider than the stage or when it would not
+// fit in a reading unit once a trailing hyphen is added.
+fn stage_parts_needed(chunk: &str, char_count: usize) -> usize {
+    let by_chars = if char_count > STAGE_SPLIT_MIN_CHARS {
+        char_count.div_ceil(STAGE_SPLIT_MIN_CHARS)
+    } else {
+        1
+    };
+    let by_bytes = chunk.len().div_ceil(MAX_READING_TOKEN_BYTES - 1);
+
+    by_chars.max(by_bytes)
+}
+
+// Breaks a long word near `target_chars`, preferring vowel-consonant-vowel
+// (ex-am-ple) and consonant-consonant (hap-pen) syllable boundaries and never
+// leaving fewer than three letters on either side.
+fn syllable_split_index(chunk: &str, target_chars: usize) -> Option<usize> {
     let char_count = chunk.chars().count();
+    if char_count < HYPHEN_MIN_FRAGMENT_CHARS * 2 {
+        return None;
+    }
 
-    if char_count <= 24 {
-        return parts;
+    let mut best: Option<(usize, usize)> = None;
+    let mut fallback = None;
+    let mut before_previous: Option<char> = None;
+    let mut previous: Option<char> = None;
+    let mut chars = chunk.char_indices().enumerate().peekable();
+
+    while let Some((char_index, (byte_index, ch))) = chars.next() {
+        let next = chars.peek().map(|(_, (_, next))| *next);
+        let in_range = char_index >= HYPHEN_MIN_FRAGMENT_CHARS
+            && char_count - char_index >= HYPHEN_MIN_FRAGMENT_CHARS;
+
+        if in_range {
+            if char_index == target_chars.max(HYPHEN_MIN_FRAGMENT_CHARS) {
+                fallback = Some(byte_index);
+            }
+            if let Some(previous) = previous
+                && is_syllable_boundary(before_previous, previous, ch, next)
+            {
+                let distance = char_index.abs_diff(target_chars);
+                let score = distance.saturating_sub(pattern_break_bonus(chunk, byte_index));
+                if best.is_none_or(|(best_score, _)| score < best_score) {
+                    best = Some((score, byte_index));
+                }
+            }
+        }
+
+        before_previous = previous;
+        previous = Some(ch);
     }
 
-    if let Some(split_byte) = hyphen_split_index(chunk) {
-        parts[0] = &chunk[..split_byte];
-        parts[1] = &chunk[split_byte..];
+    match best {
+        Some((score, byte_index)) if score <= target_chars / 2 => Some(byte_index),
+        _ => fallback.or(best.map(|(_, byte_index)| byte_index)),
     }
+}
 
-    parts
+fn is_syllable_boundary(
+    before_previous: Option<char>,
+    previous: char,
+    current: char,
+    next: Option<char>,
+) -> bool {
+    if !previous.is_alphabetic() || !current.is_alphabetic() {
+        return false;
+    }
+
+    let next_is_vowel = next.is_some_and(is_vowel);
+    if is_vowel(previous) {
+        return !is_vowel(current) && next_is_vowel;
+    }
+
+    !is_vowel(current)
+        && before_previous.is_some_and(is_vowel)
+        && (next_is_vowel || previous == current)
+}
+
+fn is_vowel(ch: char) -> bool {
+    matches!(
+        ch.to_lowercase().next().unwrap_or(ch),
+        'a' | 'e'
+            | 'i'
+            | 'o'
+            | 'u'
+            | 'y'
+            | 'á'
+            | 'à'
+            | 'â'
+            | 'ä'
+            | 'é'
+            | 'è'
+            | 'ê'
+            | 'ë'
+            | 'í'
+            | 'î'
+            | 'ï'
+            | 'ó'
+            | 'ô'
+            | 'ö'
+            | 'ú'
+            | 'û'
+            | 'ü'
+    )
+}
+
+#[cfg(feature = "hyphenation-patterns")]
+fn pattern_break_bonus(chunk: &str, byte_index: usize) -> usize {
+    const PREFIXES: &[&str] = &[
+        "counter", "inter", "trans", "under", "super", "multi", "over", "anti", "post", "pre",
+    ];
+    const SUFFIXES: &[&str] = &[
+        "ization", "ation", "ment", "ness", "able", "ible", "tion", "sion", "ship", "less", "ful",
+        "ing",
+    ];
+
+    let (head, tail) = chunk.split_at(byte_index);
+    let prefix = PREFIXES
+        .iter()
+        .any(|prefix| head.eq_ignore_ascii_case(prefix));
+    let suffix = SUFFIXES.iter().any(|suffix| {
+        tail.len() >= suffix.len() && tail[..suffix.len()].eq_ignore_ascii_case(suffix)
+    });
+
+    if prefix || suffix { 4 } else { 0 }
+}
+
+#[cfg(not(feature = "hyphenation-patterns"))]
+fn pattern_break_bonus(_chunk: &str, _byte_index: usize) -> usize {
+    0
 }
 
 fn hyphen_split_index(chunk: &str) -> Option<usize> {
@@ -463,34 +1206,136 @@ fn lexical_core(chunk: &str) -> CoreBounds<'_> {
     }
 }
 
+// Splits a long whole number into groups of three with no-break spaces, keeping any sign,
+// decimals, or trailing punctuation. Numbers already written with separators stay as they are.
+fn group_digits(chunk: &str) -> Option<InlineText<MAX_READING_TOKEN_BYTES>> {
+    let core = lexical_core(chunk);
+    let integer_len = core
+        .text
+        .bytes()
+        .position(|byte| !byte.is_ascii_digit())
+        .unwrap_or(core.text.len());
+    let fraction = &core.text[integer_len..];
+    let decimal = fraction.len() > 1
+        && fraction.starts_with(['.', ','])
+        && fraction[1..].bytes().all(|byte| byte.is_ascii_digit());
+    if integer_len < DIGIT_GROUPING_MIN_DIGITS || !(fraction.is_empty() || decimal) {
+        return None;
+    }
+
+    let mut grouped = InlineText::from_slice(&chunk[..core.start]);
+    for (index, digit) in core.text[..integer_len].chars().enumerate() {
+        if index > 0 && (integer_len - index) % 3 == 0 && !grouped.try_push_char('\u{00A0}') {
+            return None;
+        }
+        if !grouped.try_push_char(digit) {
+            return None;
+        }
+    }
+    grouped
+        .try_push_str(&chunk[core.start + integer_len..])
+        .then_some(grouped)
+}
+
+// Direction of the first strong letter, as the Unicode bidi algorithm picks a paragraph's level.
+// Digits and punctuation are neutral, so a bare number reads left to right.
+pub fn is_rtl_text(text: &str) -> bool {
+    text.chars()
+        .find(|ch| ch.is_alphabetic())
+        .is_some_and(|ch| {
+            matches!(
+                ch as u32,
+                0x0590..=0x08FF | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFF
+            )
+        })
+}
+
 fn contains_word_content(chunk: &str) -> bool {
     chunk.chars().any(|ch| ch.is_alphanumeric())
 }
 
-fn classify_trailing_punctuation(chunk: &str, abbreviation: bool) -> UnitFlags {
-    let mut flags = UnitFlags::default();
+// A full stop or ellipsis followed by a lowercase word is read as a clause
+// break rather than the end of a sentence ("approx. five", "wait... no").
+fn classify_trailing_punctuation(
+    chunk: &str,
+    abbreviation: bool,
+    pauses: &PauseRules<'_>,
+    continues_lowercase: bool,
+) -> UnitFlags {
     let core = lexical_core(chunk);
     let trailing = &chunk[core.start + core.text.len()..];
+    classify_marks(trailing, abbreviation, pauses, continues_lowercase)
+}
+
+fn classify_marks(
+    marks: &str,
+    abbreviation: bool,
+    pauses: &PauseRules<'_>,
+    continues_lowercase: bool,
+) -> UnitFlags {
+    let mut flags = UnitFlags::default();
 
-    if trailing.contains(['!', '?']) {
+    if marks.contains(pauses.sentence_marks) {
         flags.sentence_pause = true;
         return flags;
     }
 
-    if trailing.contains('.') && !abbreviation {
-        flags.sentence_pause = true;
+    if marks.contains(['.', '…']) && !abbreviation {
+        if continues_lowercase {
+            flags.clause_pause = true;
+        } else {
+            flags.sentence_pause = true;
+        }
         return flags;
     }
 
-    if trailing.contains([',', ';', ':', '—']) {
+    if marks.contains(pauses.clause_marks) {
         flags.clause_pause = true;
     }
 
     flags
 }
 
-fn looks_like_abbreviation(chunk: &str) -> bool {
-    let trimmed = lexical_core(chunk).text;
+fn is_function_word(chunk: &str, rules: &FormatRules<'_>) -> bool {
+    chunk.chars().all(char::is_alphabetic)
+        && rules
+            .function_words
+            .iter()
+            .any(|word| word.eq_ignore_ascii_case(chunk))
+}
+
+// Grouping must not push the combined unit into stage splitting.
+fn fits_grouped(lead: &str, chunk: &str) -> bool {
+    let separator = usize::from(!lead.is_empty());
+    let bytes = lead.len() + separator + chunk.len();
+    let chars = lead.chars().count() + separator + chunk.chars().count();
+
+    bytes < MAX_READING_TOKEN_BYTES && chars <= STAGE_SPLIT_MIN_CHARS
+}
+
+fn looks_like_abbreviation(chunk: &str, rules: &FormatRules<'_>) -> bool {
+    let core = lexical_core(chunk);
+    let trailing = &chunk[core.start + core.text.len()..];
+    if !trailing.starts_with('.') || trailing.starts_with("..") {
+        return false;
+    }
+
+    let trimmed = core.text;
+    let mut chars = trimmed.chars();
+    if let (Some(initial), None) = (chars.next(), chars.next())
+        && initial.is_uppercase()
+    {
+        return true;
+    }
+
+    if rules
+        .abbreviations
+        .iter()
+        .any(|abbreviation| abbreviation.eq_ignore_ascii_case(trimmed))
+    {
+        return true;
+    }
+
     let mut period_count = 0usize;
     let mut letter_count = 0usize;
 
@@ -507,6 +1352,13 @@ fn looks_like_abbreviation(chunk: &str) -> bool {
     period_count > 0 && letter_count > period_count
 }
 
+fn starts_lowercase(chunk: &str) -> bool {
+    chunk
+        .chars()
+        .find(|ch| ch.is_alphanumeric())
+        .is_some_and(|ch| ch.is_lowercase())
+}
+
 fn preferred_anchor(core_chars: usize) -> usize {
     match core_chars {
         0..=1 => 0,
@@ -529,6 +1381,28 @@ fn font_for_token(char_count: usize) -> StageFont {
     }
 }
 
+// Bionic reading bolds the first half of a word's letters; punctuation before them rides along.
+// Returns how many leading characters of the stage token (left then right) to draw bold.
+pub fn bionic_lead_chars(left: &str, right: &str) -> u8 {
+    let letters = left
+        .chars()
+        .chain(right.chars())
+        .filter(|ch| ch.is_alphanumeric())
+        .count();
+    let mut remaining = letters.div_ceil(2);
+    let mut lead = 0usize;
+    for ch in left.chars().chain(right.chars()) {
+        if remaining == 0 {
+            break;
+        }
+        if ch.is_alphanumeric() {
+            remaining -= 1;
+        }
+        lead += 1;
+    }
+    lead.min(usize::from(u8::MAX)) as u8
+}
+
 fn byte_index_for_char(text: &str, char_index: usize) -> usize {
     if char_index == 0 {
         return 0;
@@ -568,6 +1442,43 @@ mod tests {
         assert!(found);
     }
 
+    fn unit_texts(paragraph: &str) -> alloc::vec::Vec<alloc::string::String> {
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text(paragraph);
+        document.units[..document.unit_count as usize]
+            .iter()
+            .map(|unit| alloc::string::String::from(unit.display.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn accented_words_and_typographic_apostrophes_stay_whole() {
+        assert_eq!(
+            unit_texts("L’été était naïve\u{3000}déjà"),
+            ["L’été", "était", "naïve", "déjà"]
+        );
+    }
+
+    #[test]
+    fn em_dashes_split_joined_words() {
+        assert_eq!(unit_texts("calm—then noise"), ["calm—", "then", "noise"]);
+    }
+
+    #[test]
+    fn non_breaking_spaces_keep_figures_with_units() {
+        assert_eq!(
+            unit_texts("about 10\u{00A0}km north"),
+            ["about", "10\u{00A0}km", "north"]
+        );
+    }
+
+    #[test]
+    fn cjk_text_reads_one_character_per_unit() {
+        assert_eq!(unit_texts("我爱「读书」。"), ["我", "爱", "「读", "书」。"]);
+        assert_eq!(unit_texts("日本語、かな"), ["日", "本", "語、", "か", "な"]);
+        assert_eq!(unit_texts("한국어 문장"), ["한국어", "문장"]);
+    }
+
     #[test]
     fn apostrophes_and_periods_stay_inside_expected_units() {
         let segments = split_for_stage("There's");
@@ -579,6 +1490,202 @@ mod tests {
         assert_eq!(segments[1], "");
     }
 
+    fn sentence_pauses(paragraph: &str, rules: &FormatRules<'_>) -> alloc::vec::Vec<bool> {
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text_with_rules(paragraph, rules);
+        document.units[..document.unit_count as usize]
+            .iter()
+            .map(|unit| unit.flags.sentence_pause)
+            .collect()
+    }
+
+    #[test]
+    fn sentence_pauses_fire_on_real_sentence_ends() {
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text("It rained. We stayed, mostly dry!");
+
+        assert!(document.units[1].flags.sentence_pause);
+        assert!(document.units[3].flags.clause_pause);
+        assert!(document.units[5].flags.sentence_pause);
+    }
+
+    #[test]
+    fn abbreviations_and_initials_do_not_end_sentences() {
+        let rules = FormatRules::default();
+
+        assert_eq!(
+            sentence_pauses("Dr. Smith met J. Doe, etc. Then left.", &rules),
+            [false, false, false, false, false, false, false, true]
+        );
+        assert_eq!(
+            sentence_pauses("Pi is 3.14 exactly. Done.", &rules),
+            [false, false, false, true, true]
+        );
+    }
+
+    #[test]
+    fn ellipses_and_lowercase_continuations_become_clause_pauses() {
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text("Wait... no. Wait\u{2026} Yes.");
+
+        assert!(!document.units[0].flags.sentence_pause);
+        assert!(document.units[0].flags.clause_pause);
+        assert!(document.units[1].flags.sentence_pause);
+        assert!(document.units[2].flags.sentence_pause);
+    }
+
+    #[test]
+    fn abbreviation_list_is_configurable() {
+        let rules = FormatRules {
+            abbreviations: &["approx", "ca"],
+            ..FormatRules::default()
+        };
+
+        assert_eq!(
+            sentence_pauses("Ca. 1900. Mr. Smith", &rules),
+            [false, true, true, false]
+        );
+    }
+
+    #[test]
+    fn pause_rules_follow_the_book_language() {
+        let flags = |text: &str, rules: &FormatRules<'_>| -> alloc::vec::Vec<(bool, bool)> {
+            let mut document = ReadingDocument::empty();
+            document.push_paragraph_text_with_rules(text, rules);
+            document.units[..document.unit_count as usize]
+                .iter()
+                .map(|unit| (unit.flags.clause_pause, unit.flags.sentence_pause))
+                .collect()
+        };
+
+        assert_eq!(
+            flags("Vraiment ? Oui ; bien", &FormatRules::for_language("fr-CA")),
+            [(false, true), (true, false), (false, false)]
+        );
+        assert_eq!(
+            flags("Sí —dijo— ¿vienes?", &FormatRules::for_language("es")),
+            [(false, false), (false, false), (false, true)]
+        );
+        assert_eq!(
+            flags("Sí —dijo— ¿vienes?", &FormatRules::default()),
+            [(true, false), (true, false), (false, true)]
+        );
+        assert_eq!(
+            flags("Τι; Ναι· καλά", &FormatRules::for_language("EL")),
+            [(false, true), (true, false), (false, false)]
+        );
+        assert_eq!(
+            flags("Τι; Ναι", &FormatRules::for_language("")),
+            [(true, false), (false, false)]
+        );
+    }
+
+    #[test]
+    fn reference_markers_and_footnote_paragraphs_are_skipped_by_default() {
+        let texts = |document: &ReadingDocument| -> alloc::vec::Vec<alloc::string::String> {
+            document.units[..document.unit_count as usize]
+                .iter()
+                .map(|unit| alloc::string::String::from(unit.display.as_str()))
+                .collect()
+        };
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text("Rain fell[12] for days [3-5], they said [a] [sic].");
+        document.push_paragraph_text("[12] Smith, Weather, p. 4.");
+        document.push_paragraph_text("[7]");
+
+        assert_eq!(document.paragraph_count, 1);
+        assert_eq!(
+            texts(&document),
+            ["Rain", "fell", "for", "days,", "they", "said", "[sic]."]
+        );
+        assert_eq!(
+            document.preview_for_paragraph(1).as_str(),
+            "Rain fell for days, they said [sic]."
+        );
+
+        let rules = FormatRules {
+            references: ReferenceMode::Keep,
+            ..FormatRules::default()
+        };
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text_with_rules("It rained[12].", &rules);
+        document.push_paragraph_text_with_rules("[12] Smith.", &rules);
+        assert_eq!(document.paragraph_count, 2);
+        assert_eq!(texts(&document), ["It", "rained[12].", "[12]", "Smith."]);
+    }
+
+    #[test]
+    fn function_word_chunking_groups_short_words_with_the_next_word() {
+        let rules = FormatRules {
+            chunking: ChunkingMode::FunctionWords,
+            ..FormatRules::default()
+        };
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text_with_rules("Out of the house, and then a dog.", &rules);
+        let units = &document.units[..document.unit_count as usize];
+        let texts: alloc::vec::Vec<&str> = units.iter().map(|unit| unit.display.as_str()).collect();
+
+        assert_eq!(texts, ["Out", "of the house,", "and then", "a dog."]);
+        assert_eq!(units[1].stage_token().left.as_str(), "of the h");
+        assert!(units[1].flags.clause_pause);
+        assert!(units[3].flags.sentence_pause);
+        assert_eq!(units[1].lookup_word().as_str(), "house");
+    }
+
+    #[test]
+    fn function_word_chunking_keeps_trailing_and_oversized_groups_separate() {
+        let rules = FormatRules {
+            chunking: ChunkingMode::FunctionWords,
+            ..FormatRules::default()
+        };
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text_with_rules("Made of counterrevolutionaries to", &rules);
+        let texts: alloc::vec::Vec<&str> = document.units[..document.unit_count as usize]
+            .iter()
+            .map(|unit| unit.display.as_str())
+            .collect();
+
+        assert_eq!(texts, ["Made", "of", "counterrevolutionaries", "to"]);
+        assert!(document.units[0].flags.paragraph_start);
+    }
+
+    #[test]
+    fn long_words_hyphenate_into_sequential_units() {
+        assert_eq!(
+            unit_texts("pneumonoultramicroscopicsilicovolcanoconiosis"),
+            ["pneumonoultramicrosco-", "picsilicovolcanoconiosis"]
+        );
+        assert_eq!(
+            unit_texts("internationalizationoflocalization"),
+            ["internationaliza-", "tionoflocalization"]
+        );
+    }
+
+    #[test]
+    fn existing_hyphens_win_over_syllable_breaks() {
+        assert_eq!(
+            unit_texts("state-of-the-art-infrastructure"),
+            ["state-of-the-", "art-infrastructure"]
+        );
+    }
+
+    #[cfg(feature = "hyphenation-patterns")]
+    #[test]
+    fn pattern_breaks_prefer_known_affixes() {
+        assert!(pattern_break_bonus("counterrevolutionaries", 7) > 0);
+        assert!(pattern_break_bonus("internationalization", 13) > 0);
+        assert_eq!(pattern_break_bonus("internationalization", 12), 0);
+    }
+
+    #[test]
+    fn multibyte_words_split_before_overflowing_a_unit() {
+        let units = unit_texts("ÄÖÜäöüÄÖÜäöüÄÖÜäöü");
+
+        assert_eq!(units.len(), 2);
+        assert!(units.iter().all(|unit| !unit.is_empty()));
+        assert!(units[0].ends_with('-'));
+    }
+
     #[test]
     fn stage_token_splits_on_anchor() {
         let unit = ReadingUnit {
@@ -594,6 +1701,58 @@ mod tests {
 
         assert_eq!(token.left.as_str(), "Th");
         assert_eq!(token.right.as_str(), "ere's");
+        assert_eq!(token.wrap_index, 0);
+    }
+
+    #[test]
+    fn small_stage_tokens_carry_a_wrap_point() {
+        let unit = ReadingUnit {
+            display: InlineText::from_slice("Donaudampfschifffahrt"),
+            paragraph_index: 1,
+            anchor_index: 4,
+            char_count: 21,
+            font: StageFont::Small,
+            flags: UnitFlags::default(),
+        };
+        let token = unit.stage_token();
+        let (head, tail) = unit.display.as_str().split_at(byte_index_for_char(
+            unit.display.as_str(),
+            token.wrap_index as usize,
+        ));
+        assert!(head.chars().count() >= HYPHEN_MIN_FRAGMENT_CHARS);
+        assert!(tail.chars().count() >= HYPHEN_MIN_FRAGMENT_CHARS);
+
+        let led = ReadingUnit {
+            display: InlineText::from_slice("of the extraordinarily"),
+            char_count: 22,
+            ..unit
+        };
+        assert_eq!(led.stage_token().wrap_index, 7);
+        let hyphenated = ReadingUnit {
+            display: InlineText::from_slice("state-of-the-art-design"),
+            char_count: 23,
+            ..unit
+        };
+        assert_eq!(hyphenated.stage_token().wrap_index, 9);
+    }
+
+    #[test]
+    fn lookup_word_strips_surrounding_punctuation() {
+        let unit = ReadingUnit {
+            display: InlineText::from_slice("\"quixotic,\""),
+            paragraph_index: 1,
+            anchor_index: 3,
+            char_count: 11,
+            font: StageFont::Large,
+            flags: UnitFlags::default(),
+        };
+        let dash = ReadingUnit {
+            display: InlineText::from_slice("--"),
+            ..unit
+        };
+
+        assert_eq!(unit.lookup_word().as_str(), "quixotic");
+        assert!(dash.lookup_word().is_empty());
     }
 
     #[test]
@@ -668,6 +1827,229 @@ mod tests {
         assert_eq!(stacked_unit.dwell_ms(300), 700);
     }
 
+    #[test]
+    fn fixed_pause_lengths_replace_the_scaled_weights() {
+        let unit = |flags| ReadingUnit {
+            display: InlineText::from_slice("go"),
+            paragraph_index: 1,
+            anchor_index: 1,
+            char_count: 2,
+            font: StageFont::Large,
+            flags,
+        };
+        let sentence_unit = unit(UnitFlags {
+            sentence_pause: true,
+            ..UnitFlags::default()
+        });
+        let clause_unit = unit(UnitFlags {
+            clause_pause: true,
+            ..UnitFlags::default()
+        });
+        let timing = DwellTiming {
+            sentence_pause_ms: 240,
+            clause_pause_ms: 80,
+            ..DwellTiming::new(DwellMode::Standard)
+        };
+
+        assert_eq!(sentence_unit.dwell_ms_with(300, &timing), 200 + 240);
+        assert_eq!(sentence_unit.dwell_ms_with(120, &timing), 500 + 240);
+        assert_eq!(clause_unit.dwell_ms_with(300, &timing), 200 + 80);
+        assert_eq!(unit(UnitFlags::default()).dwell_ms_with(300, &timing), 200);
+    }
+
+    #[test]
+    fn weighted_dwell_slows_numbers_acronyms_and_long_words() {
+        let word = ReadingUnit {
+            display: InlineText::from_slice("reading"),
+            paragraph_index: 1,
+            anchor_index: 2,
+            char_count: 7,
+            font: StageFont::Large,
+            flags: UnitFlags::default(),
+        };
+        let number = ReadingUnit {
+            display: InlineText::from_slice("1984,"),
+            char_count: 5,
+            ..word
+        };
+        let acronym = ReadingUnit {
+            display: InlineText::from_slice("NASA"),
+            char_count: 4,
+            ..word
+        };
+        let long = ReadingUnit {
+            display: InlineText::from_slice("extraordinarily"),
+            char_count: 15,
+            ..word
+        };
+        let standard = DwellTiming::default();
+        let weighted = DwellTiming::new(DwellMode::Weighted);
+
+        assert_eq!(word.dwell_ms_with(300, &standard), word.dwell_ms(300));
+        assert_eq!(word.dwell_ms_with(300, &weighted), word.dwell_ms(300));
+        assert_eq!(
+            number.dwell_ms_with(300, &weighted),
+            number.dwell_ms(300) + 90
+        );
+        assert_eq!(
+            acronym.dwell_ms_with(300, &weighted),
+            acronym.dwell_ms(300) + 40
+        );
+        assert_eq!(long.dwell_ms_with(300, &weighted), long.dwell_ms(300) + 70);
+    }
+
+    #[test]
+    fn anchor_percent_moves_the_pivot_within_the_word() {
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text_with_rules(
+            "of \"recognition\"",
+            &FormatRules {
+                chunking: ChunkingMode::FunctionWords,
+                ..FormatRules::default()
+            },
+        );
+        let unit = document.units[0];
+        assert_eq!(unit.display.as_str(), "of \"recognition\"");
+
+        assert_eq!(unit.stage_token_at(0), unit.stage_token());
+        assert_eq!(unit.stage_token_at(20).right.as_str(), "cognition\"");
+        assert_eq!(unit.stage_token_at(50).right.as_str(), "nition\"");
+        assert_eq!(unit.stage_token_at(100).right.as_str(), "n\"");
+    }
+
+    #[test]
+    fn hebrew_and_arabic_tokens_are_marked_right_to_left() {
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text("שלום 1984 مرحبا (hello)");
+        let rtl: alloc::vec::Vec<bool> = document.units[..document.unit_count as usize]
+            .iter()
+            .map(|unit| unit.stage_token().rtl)
+            .collect();
+
+        assert_eq!(rtl, [true, false, true, false]);
+        assert!(is_rtl_text("«שלום»"));
+        assert!(is_rtl_text("1984 שלום"));
+        assert!(!is_rtl_text("42%"));
+    }
+
+    #[test]
+    fn numbers_and_acronyms_get_their_own_delays() {
+        let unit = |text: &str| ReadingUnit {
+            display: InlineText::from_slice(text),
+            char_count: text.chars().count() as u8,
+            ..ReadingUnit::default()
+        };
+        let kinds: alloc::vec::Vec<TokenKind> = [
+            "1984,",
+            "3rd",
+            "NASA",
+            "CDs",
+            "of MP3",
+            "STOP!",
+            "Hello",
+            "I",
+            "THUNDERSTRUCK",
+        ]
+        .iter()
+        .map(|text| unit(text).token_kind())
+        .collect();
+        assert_eq!(
+            kinds,
+            [
+                TokenKind::Number,
+                TokenKind::Number,
+                TokenKind::Acronym,
+                TokenKind::Acronym,
+                TokenKind::Acronym,
+                TokenKind::Acronym,
+                TokenKind::Word,
+                TokenKind::Word,
+                TokenKind::AllCaps,
+            ]
+        );
+
+        let standard = DwellTiming::default();
+        let number = unit("1984");
+        assert_eq!(
+            number.dwell_ms_with(300, &standard),
+            number.dwell_ms(300) + 50
+        );
+        let acronym = unit("NASA");
+        assert_eq!(
+            acronym.dwell_ms_with(300, &standard),
+            acronym.dwell_ms(300) + 40
+        );
+    }
+
+    #[test]
+    fn digit_grouping_splits_long_whole_numbers() {
+        let rules = FormatRules {
+            digit_grouping: true,
+            ..FormatRules::default()
+        };
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text_with_rules(
+            "In 1984 about 1000000 people paid $25000.50, not 12,500 or 12345abc.",
+            &rules,
+        );
+        let texts: alloc::vec::Vec<&str> = document.units[..document.unit_count as usize]
+            .iter()
+            .map(|unit| unit.display.as_str())
+            .collect();
+
+        assert_eq!(
+            texts,
+            [
+                "In",
+                "1984",
+                "about",
+                "1\u{00A0}000\u{00A0}000",
+                "people",
+                "paid",
+                "$25\u{00A0}000.50,",
+                "not",
+                "12,500",
+                "or",
+                "12345abc.",
+            ]
+        );
+        assert_eq!(document.units[3].token_kind(), TokenKind::Number);
+    }
+
+    #[test]
+    fn dwell_timing_clamps_to_configured_bounds() {
+        let unit = ReadingUnit {
+            display: InlineText::from_slice("go"),
+            paragraph_index: 1,
+            anchor_index: 1,
+            char_count: 2,
+            font: StageFont::Large,
+            flags: UnitFlags {
+                sentence_pause: true,
+                paragraph_end: true,
+                ..UnitFlags::default()
+            },
+        };
+        let timing = DwellTiming {
+            mode: DwellMode::Standard,
+            min_ms: 250,
+            max_ms: 600,
+            token_delays: DEFAULT_TOKEN_DELAYS,
+            sentence_pause_ms: 0,
+            clause_pause_ms: 0,
+        };
+
+        assert_eq!(unit.dwell_ms_with(300, &timing), 600);
+        assert_eq!(
+            ReadingUnit {
+                flags: UnitFlags::default(),
+                ..unit
+            }
+            .dwell_ms_with(300, &timing),
+            250
+        );
+    }
+
     #[test]
     fn article_document_helper_keeps_source_and_script() {
         let article =
@@ -684,4 +2066,12 @@ mod tests {
         assert_eq!(font_for_token(17), StageFont::Medium);
         assert_eq!(font_for_token(18), StageFont::Small);
     }
+
+    #[test]
+    fn bionic_lead_covers_half_the_letters() {
+        assert_eq!(bionic_lead_chars("rea", "ding"), 4);
+        assert_eq!(bionic_lead_chars("", "a"), 1);
+        assert_eq!(bionic_lead_chars("\"wh", "at?\""), 3);
+        assert_eq!(bionic_lead_chars("", ""), 0);
+    }
 }
@@ -0,0 +1,186 @@
+use crate::{content::CONTENT_ID_MAX_BYTES, text::InlineText};
+
+// One entry per reading session, newest last, kept for the History screen and the streak. The
+// card file mirrors this ring slot for slot, so it never grows past `HISTORY_CAPACITY` entries;
+// the oldest session is overwritten instead.
+pub const HISTORY_CAPACITY: usize = 32;
+// Enough of a title to recognise the book in a list row.
+pub const HISTORY_TITLE_MAX_BYTES: usize = 40;
+
+// Calendar day (days since 2000-01-01) and minute of day, from the RTC. Sessions read with no
+// clock fitted carry none and never count toward a streak.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct HistoryStamp {
+    pub day: u32,
+    pub minute: u16,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct HistoryEntry {
+    pub content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+    pub title: InlineText<HISTORY_TITLE_MAX_BYTES>,
+    // The paragraph the session started on (1-based). Packages carry no chapter table, so this
+    // stands in for the chapter, as it does on the contents list.
+    pub paragraph_index: u16,
+    pub started_at: Option<HistoryStamp>,
+    pub ended_at: Option<HistoryStamp>,
+    pub words: u32,
+}
+
+impl HistoryEntry {
+    pub const fn empty() -> Self {
+        Self {
+            content_id: InlineText::new(),
+            title: InlineText::new(),
+            paragraph_index: 0,
+            started_at: None,
+            ended_at: None,
+            words: 0,
+        }
+    }
+
+    // The day a session counts toward: the day it ended, or began if the clock dropped out.
+    pub fn day(&self) -> Option<u32> {
+        self.ended_at.or(self.started_at).map(|stamp| stamp.day)
+    }
+}
+
+impl Default for HistoryEntry {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct HistoryState {
+    entries: [HistoryEntry; HISTORY_CAPACITY],
+    // Slot of the oldest entry once the ring has wrapped.
+    head: u8,
+    len: u8,
+}
+
+impl HistoryState {
+    pub const fn empty() -> Self {
+        Self {
+            entries: [HistoryEntry::empty(); HISTORY_CAPACITY],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, entry: HistoryEntry) {
+        if self.len() < HISTORY_CAPACITY {
+            self.entries[(self.head as usize + self.len()) % HISTORY_CAPACITY] = entry;
+            self.len += 1;
+        } else {
+            self.entries[self.head as usize] = entry;
+            self.head = ((self.head as usize + 1) % HISTORY_CAPACITY) as u8;
+        }
+    }
+
+    // 0 is the oldest entry.
+    pub fn oldest(&self, index: usize) -> Option<&HistoryEntry> {
+        (index < self.len()).then(|| &self.entries[(self.head as usize + index) % HISTORY_CAPACITY])
+    }
+
+    // 0 is the newest entry, as the History screen lists them.
+    pub fn newest(&self, index: usize) -> Option<&HistoryEntry> {
+        self.len()
+            .checked_sub(index + 1)
+            .and_then(|oldest| self.oldest(oldest))
+    }
+
+    fn read_on(&self, day: u32) -> bool {
+        (0..self.len()).any(|index| self.oldest(index).and_then(HistoryEntry::day) == Some(day))
+    }
+
+    // Consecutive days with a session, counting back from today. A streak that reached yesterday
+    // still stands until today is over.
+    pub fn streak_days(&self, today: u32) -> u16 {
+        let mut day = if self.read_on(today) {
+            today
+        } else {
+            match today.checked_sub(1) {
+                Some(yesterday) => yesterday,
+                None => return 0,
+            }
+        };
+
+        let mut streak = 0;
+        while self.read_on(day) {
+            streak += 1;
+            match day.checked_sub(1) {
+                Some(previous) => day = previous,
+                None => break,
+            }
+        }
+        streak
+    }
+
+    pub fn total_words(&self) -> u32 {
+        (0..self.len())
+            .filter_map(|index| self.oldest(index))
+            .fold(0u32, |total, entry| total.saturating_add(entry.words))
+    }
+}
+
+impl Default for HistoryState {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(day: Option<u32>, words: u32) -> HistoryEntry {
+        HistoryEntry {
+            ended_at: day.map(|day| HistoryStamp { day, minute: 600 }),
+            words,
+            ..HistoryEntry::empty()
+        }
+    }
+
+    #[test]
+    fn ring_overwrites_the_oldest_and_lists_newest_first() {
+        let mut history = HistoryState::empty();
+        for words in 0..HISTORY_CAPACITY as u32 + 3 {
+            history.push(session(None, words));
+        }
+
+        assert_eq!(history.len(), HISTORY_CAPACITY);
+        assert_eq!(history.oldest(0).unwrap().words, 3);
+        assert_eq!(
+            history.newest(0).unwrap().words,
+            HISTORY_CAPACITY as u32 + 2
+        );
+        assert_eq!(history.newest(HISTORY_CAPACITY), None);
+    }
+
+    #[test]
+    fn streak_counts_back_from_today_or_yesterday() {
+        let mut history = HistoryState::empty();
+        assert_eq!(history.streak_days(100), 0);
+
+        for day in [95, 97, 98, 98, 99] {
+            history.push(session(Some(day), 200));
+        }
+        history.push(session(None, 50));
+
+        assert_eq!(history.streak_days(100), 3);
+        assert_eq!(history.streak_days(99), 3);
+        assert_eq!(history.streak_days(101), 0);
+        history.push(session(Some(100), 10));
+        assert_eq!(history.streak_days(100), 4);
+        assert_eq!(history.total_words(), 1_060);
+    }
+}
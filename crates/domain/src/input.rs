@@ -11,6 +11,59 @@ pub enum InputGesture {
     LongPress,
 }
 
+// How far the knob turns for one step. Normal takes one detent of the fitted encoder; high steps
+// on every half detent and low needs two.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum EncoderSensitivity {
+    #[default]
+    Normal,
+    High,
+    Low,
+}
+
+impl EncoderSensitivity {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Normal => "NORMAL",
+            Self::High => "HIGH",
+            Self::Low => "LOW",
+        }
+    }
+
+    pub const fn cycled(self) -> Self {
+        match self {
+            Self::Normal => Self::High,
+            Self::High => Self::Low,
+            Self::Low => Self::Normal,
+        }
+    }
+
+    // Quadrature transitions per step; the fitted encoder makes two per detent.
+    pub const fn transitions_per_step(self) -> i8 {
+        match self {
+            Self::Normal => 2,
+            Self::High => 1,
+            Self::Low => 4,
+        }
+    }
+
+    pub const fn to_byte(self) -> u8 {
+        match self {
+            Self::Normal => 0,
+            Self::High => 1,
+            Self::Low => 2,
+        }
+    }
+
+    pub const fn from_byte(value: u8) -> Self {
+        match value {
+            1 => Self::High,
+            2 => Self::Low,
+            _ => Self::Normal,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct InputState {
     pub last_gesture: Option<InputGesture>,
@@ -3,21 +3,39 @@
 
 extern crate alloc;
 
+pub mod adaptive_speed;
+pub mod audio;
+pub mod book_stats;
+pub mod bookmark;
+pub mod cli;
+pub mod clock;
 pub mod content;
 pub mod device;
+pub mod diagnostics;
 pub mod formatter;
+pub mod history;
 pub mod input;
+pub mod light;
 pub mod network;
+pub mod onboarding;
+pub mod pagination;
+pub mod perf;
+pub mod picker;
 pub mod power;
 pub mod provisioning;
 pub mod reader;
 pub mod runtime;
 pub mod selectors;
 pub mod settings;
+pub mod settings_schema;
 pub mod sleep;
 pub mod source;
+pub mod speed_preview;
 pub mod storage;
 pub mod store;
 pub mod sync;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod text;
+pub mod toast;
 pub mod ui;
@@ -0,0 +1,68 @@
+use crate::settings::AppearanceMode;
+
+// Lux bounds for the light sensor's appearance switch. The gap between them keeps a lamp
+// flickering around one threshold from flipping the screen back and forth.
+pub const AMBIENT_DARK_LUX: u32 = 10;
+pub const AMBIENT_BRIGHT_LUX: u32 = 50;
+
+// The platform's latest ambient light reading and which side of the thresholds it settled on.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct AmbientLight {
+    lux: Option<u32>,
+    dark: bool,
+}
+
+impl AmbientLight {
+    pub const fn new() -> Self {
+        Self {
+            lux: None,
+            dark: false,
+        }
+    }
+
+    pub const fn lux(&self) -> Option<u32> {
+        self.lux
+    }
+
+    pub fn record(&mut self, lux: u32) {
+        if lux <= AMBIENT_DARK_LUX {
+            self.dark = true;
+        } else if lux >= AMBIENT_BRIGHT_LUX {
+            self.dark = false;
+        } else if self.lux.is_none() {
+            // The first reading lands between the bounds: take the nearer side.
+            self.dark = lux - AMBIENT_DARK_LUX < AMBIENT_BRIGHT_LUX - lux;
+        }
+        self.lux = Some(lux);
+    }
+
+    // Dark in a dim room and light in daylight; `None` until the sensor has reported.
+    pub const fn appearance(&self) -> Option<AppearanceMode> {
+        match (self.lux, self.dark) {
+            (None, _) => None,
+            (Some(_), true) => Some(AppearanceMode::Dark),
+            (Some(_), false) => Some(AppearanceMode::Light),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readings_between_the_bounds_keep_the_last_side() {
+        let mut light = AmbientLight::new();
+        assert_eq!(light.appearance(), None);
+
+        light.record(4);
+        assert_eq!(light.appearance(), Some(AppearanceMode::Dark));
+        light.record(30);
+        assert_eq!(light.appearance(), Some(AppearanceMode::Dark));
+        light.record(800);
+        assert_eq!(light.appearance(), Some(AppearanceMode::Light));
+        light.record(30);
+        assert_eq!(light.appearance(), Some(AppearanceMode::Light));
+        assert_eq!(light.lux(), Some(30));
+    }
+}
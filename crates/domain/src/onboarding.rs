@@ -0,0 +1,110 @@
+// The first-boot walk-through. It takes every input until the last step is confirmed, then the
+// `onboarded` setting keeps it away until a factory reset erases the settings record.
+
+use crate::speed_preview::SpeedPreview;
+
+pub const ONBOARDING_STEP_COUNT: u8 = 4;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum OnboardingStep {
+    #[default]
+    Encoder,
+    ReadingSpeed,
+    Wifi,
+    StorageCard,
+}
+
+impl OnboardingStep {
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::Encoder => 1,
+            Self::ReadingSpeed => 2,
+            Self::Wifi => 3,
+            Self::StorageCard => 4,
+        }
+    }
+
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Encoder => "KNOB DIRECTION",
+            Self::ReadingSpeed => "READING SPEED",
+            Self::Wifi => "WI-FI",
+            Self::StorageCard => "SD CARD",
+        }
+    }
+
+    pub const fn next(self) -> Option<Self> {
+        match self {
+            Self::Encoder => Some(Self::ReadingSpeed),
+            Self::ReadingSpeed => Some(Self::Wifi),
+            Self::Wifi => Some(Self::StorageCard),
+            Self::StorageCard => None,
+        }
+    }
+
+    pub const fn previous(self) -> Option<Self> {
+        match self {
+            Self::Encoder => None,
+            Self::ReadingSpeed => Some(Self::Encoder),
+            Self::Wifi => Some(Self::ReadingSpeed),
+            Self::StorageCard => Some(Self::Wifi),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct OnboardingState {
+    pub step: OnboardingStep,
+    // Set by the first turn on the encoder step, which is also what fixes the direction.
+    pub direction_confirmed: bool,
+    demo: SpeedPreview,
+}
+
+impl OnboardingState {
+    pub const fn new() -> Self {
+        Self {
+            step: OnboardingStep::Encoder,
+            direction_confirmed: false,
+            demo: SpeedPreview::new(),
+        }
+    }
+
+    pub const fn demo_word(&self) -> &'static str {
+        self.demo.word()
+    }
+
+    pub fn advance_demo(&mut self, now_ms: u64, wpm: u16) -> bool {
+        self.demo.advance(now_ms, wpm)
+    }
+
+    pub fn restart_demo(&mut self) {
+        self.demo.restart();
+    }
+}
+
+impl Default for OnboardingState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::speed_preview::SAMPLE_SENTENCE;
+
+    #[test]
+    fn demo_sentence_steps_at_the_chosen_speed_and_wraps() {
+        let mut onboarding = OnboardingState::new();
+
+        assert!(!onboarding.advance_demo(1_000, 300));
+        assert!(!onboarding.advance_demo(1_150, 300));
+        assert!(onboarding.advance_demo(1_200, 300));
+        assert_eq!(onboarding.demo_word(), "shows");
+
+        for step in 2..=SAMPLE_SENTENCE.len() as u64 {
+            assert!(onboarding.advance_demo(1_000 + step * 200, 300));
+        }
+        assert_eq!(onboarding.demo_word(), "Motif");
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{formatter::ReadingUnit, text::InlineText};
+
+// Sized for the body font on the 400x240 panel: 8 px cells between the 20 px reader margins and
+// a 15 px line pitch between the title rule and the footer.
+pub const PAGE_COLUMNS: usize = 45;
+pub const PAGE_LINES: usize = 11;
+pub const PAGE_LINE_MAX_BYTES: usize = 96;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PageLayout {
+    pub lines: [InlineText<PAGE_LINE_MAX_BYTES>; PAGE_LINES],
+    pub line_count: u8,
+    pub start_unit_index: u32,
+    pub end_unit_index: u32,
+}
+
+impl PageLayout {
+    pub const fn empty(start_unit_index: u32) -> Self {
+        Self {
+            lines: [InlineText::new(); PAGE_LINES],
+            line_count: 0,
+            start_unit_index,
+            end_unit_index: start_unit_index,
+        }
+    }
+
+    // Greedy word wrap from `start_unit_index` until the page is full or `unit_at` runs out of
+    // loaded units. Paragraph and section starts leave one blank line, except at the top of a page.
+    pub fn layout<'a>(
+        start_unit_index: u32,
+        unit_at: impl Fn(u32) -> Option<&'a ReadingUnit>,
+    ) -> Self {
+        let mut page = Self::empty(start_unit_index);
+        let mut line = InlineText::<PAGE_LINE_MAX_BYTES>::new();
+        let mut line_chars = 0usize;
+        let mut unit_index = start_unit_index;
+
+        while let Some(unit) = unit_at(unit_index) {
+            let word = unit.display.as_str();
+            let word_chars = word.chars().count().min(PAGE_COLUMNS);
+            let breaks_paragraph = unit_index != start_unit_index
+                && (unit.flags.paragraph_start || unit.flags.section_start);
+
+            if breaks_paragraph {
+                if !page.push_line(&mut line, &mut line_chars) || !page.push_blank_line() {
+                    break;
+                }
+            } else if line_chars > 0
+                && line_chars + 1 + word_chars > PAGE_COLUMNS
+                && !page.push_line(&mut line, &mut line_chars)
+            {
+                break;
+            }
+
+            if page.line_count as usize >= PAGE_LINES {
+                break;
+            }
+
+            if line_chars > 0 {
+                let _ = line.try_push_char(' ');
+                line_chars += 1;
+            }
+            for ch in word.chars().take(word_chars) {
+                if !line.try_push_char(ch) {
+                    break;
+                }
+            }
+            line_chars += word_chars;
+            unit_index += 1;
+        }
+
+        if line_chars > 0 && (page.line_count as usize) < PAGE_LINES {
+            page.lines[page.line_count as usize] = line;
+            page.line_count += 1;
+        }
+        page.end_unit_index = unit_index;
+        page
+    }
+
+    pub fn line(&self, index: usize) -> &str {
+        if index < self.line_count as usize {
+            self.lines[index].as_str()
+        } else {
+            ""
+        }
+    }
+
+    fn push_line(
+        &mut self,
+        line: &mut InlineText<PAGE_LINE_MAX_BYTES>,
+        line_chars: &mut usize,
+    ) -> bool {
+        if *line_chars == 0 {
+            return true;
+        }
+        if self.line_count as usize >= PAGE_LINES {
+            return false;
+        }
+
+        self.lines[self.line_count as usize] = *line;
+        self.line_count += 1;
+        line.clear();
+        *line_chars = 0;
+        true
+    }
+
+    fn push_blank_line(&mut self) -> bool {
+        if self.line_count == 0 {
+            return true;
+        }
+        if self.line_count as usize >= PAGE_LINES {
+            return false;
+        }
+
+        self.line_count += 1;
+        true
+    }
+}
+
+// The start of the page that ends at `end_unit_index`: the earliest start whose forward layout
+// still reaches it, bounded by the first loaded unit.
+pub fn previous_page_start<'a>(
+    end_unit_index: u32,
+    first_loaded_unit_index: u32,
+    unit_at: impl Fn(u32) -> Option<&'a ReadingUnit> + Copy,
+) -> Option<u32> {
+    if end_unit_index <= first_loaded_unit_index {
+        return None;
+    }
+
+    let mut start = end_unit_index - 1;
+    while start > first_loaded_unit_index {
+        let candidate = start - 1;
+        if PageLayout::layout(candidate, unit_at).end_unit_index < end_unit_index {
+            break;
+        }
+        start = candidate;
+    }
+    Some(start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formatter::ReadingDocument;
+
+    fn document_with_paragraphs(paragraphs: &[&str]) -> ReadingDocument {
+        let mut document = ReadingDocument::empty();
+        for paragraph in paragraphs {
+            document.push_paragraph_text(paragraph);
+        }
+        document
+    }
+
+    #[test]
+    fn layout_wraps_words_and_separates_paragraphs() {
+        let document = document_with_paragraphs(&[
+            "The quick brown fox jumps over the lazy dog and keeps running far away.",
+            "Second paragraph.",
+        ]);
+        let unit_at =
+            |index: u32| document.units[..document.unit_count as usize].get(index as usize);
+
+        let page = PageLayout::layout(0, unit_at);
+
+        assert_eq!(page.line(0), "The quick brown fox jumps over the lazy dog");
+        assert_eq!(page.line(1), "and keeps running far away.");
+        assert_eq!(page.line(2), "");
+        assert_eq!(page.line(3), "Second paragraph.");
+        assert_eq!(page.line_count, 4);
+        assert_eq!(page.end_unit_index, u32::from(document.unit_count));
+    }
+
+    #[test]
+    fn layout_stops_when_page_is_full_and_previous_page_reaches_back() {
+        let paragraphs = ["Every paragraph here is short."; 8];
+        let document = document_with_paragraphs(&paragraphs);
+        let unit_at =
+            |index: u32| document.units[..document.unit_count as usize].get(index as usize);
+
+        let first = PageLayout::layout(0, unit_at);
+        assert_eq!(first.line_count as usize, PAGE_LINES);
+        assert_eq!(first.end_unit_index, 30);
+
+        let second = PageLayout::layout(first.end_unit_index, unit_at);
+        assert_eq!(second.line(0), "Every paragraph here is short.");
+        assert_eq!(
+            previous_page_start(second.start_unit_index, 0, unit_at),
+            Some(0)
+        );
+        assert_eq!(previous_page_start(0, 0, unit_at), None);
+    }
+}
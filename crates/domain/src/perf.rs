@@ -0,0 +1,108 @@
+// Lightweight timing counters for spotting performance regressions between releases. The platform
+// records into them around each stage of the frame and storage pipelines and exports a copy through
+// the periodic `perf` log line and the diagnostics screen.
+
+pub const PERF_LOG_INTERVAL_MS: u64 = 30_000;
+// Words are counted over windows at least this long, so one slow word does not swing the rate.
+const WORD_RATE_WINDOW_MS: u64 = 2_000;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct PerfStat {
+    pub last_us: u32,
+    pub max_us: u32,
+    total_us: u64,
+    samples: u32,
+}
+
+impl PerfStat {
+    pub const fn new() -> Self {
+        Self {
+            last_us: 0,
+            max_us: 0,
+            total_us: 0,
+            samples: 0,
+        }
+    }
+
+    pub fn record(&mut self, elapsed_us: u32) {
+        self.last_us = elapsed_us;
+        self.max_us = self.max_us.max(elapsed_us);
+        self.total_us = self.total_us.saturating_add(u64::from(elapsed_us));
+        self.samples = self.samples.saturating_add(1);
+    }
+
+    pub const fn samples(&self) -> u32 {
+        self.samples
+    }
+
+    pub fn mean_us(&self) -> u32 {
+        if self.samples == 0 {
+            return 0;
+        }
+        (self.total_us / u64::from(self.samples)) as u32
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct PerfCounters {
+    pub render: PerfStat,
+    pub flush: PerfStat,
+    pub refill: PerfStat,
+    // The rate over the last finished window; it holds its value while reading is paused.
+    pub words_per_second: u16,
+    window_started_ms: Option<u64>,
+    window_words: u32,
+}
+
+impl PerfCounters {
+    pub const fn new() -> Self {
+        Self {
+            render: PerfStat::new(),
+            flush: PerfStat::new(),
+            refill: PerfStat::new(),
+            words_per_second: 0,
+            window_started_ms: None,
+            window_words: 0,
+        }
+    }
+
+    pub fn record_words(&mut self, words: u32, now_ms: u64) {
+        let Some(started_ms) = self.window_started_ms else {
+            self.window_started_ms = Some(now_ms);
+            return;
+        };
+
+        self.window_words = self.window_words.saturating_add(words);
+        let elapsed_ms = now_ms.saturating_sub(started_ms);
+        if elapsed_ms < WORD_RATE_WINDOW_MS {
+            return;
+        }
+
+        let rate = u64::from(self.window_words) * 1_000 / elapsed_ms;
+        self.words_per_second = rate.min(u64::from(u16::MAX)) as u16;
+        self.window_started_ms = Some(now_ms);
+        self.window_words = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_track_mean_and_max_and_words_settle_per_window() {
+        let mut perf = PerfCounters::new();
+        perf.render.record(1_000);
+        perf.render.record(3_000);
+        assert_eq!(perf.render.last_us, 3_000);
+        assert_eq!(perf.render.max_us, 3_000);
+        assert_eq!(perf.render.mean_us(), 2_000);
+        assert_eq!(perf.flush.mean_us(), 0);
+
+        perf.record_words(1, 10_000);
+        for step in 1..=10 {
+            perf.record_words(1, 10_000 + step * 200);
+        }
+        assert_eq!(perf.words_per_second, 5);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::text::InlineText;
+
+// Rotary text entry: rotation walks a fixed strip of keys and click types the focused one. Library
+// search uses the `Search` set; a Wi-Fi passphrase prompt needs the full `Printable` set, which is
+// why the picker is generic over its buffer and not tied to a screen.
+
+const SEARCH_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 ";
+const PRINTABLE_FIRST: u8 = b' ';
+const PRINTABLE_COUNT: usize = (b'~' - b' ') as usize + 1;
+const ACTION_KEY_COUNT: usize = 2;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PickerCharset {
+    Search,
+    Printable,
+}
+
+impl PickerCharset {
+    const fn char_count(self) -> usize {
+        match self {
+            Self::Search => SEARCH_CHARS.len(),
+            Self::Printable => PRINTABLE_COUNT,
+        }
+    }
+
+    const fn char_at(self, index: usize) -> char {
+        match self {
+            Self::Search => SEARCH_CHARS[index] as char,
+            Self::Printable => (PRINTABLE_FIRST + index as u8) as char,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PickerKey {
+    Char(char),
+    Delete,
+    Done,
+}
+
+impl PickerKey {
+    pub fn label(self, buffer: &mut [u8; 4]) -> &str {
+        match self {
+            Self::Char(' ') => "SPC",
+            Self::Char(ch) => ch.encode_utf8(buffer),
+            Self::Delete => "DEL",
+            Self::Done => "OK",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PickerOutcome {
+    Edited,
+    Unchanged,
+    Done,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CharacterPicker<const N: usize> {
+    pub charset: PickerCharset,
+    pub text: InlineText<N>,
+    pub focus: usize,
+}
+
+impl<const N: usize> CharacterPicker<N> {
+    pub const fn new(charset: PickerCharset) -> Self {
+        Self {
+            charset,
+            text: InlineText::new(),
+            focus: 0,
+        }
+    }
+
+    // Characters come first so a fresh picker starts on `A` (or space); `DEL` and `OK` close the
+    // strip, so both are a detent or two counter-clockwise from the start.
+    pub const fn key_count(&self) -> usize {
+        self.charset.char_count() + ACTION_KEY_COUNT
+    }
+
+    pub const fn key_at(&self, index: usize) -> PickerKey {
+        let char_count = self.charset.char_count();
+        let index = index % self.key_count();
+        if index < char_count {
+            PickerKey::Char(self.charset.char_at(index))
+        } else if index == char_count {
+            PickerKey::Delete
+        } else {
+            PickerKey::Done
+        }
+    }
+
+    pub const fn focused_key(&self) -> PickerKey {
+        self.key_at(self.focus)
+    }
+
+    // `V` keys centred on the focused one, wrapping around the strip.
+    pub fn visible_keys<const V: usize>(&self) -> [PickerKey; V] {
+        let count = self.key_count();
+        let start = self.focus + count * (V / count + 1) - V / 2;
+        core::array::from_fn(|slot| self.key_at(start + slot))
+    }
+
+    pub fn move_previous(&mut self) {
+        self.focus = self
+            .focus
+            .checked_sub(1)
+            .unwrap_or(self.key_count().saturating_sub(1));
+    }
+
+    pub fn move_next(&mut self) {
+        self.focus = (self.focus + 1) % self.key_count();
+    }
+
+    pub fn confirm(&mut self) -> PickerOutcome {
+        match self.focused_key() {
+            PickerKey::Char(ch) => {
+                if self.text.try_push_char(ch) {
+                    PickerOutcome::Edited
+                } else {
+                    PickerOutcome::Unchanged
+                }
+            }
+            PickerKey::Delete => {
+                if self.text.pop_char().is_some() {
+                    PickerOutcome::Edited
+                } else {
+                    PickerOutcome::Unchanged
+                }
+            }
+            PickerKey::Done => PickerOutcome::Done,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.text.clear();
+        self.focus = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation_wraps_to_action_keys_and_click_edits_the_text() {
+        let mut picker = CharacterPicker::<8>::new(PickerCharset::Search);
+        assert_eq!(picker.focused_key(), PickerKey::Char('A'));
+
+        assert_eq!(picker.confirm(), PickerOutcome::Edited);
+        picker.move_next();
+        assert_eq!(picker.confirm(), PickerOutcome::Edited);
+        assert_eq!(picker.text.as_str(), "AB");
+
+        picker.move_previous();
+        picker.move_previous();
+        picker.move_previous();
+        assert_eq!(picker.focused_key(), PickerKey::Delete);
+        assert_eq!(picker.confirm(), PickerOutcome::Edited);
+        assert_eq!(picker.text.as_str(), "A");
+
+        picker.move_next();
+        assert_eq!(picker.focused_key(), PickerKey::Done);
+        assert_eq!(picker.confirm(), PickerOutcome::Done);
+
+        picker.move_next();
+        assert_eq!(picker.focused_key(), PickerKey::Char('A'));
+        assert_eq!(
+            picker.visible_keys::<3>(),
+            [PickerKey::Done, PickerKey::Char('A'), PickerKey::Char('B')]
+        );
+    }
+
+    #[test]
+    fn printable_set_covers_ascii_punctuation() {
+        let mut picker = CharacterPicker::<4>::new(PickerCharset::Printable);
+        assert_eq!(picker.key_count(), 97);
+        assert_eq!(picker.key_at(94), PickerKey::Char('~'));
+
+        for _ in 0..5 {
+            picker.confirm();
+        }
+        assert_eq!(picker.text.as_str(), "    ");
+        assert_eq!(picker.confirm(), PickerOutcome::Unchanged);
+    }
+}
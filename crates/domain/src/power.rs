@@ -1,11 +1,42 @@
+pub const LOW_BATTERY_PERCENT: u8 = 15;
+
+// Resting single-cell LiPo voltage against charge, sampled from the usual 3.0-4.2 V discharge
+// curve. Readings between points interpolate linearly.
+const LIPO_CURVE_MV: [(u16, u8); 11] = [
+    (3_000, 0),
+    (3_300, 5),
+    (3_500, 10),
+    (3_600, 18),
+    (3_700, 35),
+    (3_750, 45),
+    (3_800, 55),
+    (3_870, 65),
+    (3_950, 75),
+    (4_050, 88),
+    (4_200, 100),
+];
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct PowerStatus {
     pub battery_percent: u8,
+    pub charging: bool,
 }
 
 impl PowerStatus {
     pub const fn new(battery_percent: u8) -> Self {
-        Self { battery_percent }
+        Self {
+            battery_percent,
+            charging: false,
+        }
+    }
+
+    pub const fn with_charging(mut self, charging: bool) -> Self {
+        self.charging = charging;
+        self
+    }
+
+    pub const fn is_low(&self) -> bool {
+        !self.charging && self.battery_percent <= LOW_BATTERY_PERCENT
     }
 }
 
@@ -14,3 +45,131 @@ impl Default for PowerStatus {
         Self::new(82)
     }
 }
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CpuSpeed {
+    Idle,
+    Full,
+}
+
+impl CpuSpeed {
+    pub const fn mhz(self) -> u32 {
+        match self {
+            Self::Idle => 80,
+            Self::Full => 240,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CpuDemand {
+    Render,
+    Storage,
+}
+
+// Counts who currently needs the full clock. Everything else, including a paused or idle
+// reader, runs at the idle speed.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct PowerPolicy {
+    render: u8,
+    storage: u8,
+}
+
+impl PowerPolicy {
+    pub const fn new() -> Self {
+        Self {
+            render: 0,
+            storage: 0,
+        }
+    }
+
+    pub const fn speed(&self) -> CpuSpeed {
+        if self.render > 0 || self.storage > 0 {
+            CpuSpeed::Full
+        } else {
+            CpuSpeed::Idle
+        }
+    }
+
+    // Both return the new speed only when it changes, so callers touch the clock once per edge.
+    pub fn raise(&mut self, demand: CpuDemand) -> Option<CpuSpeed> {
+        let before = self.speed();
+        let count = self.count_mut(demand);
+        *count = count.saturating_add(1);
+        self.changed_from(before)
+    }
+
+    pub fn release(&mut self, demand: CpuDemand) -> Option<CpuSpeed> {
+        let before = self.speed();
+        let count = self.count_mut(demand);
+        *count = count.saturating_sub(1);
+        self.changed_from(before)
+    }
+
+    fn count_mut(&mut self, demand: CpuDemand) -> &mut u8 {
+        match demand {
+            CpuDemand::Render => &mut self.render,
+            CpuDemand::Storage => &mut self.storage,
+        }
+    }
+
+    fn changed_from(&self, before: CpuSpeed) -> Option<CpuSpeed> {
+        let after = self.speed();
+        (after != before).then_some(after)
+    }
+}
+
+pub fn battery_percent_from_millivolts(millivolts: u16) -> u8 {
+    let (first_mv, first_percent) = LIPO_CURVE_MV[0];
+    if millivolts <= first_mv {
+        return first_percent;
+    }
+
+    for pair in LIPO_CURVE_MV.windows(2) {
+        let (low_mv, low_percent) = pair[0];
+        let (high_mv, high_percent) = pair[1];
+        if millivolts <= high_mv {
+            let span_mv = u32::from(high_mv - low_mv);
+            let span_percent = u32::from(high_percent - low_percent);
+            let offset = u32::from(millivolts - low_mv) * span_percent / span_mv;
+            return low_percent + offset as u8;
+        }
+    }
+
+    100
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn millivolts_map_onto_the_lipo_curve() {
+        assert_eq!(battery_percent_from_millivolts(2_800), 0);
+        assert_eq!(battery_percent_from_millivolts(3_650), 26);
+        assert_eq!(battery_percent_from_millivolts(3_800), 55);
+        assert_eq!(battery_percent_from_millivolts(4_300), 100);
+    }
+
+    #[test]
+    fn charging_is_never_low() {
+        assert!(PowerStatus::new(LOW_BATTERY_PERCENT).is_low());
+        assert!(
+            !PowerStatus::new(LOW_BATTERY_PERCENT)
+                .with_charging(true)
+                .is_low()
+        );
+        assert!(!PowerStatus::new(LOW_BATTERY_PERCENT + 1).is_low());
+    }
+
+    #[test]
+    fn cpu_runs_full_only_while_something_demands_it() {
+        let mut policy = PowerPolicy::new();
+        assert_eq!(policy.speed(), CpuSpeed::Idle);
+        assert_eq!(policy.raise(CpuDemand::Storage), Some(CpuSpeed::Full));
+        assert_eq!(policy.raise(CpuDemand::Render), None);
+        assert_eq!(policy.release(CpuDemand::Storage), None);
+        assert_eq!(policy.release(CpuDemand::Render), Some(CpuSpeed::Idle));
+        assert_eq!(policy.release(CpuDemand::Render), None);
+    }
+}
@@ -5,18 +5,42 @@ use alloc::boxed::Box;
 use crate::{
     content::{
         ArticleId, CONTENT_ID_MAX_BYTES, CONTENT_TITLE_MAX_BYTES, CollectionKind,
-        PrepareContentProgress, REMOTE_ITEM_ID_MAX_BYTES, ReaderPauseDetail, ReadingProgressEntry,
-        SOURCE_ID_MAX_BYTES,
+        DICTIONARY_DEFINITION_MAX_BYTES, DICTIONARY_PART_OF_SPEECH_MAX_BYTES,
+        DICTIONARY_WORD_MAX_BYTES, DictionaryDefinition, PrepareContentProgress,
+        REMOTE_ITEM_ID_MAX_BYTES, ReaderPauseDetail, ReadingProgressEntry, SOURCE_ID_MAX_BYTES,
     },
-    formatter::{MAX_PARAGRAPH_PREVIEW_BYTES, ReadingDocument, ReadingUnit},
+    formatter::{
+        DwellMode, DwellTiming, MAX_PARAGRAPH_PREVIEW_BYTES, ReadingDocument, ReadingUnit,
+    },
+    pagination::{PageLayout, previous_page_start},
     settings::{DEFAULT_READING_SPEED_WPM, MIN_READING_SPEED_WPM, READING_SPEED_STEP_WPM},
     text::InlineText,
 };
 
 pub const READER_WINDOW_MAX_UNITS: usize = 128;
+pub const DEFINITION_LINE_MAX_CHARS: usize = 34;
+pub const DEFINITION_VISIBLE_LINES: usize = 5;
 const READER_WINDOW_OVERLAP_UNITS: u32 = 32;
 const READER_WINDOW_PREFETCH_THRESHOLD_UNITS: u32 = 24;
+const READER_WINDOW_PREFETCH_PARAGRAPHS: u16 = 2;
+// Heap the reader may hold in windows, overridable at build time through
+// `MOTIF_READER_WINDOW_BUDGET_BYTES`. Three fit the window behind the cursor as well as the active
+// and prefetched ones, so short rewinds and previews just behind stay off the card; a budget under
+// three windows drops that back-buffer.
+const DEFAULT_READER_WINDOW_BUDGET_BYTES: usize = 3 * core::mem::size_of::<ReaderWindow>();
+// A window the card failed to read is asked for again after 0.5 s, then 1 s; the third failure in
+// a row stalls the stream until the reader asks for a retry.
+const READER_WINDOW_RETRY_LIMIT: u8 = 3;
+const READER_WINDOW_RETRY_BASE_MS: u64 = 500;
 const SPEED_RAMP_DURATION_MS: u64 = 10_000;
+const REWIND_AFTER_PAUSE_MS: u64 = 5_000;
+const COUNTDOWN_STEP_MS: u64 = 1_000;
+const SCRUB_FAST_ROTATION_MS: u64 = 90;
+// Reaching a second chapter start since the last input means a whole chapter went by untouched;
+// at this speed or above that reads as a device left running, and the reader stops.
+const UNATTENDED_SECTION_LIMIT: u8 = 2;
+const UNATTENDED_PAUSE_MIN_WPM: u16 = 300;
+const READING_TIME_MAX_TICK_GAP_MS: u64 = 1_000;
 const SPEED_RAMP_START_NUMERATOR: u16 = 2;
 const SPEED_RAMP_START_DENOMINATOR: u16 = 3;
 const SPEED_RAMP_IDLE_AT_MS: u64 = u64::MAX;
@@ -35,6 +59,12 @@ pub enum ReaderMode {
     Paused,
     ParagraphNavigation,
     LoadingContent,
+    Definition,
+    SectionBreak,
+    WordScrub,
+    Page,
+    Contents,
+    Bookmarks,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
@@ -42,8 +72,11 @@ pub enum PauseMenuRow {
     #[default]
     ResumeRsvp,
     ParagraphView,
+    PageView,
+    ScrubWords,
     SaveArticle,
     Subscription,
+    DefineWord,
 }
 
 impl PauseMenuRow {
@@ -51,17 +84,23 @@ impl PauseMenuRow {
         match self {
             Self::ResumeRsvp => Self::ResumeRsvp,
             Self::ParagraphView => Self::ResumeRsvp,
-            Self::SaveArticle => Self::ParagraphView,
+            Self::PageView => Self::ParagraphView,
+            Self::ScrubWords => Self::PageView,
+            Self::SaveArticle => Self::ScrubWords,
             Self::Subscription => Self::SaveArticle,
+            Self::DefineWord => Self::Subscription,
         }
     }
 
     pub fn next(self) -> Self {
         match self {
             Self::ResumeRsvp => Self::ParagraphView,
-            Self::ParagraphView => Self::SaveArticle,
+            Self::ParagraphView => Self::PageView,
+            Self::PageView => Self::ScrubWords,
+            Self::ScrubWords => Self::SaveArticle,
             Self::SaveArticle => Self::Subscription,
-            Self::Subscription => Self::Subscription,
+            Self::Subscription => Self::DefineWord,
+            Self::DefineWord => Self::DefineWord,
         }
     }
 }
@@ -134,6 +173,63 @@ impl Default for ReaderPauseState {
     }
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum ReaderDefinitionStatus {
+    #[default]
+    Idle,
+    Loading,
+    Ready,
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ReaderDefinitionState {
+    pub status: ReaderDefinitionStatus,
+    pub word: InlineText<DICTIONARY_WORD_MAX_BYTES>,
+    pub part_of_speech: InlineText<DICTIONARY_PART_OF_SPEECH_MAX_BYTES>,
+    pub definition: InlineText<DICTIONARY_DEFINITION_MAX_BYTES>,
+    pub scroll_line: u8,
+}
+
+impl ReaderDefinitionState {
+    pub const fn new() -> Self {
+        Self {
+            status: ReaderDefinitionStatus::Idle,
+            word: InlineText::new(),
+            part_of_speech: InlineText::new(),
+            definition: InlineText::new(),
+            scroll_line: 0,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    pub fn line_count(&self) -> usize {
+        let text = self.definition.as_str();
+        let mut count = 0usize;
+        let mut start = 0usize;
+        while let Some((_, next)) = next_definition_line(text, start) {
+            count += 1;
+            start = next;
+        }
+        count
+    }
+
+    pub fn max_scroll_line(&self) -> u8 {
+        self.line_count()
+            .saturating_sub(DEFINITION_VISIBLE_LINES)
+            .min(u8::MAX as usize) as u8
+    }
+}
+
+impl Default for ReaderDefinitionState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct ReaderProgress {
     pub unit_index: u32,
@@ -159,11 +255,67 @@ pub struct ReaderWindow {
 pub struct ReaderWindowLoadRequest {
     pub content_id: InlineText<CONTENT_ID_MAX_BYTES>,
     pub window_start_unit_index: u32,
+    // Numbers requests across books; only the reply to the newest one is applied.
+    pub request_id: u32,
+}
+
+// Where the reader is, as the one value progress, rewind and sync hand around. `unit_index` is the
+// exact word; `paragraph_index` is the fallback when the book was re-chunked at another revision
+// and word indices moved. There is no byte offset: windows load by unit index, not by file
+// position. Outside the reader it is opaque and travels as `to_bytes`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ReaderPosition {
+    pub content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+    pub remote_revision: u64,
+    pub paragraph_index: u16,
+    pub unit_index: u32,
+}
+
+impl ReaderPosition {
+    pub const ENCODED_LEN: usize = 1 + CONTENT_ID_MAX_BYTES + 8 + 2 + 4;
+
+    // Length-prefixed id, then revision, paragraph and unit little-endian.
+    pub fn to_bytes(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut out = [0u8; Self::ENCODED_LEN];
+        let id = self.content_id.as_str().as_bytes();
+        out[0] = id.len() as u8;
+        out[1..1 + id.len()].copy_from_slice(id);
+        let rest = 1 + CONTENT_ID_MAX_BYTES;
+        out[rest..rest + 8].copy_from_slice(&self.remote_revision.to_le_bytes());
+        out[rest + 8..rest + 10].copy_from_slice(&self.paragraph_index.to_le_bytes());
+        out[rest + 10..rest + 14].copy_from_slice(&self.unit_index.to_le_bytes());
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < Self::ENCODED_LEN {
+            return None;
+        }
+        let id_len = bytes[0] as usize;
+        if id_len == 0 || id_len > CONTENT_ID_MAX_BYTES {
+            return None;
+        }
+        let content_id = core::str::from_utf8(&bytes[1..1 + id_len]).ok()?;
+        let rest = 1 + CONTENT_ID_MAX_BYTES;
+
+        Some(Self {
+            content_id: InlineText::from_slice(content_id),
+            remote_revision: u64::from_le_bytes(bytes[rest..rest + 8].try_into().ok()?),
+            paragraph_index: u16::from_le_bytes(bytes[rest + 8..rest + 10].try_into().ok()?),
+            unit_index: u32::from_le_bytes(bytes[rest + 10..rest + 14].try_into().ok()?),
+        })
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct ReaderAdvanceOutcome {
     pub advanced: bool,
+    // A countdown second started; the store plays the tick cue for it.
+    pub countdown_tick: bool,
+    // A whole chapter went by with no input; the store pauses and rewinds.
+    pub unattended: bool,
+    // The last word's time ran out, once per read-through.
+    pub finished: bool,
     pub load_request: Option<ReaderWindowLoadRequest>,
 }
 
@@ -174,19 +326,52 @@ pub struct ReaderSession {
     pub active_content_id: InlineText<CONTENT_ID_MAX_BYTES>,
     pub active_remote_revision: u64,
     pub title: InlineText<CONTENT_TITLE_MAX_BYTES>,
+    // The book unloaded last; `title` still names it until another one opens.
+    recent_content_id: InlineText<CONTENT_ID_MAX_BYTES>,
     active_window: Option<Box<ReaderWindow>>,
     prefetched_window: Option<Box<ReaderWindow>>,
+    // The window the active one replaced on the way forward.
+    previous_window: Option<Box<ReaderWindow>>,
+    window_budget_bytes: usize,
     paragraphs: Option<Box<[ReaderParagraphInfo]>>,
     total_units: u32,
     pending_window_start_unit_index: Option<u32>,
     pending_seek_unit_index: Option<u32>,
+    // The id of the last window request sent; a newer one supersedes it.
+    window_request_id: u32,
+    // Failed window loads in a row; any window that lands clears it.
+    window_failures: u8,
+    // The failed window's start and when to ask for it again.
+    window_retry: Option<(u32, u64)>,
+    // The failed window's start once retries ran out; nothing loads until the reader retries.
+    stalled_window_start: Option<u32>,
     pub progress: ReaderProgress,
     pub mode: ReaderMode,
     pub resume_mode: ReaderMode,
     pub chat_available: bool,
     pub next_due_at_ms: Option<u64>,
     pub effective_wpm: u16,
+    pub dwell_timing: DwellTiming,
+    pub section_pauses: bool,
+    pub rewind_words: u8,
+    pub countdown_seconds: u8,
+    // Set when reading starts or resumes; the next tick turns it into `countdown_remaining` with
+    // whatever `countdown_seconds` the store synced by then.
+    countdown_pending: bool,
+    countdown_remaining: u8,
+    paused_at_ms: Option<u64>,
+    last_scrub_at_ms: Option<u64>,
+    // Where the reader was at the last input, and how many chapter starts have passed since.
+    interaction_unit_index: u32,
+    unattended_sections: u8,
+    // The end has been reported; moving off the last word clears it for the next read-through.
+    end_reported: bool,
+    pub contents_index: u16,
+    pub bookmarks_index: u16,
+    pub session_reading_ms: u64,
+    last_reading_tick_ms: Option<u64>,
     pub pause: ReaderPauseState,
+    pub definition: ReaderDefinitionState,
     speed_ramp_start_wpm: u16,
     speed_ramp_started_at_ms: u64,
     prepare_progress: PrepareContentProgress,
@@ -233,12 +418,21 @@ impl ReaderSession {
             active_content_id: InlineText::new(),
             active_remote_revision: 0,
             title: InlineText::new(),
+            recent_content_id: InlineText::new(),
             active_window: None,
             prefetched_window: None,
+            previous_window: None,
+            window_budget_bytes: resolve_window_budget_bytes(option_env!(
+                "MOTIF_READER_WINDOW_BUDGET_BYTES"
+            )),
             paragraphs: None,
             total_units: 0,
             pending_window_start_unit_index: None,
             pending_seek_unit_index: None,
+            window_request_id: 0,
+            window_failures: 0,
+            window_retry: None,
+            stalled_window_start: None,
             progress: ReaderProgress {
                 unit_index: 0,
                 paragraph_index: 1,
@@ -250,7 +444,23 @@ impl ReaderSession {
             chat_available: true,
             next_due_at_ms: None,
             effective_wpm: DEFAULT_READING_SPEED_WPM,
+            dwell_timing: DwellTiming::new(DwellMode::Standard),
+            section_pauses: false,
+            rewind_words: 0,
+            countdown_seconds: 0,
+            countdown_pending: false,
+            countdown_remaining: 0,
+            paused_at_ms: None,
+            last_scrub_at_ms: None,
+            interaction_unit_index: 0,
+            unattended_sections: 0,
+            end_reported: false,
+            contents_index: 1,
+            bookmarks_index: 0,
+            session_reading_ms: 0,
+            last_reading_tick_ms: None,
             pause: ReaderPauseState::new(),
+            definition: ReaderDefinitionState::new(),
             speed_ramp_start_wpm: 0,
             speed_ramp_started_at_ms: SPEED_RAMP_IDLE_AT_MS,
             prepare_progress: PrepareContentProgress::connecting(),
@@ -271,10 +481,12 @@ impl ReaderSession {
         self.title = title;
         self.active_window = None;
         self.prefetched_window = None;
+        self.previous_window = None;
         self.paragraphs = None;
         self.total_units = 0;
         self.pending_window_start_unit_index = None;
         self.pending_seek_unit_index = None;
+        self.clear_window_retry();
         self.progress = ReaderProgress {
             unit_index: 0,
             paragraph_index: 1,
@@ -289,6 +501,7 @@ impl ReaderSession {
         self.prepare_display_progress_permille = 0;
         self.prepare_stripe_phase = 0;
         self.pause.clear();
+        self.definition.clear();
         self.clear_speed_ramp();
         self.effective_wpm = DEFAULT_READING_SPEED_WPM;
     }
@@ -359,37 +572,65 @@ impl ReaderSession {
         self.title = title;
         self.total_units = total_units;
         self.paragraphs = Some(paragraphs);
+        self.index_previews(&window);
         self.active_window = Some(window);
         self.prefetched_window = None;
+        self.previous_window = None;
         self.pending_window_start_unit_index = None;
         self.pending_seek_unit_index = None;
+        self.clear_window_retry();
         self.progress.unit_index = 0;
+        self.end_reported = false;
         self.sync_progress();
         self.mode = ReaderMode::Normal;
         self.resume_mode = ReaderMode::Normal;
         self.chat_available = chat_available;
         self.next_due_at_ms = None;
+        self.session_reading_ms = 0;
+        self.last_reading_tick_ms = None;
         self.prepare_progress = PrepareContentProgress::connecting();
         self.prepare_display_progress_permille = 0;
         self.prepare_stripe_phase = 0;
         self.pause.clear();
+        self.definition.clear();
+        self.arm_countdown();
         let request = resume_paragraph_index.and_then(|paragraph_index| {
             self.seek_to_unit(self.paragraph_start(paragraph_index.max(1)), target_wpm)
         });
         if self.pending_seek_unit_index.is_none() && self.progress.unit_index == 0 {
             self.arm_speed_ramp(target_wpm);
         }
+        self.note_interaction();
         request
     }
 
+    // The store calls this after every reader command, so the rewind target follows the input.
+    pub fn note_interaction(&mut self) {
+        self.interaction_unit_index = self
+            .pending_seek_unit_index
+            .unwrap_or(self.progress.unit_index);
+        self.unattended_sections = 0;
+    }
+
+    pub const fn interaction_unit_index(&self) -> u32 {
+        self.interaction_unit_index
+    }
+
+    pub fn rewind_to_interaction(&mut self, target_wpm: u16) -> Option<ReaderWindowLoadRequest> {
+        self.unattended_sections = 0;
+        self.seek_to_unit(self.interaction_unit_index, target_wpm)
+    }
+
     pub fn apply_loaded_window(&mut self, window: Box<ReaderWindow>) {
         let pending_seek = self.pending_seek_unit_index;
         self.pending_window_start_unit_index = None;
+        self.clear_window_retry();
+        self.index_previews(&window);
 
         if let Some(target_unit_index) = pending_seek
             && window.contains(target_unit_index)
         {
-            Self::write_window_slot(&mut self.active_window, window);
+            self.activate_window(window);
             self.prefetched_window = None;
             self.pending_seek_unit_index = None;
             self.progress.unit_index = target_unit_index;
@@ -411,14 +652,19 @@ impl ReaderSession {
     }
 
     pub fn unload_document(&mut self) {
+        if !self.active_content_id.is_empty() {
+            self.recent_content_id = self.active_content_id;
+        }
         self.active_content_id = InlineText::new();
         self.active_remote_revision = 0;
         self.active_window = None;
         self.prefetched_window = None;
+        self.previous_window = None;
         self.paragraphs = None;
         self.total_units = 0;
         self.pending_window_start_unit_index = None;
         self.pending_seek_unit_index = None;
+        self.clear_window_retry();
         self.progress = ReaderProgress {
             unit_index: 0,
             paragraph_index: 1,
@@ -428,23 +674,67 @@ impl ReaderSession {
         self.mode = ReaderMode::Normal;
         self.resume_mode = ReaderMode::Normal;
         self.next_due_at_ms = None;
+        self.session_reading_ms = 0;
+        self.last_reading_tick_ms = None;
         self.clear_speed_ramp();
         self.effective_wpm = DEFAULT_READING_SPEED_WPM;
         self.prepare_progress = PrepareContentProgress::connecting();
         self.prepare_display_progress_permille = 0;
         self.prepare_stripe_phase = 0;
         self.pause.clear();
+        self.definition.clear();
+    }
+
+    // The open book, or the last one read this session.
+    pub fn recent_content_id(&self) -> &InlineText<CONTENT_ID_MAX_BYTES> {
+        if self.active_content_id.is_empty() {
+            &self.recent_content_id
+        } else {
+            &self.active_content_id
+        }
     }
 
-    pub fn clear_pending_window_request(&mut self) {
-        let had_pending_seek = self.pending_seek_unit_index.is_some();
+    // A seek that failed keeps its target, so the retry still lands there.
+    pub fn window_load_failed(&mut self, window_start_unit_index: u32, now_ms: u64) {
         self.pending_window_start_unit_index = None;
-        self.pending_seek_unit_index = None;
         self.prefetched_window = None;
         self.next_due_at_ms = None;
-        if had_pending_seek {
-            self.clear_speed_ramp();
+        self.window_failures = self.window_failures.saturating_add(1);
+        if self.window_failures >= READER_WINDOW_RETRY_LIMIT {
+            self.window_retry = None;
+            self.stalled_window_start = Some(window_start_unit_index);
+            return;
+        }
+
+        let backoff_ms = READER_WINDOW_RETRY_BASE_MS << (self.window_failures - 1);
+        self.window_retry = Some((window_start_unit_index, now_ms.saturating_add(backoff_ms)));
+    }
+
+    pub fn due_window_retry(&mut self, now_ms: u64) -> Option<ReaderWindowLoadRequest> {
+        let (window_start_unit_index, due_at_ms) = self.window_retry?;
+        if now_ms < due_at_ms {
+            return None;
         }
+
+        self.window_retry = None;
+        self.load_request_for_window_start(window_start_unit_index)
+    }
+
+    pub const fn is_stream_stalled(&self) -> bool {
+        self.stalled_window_start.is_some()
+    }
+
+    // The reader's Retry: a fresh run of attempts at the window that stalled.
+    pub fn retry_stalled_window(&mut self) -> Option<ReaderWindowLoadRequest> {
+        let window_start_unit_index = self.stalled_window_start.take()?;
+        self.window_failures = 0;
+        self.load_request_for_window_start(window_start_unit_index)
+    }
+
+    fn clear_window_retry(&mut self) {
+        self.window_failures = 0;
+        self.window_retry = None;
+        self.stalled_window_start = None;
     }
 
     pub fn show_normal(&mut self) {
@@ -461,11 +751,12 @@ impl ReaderSession {
         }
     }
 
-    pub fn pause(&mut self, seeded_is_saved: bool) {
+    pub fn pause(&mut self, seeded_is_saved: bool, now_ms: u64) {
         if matches!(self.mode, ReaderMode::Normal | ReaderMode::Chat) {
             self.resume_mode = self.mode;
             self.mode = ReaderMode::Paused;
             self.next_due_at_ms = None;
+            self.paused_at_ms = Some(now_ms);
             self.clear_speed_ramp();
             self.pause.selected_row = PauseMenuRow::ResumeRsvp;
             if !matches!(self.pause.metadata_status, ReaderPauseMetadataStatus::Ready)
@@ -476,14 +767,224 @@ impl ReaderSession {
         }
     }
 
-    pub fn resume(&mut self, target_wpm: u16) {
+    // A long pause rewinds by `rewind_words` units so reading resumes with
+    // some context. The target usually sits in the overlap kept behind the
+    // active window; otherwise the seek requests the window that holds it.
+    pub fn resume(&mut self, target_wpm: u16, now_ms: u64) -> Option<ReaderWindowLoadRequest> {
+        if !matches!(self.mode, ReaderMode::Paused) {
+            return None;
+        }
+
+        self.mode = self.resume_mode;
+        self.next_due_at_ms = None;
+        self.arm_countdown();
+        let paused_for_ms = self
+            .paused_at_ms
+            .take()
+            .map_or(0, |paused_at_ms| now_ms.saturating_sub(paused_at_ms));
+        let rewind_target = self
+            .progress
+            .unit_index
+            .saturating_sub(self.rewind_words as u32);
+        if paused_for_ms >= REWIND_AFTER_PAUSE_MS && rewind_target != self.progress.unit_index {
+            return self.seek_to_unit(rewind_target, target_wpm);
+        }
+
+        self.arm_speed_ramp(target_wpm);
+        None
+    }
+
+    pub fn continue_after_section_break(&mut self, target_wpm: u16) {
+        if matches!(self.mode, ReaderMode::SectionBreak) {
+            self.mode = self.resume_mode;
+            self.arm_speed_ramp(target_wpm);
+        }
+    }
+
+    pub fn open_word_scrub(&mut self) {
+        if matches!(self.mode, ReaderMode::Paused) {
+            self.mode = ReaderMode::WordScrub;
+            self.last_scrub_at_ms = None;
+        }
+    }
+
+    pub fn close_word_scrub(&mut self) {
+        if matches!(self.mode, ReaderMode::WordScrub) {
+            self.mode = ReaderMode::Paused;
+        }
+    }
+
+    // Detents arriving faster than `SCRUB_FAST_ROTATION_MS` apart jump whole
+    // paragraphs; slower turns step one unit. Targets outside the loaded
+    // windows are fetched through the regular seek path.
+    pub fn scrub(
+        &mut self,
+        previous: bool,
+        now_ms: u64,
+        target_wpm: u16,
+    ) -> Option<ReaderWindowLoadRequest> {
+        if !matches!(self.mode, ReaderMode::WordScrub) || self.pending_seek_unit_index.is_some() {
+            return None;
+        }
+
+        let fast = self
+            .last_scrub_at_ms
+            .is_some_and(|last| now_ms.saturating_sub(last) < SCRUB_FAST_ROTATION_MS);
+        self.last_scrub_at_ms = Some(now_ms);
+        let target = match (fast, previous) {
+            (true, true) => self.previous_paragraph_target(),
+            (true, false) => self.next_paragraph_target(),
+            (false, true) => self.progress.unit_index.checked_sub(1),
+            (false, false) => Some(self.progress.unit_index.saturating_add(1))
+                .filter(|next| *next < self.total_units),
+        }?;
+
+        self.seek_to_unit(target, target_wpm)
+    }
+
+    pub fn resume_from_scrub(&mut self, target_wpm: u16) {
+        if matches!(self.mode, ReaderMode::WordScrub) {
+            self.mode = self.resume_mode;
+            self.paused_at_ms = None;
+            self.arm_speed_ramp(target_wpm);
+        }
+    }
+
+    pub fn open_page_view(&mut self) {
         if matches!(self.mode, ReaderMode::Paused) {
+            self.mode = ReaderMode::Page;
+        }
+    }
+
+    pub fn close_page_view(&mut self) {
+        if matches!(self.mode, ReaderMode::Page) {
+            self.mode = ReaderMode::Paused;
+        }
+    }
+
+    pub fn resume_from_page(&mut self, target_wpm: u16) {
+        if matches!(self.mode, ReaderMode::Page) {
             self.mode = self.resume_mode;
-            self.next_due_at_ms = None;
+            self.paused_at_ms = None;
             self.arm_speed_ramp(target_wpm);
         }
     }
 
+    pub fn page(&self) -> PageLayout {
+        PageLayout::layout(self.progress.unit_index, |index| self.loaded_unit(index))
+    }
+
+    // The page always starts at the current unit, so turning pages is a seek and leaving page
+    // view resumes RSVP at the top of the page on screen. A previous page that starts before the
+    // active window falls back to a one-unit step, which loads the earlier window.
+    pub fn turn_page(
+        &mut self,
+        previous: bool,
+        target_wpm: u16,
+    ) -> Option<ReaderWindowLoadRequest> {
+        if !matches!(self.mode, ReaderMode::Page) || self.pending_seek_unit_index.is_some() {
+            return None;
+        }
+
+        let current = self.progress.unit_index;
+        let target = if previous {
+            previous_page_start(current, self.active_window().start_unit_index, |index| {
+                self.loaded_unit(index)
+            })
+            .or_else(|| current.checked_sub(1))
+        } else {
+            Some(self.page().end_unit_index).filter(|end| *end < self.total_units)
+        }?;
+
+        self.seek_to_unit(target, target_wpm)
+    }
+
+    // The contents list moves its own cursor and leaves the reading position alone until a click
+    // commits it, so Back lands on the pause menu exactly where reading stopped.
+    pub fn open_contents(&mut self) {
+        if matches!(self.mode, ReaderMode::Paused) {
+            self.mode = ReaderMode::Contents;
+            self.contents_index = self.progress.paragraph_index.max(1);
+            self.next_due_at_ms = None;
+        }
+    }
+
+    pub fn close_contents(&mut self) {
+        if matches!(self.mode, ReaderMode::Contents) {
+            self.mode = ReaderMode::Paused;
+        }
+    }
+
+    pub fn move_contents(&mut self, previous: bool) {
+        self.contents_index = if previous {
+            self.contents_index.saturating_sub(1).max(1)
+        } else {
+            self.contents_index
+                .saturating_add(1)
+                .min(self.progress.total_paragraphs.max(1))
+        };
+    }
+
+    pub fn commit_contents(&mut self, target_wpm: u16) -> Option<ReaderWindowLoadRequest> {
+        if !matches!(self.mode, ReaderMode::Contents) {
+            return None;
+        }
+
+        self.mode = self.resume_mode;
+        self.paused_at_ms = None;
+        self.arm_speed_ramp(target_wpm);
+        self.seek_paragraph(self.contents_index, target_wpm)
+    }
+
+    // Like the contents list, but over the book's marked paragraphs; the store owns the marks and
+    // passes how many there are.
+    pub fn open_bookmarks(&mut self) {
+        if matches!(self.mode, ReaderMode::Paused) {
+            self.mode = ReaderMode::Bookmarks;
+            self.bookmarks_index = 0;
+            self.next_due_at_ms = None;
+        }
+    }
+
+    pub fn close_bookmarks(&mut self) {
+        if matches!(self.mode, ReaderMode::Bookmarks) {
+            self.mode = ReaderMode::Paused;
+        }
+    }
+
+    pub fn move_bookmarks(&mut self, previous: bool, count: usize) {
+        self.bookmarks_index = if previous {
+            self.bookmarks_index.saturating_sub(1)
+        } else {
+            self.bookmarks_index
+                .saturating_add(1)
+                .min(count.saturating_sub(1) as u16)
+        };
+    }
+
+    pub fn commit_bookmark(
+        &mut self,
+        paragraph_index: u16,
+        target_wpm: u16,
+    ) -> Option<ReaderWindowLoadRequest> {
+        if !matches!(self.mode, ReaderMode::Bookmarks) {
+            return None;
+        }
+
+        self.mode = self.resume_mode;
+        self.paused_at_ms = None;
+        self.arm_speed_ramp(target_wpm);
+        self.seek_paragraph(paragraph_index, target_wpm)
+    }
+
+    pub fn seek_paragraph(
+        &mut self,
+        paragraph_index: u16,
+        target_wpm: u16,
+    ) -> Option<ReaderWindowLoadRequest> {
+        self.seek_to_unit(self.paragraph_start(paragraph_index), target_wpm)
+    }
+
     pub fn open_paragraph_navigation(&mut self) {
         if matches!(self.mode, ReaderMode::Paused) {
             self.mode = ReaderMode::ParagraphNavigation;
@@ -497,6 +998,68 @@ impl ReaderSession {
         }
     }
 
+    pub fn open_definition(&mut self) -> Option<InlineText<DICTIONARY_WORD_MAX_BYTES>> {
+        if !matches!(self.mode, ReaderMode::Paused) {
+            return None;
+        }
+
+        let word = self.current_unit().lookup_word();
+        if word.is_empty() {
+            return None;
+        }
+
+        self.definition.clear();
+        self.definition.status = ReaderDefinitionStatus::Loading;
+        self.definition.word = word;
+        self.mode = ReaderMode::Definition;
+        Some(word)
+    }
+
+    pub fn apply_definition(&mut self, definition: DictionaryDefinition) {
+        if !matches!(self.definition.status, ReaderDefinitionStatus::Loading)
+            || self.definition.word != definition.word
+        {
+            return;
+        }
+
+        self.definition.status = ReaderDefinitionStatus::Ready;
+        self.definition.part_of_speech = definition.part_of_speech;
+        self.definition.definition = definition.definition;
+        self.definition.scroll_line = 0;
+    }
+
+    pub fn mark_definition_failed(&mut self, word: InlineText<DICTIONARY_WORD_MAX_BYTES>) {
+        if !matches!(self.definition.status, ReaderDefinitionStatus::Loading)
+            || self.definition.word != word
+        {
+            return;
+        }
+
+        self.definition.status = ReaderDefinitionStatus::Failed;
+    }
+
+    pub fn scroll_definition(&mut self, previous: bool) {
+        if !matches!(self.mode, ReaderMode::Definition) {
+            return;
+        }
+
+        self.definition.scroll_line = if previous {
+            self.definition.scroll_line.saturating_sub(1)
+        } else {
+            self.definition
+                .scroll_line
+                .saturating_add(1)
+                .min(self.definition.max_scroll_line())
+        };
+    }
+
+    pub fn close_definition(&mut self) {
+        if matches!(self.mode, ReaderMode::Definition) {
+            self.mode = ReaderMode::Paused;
+            self.definition.clear();
+        }
+    }
+
     pub fn move_pause_selection(&mut self, previous: bool) {
         if !matches!(self.mode, ReaderMode::Paused) {
             return;
@@ -660,32 +1223,59 @@ impl ReaderSession {
             return None;
         }
 
-        let current_start = self.paragraph_start(self.progress.paragraph_index);
-        if self.progress.unit_index > current_start {
-            return self.seek_to_unit(current_start, target_wpm);
+        let target = self.previous_paragraph_target()?;
+        self.seek_to_unit(target, target_wpm)
+    }
+
+    pub fn jump_live_next_paragraph(&mut self, target_wpm: u16) -> Option<ReaderWindowLoadRequest> {
+        if !self.is_active_reading() {
+            return None;
         }
 
-        if self.progress.paragraph_index > 1 {
-            return self.seek_to_unit(
-                self.paragraph_start(self.progress.paragraph_index - 1),
-                target_wpm,
-            );
+        let target = self.next_paragraph_target()?;
+        self.seek_to_unit(target, target_wpm)
+    }
+
+    // Turns count from a jump still loading, so quick turns add up to one fetch of the last target.
+    fn previous_paragraph_target(&self) -> Option<u32> {
+        let (unit_index, paragraph_index) = self.turn_origin();
+        let current_start = self.paragraph_start(paragraph_index);
+        if unit_index > current_start {
+            return Some(current_start);
         }
 
-        None
+        (paragraph_index > 1).then(|| self.paragraph_start(paragraph_index - 1))
     }
 
-    pub fn jump_live_next_paragraph(&mut self, target_wpm: u16) -> Option<ReaderWindowLoadRequest> {
-        if !self.is_active_reading()
-            || self.progress.paragraph_index >= self.progress.total_paragraphs.max(1)
-        {
-            return None;
+    fn next_paragraph_target(&self) -> Option<u32> {
+        let (_, paragraph_index) = self.turn_origin();
+        (paragraph_index < self.progress.total_paragraphs.max(1))
+            .then(|| self.paragraph_start(paragraph_index + 1))
+    }
+
+    fn turn_origin(&self) -> (u32, u16) {
+        match self.pending_seek_unit_index {
+            Some(unit_index) => (unit_index, self.find_paragraph_for_unit(unit_index)),
+            None => (self.progress.unit_index, self.progress.paragraph_index),
         }
+    }
 
-        self.seek_to_unit(
-            self.paragraph_start(self.progress.paragraph_index + 1),
-            target_wpm,
-        )
+    // Counts time spent in active reading between ticks. Gaps longer than a tick or two (sleep,
+    // long loads) are clipped so they never inflate the session total.
+    pub fn note_reading_time(&mut self, now_ms: u64) -> u64 {
+        if !self.is_active_reading() || self.pending_seek_unit_index.is_some() {
+            self.last_reading_tick_ms = None;
+            return 0;
+        }
+
+        let elapsed = self.last_reading_tick_ms.map_or(0, |last| {
+            now_ms
+                .saturating_sub(last)
+                .min(READING_TIME_MAX_TICK_GAP_MS)
+        });
+        self.last_reading_tick_ms = Some(now_ms);
+        self.session_reading_ms = self.session_reading_ms.saturating_add(elapsed);
+        elapsed
     }
 
     pub const fn is_active_reading(&self) -> bool {
@@ -702,11 +1292,36 @@ impl ReaderSession {
             return outcome;
         }
 
+        // The first word stays on the stage until the countdown runs out; the speed ramp only
+        // starts after it.
+        if core::mem::take(&mut self.countdown_pending) {
+            self.countdown_remaining = self.countdown_seconds;
+            self.next_due_at_ms = None;
+        }
+        if self.countdown_remaining > 0 {
+            match self.next_due_at_ms {
+                None => {
+                    self.next_due_at_ms = Some(now_ms.saturating_add(COUNTDOWN_STEP_MS));
+                    outcome.countdown_tick = true;
+                }
+                Some(due) if now_ms >= due => {
+                    self.countdown_remaining -= 1;
+                    self.next_due_at_ms = (self.countdown_remaining > 0)
+                        .then(|| due.saturating_add(COUNTDOWN_STEP_MS));
+                    outcome.countdown_tick = self.countdown_remaining > 0;
+                }
+                Some(_) => {}
+            }
+            return outcome;
+        }
+
         self.refresh_effective_wpm(now_ms, wpm);
         let current = self.current_unit();
-        let next_due = self
-            .next_due_at_ms
-            .unwrap_or_else(|| now_ms.saturating_add(current.dwell_ms(self.effective_wpm) as u64));
+        let next_due = self.next_due_at_ms.unwrap_or_else(|| {
+            now_ms.saturating_add(
+                current.dwell_ms_with(self.effective_wpm, &self.dwell_timing) as u64,
+            )
+        });
 
         if self.next_due_at_ms.is_none() {
             self.next_due_at_ms = Some(next_due);
@@ -724,6 +1339,7 @@ impl ReaderSession {
             self.progress.completion_percent = 100;
             self.clear_speed_ramp();
             self.effective_wpm = wpm;
+            outcome.finished = !core::mem::replace(&mut self.end_reported, true);
             return outcome;
         }
 
@@ -734,7 +1350,9 @@ impl ReaderSession {
                 .as_ref()
                 .is_some_and(|window| window.contains(next_unit_index))
             {
-                self.active_window = self.prefetched_window.take();
+                if let Some(window) = self.prefetched_window.take() {
+                    self.activate_window(window);
+                }
             } else {
                 self.next_due_at_ms = None;
                 outcome.load_request =
@@ -745,16 +1363,39 @@ impl ReaderSession {
 
         self.progress.unit_index = next_unit_index;
         self.sync_progress();
-        self.refresh_effective_wpm(now_ms, wpm);
-        self.next_due_at_ms =
-            Some(now_ms.saturating_add(self.current_unit().dwell_ms(self.effective_wpm) as u64));
         outcome.advanced = true;
-        outcome.load_request = self.maybe_request_prefetch();
-        outcome
-    }
-
-    pub fn display_wpm(&self, target_wpm: u16) -> u16 {
-        if self.speed_ramp_started_at_ms == SPEED_RAMP_IDLE_AT_MS {
+        if self.current_unit().flags.section_start {
+            self.unattended_sections = self.unattended_sections.saturating_add(1);
+            if self.unattended_sections >= UNATTENDED_SECTION_LIMIT
+                && wpm >= UNATTENDED_PAUSE_MIN_WPM
+            {
+                self.next_due_at_ms = None;
+                outcome.unattended = true;
+                return outcome;
+            }
+        }
+        if self.section_pauses && self.current_unit().flags.section_start {
+            self.resume_mode = self.mode;
+            self.mode = ReaderMode::SectionBreak;
+            self.next_due_at_ms = None;
+            self.clear_speed_ramp();
+            outcome.load_request = self.maybe_request_prefetch();
+            return outcome;
+        }
+
+        self.refresh_effective_wpm(now_ms, wpm);
+        self.next_due_at_ms = Some(
+            now_ms.saturating_add(
+                self.current_unit()
+                    .dwell_ms_with(self.effective_wpm, &self.dwell_timing) as u64,
+            ),
+        );
+        outcome.load_request = self.maybe_request_prefetch();
+        outcome
+    }
+
+    pub fn display_wpm(&self, target_wpm: u16) -> u16 {
+        if self.speed_ramp_started_at_ms == SPEED_RAMP_IDLE_AT_MS {
             target_wpm
         } else {
             quantize_display_wpm(self.effective_wpm, target_wpm)
@@ -818,7 +1459,7 @@ impl ReaderSession {
         self.active_window().unit_at(self.progress.unit_index)
     }
 
-    pub fn reading_progress_checkpoint(&self) -> Option<ReadingProgressEntry> {
+    pub fn position(&self) -> Option<ReaderPosition> {
         if self.active_content_id.is_empty()
             || matches!(self.mode, ReaderMode::LoadingContent)
             || self.active_window().is_empty()
@@ -826,11 +1467,41 @@ impl ReaderSession {
             return None;
         }
 
+        Some(ReaderPosition {
+            content_id: self.active_content_id,
+            remote_revision: self.active_remote_revision,
+            paragraph_index: self.progress.paragraph_index.max(1),
+            unit_index: self.progress.unit_index,
+        })
+    }
+
+    // Ignores a position in another book. The same revision lands on the exact word; another one
+    // only trusts the paragraph.
+    pub fn seek_position(
+        &mut self,
+        position: ReaderPosition,
+        target_wpm: u16,
+    ) -> Option<ReaderWindowLoadRequest> {
+        if position.content_id != self.active_content_id || self.active_content_id.is_empty() {
+            return None;
+        }
+
+        let target = if position.remote_revision == self.active_remote_revision {
+            position.unit_index
+        } else {
+            self.paragraph_start(position.paragraph_index.max(1))
+        };
+        self.seek_to_unit(target, target_wpm)
+    }
+
+    pub fn reading_progress_checkpoint(&self) -> Option<ReadingProgressEntry> {
+        let position = self.position()?;
+
         Some(
             ReadingProgressEntry {
-                content_id: self.active_content_id,
-                remote_revision: self.active_remote_revision,
-                paragraph_index: self.progress.paragraph_index.max(1),
+                content_id: position.content_id,
+                remote_revision: position.remote_revision,
+                paragraph_index: position.paragraph_index,
                 total_paragraphs: self.progress.total_paragraphs.max(1),
             }
             .sanitized(),
@@ -852,7 +1523,7 @@ impl ReaderSession {
                     .saturating_sub(1)
                     .min(paragraphs.len().saturating_sub(1) as u16)
                     as usize;
-                Some(paragraphs[safe_index].preview)
+                Some(paragraphs[safe_index].preview).filter(|preview| !preview.is_empty())
             })
             .or_else(|| Self::preview_from_window(self.active_window(), paragraph_index))
             .or_else(|| {
@@ -860,6 +1531,11 @@ impl ReaderSession {
                     .as_deref()
                     .and_then(|window| Self::preview_from_window(window, paragraph_index))
             })
+            .or_else(|| {
+                self.previous_window
+                    .as_deref()
+                    .and_then(|window| Self::preview_from_window(window, paragraph_index))
+            })
             .unwrap_or_default()
     }
 
@@ -873,12 +1549,61 @@ impl ReaderSession {
         paragraphs[safe_index].start_unit_index
     }
 
+    // Units are words, so a paragraph's length is the gap to the next paragraph's first unit; the
+    // last one runs to the end of the book. Zero without a paragraph table.
+    pub fn paragraph_word_count(&self, paragraph_index: u16) -> u32 {
+        let Some(paragraphs) = self.paragraphs.as_deref() else {
+            return 0;
+        };
+        if paragraphs.is_empty() {
+            return 0;
+        }
+        let index = paragraph_index.clamp(1, paragraphs.len() as u16);
+        let end = if usize::from(index) >= paragraphs.len() {
+            self.total_units
+        } else {
+            self.paragraph_start(index + 1)
+        };
+        end.saturating_sub(self.paragraph_start(index))
+    }
+
+    // Same rate as `remaining_reading_ms`, rounded up to whole minutes for the contents list.
+    pub fn paragraph_reading_minutes(&self, paragraph_index: u16, wpm: u16) -> u16 {
+        let words = self.paragraph_word_count(paragraph_index);
+        if words == 0 {
+            return 0;
+        }
+        words
+            .div_ceil(u32::from(wpm.max(1)))
+            .min(u32::from(u16::MAX)) as u16
+    }
+
+    fn loaded_unit(&self, global_unit_index: u32) -> Option<&ReadingUnit> {
+        if self.active_window().contains(global_unit_index) {
+            return Some(self.active_window().unit_at(global_unit_index));
+        }
+
+        [&self.prefetched_window, &self.previous_window]
+            .into_iter()
+            .filter_map(|window| window.as_deref())
+            .find(|window| window.contains(global_unit_index))
+            .map(|window| window.unit_at(global_unit_index))
+    }
+
     pub fn active_window(&self) -> &ReaderWindow {
         self.active_window
             .as_deref()
             .unwrap_or(&EMPTY_READER_WINDOW)
     }
 
+    // Units are close to words, so the estimate uses the plain words-per-minute rate and ignores
+    // punctuation dwell.
+    pub const fn remaining_reading_ms(&self, wpm: u16) -> u64 {
+        let wpm = if wpm == 0 { 1 } else { wpm as u64 };
+        let remaining_units = self.total_units.saturating_sub(self.progress.unit_index) as u64;
+        remaining_units * 60_000 / wpm
+    }
+
     pub const fn progress_width_px(&self) -> u16 {
         ((400u32 * self.progress.completion_percent as u32) / 100u32) as u16
     }
@@ -900,6 +1625,9 @@ impl ReaderSession {
         let total_units = self.total_units.max(1);
         let current = self.progress.unit_index.min(total_units.saturating_sub(1)) + 1;
         self.progress.completion_percent = ((current * 100) / total_units) as u8;
+        if current < total_units {
+            self.end_reported = false;
+        }
     }
 
     fn find_paragraph_for_unit(&self, unit_index: u32) -> u16 {
@@ -947,7 +1675,9 @@ impl ReaderSession {
 
         let remaining_in_window =
             window_end.saturating_sub(self.progress.unit_index.saturating_add(1));
-        if remaining_in_window > READER_WINDOW_PREFETCH_THRESHOLD_UNITS {
+        if remaining_in_window > READER_WINDOW_PREFETCH_THRESHOLD_UNITS
+            && !self.window_ends_within_prefetch_paragraphs(window_end)
+        {
             return None;
         }
 
@@ -958,11 +1688,25 @@ impl ReaderSession {
         self.load_request_for_window_start(next_start)
     }
 
+    // Short paragraphs can cross the unit threshold faster than the card answers, so the next
+    // window is also requested once the active one ends within a couple of paragraphs.
+    fn window_ends_within_prefetch_paragraphs(&self, window_end: u32) -> bool {
+        if self.paragraphs.is_none() {
+            return false;
+        }
+        let last_paragraph = self.find_paragraph_for_unit(window_end.saturating_sub(1));
+        last_paragraph.saturating_sub(self.progress.paragraph_index)
+            <= READER_WINDOW_PREFETCH_PARAGRAPHS
+    }
+
     fn load_request_for_window_start(
         &mut self,
         window_start_unit_index: u32,
     ) -> Option<ReaderWindowLoadRequest> {
-        if self.active_content_id.is_empty() {
+        if self.active_content_id.is_empty()
+            || self.window_retry.is_some()
+            || self.stalled_window_start.is_some()
+        {
             return None;
         }
 
@@ -978,12 +1722,19 @@ impl ReaderSession {
         }
 
         self.pending_window_start_unit_index = Some(clamped_start);
+        self.window_request_id = self.window_request_id.wrapping_add(1);
         Some(ReaderWindowLoadRequest {
             content_id: self.active_content_id,
             window_start_unit_index: clamped_start,
+            request_id: self.window_request_id,
         })
     }
 
+    // A reply to anything but the request still outstanding is stale: a later jump replaced it.
+    pub fn awaits_window_request(&self, request_id: u32) -> bool {
+        self.pending_window_start_unit_index.is_some() && request_id == self.window_request_id
+    }
+
     fn seek_to_unit(
         &mut self,
         target_unit_index: u32,
@@ -995,6 +1746,8 @@ impl ReaderSession {
 
         let target_unit_index = target_unit_index.min(self.total_units.saturating_sub(1));
         if target_unit_index == self.progress.unit_index {
+            // Turning back onto the word a pending jump left from cancels that jump.
+            self.pending_seek_unit_index = None;
             return None;
         }
 
@@ -1013,7 +1766,9 @@ impl ReaderSession {
             .as_ref()
             .is_some_and(|window| window.contains(target_unit_index))
         {
-            self.active_window = self.prefetched_window.take();
+            if let Some(window) = self.prefetched_window.take() {
+                self.activate_window(window);
+            }
             self.pending_seek_unit_index = None;
             self.progress.unit_index = target_unit_index;
             self.sync_progress();
@@ -1021,12 +1776,30 @@ impl ReaderSession {
             return None;
         }
 
+        // A rewind into the back-buffer swaps it in; the window it leaves is the one ahead.
+        if self
+            .previous_window
+            .as_ref()
+            .is_some_and(|window| window.contains(target_unit_index))
+        {
+            self.prefetched_window = self.active_window.take();
+            self.active_window = self.previous_window.take();
+            self.pending_seek_unit_index = None;
+            self.progress.unit_index = target_unit_index;
+            self.sync_progress();
+            self.arm_speed_ramp(target_wpm);
+            return None;
+        }
+
+        // A jump is the reader's own retry, so it goes out even while a failed load waits.
+        self.window_retry = None;
+        self.stalled_window_start = None;
         self.pending_seek_unit_index = Some(target_unit_index);
-        let request =
-            self.load_request_for_window_start(self.window_start_for_unit(target_unit_index));
+        let window_start_unit_index = self.window_start_for_unit(target_unit_index);
+        let request = self.load_request_for_window_start(window_start_unit_index);
         if request.is_some() {
             self.arm_speed_ramp(target_wpm);
-        } else {
+        } else if self.pending_window_start_unit_index != Some(window_start_unit_index) {
             self.pending_seek_unit_index = None;
         }
         request
@@ -1036,6 +1809,51 @@ impl ReaderSession {
         unit_index.saturating_sub(READER_WINDOW_OVERLAP_UNITS)
     }
 
+    // Packages may ship paragraphs with no preview; every window that loads fills those in from its
+    // own units, so the contents list shows text for any paragraph the reader has streamed past.
+    fn index_previews(&mut self, window: &ReaderWindow) {
+        let Some(paragraphs) = self.paragraphs.as_deref_mut() else {
+            return;
+        };
+        if window.is_empty() {
+            return;
+        }
+
+        let window_end = window
+            .start_unit_index
+            .saturating_add(window.unit_count as u32);
+        let first = paragraphs
+            .partition_point(|paragraph| paragraph.start_unit_index < window.start_unit_index);
+        for index in first..paragraphs.len() {
+            let start = paragraphs[index].start_unit_index;
+            if start >= window_end {
+                break;
+            }
+            if !paragraphs[index].preview.is_empty() {
+                continue;
+            }
+
+            let end = paragraphs
+                .get(index + 1)
+                .map_or(window_end, |next| next.start_unit_index.min(window_end));
+            let preview = &mut paragraphs[index].preview;
+            for unit_index in start..end {
+                let unit = window.unit_at(unit_index);
+                let separator = if preview.is_empty() { "" } else { " " };
+                if preview.len() + separator.len() + unit.display.len()
+                    > MAX_PARAGRAPH_PREVIEW_BYTES
+                {
+                    break;
+                }
+                let _ = preview.try_push_str(separator);
+                let _ = preview.try_push_str(unit.display.as_str());
+                if unit.flags.paragraph_end {
+                    break;
+                }
+            }
+        }
+    }
+
     fn preview_from_window(
         window: &ReaderWindow,
         paragraph_index: u16,
@@ -1073,6 +1891,19 @@ impl ReaderSession {
         found.then_some(preview)
     }
 
+    // The active window moves to the back-buffer when the new one lies ahead of it.
+    fn activate_window(&mut self, window: Box<ReaderWindow>) {
+        let keep_previous = self.window_budget_bytes >= 3 * core::mem::size_of::<ReaderWindow>()
+            && self.active_window.as_ref().is_some_and(|active| {
+                !active.is_empty() && active.start_unit_index < window.start_unit_index
+            });
+        if keep_previous {
+            self.previous_window = self.active_window.replace(window);
+        } else {
+            Self::write_window_slot(&mut self.active_window, window);
+        }
+    }
+
     fn write_window_slot(slot: &mut Option<Box<ReaderWindow>>, window: Box<ReaderWindow>) {
         if let Some(existing) = slot.as_mut() {
             **existing = *window;
@@ -1081,6 +1912,15 @@ impl ReaderSession {
         }
     }
 
+    pub const fn countdown_remaining(&self) -> u8 {
+        self.countdown_remaining
+    }
+
+    fn arm_countdown(&mut self) {
+        self.countdown_pending = true;
+        self.countdown_remaining = 0;
+    }
+
     fn arm_speed_ramp(&mut self, target_wpm: u16) {
         let start_wpm = ramp_start_wpm(target_wpm);
         self.next_due_at_ms = None;
@@ -1126,6 +1966,11 @@ impl ReaderSession {
     }
 }
 
+fn resolve_window_budget_bytes(raw: Option<&str>) -> usize {
+    raw.and_then(|raw| raw.trim().parse().ok())
+        .unwrap_or(DEFAULT_READER_WINDOW_BUDGET_BYTES)
+}
+
 const fn ramp_start_wpm(target_wpm: u16) -> u16 {
     let scaled = ((target_wpm as u32 * SPEED_RAMP_START_NUMERATOR as u32)
         / SPEED_RAMP_START_DENOMINATOR as u32) as u16;
@@ -1181,6 +2026,44 @@ impl Default for ReaderSession {
     }
 }
 
+pub fn next_definition_line(text: &str, start: usize) -> Option<(usize, usize)> {
+    let bytes = text.as_bytes();
+    let mut head = start;
+    while head < bytes.len() && bytes[head].is_ascii_whitespace() {
+        head += 1;
+    }
+    if head >= bytes.len() {
+        return None;
+    }
+
+    let mut line_end = head;
+    let mut last_break = None;
+    for (chars, (offset, ch)) in text[head..].char_indices().enumerate() {
+        if ch == '\n' {
+            return Some((head + offset, head + offset + 1));
+        }
+        if chars == DEFINITION_LINE_MAX_CHARS {
+            if ch.is_whitespace() {
+                last_break = Some(head + offset);
+            }
+            break;
+        }
+        if ch.is_whitespace() {
+            last_break = Some(head + offset);
+        }
+        line_end = head + offset + ch.len_utf8();
+    }
+
+    if line_end >= bytes.len() {
+        return Some((line_end, line_end));
+    }
+
+    match last_break {
+        Some(break_at) => Some((break_at, break_at + 1)),
+        None => Some((line_end, line_end)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1224,6 +2107,20 @@ mod tests {
         session
     }
 
+    #[test]
+    fn prefetch_starts_when_window_ends_within_a_few_paragraphs() {
+        let mut session = make_seekable_session(0, 128, &[0, 20, 40, 90, 120]);
+        session.progress.unit_index = 30;
+        session.sync_progress();
+        assert!(session.maybe_request_prefetch().is_none());
+
+        session.progress.unit_index = 50;
+        session.sync_progress();
+        let request = session.maybe_request_prefetch().expect("prefetch request");
+        assert_eq!(request.window_start_unit_index, 96);
+        assert_eq!(session.pending_window_start_unit_index, Some(96));
+    }
+
     #[test]
     fn built_in_document_opens_inside_windowed_reader() {
         let document = format_article_document(&ArticleDocument::new(
@@ -1405,6 +2302,150 @@ mod tests {
         assert_eq!(session.speed_ramp_started_at_ms, SPEED_RAMP_IDLE_AT_MS);
     }
 
+    #[test]
+    fn section_pauses_stop_on_heading_until_continued() {
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text("Intro ends.");
+        document.push_heading_text("Chapter Two");
+        let mut session = ReaderSession::new();
+        session.section_pauses = true;
+
+        session.open_article(
+            CollectionKind::Saved,
+            ArticleId(1),
+            InlineText::from_slice("Example"),
+            Box::new(document),
+            false,
+            300,
+        );
+        session.advance_if_due(0, 300);
+        assert!(session.advance_if_due(u64::MAX / 2, 300).advanced);
+        assert_eq!(session.mode, ReaderMode::Normal);
+
+        session.next_due_at_ms = Some(0);
+        let outcome = session.advance_if_due(u64::MAX / 2, 300);
+
+        assert!(outcome.advanced);
+        assert_eq!(session.mode, ReaderMode::SectionBreak);
+        assert_eq!(session.current_unit().display.as_str(), "Chapter");
+        assert!(!session.advance_if_due(u64::MAX, 300).advanced);
+
+        session.continue_after_section_break(300);
+
+        assert_eq!(session.mode, ReaderMode::Normal);
+        assert_eq!(session.progress.unit_index, 2);
+    }
+
+    #[test]
+    fn a_chapter_without_input_at_speed_reports_unattended_and_rewinds() {
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text("Intro ends.");
+        document.push_heading_text("Two");
+        document.push_paragraph_text("Middle.");
+        document.push_heading_text("Three");
+        let mut session = ReaderSession::new();
+        session.open_article(
+            CollectionKind::Saved,
+            ArticleId(1),
+            InlineText::from_slice("Example"),
+            Box::new(document),
+            false,
+            300,
+        );
+        session.advance_if_due(0, 300);
+        session.next_due_at_ms = Some(0);
+        session.advance_if_due(1, 300);
+        session.note_interaction();
+        assert_eq!(session.progress.unit_index, 1);
+
+        let mut unattended = false;
+        while !unattended && session.progress.unit_index < 4 {
+            session.next_due_at_ms = Some(0);
+            unattended = session.advance_if_due(1, 300).unattended;
+        }
+        assert!(unattended);
+        assert_eq!(session.current_unit().display.as_str(), "Three");
+
+        assert_eq!(session.rewind_to_interaction(300), None);
+        assert_eq!(session.progress.unit_index, 1);
+
+        // Below the speed threshold an unattended chapter is left alone.
+        session.note_interaction();
+        let mut unattended = false;
+        while session.progress.unit_index < 4 {
+            session.next_due_at_ms = Some(0);
+            unattended |= session.advance_if_due(1, 280).unattended;
+        }
+        assert!(!unattended);
+    }
+
+    #[test]
+    fn the_end_is_reported_once_per_read_through() {
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text("Short.");
+        document.push_paragraph_text("Done.");
+        let mut session = ReaderSession::new();
+        session.open_article(
+            CollectionKind::Saved,
+            ArticleId(1),
+            InlineText::from_slice("Example"),
+            Box::new(document),
+            false,
+            300,
+        );
+        session.advance_if_due(0, 300);
+
+        let mut finishes = 0;
+        for _ in 0..6 {
+            session.next_due_at_ms = Some(0);
+            finishes += usize::from(session.advance_if_due(1, 300).finished);
+        }
+        assert_eq!(finishes, 1);
+
+        assert_eq!(session.seek_to_unit(0, 300), None);
+        for _ in 0..6 {
+            session.next_due_at_ms = Some(0);
+            finishes += usize::from(session.advance_if_due(1, 300).finished);
+        }
+        assert_eq!(finishes, 2);
+    }
+
+    #[test]
+    fn countdown_holds_the_first_word_and_ticks_each_second() {
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text("One two three.");
+        let mut session = ReaderSession::new();
+        session.countdown_seconds = 2;
+        session.open_article(
+            CollectionKind::Saved,
+            ArticleId(1),
+            InlineText::from_slice("Example"),
+            Box::new(document),
+            false,
+            300,
+        );
+
+        assert!(session.advance_if_due(0, 300).countdown_tick);
+        assert_eq!(session.countdown_remaining(), 2);
+        assert!(!session.advance_if_due(999, 300).countdown_tick);
+        assert!(session.advance_if_due(1_000, 300).countdown_tick);
+        assert_eq!(session.countdown_remaining(), 1);
+        let last = session.advance_if_due(2_000, 300);
+        assert!(!last.countdown_tick && !last.advanced);
+        assert_eq!(session.countdown_remaining(), 0);
+        assert_eq!(session.progress.unit_index, 0);
+
+        session.advance_if_due(2_000, 300);
+        assert!(session.advance_if_due(u64::MAX / 2, 300).advanced);
+
+        session.pause(false, 0);
+        session.countdown_seconds = 0;
+        session.resume(300, 0);
+        session.advance_if_due(0, 300);
+        assert_eq!(session.countdown_remaining(), 0);
+        assert!(session.next_due_at_ms.is_some());
+    }
+
     #[test]
     fn resume_arms_fresh_speed_ramp() {
         let document = format_article_document(&ArticleDocument::new(
@@ -1422,16 +2463,112 @@ mod tests {
             false,
             300,
         );
-        session.pause(false);
+        session.pause(false, 0);
 
         assert_eq!(session.display_wpm(300), 300);
 
-        session.resume(300);
+        session.resume(300, 0);
 
         assert_eq!(session.display_wpm(300), start_wpm);
         assert_eq!(session.speed_ramp_started_at_ms, SPEED_RAMP_PENDING_AT_MS);
     }
 
+    #[test]
+    fn resume_after_long_pause_rewinds_by_configured_words() {
+        let document = format_article_document(&ArticleDocument::new(
+            SourceKind::Unknown,
+            ReaderScript::MachineSoul,
+        ));
+        let mut session = ReaderSession::new();
+        session.open_article(
+            CollectionKind::Saved,
+            ArticleId(1),
+            InlineText::from_slice("Example"),
+            Box::new(document),
+            false,
+            300,
+        );
+        session.rewind_words = 5;
+        session.progress.unit_index = 12;
+
+        session.pause(false, 1_000);
+        assert_eq!(session.resume(300, 2_000), None);
+        assert_eq!(session.progress.unit_index, 12);
+
+        session.pause(false, 10_000);
+        assert_eq!(session.resume(300, 10_000 + REWIND_AFTER_PAUSE_MS), None);
+        assert_eq!(session.progress.unit_index, 7);
+        assert_eq!(session.mode, ReaderMode::Normal);
+        assert_eq!(session.display_wpm(300), ramp_start_wpm(300));
+    }
+
+    #[test]
+    fn word_scrub_steps_slowly_and_jumps_paragraphs_on_fast_rotation() {
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text("One two three.");
+        document.push_paragraph_text("Four five six.");
+        document.push_paragraph_text("Seven eight nine.");
+        let mut session = ReaderSession::new();
+        session.open_article(
+            CollectionKind::Saved,
+            ArticleId(1),
+            InlineText::from_slice("Example"),
+            Box::new(document),
+            false,
+            300,
+        );
+        session.progress.unit_index = 4;
+        session.sync_progress();
+        session.pause(false, 0);
+        session.open_word_scrub();
+        assert_eq!(session.mode, ReaderMode::WordScrub);
+
+        assert_eq!(session.scrub(true, 1_000, 300), None);
+        assert_eq!(session.progress.unit_index, 3);
+        assert_eq!(session.scrub(false, 1_500, 300), None);
+        assert_eq!(session.progress.unit_index, 4);
+
+        assert_eq!(session.scrub(false, 1_550, 300), None);
+        assert_eq!(session.progress.unit_index, 6);
+        assert_eq!(session.mode, ReaderMode::WordScrub);
+
+        session.resume_from_scrub(300);
+        assert_eq!(session.mode, ReaderMode::Normal);
+        assert_eq!(session.progress.unit_index, 6);
+    }
+
+    #[test]
+    fn page_view_turns_pages_from_the_current_unit() {
+        let mut document = ReadingDocument::empty();
+        for _ in 0..8 {
+            document.push_paragraph_text("Every paragraph here is short.");
+        }
+        let mut session = ReaderSession::new();
+        session.open_article(
+            CollectionKind::Saved,
+            ArticleId(1),
+            InlineText::from_slice("Example"),
+            Box::new(document),
+            false,
+            300,
+        );
+        session.pause(false, 0);
+        session.open_page_view();
+        assert_eq!(session.mode, ReaderMode::Page);
+        assert_eq!(session.page().line(0), "Every paragraph here is short.");
+
+        assert_eq!(session.turn_page(false, 300), None);
+        assert_eq!(session.progress.unit_index, 30);
+        assert_eq!(session.turn_page(false, 300), None);
+        assert_eq!(session.progress.unit_index, 30);
+
+        assert_eq!(session.turn_page(true, 300), None);
+        assert_eq!(session.progress.unit_index, 0);
+
+        session.resume_from_page(300);
+        assert_eq!(session.mode, ReaderMode::Normal);
+    }
+
     #[test]
     fn minimum_target_speed_does_not_ramp_below_floor() {
         let document = format_article_document(&ArticleDocument::new(
@@ -1533,6 +2670,29 @@ mod tests {
         assert_eq!(session.preview_for_paragraph(3).as_str(), "third preview");
     }
 
+    #[test]
+    fn loaded_windows_fill_in_paragraphs_the_package_left_without_a_preview() {
+        let mut session = make_seekable_session(0, 32, &[0, 64, 128]);
+        session.paragraphs.as_mut().unwrap()[0].preview = InlineText::from_slice("shipped");
+        let mut window = make_test_window(64, 96);
+        for (offset, word) in ["Call", "me", "Ishmael."].into_iter().enumerate() {
+            window.units[offset].display = InlineText::from_slice(word);
+        }
+        window.units[2].flags.paragraph_end = true;
+        window.units[64].display = InlineText::from_slice("Later");
+        window.units[64].flags.paragraph_end = true;
+
+        assert_eq!(session.preview_for_paragraph(2).as_str(), "");
+        session.apply_loaded_window(Box::new(window));
+
+        assert_eq!(session.preview_for_paragraph(1).as_str(), "shipped");
+        assert_eq!(
+            session.preview_for_paragraph(2).as_str(),
+            "Call me Ishmael."
+        );
+        assert_eq!(session.preview_for_paragraph(3).as_str(), "Later");
+    }
+
     #[test]
     fn live_previous_jump_at_paragraph_start_moves_to_previous_paragraph() {
         let mut session = make_seekable_session(0, 32, &[0, 5, 10]);
@@ -1546,6 +2706,50 @@ mod tests {
         assert_eq!(session.progress.paragraph_index, 1);
     }
 
+    #[test]
+    fn live_previous_jump_outside_window_loads_from_the_paragraph_start() {
+        let mut session = make_seekable_session(200, 128, &[0, 100, 200]);
+        session.progress.unit_index = 200;
+        session.sync_progress();
+
+        let request = session.jump_live_previous_paragraph(300).unwrap();
+
+        assert_eq!(request.window_start_unit_index, 68);
+        assert_eq!(session.pending_seek_unit_index, Some(100));
+    }
+
+    #[test]
+    fn failed_window_loads_back_off_then_stall_until_retried() {
+        let mut session = make_seekable_session(0, 32, &[0, 64, 128]);
+        session.total_units = 300;
+        session.sync_progress();
+        let request = session.jump_live_next_paragraph(300).unwrap();
+        let start = |request: Option<ReaderWindowLoadRequest>| {
+            request.map(|request| request.window_start_unit_index)
+        };
+
+        session.window_load_failed(request.window_start_unit_index, 1_000);
+        assert_eq!(session.due_window_retry(1_499), None);
+        assert_eq!(start(session.due_window_retry(1_500)), Some(32));
+        session.window_load_failed(request.window_start_unit_index, 2_000);
+        assert_eq!(session.due_window_retry(2_999), None);
+        assert_eq!(start(session.due_window_retry(3_000)), Some(32));
+        session.window_load_failed(request.window_start_unit_index, 4_000);
+
+        assert!(session.is_stream_stalled());
+        assert_eq!(session.due_window_retry(u64::MAX), None);
+        assert_eq!(session.pending_seek_unit_index, Some(64));
+        let retry = session.retry_stalled_window().unwrap();
+        assert_eq!(retry.window_start_unit_index, 32);
+        assert!(session.awaits_window_request(retry.request_id));
+        assert!(!session.awaits_window_request(request.request_id));
+        assert!(!session.is_stream_stalled());
+
+        session.apply_loaded_window(Box::new(make_test_window(32, 96)));
+        assert_eq!(session.progress.unit_index, 64);
+        assert_eq!(session.window_failures, 0);
+    }
+
     #[test]
     fn live_previous_jump_at_first_paragraph_start_is_noop() {
         let mut session = make_seekable_session(0, 32, &[0, 5, 10]);
@@ -1594,6 +2798,53 @@ mod tests {
         assert_eq!(session.display_wpm(300), start_wpm);
     }
 
+    #[test]
+    fn rewinding_into_the_window_behind_needs_no_card_read() {
+        let mut session = make_seekable_session(0, 32, &[0, 64, 128]);
+        session.total_units = 300;
+        session.prefetched_window = Some(Box::new(make_test_window(32, 96)));
+        session.sync_progress();
+
+        assert_eq!(session.jump_live_next_paragraph(300), None);
+        assert_eq!(session.active_window().start_unit_index, 32);
+
+        assert_eq!(session.jump_live_previous_paragraph(300), None);
+        assert_eq!(session.active_window().start_unit_index, 0);
+        assert_eq!(session.progress.unit_index, 0);
+        assert!(
+            session
+                .prefetched_window
+                .as_ref()
+                .is_some_and(|window| window.start_unit_index == 32)
+        );
+        assert!(session.previous_window.is_none());
+    }
+
+    #[test]
+    fn two_window_budget_drops_the_back_buffer() {
+        let mut session = make_seekable_session(0, 32, &[0, 64, 128]);
+        session.window_budget_bytes = 2 * core::mem::size_of::<ReaderWindow>();
+        session.total_units = 300;
+        session.prefetched_window = Some(Box::new(make_test_window(32, 96)));
+        session.sync_progress();
+
+        assert_eq!(session.jump_live_next_paragraph(300), None);
+        assert_eq!(session.active_window().start_unit_index, 32);
+        assert!(session.previous_window.is_none());
+
+        let request = session.jump_live_previous_paragraph(300).unwrap();
+        assert_eq!(request.window_start_unit_index, 0);
+    }
+
+    #[test]
+    fn window_budget_falls_back_to_three_windows() {
+        let window_bytes = core::mem::size_of::<ReaderWindow>();
+
+        assert_eq!(resolve_window_budget_bytes(None), 3 * window_bytes);
+        assert_eq!(resolve_window_budget_bytes(Some("lots")), 3 * window_bytes);
+        assert_eq!(resolve_window_budget_bytes(Some(" 4096 ")), 4096);
+    }
+
     #[test]
     fn apply_loaded_window_completes_pending_live_jump() {
         let mut session = make_seekable_session(0, 32, &[0, 64, 128]);
@@ -1695,6 +2946,49 @@ mod tests {
         assert_eq!(session.display_wpm(300), start_wpm);
     }
 
+    #[test]
+    fn position_round_trips_and_seeks_by_word_or_paragraph() {
+        let mut session = make_seekable_session(0, 128, &[0, 20, 40, 90]);
+        session.active_remote_revision = 7;
+        session.progress.unit_index = 45;
+        session.sync_progress();
+        let position = session.position().unwrap();
+        assert_eq!(position.paragraph_index, 3);
+        assert_eq!(
+            ReaderPosition::from_bytes(&position.to_bytes()),
+            Some(position)
+        );
+
+        session.progress.unit_index = 0;
+        assert_eq!(session.seek_position(position, 300), None);
+        assert_eq!(session.progress.unit_index, 45);
+
+        session.active_remote_revision = 8;
+        session.progress.unit_index = 0;
+        assert_eq!(session.seek_position(position, 300), None);
+        assert_eq!(session.progress.unit_index, 40);
+
+        let elsewhere = ReaderPosition {
+            content_id: InlineText::from_slice("content-2"),
+            ..position
+        };
+        session.progress.unit_index = 0;
+        assert_eq!(session.seek_position(elsewhere, 300), None);
+        assert_eq!(session.progress.unit_index, 0);
+    }
+
+    #[test]
+    fn paragraph_word_counts_run_to_the_next_paragraph_or_the_end() {
+        let session = make_seekable_session(0, 128, &[0, 20, 40, 390]);
+
+        assert_eq!(session.paragraph_word_count(1), 20);
+        assert_eq!(session.paragraph_word_count(3), 350);
+        assert_eq!(session.paragraph_word_count(4), 10);
+        assert_eq!(session.paragraph_reading_minutes(3, 300), 2);
+        assert_eq!(session.paragraph_reading_minutes(4, 300), 1);
+        assert_eq!(ReaderSession::new().paragraph_word_count(1), 0);
+    }
+
     #[test]
     fn commit_navigation_uses_prefetched_window_when_available() {
         let mut session = make_seekable_session(0, 32, &[0, 64, 128]);
@@ -1713,4 +3007,90 @@ mod tests {
         assert_eq!(session.progress.paragraph_index, 2);
         assert_eq!(session.display_wpm(300), start_wpm);
     }
+
+    #[test]
+    fn paused_define_word_opens_definition_for_current_word() {
+        let mut session = make_seekable_session(0, 4, &[0]);
+        if let Some(window) = session.active_window.as_mut() {
+            window.units[0].display = InlineText::from_slice("Ephemeral,");
+        }
+        session.mode = ReaderMode::Paused;
+
+        let word = session.open_definition();
+
+        assert_eq!(word.map(|word| word.as_str() == "Ephemeral"), Some(true));
+        assert_eq!(session.mode, ReaderMode::Definition);
+        assert_eq!(session.definition.status, ReaderDefinitionStatus::Loading);
+
+        session.apply_definition(DictionaryDefinition {
+            word: InlineText::from_slice("Ephemeral"),
+            part_of_speech: InlineText::from_slice("adjective"),
+            definition: InlineText::from_slice("Lasting for a very short time."),
+        });
+
+        assert_eq!(session.definition.status, ReaderDefinitionStatus::Ready);
+        assert_eq!(session.definition.part_of_speech.as_str(), "adjective");
+
+        session.close_definition();
+
+        assert_eq!(session.mode, ReaderMode::Paused);
+        assert_eq!(session.definition.status, ReaderDefinitionStatus::Idle);
+    }
+
+    #[test]
+    fn stale_definition_results_are_ignored() {
+        let mut session = make_seekable_session(0, 4, &[0]);
+        if let Some(window) = session.active_window.as_mut() {
+            window.units[0].display = InlineText::from_slice("river");
+        }
+        session.mode = ReaderMode::Paused;
+        let _ = session.open_definition();
+
+        session.mark_definition_failed(InlineText::from_slice("ocean"));
+        assert_eq!(session.definition.status, ReaderDefinitionStatus::Loading);
+
+        session.mark_definition_failed(InlineText::from_slice("river"));
+        assert_eq!(session.definition.status, ReaderDefinitionStatus::Failed);
+    }
+
+    #[test]
+    fn definition_scroll_clamps_to_wrapped_line_count() {
+        let mut session = ReaderSession::new();
+        session.mode = ReaderMode::Definition;
+        session.definition.status = ReaderDefinitionStatus::Ready;
+        session.definition.definition = InlineText::from_slice(
+            "A word used to describe something that lasts for only a very short time, \
+             like a mayfly, a soap bubble, or the brief glow of a match struck in the \
+             dark before it fades away again.",
+        );
+
+        let line_count = session.definition.line_count();
+        let mut steps = 0;
+        while steps < 12 {
+            session.scroll_definition(false);
+            steps += 1;
+        }
+
+        assert!(line_count > DEFINITION_VISIBLE_LINES);
+        assert_eq!(
+            session.definition.scroll_line as usize,
+            line_count - DEFINITION_VISIBLE_LINES
+        );
+
+        session.scroll_definition(true);
+        assert_eq!(
+            session.definition.scroll_line as usize,
+            line_count - DEFINITION_VISIBLE_LINES - 1
+        );
+    }
+
+    #[test]
+    fn definition_lines_wrap_on_whitespace() {
+        let text = "short words wrap before the limit is reached";
+        let (end, next) = next_definition_line(text, 0).unwrap();
+
+        assert!(text[..end].chars().count() <= DEFINITION_LINE_MAX_CHARS);
+        assert!(!text[..end].ends_with(' '));
+        assert_eq!(&text[next..], "is reached");
+    }
 }
@@ -1,19 +1,25 @@
 use alloc::boxed::Box;
 
 use crate::{
+    cli::CliCommand,
+    clock::DateTime,
     content::{
-        CONTENT_TITLE_MAX_BYTES, CollectionKind, CollectionManifestState, PackageState,
+        CONTENT_TITLE_MAX_BYTES, CollectionKind, CollectionManifestState,
+        DICTIONARY_WORD_MAX_BYTES, DictionaryDefinition, DictionaryLookupRequest, PackageState,
         PrepareContentProgress, PrepareContentRequest, RECOMMENDATION_SUBTOPIC_SLUG_MAX_BYTES,
         REMOTE_ITEM_ID_MAX_BYTES, ReaderPauseDetail, ReaderPauseDetailRequest,
         ReaderSavedToggleRequest, ReaderSubscriptionToggleRequest, ReadingProgressState,
         RecommendationSubtopicsState, RecommendationTopicRequest,
     },
-    device::DeviceState,
-    input::InputGesture,
+    device::{CRASH_REPORT_MAX_BYTES, DeviceState},
+    diagnostics::DiagnosticsSnapshot,
+    input::{InputGesture, RotationDirection},
     network::NetworkState,
     network::NetworkStatus,
+    power::PowerStatus,
     reader::{ReaderParagraphInfo, ReaderPauseActionKind, ReaderWindow, ReaderWindowLoadRequest},
     settings::PersistedSettings,
+    sleep::SleepResume,
     storage::StorageHealth,
     sync::{StartupSyncProgress, SyncStatus},
     text::InlineText,
@@ -64,6 +70,21 @@ pub enum Command {
     Ui(UiCommand),
 }
 
+impl Command {
+    pub const fn for_gesture(gesture: InputGesture) -> Self {
+        match gesture {
+            InputGesture::Rotate {
+                direction: RotationDirection::Clockwise,
+            } => Self::Ui(UiCommand::FocusNext),
+            InputGesture::Rotate {
+                direction: RotationDirection::CounterClockwise,
+            } => Self::Ui(UiCommand::FocusPrevious),
+            InputGesture::Click => Self::Ui(UiCommand::Confirm),
+            InputGesture::LongPress => Self::Ui(UiCommand::Back),
+        }
+    }
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
 pub enum Event {
@@ -72,10 +93,21 @@ pub enum Event {
     BootCompleted,
     InputGestureReceived(InputGesture),
     NetworkStatusChanged(NetworkStatus),
+    PowerStatusChanged(PowerStatus),
     BackendSyncStatusChanged(SyncStatus),
     StartupSyncProgressChanged(StartupSyncProgress),
     CollectionContentUpdated(CollectionKind, Box<CollectionManifestState>),
+    DeviceBooksUpdated(Box<CollectionManifestState>),
     RecommendationSubtopicsUpdated(Box<RecommendationSubtopicsState>),
+    // What the card held for reading progress, bookmarks, history, book stats and topics,
+    // published once its background scan ends.
+    StoredReadingStateLoaded {
+        reading_progress: Box<ReadingProgressState>,
+        bookmarks: Box<crate::bookmark::BookmarkState>,
+        history: Box<crate::history::HistoryState>,
+        book_stats: Box<crate::book_stats::BookStatsState>,
+        recommendation_subtopics: Box<RecommendationSubtopicsState>,
+    },
     RecommendationTopicContentUpdated {
         topic_slug: InlineText<RECOMMENDATION_SUBTOPIC_SLUG_MAX_BYTES>,
         collection: Box<CollectionManifestState>,
@@ -88,6 +120,13 @@ pub enum Event {
         paragraphs: Box<[ReaderParagraphInfo]>,
         window: Box<ReaderWindow>,
     },
+    // Windows fetched in the background while the reader keeps playing the one it holds.
+    ReaderWindowLoaded {
+        content_id: InlineText<{ crate::content::CONTENT_ID_MAX_BYTES }>,
+        request_id: u32,
+        window: Box<ReaderWindow>,
+    },
+    ReaderWindowLoadFailed(ReaderWindowLoadRequest),
     ContentPackageStateChanged {
         collection: CollectionKind,
         remote_item_id: InlineText<REMOTE_ITEM_ID_MAX_BYTES>,
@@ -110,9 +149,21 @@ pub enum Event {
         content_id: InlineText<{ crate::content::CONTENT_ID_MAX_BYTES }>,
         action: ReaderPauseActionKind,
     },
+    DictionaryDefinitionLoaded(DictionaryDefinition),
+    DictionaryLookupFailed {
+        word: InlineText<DICTIONARY_WORD_MAX_BYTES>,
+    },
     UiTick(u64),
     ReaderTick(u64),
+    LocalTimeChanged(DateTime),
+    // Lux from the ambient light sensor, when one is fitted.
+    AmbientLightChanged(u32),
+    DiagnosticsSampled(DiagnosticsSnapshot),
+    InactivityTimeout,
+    ScreensaverTimeout,
     WokeFromDeepSleep,
+    ScreenshotSaved,
+    CliCommand(CliCommand),
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
@@ -130,10 +181,16 @@ pub enum Effect {
     LoadReaderPauseDetail(ReaderPauseDetailRequest),
     ToggleReaderSaved(ReaderSavedToggleRequest),
     ToggleReaderSubscription(ReaderSubscriptionToggleRequest),
+    LookupDefinition(DictionaryLookupRequest),
     LoadRecommendationSubtopics,
     LoadRecommendationTopic(RecommendationTopicRequest),
     RefreshCollection(CollectionKind),
+    KeepOnDevice(PrepareContentRequest),
+    DeleteBook(PrepareContentRequest),
     PersistSettings(PersistedSettings),
+    SwitchProfile(u8),
+    FactoryReset,
+    SaveScreenshot,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -141,9 +198,13 @@ pub struct BootstrapSnapshot {
     pub device: DeviceState,
     pub boot_at_ms: u64,
     pub content: Option<Box<crate::content::ContentState>>,
+    pub device_books: Option<Box<CollectionManifestState>>,
     pub reading_progress: Option<Box<ReadingProgressState>>,
     pub recommendation_subtopics: Option<Box<RecommendationSubtopicsState>>,
     pub settings: Option<PersistedSettings>,
+    pub profile: u8,
+    pub crash_report: Option<InlineText<CRASH_REPORT_MAX_BYTES>>,
+    pub sleep_resume: Option<SleepResume>,
     pub storage: StorageHealth,
     pub network: NetworkState,
 }
@@ -164,13 +225,40 @@ impl BootstrapSnapshot {
             device,
             boot_at_ms,
             content,
+            device_books: None,
             reading_progress,
             recommendation_subtopics,
             settings,
+            profile: 0,
+            crash_report: None,
+            sleep_resume: None,
             storage,
             network,
         }
     }
+
+    pub fn with_device_books(mut self, device_books: Option<Box<CollectionManifestState>>) -> Self {
+        self.device_books = device_books;
+        self
+    }
+
+    pub fn with_profile(mut self, profile: u8) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    pub fn with_crash_report(
+        mut self,
+        crash_report: Option<InlineText<CRASH_REPORT_MAX_BYTES>>,
+    ) -> Self {
+        self.crash_report = crash_report;
+        self
+    }
+
+    pub fn with_sleep_resume(mut self, sleep_resume: Option<SleepResume>) -> Self {
+        self.sleep_resume = sleep_resume;
+        self
+    }
 }
 
 impl Default for BootstrapSnapshot {
@@ -1,33 +1,73 @@
 use crate::{
+    book_stats::BookStatsState,
+    clock::{LOCAL_TIME_LABEL_MAX_BYTES, LocalTime},
     content::{
         CONTENT_META_MAX_BYTES, CONTENT_TITLE_MAX_BYTES, CollectionKind, CollectionManifestItem,
-        CollectionManifestState, ContentState, PackageState,
-        RECOMMENDATION_SUBTOPIC_LABEL_MAX_BYTES, ReadingProgressEntry, ReadingProgressState,
-        RecommendationSubtopic,
+        CollectionManifestState, ContentState, DICTIONARY_PART_OF_SPEECH_MAX_BYTES,
+        DICTIONARY_WORD_MAX_BYTES, PackageState, RECOMMENDATION_SUBTOPIC_LABEL_MAX_BYTES,
+        ReadingProgressEntry, ReadingProgressState, RecommendationSubtopic,
     },
-    formatter::{MAX_PARAGRAPH_PREVIEW_BYTES, MAX_STAGE_SEGMENT_BYTES, StageFont},
+    diagnostics::DiagnosticsSnapshot,
+    formatter::{
+        FontStyle, MAX_PARAGRAPH_PREVIEW_BYTES, MAX_STAGE_SEGMENT_BYTES, StageFont,
+        bionic_lead_chars, is_rtl_text,
+    },
+    history::HISTORY_TITLE_MAX_BYTES,
     network::NetworkStatus,
-    reader::{PauseMenuRow, ReaderMode, ReaderPauseMetadataStatus, ReaderPausePendingAction},
+    onboarding::{OnboardingState, OnboardingStep},
+    pagination::{PAGE_LINE_MAX_BYTES, PAGE_LINES},
+    perf::PerfStat,
+    picker::PickerKey,
+    reader::{
+        DEFINITION_VISIBLE_LINES, PauseMenuRow, ReaderDefinitionStatus, ReaderMode,
+        ReaderPauseMetadataStatus, ReaderPausePendingAction, next_definition_line,
+    },
     settings::{
         AppearanceMode, TOPIC_CATEGORY_COUNT, TOPIC_CHIP_COUNT, topic_category_label,
         topic_chip_label,
     },
     store::Store,
     text::InlineText,
-    ui::{DashboardFocus, RecommendationsRegion, SettingsMode, TopicRegion, UiRoute},
+    ui::{
+        BookDetailAction, ConfirmAction, ConfirmDialog, DashboardFocus, RecommendationsRegion,
+        SEARCH_QUERY_MAX_BYTES, SettingsMode, SettingsRow, TopicRegion, UiRoute,
+    },
 };
 
 pub const VISIBLE_LIST_ROWS: usize = 3;
+pub const LIBRARY_GRID_CELLS: usize = 6;
 pub const SETTINGS_ROW_COUNT: usize = 6;
 pub const RECOMMENDATION_VISIBLE_TABS: usize = 4;
 pub const RECOMMENDATION_TAB_LABEL_MAX_BYTES: usize = RECOMMENDATION_SUBTOPIC_LABEL_MAX_BYTES + 1;
+pub const PAUSE_ACTION_COUNT: usize = 7;
+pub const DEFINITION_LINE_MAX_BYTES: usize = 64;
+pub const PAUSE_STATUS_MAX_BYTES: usize = 24;
+pub const BOOT_LABEL_MAX_BYTES: usize = 16;
+pub const STATUS_CLOCK_MAX_BYTES: usize = LOCAL_TIME_LABEL_MAX_BYTES;
+const COUNTDOWN_BADGE_LABELS: [&str; 5] = ["1", "2", "3", "4", "5"];
+pub const SEARCH_VISIBLE_KEYS: usize = 9;
+pub const BOOK_DETAIL_ACTION_COUNT: usize = BookDetailAction::ALL.len();
+pub const CONTENTS_VISIBLE_ROWS: usize = 7;
+pub const DIAGNOSTICS_ROW_COUNT: usize = 10;
+pub const DIAGNOSTICS_VALUE_MAX_BYTES: usize = 20;
+pub const HISTORY_VISIBLE_ROWS: usize = 7;
+pub const HISTORY_WHEN_MAX_BYTES: usize = 8;
 const STARTUP_SPLASH_BAR_WIDTH_PX: u16 = 236;
 const STARTUP_SPLASH_SKIP_HINT: &str = "long press to skip sync";
+const FACTORY_RESET_BODY: &str = "Erases settings and progress.";
+const ONBOARDING_ENCODER_PROMPT: &str = "Turn the knob clockwise";
+const ONBOARDING_SPEED_PROMPT: &str = "Turn to set words per minute";
+const ONBOARDING_WIFI_PROMPT: &str = "Joins the network set at build";
+const ONBOARDING_WIFI_DISABLED_PROMPT: &str = "No network set at build";
+const ONBOARDING_CARD_PROMPT: &str = "Books and progress live here";
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub struct StatusClusterModel {
+pub struct StatusBarModel {
     pub battery_percent: u8,
+    pub charging: bool,
     pub network: NetworkStatus,
+    pub sd_card_ready: bool,
+    pub clock: Option<InlineText<STATUS_CLOCK_MAX_BYTES>>,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -40,7 +80,7 @@ pub struct DashboardItemModel {
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct DashboardScreenModel {
     pub appearance: AppearanceMode,
-    pub status: StatusClusterModel,
+    pub status: StatusBarModel,
     pub sync_indicator: Option<SyncIndicatorModel>,
     pub rail_label: &'static str,
     pub items: [DashboardItemModel; VISIBLE_LIST_ROWS],
@@ -66,6 +106,10 @@ pub struct ContentRowModel {
     pub meta: InlineText<CONTENT_META_MAX_BYTES>,
     pub title: InlineText<CONTENT_TITLE_MAX_BYTES>,
     pub progress_badge: Option<InlineText<8>>,
+    // How far into the book the saved position is, for the bar under the title; 0 hides it.
+    pub progress_percent: u8,
+    // Read to the end, or marked so from the detail screen; draws a checkmark.
+    pub finished: bool,
     pub is_fetching: bool,
     pub selected: bool,
 }
@@ -73,12 +117,14 @@ pub struct ContentRowModel {
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct ContentListScreenModel {
     pub appearance: AppearanceMode,
-    pub status: StatusClusterModel,
+    pub status: StatusBarModel,
     pub rail_label: &'static str,
     pub recommendations_bar: Option<RecommendationBarModel>,
     pub rows: [ContentRowModel; VISIBLE_LIST_ROWS],
+    pub grid: Option<[ContentRowModel; LIBRARY_GRID_CELLS]>,
     pub selected_collection: CollectionKind,
     pub selected_index: usize,
+    pub jump_letter: Option<char>,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -111,9 +157,18 @@ pub struct ReaderLoadingModel {
     pub stripe_phase: u8,
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PauseModalModel {
+    pub actions: [PauseActionModel; PAUSE_ACTION_COUNT],
+    pub session_label: InlineText<PAUSE_STATUS_MAX_BYTES>,
+    pub remaining_label: InlineText<PAUSE_STATUS_MAX_BYTES>,
+    pub goal_permille: Option<u16>,
+}
+
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum ReaderModalModel {
-    Pause([PauseActionModel; 4]),
+    Pause(PauseModalModel),
     Loading(ReaderLoadingModel),
 }
 
@@ -126,8 +181,18 @@ pub struct ReaderScreenModel {
     pub right_word: InlineText<MAX_STAGE_SEGMENT_BYTES>,
     pub preview: InlineText<MAX_PARAGRAPH_PREVIEW_BYTES>,
     pub font: StageFont,
+    // Where the word continues on a second line if it is too wide for the stage; 0 for none.
+    pub wrap_index: u8,
+    pub highlight_anchor: bool,
+    pub pivot_style: FontStyle,
+    // Leading letters of the word drawn bold for bionic reading; 0 when it is off.
+    pub bold_lead_chars: u8,
+    // The word is mirrored on the stage; the progress bar fills from the right when the current
+    // paragraph opens right to left.
+    pub rtl_word: bool,
+    pub rtl_paragraph: bool,
     pub progress_width: u16,
-    pub show_chat_badge: bool,
+    pub badge_label: Option<&'static str>,
     pub modal: Option<ReaderModalModel>,
 }
 
@@ -144,6 +209,116 @@ pub struct ParagraphNavigationModel {
     pub tick_index: u8,
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum ContentsMarker {
+    #[default]
+    Unread,
+    Read,
+    Current,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct ContentsRowModel {
+    pub number: u16,
+    pub preview: InlineText<MAX_PARAGRAPH_PREVIEW_BYTES>,
+    // Reading time at the current speed; zero hides it.
+    pub minutes: u16,
+    pub marker: ContentsMarker,
+    pub selected: bool,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ContentsScreenModel {
+    pub appearance: AppearanceMode,
+    pub title: InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub selected_index: u16,
+    pub total: u16,
+    // How many list entries sit above the first row, for the scrollbar.
+    pub first: u16,
+    // The book's marked paragraphs rather than all of them.
+    pub bookmarks: bool,
+    pub rows: [ContentsRowModel; CONTENTS_VISIBLE_ROWS],
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DefinitionScreenModel {
+    pub appearance: AppearanceMode,
+    pub word: InlineText<DICTIONARY_WORD_MAX_BYTES>,
+    pub part_of_speech: InlineText<DICTIONARY_PART_OF_SPEECH_MAX_BYTES>,
+    pub status_label: Option<&'static str>,
+    pub lines: [InlineText<DEFINITION_LINE_MAX_BYTES>; DEFINITION_VISIBLE_LINES],
+    pub show_scroll_up: bool,
+    pub show_scroll_down: bool,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CrashReportScreenModel {
+    pub appearance: AppearanceMode,
+    pub lines: [InlineText<DEFINITION_LINE_MAX_BYTES>; DEFINITION_VISIBLE_LINES],
+    pub boot_label: InlineText<BOOT_LABEL_MAX_BYTES>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LowBatteryScreenModel {
+    pub appearance: AppearanceMode,
+    pub status: StatusBarModel,
+    pub body: InlineText<PAUSE_STATUS_MAX_BYTES>,
+}
+
+// An empty title means nothing has been read since boot; the screen then shows only the clock.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ScreensaverScreenModel {
+    pub appearance: AppearanceMode,
+    pub clock: Option<InlineText<STATUS_CLOCK_MAX_BYTES>>,
+    pub title: InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub progress_percent: u8,
+}
+
+// One step of the first-boot walk-through. The demo word is only set on the speed step.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct OnboardingScreenModel {
+    pub appearance: AppearanceMode,
+    pub step: OnboardingStep,
+    pub title: &'static str,
+    pub prompt: &'static str,
+    pub value: &'static str,
+    pub demo_word: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DiagnosticsRowModel {
+    pub label: &'static str,
+    pub value: InlineText<DIAGNOSTICS_VALUE_MAX_BYTES>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DiagnosticsScreenModel {
+    pub appearance: AppearanceMode,
+    pub rows: [DiagnosticsRowModel; DIAGNOSTICS_ROW_COUNT],
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct HistoryRowModel {
+    pub title: InlineText<HISTORY_TITLE_MAX_BYTES>,
+    // How long ago, by calendar day: "TODAY", "YDAY", "3D AGO", or "--" with no clock.
+    pub when: InlineText<HISTORY_WHEN_MAX_BYTES>,
+    pub words: u32,
+    pub selected: bool,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct HistoryScreenModel {
+    pub appearance: AppearanceMode,
+    // None until the clock has given the store a date.
+    pub streak_days: Option<u16>,
+    pub total_words: u32,
+    pub selected_index: u16,
+    pub total: u16,
+    // How many list entries sit above the first row, for the scrollbar.
+    pub first: u16,
+    pub rows: [HistoryRowModel; HISTORY_VISIBLE_ROWS],
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct SettingsRowModel {
     pub label: &'static str,
@@ -176,48 +351,172 @@ pub struct TopicPreferencesModel {
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct SettingsScreenModel {
     pub appearance: AppearanceMode,
+    pub status: StatusBarModel,
     pub title: &'static str,
     pub mode: SettingsMode,
     pub rows: [SettingsRowModel; SETTINGS_ROW_COUNT],
     pub refresh_title: Option<&'static str>,
     pub refresh_body: Option<&'static str>,
     pub topic_preferences: Option<TopicPreferencesModel>,
+    // The sample sentence word on show while the speed editor is open.
+    pub preview_word: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SectionBreakScreenModel {
+    pub appearance: AppearanceMode,
+    pub title: InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub label: &'static str,
+    pub heading: InlineText<MAX_PARAGRAPH_PREVIEW_BYTES>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PageScreenModel {
+    pub appearance: AppearanceMode,
+    pub title: InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub lines: [InlineText<PAGE_LINE_MAX_BYTES>; PAGE_LINES],
+    pub progress_width: u16,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SearchScreenModel {
+    pub appearance: AppearanceMode,
+    pub status: StatusBarModel,
+    pub rail_label: &'static str,
+    pub query: InlineText<SEARCH_QUERY_MAX_BYTES>,
+    pub keys: [PickerKey; SEARCH_VISIBLE_KEYS],
+    pub match_count: usize,
+    pub matches: [InlineText<CONTENT_TITLE_MAX_BYTES>; VISIBLE_LIST_ROWS],
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BookDetailActionModel {
+    pub label: &'static str,
+    pub selected: bool,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BookDetailScreenModel {
+    pub appearance: AppearanceMode,
+    pub status: StatusBarModel,
+    pub title: InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub meta: InlineText<CONTENT_META_MAX_BYTES>,
+    pub state_label: &'static str,
+    pub progress_percent: u8,
+    pub finished: bool,
+    // Read-throughs to the last word; the detail screen notes re-reads.
+    pub reads: u16,
+    pub actions: [BookDetailActionModel; BOOK_DETAIL_ACTION_COUNT],
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ConfirmScreenModel {
+    pub appearance: AppearanceMode,
+    pub title: &'static str,
+    pub body: InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub accept_label: &'static str,
+    pub cancel_label: &'static str,
+    pub accept_focused: bool,
 }
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum ActiveScreenModel {
+    CrashReport(CrashReportScreenModel),
+    LowBattery(LowBatteryScreenModel),
     StartupSplash(StartupSplashScreenModel),
+    Onboarding(OnboardingScreenModel),
+    Screensaver(ScreensaverScreenModel),
     Dashboard(DashboardScreenModel),
     Collection(ContentListScreenModel),
+    Search(SearchScreenModel),
+    BookDetail(BookDetailScreenModel),
     Reader(ReaderScreenModel),
     ParagraphNavigation(ParagraphNavigationModel),
+    Definition(DefinitionScreenModel),
+    SectionBreak(SectionBreakScreenModel),
+    Page(PageScreenModel),
+    Contents(ContentsScreenModel),
     Settings(SettingsScreenModel),
+    Diagnostics(DiagnosticsScreenModel),
+    History(HistoryScreenModel),
+    Confirm(ConfirmScreenModel),
 }
 
 pub fn select_active_screen(store: &Store) -> ActiveScreenModel {
+    if let Some(report) = store.crash_report.as_ref() {
+        return ActiveScreenModel::CrashReport(select_crash_report(store, report.as_str()));
+    }
+
+    if store.low_battery_warning {
+        return ActiveScreenModel::LowBattery(select_low_battery(store));
+    }
+
     if store.startup_splash_visible {
         let progress_width = ((STARTUP_SPLASH_BAR_WIDTH_PX as u32
             * store.startup_splash_display_progress_permille as u32)
             / 1000) as u16;
         return ActiveScreenModel::StartupSplash(StartupSplashScreenModel {
-            appearance: store.settings.appearance,
+            appearance: store.appearance(),
             progress_width,
             stripe_phase: ((store.startup_splash_tick_ms / 160) % 8) as u8,
             skip_hint: STARTUP_SPLASH_SKIP_HINT,
         });
     }
 
+    if let Some(onboarding) = store.onboarding {
+        return ActiveScreenModel::Onboarding(select_onboarding(store, onboarding));
+    }
+
+    if store.screensaver_visible {
+        return ActiveScreenModel::Screensaver(select_screensaver(store));
+    }
+
+    if let Some(dialog) = store.ui.confirm {
+        return ActiveScreenModel::Confirm(select_confirm(store, dialog));
+    }
+
     match store.ui.route {
         UiRoute::Dashboard => ActiveScreenModel::Dashboard(select_dashboard(store)),
         UiRoute::Collection(kind) => ActiveScreenModel::Collection(select_collection(store, kind)),
+        UiRoute::Search(kind) => ActiveScreenModel::Search(select_search(store, kind)),
+        UiRoute::BookDetail(kind) => ActiveScreenModel::BookDetail(select_book_detail(store, kind)),
         UiRoute::Reader => match store.reader.mode {
             ReaderMode::ParagraphNavigation => {
                 ActiveScreenModel::ParagraphNavigation(select_paragraph_navigation(store))
             }
+            ReaderMode::Definition => ActiveScreenModel::Definition(select_definition(store)),
+            ReaderMode::SectionBreak => {
+                ActiveScreenModel::SectionBreak(select_section_break(store))
+            }
+            ReaderMode::Page => ActiveScreenModel::Page(select_page(store)),
+            ReaderMode::Contents => ActiveScreenModel::Contents(select_contents(store)),
+            ReaderMode::Bookmarks => ActiveScreenModel::Contents(select_bookmarks(store)),
             _ => ActiveScreenModel::Reader(select_reader(store)),
         },
         UiRoute::Settings => ActiveScreenModel::Settings(select_settings(store)),
+        UiRoute::Diagnostics => ActiveScreenModel::Diagnostics(select_diagnostics(store)),
+        UiRoute::History => ActiveScreenModel::History(select_history(store)),
+    }
+}
+
+pub fn select_confirm(store: &Store, dialog: ConfirmDialog) -> ConfirmScreenModel {
+    let body = match dialog.action {
+        ConfirmAction::DeleteBook(kind) => store
+            .content()
+            .manifest_item_at(kind, store.ui.collection_index(kind))
+            .map(|item| item.title)
+            .unwrap_or_default(),
+        ConfirmAction::FactoryReset => InlineText::from_slice(FACTORY_RESET_BODY),
+    };
+
+    ConfirmScreenModel {
+        appearance: store.appearance(),
+        title: dialog.action.title(),
+        body,
+        accept_label: dialog.action.accept_label(),
+        cancel_label: dialog.action.cancel_label(),
+        accept_focused: dialog.accept_focused,
     }
 }
 
@@ -226,7 +525,7 @@ pub fn select_dashboard(store: &Store) -> DashboardScreenModel {
     let focused_index = focused.index();
 
     DashboardScreenModel {
-        appearance: store.settings.appearance,
+        appearance: store.appearance(),
         status: select_status(store),
         sync_indicator: store.backend_sync.shows_dashboard_indicator().then_some(
             SyncIndicatorModel {
@@ -263,32 +562,96 @@ pub fn select_collection(store: &Store, kind: CollectionKind) -> ContentListScre
         select_collection_rows(
             store.content(),
             &store.reading_progress,
+            &store.book_stats,
             kind,
             selected_index,
         )
     };
 
     ContentListScreenModel {
-        appearance: store.settings.appearance,
+        appearance: store.appearance(),
         status: select_status(store),
         rail_label: kind.rail_label(),
         recommendations_bar: matches!(kind, CollectionKind::Recommendations)
             .then_some(select_recommendation_bar(store)),
         rows,
+        grid: select_collection_grid(store, kind, selected_index),
         selected_collection: kind,
         selected_index,
+        jump_letter: store.ui.collection_jump_letter,
+    }
+}
+
+pub fn select_search(store: &Store, kind: CollectionKind) -> SearchScreenModel {
+    let picker = &store.ui.search_picker;
+    let collection = store.content().collection_state(kind);
+    let mut matches = [InlineText::new(); VISIBLE_LIST_ROWS];
+    let mut match_count = 0;
+    for index in collection.search(picker.text.as_str()) {
+        if let Some(slot) = matches.get_mut(match_count) {
+            *slot = collection.items[index].title;
+        }
+        match_count += 1;
+    }
+
+    SearchScreenModel {
+        appearance: store.appearance(),
+        status: select_status(store),
+        rail_label: kind.rail_label(),
+        query: picker.text,
+        keys: picker.visible_keys(),
+        match_count,
+        matches,
+    }
+}
+
+pub fn select_book_detail(store: &Store, kind: CollectionKind) -> BookDetailScreenModel {
+    let item = store
+        .content()
+        .manifest_item_at(kind, store.ui.collection_index(kind))
+        .unwrap_or_else(CollectionManifestItem::empty);
+    let progress_percent = store
+        .reading_progress
+        .entry_for_item(item)
+        .map(ReadingProgressEntry::completion_percent)
+        .unwrap_or(0);
+    let stat = store.book_stats.get(&item.content_id).unwrap_or_default();
+
+    BookDetailScreenModel {
+        appearance: store.appearance(),
+        status: select_status(store),
+        title: item.title,
+        meta: item.meta,
+        state_label: match item.package_state {
+            PackageState::Cached => "DOWNLOADED",
+            PackageState::Fetching => "DOWNLOADING",
+            PackageState::Stale => "UPDATE AVAILABLE",
+            PackageState::PendingRemote => "PREPARING",
+            PackageState::Failed => "DOWNLOAD FAILED",
+            PackageState::Missing => "NOT DOWNLOADED",
+        },
+        progress_percent,
+        finished: stat.finished,
+        reads: stat.reads,
+        actions: BookDetailAction::ALL.map(|action| BookDetailActionModel {
+            label: match action {
+                BookDetailAction::Finished if stat.finished => "MARK UNFINISHED",
+                _ => action.label(),
+            },
+            selected: action == store.ui.book_detail_action,
+        }),
     }
 }
 
 pub fn select_reader(store: &Store) -> ReaderScreenModel {
     let current_unit = store.reader.current_unit();
-    let stage_token = current_unit.stage_token();
+    let stage_token = current_unit.stage_token_at(store.settings.orp_anchor_percent);
     let preview = store
         .reader
         .preview_for_paragraph(store.reader.progress.paragraph_index);
 
     ReaderScreenModel {
-        appearance: store.settings.appearance,
+        appearance: store.appearance(),
         title: store.reader.title,
         // Surface the live cadence, but only at quantized speed steps so reader ticks do not
         // force a screen refresh every 20 ms on the Sharp panel path.
@@ -297,45 +660,145 @@ pub fn select_reader(store: &Store) -> ReaderScreenModel {
         right_word: stage_token.right,
         preview,
         font: stage_token.font,
+        wrap_index: stage_token.wrap_index,
+        highlight_anchor: store.settings.orp_highlight,
+        pivot_style: if store.settings.orp_highlight {
+            FontStyle::Bold
+        } else {
+            FontStyle::Regular
+        },
+        bold_lead_chars: if store.settings.bionic_reading {
+            bionic_lead_chars(stage_token.left.as_str(), stage_token.right.as_str())
+        } else {
+            0
+        },
+        rtl_word: stage_token.rtl,
+        rtl_paragraph: is_rtl_text(preview.as_str()),
         progress_width: store.reader.progress_width_px(),
-        show_chat_badge: matches!(store.reader.mode, ReaderMode::Chat),
+        badge_label: match store.reader.mode {
+            ReaderMode::Normal | ReaderMode::Chat if store.reader.is_stream_stalled() => {
+                Some("RETRY")
+            }
+            _ if store.reader.countdown_remaining() > 0 => COUNTDOWN_BADGE_LABELS
+                .get(store.reader.countdown_remaining() as usize - 1)
+                .copied(),
+            ReaderMode::Chat => Some("CHAT"),
+            ReaderMode::WordScrub => Some("SCRUB"),
+            _ => None,
+        },
         modal: reader_modal_model(store),
     }
 }
 
 fn reader_modal_model(store: &Store) -> Option<ReaderModalModel> {
     match store.reader.mode {
-        ReaderMode::Paused => Some(ReaderModalModel::Pause([
-            PauseActionModel {
-                label: "RESUME RSVP",
-                action: "",
-                selected: matches!(store.reader.pause.selected_row, PauseMenuRow::ResumeRsvp),
-                enabled: true,
-            },
-            PauseActionModel {
-                label: "PARAGRAPH VIEW",
-                action: "",
-                selected: matches!(store.reader.pause.selected_row, PauseMenuRow::ParagraphView),
-                enabled: true,
-            },
-            PauseActionModel {
-                label: "ARTICLE",
-                action: pause_save_action_label(store),
-                selected: matches!(store.reader.pause.selected_row, PauseMenuRow::SaveArticle),
-                enabled: pause_save_action_enabled(store),
-            },
-            PauseActionModel {
-                label: "SOURCE",
-                action: pause_subscription_action_label(store),
-                selected: matches!(store.reader.pause.selected_row, PauseMenuRow::Subscription),
-                enabled: pause_subscription_action_enabled(store),
-            },
-        ])),
+        ReaderMode::Paused => Some(ReaderModalModel::Pause(PauseModalModel {
+            actions: pause_actions(store),
+            session_label: session_time_label(store.reader.session_reading_ms),
+            remaining_label: remaining_time_label(
+                store
+                    .reader
+                    .remaining_reading_ms(store.settings.reading_speed_wpm),
+            ),
+            goal_permille: store.settings.goal_permille(),
+        })),
         ReaderMode::LoadingContent => Some(ReaderModalModel::Loading(loading_modal_model(store))),
         _ => None,
     }
 }
 
+fn pause_actions(store: &Store) -> [PauseActionModel; PAUSE_ACTION_COUNT] {
+    [
+        PauseActionModel {
+            label: "RESUME RSVP",
+            action: "",
+            selected: matches!(store.reader.pause.selected_row, PauseMenuRow::ResumeRsvp),
+            enabled: true,
+        },
+        PauseActionModel {
+            label: "PARAGRAPH VIEW",
+            action: "",
+            selected: matches!(store.reader.pause.selected_row, PauseMenuRow::ParagraphView),
+            enabled: true,
+        },
+        PauseActionModel {
+            label: "PAGE VIEW",
+            action: "",
+            selected: matches!(store.reader.pause.selected_row, PauseMenuRow::PageView),
+            enabled: true,
+        },
+        PauseActionModel {
+            label: "SCRUB WORDS",
+            action: "",
+            selected: matches!(store.reader.pause.selected_row, PauseMenuRow::ScrubWords),
+            enabled: true,
+        },
+        PauseActionModel {
+            label: "ARTICLE",
+            action: pause_save_action_label(store),
+            selected: matches!(store.reader.pause.selected_row, PauseMenuRow::SaveArticle),
+            enabled: pause_save_action_enabled(store),
+        },
+        PauseActionModel {
+            label: "SOURCE",
+            action: pause_subscription_action_label(store),
+            selected: matches!(store.reader.pause.selected_row, PauseMenuRow::Subscription),
+            enabled: pause_subscription_action_enabled(store),
+        },
+        PauseActionModel {
+            label: "DEFINE WORD",
+            action: "",
+            selected: matches!(store.reader.pause.selected_row, PauseMenuRow::DefineWord),
+            enabled: pause_backend_actions_available(store)
+                && !store.reader.current_unit().lookup_word().is_empty(),
+        },
+    ]
+}
+
+fn session_time_label(session_reading_ms: u64) -> InlineText<PAUSE_STATUS_MAX_BYTES> {
+    let mut label = InlineText::from_slice("READ ");
+    push_u32(
+        &mut label,
+        (session_reading_ms / 60_000).min(u32::MAX as u64) as u32,
+    );
+    let _ = label.try_push_str(" MIN");
+    label
+}
+
+fn remaining_time_label(remaining_ms: u64) -> InlineText<PAUSE_STATUS_MAX_BYTES> {
+    let minutes = remaining_ms.div_ceil(60_000).min(u32::MAX as u64) as u32;
+    let mut label = InlineText::from_slice("~");
+    if minutes < 60 {
+        push_u32(&mut label, minutes);
+        let _ = label.try_push_str(" MIN LEFT");
+    } else {
+        let tenths_of_hours = minutes.saturating_mul(10).saturating_add(30) / 60;
+        push_u32(&mut label, tenths_of_hours / 10);
+        let _ = label.try_push_char('.');
+        push_u32(&mut label, tenths_of_hours % 10);
+        let _ = label.try_push_str(" H LEFT");
+    }
+    label
+}
+
+fn push_u32<const N: usize>(target: &mut InlineText<N>, value: u32) {
+    let mut digits = [0u8; 10];
+    let mut remaining = value;
+    let mut count = 0;
+    loop {
+        digits[count] = b'0' + (remaining % 10) as u8;
+        count += 1;
+        remaining /= 10;
+        if remaining == 0 {
+            break;
+        }
+    }
+    while count > 0 {
+        count -= 1;
+        let _ = target.try_push_char(digits[count] as char);
+    }
+}
+
 fn loading_modal_model(store: &Store) -> ReaderLoadingModel {
     ReaderLoadingModel {
         progress_width: store.reader.prepare_display_progress_width_px(214),
@@ -417,6 +880,246 @@ fn pause_subscription_action_label(store: &Store) -> &'static str {
     }
 }
 
+pub fn select_section_break(store: &Store) -> SectionBreakScreenModel {
+    SectionBreakScreenModel {
+        appearance: store.appearance(),
+        title: store.reader.title,
+        label: "NEXT SECTION",
+        heading: store
+            .reader
+            .preview_for_paragraph(store.reader.progress.paragraph_index),
+    }
+}
+
+pub fn select_page(store: &Store) -> PageScreenModel {
+    PageScreenModel {
+        appearance: store.appearance(),
+        title: store.reader.title,
+        lines: store.reader.page().lines,
+        progress_width: store.reader.progress_width_px(),
+    }
+}
+
+// Paragraph previews stand in for chapter titles. The window keeps the cursor in the middle row
+// until it reaches either end of the book.
+pub fn select_contents(store: &Store) -> ContentsScreenModel {
+    let reader = &store.reader;
+    let total = reader.progress.total_paragraphs.max(1);
+    let selected_index = reader.contents_index.clamp(1, total);
+    let current = reader.progress.paragraph_index.max(1);
+    let first = selected_index
+        .saturating_sub(CONTENTS_VISIBLE_ROWS as u16 / 2)
+        .min(total.saturating_sub(CONTENTS_VISIBLE_ROWS as u16 - 1))
+        .max(1);
+    let rows = core::array::from_fn(|slot| {
+        let number = first + slot as u16;
+        if number > total {
+            return ContentsRowModel::default();
+        }
+        ContentsRowModel {
+            number,
+            preview: reader.preview_for_paragraph(number),
+            minutes: reader.paragraph_reading_minutes(number, store.settings.reading_speed_wpm),
+            marker: match number.cmp(&current) {
+                core::cmp::Ordering::Less => ContentsMarker::Read,
+                core::cmp::Ordering::Equal => ContentsMarker::Current,
+                core::cmp::Ordering::Greater => ContentsMarker::Unread,
+            },
+            selected: number == selected_index,
+        }
+    });
+
+    ContentsScreenModel {
+        appearance: store.appearance(),
+        title: reader.title,
+        selected_index,
+        total,
+        first: first - 1,
+        rows,
+        bookmarks: false,
+    }
+}
+
+// The contents list cut down to marked paragraphs; the row numbers stay paragraph numbers and the
+// footer counts marks.
+pub fn select_bookmarks(store: &Store) -> ContentsScreenModel {
+    let reader = &store.reader;
+    let (paragraphs, count) = store.reader_bookmarks();
+    let selected = usize::from(reader.bookmarks_index).min(count.saturating_sub(1));
+    let current = reader.progress.paragraph_index.max(1);
+    let first = selected
+        .saturating_sub(CONTENTS_VISIBLE_ROWS / 2)
+        .min(count.saturating_sub(CONTENTS_VISIBLE_ROWS));
+    let rows = core::array::from_fn(|slot| {
+        let Some(&number) = paragraphs[..count].get(first + slot) else {
+            return ContentsRowModel::default();
+        };
+        ContentsRowModel {
+            number,
+            preview: reader.preview_for_paragraph(number),
+            minutes: reader.paragraph_reading_minutes(number, store.settings.reading_speed_wpm),
+            marker: match number.cmp(&current) {
+                core::cmp::Ordering::Less => ContentsMarker::Read,
+                core::cmp::Ordering::Equal => ContentsMarker::Current,
+                core::cmp::Ordering::Greater => ContentsMarker::Unread,
+            },
+            selected: first + slot == selected,
+        }
+    });
+
+    ContentsScreenModel {
+        appearance: store.appearance(),
+        title: reader.title,
+        selected_index: if count == 0 { 0 } else { selected as u16 + 1 },
+        total: count as u16,
+        first: first as u16,
+        rows,
+        bookmarks: true,
+    }
+}
+
+pub fn select_definition(store: &Store) -> DefinitionScreenModel {
+    let definition = &store.reader.definition;
+    let text = definition.definition.as_str();
+    let mut lines = [InlineText::new(); DEFINITION_VISIBLE_LINES];
+    let mut line_index = 0usize;
+    let mut visible_index = 0usize;
+    let mut start = 0usize;
+
+    while let Some((end, next)) = next_definition_line(text, start) {
+        if line_index >= definition.scroll_line as usize {
+            if visible_index >= DEFINITION_VISIBLE_LINES {
+                break;
+            }
+            lines[visible_index].set_truncated(text[start..end].trim());
+            visible_index += 1;
+        }
+        line_index += 1;
+        start = next;
+    }
+
+    DefinitionScreenModel {
+        appearance: store.appearance(),
+        word: definition.word,
+        part_of_speech: definition.part_of_speech,
+        status_label: match definition.status {
+            ReaderDefinitionStatus::Loading => Some("LOOKING UP..."),
+            ReaderDefinitionStatus::Failed => Some("NO DEFINITION FOUND"),
+            ReaderDefinitionStatus::Ready if text.is_empty() => Some("NO DEFINITION FOUND"),
+            ReaderDefinitionStatus::Ready | ReaderDefinitionStatus::Idle => None,
+        },
+        lines,
+        show_scroll_up: definition.scroll_line > 0,
+        show_scroll_down: definition.scroll_line < definition.max_scroll_line(),
+    }
+}
+
+fn select_crash_report(store: &Store, report: &str) -> CrashReportScreenModel {
+    let mut lines = [InlineText::new(); DEFINITION_VISIBLE_LINES];
+    let mut start = 0usize;
+    for line in lines.iter_mut() {
+        let Some((end, next)) = next_definition_line(report, start) else {
+            break;
+        };
+        line.set_truncated(report[start..end].trim());
+        start = next;
+    }
+
+    let mut boot_label = InlineText::new();
+    let _ = boot_label.try_push_str("BOOT ");
+    push_u32(&mut boot_label, store.device.boot_count);
+
+    CrashReportScreenModel {
+        appearance: store.appearance(),
+        lines,
+        boot_label,
+    }
+}
+
+fn select_low_battery(store: &Store) -> LowBatteryScreenModel {
+    let mut body = InlineText::new();
+    push_u32(&mut body, u32::from(store.power.battery_percent));
+    let _ = body.try_push_str("% LEFT - CHARGE SOON");
+
+    LowBatteryScreenModel {
+        appearance: store.appearance(),
+        status: select_status(store),
+        body,
+    }
+}
+
+fn select_onboarding(store: &Store, onboarding: OnboardingState) -> OnboardingScreenModel {
+    let (prompt, value) = match onboarding.step {
+        OnboardingStep::Encoder => (
+            ONBOARDING_ENCODER_PROMPT,
+            match (
+                onboarding.direction_confirmed,
+                store.settings.encoder_reversed,
+            ) {
+                (false, _) => "",
+                (true, false) => "NORMAL",
+                (true, true) => "REVERSED",
+            },
+        ),
+        OnboardingStep::ReadingSpeed => (
+            ONBOARDING_SPEED_PROMPT,
+            store.settings.reading_speed_label(),
+        ),
+        OnboardingStep::Wifi => (
+            if matches!(store.network.status, NetworkStatus::Disabled) {
+                ONBOARDING_WIFI_DISABLED_PROMPT
+            } else {
+                ONBOARDING_WIFI_PROMPT
+            },
+            store.network.status.label(),
+        ),
+        OnboardingStep::StorageCard => (
+            ONBOARDING_CARD_PROMPT,
+            if store.storage.sd_card_ready {
+                "FOUND"
+            } else {
+                "NOT FOUND"
+            },
+        ),
+    };
+
+    OnboardingScreenModel {
+        appearance: store.appearance(),
+        step: onboarding.step,
+        title: onboarding.step.title(),
+        prompt,
+        value,
+        demo_word: matches!(onboarding.step, OnboardingStep::ReadingSpeed)
+            .then(|| onboarding.demo_word()),
+    }
+}
+
+fn select_screensaver(store: &Store) -> ScreensaverScreenModel {
+    let content_id = store.reader.recent_content_id();
+    let progress_percent = if content_id.is_empty() {
+        0
+    } else if store.reader.active_content_id.is_empty() {
+        store
+            .reading_progress
+            .find_by_content_id(content_id)
+            .map(ReadingProgressEntry::completion_percent)
+            .unwrap_or(0)
+    } else {
+        store.reader.progress.completion_percent
+    };
+
+    ScreensaverScreenModel {
+        appearance: store.appearance(),
+        clock: select_status(store).clock,
+        title: if content_id.is_empty() {
+            InlineText::new()
+        } else {
+            store.reader.title
+        },
+        progress_percent,
+    }
+}
+
 pub fn select_paragraph_navigation(store: &Store) -> ParagraphNavigationModel {
     let current_index = store.reader.progress.paragraph_index as usize;
     let total = store.reader.progress.total_paragraphs;
@@ -448,7 +1151,7 @@ pub fn select_paragraph_navigation(store: &Store) -> ParagraphNavigationModel {
     let tick_index = paragraph_tick_index(store.reader.progress.paragraph_index, total);
 
     ParagraphNavigationModel {
-        appearance: store.settings.appearance,
+        appearance: store.appearance(),
         title: store.reader.title,
         current_index: store.reader.progress.paragraph_index,
         total,
@@ -472,68 +1175,177 @@ fn paragraph_tick_index(current_index: u16, total: u16) -> u8 {
 }
 
 pub fn select_settings(store: &Store) -> SettingsScreenModel {
-    let rows = [
-        SettingsRowModel {
-            label: "Reading Speed",
-            value: Some(store.settings.reading_speed_label()),
-            selected: matches!(store.ui.settings_row, crate::ui::SettingsRow::ReadingSpeed),
-            show_arrow: false,
-        },
-        SettingsRowModel {
-            label: "Appearance",
-            value: Some(store.settings.appearance.label()),
-            selected: matches!(store.ui.settings_row, crate::ui::SettingsRow::Appearance),
-            show_arrow: false,
-        },
-        SettingsRowModel {
-            label: "Refresh Data",
-            value: None,
-            selected: matches!(store.ui.settings_row, crate::ui::SettingsRow::RefreshData),
-            show_arrow: false,
-        },
-        SettingsRowModel {
-            label: "Topic Preferences",
-            value: None,
-            selected: matches!(
-                store.ui.settings_row,
-                crate::ui::SettingsRow::TopicPreferences
-            ),
-            show_arrow: true,
-        },
-        SettingsRowModel {
-            label: "Network Connection",
-            value: Some(store.network.status.label()),
-            selected: matches!(
-                store.ui.settings_row,
-                crate::ui::SettingsRow::NetworkConnection
-            ),
-            show_arrow: false,
-        },
-        SettingsRowModel {
-            label: "Connect Account",
-            value: None,
-            selected: matches!(
-                store.ui.settings_row,
-                crate::ui::SettingsRow::ConnectAccount
-            ),
-            show_arrow: true,
-        },
-    ];
+    let selected_index = store.ui.settings_row.index();
+    let first_visible = selected_index
+        .saturating_sub(SETTINGS_ROW_COUNT - 1)
+        .min(SettingsRow::COUNT.saturating_sub(SETTINGS_ROW_COUNT));
+    let rows = core::array::from_fn(|offset| {
+        settings_row_model(store, SettingsRow::from_index(first_visible + offset))
+    });
 
     SettingsScreenModel {
-        appearance: store.settings.appearance,
+        appearance: store.appearance(),
+        status: select_status(store),
         title: match store.ui.settings_mode {
             SettingsMode::TopicPreferences => "TOPIC PREFERENCES",
-            _ => "GENERAL SETTINGS",
+            _ => store.ui.settings_row.section().title(),
         },
         mode: store.ui.settings_mode,
         rows,
-        refresh_title: matches!(store.ui.settings_mode, SettingsMode::RefreshLoading)
-            .then_some("REFRESHING DATA"),
-        refresh_body: matches!(store.ui.settings_mode, SettingsMode::RefreshLoading)
-            .then_some("This may take a moment."),
+        refresh_title: match store.ui.settings_mode {
+            SettingsMode::RefreshLoading => Some("REFRESHING DATA"),
+            _ => None,
+        },
+        refresh_body: match store.ui.settings_mode {
+            SettingsMode::RefreshLoading => Some("This may take a moment."),
+            _ => None,
+        },
         topic_preferences: matches!(store.ui.settings_mode, SettingsMode::TopicPreferences)
             .then_some(select_topic_preferences(store)),
+        preview_word: matches!(store.ui.settings_mode, SettingsMode::SpeedEdit)
+            .then(|| store.ui.speed_preview.word()),
+    }
+}
+
+pub fn select_diagnostics(store: &Store) -> DiagnosticsScreenModel {
+    let sample = store.diagnostics;
+    // Peak and size come from the same stack painting, so either both are known or neither is.
+    let mut stack = diagnostics_value(sample.stack_peak_bytes.div_ceil(1024), " / ");
+    if sample.stack_peak_bytes != 0 {
+        push_u32(&mut stack, sample.stack_size_bytes / 1024);
+        let _ = stack.try_push_str(" KB");
+    }
+
+    DiagnosticsScreenModel {
+        appearance: store.appearance(),
+        rows: [
+            DiagnosticsRowModel {
+                label: "Firmware",
+                value: diagnostics_firmware(sample),
+            },
+            DiagnosticsRowModel {
+                label: "Free heap",
+                value: diagnostics_value(sample.heap_free_bytes / 1024, " KB"),
+            },
+            DiagnosticsRowModel {
+                label: "Min free heap",
+                value: diagnostics_value(sample.heap_min_free_bytes / 1024, " KB"),
+            },
+            DiagnosticsRowModel {
+                label: "Largest region",
+                value: diagnostics_value(sample.largest_free_region_bytes / 1024, " KB"),
+            },
+            DiagnosticsRowModel {
+                label: "Stack peak",
+                value: stack,
+            },
+            DiagnosticsRowModel {
+                label: "SD clock",
+                value: diagnostics_value(sample.sd_clock_hz / 1_000_000, " MHz"),
+            },
+            DiagnosticsRowModel {
+                label: "Render",
+                value: diagnostics_timing(sample.perf.render),
+            },
+            DiagnosticsRowModel {
+                label: "Frame flush",
+                value: diagnostics_timing(sample.perf.flush),
+            },
+            DiagnosticsRowModel {
+                label: "SD refill",
+                value: diagnostics_timing(sample.perf.refill),
+            },
+            DiagnosticsRowModel {
+                label: "Words/s",
+                value: diagnostics_value(u32::from(sample.perf.words_per_second), ""),
+            },
+        ],
+    }
+}
+
+fn diagnostics_firmware(sample: DiagnosticsSnapshot) -> InlineText<DIAGNOSTICS_VALUE_MAX_BYTES> {
+    if sample.firmware_version.is_empty() {
+        return InlineText::from_slice("--");
+    }
+    InlineText::from_slice(sample.firmware_version)
+}
+
+// Mean and worst case, which is what a regression moves first.
+fn diagnostics_timing(stat: PerfStat) -> InlineText<DIAGNOSTICS_VALUE_MAX_BYTES> {
+    let mut label = diagnostics_value(stat.mean_us(), " / ");
+    if stat.mean_us() != 0 {
+        push_u32(&mut label, stat.max_us);
+        let _ = label.try_push_str(" us");
+    }
+    label
+}
+
+// Zero means "not measured yet", so it reads as a dash rather than a suspicious 0.
+fn diagnostics_value(value: u32, unit: &str) -> InlineText<DIAGNOSTICS_VALUE_MAX_BYTES> {
+    let mut label = InlineText::new();
+    if value == 0 {
+        let _ = label.try_push_str("--");
+        return label;
+    }
+    push_u32(&mut label, value);
+    let _ = label.try_push_str(unit);
+    label
+}
+
+// Newest session first.
+pub fn select_history(store: &Store) -> HistoryScreenModel {
+    let history = &store.history;
+    let count = history.len();
+    let selected = store.ui.history_index.min(count.saturating_sub(1));
+    let first = selected
+        .saturating_sub(HISTORY_VISIBLE_ROWS / 2)
+        .min(count.saturating_sub(HISTORY_VISIBLE_ROWS));
+    let rows = core::array::from_fn(|slot| {
+        let Some(entry) = history.newest(first + slot) else {
+            return HistoryRowModel::default();
+        };
+        HistoryRowModel {
+            title: entry.title,
+            when: history_when(entry.day(), store.local_day),
+            words: entry.words,
+            selected: first + slot == selected,
+        }
+    });
+
+    HistoryScreenModel {
+        appearance: store.appearance(),
+        streak_days: store.local_day.map(|today| history.streak_days(today)),
+        total_words: history.total_words(),
+        selected_index: selected as u16,
+        total: count as u16,
+        first: first as u16,
+        rows,
+    }
+}
+
+fn history_when(day: Option<u32>, today: Option<u32>) -> InlineText<HISTORY_WHEN_MAX_BYTES> {
+    let (Some(day), Some(today)) = (day, today) else {
+        return InlineText::from_slice("--");
+    };
+    match today.saturating_sub(day) {
+        0 => InlineText::from_slice("TODAY"),
+        1 => InlineText::from_slice("YDAY"),
+        days => {
+            let mut label = InlineText::new();
+            push_u32(&mut label, days.min(999));
+            let _ = label.try_push_str("D AGO");
+            label
+        }
+    }
+}
+
+fn settings_row_model(store: &Store, row: SettingsRow) -> SettingsRowModel {
+    let entry = row.entry();
+    SettingsRowModel {
+        label: entry.label,
+        value: (entry.value)(store),
+        selected: store.ui.settings_row == row,
+        show_arrow: entry.show_arrow,
     }
 }
 
@@ -585,12 +1397,14 @@ fn select_topic_preferences(store: &Store) -> TopicPreferencesModel {
 fn select_collection_rows(
     content: &ContentState,
     reading_progress: &ReadingProgressState,
+    book_stats: &BookStatsState,
     kind: CollectionKind,
     selected_index: usize,
 ) -> [ContentRowModel; VISIBLE_LIST_ROWS] {
     select_manifest_collection_rows(
         content.collection_state(kind),
         reading_progress,
+        book_stats,
         kind,
         selected_index,
     )
@@ -617,6 +1431,8 @@ fn select_recommendation_rows(store: &Store) -> [ContentRowModel; VISIBLE_LIST_R
                 meta: topic_label,
                 title: InlineText::from_slice("Loading articles..."),
                 progress_badge: None,
+                progress_percent: 0,
+                finished: false,
                 is_fetching: false,
                 selected: true,
             },
@@ -647,6 +1463,8 @@ fn select_recommendation_rows(store: &Store) -> [ContentRowModel; VISIBLE_LIST_R
                 meta: topic_label,
                 title: InlineText::from_slice("No articles for this topic yet"),
                 progress_badge: None,
+                progress_percent: 0,
+                finished: false,
                 is_fetching: false,
                 selected: true,
             },
@@ -657,6 +1475,7 @@ fn select_recommendation_rows(store: &Store) -> [ContentRowModel; VISIBLE_LIST_R
     select_manifest_collection_rows(
         collection,
         &store.reading_progress,
+        &store.book_stats,
         CollectionKind::Recommendations,
         store.ui.recommendations_index,
     )
@@ -758,6 +1577,7 @@ fn recommendation_topic_meta(
 fn select_manifest_collection_rows(
     collection: &CollectionManifestState,
     reading_progress: &ReadingProgressState,
+    book_stats: &BookStatsState,
     kind: CollectionKind,
     selected_index: usize,
 ) -> [ContentRowModel; VISIBLE_LIST_ROWS] {
@@ -773,19 +1593,53 @@ fn select_manifest_collection_rows(
 
     [
         previous
-            .map(|item| content_row_from_manifest(item, reading_progress, kind, false))
+            .map(|item| content_row_from_manifest(item, reading_progress, book_stats, kind, false))
             .unwrap_or_else(empty_content_row),
-        content_row_from_manifest(selected, reading_progress, kind, true),
-        next.map(|item| content_row_from_manifest(item, reading_progress, kind, false))
+        content_row_from_manifest(selected, reading_progress, book_stats, kind, true),
+        next.map(|item| content_row_from_manifest(item, reading_progress, book_stats, kind, false))
             .unwrap_or_else(empty_content_row),
     ]
 }
 
+// The page of covers holding the selection, for Saved and Inbox when the grid layout is on.
+fn select_collection_grid(
+    store: &Store,
+    kind: CollectionKind,
+    selected_index: usize,
+) -> Option<[ContentRowModel; LIBRARY_GRID_CELLS]> {
+    if !store.settings.library_grid || matches!(kind, CollectionKind::Recommendations) {
+        return None;
+    }
+    let collection = store.content().collection_state(kind);
+    if collection.is_empty() {
+        return None;
+    }
+
+    let selected_index = selected_index.min(collection.len() - 1);
+    let page_start = selected_index - selected_index % LIBRARY_GRID_CELLS;
+    let mut cells = [empty_content_row(); LIBRARY_GRID_CELLS];
+    for (offset, cell) in cells.iter_mut().enumerate() {
+        let index = page_start + offset;
+        if let Some(item) = collection.item_at(index) {
+            *cell = content_row_from_manifest(
+                item,
+                &store.reading_progress,
+                &store.book_stats,
+                kind,
+                index == selected_index,
+            );
+        }
+    }
+    Some(cells)
+}
+
 fn content_row(meta: &str, title: &str, selected: bool) -> ContentRowModel {
     ContentRowModel {
         meta: InlineText::from_slice(meta),
         title: InlineText::from_slice(title),
         progress_badge: None,
+        progress_percent: 0,
+        finished: false,
         is_fetching: false,
         selected,
     }
@@ -814,6 +1668,7 @@ fn dashboard_empty_item() -> DashboardItemModel {
 fn content_row_from_manifest(
     item: CollectionManifestItem,
     reading_progress: &ReadingProgressState,
+    book_stats: &BookStatsState,
     kind: CollectionKind,
     selected: bool,
 ) -> ContentRowModel {
@@ -822,6 +1677,8 @@ fn content_row_from_manifest(
         meta: content_row_meta(kind, item),
         title: item.title,
         progress_badge: row_progress_badge(kind, item, reading_progress),
+        progress_percent: row_progress_percent(item, reading_progress),
+        finished: book_stats.is_finished(&item.content_id),
         is_fetching,
         selected,
     }
@@ -888,6 +1745,22 @@ fn row_progress_badge(
     progress_badge_label(reading_progress.entry_for_item(item)?)
 }
 
+// From the saved paragraph against the book's paragraph total, the same position the reader
+// resumes from, so it moves whenever a position is recorded.
+fn row_progress_percent(
+    item: CollectionManifestItem,
+    reading_progress: &ReadingProgressState,
+) -> u8 {
+    if matches!(item.package_state, PackageState::Fetching) {
+        return 0;
+    }
+
+    reading_progress
+        .entry_for_item(item)
+        .map(ReadingProgressEntry::completion_percent)
+        .unwrap_or(0)
+}
+
 fn progress_badge_label(entry: ReadingProgressEntry) -> Option<InlineText<8>> {
     let percent = entry.completion_percent();
     if percent == 0 {
@@ -934,10 +1807,13 @@ fn empty_collection_rows(kind: CollectionKind) -> [ContentRowModel; VISIBLE_LIST
     }
 }
 
-fn select_status(store: &Store) -> StatusClusterModel {
-    StatusClusterModel {
+fn select_status(store: &Store) -> StatusBarModel {
+    StatusBarModel {
         battery_percent: store.power.battery_percent,
+        charging: store.power.charging,
         network: store.network.status,
+        sd_card_ready: store.storage.sd_card_ready,
+        clock: store.local_time.map(LocalTime::label),
     }
 }
 
@@ -1142,6 +2018,44 @@ mod tests {
         assert!(last.final_excerpt.is_empty());
     }
 
+    #[test]
+    fn definition_selector_wraps_visible_lines_from_scroll_offset() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Reader;
+        store.reader.mode = ReaderMode::Definition;
+        store.reader.definition.status = ReaderDefinitionStatus::Ready;
+        store.reader.definition.word = InlineText::from_slice("ephemeral");
+        store.reader.definition.definition = InlineText::from_slice(
+            "Lasting for a very short time; fleeting. Used of plants and insects that live \
+             for a single day, and more broadly of fashions, fame, and the small pleasures \
+             of an ordinary afternoon.",
+        );
+        store.reader.definition.scroll_line = 1;
+
+        let ActiveScreenModel::Definition(model) = select_active_screen(&store) else {
+            panic!("expected definition screen");
+        };
+
+        assert_eq!(model.word.as_str(), "ephemeral");
+        assert_eq!(model.status_label, None);
+        assert!(model.show_scroll_up);
+        assert!(!model.lines[0].is_empty());
+        assert!(!model.lines[0].as_str().starts_with("Lasting"));
+    }
+
+    #[test]
+    fn definition_selector_reports_loading_state() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Reader;
+        store.reader.mode = ReaderMode::Definition;
+        store.reader.definition.status = ReaderDefinitionStatus::Loading;
+
+        let model = select_definition(&store);
+
+        assert_eq!(model.status_label, Some("LOOKING UP..."));
+        assert!(!model.show_scroll_down);
+    }
+
     #[test]
     fn reader_selector_uses_live_rsvp_stage() {
         let mut store = Store::new();
@@ -1167,6 +2081,17 @@ mod tests {
         assert_eq!(model.wpm, 200);
         assert!(!model.right_word.is_empty());
         assert!(!model.preview.is_empty());
+        assert!(!model.highlight_anchor);
+
+        assert_eq!(model.pivot_style, FontStyle::Regular);
+        assert_eq!(model.bold_lead_chars, 0);
+
+        store.settings.toggle_orp_highlight();
+        store.settings.toggle_bionic_reading();
+        let model = select_reader(&store);
+        assert!(model.highlight_anchor);
+        assert_eq!(model.pivot_style, FontStyle::Bold);
+        assert!(model.bold_lead_chars > 0);
     }
 
     #[test]
@@ -1193,15 +2118,42 @@ mod tests {
         assert_eq!(model.wpm, 200);
     }
 
+    #[test]
+    fn remaining_time_label_switches_to_hours_past_an_hour() {
+        assert_eq!(remaining_time_label(0).as_str(), "~0 MIN LEFT");
+        assert_eq!(
+            remaining_time_label(41 * 60_000 + 1).as_str(),
+            "~42 MIN LEFT"
+        );
+        assert_eq!(remaining_time_label(192 * 60_000).as_str(), "~3.2 H LEFT");
+    }
+
     #[test]
     fn settings_selector_surfaces_network_status_value() {
         let mut store = Store::new();
         store.network.status = NetworkStatus::ProbeFailed;
+        store.ui.settings_row = crate::ui::SettingsRow::NetworkConnection;
+
+        let model = select_settings(&store);
+
+        assert_eq!(model.rows[5].value, Some("Probe Failed"));
+        assert!(!model.rows[5].show_arrow);
+    }
+
+    #[test]
+    fn settings_selector_scrolls_to_keep_the_selected_row_visible() {
+        let mut store = Store::new();
+        store.ui.settings_row = crate::ui::SettingsRow::ConnectAccount;
 
         let model = select_settings(&store);
 
-        assert_eq!(model.rows[4].value, Some("Probe Failed"));
-        assert!(!model.rows[4].show_arrow);
+        assert_eq!(model.rows[0].label, "Encoder Direction");
+        assert_eq!(model.rows[0].value, Some("NORMAL"));
+        assert_eq!(model.rows[1].label, "Encoder Sensitivity");
+        assert_eq!(model.rows[1].value, Some("NORMAL"));
+        assert!(model.rows[SETTINGS_ROW_COUNT - 1].selected);
+        assert_eq!(model.rows[SETTINGS_ROW_COUNT - 1].label, "Connect Account");
+        assert_eq!(model.title, "NETWORK SETTINGS");
     }
 
     #[test]
@@ -1223,6 +2175,42 @@ mod tests {
         assert!(!model.rows[1].is_fetching);
     }
 
+    #[test]
+    fn library_grid_pages_saved_covers_around_the_selection() {
+        let mut store = Store::new();
+        for index in 0..8u8 {
+            let mut item = CollectionManifestItem::empty();
+            item.title
+                .set_truncated(char::from(b'A' + index).encode_utf8(&mut [0; 4]));
+            let _ = store
+                .content_mut()
+                .collection_state_mut(CollectionKind::Saved)
+                .try_push(item);
+        }
+        store.ui.saved_index = 7;
+
+        assert!(
+            select_collection(&store, CollectionKind::Saved)
+                .grid
+                .is_none()
+        );
+
+        store.settings.toggle_library_grid();
+        let grid = select_collection(&store, CollectionKind::Saved)
+            .grid
+            .expect("grid layout");
+
+        assert_eq!(grid[0].title.as_str(), "G");
+        assert_eq!(grid[1].title.as_str(), "H");
+        assert!(grid[1].selected && !grid[0].selected);
+        assert!(grid[2..].iter().all(|cell| cell.title.is_empty()));
+        assert!(
+            select_collection(&store, CollectionKind::Recommendations)
+                .grid
+                .is_none()
+        );
+    }
+
     #[test]
     fn inbox_collection_selector_uses_live_manifest_without_inbox_suffix() {
         let mut store = Store::new();
@@ -1421,6 +2409,7 @@ mod tests {
         let model = select_collection(&store, CollectionKind::Saved);
 
         assert_eq!(model.rows[1].progress_badge.unwrap().as_str(), "25%");
+        assert_eq!(model.rows[1].progress_percent, 25);
     }
 
     #[test]
@@ -1504,6 +2493,7 @@ mod tests {
         assert!(model.rows[1].is_fetching);
         assert_eq!(model.rows[1].meta.as_str(), "EXAMPLE / FETCHING");
         assert_eq!(model.rows[1].progress_badge, None);
+        assert_eq!(model.rows[1].progress_percent, 0);
     }
 
     #[test]
@@ -1688,4 +2678,17 @@ mod tests {
             "25%"
         );
     }
+
+    #[test]
+    fn crash_report_shows_the_boot_count() {
+        let mut store = Store::new();
+        store.crash_report = Some(InlineText::from_slice("src/main.rs:1: boom"));
+        store.device = store.device.with_boot_count(42);
+
+        let ActiveScreenModel::CrashReport(model) = select_active_screen(&store) else {
+            panic!("expected crash report");
+        };
+        assert_eq!(model.boot_label.as_str(), "BOOT 42");
+        assert_eq!(model.lines[0].as_str(), "src/main.rs:1: boom");
+    }
 }
@@ -1,3 +1,9 @@
+use crate::audio::AudioVolume;
+use crate::formatter::{
+    ChunkingMode, DEFAULT_MAX_DWELL_MS, DEFAULT_MIN_DWELL_MS, DwellMode, DwellTiming,
+    FormatPreferences, ReferenceMode,
+};
+use crate::input::EncoderSensitivity;
 use crate::sleep::DEFAULT_INACTIVITY_TIMEOUT_MS;
 
 pub const TOPIC_CATEGORY_COUNT: usize = 4;
@@ -7,6 +13,26 @@ pub const MIN_READING_SPEED_WPM: u16 = 200;
 pub const MAX_READING_SPEED_WPM: u16 = 360;
 pub const READING_SPEED_STEP_WPM: u16 = 20;
 pub const REFRESH_LOADING_DURATION_MS: u64 = 720;
+pub const REWIND_WORD_OPTIONS: [u8; 4] = [0, 3, 5, 10];
+pub const READING_GOAL_MINUTE_OPTIONS: [u8; 4] = [0, 10, 20, 30];
+pub const SCREENSAVER_MINUTE_OPTIONS: [u8; 4] = [0, 2, 5, 10];
+pub const COUNTDOWN_SECONDS_OPTIONS: [u8; 3] = [0, 3, 5];
+pub const ORP_ANCHOR_PERCENT_OPTIONS: [u8; 5] = [0, 20, 30, 40, 50];
+// Bounds every word's time is clamped to, whichever dwell mode is on. Stored in 10 ms and 100 ms
+// steps.
+pub const MIN_DWELL_OPTIONS_MS: [u16; 4] = [DEFAULT_MIN_DWELL_MS, 120, 160, 200];
+pub const MAX_DWELL_OPTIONS_MS: [u16; 4] = [1_000, 1_500, DEFAULT_MAX_DWELL_MS, 3_000];
+// An anchor percent of 0 keeps the length-based anchor.
+pub const DEFAULT_COUNTDOWN_SECONDS: u8 = 0;
+pub const DEFAULT_ORP_ANCHOR_PERCENT: u8 = 0;
+// Fixed dot and comma pauses step by 40 ms; 0 leaves the formatter's own pause weights in charge.
+pub const PAUSE_MS_STEP: u16 = 40;
+pub const MAX_PAUSE_MS: u16 = 480;
+const PAUSE_MS_LABELS: [&str; (MAX_PAUSE_MS / PAUSE_MS_STEP) as usize + 1] = [
+    "AUTO", "40 MS", "80 MS", "120 MS", "160 MS", "200 MS", "240 MS", "280 MS", "320 MS", "360 MS",
+    "400 MS", "440 MS", "480 MS",
+];
+pub const PROFILE_COUNT: u8 = 3;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct PersistedSettings {
@@ -14,6 +40,32 @@ pub struct PersistedSettings {
     pub reading_speed_wpm: u16,
     pub appearance: AppearanceMode,
     pub topics: TopicPreferences,
+    pub dwell_mode: DwellMode,
+    pub min_dwell_ms: u16,
+    pub max_dwell_ms: u16,
+    pub chunking: ChunkingMode,
+    pub references: ReferenceMode,
+    pub digit_grouping: bool,
+    pub section_pauses: bool,
+    pub rewind_words: u8,
+    pub orp_highlight: bool,
+    pub bionic_reading: bool,
+    pub library_grid: bool,
+    pub adaptive_speed: bool,
+    pub reading_goal_minutes: u8,
+    pub goal_progress_s: u32,
+    pub sentence_pause_ms: u16,
+    pub clause_pause_ms: u16,
+    pub countdown_seconds: u8,
+    pub orp_anchor_percent: u8,
+    pub encoder_reversed: bool,
+    pub encoder_sensitivity: EncoderSensitivity,
+    pub screensaver_minutes: u8,
+    pub night_mode: bool,
+    pub light_sensor: bool,
+    pub audio_volume: AudioVolume,
+    pub quote_export: bool,
+    pub onboarded: bool,
 }
 
 impl PersistedSettings {
@@ -37,8 +89,155 @@ impl PersistedSettings {
             reading_speed_wpm,
             appearance,
             topics,
+            dwell_mode: DwellMode::Standard,
+            min_dwell_ms: DEFAULT_MIN_DWELL_MS,
+            max_dwell_ms: DEFAULT_MAX_DWELL_MS,
+            chunking: ChunkingMode::SingleWord,
+            references: ReferenceMode::Skip,
+            digit_grouping: false,
+            section_pauses: false,
+            rewind_words: 0,
+            orp_highlight: false,
+            bionic_reading: false,
+            library_grid: false,
+            adaptive_speed: false,
+            reading_goal_minutes: 0,
+            goal_progress_s: 0,
+            sentence_pause_ms: 0,
+            clause_pause_ms: 0,
+            countdown_seconds: DEFAULT_COUNTDOWN_SECONDS,
+            orp_anchor_percent: DEFAULT_ORP_ANCHOR_PERCENT,
+            encoder_reversed: false,
+            encoder_sensitivity: EncoderSensitivity::Normal,
+            screensaver_minutes: 0,
+            night_mode: false,
+            light_sensor: false,
+            audio_volume: AudioVolume::Off,
+            quote_export: false,
+            onboarded: false,
         }
     }
+
+    pub const fn with_dwell_mode(mut self, dwell_mode: DwellMode) -> Self {
+        self.dwell_mode = dwell_mode;
+        self
+    }
+
+    pub const fn with_dwell_bounds(mut self, min_ms: u16, max_ms: u16) -> Self {
+        self.min_dwell_ms = min_ms;
+        self.max_dwell_ms = max_ms;
+        self
+    }
+
+    pub const fn with_chunking(mut self, chunking: ChunkingMode) -> Self {
+        self.chunking = chunking;
+        self
+    }
+
+    pub const fn with_references(mut self, references: ReferenceMode) -> Self {
+        self.references = references;
+        self
+    }
+
+    pub const fn with_digit_grouping(mut self, digit_grouping: bool) -> Self {
+        self.digit_grouping = digit_grouping;
+        self
+    }
+
+    pub const fn with_section_pauses(mut self, section_pauses: bool) -> Self {
+        self.section_pauses = section_pauses;
+        self
+    }
+
+    pub const fn with_rewind_words(mut self, rewind_words: u8) -> Self {
+        self.rewind_words = rewind_words;
+        self
+    }
+
+    pub const fn with_orp_highlight(mut self, orp_highlight: bool) -> Self {
+        self.orp_highlight = orp_highlight;
+        self
+    }
+
+    pub const fn with_bionic_reading(mut self, bionic_reading: bool) -> Self {
+        self.bionic_reading = bionic_reading;
+        self
+    }
+
+    pub const fn with_library_grid(mut self, library_grid: bool) -> Self {
+        self.library_grid = library_grid;
+        self
+    }
+
+    pub const fn with_adaptive_speed(mut self, adaptive_speed: bool) -> Self {
+        self.adaptive_speed = adaptive_speed;
+        self
+    }
+
+    pub const fn with_reading_goal(mut self, minutes: u8, progress_s: u32) -> Self {
+        self.reading_goal_minutes = minutes;
+        self.goal_progress_s = progress_s;
+        self
+    }
+
+    pub const fn with_pause_ms(mut self, sentence: u16, clause: u16) -> Self {
+        self.sentence_pause_ms = sentence;
+        self.clause_pause_ms = clause;
+        self
+    }
+
+    pub const fn with_countdown_seconds(mut self, countdown_seconds: u8) -> Self {
+        self.countdown_seconds = countdown_seconds;
+        self
+    }
+
+    pub const fn with_orp_anchor_percent(mut self, orp_anchor_percent: u8) -> Self {
+        self.orp_anchor_percent = orp_anchor_percent;
+        self
+    }
+
+    pub const fn with_encoder_reversed(mut self, encoder_reversed: bool) -> Self {
+        self.encoder_reversed = encoder_reversed;
+        self
+    }
+
+    pub const fn with_screensaver_minutes(mut self, screensaver_minutes: u8) -> Self {
+        self.screensaver_minutes = screensaver_minutes;
+        self
+    }
+
+    pub const fn with_night_mode(mut self, night_mode: bool) -> Self {
+        self.night_mode = night_mode;
+        self
+    }
+
+    pub const fn with_light_sensor(mut self, light_sensor: bool) -> Self {
+        self.light_sensor = light_sensor;
+        self
+    }
+
+    pub const fn with_encoder_sensitivity(
+        mut self,
+        encoder_sensitivity: EncoderSensitivity,
+    ) -> Self {
+        self.encoder_sensitivity = encoder_sensitivity;
+        self
+    }
+
+    pub const fn with_audio_volume(mut self, audio_volume: AudioVolume) -> Self {
+        self.audio_volume = audio_volume;
+        self
+    }
+
+    pub const fn with_quote_export(mut self, quote_export: bool) -> Self {
+        self.quote_export = quote_export;
+        self
+    }
+
+    pub const fn with_onboarded(mut self, onboarded: bool) -> Self {
+        self.onboarded = onboarded;
+        self
+    }
 }
 
 impl Default for PersistedSettings {
@@ -171,6 +370,33 @@ pub struct SettingsState {
     pub refresh_state: RefreshState,
     pub refresh_started_at_ms: Option<u64>,
     pub topics: TopicPreferences,
+    pub dwell_mode: DwellMode,
+    pub min_dwell_ms: u16,
+    pub max_dwell_ms: u16,
+    pub chunking: ChunkingMode,
+    pub references: ReferenceMode,
+    pub digit_grouping: bool,
+    pub section_pauses: bool,
+    pub rewind_words: u8,
+    pub orp_highlight: bool,
+    pub bionic_reading: bool,
+    pub library_grid: bool,
+    pub adaptive_speed: bool,
+    pub reading_goal_minutes: u8,
+    pub goal_progress_ms: u64,
+    pub sentence_pause_ms: u16,
+    pub clause_pause_ms: u16,
+    pub countdown_seconds: u8,
+    pub orp_anchor_percent: u8,
+    pub encoder_reversed: bool,
+    pub encoder_sensitivity: EncoderSensitivity,
+    pub screensaver_minutes: u8,
+    pub night_mode: bool,
+    pub light_sensor: bool,
+    pub audio_volume: AudioVolume,
+    pub quote_export: bool,
+    pub onboarded: bool,
+    pub profile: u8,
 }
 
 impl SettingsState {
@@ -182,6 +408,33 @@ impl SettingsState {
             refresh_state: RefreshState::Idle,
             refresh_started_at_ms: None,
             topics: TopicPreferences::new(),
+            dwell_mode: DwellMode::Standard,
+            min_dwell_ms: DEFAULT_MIN_DWELL_MS,
+            max_dwell_ms: DEFAULT_MAX_DWELL_MS,
+            chunking: ChunkingMode::SingleWord,
+            references: ReferenceMode::Skip,
+            digit_grouping: false,
+            section_pauses: false,
+            rewind_words: 0,
+            orp_highlight: false,
+            bionic_reading: false,
+            library_grid: false,
+            adaptive_speed: false,
+            reading_goal_minutes: 0,
+            goal_progress_ms: 0,
+            sentence_pause_ms: 0,
+            clause_pause_ms: 0,
+            countdown_seconds: DEFAULT_COUNTDOWN_SECONDS,
+            orp_anchor_percent: DEFAULT_ORP_ANCHOR_PERCENT,
+            encoder_reversed: false,
+            encoder_sensitivity: EncoderSensitivity::Normal,
+            screensaver_minutes: 0,
+            night_mode: false,
+            light_sensor: false,
+            audio_volume: AudioVolume::Off,
+            quote_export: false,
+            onboarded: false,
+            profile: 0,
         }
     }
 
@@ -201,6 +454,33 @@ impl SettingsState {
             refresh_state: RefreshState::Idle,
             refresh_started_at_ms: None,
             topics: settings.topics,
+            dwell_mode: settings.dwell_mode,
+            min_dwell_ms: settings.min_dwell_ms,
+            max_dwell_ms: settings.max_dwell_ms,
+            chunking: settings.chunking,
+            references: settings.references,
+            digit_grouping: settings.digit_grouping,
+            section_pauses: settings.section_pauses,
+            rewind_words: settings.rewind_words,
+            orp_highlight: settings.orp_highlight,
+            bionic_reading: settings.bionic_reading,
+            library_grid: settings.library_grid,
+            adaptive_speed: settings.adaptive_speed,
+            reading_goal_minutes: settings.reading_goal_minutes,
+            goal_progress_ms: settings.goal_progress_s as u64 * 1_000,
+            sentence_pause_ms: settings.sentence_pause_ms,
+            clause_pause_ms: settings.clause_pause_ms,
+            countdown_seconds: settings.countdown_seconds,
+            orp_anchor_percent: settings.orp_anchor_percent,
+            encoder_reversed: settings.encoder_reversed,
+            encoder_sensitivity: settings.encoder_sensitivity,
+            screensaver_minutes: settings.screensaver_minutes,
+            night_mode: settings.night_mode,
+            light_sensor: settings.light_sensor,
+            audio_volume: settings.audio_volume,
+            quote_export: settings.quote_export,
+            onboarded: settings.onboarded,
+            profile: 0,
         }
     }
 
@@ -211,6 +491,47 @@ impl SettingsState {
             self.appearance,
             self.topics,
         )
+        .with_dwell_mode(self.dwell_mode)
+        .with_dwell_bounds(self.min_dwell_ms, self.max_dwell_ms)
+        .with_chunking(self.chunking)
+        .with_references(self.references)
+        .with_digit_grouping(self.digit_grouping)
+        .with_section_pauses(self.section_pauses)
+        .with_rewind_words(self.rewind_words)
+        .with_orp_highlight(self.orp_highlight)
+        .with_bionic_reading(self.bionic_reading)
+        .with_library_grid(self.library_grid)
+        .with_adaptive_speed(self.adaptive_speed)
+        .with_reading_goal(self.reading_goal_minutes, self.goal_progress_seconds())
+        .with_pause_ms(self.sentence_pause_ms, self.clause_pause_ms)
+        .with_countdown_seconds(self.countdown_seconds)
+        .with_orp_anchor_percent(self.orp_anchor_percent)
+        .with_encoder_reversed(self.encoder_reversed)
+        .with_encoder_sensitivity(self.encoder_sensitivity)
+        .with_screensaver_minutes(self.screensaver_minutes)
+        .with_night_mode(self.night_mode)
+        .with_light_sensor(self.light_sensor)
+        .with_audio_volume(self.audio_volume)
+        .with_quote_export(self.quote_export)
+        .with_onboarded(self.onboarded)
+    }
+
+    pub const fn dwell_timing(&self) -> DwellTiming {
+        let mut timing = DwellTiming::new(self.dwell_mode);
+        timing.min_ms = self.min_dwell_ms;
+        timing.max_ms = self.max_dwell_ms;
+        timing.sentence_pause_ms = self.sentence_pause_ms;
+        timing.clause_pause_ms = self.clause_pause_ms;
+        timing
+    }
+
+    // Applies to books as they are parsed on the device, so a change shows in the next one opened.
+    pub const fn format_preferences(&self) -> FormatPreferences {
+        FormatPreferences {
+            chunking: self.chunking,
+            references: self.references,
+            digit_grouping: self.digit_grouping,
+        }
     }
 
     pub fn adjust_reading_speed(&mut self, increase: bool) {
@@ -229,6 +550,348 @@ impl SettingsState {
         self.appearance = self.appearance.toggled();
     }
 
+    pub fn toggle_night_mode(&mut self) {
+        self.night_mode = !self.night_mode;
+    }
+
+    pub const fn night_mode_label(&self) -> &'static str {
+        if self.night_mode { "AUTO" } else { "OFF" }
+    }
+
+    pub fn toggle_light_sensor(&mut self) {
+        self.light_sensor = !self.light_sensor;
+    }
+
+    pub const fn light_sensor_label(&self) -> &'static str {
+        if self.light_sensor { "AUTO" } else { "OFF" }
+    }
+
+    pub fn toggle_quote_export(&mut self) {
+        self.quote_export = !self.quote_export;
+    }
+
+    pub const fn quote_export_label(&self) -> &'static str {
+        if self.quote_export { "ON" } else { "OFF" }
+    }
+
+    pub fn toggle_section_pauses(&mut self) {
+        self.section_pauses = !self.section_pauses;
+    }
+
+    pub const fn section_pauses_label(&self) -> &'static str {
+        if self.section_pauses { "ON" } else { "OFF" }
+    }
+
+    pub fn toggle_orp_highlight(&mut self) {
+        self.orp_highlight = !self.orp_highlight;
+    }
+
+    pub const fn orp_highlight_label(&self) -> &'static str {
+        if self.orp_highlight { "ON" } else { "OFF" }
+    }
+
+    pub fn cycle_sentence_pause_ms(&mut self) {
+        self.sentence_pause_ms = next_pause_ms(self.sentence_pause_ms);
+    }
+
+    pub fn cycle_clause_pause_ms(&mut self) {
+        self.clause_pause_ms = next_pause_ms(self.clause_pause_ms);
+    }
+
+    pub const fn sentence_pause_label(&self) -> &'static str {
+        pause_ms_label(self.sentence_pause_ms)
+    }
+
+    pub const fn clause_pause_label(&self) -> &'static str {
+        pause_ms_label(self.clause_pause_ms)
+    }
+
+    pub fn toggle_dwell_mode(&mut self) {
+        self.dwell_mode = match self.dwell_mode {
+            DwellMode::Standard => DwellMode::Weighted,
+            DwellMode::Weighted => DwellMode::Standard,
+        };
+    }
+
+    pub const fn dwell_mode_label(&self) -> &'static str {
+        self.dwell_mode.label()
+    }
+
+    pub fn toggle_chunking(&mut self) {
+        self.chunking = match self.chunking {
+            ChunkingMode::SingleWord => ChunkingMode::FunctionWords,
+            ChunkingMode::FunctionWords => ChunkingMode::SingleWord,
+        };
+    }
+
+    pub const fn chunking_label(&self) -> &'static str {
+        match self.chunking {
+            ChunkingMode::SingleWord => "SINGLE",
+            ChunkingMode::FunctionWords => "GROUPED",
+        }
+    }
+
+    pub fn toggle_references(&mut self) {
+        self.references = match self.references {
+            ReferenceMode::Skip => ReferenceMode::Keep,
+            ReferenceMode::Keep => ReferenceMode::Skip,
+        };
+    }
+
+    pub const fn references_label(&self) -> &'static str {
+        match self.references {
+            ReferenceMode::Skip => "SKIP",
+            ReferenceMode::Keep => "KEEP",
+        }
+    }
+
+    pub fn toggle_digit_grouping(&mut self) {
+        self.digit_grouping = !self.digit_grouping;
+    }
+
+    pub const fn digit_grouping_label(&self) -> &'static str {
+        if self.digit_grouping { "ON" } else { "OFF" }
+    }
+
+    pub fn cycle_min_dwell_ms(&mut self) {
+        let current = MIN_DWELL_OPTIONS_MS
+            .iter()
+            .position(|ms| *ms == self.min_dwell_ms)
+            .unwrap_or(0);
+        self.min_dwell_ms = MIN_DWELL_OPTIONS_MS[(current + 1) % MIN_DWELL_OPTIONS_MS.len()];
+    }
+
+    pub const fn min_dwell_label(&self) -> &'static str {
+        match self.min_dwell_ms {
+            80 => "80 MS",
+            120 => "120 MS",
+            160 => "160 MS",
+            200 => "200 MS",
+            _ => "CUSTOM",
+        }
+    }
+
+    pub fn cycle_max_dwell_ms(&mut self) {
+        let current = MAX_DWELL_OPTIONS_MS
+            .iter()
+            .position(|ms| *ms == self.max_dwell_ms)
+            .unwrap_or(0);
+        self.max_dwell_ms = MAX_DWELL_OPTIONS_MS[(current + 1) % MAX_DWELL_OPTIONS_MS.len()];
+    }
+
+    pub const fn max_dwell_label(&self) -> &'static str {
+        match self.max_dwell_ms {
+            1_000 => "1 S",
+            1_500 => "1.5 S",
+            2_000 => "2 S",
+            3_000 => "3 S",
+            _ => "CUSTOM",
+        }
+    }
+
+    pub fn cycle_countdown_seconds(&mut self) {
+        let current = COUNTDOWN_SECONDS_OPTIONS
+            .iter()
+            .position(|seconds| *seconds == self.countdown_seconds)
+            .unwrap_or(0);
+        self.countdown_seconds =
+            COUNTDOWN_SECONDS_OPTIONS[(current + 1) % COUNTDOWN_SECONDS_OPTIONS.len()];
+    }
+
+    pub const fn countdown_label(&self) -> &'static str {
+        match self.countdown_seconds {
+            0 => "OFF",
+            3 => "3 S",
+            5 => "5 S",
+            _ => "CUSTOM",
+        }
+    }
+
+    pub fn cycle_orp_anchor_percent(&mut self) {
+        let current = ORP_ANCHOR_PERCENT_OPTIONS
+            .iter()
+            .position(|percent| *percent == self.orp_anchor_percent)
+            .unwrap_or(0);
+        self.orp_anchor_percent =
+            ORP_ANCHOR_PERCENT_OPTIONS[(current + 1) % ORP_ANCHOR_PERCENT_OPTIONS.len()];
+    }
+
+    // The value doubles as the preview: the capital is where the anchor lands in an 11-letter
+    // word, as the stage would place it.
+    pub const fn orp_anchor_label(&self) -> &'static str {
+        match self.orp_anchor_percent {
+            0 => "AUTO recOgnition",
+            20 => "20% reCognition",
+            30 => "30% recOgnition",
+            40 => "40% recoGnition",
+            50 => "50% recogNition",
+            _ => "CUSTOM",
+        }
+    }
+
+    pub fn toggle_bionic_reading(&mut self) {
+        self.bionic_reading = !self.bionic_reading;
+    }
+
+    pub const fn bionic_reading_label(&self) -> &'static str {
+        if self.bionic_reading { "ON" } else { "OFF" }
+    }
+
+    pub fn toggle_adaptive_speed(&mut self) {
+        self.adaptive_speed = !self.adaptive_speed;
+    }
+
+    pub const fn adaptive_speed_label(&self) -> &'static str {
+        if self.adaptive_speed { "ON" } else { "OFF" }
+    }
+
+    pub fn toggle_library_grid(&mut self) {
+        self.library_grid = !self.library_grid;
+    }
+
+    pub const fn library_grid_label(&self) -> &'static str {
+        if self.library_grid { "GRID" } else { "LIST" }
+    }
+
+    pub fn cycle_reading_goal(&mut self) {
+        let current = READING_GOAL_MINUTE_OPTIONS
+            .iter()
+            .position(|minutes| *minutes == self.reading_goal_minutes)
+            .unwrap_or(0);
+        self.reading_goal_minutes =
+            READING_GOAL_MINUTE_OPTIONS[(current + 1) % READING_GOAL_MINUTE_OPTIONS.len()];
+    }
+
+    pub const fn reading_goal_label(&self) -> &'static str {
+        match self.reading_goal_minutes {
+            0 => "OFF",
+            10 => "10 MIN",
+            20 => "20 MIN",
+            30 => "30 MIN",
+            _ => "CUSTOM",
+        }
+    }
+
+    pub fn cycle_screensaver(&mut self) {
+        let current = SCREENSAVER_MINUTE_OPTIONS
+            .iter()
+            .position(|minutes| *minutes == self.screensaver_minutes)
+            .unwrap_or(0);
+        self.screensaver_minutes =
+            SCREENSAVER_MINUTE_OPTIONS[(current + 1) % SCREENSAVER_MINUTE_OPTIONS.len()];
+    }
+
+    pub const fn screensaver_label(&self) -> &'static str {
+        match self.screensaver_minutes {
+            0 => "OFF",
+            2 => "2 MIN",
+            5 => "5 MIN",
+            10 => "10 MIN",
+            _ => "CUSTOM",
+        }
+    }
+
+    pub fn toggle_encoder_reversed(&mut self) {
+        self.encoder_reversed = !self.encoder_reversed;
+    }
+
+    pub const fn encoder_direction_label(&self) -> &'static str {
+        if self.encoder_reversed {
+            "REVERSED"
+        } else {
+            "NORMAL"
+        }
+    }
+
+    pub fn cycle_encoder_sensitivity(&mut self) {
+        self.encoder_sensitivity = self.encoder_sensitivity.cycled();
+    }
+
+    pub const fn encoder_sensitivity_label(&self) -> &'static str {
+        self.encoder_sensitivity.label()
+    }
+
+    pub fn cycle_audio_volume(&mut self) {
+        self.audio_volume = self.audio_volume.cycled();
+    }
+
+    pub const fn audio_volume_label(&self) -> &'static str {
+        self.audio_volume.label()
+    }
+
+    pub const fn screensaver_after_ms(&self) -> u64 {
+        self.screensaver_minutes as u64 * 60_000
+    }
+
+    pub const fn goal_progress_seconds(&self) -> u32 {
+        let seconds = self.goal_progress_ms / 1_000;
+        if seconds > u32::MAX as u64 {
+            u32::MAX
+        } else {
+            seconds as u32
+        }
+    }
+
+    pub const fn goal_permille(&self) -> Option<u16> {
+        if self.reading_goal_minutes == 0 {
+            return None;
+        }
+
+        let goal_ms = self.reading_goal_minutes as u64 * 60_000;
+        let permille = self.goal_progress_ms.saturating_mul(1_000) / goal_ms;
+        Some(if permille > 1_000 {
+            1_000
+        } else {
+            permille as u16
+        })
+    }
+
+    // There is no wall clock yet, so a met goal rolls over when the next reading session starts
+    // instead of at midnight.
+    pub fn begin_goal_session(&mut self) {
+        if self.goal_permille() == Some(1_000) {
+            self.goal_progress_ms = 0;
+        }
+    }
+
+    // Returns true when the total crosses a whole minute, which is when the progress is worth
+    // persisting.
+    pub fn add_goal_reading_time(&mut self, elapsed_ms: u64) -> bool {
+        let before = self.goal_progress_ms / 60_000;
+        self.goal_progress_ms = self.goal_progress_ms.saturating_add(elapsed_ms);
+        self.goal_progress_ms / 60_000 != before
+    }
+
+    pub const fn next_profile(&self) -> u8 {
+        (self.profile + 1) % PROFILE_COUNT
+    }
+
+    pub const fn profile_label(&self) -> &'static str {
+        match self.profile {
+            0 => "ONE",
+            1 => "TWO",
+            _ => "THREE",
+        }
+    }
+
+    pub fn cycle_rewind_words(&mut self) {
+        let current = REWIND_WORD_OPTIONS
+            .iter()
+            .position(|words| *words == self.rewind_words)
+            .unwrap_or(0);
+        self.rewind_words = REWIND_WORD_OPTIONS[(current + 1) % REWIND_WORD_OPTIONS.len()];
+    }
+
+    pub const fn rewind_words_label(&self) -> &'static str {
+        match self.rewind_words {
+            0 => "OFF",
+            3 => "3 WORDS",
+            5 => "5 WORDS",
+            10 => "10 WORDS",
+            _ => "CUSTOM",
+        }
+    }
+
     pub fn start_refresh(&mut self, now_ms: u64) {
         self.refresh_state = RefreshState::Refreshing;
         self.refresh_started_at_ms = Some(now_ms);
@@ -261,6 +924,19 @@ impl Default for SettingsState {
     }
 }
 
+// Wraps from the longest pause back to AUTO; a value off the 40 ms grid rounds down first.
+fn next_pause_ms(pause_ms: u16) -> u16 {
+    let stepped = (pause_ms / PAUSE_MS_STEP + 1) * PAUSE_MS_STEP;
+    if stepped > MAX_PAUSE_MS { 0 } else { stepped }
+}
+
+const fn pause_ms_label(pause_ms: u16) -> &'static str {
+    if !pause_ms.is_multiple_of(PAUSE_MS_STEP) || pause_ms > MAX_PAUSE_MS {
+        return "CUSTOM";
+    }
+    PAUSE_MS_LABELS[(pause_ms / PAUSE_MS_STEP) as usize]
+}
+
 pub const fn topic_category_label(index: usize) -> &'static str {
     match index {
         0 => "Technology",
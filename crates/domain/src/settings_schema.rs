@@ -0,0 +1,349 @@
+// The settings list as data. Each entry gives a row its section, label, value and what a click
+// does, so an ordinary preference row is one entry here plus its `SettingsState` field and
+// methods; the store and the selector walk this table instead of matching on every row.
+
+use crate::{
+    settings::SettingsState,
+    store::Store,
+    ui::{SettingsMode, SettingsRow},
+};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SettingsSection {
+    Reading,
+    Display,
+    Input,
+    Network,
+    System,
+}
+
+impl SettingsSection {
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Reading => "READING SETTINGS",
+            Self::Display => "DISPLAY SETTINGS",
+            Self::Input => "INPUT SETTINGS",
+            Self::Network => "NETWORK SETTINGS",
+            Self::System => "SYSTEM SETTINGS",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SettingsAction {
+    None,
+    // Changes the preference in place; the store persists the result.
+    Adjust(fn(&mut SettingsState)),
+    // Hands the encoder to an editor or sub-screen until it is left.
+    Open(SettingsMode),
+    Screenshot,
+    SwitchProfile,
+    FactoryReset,
+    History,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SettingsEntry {
+    pub row: SettingsRow,
+    pub section: SettingsSection,
+    pub label: &'static str,
+    pub value: fn(&Store) -> Option<&'static str>,
+    pub action: SettingsAction,
+    pub show_arrow: bool,
+}
+
+const fn entry(
+    row: SettingsRow,
+    section: SettingsSection,
+    label: &'static str,
+    value: fn(&Store) -> Option<&'static str>,
+    action: SettingsAction,
+) -> SettingsEntry {
+    SettingsEntry {
+        row,
+        section,
+        label,
+        value,
+        action,
+        show_arrow: false,
+    }
+}
+
+const fn with_arrow(mut entry: SettingsEntry) -> SettingsEntry {
+    entry.show_arrow = true;
+    entry
+}
+
+const fn no_value(_: &Store) -> Option<&'static str> {
+    None
+}
+
+// Display order. Rows of a section stay together, since the title follows the selected row's
+// section.
+pub const SETTINGS_SCHEMA: &[SettingsEntry] = &[
+    entry(
+        SettingsRow::ReadingSpeed,
+        SettingsSection::Reading,
+        "Reading Speed",
+        |store| Some(store.settings.reading_speed_label()),
+        SettingsAction::Open(SettingsMode::SpeedEdit),
+    ),
+    entry(
+        SettingsRow::AdaptiveSpeed,
+        SettingsSection::Reading,
+        "Adaptive Speed",
+        |store| Some(store.settings.adaptive_speed_label()),
+        SettingsAction::Adjust(SettingsState::toggle_adaptive_speed),
+    ),
+    entry(
+        SettingsRow::SectionPauses,
+        SettingsSection::Reading,
+        "Section Pauses",
+        |store| Some(store.settings.section_pauses_label()),
+        SettingsAction::Adjust(SettingsState::toggle_section_pauses),
+    ),
+    entry(
+        SettingsRow::DotPause,
+        SettingsSection::Reading,
+        "Dot Pause",
+        |store| Some(store.settings.sentence_pause_label()),
+        SettingsAction::Adjust(SettingsState::cycle_sentence_pause_ms),
+    ),
+    entry(
+        SettingsRow::CommaPause,
+        SettingsSection::Reading,
+        "Comma Pause",
+        |store| Some(store.settings.clause_pause_label()),
+        SettingsAction::Adjust(SettingsState::cycle_clause_pause_ms),
+    ),
+    entry(
+        SettingsRow::WordTiming,
+        SettingsSection::Reading,
+        "Word Timing",
+        |store| Some(store.settings.dwell_mode_label()),
+        SettingsAction::Adjust(SettingsState::toggle_dwell_mode),
+    ),
+    entry(
+        SettingsRow::ShortestWord,
+        SettingsSection::Reading,
+        "Shortest Word",
+        |store| Some(store.settings.min_dwell_label()),
+        SettingsAction::Adjust(SettingsState::cycle_min_dwell_ms),
+    ),
+    entry(
+        SettingsRow::LongestWord,
+        SettingsSection::Reading,
+        "Longest Word",
+        |store| Some(store.settings.max_dwell_label()),
+        SettingsAction::Adjust(SettingsState::cycle_max_dwell_ms),
+    ),
+    entry(
+        SettingsRow::WordGroups,
+        SettingsSection::Reading,
+        "Word Groups",
+        |store| Some(store.settings.chunking_label()),
+        SettingsAction::Adjust(SettingsState::toggle_chunking),
+    ),
+    entry(
+        SettingsRow::References,
+        SettingsSection::Reading,
+        "References",
+        |store| Some(store.settings.references_label()),
+        SettingsAction::Adjust(SettingsState::toggle_references),
+    ),
+    entry(
+        SettingsRow::DigitGroups,
+        SettingsSection::Reading,
+        "Digit Groups",
+        |store| Some(store.settings.digit_grouping_label()),
+        SettingsAction::Adjust(SettingsState::toggle_digit_grouping),
+    ),
+    entry(
+        SettingsRow::ResumeRewind,
+        SettingsSection::Reading,
+        "Resume Rewind",
+        |store| Some(store.settings.rewind_words_label()),
+        SettingsAction::Adjust(SettingsState::cycle_rewind_words),
+    ),
+    entry(
+        SettingsRow::Countdown,
+        SettingsSection::Reading,
+        "Countdown",
+        |store| Some(store.settings.countdown_label()),
+        SettingsAction::Adjust(SettingsState::cycle_countdown_seconds),
+    ),
+    entry(
+        SettingsRow::AnchorHighlight,
+        SettingsSection::Reading,
+        "Anchor Highlight",
+        |store| Some(store.settings.orp_highlight_label()),
+        SettingsAction::Adjust(SettingsState::toggle_orp_highlight),
+    ),
+    entry(
+        SettingsRow::AnchorPosition,
+        SettingsSection::Reading,
+        "Anchor Position",
+        |store| Some(store.settings.orp_anchor_label()),
+        SettingsAction::Adjust(SettingsState::cycle_orp_anchor_percent),
+    ),
+    entry(
+        SettingsRow::BionicReading,
+        SettingsSection::Reading,
+        "Bionic Reading",
+        |store| Some(store.settings.bionic_reading_label()),
+        SettingsAction::Adjust(SettingsState::toggle_bionic_reading),
+    ),
+    entry(
+        SettingsRow::ReadingGoal,
+        SettingsSection::Reading,
+        "Reading Goal",
+        |store| Some(store.settings.reading_goal_label()),
+        SettingsAction::Adjust(SettingsState::cycle_reading_goal),
+    ),
+    with_arrow(entry(
+        SettingsRow::ReadingHistory,
+        SettingsSection::Reading,
+        "Reading History",
+        no_value,
+        SettingsAction::History,
+    )),
+    entry(
+        SettingsRow::Appearance,
+        SettingsSection::Display,
+        "Appearance",
+        |store| Some(store.settings.appearance.label()),
+        SettingsAction::Open(SettingsMode::AppearanceEdit),
+    ),
+    entry(
+        SettingsRow::NightMode,
+        SettingsSection::Display,
+        "Night Mode",
+        |store| Some(store.settings.night_mode_label()),
+        SettingsAction::Adjust(SettingsState::toggle_night_mode),
+    ),
+    entry(
+        SettingsRow::LightSensor,
+        SettingsSection::Display,
+        "Light Sensor",
+        |store| Some(store.settings.light_sensor_label()),
+        SettingsAction::Adjust(SettingsState::toggle_light_sensor),
+    ),
+    entry(
+        SettingsRow::Screensaver,
+        SettingsSection::Display,
+        "Screensaver",
+        |store| Some(store.settings.screensaver_label()),
+        SettingsAction::Adjust(SettingsState::cycle_screensaver),
+    ),
+    entry(
+        SettingsRow::LibraryLayout,
+        SettingsSection::Display,
+        "Library Layout",
+        |store| Some(store.settings.library_grid_label()),
+        SettingsAction::Adjust(SettingsState::toggle_library_grid),
+    ),
+    entry(
+        SettingsRow::EncoderDirection,
+        SettingsSection::Input,
+        "Encoder Direction",
+        |store| Some(store.settings.encoder_direction_label()),
+        SettingsAction::Adjust(SettingsState::toggle_encoder_reversed),
+    ),
+    entry(
+        SettingsRow::EncoderSensitivity,
+        SettingsSection::Input,
+        "Encoder Sensitivity",
+        |store| Some(store.settings.encoder_sensitivity_label()),
+        SettingsAction::Adjust(SettingsState::cycle_encoder_sensitivity),
+    ),
+    entry(
+        SettingsRow::RefreshData,
+        SettingsSection::Network,
+        "Refresh Data",
+        no_value,
+        SettingsAction::Open(SettingsMode::RefreshLoading),
+    ),
+    with_arrow(entry(
+        SettingsRow::TopicPreferences,
+        SettingsSection::Network,
+        "Topic Preferences",
+        no_value,
+        SettingsAction::Open(SettingsMode::TopicPreferences),
+    )),
+    entry(
+        SettingsRow::NetworkConnection,
+        SettingsSection::Network,
+        "Network Connection",
+        |store| Some(store.network.status.label()),
+        SettingsAction::None,
+    ),
+    with_arrow(entry(
+        SettingsRow::ConnectAccount,
+        SettingsSection::Network,
+        "Connect Account",
+        no_value,
+        SettingsAction::None,
+    )),
+    entry(
+        SettingsRow::Sound,
+        SettingsSection::System,
+        "Sound",
+        |store| Some(store.settings.audio_volume_label()),
+        SettingsAction::Adjust(SettingsState::cycle_audio_volume),
+    ),
+    entry(
+        SettingsRow::Screenshot,
+        SettingsSection::System,
+        "Screenshot",
+        |_| Some("5 s timer"),
+        SettingsAction::Screenshot,
+    ),
+    entry(
+        SettingsRow::QuoteExport,
+        SettingsSection::System,
+        "Quote Export",
+        |store| Some(store.settings.quote_export_label()),
+        SettingsAction::Adjust(SettingsState::toggle_quote_export),
+    ),
+    entry(
+        SettingsRow::Profile,
+        SettingsSection::System,
+        "Profile",
+        |store| Some(store.settings.profile_label()),
+        SettingsAction::SwitchProfile,
+    ),
+    entry(
+        SettingsRow::ResetDevice,
+        SettingsSection::System,
+        "Reset Device",
+        no_value,
+        SettingsAction::FactoryReset,
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_lists_every_row_once_with_sections_together() {
+        for (index, entry) in SETTINGS_SCHEMA.iter().enumerate() {
+            assert_eq!(entry.row.index(), index);
+            assert_eq!(SettingsRow::from_index(index), entry.row);
+
+            let first = SETTINGS_SCHEMA
+                .iter()
+                .position(|other| other.section == entry.section)
+                .unwrap();
+            let last = SETTINGS_SCHEMA
+                .iter()
+                .rposition(|other| other.section == entry.section)
+                .unwrap();
+            assert!(
+                SETTINGS_SCHEMA[first..=last]
+                    .iter()
+                    .all(|other| other.section == entry.section)
+            );
+        }
+    }
+}
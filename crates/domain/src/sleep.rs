@@ -1,4 +1,16 @@
+use crate::{
+    content::{CONTENT_ID_MAX_BYTES, CollectionKind},
+    text::InlineText,
+};
+
 pub const DEFAULT_INACTIVITY_TIMEOUT_MS: u64 = 30_000;
+pub const SLEEP_RESUME_ENCODED_LEN: usize = 3 + CONTENT_ID_MAX_BYTES;
+pub const LIGHT_SLEEP_MIN_WINDOW_MS: u64 = 40;
+pub const LIGHT_SLEEP_WAKE_MARGIN_MS: u64 = 4;
+// How long the screensaver stays up before the device gives in and deep sleeps anyway, and how
+// often it is redrawn meanwhile.
+pub const SCREENSAVER_SLEEP_AFTER_MS: u64 = 30 * 60_000;
+pub const SCREENSAVER_REFRESH_MS: u64 = 60_000;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub enum SleepState {
@@ -19,12 +31,15 @@ pub enum WakeReason {
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct SleepConfig {
     pub inactivity_timeout_ms: u64,
+    // Zero when the screensaver is off.
+    pub screensaver_after_ms: u64,
 }
 
 impl SleepConfig {
     pub const fn new(inactivity_timeout_ms: u64) -> Self {
         Self {
             inactivity_timeout_ms,
+            screensaver_after_ms: 0,
         }
     }
 }
@@ -67,6 +82,27 @@ impl SleepModel {
             || now_ms.saturating_sub(self.last_activity_ms) >= self.config.inactivity_timeout_ms
     }
 
+    // Screens that can show the screensaver wait for it instead of sleeping at the inactivity
+    // timeout, then sleep once it has been up for `SCREENSAVER_SLEEP_AFTER_MS`.
+    pub const fn idle_sleep_after_ms(&self, screensaver_eligible: bool) -> u64 {
+        if screensaver_eligible && self.config.screensaver_after_ms > 0 {
+            self.config.screensaver_after_ms + SCREENSAVER_SLEEP_AFTER_MS
+        } else {
+            self.config.inactivity_timeout_ms
+        }
+    }
+
+    pub const fn screensaver_due_ms(&self) -> Option<u64> {
+        if self.config.screensaver_after_ms == 0 {
+            None
+        } else {
+            Some(
+                self.last_activity_ms
+                    .saturating_add(self.config.screensaver_after_ms),
+            )
+        }
+    }
+
     pub fn mark_deep_sleeping(&mut self) {
         self.state = SleepState::DeepSleeping;
     }
@@ -83,3 +119,135 @@ impl Default for SleepModel {
         Self::new(SleepConfig::default())
     }
 }
+
+// Where to land after a deep-sleep wake. The platform keeps the encoded form in RTC memory, which
+// survives deep sleep but not a power loss.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum SleepResume {
+    #[default]
+    Dashboard,
+    Collection(CollectionKind),
+    Reader {
+        collection: CollectionKind,
+        content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+    },
+}
+
+impl SleepResume {
+    pub fn encode(&self) -> [u8; SLEEP_RESUME_ENCODED_LEN] {
+        let mut bytes = [0u8; SLEEP_RESUME_ENCODED_LEN];
+        match self {
+            Self::Dashboard => {}
+            Self::Collection(collection) => {
+                bytes[0] = 1;
+                bytes[1] = encode_collection(*collection);
+            }
+            Self::Reader {
+                collection,
+                content_id,
+            } => {
+                let id = content_id.as_str().as_bytes();
+                bytes[0] = 2;
+                bytes[1] = encode_collection(*collection);
+                bytes[2] = id.len() as u8;
+                bytes[3..3 + id.len()].copy_from_slice(id);
+            }
+        }
+        bytes
+    }
+
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < SLEEP_RESUME_ENCODED_LEN {
+            return None;
+        }
+
+        match bytes[0] {
+            0 => Some(Self::Dashboard),
+            1 => Some(Self::Collection(decode_collection(bytes[1])?)),
+            2 => {
+                let len = bytes[2] as usize;
+                if len == 0 || len > CONTENT_ID_MAX_BYTES {
+                    return None;
+                }
+                let content_id = core::str::from_utf8(&bytes[3..3 + len]).ok()?;
+                Some(Self::Reader {
+                    collection: decode_collection(bytes[1])?,
+                    content_id: InlineText::from_slice(content_id),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+const fn encode_collection(collection: CollectionKind) -> u8 {
+    match collection {
+        CollectionKind::Saved => 0,
+        CollectionKind::Inbox => 1,
+        CollectionKind::Recommendations => 2,
+    }
+}
+
+const fn decode_collection(value: u8) -> Option<CollectionKind> {
+    match value {
+        0 => Some(CollectionKind::Saved),
+        1 => Some(CollectionKind::Inbox),
+        2 => Some(CollectionKind::Recommendations),
+        _ => None,
+    }
+}
+
+// How long the CPU may light-sleep before the next deadline it has to meet. Short gaps are not
+// worth the wake-up cost, and the margin lets the word land on time rather than a tick late.
+pub fn light_sleep_window_ms(now_ms: u64, next_deadline_ms: u64) -> Option<u64> {
+    let window_ms = next_deadline_ms
+        .saturating_sub(now_ms)
+        .saturating_sub(LIGHT_SLEEP_WAKE_MARGIN_MS);
+    (window_ms >= LIGHT_SLEEP_MIN_WINDOW_MS).then_some(window_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sleep_resume_round_trips_through_bytes() {
+        for resume in [
+            SleepResume::Dashboard,
+            SleepResume::Collection(CollectionKind::Inbox),
+            SleepResume::Reader {
+                collection: CollectionKind::Saved,
+                content_id: InlineText::from_slice("article-42"),
+            },
+        ] {
+            assert_eq!(SleepResume::decode(&resume.encode()), Some(resume));
+        }
+
+        let mut corrupt = SleepResume::Dashboard.encode();
+        corrupt[0] = 9;
+        assert_eq!(SleepResume::decode(&corrupt), None);
+    }
+
+    #[test]
+    fn screensaver_delays_idle_sleep_only_where_it_can_show() {
+        let mut model = SleepModel::new(SleepConfig::new(30_000));
+        model.note_activity(1_000);
+        assert_eq!(model.screensaver_due_ms(), None);
+        assert_eq!(model.idle_sleep_after_ms(true), 30_000);
+
+        model.config.screensaver_after_ms = 120_000;
+        assert_eq!(model.screensaver_due_ms(), Some(121_000));
+        assert_eq!(
+            model.idle_sleep_after_ms(true),
+            120_000 + SCREENSAVER_SLEEP_AFTER_MS
+        );
+        assert_eq!(model.idle_sleep_after_ms(false), 30_000);
+    }
+
+    #[test]
+    fn light_sleep_window_skips_short_gaps() {
+        assert_eq!(light_sleep_window_ms(1_000, 1_300), Some(296));
+        assert_eq!(light_sleep_window_ms(1_000, 1_030), None);
+        assert_eq!(light_sleep_window_ms(1_000, 900), None);
+    }
+}
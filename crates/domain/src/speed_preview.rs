@@ -0,0 +1,46 @@
+// A sample sentence stepped at a chosen speed, so the speed can be felt before it is committed.
+// The walk-through's speed step and the settings speed editor both play it.
+
+pub(crate) const SAMPLE_SENTENCE: [&str; 10] = [
+    "Motif", "shows", "one", "word", "at", "a", "time,", "right", "here.", "",
+];
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct SpeedPreview {
+    word_index: usize,
+    word_at_ms: Option<u64>,
+}
+
+impl SpeedPreview {
+    pub const fn new() -> Self {
+        Self {
+            word_index: 0,
+            word_at_ms: None,
+        }
+    }
+
+    // The blank entry at the end is a beat of silence before the sentence starts over.
+    pub const fn word(&self) -> &'static str {
+        SAMPLE_SENTENCE[self.word_index]
+    }
+
+    // Steps the sentence at the chosen speed; returns whether the word changed. The speed is read
+    // on every step, so turning the knob mid-sentence changes the pace at once.
+    pub fn advance(&mut self, now_ms: u64, wpm: u16) -> bool {
+        let Some(shown_at_ms) = self.word_at_ms else {
+            self.word_at_ms = Some(now_ms);
+            return false;
+        };
+        if now_ms.saturating_sub(shown_at_ms) < 60_000 / u64::from(wpm.max(1)) {
+            return false;
+        }
+
+        self.word_index = (self.word_index + 1) % SAMPLE_SENTENCE.len();
+        self.word_at_ms = Some(now_ms);
+        true
+    }
+
+    pub fn restart(&mut self) {
+        *self = Self::new();
+    }
+}
@@ -1,29 +1,50 @@
 use alloc::boxed::Box;
 
 use crate::{
+    adaptive_speed::AdaptiveSpeed,
+    audio::AudioCue,
+    book_stats::BookStatsState,
+    bookmark::{BOOKMARK_CAPACITY, Bookmark, BookmarkState, Quote},
+    clock::LocalTime,
     content::{
-        CollectionKind, CollectionManifestState, ContentState, PackageState, PrepareContentRequest,
-        ReaderPauseDetailRequest, ReaderSavedToggleRequest, ReaderSubscriptionToggleRequest,
-        ReadingProgressEntry, ReadingProgressState, RecommendationState,
-        RecommendationTopicRequest,
+        CONTENT_ID_MAX_BYTES, CollectionKind, CollectionManifestState, ContentState,
+        DictionaryLookupRequest, PackageState, PrepareContentRequest, ReaderPauseDetailRequest,
+        ReaderSavedToggleRequest, ReaderSubscriptionToggleRequest, ReadingProgressEntry,
+        ReadingProgressState, RecommendationState, RecommendationTopicRequest,
     },
-    device::{BootState, DeviceState},
+    device::{BootState, CRASH_REPORT_MAX_BYTES, DeviceState},
+    diagnostics::{DIAGNOSTICS_UNLOCK_TURNS, DiagnosticsSnapshot},
+    history::{HistoryEntry, HistoryStamp, HistoryState},
     input::InputState,
+    light::AmbientLight,
     network::{NetworkState, NetworkStatus},
+    onboarding::{OnboardingState, OnboardingStep},
+    picker::PickerOutcome,
     power::PowerStatus,
     reader::{PauseMenuRow, ReaderMode, ReaderSession},
     runtime::{
         BootstrapSnapshot, CollectionConfirmIgnoredReason, Command, Effect, Event, UiCommand,
     },
-    settings::{REFRESH_LOADING_DURATION_MS, RefreshState, SettingsState},
-    sleep::{SleepModel, WakeReason},
+    settings::{AppearanceMode, REFRESH_LOADING_DURATION_MS, RefreshState, SettingsState},
+    settings_schema::SettingsAction,
+    sleep::{SleepModel, SleepResume, WakeReason},
     storage::StorageHealth,
     sync::{StartupSyncProgress, SyncState, SyncStatus},
-    ui::{RecommendationsRegion, SettingsMode, SettingsRow, TopicRegion, UiRoute, UiState},
+    text::InlineText,
+    toast::ToastKind,
+    ui::{
+        BookDetailAction, ConfirmAction, ConfirmDialog, RecommendationsRegion, SettingsMode,
+        SettingsRow, TopicRegion, UiRoute, UiState,
+    },
 };
 
 static EMPTY_CONTENT_STATE: ContentState = ContentState::empty();
 const RECOMMENDATION_SUBTOPIC_FOCUS_FLASH_TICKS: u8 = 8;
+const COLLECTION_FAST_SCROLL_ROTATION_MS: u64 = 90;
+const COLLECTION_FAST_SCROLL_MIN_ITEMS: usize = 8;
+const COLLECTION_JUMP_LETTER_VISIBLE_MS: u64 = 700;
+// Long enough to leave settings and reach the screen worth capturing.
+const SCREENSHOT_TIMER_MS: u64 = 5_000;
 const STARTUP_SPLASH_IDLE_PROGRESS_PERMILLE: u16 = 60;
 const STARTUP_SPLASH_REFRESH_PROGRESS_PERMILLE: u16 = 160;
 const STARTUP_SPLASH_PROGRESS_BASE_PERMILLE: u16 = 160;
@@ -49,6 +70,7 @@ struct PendingPrepare {
 #[derive(Debug)]
 pub struct Store {
     pub device: DeviceState,
+    pub crash_report: Option<InlineText<CRASH_REPORT_MAX_BYTES>>,
     pub startup_splash_visible: bool,
     pub startup_splash_started_at_ms: u64,
     pub startup_splash_tick_ms: u64,
@@ -56,15 +78,54 @@ pub struct Store {
     pub startup_splash_target_progress_permille: u16,
     pub startup_splash_display_progress_permille: u16,
     content: Option<Box<ContentState>>,
+    // Books held on the device itself, folded into Saved whatever the card or backend reports.
+    device_books: Option<Box<CollectionManifestState>>,
     pub reading_progress: ReadingProgressState,
+    pub bookmarks: BookmarkState,
     pub recommendations: RecommendationState,
     pending_prepare: Option<PendingPrepare>,
+    // Set by "read from start" on the book detail screen; the next open of that content skips the
+    // saved paragraph.
+    open_from_start: Option<InlineText<CONTENT_ID_MAX_BYTES>>,
+    // Set by "contents" on the same screen; that open lands paused on the contents list.
+    open_into_contents: Option<InlineText<CONTENT_ID_MAX_BYTES>>,
+    // Set by "bookmarks"; that open lands paused on the book's bookmark list.
+    open_into_bookmarks: Option<InlineText<CONTENT_ID_MAX_BYTES>>,
     pending_reading_progress_write: Option<ReadingProgressEntry>,
+    // Marks are few and written whole, so a change just flags the set for the next flush.
+    pending_bookmarks_write: bool,
+    // Reading sessions on this profile; the ring is written whole after each one closes.
+    pub history: HistoryState,
+    // The session being read, its `words` counting each word shown; closed into `history` once
+    // reading stops.
+    history_session: Option<HistoryEntry>,
+    pending_history_write: bool,
+    // Finished flags and read-through counts on this profile, written whole like the marks.
+    pub book_stats: BookStatsState,
+    pending_book_stats_write: bool,
+    // A new mark on its way to the card's quote file, with quote export on.
+    pending_quote: Option<Quote>,
+    pending_sleep_resume: Option<SleepResume>,
+    pending_audio_cue: Option<AudioCue>,
     pub input: InputState,
     pub network: NetworkState,
     pub power: PowerStatus,
+    pub low_battery_warning: bool,
+    pub screensaver_visible: bool,
+    // The first-boot walk-through; present until its last step is confirmed.
+    pub onboarding: Option<OnboardingState>,
     pub reader: ReaderSession,
+    // Only consulted with the Adaptive Speed setting on.
+    adaptive_speed: AdaptiveSpeed,
     pub settings: SettingsState,
+    // None until a time source reports in; nothing keeps wall-clock time across a power loss yet.
+    pub local_time: Option<LocalTime>,
+    // The calendar day from the same source, for the reading history's streak.
+    pub local_day: Option<u32>,
+    // Stays empty unless a light sensor is fitted.
+    pub ambient_light: AmbientLight,
+    // The latest platform sample; all zero until the diagnostics screen has been opened.
+    pub diagnostics: DiagnosticsSnapshot,
     pub sleep: SleepModel,
     pub storage: StorageHealth,
     pub backend_sync: SyncState,
@@ -75,6 +136,7 @@ impl Store {
     pub fn new() -> Self {
         Self {
             device: DeviceState::new(),
+            crash_report: None,
             startup_splash_visible: false,
             startup_splash_started_at_ms: 0,
             startup_splash_tick_ms: 0,
@@ -82,15 +144,37 @@ impl Store {
             startup_splash_target_progress_permille: STARTUP_SPLASH_IDLE_PROGRESS_PERMILLE,
             startup_splash_display_progress_permille: STARTUP_SPLASH_IDLE_PROGRESS_PERMILLE,
             content: None,
+            device_books: None,
             reading_progress: ReadingProgressState::empty(),
+            bookmarks: BookmarkState::empty(),
             recommendations: RecommendationState::new(),
             pending_prepare: None,
+            open_from_start: None,
+            open_into_contents: None,
+            open_into_bookmarks: None,
             pending_reading_progress_write: None,
+            pending_bookmarks_write: false,
+            history: HistoryState::empty(),
+            history_session: None,
+            pending_history_write: false,
+            book_stats: BookStatsState::empty(),
+            pending_book_stats_write: false,
+            pending_quote: None,
+            pending_sleep_resume: None,
+            pending_audio_cue: None,
             input: InputState::new(),
             network: NetworkState::disabled(),
             power: PowerStatus::new(82),
+            low_battery_warning: false,
+            screensaver_visible: false,
+            onboarding: None,
             reader: ReaderSession::new(),
+            adaptive_speed: AdaptiveSpeed::new(),
             settings: SettingsState::new(crate::sleep::DEFAULT_INACTIVITY_TIMEOUT_MS),
+            local_time: None,
+            local_day: None,
+            ambient_light: AmbientLight::new(),
+            diagnostics: DiagnosticsSnapshot::default(),
             sleep: SleepModel {
                 config: crate::sleep::SleepConfig::new(crate::sleep::DEFAULT_INACTIVITY_TIMEOUT_MS),
                 state: crate::sleep::SleepState::Awake,
@@ -110,10 +194,11 @@ impl Store {
     }
 
     pub fn hydrate_from_bootstrap(&mut self, snapshot: BootstrapSnapshot) {
-        let show_startup_splash = matches!(
-            snapshot.device.boot,
-            BootState::ColdBoot | BootState::DeepSleepWake
-        );
+        let show_startup_splash = snapshot.sleep_resume.is_none()
+            && matches!(
+                snapshot.device.boot,
+                BootState::ColdBoot | BootState::DeepSleepWake
+            );
         let settings = match snapshot.settings {
             Some(settings) => SettingsState::from_persisted(settings),
             None => SettingsState::new(crate::sleep::DEFAULT_INACTIVITY_TIMEOUT_MS),
@@ -123,6 +208,7 @@ impl Store {
             BootState::ColdBoot => WakeReason::ColdBoot,
         };
         self.device = snapshot.device;
+        self.crash_report = snapshot.crash_report;
         self.startup_splash_visible = show_startup_splash;
         self.startup_splash_started_at_ms = snapshot.boot_at_ms;
         self.startup_splash_tick_ms = snapshot.boot_at_ms;
@@ -130,21 +216,38 @@ impl Store {
         self.startup_splash_target_progress_permille = STARTUP_SPLASH_IDLE_PROGRESS_PERMILLE;
         self.startup_splash_display_progress_permille = STARTUP_SPLASH_IDLE_PROGRESS_PERMILLE;
         self.content = snapshot.content;
+        self.device_books = snapshot.device_books;
+        self.merge_device_books();
         self.reading_progress = snapshot
             .reading_progress
             .map(|progress| *progress)
             .unwrap_or_else(ReadingProgressState::empty);
+        self.bookmarks = BookmarkState::empty();
         self.recommendations = RecommendationState::new();
         if let Some(subtopics) = snapshot.recommendation_subtopics {
             self.recommendations.set_subtopics(*subtopics);
         }
         self.pending_prepare = None;
+        self.open_from_start = None;
+        self.open_into_contents = None;
+        self.open_into_bookmarks = None;
         self.pending_reading_progress_write = None;
+        self.pending_bookmarks_write = false;
+        self.history = HistoryState::empty();
+        self.history_session = None;
+        self.pending_history_write = false;
+        self.book_stats = BookStatsState::empty();
+        self.pending_book_stats_write = false;
+        self.pending_quote = None;
+        self.pending_sleep_resume = snapshot.sleep_resume;
         self.input = InputState::new();
         self.network = snapshot.network;
         self.power = PowerStatus::new(82);
+        self.low_battery_warning = false;
+        self.screensaver_visible = false;
         self.reader = ReaderSession::new();
         self.settings = settings;
+        self.settings.profile = snapshot.profile;
         self.sleep = SleepModel {
             config: crate::sleep::SleepConfig::new(self.settings.inactivity_timeout_ms),
             state: crate::sleep::SleepState::Awake,
@@ -154,15 +257,13 @@ impl Store {
         self.storage = snapshot.storage;
         self.backend_sync = SyncState::new();
         self.ui = UiState::new();
+        self.onboarding = (!self.settings.onboarded).then(OnboardingState::new);
     }
 
     pub fn dispatch(&mut self, command: Command) -> DispatchResult {
         match command {
-            Command::RequestDeepSleep => {
-                self.sleep.request_sleep();
-                Ok(Effect::EnterDeepSleep)
-            }
-            Command::Ui(command) => Ok(self.dispatch_ui(command)),
+            Command::RequestDeepSleep => Ok(self.prepare_for_sleep()),
+            Command::Ui(command) => Ok(self.dispatch_ui(self.oriented(command))),
             Command::Noop | Command::Boot => Ok(Effect::Noop),
         }
     }
@@ -174,7 +275,14 @@ impl Store {
                 self.sleep.note_activity(now_ms);
             }
             Event::NetworkStatusChanged(status) => {
+                let was_online = matches!(self.network.status, NetworkStatus::Online);
+                let is_online = matches!(status, NetworkStatus::Online);
                 self.network.status = status;
+                if is_online && !was_online {
+                    self.show_toast(ToastKind::WifiConnected, now_ms);
+                } else if was_online && !is_online {
+                    self.show_toast(ToastKind::WifiLost, now_ms);
+                }
                 if let Some(request) = self.dispatchable_pending_prepare_request() {
                     return Ok(Effect::PrepareContent(request));
                 }
@@ -182,6 +290,15 @@ impl Store {
                     return Ok(effect);
                 }
             }
+            Event::PowerStatusChanged(status) => {
+                if status.is_low() && !self.power.is_low() {
+                    self.low_battery_warning = true;
+                    self.queue_audio_cue(AudioCue::LowBattery);
+                } else if !status.is_low() {
+                    self.low_battery_warning = false;
+                }
+                self.power = status;
+            }
             Event::BackendSyncStatusChanged(status) => {
                 self.backend_sync.set_status(status);
                 self.update_startup_splash_target_for_status(status);
@@ -223,6 +340,11 @@ impl Store {
                         PackageState::Fetching,
                     );
                 }
+                if kind == CollectionKind::Saved
+                    && let Some(device_books) = self.device_books.as_deref()
+                {
+                    collection.merge_missing(device_books);
+                }
                 if self.content.is_some() || !collection.is_empty() {
                     self.content_mut().update_boxed_collection(kind, collection);
                 }
@@ -235,6 +357,53 @@ impl Store {
                 } else {
                     self.set_collection_index(kind, 0);
                 }
+                if matches!(
+                    self.pending_sleep_resume,
+                    Some(SleepResume::Reader { collection, .. }) if collection == kind
+                ) {
+                    return Ok(self.restore_sleep_resume(false));
+                }
+            }
+            Event::StoredReadingStateLoaded {
+                reading_progress,
+                bookmarks,
+                history,
+                book_stats,
+                recommendation_subtopics,
+            } => {
+                // Progress recorded while the card was still being scanned is newer than the
+                // stored copy, so it is replayed on top.
+                let mut merged = *reading_progress;
+                for entry in &self.reading_progress.entries[..self.reading_progress.len()] {
+                    merged.record_progress(*entry);
+                }
+                self.reading_progress = merged;
+                let mut merged = *bookmarks;
+                for bookmark in &self.bookmarks.entries[..self.bookmarks.len()] {
+                    merged.insert(*bookmark);
+                }
+                self.bookmarks = merged;
+                let mut merged = *history;
+                for index in 0..self.history.len() {
+                    if let Some(entry) = self.history.oldest(index) {
+                        merged.push(*entry);
+                    }
+                }
+                self.history = merged;
+                let mut merged = *book_stats;
+                for stat in &self.book_stats.entries[..self.book_stats.len()] {
+                    merged.insert(*stat);
+                }
+                self.book_stats = merged;
+                if self.recommendations.subtopics.is_empty() && !recommendation_subtopics.is_empty()
+                {
+                    self.recommendations
+                        .set_subtopics(*recommendation_subtopics);
+                }
+            }
+            Event::DeviceBooksUpdated(device_books) => {
+                self.device_books = Some(device_books);
+                self.merge_device_books();
             }
             Event::RecommendationSubtopicsUpdated(subtopics) => {
                 let previous_active_topic = self.recommendations.active_topic_slug;
@@ -337,6 +506,30 @@ impl Store {
                     self.pending_prepare = None;
                 }
             }
+            // A load can land after the reader moved to another book or jumped past its target;
+            // its window no longer fits.
+            Event::ReaderWindowLoaded {
+                content_id,
+                request_id,
+                window,
+            } => {
+                if content_id == self.reader.active_content_id
+                    && self.reader.awaits_window_request(request_id)
+                {
+                    self.load_reader_window(window);
+                }
+            }
+            Event::ReaderWindowLoadFailed(request) => {
+                if request.content_id == self.reader.active_content_id
+                    && self.reader.awaits_window_request(request.request_id)
+                {
+                    self.reader
+                        .window_load_failed(request.window_start_unit_index, now_ms);
+                    if self.reader.is_stream_stalled() {
+                        self.show_toast(ToastKind::CardReadFailed, now_ms);
+                    }
+                }
+            }
             Event::ContentPackageStateChanged {
                 collection,
                 remote_item_id,
@@ -390,6 +583,12 @@ impl Store {
             Event::ReaderPauseActionFailed { content_id, action } => {
                 self.reader.fail_pause_action(content_id, action);
             }
+            Event::DictionaryDefinitionLoaded(definition) => {
+                self.reader.apply_definition(definition);
+            }
+            Event::DictionaryLookupFailed { word } => {
+                self.reader.mark_definition_failed(word);
+            }
             Event::UiTick(tick_ms) => {
                 if self.startup_splash_visible {
                     self.startup_splash_tick_ms = tick_ms;
@@ -402,6 +601,25 @@ impl Store {
                 if self.ui.recommendations_focus_flash_ticks > 0 {
                     self.ui.recommendations_focus_flash_ticks -= 1;
                 }
+                if self.ui.collection_jump_letter.is_some()
+                    && tick_ms.saturating_sub(self.ui.collection_jump_letter_at_ms)
+                        >= COLLECTION_JUMP_LETTER_VISIBLE_MS
+                {
+                    self.ui.collection_jump_letter = None;
+                }
+                self.ui.toasts.expire(tick_ms);
+                if let Some(onboarding) = self.onboarding.as_mut()
+                    && matches!(onboarding.step, OnboardingStep::ReadingSpeed)
+                {
+                    onboarding.advance_demo(tick_ms, self.settings.reading_speed_wpm);
+                }
+                if matches!(self.ui.route, UiRoute::Settings)
+                    && matches!(self.ui.settings_mode, SettingsMode::SpeedEdit)
+                {
+                    self.ui
+                        .speed_preview
+                        .advance(tick_ms, self.settings.reading_speed_wpm);
+                }
                 if matches!(self.ui.route, UiRoute::Reader)
                     && matches!(self.reader.mode, crate::reader::ReaderMode::LoadingContent)
                 {
@@ -414,30 +632,103 @@ impl Store {
                         self.ui.settings_mode = SettingsMode::Master;
                     }
                 }
+                if self
+                    .ui
+                    .screenshot_at_ms
+                    .is_some_and(|at_ms| tick_ms >= at_ms)
+                {
+                    self.ui.screenshot_at_ms = None;
+                    return Ok(Effect::SaveScreenshot);
+                }
             }
             Event::ReaderTick(tick_ms) => {
                 if matches!(self.ui.route, UiRoute::Reader) {
                     if self.reader.is_active_reading() {
                         self.sleep.note_activity(tick_ms);
                     }
+                    if self.reader.session_reading_ms == 0 && self.reader.is_active_reading() {
+                        self.settings.begin_goal_session();
+                    }
+                    if self.reader.is_active_reading() {
+                        self.begin_history_session();
+                    }
+                    let reading_ms = self.reader.note_reading_time(tick_ms);
+                    let goal_minute_reached = self.settings.add_goal_reading_time(reading_ms)
+                        && self.settings.reading_goal_minutes > 0;
+                    let drifted = self.settings.adaptive_speed
+                        && self
+                            .adaptive_speed
+                            .note_reading(reading_ms)
+                            .is_some_and(|drift| {
+                                self.settings.adjust_reading_speed(drift.increases());
+                                true
+                            });
+                    if let Some(previous) = self.adaptive_speed.due_turn(tick_ms) {
+                        let effect = self.jump_reader_paragraph(previous);
+                        if !matches!(effect, Effect::Noop) {
+                            return Ok(effect);
+                        }
+                    }
+                    if let Some(request) = self.reader.due_window_retry(tick_ms) {
+                        return Ok(Effect::LoadReaderWindow(request));
+                    }
                     let previous_paragraph = self.reader.progress.paragraph_index;
+                    self.sync_reader_preferences();
                     let outcome = self
                         .reader
                         .advance_if_due(tick_ms, self.settings.reading_speed_wpm);
+                    if outcome.advanced
+                        && let Some(session) = self.history_session.as_mut()
+                    {
+                        session.words = session.words.saturating_add(1);
+                    }
+                    if outcome.finished && !self.reader.active_content_id.is_empty() {
+                        self.book_stats.record_finish(self.reader.active_content_id);
+                        self.pending_book_stats_write = true;
+                    }
+                    if outcome.unattended {
+                        return Ok(self.pause_unattended_reader(tick_ms));
+                    }
                     if self.reader.progress.paragraph_index != previous_paragraph {
                         self.track_reader_progress();
                     }
+                    if outcome.advanced && self.reader.current_unit().flags.section_start {
+                        self.queue_audio_cue(AudioCue::ChapterChime);
+                    }
+                    if outcome.countdown_tick {
+                        self.queue_audio_cue(AudioCue::CountdownTick);
+                    }
                     if let Some(request) = outcome.load_request {
                         return Ok(Effect::LoadReaderWindow(request));
                     }
+                    if goal_minute_reached || drifted {
+                        return Ok(self.persist_settings_effect());
+                    }
+                }
+            }
+            Event::LocalTimeChanged(time) => {
+                self.local_time = Some(time.local_time());
+                self.local_day = Some(time.day_number());
+            }
+            Event::AmbientLightChanged(lux) => self.ambient_light.record(lux),
+            Event::DiagnosticsSampled(snapshot) => self.diagnostics = snapshot,
+            Event::InactivityTimeout => return Ok(self.prepare_for_sleep()),
+            Event::ScreensaverTimeout => {
+                if self.screensaver_allowed()
+                    && now_ms.saturating_sub(self.sleep.last_activity_ms)
+                        >= self.settings.screensaver_after_ms()
+                {
+                    self.screensaver_visible = true;
                 }
             }
             Event::WokeFromDeepSleep => {
                 self.device.boot = BootState::DeepSleepWake;
                 self.sleep.mark_woke(WakeReason::ExternalButton, now_ms);
             }
-            Event::BootCompleted => {}
-            Event::Noop => {}
+            Event::ScreenshotSaved => self.show_toast(ToastKind::ScreenshotSaved, now_ms),
+            Event::BootCompleted => return Ok(self.restore_sleep_resume(true)),
+            // The shell transport runs these through `cli::execute` so it can collect the reply.
+            Event::CliCommand(_) | Event::Noop => {}
         }
 
         Ok(Effect::Noop)
@@ -459,10 +750,13 @@ impl Store {
         }) {
             self.pending_prepare = None;
         }
+        let from_start = self.open_from_start.take() == Some(content_id);
+        let into_contents = self.open_into_contents.take() == Some(content_id);
+        let into_bookmarks = self.open_into_bookmarks.take() == Some(content_id);
         let resume_paragraph_index = self
             .reading_progress
             .find_by_content_id(&content_id)
-            .filter(|entry| entry.remote_revision == remote_revision)
+            .filter(|entry| entry.remote_revision == remote_revision && !from_start)
             .map(|entry| entry.paragraph_index.max(1));
         let request = self.reader.open_cached_reader_content(
             collection,
@@ -481,6 +775,17 @@ impl Store {
             self.show_recommendation_articles();
         }
         self.ui.route = UiRoute::Reader;
+        if into_contents || into_bookmarks {
+            let is_saved = self
+                .content()
+                .collection_contains_content_id(CollectionKind::Saved, &content_id);
+            self.reader.pause(is_saved, self.sleep.last_activity_ms);
+            if into_contents {
+                self.reader.open_contents();
+            } else {
+                self.reader.open_bookmarks();
+            }
+        }
         self.track_reader_progress();
         request
     }
@@ -490,10 +795,147 @@ impl Store {
         self.track_reader_progress();
     }
 
+    pub fn sleep_resume(&self) -> SleepResume {
+        match self.ui.route {
+            UiRoute::Reader if !self.reader.active_content_id.is_empty() => SleepResume::Reader {
+                collection: self.reader.active_collection,
+                content_id: self.reader.active_content_id,
+            },
+            UiRoute::Collection(kind) | UiRoute::Search(kind) | UiRoute::BookDetail(kind) => {
+                SleepResume::Collection(kind)
+            }
+            UiRoute::Reader => SleepResume::Collection(self.reader.active_collection),
+            UiRoute::Dashboard | UiRoute::Settings | UiRoute::Diagnostics | UiRoute::History => {
+                SleepResume::Dashboard
+            }
+        }
+    }
+
+    // What every screen draws with: the chosen appearance, except that the light sensor follows
+    // the room once it has a reading, and otherwise night mode forces dark inside the night
+    // window once the time is known.
+    pub fn appearance(&self) -> AppearanceMode {
+        if self.settings.light_sensor
+            && let Some(appearance) = self.ambient_light.appearance()
+        {
+            appearance
+        } else if self.settings.night_mode && self.local_time.is_some_and(LocalTime::is_night) {
+            AppearanceMode::Dark
+        } else {
+            self.settings.appearance
+        }
+    }
+
+    // The screensaver only replaces screens nothing is happening on: the library and a paused book.
+    fn screensaver_allowed(&self) -> bool {
+        self.settings.screensaver_minutes > 0
+            && self.crash_report.is_none()
+            && !self.low_battery_warning
+            && !self.startup_splash_visible
+            && self.onboarding.is_none()
+            && self.ui.confirm.is_none()
+            && match self.ui.route {
+                UiRoute::Dashboard | UiRoute::Collection(_) => true,
+                UiRoute::Reader => matches!(self.reader.mode, crate::reader::ReaderMode::Paused),
+                _ => false,
+            }
+    }
+
+    fn prepare_for_sleep(&mut self) -> Effect {
+        self.track_reader_progress();
+        self.end_history_session();
+        self.sleep.request_sleep();
+        Effect::EnterDeepSleep
+    }
+
+    // The card is scanned after boot, so a reader resume whose collection has not arrived yet stays
+    // pending until that collection's first update.
+    fn restore_sleep_resume(&mut self, wait_for_library: bool) -> Effect {
+        let Some(resume) = self.pending_sleep_resume.take() else {
+            return Effect::Noop;
+        };
+
+        match resume {
+            SleepResume::Dashboard => Effect::Noop,
+            SleepResume::Collection(CollectionKind::Recommendations) => {
+                self.ui.route = UiRoute::Collection(CollectionKind::Recommendations);
+                self.enter_recommendations()
+            }
+            SleepResume::Collection(kind) => {
+                self.ui.route = UiRoute::Collection(kind);
+                Effect::Noop
+            }
+            SleepResume::Reader {
+                collection,
+                content_id,
+            } => {
+                self.ui.route = UiRoute::Collection(collection);
+                let collection_state = self.content().collection_state(collection);
+                if wait_for_library && collection_state.is_empty() {
+                    self.pending_sleep_resume = Some(resume);
+                    return Effect::Noop;
+                }
+                let Some(index) = (0..collection_state.len())
+                    .find(|index| collection_state.items[*index].content_id == content_id)
+                else {
+                    return Effect::Noop;
+                };
+                let cached = matches!(
+                    collection_state.items[index].package_state,
+                    PackageState::Cached
+                );
+                self.set_collection_index(collection, index);
+                if cached {
+                    self.confirm_collection_item(collection)
+                } else {
+                    Effect::Noop
+                }
+            }
+        }
+    }
+
     pub fn take_pending_reading_progress_write(&mut self) -> Option<ReadingProgressEntry> {
         self.pending_reading_progress_write.take()
     }
 
+    // The whole ring, for the platform to write over the profile's history file.
+    pub fn take_pending_history_write(&mut self) -> Option<HistoryState> {
+        core::mem::take(&mut self.pending_history_write).then_some(self.history)
+    }
+
+    // The whole table, for the platform to write over the profile's stats file.
+    pub fn take_pending_book_stats_write(&mut self) -> Option<BookStatsState> {
+        core::mem::take(&mut self.pending_book_stats_write).then_some(self.book_stats)
+    }
+
+    // The whole set, for the platform to write over the profile's bookmark file.
+    pub fn take_pending_bookmarks_write(&mut self) -> Option<BookmarkState> {
+        core::mem::take(&mut self.pending_bookmarks_write).then_some(self.bookmarks)
+    }
+
+    pub fn take_pending_quote(&mut self) -> Option<Quote> {
+        self.pending_quote.take()
+    }
+
+    // The open book's marked paragraphs in reading order.
+    pub fn reader_bookmarks(&self) -> ([u16; BOOKMARK_CAPACITY], usize) {
+        self.bookmarks.paragraphs_for(
+            &self.reader.active_content_id,
+            self.reader.active_remote_revision,
+        )
+    }
+
+    pub fn take_pending_audio_cue(&mut self) -> Option<AudioCue> {
+        self.pending_audio_cue.take()
+    }
+
+    // A later cue replaces one the platform has not picked up yet; they are too short to queue.
+    fn queue_audio_cue(&mut self, cue: AudioCue) {
+        if !self.settings.audio_volume.is_off() {
+            self.pending_audio_cue = Some(cue);
+        }
+    }
+
     pub fn content(&self) -> &ContentState {
         self.content.as_deref().unwrap_or(&EMPTY_CONTENT_STATE)
     }
@@ -504,6 +946,18 @@ impl Store {
             .as_mut()
     }
 
+    fn merge_device_books(&mut self) {
+        let Some(device_books) = self.device_books.take() else {
+            return;
+        };
+        if !device_books.is_empty() {
+            self.content_mut()
+                .collection_state_mut(CollectionKind::Saved)
+                .merge_missing(&device_books);
+        }
+        self.device_books = Some(device_books);
+    }
+
     fn focus_recommendation_subtopics(&mut self, flash: bool) {
         self.ui.recommendations_region = RecommendationsRegion::Subtopics;
         self.ui.recommendations_focus_flash_ticks = if flash {
@@ -545,6 +999,20 @@ impl Store {
     }
 
     fn dispatch_ui(&mut self, command: UiCommand) -> Effect {
+        if self.crash_report.is_some() {
+            if matches!(command, UiCommand::Confirm | UiCommand::Back) {
+                self.crash_report = None;
+            }
+            return Effect::Noop;
+        }
+
+        if self.low_battery_warning {
+            if matches!(command, UiCommand::Confirm | UiCommand::Back) {
+                self.low_battery_warning = false;
+            }
+            return Effect::Noop;
+        }
+
         if self.startup_splash_visible {
             if matches!(command, UiCommand::Back) {
                 self.startup_splash_visible = false;
@@ -552,12 +1020,133 @@ impl Store {
             return Effect::Noop;
         }
 
+        if let Some(onboarding) = self.onboarding {
+            return self.dispatch_onboarding(command, onboarding);
+        }
+
+        // Any input only wakes the screen, so a stray turn cannot move the library underneath.
+        if self.screensaver_visible {
+            self.screensaver_visible = false;
+            return Effect::Noop;
+        }
+
+        if let Some(dialog) = self.ui.confirm {
+            return self.dispatch_confirm(command, dialog);
+        }
+
         match self.ui.route {
             UiRoute::Dashboard => self.dispatch_dashboard(command),
             UiRoute::Collection(kind) => self.dispatch_collection(command, kind),
-            UiRoute::Reader => self.dispatch_reader(command),
+            UiRoute::Search(kind) => self.dispatch_search(command, kind),
+            UiRoute::BookDetail(kind) => self.dispatch_book_detail(command, kind),
+            UiRoute::Reader => {
+                let effect = self.dispatch_reader(command);
+                self.reader.note_interaction();
+                if !matches!(self.ui.route, UiRoute::Reader) || !self.reader.is_active_reading() {
+                    self.end_history_session();
+                }
+                effect
+            }
             UiRoute::Settings => self.dispatch_settings(command),
+            UiRoute::Diagnostics => {
+                if matches!(command, UiCommand::Back) {
+                    self.ui.route = UiRoute::Settings;
+                }
+                Effect::Noop
+            }
+            UiRoute::History => {
+                let last = self.history.len().saturating_sub(1);
+                match command {
+                    UiCommand::FocusPrevious => {
+                        self.ui.history_index = self.ui.history_index.saturating_sub(1);
+                    }
+                    UiCommand::FocusNext => {
+                        self.ui.history_index = (self.ui.history_index + 1).min(last);
+                    }
+                    UiCommand::Back => self.ui.route = UiRoute::Settings,
+                    UiCommand::Confirm | UiCommand::Noop => {}
+                }
+                Effect::Noop
+            }
+        }
+    }
+
+    // A reversed encoder swaps the two turn directions before anything else sees them.
+    const fn oriented(&self, command: UiCommand) -> UiCommand {
+        match command {
+            UiCommand::FocusPrevious if self.settings.encoder_reversed => UiCommand::FocusNext,
+            UiCommand::FocusNext if self.settings.encoder_reversed => UiCommand::FocusPrevious,
+            command => command,
+        }
+    }
+
+    // Click moves forward and a long press goes back a step. Nothing is persisted until the last
+    // step, so losing power part way through starts the walk-through again.
+    fn dispatch_onboarding(
+        &mut self,
+        command: UiCommand,
+        mut onboarding: OnboardingState,
+    ) -> Effect {
+        match (onboarding.step, command) {
+            (OnboardingStep::Encoder, UiCommand::FocusPrevious | UiCommand::FocusNext) => {
+                // The step asks for a clockwise turn, so one arriving as "previous" means the
+                // knob is wired the other way round.
+                if matches!(command, UiCommand::FocusPrevious) {
+                    self.settings.encoder_reversed = !self.settings.encoder_reversed;
+                }
+                onboarding.direction_confirmed = true;
+            }
+            (OnboardingStep::ReadingSpeed, UiCommand::FocusPrevious) => {
+                self.settings.adjust_reading_speed(true);
+            }
+            (OnboardingStep::ReadingSpeed, UiCommand::FocusNext) => {
+                self.settings.adjust_reading_speed(false);
+            }
+            (step, UiCommand::Confirm) => match step.next() {
+                Some(next) => {
+                    onboarding.step = next;
+                    onboarding.restart_demo();
+                }
+                None => {
+                    self.onboarding = None;
+                    self.settings.onboarded = true;
+                    return self.persist_settings_effect();
+                }
+            },
+            (step, UiCommand::Back) => {
+                if let Some(previous) = step.previous() {
+                    onboarding.step = previous;
+                    onboarding.restart_demo();
+                }
+            }
+            _ => {}
+        }
+
+        self.onboarding = Some(onboarding);
+        Effect::Noop
+    }
+
+    // The dialog holds every input until answered; rotation moves between the two choices.
+    fn dispatch_confirm(&mut self, command: UiCommand, mut dialog: ConfirmDialog) -> Effect {
+        match command {
+            UiCommand::FocusPrevious | UiCommand::FocusNext => {
+                dialog.accept_focused = !dialog.accept_focused;
+                self.ui.confirm = Some(dialog);
+            }
+            UiCommand::Confirm => {
+                self.ui.confirm = None;
+                if dialog.accept_focused {
+                    return match dialog.action {
+                        ConfirmAction::DeleteBook(kind) => self.delete_book(kind),
+                        ConfirmAction::FactoryReset => Effect::FactoryReset,
+                    };
+                }
+            }
+            UiCommand::Back => self.ui.confirm = None,
+            UiCommand::Noop => {}
         }
+
+        Effect::Noop
     }
 
     fn dispatch_dashboard(&mut self, command: UiCommand) -> Effect {
@@ -588,11 +1177,39 @@ impl Store {
         }
 
         let collection_len = self.content().collection_len(kind);
+        self.ui.collection_jump_letter = None;
+        if matches!(command, UiCommand::FocusPrevious | UiCommand::FocusNext) {
+            let now_ms = self.sleep.last_activity_ms;
+            let fast = self.ui.collection_rotation_at_ms.is_some_and(|last| {
+                now_ms.saturating_sub(last) < COLLECTION_FAST_SCROLL_ROTATION_MS
+            });
+            self.ui.collection_rotation_at_ms = Some(now_ms);
+            if fast && collection_len >= COLLECTION_FAST_SCROLL_MIN_ITEMS {
+                self.jump_collection_letter(kind, matches!(command, UiCommand::FocusPrevious));
+                return Effect::Noop;
+            }
+            // Turning back past the first item opens search.
+            if !fast
+                && matches!(command, UiCommand::FocusPrevious)
+                && collection_len > 0
+                && self.ui.collection_index(kind) == 0
+            {
+                self.ui.search_picker.reset();
+                self.ui.route = UiRoute::Search(kind);
+                return Effect::Noop;
+            }
+        }
 
         match command {
             UiCommand::FocusPrevious => self.ui.move_collection_previous(kind, collection_len),
             UiCommand::FocusNext => self.ui.move_collection_next(kind, collection_len),
-            UiCommand::Confirm => return self.confirm_collection_item(kind),
+            UiCommand::Confirm => {
+                if collection_len == 0 {
+                    return self.confirm_collection_item(kind);
+                }
+                self.ui.book_detail_action = BookDetailAction::Read;
+                self.ui.route = UiRoute::BookDetail(kind);
+            }
             UiCommand::Back => {
                 self.ui.route = UiRoute::Dashboard;
                 self.ui.dashboard_focus = match kind {
@@ -607,6 +1224,169 @@ impl Store {
         Effect::Noop
     }
 
+    // Library items open onto their detail screen; reading starts from an action there. Delete
+    // asks first, and only for a book that is actually on the device.
+    fn dispatch_book_detail(&mut self, command: UiCommand, kind: CollectionKind) -> Effect {
+        let action_index = self.ui.book_detail_action.index();
+        match command {
+            UiCommand::FocusPrevious => {
+                self.ui.book_detail_action =
+                    BookDetailAction::from_index(action_index.saturating_sub(1));
+            }
+            UiCommand::FocusNext => {
+                self.ui.book_detail_action = BookDetailAction::from_index(action_index + 1);
+            }
+            UiCommand::Confirm if self.ui.book_detail_action == BookDetailAction::Delete => {
+                if self.book_detail_item_on_device(kind) {
+                    self.ui.confirm = Some(ConfirmDialog::new(ConfirmAction::DeleteBook(kind)));
+                }
+            }
+            UiCommand::Confirm if self.ui.book_detail_action == BookDetailAction::Finished => {
+                if let Some(item) = self
+                    .content()
+                    .manifest_item_at(kind, self.ui.collection_index(kind))
+                {
+                    self.book_stats.toggle_finished(item.content_id);
+                    self.pending_book_stats_write = true;
+                }
+            }
+            UiCommand::Confirm => {
+                let item = self
+                    .content()
+                    .manifest_item_at(kind, self.ui.collection_index(kind));
+                self.ui.route = UiRoute::Collection(kind);
+                match (self.ui.book_detail_action, item) {
+                    (BookDetailAction::ReadFromStart, Some(item)) => {
+                        self.open_from_start = Some(item.content_id);
+                    }
+                    (BookDetailAction::Contents, Some(item)) => {
+                        self.open_into_contents = Some(item.content_id);
+                    }
+                    (BookDetailAction::Bookmarks, Some(item)) => {
+                        self.open_into_bookmarks = Some(item.content_id);
+                    }
+                    _ => {}
+                }
+                return self.confirm_collection_item(kind);
+            }
+            UiCommand::Back => self.ui.route = UiRoute::Collection(kind),
+            UiCommand::Noop => {}
+        }
+
+        Effect::Noop
+    }
+
+    fn book_detail_item_on_device(&self, kind: CollectionKind) -> bool {
+        self.content()
+            .manifest_item_at(kind, self.ui.collection_index(kind))
+            .is_some_and(|item| {
+                matches!(
+                    item.package_state,
+                    PackageState::Cached | PackageState::Stale
+                )
+            })
+    }
+
+    // Updates the lists in place rather than waiting for the platform: a book that only came from
+    // flash leaves the list, and a synced item stays listed as not downloaded.
+    fn delete_book(&mut self, kind: CollectionKind) -> Effect {
+        let index = self.ui.collection_index(kind);
+        let Some(item) = self.content().manifest_item_at(kind, index) else {
+            return Effect::Noop;
+        };
+
+        let mut flash_only = false;
+        if let Some(device_books) = self.device_books.as_deref_mut() {
+            flash_only =
+                (0..device_books.len()).any(|book| device_books.item_at(book) == Some(item));
+            device_books.remove_content_id(&item.content_id);
+        }
+        for collection_kind in CollectionKind::ALL {
+            let collection = self.content_mut().collection_state_mut(collection_kind);
+            if flash_only {
+                collection.remove_content_id(&item.content_id);
+                continue;
+            }
+            for item_index in 0..collection.len() {
+                if let Some(listed) = collection.item_mut_at(item_index)
+                    && listed.content_id == item.content_id
+                {
+                    listed.package_state = PackageState::Missing;
+                }
+            }
+        }
+
+        let remaining = self.content().collection_state(kind).len();
+        self.set_collection_index(kind, index.min(remaining.saturating_sub(1)));
+        self.ui.route = UiRoute::Collection(kind);
+        self.show_toast(ToastKind::BookDeleted, self.sleep.last_activity_ms);
+        Effect::DeleteBook(PrepareContentRequest::from_manifest(kind, item))
+    }
+
+    // The query narrows the list live on the search screen; `OK` lands on the first match back in
+    // the list, and Back leaves the selection where it was.
+    fn dispatch_search(&mut self, command: UiCommand, kind: CollectionKind) -> Effect {
+        match command {
+            UiCommand::FocusPrevious => self.ui.search_picker.move_previous(),
+            UiCommand::FocusNext => self.ui.search_picker.move_next(),
+            UiCommand::Confirm => {
+                if matches!(self.ui.search_picker.confirm(), PickerOutcome::Done) {
+                    let query = self.ui.search_picker.text;
+                    let first_match = self
+                        .content()
+                        .collection_state(kind)
+                        .search(query.as_str())
+                        .next();
+                    if let Some(index) = first_match {
+                        self.set_collection_index(kind, index);
+                        self.ui.route = UiRoute::Collection(kind);
+                    }
+                }
+            }
+            UiCommand::Back => self.ui.route = UiRoute::Collection(kind),
+            UiCommand::Noop => {}
+        }
+
+        Effect::Noop
+    }
+
+    // Fast rotation in a long list moves to the first item under the next (or previous) title
+    // initial, in list order, and flashes that letter over the list.
+    fn jump_collection_letter(&mut self, kind: CollectionKind, previous: bool) {
+        let collection = self.content().collection_state(kind);
+        let current_index = self.ui.collection_index(kind);
+        let Some(current) = collection.item_at(current_index) else {
+            return;
+        };
+        let current_initial = title_initial(current.title.as_str());
+
+        let mut target: Option<(usize, char)> = None;
+        for index in 0..collection.len() {
+            let Some(item) = collection.item_at(index) else {
+                break;
+            };
+            let initial = title_initial(item.title.as_str());
+            let closer = match target {
+                None => true,
+                Some((_, best)) if previous => initial > best,
+                Some((_, best)) => initial < best,
+            };
+            let beyond = if previous {
+                initial < current_initial
+            } else {
+                initial > current_initial
+            };
+            if beyond && closer {
+                target = Some((index, initial));
+            }
+        }
+
+        let (index, initial) = target.unwrap_or((current_index, current_initial));
+        self.set_collection_index(kind, index);
+        self.ui.collection_jump_letter = Some(initial);
+        self.ui.collection_jump_letter_at_ms = self.sleep.last_activity_ms;
+    }
+
     fn enter_recommendations(&mut self) -> Effect {
         if self.recommendations.subtopics.is_empty() {
             self.focus_recommendation_subtopics(false);
@@ -920,7 +1700,18 @@ impl Store {
         ))
     }
 
-    fn dispatchable_pending_prepare_request(&mut self) -> Option<PrepareContentRequest> {
+    fn dispatch_pause_definition_lookup(&mut self) -> Effect {
+        if !self.backend_actions_available() {
+            return Effect::Noop;
+        }
+
+        match self.reader.open_definition() {
+            Some(word) => Effect::LookupDefinition(DictionaryLookupRequest::new(word)),
+            None => Effect::Noop,
+        }
+    }
+
+    fn dispatchable_pending_prepare_request(&mut self) -> Option<PrepareContentRequest> {
         if !self.can_dispatch_prepare_now() {
             return None;
         }
@@ -1008,35 +1799,31 @@ impl Store {
     fn dispatch_reader(&mut self, command: UiCommand) -> Effect {
         match self.reader.mode {
             ReaderMode::Normal | ReaderMode::Chat => match command {
-                UiCommand::FocusPrevious => {
-                    let request = self
-                        .reader
-                        .jump_live_previous_paragraph(self.settings.reading_speed_wpm);
-                    self.track_reader_progress();
-                    if let Some(request) = request {
-                        return Effect::LoadReaderWindow(request);
-                    }
+                UiCommand::FocusPrevious | UiCommand::FocusNext => {
+                    return self.dispatch_reader_turn(matches!(command, UiCommand::FocusPrevious));
                 }
-                UiCommand::FocusNext => {
-                    let request = self
-                        .reader
-                        .jump_live_next_paragraph(self.settings.reading_speed_wpm);
-                    self.track_reader_progress();
-                    if let Some(request) = request {
+                UiCommand::Confirm if self.reader.is_stream_stalled() => {
+                    if let Some(request) = self.reader.retry_stalled_window() {
                         return Effect::LoadReaderWindow(request);
                     }
                 }
                 UiCommand::Confirm => {
+                    self.adaptive_speed.drop_turn();
+                    if self.settings.adaptive_speed {
+                        self.adaptive_speed
+                            .note_interruption(self.sleep.last_activity_ms);
+                    }
                     let is_saved = self.content().collection_contains_content_id(
                         CollectionKind::Saved,
                         &self.reader.active_content_id,
                     );
-                    self.reader.pause(is_saved);
+                    self.reader.pause(is_saved, self.sleep.last_activity_ms);
                     if let Some(effect) = self.reader_pause_detail_effect() {
                         return effect;
                     }
                 }
                 UiCommand::Back => {
+                    self.adaptive_speed.drop_turn();
                     self.ui.route = UiRoute::Collection(self.reader.active_collection);
                     self.reader.unload_document();
                     self.reader.mode = ReaderMode::Normal;
@@ -1049,9 +1836,9 @@ impl Store {
                 UiCommand::FocusPrevious => self.reader.move_pause_selection(true),
                 UiCommand::FocusNext => self.reader.move_pause_selection(false),
                 UiCommand::Confirm => return self.dispatch_pause_action(),
-                UiCommand::Back => {
-                    self.reader.resume(self.settings.reading_speed_wpm);
-                }
+                // The one place a long press is not Back: it marks the paragraph, and the
+                // RESUME RSVP row the menu opens on takes reading back up.
+                UiCommand::Back => self.toggle_bookmark(),
                 UiCommand::Noop => {}
             },
             ReaderMode::ParagraphNavigation => match command {
@@ -1069,6 +1856,96 @@ impl Store {
                 UiCommand::Back => self.reader.close_paragraph_navigation(),
                 UiCommand::Noop => {}
             },
+            ReaderMode::Contents => match command {
+                UiCommand::FocusPrevious => self.reader.move_contents(true),
+                UiCommand::FocusNext => self.reader.move_contents(false),
+                UiCommand::Confirm => {
+                    self.sync_reader_preferences();
+                    let request = self.reader.commit_contents(self.settings.reading_speed_wpm);
+                    self.track_reader_progress();
+                    if let Some(request) = request {
+                        return Effect::LoadReaderWindow(request);
+                    }
+                }
+                UiCommand::Back => self.reader.close_contents(),
+                UiCommand::Noop => {}
+            },
+            ReaderMode::Bookmarks => match command {
+                UiCommand::FocusPrevious | UiCommand::FocusNext => {
+                    let (_, count) = self.reader_bookmarks();
+                    self.reader
+                        .move_bookmarks(matches!(command, UiCommand::FocusPrevious), count);
+                }
+                UiCommand::Confirm => {
+                    let (paragraphs, count) = self.reader_bookmarks();
+                    let index = usize::from(self.reader.bookmarks_index);
+                    if index >= count {
+                        return Effect::Noop;
+                    }
+                    self.sync_reader_preferences();
+                    let request = self
+                        .reader
+                        .commit_bookmark(paragraphs[index], self.settings.reading_speed_wpm);
+                    self.track_reader_progress();
+                    if let Some(request) = request {
+                        return Effect::LoadReaderWindow(request);
+                    }
+                }
+                UiCommand::Back => self.reader.close_bookmarks(),
+                UiCommand::Noop => {}
+            },
+            ReaderMode::WordScrub => match command {
+                UiCommand::FocusPrevious | UiCommand::FocusNext => {
+                    let request = self.reader.scrub(
+                        matches!(command, UiCommand::FocusPrevious),
+                        self.sleep.last_activity_ms,
+                        self.settings.reading_speed_wpm,
+                    );
+                    self.track_reader_progress();
+                    if let Some(request) = request {
+                        return Effect::LoadReaderWindow(request);
+                    }
+                }
+                UiCommand::Confirm => {
+                    self.sync_reader_preferences();
+                    self.reader
+                        .resume_from_scrub(self.settings.reading_speed_wpm);
+                }
+                UiCommand::Back => self.reader.close_word_scrub(),
+                UiCommand::Noop => {}
+            },
+            ReaderMode::Page => match command {
+                UiCommand::FocusPrevious | UiCommand::FocusNext => {
+                    let request = self.reader.turn_page(
+                        matches!(command, UiCommand::FocusPrevious),
+                        self.settings.reading_speed_wpm,
+                    );
+                    self.track_reader_progress();
+                    if let Some(request) = request {
+                        return Effect::LoadReaderWindow(request);
+                    }
+                }
+                UiCommand::Confirm => {
+                    self.sync_reader_preferences();
+                    self.reader
+                        .resume_from_page(self.settings.reading_speed_wpm);
+                }
+                UiCommand::Back => self.reader.close_page_view(),
+                UiCommand::Noop => {}
+            },
+            ReaderMode::Definition => match command {
+                UiCommand::FocusPrevious => self.reader.scroll_definition(true),
+                UiCommand::FocusNext => self.reader.scroll_definition(false),
+                UiCommand::Confirm | UiCommand::Back => self.reader.close_definition(),
+                UiCommand::Noop => {}
+            },
+            ReaderMode::SectionBreak => match command {
+                UiCommand::Confirm | UiCommand::Back => {
+                    self.reader
+                        .continue_after_section_break(self.settings.reading_speed_wpm);
+                }
+                UiCommand::FocusPrevious | UiCommand::FocusNext | UiCommand::Noop => {}
+            },
             ReaderMode::LoadingContent => match command {
                 UiCommand::Back => {
                     if let Some(pending) = self.pending_prepare.as_mut() {
@@ -1087,23 +1964,153 @@ impl Store {
         Effect::Noop
     }
 
+    fn history_stamp(&self) -> Option<HistoryStamp> {
+        Some(HistoryStamp {
+            day: self.local_day?,
+            minute: self.local_time?.minute_of_day(),
+        })
+    }
+
+    fn begin_history_session(&mut self) {
+        if self.history_session.is_some() {
+            return;
+        }
+
+        let mut entry = HistoryEntry::empty();
+        entry.content_id = self.reader.active_content_id;
+        entry.title.set_truncated(self.reader.title.as_str());
+        entry.paragraph_index = self.reader.progress.paragraph_index;
+        entry.started_at = self.history_stamp();
+        self.history_session = Some(entry);
+    }
+
+    // Reading stopped: a pause, leaving the book, or sleep. A session that showed no words leaves
+    // no entry.
+    fn end_history_session(&mut self) {
+        let Some(mut entry) = self.history_session.take() else {
+            return;
+        };
+        if entry.words == 0 {
+            return;
+        }
+
+        entry.ended_at = self.history_stamp();
+        self.history.push(entry);
+        self.pending_history_write = true;
+    }
+
+    // A whole chapter went by at speed without input, most likely with the device set down, so
+    // reading stops on the pause menu back where the reader last touched it.
+    fn pause_unattended_reader(&mut self, now_ms: u64) -> Effect {
+        // Words that went by unattended were not read, so the session gives them back.
+        let skipped = self
+            .reader
+            .progress
+            .unit_index
+            .saturating_sub(self.reader.interaction_unit_index());
+        if let Some(session) = self.history_session.as_mut() {
+            session.words = session.words.saturating_sub(skipped);
+        }
+        self.end_history_session();
+        let request = self
+            .reader
+            .rewind_to_interaction(self.settings.reading_speed_wpm);
+        let is_saved = self
+            .content()
+            .collection_contains_content_id(CollectionKind::Saved, &self.reader.active_content_id);
+        self.reader.pause(is_saved, now_ms);
+        self.track_reader_progress();
+        self.show_toast(ToastKind::AutoPaused, now_ms);
+        match request {
+            Some(request) => Effect::LoadReaderWindow(request),
+            None => self.reader_pause_detail_effect().unwrap_or(Effect::Noop),
+        }
+    }
+
+    // With adaptive speed on, a turn waits out the double-turn window before it jumps, so a quick
+    // second turn the same way can take a speed step instead.
+    fn dispatch_reader_turn(&mut self, previous: bool) -> Effect {
+        if !self.settings.adaptive_speed {
+            return self.jump_reader_paragraph(previous);
+        }
+
+        let now_ms = self.sleep.last_activity_ms;
+        if let Some(drift) = self.adaptive_speed.double_turn(previous, now_ms) {
+            self.adaptive_speed.restart();
+            self.settings.adjust_reading_speed(drift.increases());
+            return self.persist_settings_effect();
+        }
+        match self.adaptive_speed.hold_turn(previous, now_ms) {
+            Some(held_previous) => self.jump_reader_paragraph(held_previous),
+            None => Effect::Noop,
+        }
+    }
+
+    fn jump_reader_paragraph(&mut self, previous: bool) -> Effect {
+        if !matches!(self.reader.mode, ReaderMode::Normal | ReaderMode::Chat) {
+            return Effect::Noop;
+        }
+
+        let request = if previous {
+            if self.settings.adaptive_speed {
+                self.adaptive_speed
+                    .note_interruption(self.sleep.last_activity_ms);
+            }
+            self.reader
+                .jump_live_previous_paragraph(self.settings.reading_speed_wpm)
+        } else {
+            self.reader
+                .jump_live_next_paragraph(self.settings.reading_speed_wpm)
+        };
+        self.track_reader_progress();
+        match request {
+            Some(request) => Effect::LoadReaderWindow(request),
+            None => Effect::Noop,
+        }
+    }
+
     fn dispatch_pause_action(&mut self) -> Effect {
         match self.reader.selected_pause_row() {
-            PauseMenuRow::ResumeRsvp => {
-                self.reader.resume(self.settings.reading_speed_wpm);
-                Effect::Noop
-            }
+            PauseMenuRow::ResumeRsvp => self.resume_reader(),
             PauseMenuRow::ParagraphView => {
                 self.reader.open_paragraph_navigation();
                 Effect::Noop
             }
+            PauseMenuRow::PageView => {
+                self.reader.open_page_view();
+                Effect::Noop
+            }
+            PauseMenuRow::ScrubWords => {
+                self.reader.open_word_scrub();
+                Effect::Noop
+            }
             PauseMenuRow::SaveArticle => self.dispatch_pause_save_toggle(),
             PauseMenuRow::Subscription => self.dispatch_pause_subscription_toggle(),
+            PauseMenuRow::DefineWord => self.dispatch_pause_definition_lookup(),
+        }
+    }
+
+    fn resume_reader(&mut self) -> Effect {
+        self.sync_reader_preferences();
+        let request = self
+            .reader
+            .resume(self.settings.reading_speed_wpm, self.sleep.last_activity_ms);
+        self.track_reader_progress();
+        match request {
+            Some(request) => Effect::LoadReaderWindow(request),
+            None => Effect::Noop,
         }
     }
 
+    fn sync_reader_preferences(&mut self) {
+        self.reader.dwell_timing = self.settings.dwell_timing();
+        self.reader.section_pauses = self.settings.section_pauses;
+        self.reader.rewind_words = self.settings.rewind_words;
+        self.reader.countdown_seconds = self.settings.countdown_seconds;
+    }
+
     fn dispatch_settings(&mut self, command: UiCommand) -> Effect {
-        match self.ui.settings_mode {
+        let effect = match self.ui.settings_mode {
             SettingsMode::Master => self.dispatch_settings_master(command),
             SettingsMode::SpeedEdit => self.dispatch_speed_edit(command),
             SettingsMode::AppearanceEdit => self.dispatch_appearance_edit(command),
@@ -1115,25 +2122,57 @@ impl Store {
                 Effect::Noop
             }
             SettingsMode::TopicPreferences => self.dispatch_topic_preferences(command),
+        };
+
+        // Every edit is written as it happens; one toast on the way out confirms them all.
+        if matches!(effect, Effect::PersistSettings(_)) {
+            self.ui.settings_changed = true;
+        }
+        if !matches!(self.ui.route, UiRoute::Settings)
+            && core::mem::take(&mut self.ui.settings_changed)
+        {
+            self.show_toast(ToastKind::SettingsSaved, self.sleep.last_activity_ms);
         }
+        effect
     }
 
     fn dispatch_settings_master(&mut self, command: UiCommand) -> Effect {
+        let at_last_row = self.ui.settings_row.index() + 1 == SettingsRow::COUNT;
+        if matches!(command, UiCommand::FocusNext) && at_last_row {
+            self.ui.settings_overscroll += 1;
+            if self.ui.settings_overscroll >= DIAGNOSTICS_UNLOCK_TURNS {
+                self.ui.settings_overscroll = 0;
+                self.ui.route = UiRoute::Diagnostics;
+            }
+            return Effect::Noop;
+        }
+        self.ui.settings_overscroll = 0;
+
         match command {
             UiCommand::FocusPrevious => self.ui.move_settings_previous(),
             UiCommand::FocusNext => self.ui.move_settings_next(),
-            UiCommand::Confirm => match self.ui.settings_row {
-                SettingsRow::ReadingSpeed => self.ui.settings_mode = SettingsMode::SpeedEdit,
-                SettingsRow::Appearance => self.ui.settings_mode = SettingsMode::AppearanceEdit,
-                SettingsRow::RefreshData => {
-                    self.ui.settings_mode = SettingsMode::RefreshLoading;
-                    self.settings.start_refresh(self.sleep.last_activity_ms);
-                }
-                SettingsRow::TopicPreferences => {
-                    self.ui.settings_mode = SettingsMode::TopicPreferences;
-                    self.ui.topic_focus.region = TopicRegion::Categories;
+            UiCommand::Confirm => match self.ui.settings_row.entry().action {
+                SettingsAction::None => {}
+                SettingsAction::Adjust(adjust) => {
+                    adjust(&mut self.settings);
+                    return self.persist_settings_effect();
+                }
+                SettingsAction::Open(mode) => self.open_settings_mode(mode),
+                SettingsAction::Screenshot => {
+                    let now_ms = self.sleep.last_activity_ms;
+                    self.ui.screenshot_at_ms = Some(now_ms + SCREENSHOT_TIMER_MS);
+                    self.show_toast(ToastKind::ScreenshotTimer, now_ms);
+                }
+                SettingsAction::SwitchProfile => {
+                    return Effect::SwitchProfile(self.settings.next_profile());
+                }
+                SettingsAction::FactoryReset => {
+                    self.ui.confirm = Some(ConfirmDialog::new(ConfirmAction::FactoryReset));
+                }
+                SettingsAction::History => {
+                    self.ui.history_index = 0;
+                    self.ui.route = UiRoute::History;
                 }
-                SettingsRow::NetworkConnection | SettingsRow::ConnectAccount => {}
             },
             UiCommand::Back => self.ui.route = UiRoute::Dashboard,
             UiCommand::Noop => {}
@@ -1142,6 +2181,20 @@ impl Store {
         Effect::Noop
     }
 
+    fn open_settings_mode(&mut self, mode: SettingsMode) {
+        self.ui.settings_mode = mode;
+        match mode {
+            SettingsMode::SpeedEdit => self.ui.speed_preview.restart(),
+            SettingsMode::RefreshLoading => {
+                self.settings.start_refresh(self.sleep.last_activity_ms);
+            }
+            SettingsMode::TopicPreferences => {
+                self.ui.topic_focus.region = TopicRegion::Categories;
+            }
+            SettingsMode::Master | SettingsMode::AppearanceEdit => {}
+        }
+    }
+
     fn dispatch_speed_edit(&mut self, command: UiCommand) -> Effect {
         match command {
             UiCommand::FocusPrevious => {
@@ -1211,6 +2264,42 @@ impl Store {
         Effect::Noop
     }
 
+    fn toggle_bookmark(&mut self) {
+        let paragraph_index = self.reader.progress.paragraph_index;
+        if self.reader.active_content_id.is_empty() || paragraph_index == 0 {
+            return;
+        }
+
+        let marked = self.bookmarks.toggle(Bookmark {
+            content_id: self.reader.active_content_id,
+            remote_revision: self.reader.active_remote_revision,
+            paragraph_index,
+        });
+        self.pending_bookmarks_write = true;
+        if marked && self.settings.quote_export {
+            self.pending_quote = Some(Quote {
+                title: self.reader.title,
+                paragraph_index,
+                preview: self.reader.preview_for_paragraph(paragraph_index),
+            });
+        }
+        self.show_toast(
+            if marked {
+                ToastKind::BookmarkAdded
+            } else {
+                ToastKind::BookmarkRemoved
+            },
+            self.sleep.last_activity_ms,
+        );
+    }
+
+    // The startup splash covers the whole panel, so anything raised behind it is dropped.
+    fn show_toast(&mut self, kind: ToastKind, now_ms: u64) {
+        if !self.startup_splash_visible {
+            self.ui.toasts.push(kind, now_ms);
+        }
+    }
+
     fn persist_settings_effect(&self) -> Effect {
         Effect::PersistSettings(self.settings.to_persisted())
     }
@@ -1261,20 +2350,34 @@ fn collection_package_state_for_remote_item(
     None
 }
 
+// Letters group case-insensitively; titles opening with anything else share `#`, which sorts
+// ahead of every letter.
+fn title_initial(title: &str) -> char {
+    match title.trim_start().chars().next() {
+        Some(first) if first.is_ascii_alphabetic() => first.to_ascii_uppercase(),
+        _ => '#',
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
+        audio::AudioVolume,
+        clock::DateTime,
         content::{
-            CollectionManifestItem, CollectionManifestState, DetailLocator, PackageState,
-            RECOMMENDATION_SUBTOPIC_SLUG_MAX_BYTES, ReadingProgressEntry, RecommendationSubtopic,
-            RecommendationSubtopicsState, RemoteContentStatus,
+            CollectionManifestItem, CollectionManifestState, DetailLocator, MANIFEST_ITEM_CAPACITY,
+            PackageState, RECOMMENDATION_SUBTOPIC_SLUG_MAX_BYTES, ReadingProgressEntry,
+            RecommendationSubtopic, RecommendationSubtopicsState, RemoteContentStatus,
         },
         device::{BootState, DeviceState},
-        formatter::{article_document_from_script, format_article_document},
+        formatter::{ReadingDocument, article_document_from_script, format_article_document},
+        input::{InputGesture, RotationDirection},
         network::{NetworkState, NetworkStatus},
+        perf::PerfCounters,
         reader::{ReaderParagraphInfo, ReaderWindow},
         runtime::CollectionConfirmIgnoredReason,
+        selectors::{ActiveScreenModel, ContentsMarker, select_active_screen},
         settings::{AppearanceMode, PersistedSettings},
         storage::{StorageHealth, StorageRecoveryStatus},
         sync::SyncStatus,
@@ -1292,6 +2395,19 @@ mod tests {
         item
     }
 
+    fn read_from_book_detail(store: &mut Store) -> Effect {
+        let kind = match store.ui.route {
+            UiRoute::Collection(kind) => kind,
+            route => panic!("expected a collection route, got {route:?}"),
+        };
+        assert_eq!(
+            store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap(),
+            Effect::Noop
+        );
+        assert_eq!(store.ui.route, UiRoute::BookDetail(kind));
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap()
+    }
+
     fn make_manifest_item(remote_item_id: &str, title: &str) -> CollectionManifestItem {
         let mut item = CollectionManifestItem::empty();
         item.remote_item_id.set_truncated(remote_item_id);
@@ -1343,6 +2459,12 @@ mod tests {
             false,
             true,
         ));
+        let _ = subtopics.try_push(make_recommendation_subtopic(
+            "retro-computing",
+            "Retro Computing",
+            false,
+            false,
+        ));
         subtopics
     }
 
@@ -1615,91 +2737,558 @@ mod tests {
     }
 
     #[test]
-    fn dashboard_confirm_opens_selected_collection() {
+    fn fast_rotation_in_long_collection_jumps_by_title_initial() {
         let mut store = Store::new();
+        store.ui.route = UiRoute::Collection(CollectionKind::Saved);
+        let mut manifest = CollectionManifestState::empty();
+        for index in 0..MANIFEST_ITEM_CAPACITY as u8 {
+            let mut title = alloc::string::String::new();
+            title.push((b'a' + index / 3) as char);
+            title.push_str(" title");
+            let id = alloc::format!("saved-{index}");
+            let _ = manifest.try_push(make_manifest_item(&id, &title));
+        }
+        store
+            .content_mut()
+            .update_collection(CollectionKind::Saved, manifest);
+        let rotate = |store: &mut Store, at_ms: u64, previous: bool| {
+            let direction = if previous {
+                RotationDirection::CounterClockwise
+            } else {
+                RotationDirection::Clockwise
+            };
+            let gesture = InputGesture::Rotate { direction };
+            store
+                .handle_event(Event::InputGestureReceived(gesture), at_ms)
+                .unwrap();
+            store.dispatch(Command::for_gesture(gesture)).unwrap();
+        };
 
-        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        rotate(&mut store, 0, false);
+        assert_eq!(store.ui.saved_index, 1);
+        assert_eq!(store.ui.collection_jump_letter, None);
 
-        assert_eq!(store.ui.route, UiRoute::Collection(CollectionKind::Saved));
+        rotate(&mut store, 50, false);
+        rotate(&mut store, 100, false);
+        assert_eq!(store.ui.saved_index, 6);
+        assert_eq!(store.ui.collection_jump_letter, Some('C'));
+
+        rotate(&mut store, 150, true);
+        assert_eq!(store.ui.saved_index, 3);
+        assert_eq!(store.ui.collection_jump_letter, Some('B'));
+
+        store.handle_event(Event::UiTick(1_000), 1_000).unwrap();
+        assert_eq!(store.ui.collection_jump_letter, None);
+
+        rotate(&mut store, 1_000, false);
+        assert_eq!(store.ui.saved_index, 4);
     }
 
     #[test]
-    fn saved_confirm_ignores_empty_collection() {
+    fn search_opens_above_first_item_and_lands_on_first_match() {
         let mut store = Store::new();
         store.ui.route = UiRoute::Collection(CollectionKind::Saved);
+        let mut manifest = CollectionManifestState::empty();
+        for (id, title) in [
+            ("saved-1", "Deep work"),
+            ("saved-2", "Trusting trust"),
+            ("saved-3", "Rust for embedded"),
+        ] {
+            let _ = manifest.try_push(make_manifest_item(id, title));
+        }
+        store
+            .content_mut()
+            .update_collection(CollectionKind::Saved, manifest);
 
-        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        store
+            .dispatch(Command::Ui(UiCommand::FocusPrevious))
+            .unwrap();
+        assert_eq!(store.ui.route, UiRoute::Search(CollectionKind::Saved));
 
-        assert_eq!(
-            effect,
-            Effect::CollectionConfirmIgnored {
-                collection: CollectionKind::Saved,
-                reason: CollectionConfirmIgnoredReason::EmptyCollection,
-            }
-        );
+        store.ui.search_picker.text = InlineText::from_slice("RUS");
+        let model = crate::selectors::select_search(&store, CollectionKind::Saved);
+        assert_eq!(model.match_count, 2);
+        assert_eq!(model.matches[0].as_str(), "Trusting trust");
+
+        store
+            .dispatch(Command::Ui(UiCommand::FocusPrevious))
+            .unwrap();
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert_eq!(store.ui.route, UiRoute::Collection(CollectionKind::Saved));
+        assert_eq!(store.ui.saved_index, 1);
     }
 
     #[test]
-    fn saved_confirm_queues_when_backend_not_ready() {
+    fn book_detail_read_from_start_skips_saved_progress() {
         let mut store = Store::new();
         store.ui.route = UiRoute::Collection(CollectionKind::Saved);
         store.storage = make_storage_with_sd();
-        store
-            .handle_event(Event::NetworkStatusChanged(NetworkStatus::Online), 0)
-            .unwrap();
         let mut manifest = CollectionManifestState::empty();
-        let item = make_ready_saved_item(PackageState::Missing);
-        let _ = manifest.try_push(item);
+        let _ = manifest.try_push(make_ready_saved_item(PackageState::Cached));
         store
             .content_mut()
             .update_collection(CollectionKind::Saved, manifest);
+        let item = store
+            .content()
+            .manifest_item_at(CollectionKind::Saved, 0)
+            .unwrap();
+        store
+            .reading_progress
+            .record_progress(ReadingProgressEntry {
+                content_id: item.content_id,
+                remote_revision: item.remote_revision,
+                paragraph_index: 2,
+                total_paragraphs: 3,
+            });
 
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
         let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert!(matches!(effect, Effect::OpenCachedContent(_)));
 
-        assert_eq!(effect, Effect::Noop);
-        assert_eq!(
-            store
-                .content()
-                .collection_state(CollectionKind::Saved)
-                .item_at(0)
-                .unwrap()
-                .package_state,
-            PackageState::Fetching
-        );
-        assert_eq!(
-            store
-                .handle_event(Event::BackendSyncStatusChanged(SyncStatus::Ready), 0)
-                .unwrap(),
-            Effect::PrepareContent(PrepareContentRequest::from_manifest(
-                CollectionKind::Saved,
-                item,
-            ))
+        let paragraphs = [0u32, 40, 80].map(|start_unit_index| ReaderParagraphInfo {
+            start_unit_index,
+            preview: InlineText::new(),
+        });
+        let mut window = ReaderWindow::empty();
+        window.unit_count = 120;
+        let request = store.open_cached_content(
+            CollectionKind::Saved,
+            item.content_id,
+            item.remote_revision,
+            item.title,
+            120,
+            Box::new(paragraphs),
+            Box::new(window),
         );
+
+        assert_eq!(request, None);
+        assert_eq!(store.reader.progress.paragraph_index, 1);
     }
 
     #[test]
-    fn saved_confirm_prepares_ready_missing_item() {
+    fn book_detail_contents_opens_paused_on_the_saved_paragraph_and_jumps_on_click() {
         let mut store = Store::new();
         store.ui.route = UiRoute::Collection(CollectionKind::Saved);
         store.storage = make_storage_with_sd();
-        store
-            .handle_event(Event::NetworkStatusChanged(NetworkStatus::Online), 0)
-            .unwrap();
-        store
-            .handle_event(Event::BackendSyncStatusChanged(SyncStatus::Ready), 0)
-            .unwrap();
         let mut manifest = CollectionManifestState::empty();
-        let item = make_ready_saved_item(PackageState::Missing);
-        let _ = manifest.try_push(item);
+        let _ = manifest.try_push(make_ready_saved_item(PackageState::Cached));
         store
             .content_mut()
             .update_collection(CollectionKind::Saved, manifest);
-
-        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
-
-        assert_eq!(
-            effect,
-            Effect::PrepareContent(PrepareContentRequest::from_manifest(
+        let item = store
+            .content()
+            .manifest_item_at(CollectionKind::Saved, 0)
+            .unwrap();
+        store
+            .reading_progress
+            .record_progress(ReadingProgressEntry {
+                content_id: item.content_id,
+                remote_revision: item.remote_revision,
+                paragraph_index: 2,
+                total_paragraphs: 3,
+            });
+
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        store.ui.book_detail_action = BookDetailAction::Contents;
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert!(matches!(effect, Effect::OpenCachedContent(_)));
+
+        let paragraphs = [0u32, 40, 80].map(|start_unit_index| ReaderParagraphInfo {
+            start_unit_index,
+            preview: InlineText::new(),
+        });
+        let mut window = ReaderWindow::empty();
+        window.unit_count = 120;
+        store.open_cached_content(
+            CollectionKind::Saved,
+            item.content_id,
+            item.remote_revision,
+            item.title,
+            120,
+            Box::new(paragraphs),
+            Box::new(window),
+        );
+        assert_eq!(store.reader.mode, ReaderMode::Contents);
+        let ActiveScreenModel::Contents(model) = select_active_screen(&store) else {
+            panic!("expected the contents screen");
+        };
+        assert_eq!(model.selected_index, 2);
+        assert_eq!(
+            [model.rows[0], model.rows[1], model.rows[2]].map(|row| row.marker),
+            [
+                ContentsMarker::Read,
+                ContentsMarker::Current,
+                ContentsMarker::Unread
+            ]
+        );
+        assert_eq!(model.rows[3].number, 0);
+
+        store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        assert_eq!(store.reader.contents_index, 3);
+        assert_eq!(store.reader.progress.paragraph_index, 2);
+
+        store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+        assert_eq!(store.reader.mode, ReaderMode::Paused);
+        store.reader.open_contents();
+        assert_eq!(store.reader.contents_index, 2);
+        store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert_eq!(store.reader.mode, ReaderMode::Normal);
+        assert_eq!(store.reader.progress.paragraph_index, 3);
+    }
+
+    #[test]
+    fn long_press_while_paused_marks_the_paragraph_and_bookmarks_jump_on_click() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Collection(CollectionKind::Saved);
+        store.storage = make_storage_with_sd();
+        let mut manifest = CollectionManifestState::empty();
+        let _ = manifest.try_push(make_ready_saved_item(PackageState::Cached));
+        store
+            .content_mut()
+            .update_collection(CollectionKind::Saved, manifest);
+        let item = store
+            .content()
+            .manifest_item_at(CollectionKind::Saved, 0)
+            .unwrap();
+        store
+            .reading_progress
+            .record_progress(ReadingProgressEntry {
+                content_id: item.content_id,
+                remote_revision: item.remote_revision,
+                paragraph_index: 2,
+                total_paragraphs: 3,
+            });
+        let mark = |paragraph_index| Bookmark {
+            content_id: item.content_id,
+            remote_revision: item.remote_revision,
+            paragraph_index,
+        };
+        store.bookmarks.toggle(mark(3));
+
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        store.ui.book_detail_action = BookDetailAction::Bookmarks;
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        let paragraphs = [0u32, 40, 80].map(|start_unit_index| ReaderParagraphInfo {
+            start_unit_index,
+            preview: InlineText::new(),
+        });
+        let mut window = ReaderWindow::empty();
+        window.unit_count = 120;
+        store.open_cached_content(
+            CollectionKind::Saved,
+            item.content_id,
+            item.remote_revision,
+            item.title,
+            120,
+            Box::new(paragraphs),
+            Box::new(window),
+        );
+        assert_eq!(store.reader.mode, ReaderMode::Bookmarks);
+
+        store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+        assert_eq!(store.reader.mode, ReaderMode::Paused);
+        store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+        assert_eq!(store.reader.mode, ReaderMode::Paused);
+        assert_eq!(
+            store.ui.toasts.iter().last().map(|toast| toast.kind),
+            Some(ToastKind::BookmarkAdded)
+        );
+        assert!(store.bookmarks.contains(&mark(2)));
+        assert_eq!(
+            store.take_pending_bookmarks_write().map(|set| set.len()),
+            Some(2)
+        );
+        assert_eq!(store.take_pending_bookmarks_write(), None);
+
+        store.reader.open_bookmarks();
+        let ActiveScreenModel::Contents(model) = select_active_screen(&store) else {
+            panic!("expected the bookmark list");
+        };
+        assert!(model.bookmarks);
+        assert_eq!((model.selected_index, model.total), (1, 2));
+        assert_eq!(
+            [
+                model.rows[0].number,
+                model.rows[1].number,
+                model.rows[2].number
+            ],
+            [2, 3, 0]
+        );
+
+        store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert_eq!(store.reader.mode, ReaderMode::Normal);
+        assert_eq!(store.reader.progress.paragraph_index, 3);
+
+        store.reader.pause(true, 0);
+        store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+        assert_eq!(
+            store.ui.toasts.iter().last().map(|toast| toast.kind),
+            Some(ToastKind::BookmarkRemoved)
+        );
+        assert!(!store.bookmarks.contains(&mark(3)));
+    }
+
+    #[test]
+    fn quote_export_queues_new_marks_only() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Reader;
+        store.reader.active_content_id = InlineText::from_slice("book");
+        store.reader.title = InlineText::from_slice("Book");
+        store.reader.progress.paragraph_index = 4;
+        store.reader.pause(false, 0);
+
+        store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+        assert_eq!(store.take_pending_quote(), None);
+        store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+
+        store.settings.quote_export = true;
+        store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+        let quote = store.take_pending_quote().unwrap();
+        assert_eq!((quote.title.as_str(), quote.paragraph_index), ("Book", 4));
+        store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+        assert_eq!(store.take_pending_quote(), None);
+    }
+
+    #[test]
+    fn reaching_the_end_marks_the_book_finished_and_the_detail_toggle_clears_it() {
+        let mut store = Store::new();
+        store.settings.reading_speed_wpm = 300;
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text("Short.");
+        document.push_paragraph_text("Done.");
+        store.reader.open_article(
+            CollectionKind::Saved,
+            crate::content::ArticleId(1),
+            InlineText::from_slice("Book"),
+            alloc::boxed::Box::new(document),
+            false,
+            store.settings.reading_speed_wpm,
+        );
+        store.reader.active_content_id = InlineText::from_slice("book-1");
+        store.ui.route = UiRoute::Reader;
+
+        for tick_ms in (0..=5_000).step_by(100) {
+            store.handle_event(Event::ReaderTick(tick_ms), 0).unwrap();
+        }
+
+        let stat = store.book_stats.get(&InlineText::from_slice("book-1"));
+        assert_eq!(
+            stat.map(|stat| (stat.finished, stat.reads)),
+            Some((true, 1))
+        );
+        assert_eq!(
+            store.take_pending_book_stats_write(),
+            Some(store.book_stats)
+        );
+        assert_eq!(store.take_pending_book_stats_write(), None);
+
+        let mut item = CollectionManifestItem::empty();
+        item.content_id.set_truncated("book-1");
+        item.package_state = PackageState::Cached;
+        let mut saved = CollectionManifestState::empty();
+        let _ = saved.try_push(item);
+        store
+            .handle_event(
+                Event::CollectionContentUpdated(CollectionKind::Saved, Box::new(saved)),
+                0,
+            )
+            .unwrap();
+        store.ui.route = UiRoute::Collection(CollectionKind::Saved);
+        let ActiveScreenModel::Collection(model) = select_active_screen(&store) else {
+            panic!("expected the library list");
+        };
+        assert!(model.rows[1].finished);
+
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        store.ui.book_detail_action = BookDetailAction::Finished;
+        let ActiveScreenModel::BookDetail(model) = select_active_screen(&store) else {
+            panic!("expected the book detail screen");
+        };
+        assert_eq!(model.actions[4].label, "MARK UNFINISHED");
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        assert_eq!(store.ui.route, UiRoute::BookDetail(CollectionKind::Saved));
+        let stat = store.book_stats.get(&InlineText::from_slice("book-1"));
+        assert_eq!(
+            stat.map(|stat| (stat.finished, stat.reads)),
+            Some((false, 1))
+        );
+        assert!(store.take_pending_book_stats_write().is_some());
+    }
+
+    #[test]
+    fn book_detail_delete_asks_for_confirmation_and_updates_lists_in_place() {
+        let cached = |content_id: &str| {
+            let mut item = CollectionManifestItem::empty();
+            item.remote_item_id.set_truncated(content_id);
+            item.content_id.set_truncated(content_id);
+            item.package_state = PackageState::Cached;
+            item
+        };
+        let mut device_books = CollectionManifestState::empty();
+        let _ = device_books.try_push(cached("flash-1"));
+        let mut store = Store::new();
+        store
+            .handle_event(Event::DeviceBooksUpdated(Box::new(device_books)), 0)
+            .unwrap();
+        let mut synced = CollectionManifestState::empty();
+        let _ = synced.try_push(cached("net-1"));
+        store
+            .handle_event(
+                Event::CollectionContentUpdated(CollectionKind::Saved, Box::new(synced)),
+                0,
+            )
+            .unwrap();
+        store.ui.route = UiRoute::Collection(CollectionKind::Saved);
+
+        let delete_selected = |store: &mut Store| {
+            store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+            for _ in 0..BookDetailAction::ALL.len() {
+                store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+            }
+            assert_eq!(
+                store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap(),
+                Effect::Noop
+            );
+            assert_eq!(
+                store.ui.confirm,
+                Some(ConfirmDialog::new(ConfirmAction::DeleteBook(
+                    CollectionKind::Saved
+                )))
+            );
+            store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+            store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap()
+        };
+
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        store.ui.book_detail_action = BookDetailAction::Delete;
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+        assert_eq!(store.ui.confirm, None);
+        assert_eq!(store.ui.route, UiRoute::BookDetail(CollectionKind::Saved));
+        store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+
+        let effect = delete_selected(&mut store);
+        assert!(
+            matches!(effect, Effect::DeleteBook(request) if request.content_id.as_str() == "net-1")
+        );
+        let saved = store.content().collection_state(CollectionKind::Saved);
+        assert_eq!(saved.len(), 2);
+        assert_eq!(
+            saved.item_at(0).unwrap().package_state,
+            PackageState::Missing
+        );
+
+        store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        let effect = delete_selected(&mut store);
+        assert!(
+            matches!(effect, Effect::DeleteBook(request) if request.content_id.as_str() == "flash-1")
+        );
+        assert_eq!(store.ui.route, UiRoute::Collection(CollectionKind::Saved));
+        assert_eq!(store.ui.saved_index, 0);
+        assert_eq!(store.content().collection_len(CollectionKind::Saved), 1);
+
+        let mut synced = CollectionManifestState::empty();
+        let _ = synced.try_push(cached("net-1"));
+        store
+            .handle_event(
+                Event::CollectionContentUpdated(CollectionKind::Saved, Box::new(synced)),
+                0,
+            )
+            .unwrap();
+        assert_eq!(store.content().collection_len(CollectionKind::Saved), 1);
+    }
+
+    #[test]
+    fn dashboard_confirm_opens_selected_collection() {
+        let mut store = Store::new();
+
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        assert_eq!(store.ui.route, UiRoute::Collection(CollectionKind::Saved));
+    }
+
+    #[test]
+    fn saved_confirm_ignores_empty_collection() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Collection(CollectionKind::Saved);
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        assert_eq!(
+            effect,
+            Effect::CollectionConfirmIgnored {
+                collection: CollectionKind::Saved,
+                reason: CollectionConfirmIgnoredReason::EmptyCollection,
+            }
+        );
+    }
+
+    #[test]
+    fn saved_confirm_queues_when_backend_not_ready() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Collection(CollectionKind::Saved);
+        store.storage = make_storage_with_sd();
+        store
+            .handle_event(Event::NetworkStatusChanged(NetworkStatus::Online), 0)
+            .unwrap();
+        let mut manifest = CollectionManifestState::empty();
+        let item = make_ready_saved_item(PackageState::Missing);
+        let _ = manifest.try_push(item);
+        store
+            .content_mut()
+            .update_collection(CollectionKind::Saved, manifest);
+
+        let effect = read_from_book_detail(&mut store);
+
+        assert_eq!(effect, Effect::Noop);
+        assert_eq!(
+            store
+                .content()
+                .collection_state(CollectionKind::Saved)
+                .item_at(0)
+                .unwrap()
+                .package_state,
+            PackageState::Fetching
+        );
+        assert_eq!(
+            store
+                .handle_event(Event::BackendSyncStatusChanged(SyncStatus::Ready), 0)
+                .unwrap(),
+            Effect::PrepareContent(PrepareContentRequest::from_manifest(
+                CollectionKind::Saved,
+                item,
+            ))
+        );
+    }
+
+    #[test]
+    fn saved_confirm_prepares_ready_missing_item() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Collection(CollectionKind::Saved);
+        store.storage = make_storage_with_sd();
+        store
+            .handle_event(Event::NetworkStatusChanged(NetworkStatus::Online), 0)
+            .unwrap();
+        store
+            .handle_event(Event::BackendSyncStatusChanged(SyncStatus::Ready), 0)
+            .unwrap();
+        let mut manifest = CollectionManifestState::empty();
+        let item = make_ready_saved_item(PackageState::Missing);
+        let _ = manifest.try_push(item);
+        store
+            .content_mut()
+            .update_collection(CollectionKind::Saved, manifest);
+
+        let effect = read_from_book_detail(&mut store);
+
+        assert_eq!(
+            effect,
+            Effect::PrepareContent(PrepareContentRequest::from_manifest(
                 CollectionKind::Saved,
                 item,
             ))
@@ -1733,7 +3322,7 @@ mod tests {
             .content_mut()
             .update_collection(CollectionKind::Saved, manifest);
 
-        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        let effect = read_from_book_detail(&mut store);
 
         assert_eq!(
             effect,
@@ -1768,7 +3357,7 @@ mod tests {
             .content_mut()
             .update_collection(CollectionKind::Saved, manifest);
 
-        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        let effect = read_from_book_detail(&mut store);
 
         assert_eq!(effect, Effect::Noop);
         assert_eq!(
@@ -1804,10 +3393,7 @@ mod tests {
             .update_collection(CollectionKind::Saved, initial_manifest);
         store.ui.saved_index = 1;
 
-        assert_eq!(
-            store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap(),
-            Effect::Noop
-        );
+        assert_eq!(read_from_book_detail(&mut store), Effect::Noop);
 
         let mut refreshed_manifest = CollectionManifestState::empty();
         let refreshed_first = make_ready_saved_item(PackageState::Cached);
@@ -1895,7 +3481,7 @@ mod tests {
             .content_mut()
             .update_collection(CollectionKind::Saved, manifest);
 
-        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        let effect = read_from_book_detail(&mut store);
 
         assert_eq!(
             effect,
@@ -1934,7 +3520,7 @@ mod tests {
             .content_mut()
             .update_collection(CollectionKind::Saved, manifest);
 
-        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        let effect = read_from_book_detail(&mut store);
 
         assert_eq!(
             effect,
@@ -1992,7 +3578,7 @@ mod tests {
         let mut store = Store::new();
         store.settings.reading_speed_wpm = 300;
         store.ui.route = UiRoute::Reader;
-        store.reader.pause(false);
+        store.reader.pause(false, 0);
 
         store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
 
@@ -2007,17 +3593,17 @@ mod tests {
     }
 
     #[test]
-    fn paused_reader_back_resumes_live_session() {
+    fn paused_reader_back_stays_paused_for_bookmarks() {
         let mut store = Store::new();
         store.settings.reading_speed_wpm = 300;
         store.ui.route = UiRoute::Reader;
-        store.reader.pause(false);
+        store.reader.pause(false, 0);
 
         store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
 
         assert!(matches!(
             store.reader.mode,
-            crate::reader::ReaderMode::Normal
+            crate::reader::ReaderMode::Paused
         ));
     }
 
@@ -2025,7 +3611,7 @@ mod tests {
     fn paused_reader_focus_moves_to_paragraph_view_and_confirms() {
         let mut store = Store::new();
         store.ui.route = UiRoute::Reader;
-        store.reader.pause(false);
+        store.reader.pause(false, 0);
 
         let effect = store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
 
@@ -2086,9 +3672,46 @@ mod tests {
     }
 
     #[test]
-    fn reader_tick_advances_live_rsvp_session() {
+    fn toasts_stack_without_taking_input_and_expire_on_ui_ticks() {
         let mut store = Store::new();
-        store.settings.reading_speed_wpm = 300;
+        store.ui.route = UiRoute::Settings;
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert!(store.ui.toasts.is_empty());
+
+        store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+        store
+            .handle_event(Event::NetworkStatusChanged(NetworkStatus::Online), 500)
+            .unwrap();
+        let kinds = |store: &Store| {
+            let mut kinds = [None; 2];
+            for (slot, toast) in store.ui.toasts.iter().take(2).enumerate() {
+                kinds[slot] = Some(toast.kind);
+            }
+            kinds
+        };
+        assert_eq!(store.ui.route, UiRoute::Dashboard);
+        assert_eq!(
+            kinds(&store),
+            [
+                Some(ToastKind::SettingsSaved),
+                Some(ToastKind::WifiConnected)
+            ]
+        );
+
+        store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        assert_eq!(store.ui.dashboard_focus.index(), 2);
+        store.handle_event(Event::UiTick(2_000), 2_000).unwrap();
+        assert_eq!(kinds(&store), [Some(ToastKind::WifiConnected), None]);
+        store.handle_event(Event::UiTick(2_400), 2_400).unwrap();
+        assert!(store.ui.toasts.is_empty());
+    }
+
+    #[test]
+    fn reader_tick_advances_live_rsvp_session() {
+        let mut store = Store::new();
+        store.settings.reading_speed_wpm = 300;
         let article = store.content().article_at(CollectionKind::Inbox, 0);
         let document = format_article_document(&article_document_from_script(
             article.source,
@@ -2112,6 +3735,133 @@ mod tests {
         assert_eq!(store.ui.route, UiRoute::Reader);
     }
 
+    #[test]
+    fn adaptive_speed_double_turn_steps_speed_and_a_single_turn_still_jumps() {
+        let mut store = Store::new();
+        store.settings.adaptive_speed = true;
+        let article = store.content().article_at(CollectionKind::Inbox, 0);
+        let document = format_article_document(&article_document_from_script(
+            article.source,
+            article.script,
+        ));
+        store.reader.open_article(
+            CollectionKind::Inbox,
+            article.id,
+            crate::text::InlineText::from_slice(article.reader_title),
+            alloc::boxed::Box::new(document),
+            article.has_chat,
+            store.settings.reading_speed_wpm,
+        );
+        store.ui.route = UiRoute::Reader;
+        let speed = store.settings.reading_speed_wpm;
+        let paragraph = store.reader.progress.paragraph_index;
+        let turn = |store: &mut Store, at_ms: u64| {
+            let gesture = InputGesture::Rotate {
+                direction: RotationDirection::Clockwise,
+            };
+            store
+                .handle_event(Event::InputGestureReceived(gesture), at_ms)
+                .unwrap();
+            store.dispatch(Command::for_gesture(gesture)).unwrap()
+        };
+
+        assert_eq!(turn(&mut store, 1_000), Effect::Noop);
+        let effect = turn(&mut store, 1_200);
+        assert!(matches!(
+            effect,
+            Effect::PersistSettings(settings) if settings.reading_speed_wpm == speed + 20
+        ));
+        assert_eq!(store.reader.progress.paragraph_index, paragraph);
+
+        turn(&mut store, 2_000);
+        store.handle_event(Event::ReaderTick(2_200), 2_200).unwrap();
+        assert_eq!(store.reader.progress.paragraph_index, paragraph);
+        store.handle_event(Event::ReaderTick(2_400), 2_400).unwrap();
+        assert_eq!(store.reader.progress.paragraph_index, paragraph + 1);
+        assert_eq!(store.settings.reading_speed_wpm, speed + 20);
+    }
+
+    #[test]
+    fn unattended_chapter_pauses_back_at_the_last_input() {
+        let mut document = ReadingDocument::empty();
+        document.push_paragraph_text("Intro ends.");
+        document.push_heading_text("Two");
+        document.push_paragraph_text("Middle.");
+        document.push_heading_text("Three");
+        let mut store = Store::new();
+        store.settings.reading_speed_wpm = 300;
+        store.reader.open_article(
+            CollectionKind::Saved,
+            crate::content::ArticleId(1),
+            crate::text::InlineText::from_slice("Example"),
+            alloc::boxed::Box::new(document),
+            false,
+            store.settings.reading_speed_wpm,
+        );
+        store.ui.route = UiRoute::Reader;
+
+        let mut tick_ms = 0;
+        while store.reader.mode == ReaderMode::Normal && tick_ms < 20_000 {
+            store.handle_event(Event::ReaderTick(tick_ms), 0).unwrap();
+            tick_ms += 100;
+        }
+
+        assert_eq!(store.reader.mode, ReaderMode::Paused);
+        assert_eq!(store.reader.progress.unit_index, 0);
+        assert_eq!(
+            store.ui.toasts.iter().next().map(|toast| toast.kind),
+            Some(ToastKind::AutoPaused)
+        );
+    }
+
+    #[test]
+    fn a_paused_session_lands_in_history_and_counts_toward_the_streak() {
+        let mut store = Store::new();
+        store.settings.reading_speed_wpm = 300;
+        store
+            .handle_event(
+                Event::LocalTimeChanged(DateTime::new(2026, 10, 16, 21, 30, 0).unwrap()),
+                0,
+            )
+            .unwrap();
+        let article = store.content().article_at(CollectionKind::Inbox, 0);
+        let document = format_article_document(&article_document_from_script(
+            article.source,
+            article.script,
+        ));
+        store.reader.open_article(
+            CollectionKind::Inbox,
+            article.id,
+            crate::text::InlineText::from_slice(article.reader_title),
+            alloc::boxed::Box::new(document),
+            article.has_chat,
+            store.settings.reading_speed_wpm,
+        );
+        store.ui.route = UiRoute::Reader;
+
+        for tick_ms in (0..=1_000).step_by(100) {
+            store.handle_event(Event::ReaderTick(tick_ms), 0).unwrap();
+        }
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        assert_eq!(store.reader.mode, ReaderMode::Paused);
+        assert_eq!(store.history.len(), 1);
+        let entry = *store.history.newest(0).unwrap();
+        assert_eq!(entry.words, store.reader.progress.unit_index);
+        assert!(entry.words > 0);
+        assert_eq!(entry.ended_at.map(|stamp| stamp.minute), Some(21 * 60 + 30));
+        assert_eq!(store.take_pending_history_write(), Some(store.history));
+        assert_eq!(store.take_pending_history_write(), None);
+
+        store.ui.route = UiRoute::History;
+        let ActiveScreenModel::History(model) = select_active_screen(&store) else {
+            panic!("expected the history screen");
+        };
+        assert_eq!(model.streak_days, Some(1));
+        assert_eq!(model.rows[0].when.as_str(), "TODAY");
+        assert_eq!(model.rows[0].words, entry.words);
+    }
+
     #[test]
     fn active_reader_tick_keeps_sleep_awake() {
         let mut store = Store::new();
@@ -2137,6 +3887,46 @@ mod tests {
         assert_eq!(store.sleep.last_activity_ms, 250);
     }
 
+    #[test]
+    fn reader_ticks_count_toward_goal_and_persist_each_minute() {
+        let mut store = Store::new();
+        store.settings.reading_goal_minutes = 10;
+        store.settings.goal_progress_ms = 59_500;
+        let article = store.content().article_at(CollectionKind::Inbox, 0);
+        let document = format_article_document(&article_document_from_script(
+            article.source,
+            article.script,
+        ));
+        store.reader.open_article(
+            CollectionKind::Inbox,
+            article.id,
+            crate::text::InlineText::from_slice(article.reader_title),
+            alloc::boxed::Box::new(document),
+            article.has_chat,
+            store.settings.reading_speed_wpm,
+        );
+        store.ui.route = UiRoute::Reader;
+
+        let first = store.handle_event(Event::ReaderTick(0), 0).unwrap();
+        let second = store.handle_event(Event::ReaderTick(400), 0).unwrap();
+        let third = store.handle_event(Event::ReaderTick(800), 0).unwrap();
+
+        assert_eq!(first, Effect::Noop);
+        assert_eq!(second, Effect::Noop);
+        assert_eq!(
+            third,
+            Effect::PersistSettings(store.settings.to_persisted())
+        );
+        assert_eq!(store.reader.session_reading_ms, 800);
+        assert_eq!(store.settings.goal_progress_ms, 60_300);
+        assert_eq!(store.settings.goal_permille(), Some(100));
+
+        store.settings.goal_progress_ms = 600_000;
+        store.reader.session_reading_ms = 0;
+        store.handle_event(Event::ReaderTick(900), 0).unwrap();
+        assert_eq!(store.settings.goal_progress_ms, 100);
+    }
+
     #[test]
     fn paused_reader_tick_does_not_refresh_sleep_timer() {
         let mut store = Store::new();
@@ -2155,7 +3945,7 @@ mod tests {
             store.settings.reading_speed_wpm,
         );
         store.ui.route = UiRoute::Reader;
-        store.reader.pause(false);
+        store.reader.pause(false, 0);
         store.sleep.last_activity_ms = 10;
 
         store.handle_event(Event::ReaderTick(250), 0).unwrap();
@@ -2237,6 +4027,181 @@ mod tests {
         );
     }
 
+    #[test]
+    fn background_window_loads_only_apply_to_the_open_book() {
+        let mut store = Store::new();
+        let _ = store.open_cached_content(
+            CollectionKind::Saved,
+            crate::text::InlineText::from_slice("content-1"),
+            7,
+            crate::text::InlineText::from_slice("Example saved title"),
+            256,
+            alloc::vec![ReaderParagraphInfo {
+                start_unit_index: 0,
+                preview: crate::text::InlineText::new(),
+            }]
+            .into_boxed_slice(),
+            make_reader_window(0, 128),
+        );
+
+        store
+            .handle_event(
+                Event::ReaderWindowLoaded {
+                    content_id: crate::text::InlineText::from_slice("content-2"),
+                    request_id: 0,
+                    window: make_reader_window(0, 16),
+                },
+                0,
+            )
+            .unwrap();
+        assert_eq!(store.reader.active_window().unit_count, 128);
+
+        store
+            .handle_event(
+                Event::ReaderWindowLoaded {
+                    content_id: crate::text::InlineText::from_slice("content-1"),
+                    request_id: 0,
+                    window: make_reader_window(96, 128),
+                },
+                0,
+            )
+            .unwrap();
+        assert_eq!(store.reader.active_window().start_unit_index, 0);
+        assert_eq!(store.reader.active_window().unit_count, 128);
+    }
+
+    #[test]
+    fn failed_window_loads_retry_on_ticks_then_wait_for_a_click() {
+        let mut store = Store::new();
+        let _ = store.open_cached_content(
+            CollectionKind::Saved,
+            crate::text::InlineText::from_slice("content-1"),
+            7,
+            crate::text::InlineText::from_slice("Example saved title"),
+            256,
+            alloc::vec![ReaderParagraphInfo {
+                start_unit_index: 0,
+                preview: crate::text::InlineText::new(),
+            }]
+            .into_boxed_slice(),
+            make_reader_window(0, 128),
+        );
+        store.ui.route = UiRoute::Reader;
+        store.reader.progress.unit_index = 110;
+        let Effect::LoadReaderWindow(mut request) =
+            store.handle_event(Event::ReaderTick(0), 0).unwrap()
+        else {
+            panic!("expected the next window to be requested");
+        };
+        assert_eq!(request.window_start_unit_index, 96);
+
+        for (failed_at_ms, retry_at_ms) in [(0, 500), (600, 1_600)] {
+            store
+                .handle_event(Event::ReaderWindowLoadFailed(request), failed_at_ms)
+                .unwrap();
+            assert_eq!(
+                store
+                    .handle_event(Event::ReaderTick(retry_at_ms - 1), retry_at_ms - 1)
+                    .unwrap(),
+                Effect::Noop
+            );
+            let Effect::LoadReaderWindow(retry) = store
+                .handle_event(Event::ReaderTick(retry_at_ms), retry_at_ms)
+                .unwrap()
+            else {
+                panic!("expected the window to be asked for again");
+            };
+            assert_eq!(retry.window_start_unit_index, 96);
+            request = retry;
+        }
+        store
+            .handle_event(Event::ReaderWindowLoadFailed(request), 1_700)
+            .unwrap();
+
+        assert!(store.reader.is_stream_stalled());
+        assert_eq!(
+            store.ui.toasts.iter().next().map(|toast| toast.kind),
+            Some(ToastKind::CardReadFailed)
+        );
+        let ActiveScreenModel::Reader(model) = select_active_screen(&store) else {
+            panic!("expected the reader");
+        };
+        assert_eq!(model.badge_label, Some("RETRY"));
+        assert_eq!(
+            store
+                .handle_event(Event::ReaderTick(60_000), 60_000)
+                .unwrap(),
+            Effect::Noop
+        );
+
+        let Effect::LoadReaderWindow(retry) =
+            store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap()
+        else {
+            panic!("expected the click to retry");
+        };
+        assert_eq!(retry.window_start_unit_index, 96);
+        assert_eq!(store.reader.mode, ReaderMode::Normal);
+        assert!(!store.reader.is_stream_stalled());
+
+        // A failure reported for a request that was since replaced does not count.
+        store
+            .handle_event(Event::ReaderWindowLoadFailed(request), 61_000)
+            .unwrap();
+        assert_eq!(
+            store
+                .handle_event(Event::ReaderTick(62_000), 62_000)
+                .unwrap(),
+            Effect::Noop
+        );
+    }
+
+    #[test]
+    fn quick_turns_fetch_only_the_last_target_and_drop_stale_windows() {
+        let mut store = Store::new();
+        store.settings.reading_speed_wpm = 300;
+        let _ = store.open_cached_content(
+            CollectionKind::Inbox,
+            crate::text::InlineText::from_slice("content-1"),
+            7,
+            crate::text::InlineText::from_slice("Example"),
+            256,
+            [0, 64, 128, 192]
+                .map(|start_unit_index| ReaderParagraphInfo {
+                    start_unit_index,
+                    preview: crate::text::InlineText::new(),
+                })
+                .to_vec()
+                .into_boxed_slice(),
+            make_reader_window(0, 32),
+        );
+
+        let Effect::LoadReaderWindow(first) =
+            store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap()
+        else {
+            panic!("expected a window request");
+        };
+        let Effect::LoadReaderWindow(second) =
+            store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap()
+        else {
+            panic!("expected a window request");
+        };
+        assert_eq!(first.window_start_unit_index, 32);
+        assert_eq!(second.window_start_unit_index, 96);
+
+        let loaded = |request: crate::reader::ReaderWindowLoadRequest| Event::ReaderWindowLoaded {
+            content_id: request.content_id,
+            request_id: request.request_id,
+            window: make_reader_window(request.window_start_unit_index, 128),
+        };
+        store.handle_event(loaded(first), 0).unwrap();
+        assert_eq!(store.reader.progress.unit_index, 0);
+        assert_eq!(store.reader.active_window().start_unit_index, 0);
+
+        store.handle_event(loaded(second), 0).unwrap();
+        assert_eq!(store.reader.progress.unit_index, 128);
+        assert_eq!(store.reader.progress.paragraph_index, 3);
+    }
+
     #[test]
     fn live_reader_scroll_back_jumps_to_current_paragraph_start() {
         let mut store = Store::new();
@@ -2316,6 +4281,7 @@ mod tests {
             Effect::LoadReaderWindow(crate::reader::ReaderWindowLoadRequest {
                 content_id: crate::text::InlineText::from_slice("content-1"),
                 window_start_unit_index: 32,
+                request_id: 1,
             })
         );
         assert_eq!(store.reader.progress.unit_index, 0);
@@ -2428,6 +4394,60 @@ mod tests {
         assert_eq!(store.reader.progress.paragraph_index, 3);
     }
 
+    #[test]
+    fn night_mode_darkens_screens_only_inside_the_night_window() {
+        let mut store = Store::new();
+        store.settings.appearance = AppearanceMode::Light;
+        store.settings.night_mode = true;
+        assert_eq!(store.appearance(), AppearanceMode::Light);
+
+        store
+            .handle_event(
+                Event::LocalTimeChanged(DateTime::new(2026, 10, 16, 21, 30, 0).unwrap()),
+                0,
+            )
+            .unwrap();
+        assert_eq!(store.appearance(), AppearanceMode::Dark);
+        assert_eq!(store.settings.appearance, AppearanceMode::Light);
+
+        store
+            .handle_event(
+                Event::LocalTimeChanged(DateTime::new(2026, 10, 17, 8, 0, 0).unwrap()),
+                0,
+            )
+            .unwrap();
+        assert_eq!(store.appearance(), AppearanceMode::Light);
+    }
+
+    #[test]
+    fn light_sensor_overrides_night_mode_once_it_reports() {
+        let mut store = Store::new();
+        store.settings.appearance = AppearanceMode::Light;
+        store.settings.night_mode = true;
+        store.settings.light_sensor = true;
+        store
+            .handle_event(
+                Event::LocalTimeChanged(DateTime::new(2026, 10, 16, 21, 30, 0).unwrap()),
+                0,
+            )
+            .unwrap();
+        assert_eq!(store.appearance(), AppearanceMode::Dark);
+
+        store
+            .handle_event(Event::AmbientLightChanged(2_000), 0)
+            .unwrap();
+        assert_eq!(store.appearance(), AppearanceMode::Light);
+
+        store
+            .handle_event(Event::AmbientLightChanged(3), 0)
+            .unwrap();
+        assert_eq!(store.appearance(), AppearanceMode::Dark);
+
+        store.settings.light_sensor = false;
+        store.settings.night_mode = false;
+        assert_eq!(store.appearance(), AppearanceMode::Light);
+    }
+
     #[test]
     fn appearance_edit_toggles_theme_setting() {
         let mut store = Store::new();
@@ -2445,31 +4465,548 @@ mod tests {
     }
 
     #[test]
-    fn paused_reader_save_row_dispatches_save_toggle_effect() {
+    fn speed_editor_plays_the_sample_sentence_at_the_edited_speed() {
         let mut store = Store::new();
-        store.network.status = NetworkStatus::Online;
-        store.backend_sync.status = SyncStatus::Ready;
-        let request = store.open_cached_content(
-            CollectionKind::Inbox,
-            crate::text::InlineText::from_slice("content-1"),
-            7,
-            crate::text::InlineText::from_slice("Example inbox title"),
-            120,
-            alloc::vec![
-                ReaderParagraphInfo {
-                    start_unit_index: 0,
-                    preview: crate::text::InlineText::new(),
-                },
-                ReaderParagraphInfo {
-                    start_unit_index: 64,
-                    preview: crate::text::InlineText::new(),
+        store.ui.route = UiRoute::Settings;
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert_eq!(store.ui.settings_mode, SettingsMode::SpeedEdit);
+        assert_eq!(
+            crate::selectors::select_settings(&store).preview_word,
+            Some("Motif")
+        );
+
+        store
+            .dispatch(Command::Ui(UiCommand::FocusPrevious))
+            .unwrap();
+        let step_ms = 60_000 / u64::from(store.settings.reading_speed_wpm);
+        store.handle_event(Event::UiTick(1_000), 0).unwrap();
+        store
+            .handle_event(Event::UiTick(1_000 + step_ms - 1), 0)
+            .unwrap();
+        assert_eq!(store.ui.speed_preview.word(), "Motif");
+        store
+            .handle_event(Event::UiTick(1_000 + step_ms), 0)
+            .unwrap();
+        assert_eq!(store.ui.speed_preview.word(), "shows");
+
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert_eq!(crate::selectors::select_settings(&store).preview_word, None);
+    }
+
+    #[test]
+    fn section_pauses_row_toggles_and_persists_setting() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Settings;
+        store.ui.settings_mode = SettingsMode::Master;
+        store.ui.settings_row = SettingsRow::SectionPauses;
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        assert!(store.settings.section_pauses);
+        assert_eq!(store.ui.settings_mode, SettingsMode::Master);
+        assert_eq!(
+            effect,
+            Effect::PersistSettings(store.settings.to_persisted())
+        );
+    }
+
+    #[test]
+    fn word_timing_rows_pick_the_dwell_mode_and_bounds_the_reader_uses() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Settings;
+        store.ui.settings_mode = SettingsMode::Master;
+
+        for row in [
+            SettingsRow::WordTiming,
+            SettingsRow::ShortestWord,
+            SettingsRow::LongestWord,
+        ] {
+            store.ui.settings_row = row;
+            let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+            assert_eq!(
+                effect,
+                Effect::PersistSettings(store.settings.to_persisted())
+            );
+        }
+        store.sync_reader_preferences();
+
+        let timing = store.reader.dwell_timing;
+        assert_eq!(timing.mode, crate::formatter::DwellMode::Weighted);
+        assert_eq!((timing.min_ms, timing.max_ms), (120, 3_000));
+    }
+
+    #[test]
+    fn word_groups_row_persists_the_chunking_the_parser_gets() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Settings;
+        store.ui.settings_mode = SettingsMode::Master;
+        store.ui.settings_row = SettingsRow::WordGroups;
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        assert_eq!(
+            effect,
+            Effect::PersistSettings(store.settings.to_persisted())
+        );
+        assert_eq!(
+            store.settings.format_preferences().chunking,
+            crate::formatter::ChunkingMode::FunctionWords
+        );
+        assert_eq!(store.settings.chunking_label(), "GROUPED");
+    }
+
+    #[test]
+    fn references_row_persists_keeping_markers_for_the_parser() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Settings;
+        store.ui.settings_mode = SettingsMode::Master;
+        store.ui.settings_row = SettingsRow::References;
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        assert_eq!(
+            effect,
+            Effect::PersistSettings(store.settings.to_persisted())
+        );
+        assert_eq!(
+            store.settings.format_preferences().references,
+            crate::formatter::ReferenceMode::Keep
+        );
+        assert_eq!(store.settings.references_label(), "KEEP");
+    }
+
+    #[test]
+    fn digit_groups_row_persists_grouping_for_the_parser() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Settings;
+        store.ui.settings_mode = SettingsMode::Master;
+        store.ui.settings_row = SettingsRow::DigitGroups;
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        assert_eq!(
+            effect,
+            Effect::PersistSettings(store.settings.to_persisted())
+        );
+        assert!(store.settings.format_preferences().digit_grouping);
+        assert_eq!(store.settings.digit_grouping_label(), "ON");
+    }
+
+    #[test]
+    fn encoder_direction_row_swaps_turns_from_the_next_command() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Settings;
+        store.ui.settings_row = SettingsRow::EncoderDirection;
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert!(matches!(effect, Effect::PersistSettings(settings) if settings.encoder_reversed));
+
+        store
+            .dispatch(Command::Ui(UiCommand::FocusPrevious))
+            .unwrap();
+        assert_eq!(store.ui.settings_row, SettingsRow::EncoderSensitivity);
+    }
+
+    #[test]
+    fn profile_row_requests_the_next_profile() {
+        let hydrated = Store::from_bootstrap(BootstrapSnapshot::default().with_profile(2));
+        assert_eq!(hydrated.settings.profile, 2);
+
+        let mut store = Store::new();
+        store.settings.profile = 2;
+        store.ui.route = UiRoute::Settings;
+        store.ui.settings_row = SettingsRow::Profile;
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        assert_eq!(effect, Effect::SwitchProfile(0));
+    }
+
+    #[test]
+    fn crash_report_holds_input_until_dismissed() {
+        let hydrated = Store::from_bootstrap(
+            BootstrapSnapshot::default()
+                .with_crash_report(Some(InlineText::from_slice("src/main.rs:1: boom"))),
+        );
+        assert!(hydrated.crash_report.is_some());
+
+        let mut store = Store::new();
+        store.crash_report = hydrated.crash_report;
+
+        let effect = store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        assert_eq!(effect, Effect::Noop);
+        assert!(store.crash_report.is_some());
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert_eq!(effect, Effect::Noop);
+        assert!(store.crash_report.is_none());
+    }
+
+    #[test]
+    fn low_battery_warning_shows_once_per_discharge() {
+        let mut store = Store::new();
+
+        store
+            .handle_event(Event::PowerStatusChanged(PowerStatus::new(12)), 0)
+            .unwrap();
+        assert!(store.low_battery_warning);
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert_eq!(effect, Effect::Noop);
+        assert!(!store.low_battery_warning);
+
+        store
+            .handle_event(Event::PowerStatusChanged(PowerStatus::new(10)), 0)
+            .unwrap();
+        assert!(!store.low_battery_warning);
+
+        store
+            .handle_event(
+                Event::PowerStatusChanged(PowerStatus::new(10).with_charging(true)),
+                0,
+            )
+            .unwrap();
+        store
+            .handle_event(Event::PowerStatusChanged(PowerStatus::new(9)), 0)
+            .unwrap();
+        assert!(store.low_battery_warning);
+    }
+
+    #[test]
+    fn low_battery_cue_plays_only_with_sound_on() {
+        let mut store = Store::new();
+        store
+            .handle_event(Event::PowerStatusChanged(PowerStatus::new(12)), 0)
+            .unwrap();
+        assert_eq!(store.take_pending_audio_cue(), None);
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        store.ui.route = UiRoute::Settings;
+        store.ui.settings_row = SettingsRow::Sound;
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert!(
+            matches!(effect, Effect::PersistSettings(settings) if settings.audio_volume == AudioVolume::Low)
+        );
+
+        store
+            .handle_event(
+                Event::PowerStatusChanged(PowerStatus::new(12).with_charging(true)),
+                0,
+            )
+            .unwrap();
+        store
+            .handle_event(Event::PowerStatusChanged(PowerStatus::new(11)), 0)
+            .unwrap();
+        assert_eq!(store.take_pending_audio_cue(), Some(AudioCue::LowBattery));
+        assert_eq!(store.take_pending_audio_cue(), None);
+    }
+
+    #[test]
+    fn inactivity_sleep_records_and_restores_the_open_article() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Reader;
+        store.reader.active_collection = CollectionKind::Saved;
+        store.reader.active_content_id.set_truncated("content-1");
+
+        let effect = store.handle_event(Event::InactivityTimeout, 0).unwrap();
+        assert_eq!(effect, Effect::EnterDeepSleep);
+        let resume = store.sleep_resume();
+
+        let woken = Store::from_bootstrap(
+            BootstrapSnapshot::new(
+                DeviceState::with_boot(BootState::DeepSleepWake),
+                0,
+                None,
+                None,
+                None,
+                None,
+                make_storage_with_sd(),
+                NetworkState::disabled(),
+            )
+            .with_sleep_resume(Some(resume)),
+        );
+        assert!(!woken.startup_splash_visible);
+
+        let mut store = Store::new();
+        store.storage = make_storage_with_sd();
+        store.pending_sleep_resume = woken.pending_sleep_resume;
+        let mut manifest = CollectionManifestState::empty();
+        let _ = manifest.try_push(make_manifest_item("other", "Other"));
+        let _ = manifest.try_push(make_ready_saved_item(PackageState::Cached));
+        store
+            .content_mut()
+            .update_collection(CollectionKind::Saved, manifest);
+
+        let effect = store.handle_event(Event::BootCompleted, 0).unwrap();
+
+        assert_eq!(store.ui.route, UiRoute::Collection(CollectionKind::Saved));
+        assert_eq!(store.ui.saved_index, 1);
+        match effect {
+            Effect::OpenCachedContent(request) => {
+                assert_eq!(request.content_id.as_str(), "content-1")
+            }
+            other => panic!("expected cached open, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn sleep_resume_waits_for_the_scanned_collection() {
+        let mut store = Store::new();
+        store.storage = make_storage_with_sd();
+        let mut content_id = InlineText::new();
+        content_id.set_truncated("content-1");
+        store.pending_sleep_resume = Some(SleepResume::Reader {
+            collection: CollectionKind::Saved,
+            content_id,
+        });
+
+        let effect = store.handle_event(Event::BootCompleted, 0).unwrap();
+        assert_eq!(effect, Effect::Noop);
+        assert!(store.pending_sleep_resume.is_some());
+
+        let mut manifest = CollectionManifestState::empty();
+        let _ = manifest.try_push(make_ready_saved_item(PackageState::Cached));
+        let effect = store
+            .handle_event(
+                Event::CollectionContentUpdated(CollectionKind::Saved, Box::new(manifest)),
+                0,
+            )
+            .unwrap();
+
+        assert!(matches!(effect, Effect::OpenCachedContent(_)));
+        assert!(store.pending_sleep_resume.is_none());
+    }
+
+    #[test]
+    fn stored_reading_state_keeps_progress_recorded_during_the_scan() {
+        let entry = |content_id: &str, paragraph_index| {
+            let mut entry = ReadingProgressEntry {
+                content_id: InlineText::new(),
+                remote_revision: 1,
+                paragraph_index,
+                total_paragraphs: 10,
+            };
+            entry.content_id.set_truncated(content_id);
+            entry
+        };
+        let mut store = Store::new();
+        store.reading_progress.record_progress(entry("a", 5));
+        let mut stored = ReadingProgressState::empty();
+        stored.record_progress(entry("a", 2));
+        stored.record_progress(entry("b", 1));
+
+        store
+            .handle_event(
+                Event::StoredReadingStateLoaded {
+                    reading_progress: Box::new(stored),
+                    bookmarks: Box::new(BookmarkState::empty()),
+                    history: Box::new(HistoryState::empty()),
+                    book_stats: Box::new(BookStatsState::empty()),
+                    recommendation_subtopics: Box::new(make_recommendation_subtopics()),
+                },
+                0,
+            )
+            .unwrap();
+
+        let content_id = |value: &str| {
+            let mut id = InlineText::new();
+            id.set_truncated(value);
+            id
+        };
+        let progress = &store.reading_progress;
+        assert_eq!(
+            progress
+                .find_by_content_id(&content_id("a"))
+                .map(|entry| entry.paragraph_index),
+            Some(5)
+        );
+        assert_eq!(
+            progress
+                .find_by_content_id(&content_id("b"))
+                .map(|entry| entry.paragraph_index),
+            Some(1)
+        );
+        assert_eq!(
+            store.recommendations.subtopics.len(),
+            make_recommendation_subtopics().len()
+        );
+    }
+
+    #[test]
+    fn reset_device_row_requires_confirmation() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Settings;
+        store.ui.settings_mode = SettingsMode::Master;
+        store.ui.settings_row = SettingsRow::ResetDevice;
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert_eq!(effect, Effect::Noop);
+        assert!(matches!(
+            select_active_screen(&store),
+            ActiveScreenModel::Confirm(model) if model.title == "RESET DEVICE?" && !model.accept_focused
+        ));
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+        assert_eq!(effect, Effect::Noop);
+        assert_eq!(store.ui.confirm, None);
+        assert_eq!(store.ui.settings_mode, SettingsMode::Master);
+
+        let _ = store.dispatch(Command::Ui(UiCommand::Confirm));
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert_eq!(effect, Effect::Noop);
+        assert_eq!(store.ui.route, UiRoute::Settings);
+
+        let _ = store.dispatch(Command::Ui(UiCommand::Confirm));
+        let _ = store.dispatch(Command::Ui(UiCommand::FocusPrevious));
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert_eq!(effect, Effect::FactoryReset);
+    }
+
+    #[test]
+    fn screenshot_row_saves_the_frame_once_the_timer_runs_out() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Settings;
+        store.ui.settings_mode = SettingsMode::Master;
+        store.ui.settings_row = SettingsRow::Screenshot;
+        store.sleep.note_activity(1_000);
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert_eq!(effect, Effect::Noop);
+        assert!(
+            store
+                .ui
+                .toasts
+                .iter()
+                .any(|toast| toast.kind == ToastKind::ScreenshotTimer)
+        );
+
+        let _ = store.dispatch(Command::Ui(UiCommand::Back));
+        assert_eq!(
+            store.handle_event(Event::UiTick(5_999), 5_999).unwrap(),
+            Effect::Noop
+        );
+        assert_eq!(
+            store.handle_event(Event::UiTick(6_000), 6_000).unwrap(),
+            Effect::SaveScreenshot
+        );
+        assert_eq!(
+            store.handle_event(Event::UiTick(6_100), 6_100).unwrap(),
+            Effect::Noop
+        );
+
+        store.handle_event(Event::ScreenshotSaved, 6_200).unwrap();
+        assert!(
+            store
+                .ui
+                .toasts
+                .iter()
+                .any(|toast| toast.kind == ToastKind::ScreenshotSaved)
+        );
+    }
+
+    #[test]
+    fn onboarding_fixes_the_knob_and_speed_then_persists_once_finished() {
+        let mut store = Store::new();
+        store.onboarding = Some(OnboardingState::new());
+
+        // The first step asks for a clockwise turn; this knob reports it as counterclockwise.
+        store
+            .dispatch(Command::Ui(UiCommand::FocusPrevious))
+            .unwrap();
+        assert!(store.settings.encoder_reversed);
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        assert_eq!(store.settings.reading_speed_wpm, 280);
+        match select_active_screen(&store) {
+            ActiveScreenModel::Onboarding(model) => {
+                assert_eq!(model.step, OnboardingStep::ReadingSpeed);
+                assert_eq!(model.demo_word, Some("Motif"));
+            }
+            other => panic!("expected onboarding, got {other:?}"),
+        }
+
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+        assert_eq!(store.onboarding.unwrap().step, OnboardingStep::Wifi);
+        store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        assert_eq!(store.onboarding, None);
+        let Effect::PersistSettings(persisted) = effect else {
+            panic!("expected a settings write, got {effect:?}");
+        };
+        assert!(persisted.onboarded);
+        assert!(persisted.encoder_reversed);
+        assert_eq!(persisted.reading_speed_wpm, 280);
+        assert!(matches!(
+            select_active_screen(&store),
+            ActiveScreenModel::Dashboard(_)
+        ));
+    }
+
+    #[test]
+    fn screensaver_covers_idle_library_and_first_input_only_wakes() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Settings;
+        store.ui.settings_row = SettingsRow::Screensaver;
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+        assert!(
+            matches!(effect, Effect::PersistSettings(settings) if settings.screensaver_minutes == 2)
+        );
+
+        store
+            .handle_event(Event::ScreensaverTimeout, 200_000)
+            .unwrap();
+        assert!(!store.screensaver_visible);
+
+        store.ui.route = UiRoute::Dashboard;
+        store
+            .handle_event(Event::ScreensaverTimeout, 60_000)
+            .unwrap();
+        assert!(!store.screensaver_visible);
+        store
+            .handle_event(Event::ScreensaverTimeout, 120_000)
+            .unwrap();
+        assert!(matches!(
+            select_active_screen(&store),
+            ActiveScreenModel::Screensaver(model) if model.title.is_empty()
+        ));
+
+        let focused = store.ui.dashboard_focus;
+        let effect = store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        assert_eq!(effect, Effect::Noop);
+        assert!(!store.screensaver_visible);
+        assert_eq!(store.ui.dashboard_focus, focused);
+    }
+
+    #[test]
+    fn paused_reader_save_row_dispatches_save_toggle_effect() {
+        let mut store = Store::new();
+        store.network.status = NetworkStatus::Online;
+        store.backend_sync.status = SyncStatus::Ready;
+        let request = store.open_cached_content(
+            CollectionKind::Inbox,
+            crate::text::InlineText::from_slice("content-1"),
+            7,
+            crate::text::InlineText::from_slice("Example inbox title"),
+            120,
+            alloc::vec![
+                ReaderParagraphInfo {
+                    start_unit_index: 0,
+                    preview: crate::text::InlineText::new(),
+                },
+                ReaderParagraphInfo {
+                    start_unit_index: 64,
+                    preview: crate::text::InlineText::new(),
                 },
             ]
             .into_boxed_slice(),
             make_reader_window(0, 64),
         );
         assert_eq!(request, None);
-        store.reader.pause(false);
+        store.reader.pause(false, 0);
         store
             .reader
             .apply_pause_detail(crate::content::ReaderPauseDetail {
@@ -2481,6 +5018,8 @@ mod tests {
             });
         let _ = store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
         let _ = store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        let _ = store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        let _ = store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
 
         let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
 
@@ -2518,7 +5057,7 @@ mod tests {
             make_reader_window(0, 64),
         );
         assert_eq!(request, None);
-        store.reader.pause(false);
+        store.reader.pause(false, 0);
         store
             .reader
             .apply_pause_detail(crate::content::ReaderPauseDetail {
@@ -2531,6 +5070,8 @@ mod tests {
         let _ = store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
         let _ = store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
         let _ = store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        let _ = store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        let _ = store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
 
         let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
 
@@ -2544,6 +5085,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn paused_reader_define_row_looks_up_current_word_and_shows_result() {
+        let mut store = Store::new();
+        store.network.status = NetworkStatus::Online;
+        store.backend_sync.status = SyncStatus::Ready;
+        let mut window = make_reader_window(0, 64);
+        window.units[0].display = crate::text::InlineText::from_slice("Liminal.");
+        let _ = store.open_cached_content(
+            CollectionKind::Inbox,
+            crate::text::InlineText::from_slice("content-1"),
+            7,
+            crate::text::InlineText::from_slice("Example inbox title"),
+            64,
+            alloc::vec![ReaderParagraphInfo {
+                start_unit_index: 0,
+                preview: crate::text::InlineText::new(),
+            }]
+            .into_boxed_slice(),
+            window,
+        );
+        store.ui.route = UiRoute::Reader;
+        store.reader.pause(false, 0);
+        store.reader.pause.selected_row = PauseMenuRow::DefineWord;
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        assert_eq!(
+            effect,
+            Effect::LookupDefinition(DictionaryLookupRequest::new(
+                crate::text::InlineText::from_slice("Liminal")
+            ))
+        );
+        assert_eq!(store.reader.mode, ReaderMode::Definition);
+
+        let _ = store
+            .handle_event(
+                Event::DictionaryDefinitionLoaded(crate::content::DictionaryDefinition {
+                    word: crate::text::InlineText::from_slice("Liminal"),
+                    part_of_speech: crate::text::InlineText::from_slice("adjective"),
+                    definition: crate::text::InlineText::from_slice(
+                        "Occupying a position at a boundary or threshold.",
+                    ),
+                }),
+                10,
+            )
+            .unwrap();
+
+        assert_eq!(
+            store.reader.definition.status,
+            crate::reader::ReaderDefinitionStatus::Ready
+        );
+
+        let _ = store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+
+        assert_eq!(store.reader.mode, ReaderMode::Paused);
+    }
+
+    #[test]
+    fn paused_reader_define_row_is_ignored_offline() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Reader;
+        store.reader.mode = ReaderMode::Paused;
+        store.reader.pause.selected_row = PauseMenuRow::DefineWord;
+
+        let effect = store.dispatch(Command::Ui(UiCommand::Confirm)).unwrap();
+
+        assert_eq!(effect, Effect::Noop);
+        assert_eq!(store.reader.mode, ReaderMode::Paused);
+    }
+
     #[test]
     fn saved_content_events_replace_live_saved_manifest() {
         let mut store = Store::new();
@@ -2576,6 +5187,49 @@ mod tests {
         assert_eq!(store.ui.saved_index, 0);
     }
 
+    #[test]
+    fn device_books_stay_in_saved_across_sync_updates() {
+        let manifest_with = |entries: &[(&str, &str)]| {
+            let mut manifest = CollectionManifestState::empty();
+            for (content_id, meta) in entries {
+                let mut item = CollectionManifestItem::empty();
+                item.remote_item_id.set_truncated(content_id);
+                item.content_id.set_truncated(content_id);
+                item.meta.set_truncated(meta);
+                let _ = manifest.try_push(item);
+            }
+            manifest
+        };
+        let mut store = Store::from_bootstrap(BootstrapSnapshot::default().with_device_books(
+            Some(alloc::boxed::Box::new(manifest_with(&[
+                ("flash-1", "ON DEVICE"),
+                ("shared", "ON DEVICE"),
+            ]))),
+        ));
+        assert_eq!(store.content().collection_len(CollectionKind::Saved), 2);
+
+        store
+            .handle_event(
+                Event::CollectionContentUpdated(
+                    CollectionKind::Saved,
+                    alloc::boxed::Box::new(manifest_with(&[("shared", "SYNCED"), ("net-1", "")])),
+                ),
+                0,
+            )
+            .unwrap();
+
+        let saved = store.content().collection_state(CollectionKind::Saved);
+        let ids: alloc::vec::Vec<_> = (0..saved.len())
+            .filter_map(|index| saved.item_at(index))
+            .map(|item| (item.content_id, item.meta))
+            .collect();
+        assert_eq!(ids.len(), 3);
+        assert_eq!(ids[0].0.as_str(), "shared");
+        assert_eq!(ids[0].1.as_str(), "SYNCED");
+        assert_eq!(ids[1].0.as_str(), "net-1");
+        assert_eq!(ids[2].0.as_str(), "flash-1");
+    }
+
     #[test]
     fn empty_collection_update_does_not_allocate_live_content_state() {
         let mut store = Store::new();
@@ -2663,6 +5317,59 @@ mod tests {
         assert_eq!(store.ui.settings_row, SettingsRow::ReadingSpeed);
     }
 
+    #[test]
+    fn turning_past_the_last_settings_row_opens_diagnostics() {
+        let mut store = Store::new();
+        store.ui.route = UiRoute::Settings;
+        store.ui.settings_mode = SettingsMode::Master;
+        store.ui.settings_row = SettingsRow::ResetDevice;
+
+        for _ in 1..DIAGNOSTICS_UNLOCK_TURNS {
+            store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        }
+        store
+            .dispatch(Command::Ui(UiCommand::FocusPrevious))
+            .unwrap();
+        store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        assert_eq!(store.ui.route, UiRoute::Settings);
+
+        for _ in 0..DIAGNOSTICS_UNLOCK_TURNS {
+            store.dispatch(Command::Ui(UiCommand::FocusNext)).unwrap();
+        }
+        assert_eq!(store.ui.route, UiRoute::Diagnostics);
+
+        let mut perf = PerfCounters::new();
+        perf.flush.record(800);
+        store
+            .handle_event(
+                Event::DiagnosticsSampled(DiagnosticsSnapshot {
+                    firmware_version: "0.1.0",
+                    heap_free_bytes: 96 * 1024,
+                    stack_peak_bytes: 9_000,
+                    stack_size_bytes: 64 * 1024,
+                    sd_clock_hz: 20_000_000,
+                    perf,
+                    ..DiagnosticsSnapshot::default()
+                }),
+                0,
+            )
+            .unwrap();
+        let ActiveScreenModel::Diagnostics(model) = select_active_screen(&store) else {
+            panic!("expected the diagnostics screen");
+        };
+        let values = model.rows.map(|row| row.value);
+        assert_eq!(values[0].as_str(), "0.1.0");
+        assert_eq!(values[1].as_str(), "96 KB");
+        assert_eq!(values[2].as_str(), "--");
+        assert_eq!(values[4].as_str(), "9 / 64 KB");
+        assert_eq!(values[5].as_str(), "20 MHz");
+        assert_eq!(values[6].as_str(), "--");
+        assert_eq!(values[7].as_str(), "800 / 800 us");
+
+        store.dispatch(Command::Ui(UiCommand::Back)).unwrap();
+        assert_eq!(store.ui.route, UiRoute::Settings);
+    }
+
     #[test]
     fn topic_preferences_focus_next_stops_at_last_chip() {
         let mut store = Store::new();
@@ -0,0 +1,196 @@
+use alloc::vec::Vec;
+
+use crate::{
+    input::{InputGesture, RotationDirection},
+    runtime::{Command, Effect, Event},
+    selectors::{ActiveScreenModel, select_active_screen},
+    store::Store,
+};
+
+// Match the platform loop so timing-dependent flows behave the same under test.
+pub const MOCK_READER_TICK_MS: u64 = 20;
+pub const MOCK_UI_TICK_MS: u64 = 160;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ScriptedGesture {
+    pub at_ms: u64,
+    pub gesture: InputGesture,
+}
+
+// A scripted encoder. Gestures replay in the order they were added at absolute simulated times,
+// interleaved with the reader and UI ticks the platform would have sent, so a whole flow runs
+// deterministically without a clock.
+#[derive(Debug, Clone, Default)]
+pub struct MockInput {
+    steps: Vec<ScriptedGesture>,
+    cursor: usize,
+    now_ms: u64,
+    next_ui_tick_ms: u64,
+}
+
+impl MockInput {
+    pub const fn new() -> Self {
+        Self {
+            steps: Vec::new(),
+            cursor: 0,
+            now_ms: 0,
+            next_ui_tick_ms: 0,
+        }
+    }
+
+    pub fn gesture(mut self, at_ms: u64, gesture: InputGesture) -> Self {
+        self.steps.push(ScriptedGesture { at_ms, gesture });
+        self
+    }
+
+    pub fn rotate(self, at_ms: u64, direction: RotationDirection) -> Self {
+        self.gesture(at_ms, InputGesture::Rotate { direction })
+    }
+
+    pub fn focus_next(self, at_ms: u64) -> Self {
+        self.rotate(at_ms, RotationDirection::Clockwise)
+    }
+
+    pub fn focus_previous(self, at_ms: u64) -> Self {
+        self.rotate(at_ms, RotationDirection::CounterClockwise)
+    }
+
+    pub fn click(self, at_ms: u64) -> Self {
+        self.gesture(at_ms, InputGesture::Click)
+    }
+
+    pub fn long_press(self, at_ms: u64) -> Self {
+        self.gesture(at_ms, InputGesture::LongPress)
+    }
+
+    pub const fn now_ms(&self) -> u64 {
+        self.now_ms
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.cursor >= self.steps.len()
+    }
+
+    // Advances simulated time to `until_ms`, one reader tick at a time. Due gestures go first at
+    // each step, then the ticks; the renderer sees the store after every event. Returns every
+    // effect the store raised, in order, for the test to assert on or feed back in.
+    pub fn run_until(
+        &mut self,
+        store: &mut Store,
+        renderer: &mut RecordingRenderer,
+        until_ms: u64,
+    ) -> Vec<Effect> {
+        let mut effects = Vec::new();
+        renderer.record(store, self.now_ms);
+
+        while self.now_ms <= until_ms {
+            let now_ms = self.now_ms;
+            while let Some(step) = self.steps.get(self.cursor).copied() {
+                if step.at_ms > now_ms {
+                    break;
+                }
+                self.cursor += 1;
+                push_effect(
+                    &mut effects,
+                    store.handle_event(Event::InputGestureReceived(step.gesture), now_ms),
+                );
+                push_effect(
+                    &mut effects,
+                    store.dispatch(Command::for_gesture(step.gesture)),
+                );
+                renderer.record(store, now_ms);
+            }
+
+            push_effect(
+                &mut effects,
+                store.handle_event(Event::ReaderTick(now_ms), now_ms),
+            );
+            if now_ms >= self.next_ui_tick_ms {
+                self.next_ui_tick_ms = now_ms + MOCK_UI_TICK_MS;
+                push_effect(
+                    &mut effects,
+                    store.handle_event(Event::UiTick(now_ms), now_ms),
+                );
+            }
+            renderer.record(store, now_ms);
+            self.now_ms += MOCK_READER_TICK_MS;
+        }
+
+        effects
+    }
+}
+
+fn push_effect<E>(effects: &mut Vec<Effect>, result: Result<Effect, E>) {
+    if let Ok(effect) = result
+        && !matches!(effect, Effect::Noop)
+    {
+        effects.push(effect);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RecordedScreen {
+    pub at_ms: u64,
+    pub screen: ActiveScreenModel,
+}
+
+// Stands in for the display: keeps each distinct screen model the selectors produce, in order.
+// Repeats are dropped the way the app task skips unchanged updates.
+#[derive(Debug, Clone, Default)]
+pub struct RecordingRenderer {
+    screens: Vec<RecordedScreen>,
+}
+
+impl RecordingRenderer {
+    pub const fn new() -> Self {
+        Self {
+            screens: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, store: &Store, at_ms: u64) -> bool {
+        let screen = select_active_screen(store);
+        if self.last() == Some(&screen) {
+            return false;
+        }
+        self.screens.push(RecordedScreen { at_ms, screen });
+        true
+    }
+
+    pub fn screens(&self) -> &[RecordedScreen] {
+        &self.screens
+    }
+
+    pub fn last(&self) -> Option<&ActiveScreenModel> {
+        self.screens.last().map(|recorded| &recorded.screen)
+    }
+
+    pub fn clear(&mut self) {
+        self.screens.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scripted_input_walks_into_a_collection_and_back() {
+        let mut store = Store::new();
+        let mut renderer = RecordingRenderer::new();
+        let mut input = MockInput::new().click(100).long_press(400);
+
+        input.run_until(&mut store, &mut renderer, 600);
+
+        assert!(input.is_finished());
+        let screens = renderer.screens();
+        assert!(matches!(screens[0].screen, ActiveScreenModel::Dashboard(_)));
+        assert!(screens.iter().any(|recorded| {
+            recorded.at_ms == 100 && matches!(recorded.screen, ActiveScreenModel::Collection(_))
+        }));
+        assert!(matches!(
+            renderer.last(),
+            Some(ActiveScreenModel::Dashboard(_))
+        ));
+    }
+}
@@ -1,5 +1,12 @@
 use core::str;
 
+// Long enough for `&#x10FFFF` and the longest named entity in the table below.
+const HTML_ENTITY_MAX_BYTES: usize = 10;
+const HTML_TAG_NAME_MAX_BYTES: usize = 10;
+// Text after a `<` is held until the tag closes; an unclosed run this long is taken for a tag cut
+// off by the end of the input rather than a literal `<`.
+const HTML_TAG_PENDING_MAX_BYTES: usize = 128;
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct InlineText<const N: usize> {
     bytes: [u8; N],
@@ -42,6 +49,12 @@ impl<const N: usize> InlineText<N> {
         self.try_push_str(ch.encode_utf8(&mut utf8))
     }
 
+    pub fn pop_char(&mut self) -> Option<char> {
+        let last = self.as_str().chars().next_back()?;
+        self.len -= last.len_utf8() as u8;
+        Some(last)
+    }
+
     pub fn set_truncated(&mut self, value: &str) {
         self.clear();
 
@@ -74,3 +87,423 @@ impl<const N: usize> Default for InlineText<N> {
         Self::new()
     }
 }
+
+// Package text can still carry HTML entities from the source page. The decoder
+// is fed one character at a time so an entity split across read chunks is
+// carried in `pending` until its terminating `;` arrives.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct HtmlEntityDecoder {
+    pending: InlineText<HTML_ENTITY_MAX_BYTES>,
+}
+
+impl HtmlEntityDecoder {
+    pub const fn new() -> Self {
+        Self {
+            pending: InlineText::new(),
+        }
+    }
+
+    pub fn push(&mut self, ch: char, emit: &mut impl FnMut(char)) {
+        if self.pending.is_empty() {
+            if ch == '&' {
+                let _ = self.pending.try_push_char(ch);
+            } else {
+                emit(ch);
+            }
+            return;
+        }
+
+        if ch == ';' {
+            match decode_html_entity(&self.pending.as_str()[1..]) {
+                Some(decoded) => emit(decoded),
+                None => {
+                    self.flush(emit);
+                    emit(ch);
+                }
+            }
+            self.pending.clear();
+            return;
+        }
+
+        if ch == '&' {
+            self.flush(emit);
+            let _ = self.pending.try_push_char(ch);
+            return;
+        }
+
+        if (ch.is_ascii_alphanumeric() || ch == '#') && self.pending.try_push_char(ch) {
+            return;
+        }
+
+        self.flush(emit);
+        emit(ch);
+    }
+
+    pub fn finish(&mut self, emit: &mut impl FnMut(char)) {
+        self.flush(emit);
+    }
+
+    fn flush(&mut self, emit: &mut impl FnMut(char)) {
+        for pending in self.pending.as_str().chars() {
+            emit(pending);
+        }
+        self.pending.clear();
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+enum HtmlTagState {
+    #[default]
+    Text,
+    Open,
+    Name,
+    Attributes,
+    Quoted(char),
+}
+
+// Markup that survives backend normalization is dropped without leaking
+// attribute fragments, even when a tag is split across read chunks. Block
+// level tags become a space so the words on either side stay apart. A `<`
+// whose tag never closes (`i<n` at the end of a block) reads as text.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct HtmlTagStripper {
+    state: HtmlTagState,
+    name: InlineText<HTML_TAG_NAME_MAX_BYTES>,
+    pending: InlineText<HTML_TAG_PENDING_MAX_BYTES>,
+    pending_overflowed: bool,
+}
+
+impl HtmlTagStripper {
+    pub const fn new() -> Self {
+        Self {
+            state: HtmlTagState::Text,
+            name: InlineText::new(),
+            pending: InlineText::new(),
+            pending_overflowed: false,
+        }
+    }
+
+    pub fn push(&mut self, ch: char, emit: &mut impl FnMut(char)) {
+        if matches!(
+            self.state,
+            HtmlTagState::Name | HtmlTagState::Attributes | HtmlTagState::Quoted(_)
+        ) {
+            self.push_pending(ch);
+        }
+        match self.state {
+            HtmlTagState::Text => {
+                if ch == '<' {
+                    self.state = HtmlTagState::Open;
+                } else {
+                    emit(ch);
+                }
+            }
+            HtmlTagState::Open => {
+                if ch.is_ascii_alphabetic() || matches!(ch, '/' | '!' | '?') {
+                    self.name.clear();
+                    self.pending.clear();
+                    self.pending_overflowed = false;
+                    self.state = HtmlTagState::Name;
+                    self.push_pending(ch);
+                    self.push_name(ch);
+                } else {
+                    emit('<');
+                    self.state = HtmlTagState::Text;
+                    self.push(ch, emit);
+                }
+            }
+            HtmlTagState::Name => {
+                if ch == '>' {
+                    self.close_tag(emit);
+                } else if ch.is_ascii_alphanumeric() {
+                    self.push_name(ch);
+                } else {
+                    self.state = HtmlTagState::Attributes;
+                }
+            }
+            HtmlTagState::Attributes => match ch {
+                '>' => self.close_tag(emit),
+                '"' | '\'' => self.state = HtmlTagState::Quoted(ch),
+                _ => {}
+            },
+            HtmlTagState::Quoted(quote) => {
+                if ch == quote {
+                    self.state = HtmlTagState::Attributes;
+                }
+            }
+        }
+    }
+
+    pub fn finish(&mut self, emit: &mut impl FnMut(char)) {
+        match self.state {
+            HtmlTagState::Open => emit('<'),
+            HtmlTagState::Name | HtmlTagState::Attributes | HtmlTagState::Quoted(_)
+                if !self.pending_overflowed =>
+            {
+                emit('<');
+                self.pending.as_str().chars().for_each(&mut *emit);
+            }
+            _ => {}
+        }
+        self.state = HtmlTagState::Text;
+        self.name.clear();
+        self.pending.clear();
+    }
+
+    fn push_pending(&mut self, ch: char) {
+        if !self.pending.try_push_char(ch) {
+            self.pending_overflowed = true;
+        }
+    }
+
+    fn push_name(&mut self, ch: char) {
+        if ch != '/' {
+            let _ = self.name.try_push_char(ch.to_ascii_lowercase());
+        }
+    }
+
+    fn close_tag(&mut self, emit: &mut impl FnMut(char)) {
+        if is_block_html_tag(self.name.as_str()) {
+            emit(' ');
+        }
+        self.state = HtmlTagState::Text;
+        self.name.clear();
+        self.pending.clear();
+    }
+}
+
+fn is_block_html_tag(name: &str) -> bool {
+    matches!(
+        name,
+        "p" | "br"
+            | "div"
+            | "li"
+            | "ul"
+            | "ol"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "hr"
+            | "tr"
+            | "td"
+            | "th"
+            | "blockquote"
+            | "section"
+            | "article"
+            | "figure"
+            | "figcaption"
+            | "pre"
+    )
+}
+
+// Reader-visible package text runs through tag stripping first so entities
+// inside attributes are discarded, then entity decoding on what remains.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct HtmlTextFilter {
+    tags: HtmlTagStripper,
+    entities: HtmlEntityDecoder,
+}
+
+impl HtmlTextFilter {
+    pub const fn new() -> Self {
+        Self {
+            tags: HtmlTagStripper::new(),
+            entities: HtmlEntityDecoder::new(),
+        }
+    }
+
+    pub fn push(&mut self, ch: char, emit: &mut impl FnMut(char)) {
+        let entities = &mut self.entities;
+        self.tags
+            .push(ch, &mut |text| entities.push(text, &mut *emit));
+    }
+
+    pub fn finish(&mut self, emit: &mut impl FnMut(char)) {
+        let entities = &mut self.entities;
+        self.tags
+            .finish(&mut |text| entities.push(text, &mut *emit));
+        self.entities.finish(emit);
+    }
+}
+
+const NAMED_HTML_ENTITIES: &[(&str, char)] = &[
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+    ("nbsp", '\u{00A0}'),
+    ("ensp", ' '),
+    ("emsp", ' '),
+    ("thinsp", ' '),
+    ("shy", '\u{00AD}'),
+    ("ndash", '–'),
+    ("mdash", '—'),
+    ("hellip", '…'),
+    ("lsquo", '‘'),
+    ("rsquo", '’'),
+    ("sbquo", '‚'),
+    ("ldquo", '“'),
+    ("rdquo", '”'),
+    ("bdquo", '„'),
+    ("laquo", '«'),
+    ("raquo", '»'),
+    ("bull", '•'),
+    ("middot", '·'),
+    ("deg", '°'),
+    ("times", '×'),
+    ("divide", '÷'),
+    ("copy", '©'),
+    ("reg", '®'),
+    ("trade", '™'),
+    ("sect", '§'),
+    ("para", '¶'),
+    ("euro", '€'),
+    ("pound", '£'),
+    ("cent", '¢'),
+    ("yen", '¥'),
+    ("iexcl", '¡'),
+    ("iquest", '¿'),
+    ("aacute", 'á'),
+    ("agrave", 'à'),
+    ("acirc", 'â'),
+    ("auml", 'ä'),
+    ("aring", 'å'),
+    ("ccedil", 'ç'),
+    ("eacute", 'é'),
+    ("egrave", 'è'),
+    ("ecirc", 'ê'),
+    ("euml", 'ë'),
+    ("iacute", 'í'),
+    ("icirc", 'î'),
+    ("iuml", 'ï'),
+    ("ntilde", 'ñ'),
+    ("oacute", 'ó'),
+    ("ocirc", 'ô'),
+    ("ouml", 'ö'),
+    ("oslash", 'ø'),
+    ("uacute", 'ú'),
+    ("ucirc", 'û'),
+    ("uuml", 'ü'),
+    ("szlig", 'ß'),
+    ("Aacute", 'Á'),
+    ("Agrave", 'À'),
+    ("Auml", 'Ä'),
+    ("Ccedil", 'Ç'),
+    ("Eacute", 'É'),
+    ("Egrave", 'È'),
+    ("Iacute", 'Í'),
+    ("Ntilde", 'Ñ'),
+    ("Oacute", 'Ó'),
+    ("Ouml", 'Ö'),
+    ("Uacute", 'Ú'),
+    ("Uuml", 'Ü'),
+];
+
+fn decode_html_entity(name: &str) -> Option<char> {
+    if let Some(numeric) = name.strip_prefix('#') {
+        let codepoint = match numeric.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => numeric.parse::<u32>().ok()?,
+        };
+        if codepoint == 0 {
+            return None;
+        }
+        return char::from_u32(codepoint);
+    }
+
+    NAMED_HTML_ENTITIES
+        .iter()
+        .find(|(entity, _)| *entity == name)
+        .map(|(_, decoded)| *decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    fn decode_chunks(chunks: &[&str]) -> String {
+        let mut decoder = HtmlEntityDecoder::new();
+        let mut output = String::new();
+        for chunk in chunks {
+            for ch in chunk.chars() {
+                decoder.push(ch, &mut |decoded| output.push(decoded));
+            }
+        }
+        decoder.finish(&mut |decoded| output.push(decoded));
+        output
+    }
+
+    #[test]
+    fn decodes_named_and_numeric_entities() {
+        assert_eq!(
+            decode_chunks(&["Fish &amp; chips &mdash; it&#8217;s caf&eacute; &#x2026;"]),
+            "Fish & chips — it’s café …"
+        );
+    }
+
+    #[test]
+    fn entities_split_across_chunks_still_decode() {
+        assert_eq!(
+            decode_chunks(&["rock &am", "p; roll &#82", "17;"]),
+            "rock & roll ’"
+        );
+    }
+
+    fn filter_chunks(chunks: &[&str]) -> String {
+        let mut filter = HtmlTextFilter::new();
+        let mut output = String::new();
+        for chunk in chunks {
+            for ch in chunk.chars() {
+                filter.push(ch, &mut |filtered| output.push(filtered));
+            }
+        }
+        filter.finish(&mut |filtered| output.push(filtered));
+        output
+    }
+
+    #[test]
+    fn tags_split_across_chunks_do_not_leak_attributes() {
+        assert_eq!(
+            filter_chunks(&["Call <span cl", "ass=\"calib>re\">me</sp", "an> Ishmael."]),
+            "Call me Ishmael."
+        );
+    }
+
+    #[test]
+    fn block_tags_separate_words_and_inline_tags_do_not() {
+        assert_eq!(
+            filter_chunks(&["end<br/>start <em>bold</em>ly<p>next"]),
+            "end start boldly next"
+        );
+    }
+
+    #[test]
+    fn stray_angle_brackets_and_escaped_markup_stay_visible() {
+        assert_eq!(filter_chunks(&["a < b and c <"]), "a < b and c <");
+        assert_eq!(filter_chunks(&["&lt;b&gt; is &amp;amp;"]), "<b> is &amp;");
+    }
+
+    #[test]
+    fn unterminated_tags_at_the_end_read_as_text() {
+        assert_eq!(filter_chunks(&["x<y"]), "x<y");
+        assert_eq!(
+            filter_chunks(&["for i<n", " or i<=\"m"]),
+            "for i<n or i<=\"m"
+        );
+        assert_eq!(filter_chunks(&["cut <a href=\"", &"x".repeat(200)]), "cut ");
+    }
+
+    #[test]
+    fn unknown_or_unterminated_entities_pass_through() {
+        assert_eq!(
+            decode_chunks(&["AT&T &bogus; 5 & 6 &amp"]),
+            "AT&T &bogus; 5 & 6 &amp"
+        );
+        assert_eq!(decode_chunks(&["&&lt;"]), "&<");
+    }
+}
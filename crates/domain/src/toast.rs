@@ -0,0 +1,185 @@
+// Short banners drawn over whatever screen is up. They never take input: rotation and clicks keep
+// going to the screen underneath, and each toast leaves on its own once its time is up.
+
+pub const TOAST_QUEUE_CAPACITY: usize = 3;
+const TOAST_SHORT_MS: u64 = 1_800;
+const TOAST_LONG_MS: u64 = 3_000;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ToastKind {
+    SettingsSaved,
+    WifiConnected,
+    WifiLost,
+    BookDeleted,
+    ScreenshotTimer,
+    ScreenshotSaved,
+    BookmarkAdded,
+    BookmarkRemoved,
+    AutoPaused,
+    CardReadFailed,
+}
+
+impl ToastKind {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::SettingsSaved => "Settings saved",
+            Self::WifiConnected => "Wi-Fi connected",
+            Self::WifiLost => "Wi-Fi lost",
+            Self::BookDeleted => "Book deleted",
+            Self::ScreenshotTimer => "Screenshot in 5 s",
+            Self::ScreenshotSaved => "Screenshot saved",
+            Self::BookmarkAdded => "Bookmark added",
+            Self::BookmarkRemoved => "Bookmark removed",
+            Self::AutoPaused => "Paused: no input",
+            Self::CardReadFailed => "Card read failed",
+        }
+    }
+
+    pub const fn duration_ms(self) -> u64 {
+        match self {
+            Self::SettingsSaved
+            | Self::WifiConnected
+            | Self::BookDeleted
+            | Self::ScreenshotTimer
+            | Self::ScreenshotSaved
+            | Self::BookmarkAdded
+            | Self::BookmarkRemoved => TOAST_SHORT_MS,
+            Self::WifiLost | Self::AutoPaused | Self::CardReadFailed => TOAST_LONG_MS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Toast {
+    pub kind: ToastKind,
+    pub shown_at_ms: u64,
+}
+
+impl Toast {
+    const fn expired(&self, now_ms: u64) -> bool {
+        now_ms.saturating_sub(self.shown_at_ms) >= self.kind.duration_ms()
+    }
+}
+
+// Oldest first. A toast already queued moves to the end with a fresh timer instead of stacking a
+// copy, and a full queue drops its oldest entry to make room.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ToastQueue {
+    toasts: [Option<Toast>; TOAST_QUEUE_CAPACITY],
+    len: usize,
+}
+
+impl ToastQueue {
+    pub const fn new() -> Self {
+        Self {
+            toasts: [None; TOAST_QUEUE_CAPACITY],
+            len: 0,
+        }
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts[..self.len].iter().flatten()
+    }
+
+    pub fn push(&mut self, kind: ToastKind, now_ms: u64) {
+        let queued = self.iter().position(|toast| toast.kind == kind);
+        if let Some(index) = queued {
+            self.remove(index);
+        } else if self.len == TOAST_QUEUE_CAPACITY {
+            self.remove(0);
+        }
+        self.toasts[self.len] = Some(Toast {
+            kind,
+            shown_at_ms: now_ms,
+        });
+        self.len += 1;
+    }
+
+    // Returns whether anything left, so the caller knows the overlay changed.
+    pub fn expire(&mut self, now_ms: u64) -> bool {
+        let before = self.len;
+        let mut index = 0;
+        while index < self.len {
+            if self.toasts[index].is_some_and(|toast| toast.expired(now_ms)) {
+                self.remove(index);
+            } else {
+                index += 1;
+            }
+        }
+        self.len != before
+    }
+
+    fn remove(&mut self, index: usize) {
+        self.toasts.copy_within(index + 1..self.len, index);
+        self.len -= 1;
+        self.toasts[self.len] = None;
+    }
+}
+
+impl Default for ToastQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(queue: &ToastQueue) -> [Option<ToastKind>; TOAST_QUEUE_CAPACITY] {
+        let mut kinds = [None; TOAST_QUEUE_CAPACITY];
+        for (slot, toast) in queue.iter().enumerate() {
+            kinds[slot] = Some(toast.kind);
+        }
+        kinds
+    }
+
+    #[test]
+    fn repeated_toast_refreshes_and_full_queue_drops_the_oldest() {
+        let mut queue = ToastQueue::new();
+        queue.push(ToastKind::WifiLost, 0);
+        queue.push(ToastKind::SettingsSaved, 100);
+        queue.push(ToastKind::WifiLost, 200);
+        assert_eq!(
+            kinds(&queue),
+            [
+                Some(ToastKind::SettingsSaved),
+                Some(ToastKind::WifiLost),
+                None
+            ]
+        );
+
+        queue.push(ToastKind::BookDeleted, 300);
+        queue.push(ToastKind::WifiConnected, 400);
+        assert_eq!(
+            kinds(&queue),
+            [
+                Some(ToastKind::WifiLost),
+                Some(ToastKind::BookDeleted),
+                Some(ToastKind::WifiConnected)
+            ]
+        );
+    }
+
+    #[test]
+    fn each_toast_expires_on_its_own_duration() {
+        let mut queue = ToastQueue::new();
+        queue.push(ToastKind::WifiLost, 0);
+        queue.push(ToastKind::SettingsSaved, 500);
+
+        assert!(!queue.expire(2_000));
+        assert!(queue.expire(2_300));
+        assert_eq!(kinds(&queue), [Some(ToastKind::WifiLost), None, None]);
+
+        assert!(queue.expire(3_000));
+        assert!(queue.is_empty());
+    }
+}
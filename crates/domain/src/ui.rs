@@ -1,12 +1,25 @@
-use crate::{content::CollectionKind, settings::TOPIC_CATEGORY_COUNT};
+use crate::{
+    content::CollectionKind,
+    picker::{CharacterPicker, PickerCharset},
+    settings::TOPIC_CATEGORY_COUNT,
+    settings_schema::{SETTINGS_SCHEMA, SettingsEntry, SettingsSection},
+    speed_preview::SpeedPreview,
+    toast::ToastQueue,
+};
+
+pub const SEARCH_QUERY_MAX_BYTES: usize = 24;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub enum UiRoute {
     #[default]
     Dashboard,
     Collection(CollectionKind),
+    Search(CollectionKind),
+    BookDetail(CollectionKind),
     Reader,
     Settings,
+    Diagnostics,
+    History,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
@@ -55,50 +68,171 @@ pub enum SettingsMode {
     TopicPreferences,
 }
 
+// The order, section, label and behaviour of each row live in `settings_schema::SETTINGS_SCHEMA`;
+// the variants only name the rows.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub enum SettingsRow {
     #[default]
     ReadingSpeed,
+    AdaptiveSpeed,
+    SectionPauses,
+    DotPause,
+    CommaPause,
+    WordTiming,
+    ShortestWord,
+    LongestWord,
+    WordGroups,
+    References,
+    DigitGroups,
+    ResumeRewind,
+    Countdown,
+    AnchorHighlight,
+    AnchorPosition,
+    BionicReading,
+    ReadingGoal,
+    ReadingHistory,
     Appearance,
+    NightMode,
+    LightSensor,
+    Screensaver,
+    LibraryLayout,
+    EncoderDirection,
+    EncoderSensitivity,
     RefreshData,
     TopicPreferences,
     NetworkConnection,
     ConnectAccount,
+    Sound,
+    Screenshot,
+    QuoteExport,
+    Profile,
+    ResetDevice,
 }
 
 impl SettingsRow {
-    pub const COUNT: usize = 6;
+    pub const COUNT: usize = SETTINGS_SCHEMA.len();
+
+    pub const fn entry(self) -> &'static SettingsEntry {
+        &SETTINGS_SCHEMA[self.index()]
+    }
+
+    pub const fn label(self) -> &'static str {
+        self.entry().label
+    }
+
+    pub const fn section(self) -> SettingsSection {
+        self.entry().section
+    }
+
+    pub const fn index(self) -> usize {
+        let mut index = 0;
+        while index < SETTINGS_SCHEMA.len() {
+            if SETTINGS_SCHEMA[index].row as usize == self as usize {
+                return index;
+            }
+            index += 1;
+        }
+        0
+    }
+
+    pub const fn from_index(index: usize) -> Self {
+        let last = SETTINGS_SCHEMA.len() - 1;
+        SETTINGS_SCHEMA[if index < last { index } else { last }].row
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum BookDetailAction {
+    #[default]
+    Read,
+    ReadFromStart,
+    Contents,
+    Bookmarks,
+    Finished,
+    Delete,
+}
+
+impl BookDetailAction {
+    pub const ALL: [Self; 6] = [
+        Self::Read,
+        Self::ReadFromStart,
+        Self::Contents,
+        Self::Bookmarks,
+        Self::Finished,
+        Self::Delete,
+    ];
 
     pub const fn label(self) -> &'static str {
         match self {
-            Self::ReadingSpeed => "Reading Speed",
-            Self::Appearance => "Appearance",
-            Self::RefreshData => "Refresh Data",
-            Self::TopicPreferences => "Topic Preferences",
-            Self::NetworkConnection => "Network Connection",
-            Self::ConnectAccount => "Connect Account",
+            Self::Read => "READ",
+            Self::ReadFromStart => "READ FROM START",
+            Self::Contents => "CONTENTS",
+            Self::Bookmarks => "BOOKMARKS",
+            Self::Finished => "MARK FINISHED",
+            Self::Delete => "DELETE FROM DEVICE",
         }
     }
 
     pub const fn index(self) -> usize {
         match self {
-            Self::ReadingSpeed => 0,
-            Self::Appearance => 1,
-            Self::RefreshData => 2,
-            Self::TopicPreferences => 3,
-            Self::NetworkConnection => 4,
-            Self::ConnectAccount => 5,
+            Self::Read => 0,
+            Self::ReadFromStart => 1,
+            Self::Contents => 2,
+            Self::Bookmarks => 3,
+            Self::Finished => 4,
+            Self::Delete => 5,
         }
     }
 
     pub const fn from_index(index: usize) -> Self {
-        match index {
-            0 => Self::ReadingSpeed,
-            1 => Self::Appearance,
-            2 => Self::RefreshData,
-            3 => Self::TopicPreferences,
-            4 => Self::NetworkConnection,
-            _ => Self::ConnectAccount,
+        Self::ALL[if index < Self::ALL.len() {
+            index
+        } else {
+            Self::ALL.len() - 1
+        }]
+    }
+}
+
+// What a yes/no dialog does when accepted. Declining, by click on the default choice or by Back,
+// always leaves the screen underneath as it was.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ConfirmAction {
+    DeleteBook(CollectionKind),
+    FactoryReset,
+}
+
+impl ConfirmAction {
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::DeleteBook(_) => "DELETE BOOK?",
+            Self::FactoryReset => "RESET DEVICE?",
+        }
+    }
+
+    pub const fn accept_label(self) -> &'static str {
+        match self {
+            Self::DeleteBook(_) => "DELETE",
+            Self::FactoryReset => "ERASE",
+        }
+    }
+
+    pub const fn cancel_label(self) -> &'static str {
+        "KEEP"
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ConfirmDialog {
+    pub action: ConfirmAction,
+    pub accept_focused: bool,
+}
+
+impl ConfirmDialog {
+    // Opens on the safe choice, so a stray double click never accepts.
+    pub const fn new(action: ConfirmAction) -> Self {
+        Self {
+            action,
+            accept_focused: false,
         }
     }
 }
@@ -152,7 +286,25 @@ pub struct UiState {
     pub recommendations_region: RecommendationsRegion,
     pub settings_mode: SettingsMode,
     pub settings_row: SettingsRow,
+    // Turns past the last settings row in a row; enough of them open diagnostics.
+    pub settings_overscroll: u8,
+    // Newest session first, like the History screen lists them.
+    pub history_index: usize,
+    // Plays while the speed editor is open.
+    pub speed_preview: SpeedPreview,
     pub topic_focus: TopicFocus,
+    pub collection_rotation_at_ms: Option<u64>,
+    pub collection_jump_letter: Option<char>,
+    pub collection_jump_letter_at_ms: u64,
+    pub search_picker: CharacterPicker<SEARCH_QUERY_MAX_BYTES>,
+    pub book_detail_action: BookDetailAction,
+    pub confirm: Option<ConfirmDialog>,
+    // Set by any settings write during a visit; leaving settings turns it into one toast.
+    pub settings_changed: bool,
+    // When the screenshot timer started from settings fires; the first UI tick past it saves the
+    // frame on screen.
+    pub screenshot_at_ms: Option<u64>,
+    pub toasts: ToastQueue,
 }
 
 impl UiState {
@@ -168,7 +320,19 @@ impl UiState {
             recommendations_region: RecommendationsRegion::Articles,
             settings_mode: SettingsMode::Master,
             settings_row: SettingsRow::ReadingSpeed,
+            settings_overscroll: 0,
+            history_index: 0,
+            speed_preview: SpeedPreview::new(),
             topic_focus: TopicFocus::new(),
+            collection_rotation_at_ms: None,
+            collection_jump_letter: None,
+            collection_jump_letter_at_ms: 0,
+            search_picker: CharacterPicker::new(PickerCharset::Search),
+            book_detail_action: BookDetailAction::Read,
+            confirm: None,
+            settings_changed: false,
+            screenshot_at_ms: None,
+            toasts: ToastQueue::new(),
         }
     }
 
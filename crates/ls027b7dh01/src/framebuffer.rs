@@ -187,6 +187,19 @@ impl FrameBuffer {
     }
 }
 
+/// Rows whose contents differ between the panel's committed frame and a newly drawn one.
+pub fn diff_dirty_rows(committed: &FrameBuffer, working: &FrameBuffer) -> DirtyRows {
+    let mut dirty_rows = DirtyRows::new();
+
+    for row in 0..HEIGHT {
+        if committed.row(row) != working.row(row) {
+            let _ = dirty_rows.mark_row(row);
+        }
+    }
+
+    dirty_rows
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -248,4 +261,18 @@ mod tests {
         assert_eq!(target.row(5), source.row(5));
         assert_eq!(target.row(6).unwrap(), &[0u8; LINE_BYTES]);
     }
+
+    #[test]
+    fn diff_dirty_rows_marks_only_changed_rows() {
+        let committed = FrameBuffer::new();
+        let mut working = FrameBuffer::new();
+        working.fill_rect(0, 12, 16, 2, true);
+
+        let dirty = diff_dirty_rows(&committed, &working);
+
+        assert_eq!(dirty.count(), 2);
+        assert!(dirty.is_dirty_row(12));
+        assert!(dirty.is_dirty_row(13));
+        assert!(!dirty.is_dirty_row(11));
+    }
 }
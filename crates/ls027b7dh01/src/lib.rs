@@ -10,7 +10,7 @@ pub mod protocol;
 mod graphics;
 
 pub use dirty_rows::{DirtyRowSpan, DirtyRows};
-pub use framebuffer::FrameBuffer;
+pub use framebuffer::{FrameBuffer, diff_dirty_rows};
 
 use core::convert::TryFrom;
 
@@ -48,14 +48,14 @@ use crate::{
 use domain::{
     content::{
         CONTENT_META_MAX_BYTES, CONTENT_TITLE_MAX_BYTES, CollectionKind, CollectionManifestItem,
-        CollectionManifestState, DetailLocator, MANIFEST_ITEM_CAPACITY, PackageState,
-        PrepareContentPhase, PrepareContentProgress, PrepareContentRequest,
-        RECOMMENDATION_SERVE_ID_MAX_BYTES, RECOMMENDATION_SUBTOPIC_CAPACITY,
-        RECOMMENDATION_SUBTOPIC_LABEL_MAX_BYTES, RECOMMENDATION_SUBTOPIC_SLUG_MAX_BYTES,
-        REMOTE_ITEM_ID_MAX_BYTES, ReaderPauseDetail, ReaderPauseDetailRequest,
-        ReaderSavedToggleRequest, ReaderSubscriptionToggleRequest, RecommendationSubtopic,
-        RecommendationSubtopicsState, RecommendationTopicRequest, RemoteContentStatus,
-        SOURCE_ID_MAX_BYTES,
+        CollectionManifestState, DICTIONARY_WORD_MAX_BYTES, DetailLocator, DictionaryDefinition,
+        DictionaryLookupRequest, MANIFEST_ITEM_CAPACITY, PackageState, PrepareContentPhase,
+        PrepareContentProgress, PrepareContentRequest, RECOMMENDATION_SERVE_ID_MAX_BYTES,
+        RECOMMENDATION_SUBTOPIC_CAPACITY, RECOMMENDATION_SUBTOPIC_LABEL_MAX_BYTES,
+        RECOMMENDATION_SUBTOPIC_SLUG_MAX_BYTES, REMOTE_ITEM_ID_MAX_BYTES, ReaderPauseDetail,
+        ReaderPauseDetailRequest, ReaderSavedToggleRequest, ReaderSubscriptionToggleRequest,
+        RecommendationSubtopic, RecommendationSubtopicsState, RecommendationTopicRequest,
+        RemoteContentStatus, SOURCE_ID_MAX_BYTES,
     },
     runtime::Event,
     text::InlineText,
@@ -76,6 +76,12 @@ const READER_PAUSE_CONTENT_PATH_PREFIX: &str = "/device/v1/me/content/";
 const READER_SAVE_SUFFIX: &str = "/save";
 const READER_SOURCE_SUBSCRIPTION_PATH_PREFIX: &str = "/device/v1/me/sources/";
 const READER_SOURCE_SUBSCRIPTION_SUFFIX: &str = "/subscription";
+// A build can send lookups to another dictionary route with `MOTIF_DICTIONARY_LOOKUP_PATH`; the
+// percent-encoded word is appended to it as is.
+const DICTIONARY_LOOKUP_PATH_PREFIX: &str = match option_env!("MOTIF_DICTIONARY_LOOKUP_PATH") {
+    Some(prefix) => prefix,
+    None => "/device/v1/dictionary/",
+};
 pub(crate) const BACKEND_PORT: u16 = 443;
 const NETWORK_POLL_MS: u64 = 500;
 const RETRY_BACKOFF_MS: u64 = 10_000;
@@ -498,6 +504,7 @@ enum BackendCommand {
     LoadReaderPauseDetail(ReaderPauseDetailRequest),
     ToggleReaderSaved(ReaderSavedToggleRequest),
     ToggleReaderSubscription(ReaderSubscriptionToggleRequest),
+    LookupDefinition(DictionaryLookupRequest),
     LoadRecommendationSubtopics,
     LoadRecommendationTopic(RecommendationTopicRequest),
 }
@@ -860,6 +867,12 @@ pub async fn request_reader_subscription_toggle(request: ReaderSubscriptionToggl
         .await;
 }
 
+pub async fn request_dictionary_lookup(request: DictionaryLookupRequest) {
+    BACKEND_CMD_CH
+        .send(BackendCommand::LookupDefinition(request))
+        .await;
+}
+
 pub async fn request_recommendation_subtopics() {
     BACKEND_CMD_CH
         .send(BackendCommand::LoadRecommendationSubtopics)
@@ -929,6 +942,7 @@ async fn backend_task(
 
             log_status(SyncStatus::RefreshingSession);
             crate::internet::set_probe_suspended(true);
+            let startup_busy = crate::watchdog::busy(crate::watchdog::WatchedTask::Network);
             let startup_sync_id = next_sync_id();
             log_startup_progress(0, STARTUP_SYNC_QUERY_COUNT);
             crate::memtrace!(
@@ -948,6 +962,7 @@ async fn backend_task(
                 startup_sync_id,
             )
             .await;
+            drop(startup_busy);
             crate::internet::set_probe_suspended(false);
 
             let startup_sync = match startup_sync {
@@ -1127,6 +1142,7 @@ async fn run_backend_command_loop<'a>(
         let Some(command) = command else {
             continue;
         };
+        let _busy = crate::watchdog::busy(crate::watchdog::WatchedTask::Network);
 
         match command {
             BackendCommand::PrepareContent(request) => {
@@ -1189,6 +1205,18 @@ async fn run_backend_command_loop<'a>(
                 .await;
                 log_status(SyncStatus::Ready);
             }
+            BackendCommand::LookupDefinition(request) => {
+                handle_dictionary_lookup_request(
+                    context,
+                    current,
+                    access_session,
+                    &mut reusable_session,
+                    &mut tls_session_cache,
+                    request,
+                )
+                .await;
+                log_status(SyncStatus::Ready);
+            }
             BackendCommand::LoadRecommendationSubtopics => {
                 handle_recommendation_subtopics_request(
                     context,
@@ -2456,6 +2484,84 @@ async fn handle_reader_subscription_toggle_request<'a>(
 }
 
 #[allow(clippy::too_many_arguments)]
+async fn handle_dictionary_lookup_request<'a>(
+    context: BackendRequestContext<'a>,
+    current: &mut StartupCredential,
+    access_session: &mut Option<ActiveAccessSession>,
+    reusable_session: &mut Option<ReusableBackendSession<'a>>,
+    tls_session_cache: &mut Option<SerializedClientSession>,
+    request: DictionaryLookupRequest,
+) {
+    if request.word.is_empty() {
+        return;
+    }
+
+    let operation_sync_id = next_sync_id();
+    if let Err(err) = ensure_access_session(
+        context.stack,
+        context.tls,
+        context.ca_chain,
+        context.tcp_state,
+        current,
+        access_session,
+        reusable_session,
+        tls_session_cache,
+        operation_sync_id,
+    )
+    .await
+    {
+        handle_reader_pause_access_error(err, current, access_session, reusable_session).await;
+        publish_event(
+            Event::DictionaryLookupFailed { word: request.word },
+            now_ms(),
+        );
+        return;
+    }
+
+    log_status(SyncStatus::SyncingContent);
+    let access_token = access_session
+        .as_ref()
+        .map(|session| session.access_token.clone())
+        .unwrap_or_default();
+    match perform_dictionary_lookup_reusing_session(
+        context.stack,
+        context.tls,
+        context.ca_chain,
+        context.tcp_client,
+        reusable_session,
+        tls_session_cache,
+        request.word,
+        access_token.as_ref(),
+        operation_sync_id,
+    )
+    .await
+    {
+        Ok(definition) => {
+            publish_event(Event::DictionaryDefinitionLoaded(definition), now_ms());
+        }
+        Err(CollectionQueryError::Rejected(status)) => {
+            if is_auth_status(status) {
+                invalidate_access_state(access_session, reusable_session).await;
+                log_status(SyncStatus::AuthFailed);
+            }
+            publish_event(
+                Event::DictionaryLookupFailed { word: request.word },
+                now_ms(),
+            );
+        }
+        Err(CollectionQueryError::Other(err)) => {
+            if is_transient_transport_error(err) {
+                *access_session = None;
+                log_status(SyncStatus::TransportFailed);
+            }
+            publish_event(
+                Event::DictionaryLookupFailed { word: request.word },
+                now_ms(),
+            );
+        }
+    }
+}
+
 async fn refresh_reader_pause_detail_after_action<'a>(
     context: BackendRequestContext<'a>,
     current: &StartupCredential,
@@ -4494,6 +4600,23 @@ fn build_reader_source_subscription_path(
     Ok(path)
 }
 
+fn build_dictionary_lookup_path(
+    word: &InlineText<DICTIONARY_WORD_MAX_BYTES>,
+) -> Result<heapless::String<128>, BackendError> {
+    let mut path = heapless::String::<128>::new();
+    path.push_str(DICTIONARY_LOOKUP_PATH_PREFIX)
+        .map_err(|_| BackendError::ResponseTooLarge)?;
+    for byte in word.as_str().bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            path.push(byte as char)
+                .map_err(|_| BackendError::ResponseTooLarge)?;
+        } else {
+            write!(&mut path, "%{:02X}", byte).map_err(|_| BackendError::ResponseTooLarge)?;
+        }
+    }
+    Ok(path)
+}
+
 fn parse_collection_page_cursor(
     endpoint: CollectionEndpoint,
     body: &str,
@@ -5125,6 +5248,54 @@ async fn perform_reader_pause_detail_fetch_reusing_session<'a>(
     parse_reader_pause_detail(content_id, response.body).map_err(CollectionQueryError::Other)
 }
 
+#[allow(clippy::too_many_arguments)]
+async fn perform_dictionary_lookup_reusing_session<'a>(
+    stack: Stack<'static>,
+    tls: TlsReference<'a>,
+    ca_chain: &Certificate<'static>,
+    tcp_client: &'a BackendTcpClient<'a>,
+    reusable_session: &mut Option<ReusableBackendSession<'a>>,
+    tls_session_cache: &mut Option<SerializedClientSession>,
+    word: InlineText<DICTIONARY_WORD_MAX_BYTES>,
+    access_token: &str,
+    sync_id: u32,
+) -> Result<DictionaryDefinition, CollectionQueryError> {
+    let path = build_dictionary_lookup_path(&word).map_err(CollectionQueryError::Other)?;
+    let trace = next_request_trace(sync_id);
+    let mut response_buffer =
+        allocate_standard_response_buffer(path.as_str()).map_err(CollectionQueryError::Other)?;
+    let response = send_https_request_reusing_session(
+        stack,
+        tls,
+        ca_chain,
+        tcp_client,
+        reusable_session,
+        tls_session_cache,
+        HttpRequest {
+            trace,
+            class: RequestClass::BufferedMetadata,
+            method: "GET",
+            path: path.as_str(),
+            content_type: Some("application/json"),
+            bearer_token: Some(access_token),
+            body: b"",
+            connection_close: false,
+        },
+        response_buffer.as_mut_slice(),
+    )
+    .await
+    .map_err(CollectionQueryError::Other)?;
+
+    if (400..500).contains(&response.status) {
+        return Err(CollectionQueryError::Rejected(response.status));
+    }
+    if response.status != 200 {
+        return Err(CollectionQueryError::Other(BackendError::InvalidResponse));
+    }
+
+    parse_dictionary_definition(word, response.body).map_err(CollectionQueryError::Other)
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn perform_reader_pause_mutation_reusing_session<'a>(
     stack: Stack<'static>,
@@ -6887,6 +7058,23 @@ fn parse_reader_pause_detail(
     })
 }
 
+fn parse_dictionary_definition(
+    word: InlineText<DICTIONARY_WORD_MAX_BYTES>,
+    body: &str,
+) -> Result<DictionaryDefinition, BackendError> {
+    let definition = extract_json_string_raw(body, "\"definition\"")
+        .ok_or(BackendError::MissingField)
+        .and_then(decode_json_string)?;
+    let part_of_speech =
+        extract_json_optional_inline_text(body, "\"part_of_speech\"")?.unwrap_or_default();
+
+    Ok(DictionaryDefinition {
+        word,
+        part_of_speech,
+        definition,
+    })
+}
+
 fn extract_json_top_level_array_items<'a, const N: usize>(
     json: &'a str,
     key: &str,
@@ -7875,6 +8063,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn builds_dictionary_lookup_path_with_escaped_word() {
+        let path = build_dictionary_lookup_path(&InlineText::from_slice("naïve")).unwrap();
+
+        assert_eq!(path.as_str(), "/device/v1/dictionary/na%C3%AFve");
+    }
+
+    #[test]
+    fn parses_dictionary_definition_with_optional_part_of_speech() {
+        let definition = parse_dictionary_definition(
+            InlineText::from_slice("ephemeral"),
+            r#"{"word":"ephemeral","part_of_speech":null,"definition":"Lasting for a very short time.\nShort-lived."}"#,
+        )
+        .unwrap();
+
+        assert_eq!(definition.word.as_str(), "ephemeral");
+        assert!(definition.part_of_speech.is_empty());
+        assert_eq!(
+            definition.definition.as_str(),
+            "Lasting for a very short time.\nShort-lived."
+        );
+    }
+
     #[test]
     fn parses_saved_content_page_cursor() {
         let page = parse_saved_content_fetch_page(
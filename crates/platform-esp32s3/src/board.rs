@@ -1,8 +1,12 @@
+use esp_hal::gpio::AnyPin;
+
 pub const DISPLAY_CLK_GPIO: u8 = 13;
 pub const DISPLAY_DI_GPIO: u8 = 14;
 pub const DISPLAY_CS_GPIO: u8 = 15;
 pub const DISPLAY_DISP_GPIO: u8 = 2;
 pub const DISPLAY_EMD_GPIO: u8 = 9;
+// LEDC cannot go much below 4.8 Hz from the 80 MHz APB clock at 14-bit resolution.
+pub const DISPLAY_EXTCOMIN_HZ: u32 = 5;
 
 pub const SD_CS_GPIO: u8 = 8;
 pub const SD_SCK_GPIO: u8 = 4;
@@ -14,13 +18,51 @@ pub const ENCODER_DT_GPIO: u8 = 11;
 pub const ENCODER_SW_GPIO: u8 = 12;
 pub const SLEEP_WAKE_GPIO: u8 = ENCODER_SW_GPIO;
 
+pub const BATTERY_SENSE_GPIO: u8 = 16;
+pub const BATTERY_CHARGE_GPIO: u8 = 7;
+// I2C0, shared by the MAX17048 fuel gauge, an RTC and a light sensor.
+pub const I2C_SDA_GPIO: u8 = 5;
+pub const I2C_SCL_GPIO: u8 = 6;
+// Piezo buzzer, driven from LEDC through a transistor.
+pub const BUZZER_GPIO: u8 = 17;
+
+// Seeed XIAO ESP32S3 Sense: the expansion board's microSD slot, the display on D0 and D1 (both
+// RTC pads, so they hold through deep sleep) and D3 to D5, and the encoder on D2, D6 and D7.
+pub const XIAO_S3_DISPLAY_CLK_GPIO: u8 = 4;
+pub const XIAO_S3_DISPLAY_DI_GPIO: u8 = 5;
+pub const XIAO_S3_DISPLAY_CS_GPIO: u8 = 6;
+pub const XIAO_S3_DISPLAY_DISP_GPIO: u8 = 1;
+pub const XIAO_S3_DISPLAY_EMD_GPIO: u8 = 2;
+pub const XIAO_S3_SD_CS_GPIO: u8 = 21;
+pub const XIAO_S3_SD_SCK_GPIO: u8 = 7;
+pub const XIAO_S3_SD_MOSI_GPIO: u8 = 9;
+pub const XIAO_S3_SD_MISO_GPIO: u8 = 8;
+pub const XIAO_S3_ENCODER_CLK_GPIO: u8 = 43;
+pub const XIAO_S3_ENCODER_DT_GPIO: u8 = 44;
+pub const XIAO_S3_ENCODER_SW_GPIO: u8 = 3;
+
+// The XIAO has no header pins left for I2C, a divider or a buzzer.
+#[cfg(all(
+    feature = "board-xiao-s3",
+    any(
+        feature = "battery-adc",
+        feature = "battery-max17048",
+        feature = "rtc-i2c",
+        feature = "light-veml7700",
+        feature = "buzzer-ledc"
+    )
+))]
+compile_error!("board-xiao-s3 has no pins for battery sensing, I2C or a buzzer");
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct BoardConfig {
+    pub name: &'static str,
     pub display_clk_gpio: u8,
     pub display_di_gpio: u8,
     pub display_cs_gpio: u8,
     pub display_disp_gpio: u8,
     pub display_emd_gpio: u8,
+    pub display_extcomin_hz: u32,
     pub sd_cs_gpio: u8,
     pub sd_sck_gpio: u8,
     pub sd_mosi_gpio: u8,
@@ -29,16 +71,32 @@ pub struct BoardConfig {
     pub encoder_dt_gpio: u8,
     pub encoder_sw_gpio: u8,
     pub sleep_wake_gpio: u8,
+    pub battery_sense_gpio: u8,
+    pub battery_charge_gpio: u8,
+    pub i2c_sda_gpio: u8,
+    pub i2c_scl_gpio: u8,
+    pub buzzer_gpio: u8,
 }
 
 impl BoardConfig {
+    // The preset the firmware was built for; `board-xiao-s3` swaps in the XIAO wiring.
     pub const fn new() -> Self {
+        if cfg!(feature = "board-xiao-s3") {
+            Self::xiao_s3_sense()
+        } else {
+            Self::motif()
+        }
+    }
+
+    pub const fn motif() -> Self {
         Self {
+            name: "motif",
             display_clk_gpio: DISPLAY_CLK_GPIO,
             display_di_gpio: DISPLAY_DI_GPIO,
             display_cs_gpio: DISPLAY_CS_GPIO,
             display_disp_gpio: DISPLAY_DISP_GPIO,
             display_emd_gpio: DISPLAY_EMD_GPIO,
+            display_extcomin_hz: DISPLAY_EXTCOMIN_HZ,
             sd_cs_gpio: SD_CS_GPIO,
             sd_sck_gpio: SD_SCK_GPIO,
             sd_mosi_gpio: SD_MOSI_GPIO,
@@ -47,8 +105,77 @@ impl BoardConfig {
             encoder_dt_gpio: ENCODER_DT_GPIO,
             encoder_sw_gpio: ENCODER_SW_GPIO,
             sleep_wake_gpio: SLEEP_WAKE_GPIO,
+            battery_sense_gpio: BATTERY_SENSE_GPIO,
+            battery_charge_gpio: BATTERY_CHARGE_GPIO,
+            i2c_sda_gpio: I2C_SDA_GPIO,
+            i2c_scl_gpio: I2C_SCL_GPIO,
+            buzzer_gpio: BUZZER_GPIO,
+        }
+    }
+
+    pub const fn xiao_s3_sense() -> Self {
+        Self {
+            name: "xiao-s3-sense",
+            ..Self::motif()
         }
+        .with_display_pins(
+            XIAO_S3_DISPLAY_CLK_GPIO,
+            XIAO_S3_DISPLAY_DI_GPIO,
+            XIAO_S3_DISPLAY_CS_GPIO,
+            XIAO_S3_DISPLAY_DISP_GPIO,
+            XIAO_S3_DISPLAY_EMD_GPIO,
+        )
+        .with_sd_pins(
+            XIAO_S3_SD_CS_GPIO,
+            XIAO_S3_SD_SCK_GPIO,
+            XIAO_S3_SD_MOSI_GPIO,
+            XIAO_S3_SD_MISO_GPIO,
+        )
+        .with_encoder_pins(
+            XIAO_S3_ENCODER_CLK_GPIO,
+            XIAO_S3_ENCODER_DT_GPIO,
+            XIAO_S3_ENCODER_SW_GPIO,
+        )
     }
+
+    // DISP and EMD must be RTC pads (GPIO0 to GPIO21) so they can be held low in deep sleep.
+    pub const fn with_display_pins(mut self, clk: u8, di: u8, cs: u8, disp: u8, emd: u8) -> Self {
+        self.display_clk_gpio = clk;
+        self.display_di_gpio = di;
+        self.display_cs_gpio = cs;
+        self.display_disp_gpio = disp;
+        self.display_emd_gpio = emd;
+        self
+    }
+
+    pub const fn with_sd_pins(mut self, cs: u8, sck: u8, mosi: u8, miso: u8) -> Self {
+        self.sd_cs_gpio = cs;
+        self.sd_sck_gpio = sck;
+        self.sd_mosi_gpio = mosi;
+        self.sd_miso_gpio = miso;
+        self
+    }
+
+    // The encoder switch doubles as the deep-sleep wake source, so it must be an RTC pad too.
+    pub const fn with_encoder_pins(mut self, clk: u8, dt: u8, sw: u8) -> Self {
+        self.encoder_clk_gpio = clk;
+        self.encoder_dt_gpio = dt;
+        self.encoder_sw_gpio = sw;
+        self.sleep_wake_gpio = sw;
+        self
+    }
+
+    pub const fn with_i2c_pins(mut self, sda: u8, scl: u8) -> Self {
+        self.i2c_sda_gpio = sda;
+        self.i2c_scl_gpio = scl;
+        self
+    }
+}
+
+// Pins are taken by number so a preset can rewire them. Each is claimed once during bring-up (or
+// again by the panic screen and the sleep path, which steal the display pins on purpose).
+pub(crate) fn pin(gpio: u8) -> AnyPin<'static> {
+    unsafe { AnyPin::steal(gpio) }
 }
 
 impl Default for BoardConfig {
@@ -0,0 +1,157 @@
+extern crate alloc;
+
+use alloc::boxed::Box;
+
+use domain::{
+    content::{
+        CONTENT_ID_MAX_BYTES, CollectionManifestItem, CollectionManifestState, DetailLocator,
+        PackageState, RemoteContentStatus,
+    },
+    reader::ReaderWindow,
+    text::InlineText,
+};
+use embassy_sync::{
+    blocking_mutex::raw::CriticalSectionRawMutex,
+    mutex::{Mutex, MutexGuard},
+};
+use esp_storage::FlashStorage;
+use log::info;
+use services::{
+    book_flash::{BookPartition, FlashBook},
+    storage::StorageError,
+};
+
+use crate::content_storage::{
+    OpenedReaderPackage, read_reader_package_paragraphs, read_reader_package_window,
+};
+
+// Books kept in internal flash, read when the SD card is missing or no longer holds a package.
+// The SD task copies packages in through `lock`; everything else only reads.
+pub type FlashBooks = BookPartition<FlashStorage<'static>>;
+
+static BOOKS: Mutex<CriticalSectionRawMutex, Option<FlashBooks>> = Mutex::new(None);
+
+pub fn install(books: FlashBooks) {
+    let Ok(mut slot) = BOOKS.try_lock() else {
+        return;
+    };
+    let slot_capacity = books.capacity_bytes();
+    *slot = Some(books);
+    info!(
+        "flash books installed slot_capacity_bytes={}",
+        slot_capacity
+    );
+}
+
+pub(crate) async fn lock() -> MutexGuard<'static, CriticalSectionRawMutex, Option<FlashBooks>> {
+    BOOKS.lock().await
+}
+
+// The store folds these into Saved next to whatever the card and backend list, so the books stay
+// reachable with no card inserted.
+pub(crate) fn bootstrap_device_books() -> Option<Box<CollectionManifestState>> {
+    let mut books = BOOKS.try_lock().ok()?;
+    let device_books = device_book_manifest(books.as_mut()?);
+    info!("flash books bootstrap count={}", device_books.len());
+    Some(device_books)
+}
+
+pub async fn device_books() -> Option<Box<CollectionManifestState>> {
+    let mut books = BOOKS.lock().await;
+    Some(device_book_manifest(books.as_mut()?))
+}
+
+pub async fn open_reader_package(
+    content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+) -> Result<Box<OpenedReaderPackage>, StorageError> {
+    let mut books = BOOKS.lock().await;
+    let books = books.as_mut().ok_or(StorageError::Unavailable)?;
+    let book = books
+        .find(content_id.as_str())
+        .ok_or(StorageError::Unavailable)?;
+    books.verify(&book)?;
+
+    let mut file = books.open(&book);
+    let header = reader_package::read_header(&mut file)?;
+    let title = reader_package::read_title(&mut file, header)?;
+    let paragraphs = read_reader_package_paragraphs(&mut file, header)?;
+    let window = read_reader_package_window(&mut file, header, 0)?;
+    info!(
+        "flash books package open content_id={} slot={} size_bytes={} total_units={} paragraphs={}",
+        content_id.as_str(),
+        book.slot,
+        book.size_bytes,
+        header.unit_count,
+        header.paragraph_count,
+    );
+    Ok(Box::new(OpenedReaderPackage {
+        title,
+        total_units: header.unit_count,
+        paragraphs,
+        window,
+    }))
+}
+
+pub async fn load_reader_window(
+    content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+    window_start_unit_index: u32,
+) -> Result<Box<ReaderWindow>, StorageError> {
+    let mut books = BOOKS.lock().await;
+    let books = books.as_mut().ok_or(StorageError::Unavailable)?;
+    let book = books
+        .find(content_id.as_str())
+        .ok_or(StorageError::Unavailable)?;
+
+    let mut file = books.open(&book);
+    let header = reader_package::read_header(&mut file)?;
+    read_reader_package_window(&mut file, header, window_start_unit_index)
+}
+
+// The SD task removes flash copies through `lock` while a card is mounted; this is the path with no
+// card.
+pub async fn remove_book(content_id: InlineText<CONTENT_ID_MAX_BYTES>) -> Result<(), StorageError> {
+    let mut books = BOOKS.lock().await;
+    let books = books.as_mut().ok_or(StorageError::PartitionMissing)?;
+    let book = books
+        .find(content_id.as_str())
+        .ok_or(StorageError::Unavailable)?;
+    books.remove(book.slot)?;
+    info!(
+        "flash books removed content_id={} slot={}",
+        content_id.as_str(),
+        book.slot
+    );
+    Ok(())
+}
+
+// Factory reset wipes the partition whether or not a card is mounted.
+pub async fn clear_books() -> Result<(), StorageError> {
+    let mut books = BOOKS.lock().await;
+    let books = books.as_mut().ok_or(StorageError::PartitionMissing)?;
+    books.clear()?;
+    info!("flash books cleared");
+    Ok(())
+}
+
+fn device_book_manifest(books: &mut FlashBooks) -> Box<CollectionManifestState> {
+    let mut manifest = Box::new(CollectionManifestState::empty());
+    for book in books.books().into_iter().flatten() {
+        if !manifest.try_push(flash_book_manifest_item(&book)) {
+            break;
+        }
+    }
+    manifest
+}
+
+fn flash_book_manifest_item(book: &FlashBook) -> CollectionManifestItem {
+    let mut item = CollectionManifestItem::empty();
+    item.remote_item_id.set_truncated(book.content_id.as_str());
+    item.content_id = book.content_id;
+    item.detail_locator = DetailLocator::Content;
+    item.meta.set_truncated("ON DEVICE");
+    item.title = book.title;
+    item.remote_revision = book.remote_revision;
+    item.remote_status = RemoteContentStatus::Ready;
+    item.package_state = PackageState::Cached;
+    item
+}
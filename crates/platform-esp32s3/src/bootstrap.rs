@@ -11,6 +11,9 @@ extern crate alloc;
 use ::domain::{
     content::PackageState,
     device::{BootState, DeviceState},
+    diagnostics::DIAGNOSTICS_SAMPLE_INTERVAL_MS,
+    perf::PERF_LOG_INTERVAL_MS,
+    power::CpuDemand,
     runtime::{BootstrapSnapshot, Effect, Event},
     sleep::{SleepModel, SleepState},
     storage::StorageRecoveryStatus,
@@ -18,7 +21,13 @@ use ::domain::{
     sync::SyncStatus,
 };
 use ::services::storage::StorageError;
-use ::services::{input::InputService, sleep::SleepService};
+use ::services::{
+    clock::{Clock, ClockError},
+    input::InputService,
+    light::{AmbientLightSensor, LightSensorError},
+    power::{PowerMonitor, PowerMonitorError},
+    sleep::SleepService,
+};
 use alloc::boxed::Box;
 use app_runtime::{AppRuntime, PreparedScreen, Screen, ScreenUpdate, TransitionPlan};
 use core::mem::size_of;
@@ -30,10 +39,16 @@ use embassy_sync::{
 };
 use embassy_time::{Duration, Instant, Ticker, Timer};
 use embedded_hal::delay::DelayNs;
+#[cfg(any(
+    feature = "battery-max17048",
+    feature = "rtc-i2c",
+    feature = "light-veml7700"
+))]
+use esp_hal::i2c::master::I2c;
 use esp_hal::{
     clock::CpuClock,
     delay::Delay,
-    gpio::{AnyPin, Level, Output, OutputConfig, Pin, RtcPin},
+    gpio::{Level, Output, OutputConfig, RtcPin},
     rtc_cntl::{Rtc, SocResetReason, reset_reason, wakeup_cause},
     spi::master::Spi,
     system::Cpu,
@@ -41,27 +56,32 @@ use esp_hal::{
     timer::timg::TimerGroup,
 };
 use log::{info, warn};
-use ls027b7dh01::FrameBuffer;
+use ls027b7dh01::{FrameBuffer, diff_dirty_rows};
+use renderer::AnimationPlayback;
 
 use crate::{
     backend,
-    board::BoardConfig,
-    content_storage,
-    display::{HEARTBEAT_INTERVAL_MS, PlatformDisplay, diff_dirty_rows},
+    board::{self, BoardConfig},
+    book_storage, content_storage, crash_log,
+    display::{HEARTBEAT_INTERVAL_MS, PlatformDisplay},
     input::PlatformInputService,
-    internet,
-    renderer::{self, AnimationPlayback},
+    internet, power,
     sleep::enter_deep_sleep_with_button,
     storage::PlatformStorageService,
-    telemetry::{bool_flag, capture_heap},
+    telemetry::{
+        bool_flag, capture_diagnostics, capture_heap, log_perf, paint_stack, record_perf,
+        set_sd_clock_hz,
+    },
+    watchdog::{self, WatchedTask},
 };
 
-const DISPLAY_SPI_HZ: u32 = 2_000_000;
+pub(crate) const DISPLAY_SPI_HZ: u32 = 2_000_000;
 const SD_SPI_INIT_HZ: u32 = 400_000;
 const SD_SPI_PRODUCT_RUN_HZ: u32 = 8_000_000;
 const SD_SPI_RUN_HZ_OVERRIDE_ENV: &str = "MOTIF_SD_SPI_RUN_HZ";
 const INPUT_POLL_MS: u64 = 2;
 const READER_TICK_MS: u64 = 20;
+const SLEEP_HANDOFF_TIMEOUT_MS: u64 = 2_000;
 const RECLAIMED_INTERNAL_HEAP_BYTES: usize = 64 * 1024;
 const PRIMARY_INTERNAL_HEAP_BYTES: usize = 96 * 1024;
 // TimedEvent can carry whole manifest snapshots, so this queue must stay small.
@@ -77,10 +97,22 @@ static PLATFORM_CMD_CH: Channel<
     PLATFORM_COMMAND_QUEUE_CAPACITY,
 > = Channel::new();
 static SCREEN_SIGNAL: Signal<CriticalSectionRawMutex, ScreenUpdate> = Signal::new();
+// Windows the reader asks for while playing load here, off the app task, so it keeps showing
+// words from the window it holds. A newer request replaces one that has not started, and the reply
+// to one it overtook mid-read is dropped.
+static READER_WINDOW_REQUEST: Signal<
+    CriticalSectionRawMutex,
+    domain::reader::ReaderWindowLoadRequest,
+> = Signal::new();
 static PENDING_UI_TICK: AtomicBool = AtomicBool::new(false);
 static PENDING_READER_TICK: AtomicBool = AtomicBool::new(false);
 static DROPPED_UI_TICKS: AtomicU32 = AtomicU32::new(0);
 static DROPPED_READER_TICKS: AtomicU32 = AtomicU32::new(0);
+// The reader's next word deadline, published by the app task for the light-sleep idle check.
+static READER_DUE_AT_MS: embassy_sync::blocking_mutex::Mutex<
+    CriticalSectionRawMutex,
+    core::cell::Cell<Option<u64>>,
+> = embassy_sync::blocking_mutex::Mutex::new(core::cell::Cell::new(None));
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 struct SdSpiClockConfig {
@@ -100,6 +132,10 @@ enum PlatformCommand {
     RequestDeepSleep,
     PersistBackendCredential(Box<crate::storage::BackendCredential>),
     PersistSettings(domain::settings::PersistedSettings),
+    SwitchProfile(u8),
+    FactoryReset,
+    CliScreenshot,
+    SaveScreenshot,
 }
 
 #[embassy_executor::task]
@@ -125,9 +161,23 @@ async fn app_task(snapshot: BootstrapSnapshot) {
             Event::InputGestureReceived(gesture) => Some(*gesture),
             _ => None,
         };
-        let mut effect = store
-            .handle_event(timed_event.event, timed_event.at_ms)
-            .unwrap_or(Effect::Noop);
+        let unit_index_before = store.reader.progress.unit_index;
+        let is_reader_tick = matches!(timed_event.event, Event::ReaderTick(_));
+        let mut effect = match timed_event.event {
+            #[cfg(feature = "usb-cli")]
+            Event::CliCommand(command) => crate::cli::run_command(&mut store, command).await,
+            event => store
+                .handle_event(event, timed_event.at_ms)
+                .unwrap_or(Effect::Noop),
+        };
+        if is_reader_tick {
+            let words = store
+                .reader
+                .progress
+                .unit_index
+                .saturating_sub(unit_index_before);
+            record_perf(|perf| perf.record_words(words, timed_event.at_ms));
+        }
 
         if let Some(gesture) = input_gesture {
             let command = app.handle_input_gesture(gesture);
@@ -139,9 +189,17 @@ async fn app_task(snapshot: BootstrapSnapshot) {
 
         apply_effect(&mut store, effect, timed_event.at_ms).await;
         flush_pending_reading_progress(&mut store).await;
+        flush_pending_bookmarks(&mut store).await;
+        flush_pending_history(&mut store).await;
+        flush_pending_book_stats(&mut store).await;
+        flush_pending_quote(&mut store).await;
+        flush_pending_audio_cue(&mut store);
+        READER_DUE_AT_MS.lock(|due| due.set(store.reader.next_due_at_ms));
 
         let next_update = app.tick(&store);
-        if next_update.screen != last_update.screen || next_update.prepared != last_update.prepared
+        if next_update.screen != last_update.screen
+            || next_update.prepared != last_update.prepared
+            || next_update.toasts != last_update.toasts
         {
             SCREEN_SIGNAL.signal(next_update);
             *last_update = next_update;
@@ -219,9 +277,20 @@ fn prioritize_non_tick_event(
     latest_tick
 }
 
+// Effects are the app's commands to the platform. Slow work goes to the task that owns it
+// (backend, SD storage, reader windows), whose answer comes back as an `Event` on APP_EVENT_CH;
+// replies handled here still reach the store only through `handle_event`.
 async fn apply_effect(store: &mut Store, effect: Effect, at_ms: u64) {
     match effect {
         Effect::EnterDeepSleep => {
+            flush_pending_reading_progress(store).await;
+            flush_pending_bookmarks(store).await;
+            flush_pending_history(store).await;
+            flush_pending_book_stats(store).await;
+            if let Err(err) = content_storage::flush_writes().await {
+                info!("sleep storage flush failed: {:?}", err);
+            }
+            crate::sleep::store_sleep_resume(store.sleep_resume());
             PLATFORM_CMD_CH
                 .send(PlatformCommand::RequestDeepSleep)
                 .await;
@@ -254,7 +323,7 @@ async fn apply_effect(store: &mut Store, effect: Effect, at_ms: u64) {
                         opened.window,
                     );
                     if let Some(request) = resume_request {
-                        load_reader_window_for_request(store, request).await;
+                        READER_WINDOW_REQUEST.signal(request);
                     }
                     info!(
                         "content storage opened cached package collection={:?} content_id={} total_units={} paragraph_count={} window_units={}",
@@ -308,10 +377,13 @@ async fn apply_effect(store: &mut Store, effect: Effect, at_ms: u64) {
                         && store.storage.sd_card_ready
                         && matches!(store.backend_sync.status, SyncStatus::Ready)
                     {
-                        let _ = store.content_mut().update_package_state(
-                            request.collection,
-                            &request.remote_item_id,
-                            PackageState::Fetching,
+                        let _ = store.handle_event(
+                            Event::ContentPackageStateChanged {
+                                collection: request.collection,
+                                remote_item_id: request.remote_item_id,
+                                package_state: PackageState::Fetching,
+                            },
+                            at_ms,
                         );
                         info!(
                             "content storage cached content corrupt, refetching collection={:?} content_id={}",
@@ -324,7 +396,7 @@ async fn apply_effect(store: &mut Store, effect: Effect, at_ms: u64) {
             }
         }
         Effect::LoadReaderWindow(request) => {
-            load_reader_window_for_request(store, request).await;
+            READER_WINDOW_REQUEST.signal(request);
         }
         Effect::PrepareContent(request) => {
             info!(
@@ -344,6 +416,9 @@ async fn apply_effect(store: &mut Store, effect: Effect, at_ms: u64) {
         Effect::ToggleReaderSubscription(request) => {
             backend::request_reader_subscription_toggle(request).await;
         }
+        Effect::LookupDefinition(request) => {
+            backend::request_dictionary_lookup(request).await;
+        }
         Effect::LoadRecommendationSubtopics => {
             info!("recommendations load subtopics");
             backend::request_recommendation_subtopics().await;
@@ -358,49 +433,138 @@ async fn apply_effect(store: &mut Store, effect: Effect, at_ms: u64) {
         Effect::RefreshCollection(collection) => {
             backend::request_collection_refresh(collection).await;
         }
+        Effect::KeepOnDevice(request) => {
+            match content_storage::keep_on_device(request.content_id).await {
+                Ok(()) => {
+                    info!(
+                        "content kept on device content_id={}",
+                        request.content_id.as_str()
+                    );
+                    if let Some(device_books) = book_storage::device_books().await {
+                        let _ = store.handle_event(Event::DeviceBooksUpdated(device_books), at_ms);
+                    }
+                }
+                Err(err) => info!(
+                    "content keep on device failed content_id={} err={:?}",
+                    request.content_id.as_str(),
+                    err
+                ),
+            }
+        }
+        Effect::DeleteBook(request) => {
+            match content_storage::delete_book(request.content_id).await {
+                Ok(()) => {
+                    info!("content deleted content_id={}", request.content_id.as_str());
+                    if let Some(device_books) = book_storage::device_books().await {
+                        let _ = store.handle_event(Event::DeviceBooksUpdated(device_books), at_ms);
+                    }
+                }
+                Err(err) => info!(
+                    "content delete failed content_id={} err={:?}",
+                    request.content_id.as_str(),
+                    err
+                ),
+            }
+        }
         Effect::PersistSettings(settings) => {
             PLATFORM_CMD_CH
                 .send(PlatformCommand::PersistSettings(settings))
                 .await;
         }
+        Effect::SwitchProfile(profile) => {
+            info!("profile switch requested profile={}", profile);
+            flush_pending_reading_progress(store).await;
+            flush_pending_bookmarks(store).await;
+            flush_pending_history(store).await;
+            flush_pending_book_stats(store).await;
+            PLATFORM_CMD_CH
+                .send(PlatformCommand::SwitchProfile(profile))
+                .await;
+        }
+        Effect::FactoryReset => {
+            info!("factory reset requested");
+            if let Err(err) = content_storage::clear_device_data().await {
+                info!("factory reset content clear failed: {:?}", err);
+            }
+            if let Err(err) = book_storage::clear_books().await {
+                info!("factory reset flash books clear failed: {:?}", err);
+            }
+            PLATFORM_CMD_CH.send(PlatformCommand::FactoryReset).await;
+        }
+        // The display loop owns the committed frame, so it does the writing.
+        Effect::SaveScreenshot => {
+            PLATFORM_CMD_CH.send(PlatformCommand::SaveScreenshot).await;
+        }
         Effect::Noop => {}
     }
 }
 
-async fn load_reader_window_for_request(
-    store: &mut Store,
-    request: domain::reader::ReaderWindowLoadRequest,
-) {
-    match content_storage::load_reader_window(request.content_id, request.window_start_unit_index)
+#[embassy_executor::task]
+async fn reader_window_task() {
+    loop {
+        let request = READER_WINDOW_REQUEST.wait().await;
+        let started = Instant::now();
+        let event = match content_storage::load_reader_window(
+            request.content_id,
+            request.window_start_unit_index,
+        )
         .await
-    {
-        Ok(window) => {
-            info!(
-                "content storage loaded reader window content_id={} start_unit={} unit_count={}",
-                request.content_id.as_str(),
-                window.start_unit_index,
-                window.unit_count,
-            );
-            store.load_reader_window(window);
-        }
-        Err(err) => {
+        {
+            Ok(window) => {
+                record_refill_time(started);
+                info!(
+                    "content storage loaded reader window content_id={} start_unit={} unit_count={}",
+                    request.content_id.as_str(),
+                    window.start_unit_index,
+                    window.unit_count,
+                );
+                Event::ReaderWindowLoaded {
+                    content_id: request.content_id,
+                    request_id: request.request_id,
+                    window,
+                }
+            }
+            Err(err) => {
+                info!(
+                    "content storage reader window load failed content_id={} start_unit={} err={:?}",
+                    request.content_id.as_str(),
+                    request.window_start_unit_index,
+                    err,
+                );
+                Event::ReaderWindowLoadFailed(request)
+            }
+        };
+        // A newer target came in during the read, so the store would drop this reply anyway; go
+        // straight on to the newer one.
+        if READER_WINDOW_REQUEST.signaled() {
             info!(
-                "content storage reader window load failed content_id={} start_unit={} err={:?}",
-                request.content_id.as_str(),
-                request.window_start_unit_index,
-                err,
+                "content storage dropped stale reader window request_id={}",
+                request.request_id,
             );
-            store.reader.clear_pending_window_request();
+            continue;
         }
+        // Waits for room instead of dropping: a lost reply would leave the window pending.
+        APP_EVENT_CH
+            .send(TimedEvent {
+                event,
+                at_ms: Instant::now().as_millis(),
+            })
+            .await;
     }
 }
 
+fn record_refill_time(started: Instant) {
+    let elapsed_us = started.elapsed().as_micros() as u32;
+    record_perf(|perf| perf.refill.record(elapsed_us));
+}
+
 pub async fn run_minimal(spawner: Spawner) -> ! {
     let board = BoardConfig::new();
     let config = esp_hal::Config::default()
         .with_cpu_clock(CpuClock::max())
         .with_psram(esp_hal::psram::PsramConfig::default());
     let peripherals = esp_hal::init(config);
+    paint_stack();
 
     let boot_reset_reason = reset_reason(Cpu::ProCpu);
     let boot_wakeup_cause = wakeup_cause();
@@ -445,6 +609,7 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
     log_static_inventory();
     crate::memory_policy::log_policy_inventory();
     let sd_spi_clock = resolve_sd_spi_clock_config();
+    set_sd_clock_hz(sd_spi_clock.run_hz);
     backend::log_static_inventory();
     content_storage::log_static_inventory(
         sd_spi_clock.init_hz,
@@ -468,8 +633,46 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
     esp_rtos::start(timg0.timer0);
 
     let mut rtc = Rtc::new(peripherals.LPWR);
+    // SAFETY: both flash handles are only used from tasks on this executor and every esp-storage
+    // operation blocks until it finishes, so their accesses never interleave.
+    let books_flash = unsafe { peripherals.FLASH.clone_unchecked() };
     let mut storage = PlatformStorageService::mount(peripherals.FLASH);
-    let persisted_settings = match storage.read_persisted_settings_sync() {
+    match crate::storage::mount_book_partition(books_flash) {
+        Ok(books) => book_storage::install(books),
+        Err(err) => info!("flash books unavailable: {:?}", err),
+    }
+    let active_profile = match storage.read_active_profile_sync() {
+        Ok(profile) => profile,
+        Err(err) => {
+            info!("active profile hydrate failed: {:?}", err);
+            0
+        }
+    };
+    content_storage::set_active_profile(active_profile);
+    let boot_count = match storage.read_boot_count_sync() {
+        Ok(boot_count) => boot_count,
+        Err(err) => {
+            info!("boot count hydrate failed: {:?}", err);
+            0
+        }
+    };
+    // Deep-sleep wakes are routine; only power-ups and resets count as boots.
+    let boot_count = if woke_from_deep_sleep {
+        boot_count
+    } else {
+        let next = boot_count.saturating_add(1);
+        if let Err(err) = storage.write_boot_count_sync(next) {
+            info!("boot count write failed: {:?}", err);
+        }
+        next
+    };
+    info!("boot count={}", boot_count);
+    let crash_report = crash_log::take_previous_crash();
+    let sleep_resume = crate::sleep::take_sleep_resume().filter(|_| woke_from_deep_sleep);
+    if let Some(report) = crash_report.as_ref() {
+        info!("previous crash: {}", report.as_str());
+    }
+    let persisted_settings = match storage.read_persisted_settings_sync(active_profile) {
         Ok(settings) => settings,
         Err(err) => {
             info!("settings hydrate failed: {:?}", err);
@@ -483,17 +686,98 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
             None
         }
     };
+
+    let disp_pin = board::pin(board.display_disp_gpio);
+    disp_pin.rtcio_pad_hold(false);
+    let emd_pin = board::pin(board.display_emd_gpio);
+    emd_pin.rtcio_pad_hold(false);
+    let disp = Output::new(disp_pin, Level::Low, OutputConfig::default());
+    #[cfg(not(feature = "extcomin-ledc"))]
+    let emd = Output::new(emd_pin, Level::Low, OutputConfig::default());
+    // LEDC owns EXTCOMIN here, so the display driver gets a placeholder for its EMD line.
+    #[cfg(feature = "extcomin-ledc")]
+    let emd = esp_hal::gpio::NoPin;
+    // EXTCOMIN and the buzzer share the controller, which lives until the next deep sleep.
+    #[cfg(any(feature = "extcomin-ledc", feature = "buzzer-ledc"))]
+    let ledc: &'static esp_hal::ledc::Ledc<'static> = {
+        let mut ledc = esp_hal::ledc::Ledc::new(peripherals.LEDC);
+        ledc.set_global_slow_clock(esp_hal::ledc::LSGlobalClkSource::APBClk);
+        Box::leak(Box::new(ledc))
+    };
+    #[cfg(feature = "extcomin-ledc")]
+    let _extcomin = crate::extcomin::LedcExtcomin::start(ledc, emd_pin, board.display_extcomin_hz)
+        .inspect_err(|err| info!("extcomin ledc start failed: {:?}", err));
+    let cs = Output::new(
+        board::pin(board.display_cs_gpio),
+        Level::Low,
+        OutputConfig::default(),
+    );
+
+    let spi_config = esp_hal::spi::master::Config::default()
+        .with_frequency(Rate::from_hz(DISPLAY_SPI_HZ))
+        .with_mode(esp_hal::spi::Mode::_1);
+    let spi = Spi::new(peripherals.SPI2, spi_config)
+        .unwrap()
+        .with_sck(board::pin(board.display_clk_gpio))
+        .with_mosi(board::pin(board.display_di_gpio));
+
+    let mut delay = Delay::new();
+    let mut display = PlatformDisplay::new(spi, disp, emd, cs);
+
+    if let Err(err) = display.initialize(&mut delay) {
+        info!("display initialize failed: {:?}", err);
+    }
+    if let Err(err) = display.clear_all(&mut delay) {
+        info!("display clear failed: {:?}", err);
+    }
+
+    // Cold boots show the card mount as it runs; a deep-sleep wake skips it to come back quickly.
+    let mut committed_frame = FrameBuffer::new();
+    let mut working_frame = FrameBuffer::new();
+    let boot_appearance = persisted_settings
+        .map(|settings| settings.appearance)
+        .unwrap_or(domain::settings::AppearanceMode::Light);
+    let mut report_boot_scan = |progress: content_storage::BootScanProgress| {
+        if woke_from_deep_sleep {
+            return;
+        }
+        renderer::draw_boot_scan(
+            &mut working_frame,
+            boot_appearance,
+            progress.completed,
+            progress.total,
+            progress.label,
+        );
+        let dirty_rows = diff_dirty_rows(&committed_frame, &working_frame);
+        present_frame(
+            &mut display,
+            &mut committed_frame,
+            &working_frame,
+            &dirty_rows,
+            &mut delay,
+        );
+    };
+
     let sd_spi_config = esp_hal::spi::master::Config::default()
         .with_frequency(Rate::from_hz(sd_spi_clock.init_hz))
         .with_mode(esp_hal::spi::Mode::_0);
     let sd_spi = Spi::new(peripherals.SPI3, sd_spi_config)
         .unwrap()
-        .with_sck(peripherals.GPIO4)
-        .with_mosi(peripherals.GPIO40)
-        .with_miso(peripherals.GPIO41);
-    let sd_cs = Output::new(peripherals.GPIO8, Level::High, OutputConfig::default());
-    let mut content_mount =
-        content_storage::mount(sd_spi, sd_cs, sd_spi_clock.run_hz, sd_spi_clock.source);
+        .with_sck(board::pin(board.sd_sck_gpio))
+        .with_mosi(board::pin(board.sd_mosi_gpio))
+        .with_miso(board::pin(board.sd_miso_gpio));
+    let sd_cs = Output::new(
+        board::pin(board.sd_cs_gpio),
+        Level::High,
+        OutputConfig::default(),
+    );
+    let content_mount = content_storage::mount(
+        sd_spi,
+        sd_cs,
+        sd_spi_clock.run_hz,
+        sd_spi_clock.source,
+        &mut report_boot_scan,
+    );
     let mut storage_health = storage.health_snapshot().with_sd_card(
         content_mount.sd_card_ready,
         content_mount.sd_total_bytes,
@@ -528,29 +812,34 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
     } else {
         BootState::ColdBoot
     };
-    let bootstrap_content =
-        content_storage::bootstrap_content_state(content_mount.storage.as_deref_mut());
-    let bootstrap_reading_progress =
-        content_storage::bootstrap_reading_progress_state(content_mount.storage.as_deref_mut());
-    let bootstrap_recommendation_subtopics =
-        content_storage::bootstrap_recommendation_subtopics_state(
-            content_mount.storage.as_deref_mut(),
-        );
     let snapshot = BootstrapSnapshot::new(
         DeviceState {
             pairing: backend::initial_pairing_state(backend_credential),
             boot: boot_state,
+            boot_count,
         },
         boot_ms,
-        bootstrap_content,
-        bootstrap_reading_progress,
-        bootstrap_recommendation_subtopics,
+        None,
+        None,
+        None,
         persisted_settings,
         storage_health,
         internet::initial_network_state(),
-    );
+    )
+    .with_device_books(book_storage::bootstrap_device_books())
+    .with_profile(active_profile)
+    .with_crash_report(crash_report)
+    .with_sleep_resume(sleep_resume);
 
     spawner.spawn(app_task(snapshot)).unwrap();
+    spawner.spawn(reader_window_task()).unwrap();
+    #[cfg(feature = "buzzer-ledc")]
+    spawner
+        .spawn(crate::buzzer::audio_task(crate::buzzer::LedcBuzzer::new(
+            ledc,
+            board::pin(board.buzzer_gpio),
+        )))
+        .unwrap();
     content_storage::install(spawner, content_mount.storage);
     let network_stack = internet::install(spawner, peripherals.WIFI);
     backend::install(
@@ -560,17 +849,65 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
         peripherals.RNG,
         peripherals.ADC1,
     );
+    watchdog::install(spawner, peripherals.TIMG1);
+    #[cfg(feature = "usb-cli")]
+    crate::cli::install(spawner, peripherals.USB_DEVICE);
 
     let mut input = PlatformInputService::new(
-        peripherals.GPIO10.degrade(),
-        peripherals.GPIO11.degrade(),
-        peripherals.GPIO12.degrade(),
+        board::pin(board.encoder_clk_gpio),
+        board::pin(board.encoder_dt_gpio),
+        board::pin(board.encoder_sw_gpio),
         woke_from_deep_sleep,
     );
+    #[cfg(any(
+        feature = "battery-max17048",
+        feature = "rtc-i2c",
+        feature = "light-veml7700"
+    ))]
+    let i2c_bus = core::cell::RefCell::new(
+        I2c::new(peripherals.I2C0, esp_hal::i2c::master::Config::default())
+            .unwrap()
+            .with_sda(board::pin(board.i2c_sda_gpio))
+            .with_scl(board::pin(board.i2c_scl_gpio)),
+    );
+    #[cfg(feature = "battery-max17048")]
+    let mut power_monitor =
+        power::Max17048Monitor::new(embedded_hal_bus::i2c::RefCellDevice::new(&i2c_bus));
+    // The ADC channel is typed to its pad, so this one stays on the motif board's GPIO16 and GPIO7.
+    #[cfg(all(feature = "battery-adc", not(feature = "battery-max17048")))]
+    let mut power_monitor =
+        power::AdcBatteryMonitor::mount(peripherals.ADC2, peripherals.GPIO16, peripherals.GPIO7);
+    #[cfg(not(any(feature = "battery-adc", feature = "battery-max17048")))]
+    let mut power_monitor = ::services::power::NoopPowerMonitor;
+    #[cfg(feature = "rtc-i2c")]
+    let mut clock =
+        crate::clock::RtcClock::probe(embedded_hal_bus::i2c::RefCellDevice::new(&i2c_bus));
+    #[cfg(feature = "rtc-i2c")]
+    info!(
+        "clock rtc chip={:?}",
+        clock.as_ref().map(|clock| clock.chip())
+    );
+    #[cfg(not(feature = "rtc-i2c"))]
+    let mut clock: Option<::services::clock::NoopClock> = None;
+    #[cfg(feature = "light-veml7700")]
+    let mut light_sensor =
+        crate::light::Veml7700Sensor::probe(embedded_hal_bus::i2c::RefCellDevice::new(&i2c_bus));
+    #[cfg(feature = "light-veml7700")]
+    info!("light sensor found={}", light_sensor.is_some());
+    #[cfg(not(feature = "light-veml7700"))]
+    let mut light_sensor: Option<::services::light::NoopAmbientLightSensor> = None;
+    let mut next_light_sample_ms = boot_ms;
+    let mut next_clock_sample_ms = boot_ms;
+    let mut next_power_sample_ms = boot_ms;
+    let mut next_diagnostics_sample_ms = boot_ms;
+    let mut next_perf_log_ms = boot_ms + PERF_LOG_INTERVAL_MS;
+
     let mut sleep = crate::sleep::PlatformSleepService::new();
     sleep.hydrate_from_boot(woke_from_deep_sleep, boot_ms);
     if let Some(settings) = persisted_settings {
         sleep.configure_inactivity_timeout(settings.inactivity_timeout_ms);
+        sleep.configure_screensaver(settings.screensaver_minutes);
+        input.configure_encoder(settings.encoder_sensitivity);
     }
 
     publish_event(Event::BootCompleted, boot_ms);
@@ -578,61 +915,80 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
         publish_event(Event::WokeFromDeepSleep, boot_ms);
     }
 
-    let disp_pin = peripherals.GPIO2;
-    disp_pin.rtcio_pad_hold(false);
-    let emd_pin = peripherals.GPIO9;
-    emd_pin.rtcio_pad_hold(false);
-    let disp = Output::new(disp_pin, Level::Low, OutputConfig::default());
-    let emd = Output::new(emd_pin, Level::Low, OutputConfig::default());
-    let cs = Output::new(peripherals.GPIO15, Level::Low, OutputConfig::default());
-
-    let spi_config = esp_hal::spi::master::Config::default()
-        .with_frequency(Rate::from_hz(DISPLAY_SPI_HZ))
-        .with_mode(esp_hal::spi::Mode::_1);
-    let spi = Spi::new(peripherals.SPI2, spi_config)
-        .unwrap()
-        .with_sck(peripherals.GPIO13)
-        .with_mosi(peripherals.GPIO14);
-
-    let mut delay = Delay::new();
-    let mut display = PlatformDisplay::new(spi, disp, emd, cs);
-
-    if let Err(err) = display.initialize(&mut delay) {
-        info!("display initialize failed: {:?}", err);
-    }
-    if let Err(err) = display.clear_all(&mut delay) {
-        info!("display clear failed: {:?}", err);
-    }
-
     log_gpio_contract(&board, sd_spi_clock);
+    power::apply_cpu_policy();
 
-    let mut committed_frame = FrameBuffer::new();
-    let mut working_frame = FrameBuffer::new();
     let mut committed_update: Option<ScreenUpdate> = None;
     let mut animation: Option<AnimationPlayback> = None;
     let mut next_animation_deadline: Option<Instant> = None;
     let mut next_heartbeat_deadline = Instant::now() + Duration::from_millis(HEARTBEAT_INTERVAL_MS);
+    let mut sleep_handoff_deadline: Option<Instant> = None;
+    let mut screensaver_requested_at_activity_ms: Option<u64> = None;
 
     let mut input_tick = Ticker::every(Duration::from_millis(INPUT_POLL_MS));
     let mut ui_tick = Ticker::every(Duration::from_millis(renderer::UI_TICK_MS));
     let mut reader_tick = Ticker::every(Duration::from_millis(READER_TICK_MS));
     let event_loop = crate::memory_policy::try_external_pinned_box(async move {
         loop {
-            let suppress_sleep = current_prepared_screen(animation, committed_update)
-                .is_some_and(|screen| prepared_screen_suppresses_sleep(&screen));
-            let sleep_deadline = next_sleep_deadline(sleep.model(), suppress_sleep);
+            let current_screen = current_prepared_screen(animation, committed_update);
+            let suppress_sleep =
+                current_screen.is_some_and(|screen| prepared_screen_suppresses_sleep(&screen));
+            let screensaver_eligible =
+                current_screen.is_some_and(|screen| prepared_screen_allows_screensaver(&screen));
+            let sleep_deadline = sleep_handoff_deadline.unwrap_or_else(|| {
+                next_sleep_deadline(sleep.model(), suppress_sleep, screensaver_eligible)
+            });
+            let screensaver_deadline = next_screensaver_deadline(
+                sleep.model(),
+                current_screen.as_ref(),
+                screensaver_requested_at_activity_ms,
+                Instant::now().as_millis(),
+            );
             let display_deadline =
                 next_display_deadline(next_animation_deadline, next_heartbeat_deadline);
 
-            match select5(
+            #[cfg(feature = "reader-light-sleep")]
+            {
+                let now_ms = Instant::now().as_millis();
+                if let Some(window_ms) = reader_light_sleep_window_ms(
+                    &input,
+                    reader_ticks_are_active(animation, committed_update),
+                    now_ms,
+                    display_deadline.min(sleep_deadline),
+                ) {
+                    crate::sleep::enter_light_sleep(&mut rtc, &mut input, window_ms);
+                    // Tickers would otherwise replay every period missed while asleep as a burst.
+                    input_tick.reset();
+                    ui_tick.reset();
+                    reader_tick.reset();
+
+                    // A timer wake lands just short of the deadline; finish the wait awake and
+                    // tick the reader at once instead of up to a full reader tick late.
+                    let wake_at = Instant::from_millis(
+                        now_ms + window_ms + domain::sleep::LIGHT_SLEEP_WAKE_MARGIN_MS,
+                    );
+                    if Instant::now()
+                        + Duration::from_millis(domain::sleep::LIGHT_SLEEP_WAKE_MARGIN_MS)
+                        >= wake_at
+                    {
+                        Timer::at(wake_at).await;
+                        let tick_ms = Instant::now().as_millis();
+                        publish_event(Event::ReaderTick(tick_ms), tick_ms);
+                    }
+                }
+            }
+
+            let wake = select5(
                 input_tick.next(),
                 select(ui_tick.next(), reader_tick.next()),
-                Timer::at(sleep_deadline),
+                select(Timer::at(sleep_deadline), Timer::at(screensaver_deadline)),
                 PLATFORM_CMD_CH.receive(),
                 select(Timer::at(display_deadline), SCREEN_SIGNAL.wait()),
             )
-            .await
-            {
+            .await;
+            let _busy = watchdog::busy(WatchedTask::Ui);
+
+            match wake {
                 Either5::First(_) => {
                     let now_ms = Instant::now().as_millis();
                     input.sample(now_ms);
@@ -645,19 +1001,57 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
                     while let Some(gesture) = input.pop_gesture() {
                         info!("input gesture={:?}", gesture);
                         sleep.note_activity(now_ms);
+                        sleep_handoff_deadline = None;
                         publish_event(Event::InputGestureReceived(gesture), now_ms);
                     }
+
+                    if now_ms >= next_power_sample_ms {
+                        next_power_sample_ms = now_ms + power::POWER_SAMPLE_INTERVAL_MS;
+                        match power_monitor.read() {
+                            Ok(status) => {
+                                publish_event(Event::PowerStatusChanged(status), now_ms);
+                            }
+                            Err(PowerMonitorError::Unsupported) => {}
+                            Err(err) => info!("power sample failed: {:?}", err),
+                        }
+                    }
+
+                    if now_ms >= next_clock_sample_ms {
+                        next_clock_sample_ms = now_ms + crate::clock::CLOCK_SAMPLE_INTERVAL_MS;
+                        sample_clock(&mut clock, now_ms);
+                    }
+
+                    if light_sensor.is_some() && now_ms >= next_light_sample_ms {
+                        next_light_sample_ms = now_ms + crate::light::LIGHT_SAMPLE_INTERVAL_MS;
+                        sample_light(&mut light_sensor, now_ms);
+                    }
+
+                    if now_ms >= next_perf_log_ms {
+                        next_perf_log_ms = now_ms + PERF_LOG_INTERVAL_MS;
+                        log_perf();
+                    }
                 }
                 Either5::Second(tick_kind) => {
                     let now_ms = Instant::now().as_millis();
 
                     match tick_kind {
                         Either::First(_) => {
-                            if current_prepared_screen(animation, committed_update)
-                                .is_some_and(|screen| prepared_screen_drives_ui_ticks(&screen))
+                            let screen = current_prepared_screen(animation, committed_update);
+                            if screen.is_some_and(|screen| prepared_screen_drives_ui_ticks(&screen))
+                                || toasts_are_showing(animation, committed_update)
                             {
                                 publish_event(Event::UiTick(now_ms), now_ms);
                             }
+                            if matches!(screen, Some(PreparedScreen::Diagnostics(_)))
+                                && now_ms >= next_diagnostics_sample_ms
+                            {
+                                next_diagnostics_sample_ms =
+                                    now_ms + DIAGNOSTICS_SAMPLE_INTERVAL_MS;
+                                publish_event(
+                                    Event::DiagnosticsSampled(capture_diagnostics()),
+                                    now_ms,
+                                );
+                            }
                         }
                         Either::Second(_) => {
                             if reader_ticks_are_active(animation, committed_update) {
@@ -666,7 +1060,27 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
                         }
                     }
                 }
-                Either5::Third(_) => {
+                Either5::Third(Either::Second(_)) => {
+                    let now_ms = Instant::now().as_millis();
+                    if current_screen.is_some_and(|screen| prepared_screen_is_screensaver(&screen))
+                    {
+                        publish_event(Event::UiTick(now_ms), now_ms);
+                    } else {
+                        screensaver_requested_at_activity_ms = Some(sleep.model().last_activity_ms);
+                        publish_event(Event::ScreensaverTimeout, now_ms);
+                    }
+                }
+                Either5::Third(Either::First(_)) => {
+                    // Give the app task a chance to save progress and the resume target first;
+                    // it answers with RequestDeepSleep. Sleep anyway if it never does.
+                    if sleep_handoff_deadline.is_none() {
+                        let now = Instant::now();
+                        sleep_handoff_deadline =
+                            Some(now + Duration::from_millis(SLEEP_HANDOFF_TIMEOUT_MS));
+                        publish_event(Event::InactivityTimeout, now.as_millis());
+                        continue;
+                    }
+                    internet::shutdown_for_sleep().await;
                     enter_low_power_sleep(
                         &board,
                         &mut display,
@@ -678,6 +1092,7 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
                 }
                 Either5::Fourth(command) => match command {
                     PlatformCommand::RequestDeepSleep => {
+                        internet::shutdown_for_sleep().await;
                         enter_low_power_sleep(
                             &board,
                             &mut display,
@@ -694,10 +1109,39 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
                     }
                     PlatformCommand::PersistSettings(settings) => {
                         sleep.configure_inactivity_timeout(settings.inactivity_timeout_ms);
-                        if let Err(err) = storage.write_persisted_settings_sync(&settings) {
+                        sleep.configure_screensaver(settings.screensaver_minutes);
+                        input.configure_encoder(settings.encoder_sensitivity);
+                        if let Err(err) =
+                            storage.write_persisted_settings_sync(active_profile, &settings)
+                        {
                             info!("persist settings failed: {:?}", err);
                         }
                     }
+                    PlatformCommand::SwitchProfile(profile) => {
+                        if let Err(err) = storage.write_active_profile_sync(profile) {
+                            info!("profile switch write failed: {:?}", err);
+                        } else {
+                            esp_hal::system::software_reset();
+                        }
+                    }
+                    PlatformCommand::FactoryReset => {
+                        if let Err(err) = storage.erase_device_records_sync() {
+                            info!("factory reset flash erase failed: {:?}", err);
+                        }
+                        esp_hal::system::software_reset();
+                    }
+                    PlatformCommand::CliScreenshot => {
+                        #[cfg(feature = "usb-cli")]
+                        crate::cli::write_screenshot(&committed_frame).await;
+                    }
+                    PlatformCommand::SaveScreenshot => {
+                        match content_storage::save_screenshot(&committed_frame).await {
+                            Ok(()) => {
+                                publish_event(Event::ScreenshotSaved, Instant::now().as_millis())
+                            }
+                            Err(err) => info!("screenshot save failed: {:?}", err),
+                        }
+                    }
                 },
                 Either5::Fifth(display_event) => match display_event {
                     Either::First(_) => {
@@ -720,6 +1164,7 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
                                         screen: next_frame.screen,
                                         prepared: next_frame.target_screen(),
                                         transition: TransitionPlan::none(),
+                                        toasts: next_frame.toasts,
                                     });
                                     animation = None;
                                     next_animation_deadline = None;
@@ -779,7 +1224,7 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
                                     &mut committed_frame,
                                     &mut working_frame,
                                     &mut delay,
-                                    &update.prepared,
+                                    &update,
                                 );
                                 next_heartbeat_deadline = schedule_heartbeat_deadline();
                             } else {
@@ -798,6 +1243,7 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
                                         screen: next_animation.screen,
                                         prepared: next_animation.target_screen(),
                                         transition: TransitionPlan::none(),
+                                        toasts: next_animation.toasts,
                                     });
                                     animation = None;
                                     next_animation_deadline = None;
@@ -817,7 +1263,7 @@ pub async fn run_minimal(spawner: Spawner) -> ! {
                                 &mut committed_frame,
                                 &mut working_frame,
                                 &mut delay,
-                                &update.prepared,
+                                &update,
                             );
                             next_heartbeat_deadline = schedule_heartbeat_deadline();
                         }
@@ -865,6 +1311,10 @@ const fn is_tick_event(event: &Event) -> bool {
     matches!(event, Event::UiTick(_) | Event::ReaderTick(_))
 }
 
+pub(crate) async fn request_cli_screenshot() {
+    PLATFORM_CMD_CH.send(PlatformCommand::CliScreenshot).await;
+}
+
 pub(crate) async fn persist_backend_credential(credential: crate::storage::BackendCredential) {
     PLATFORM_CMD_CH
         .send(PlatformCommand::PersistBackendCredential(Box::new(
@@ -873,6 +1323,17 @@ pub(crate) async fn persist_backend_credential(credential: crate::storage::Backe
         .await;
 }
 
+// Without a buzzer the cue is dropped here, so the store never holds a stale one.
+fn flush_pending_audio_cue(store: &mut Store) {
+    let Some(cue) = store.take_pending_audio_cue() else {
+        return;
+    };
+    #[cfg(feature = "buzzer-ledc")]
+    crate::buzzer::queue_cue(cue, store.settings.audio_volume);
+    #[cfg(not(feature = "buzzer-ledc"))]
+    let _ = cue;
+}
+
 async fn flush_pending_reading_progress(store: &mut Store) {
     while let Some(entry) = store.take_pending_reading_progress_write() {
         if let Err(err) = content_storage::queue_reading_progress_write(entry).await {
@@ -889,6 +1350,57 @@ async fn flush_pending_reading_progress(store: &mut Store) {
     }
 }
 
+async fn flush_pending_bookmarks(store: &mut Store) {
+    let Some(bookmarks) = store.take_pending_bookmarks_write() else {
+        return;
+    };
+    if let Err(err) = content_storage::queue_bookmarks_write(bookmarks).await {
+        info!(
+            "content storage bookmarks persist failed count={} err={:?}",
+            bookmarks.len(),
+            err,
+        );
+    }
+}
+
+async fn flush_pending_history(store: &mut Store) {
+    let Some(history) = store.take_pending_history_write() else {
+        return;
+    };
+    if let Err(err) = content_storage::queue_history_write(history).await {
+        info!(
+            "content storage history persist failed count={} err={:?}",
+            history.len(),
+            err,
+        );
+    }
+}
+
+async fn flush_pending_book_stats(store: &mut Store) {
+    let Some(book_stats) = store.take_pending_book_stats_write() else {
+        return;
+    };
+    if let Err(err) = content_storage::queue_book_stats_write(book_stats).await {
+        info!(
+            "content storage book stats persist failed count={} err={:?}",
+            book_stats.len(),
+            err,
+        );
+    }
+}
+
+async fn flush_pending_quote(store: &mut Store) {
+    let Some(quote) = store.take_pending_quote() else {
+        return;
+    };
+    if let Err(err) = content_storage::queue_quote_append(quote).await {
+        info!(
+            "content storage quote export failed paragraph_index={} err={:?}",
+            quote.paragraph_index, err,
+        );
+    }
+}
+
 fn current_prepared_screen(
     animation: Option<AnimationPlayback>,
     committed_update: Option<ScreenUpdate>,
@@ -898,6 +1410,16 @@ fn current_prepared_screen(
         .or(committed_update.map(|update| update.prepared))
 }
 
+fn toasts_are_showing(
+    animation: Option<AnimationPlayback>,
+    committed_update: Option<ScreenUpdate>,
+) -> bool {
+    animation
+        .map(|active| active.toasts)
+        .or(committed_update.map(|update| update.toasts))
+        .is_some_and(|toasts| !toasts.is_empty())
+}
+
 fn prepared_screen_suppresses_sleep(screen: &PreparedScreen) -> bool {
     prepared_screen_shows_startup_splash(screen)
         || prepared_screen_drives_reader_ticks(screen)
@@ -906,6 +1428,24 @@ fn prepared_screen_suppresses_sleep(screen: &PreparedScreen) -> bool {
         || prepared_screen_shows_dashboard_sync(screen)
 }
 
+// The library and a paused book, plus the screensaver itself so it keeps the longer sleep deadline.
+fn prepared_screen_allows_screensaver(screen: &PreparedScreen) -> bool {
+    match screen {
+        PreparedScreen::Dashboard(_)
+        | PreparedScreen::Collection(_)
+        | PreparedScreen::Screensaver(_) => true,
+        PreparedScreen::Reader(shell) => matches!(
+            shell.modal,
+            Some(app_runtime::components::ReaderModal::Pause(_))
+        ),
+        _ => false,
+    }
+}
+
+fn prepared_screen_is_screensaver(screen: &PreparedScreen) -> bool {
+    matches!(screen, PreparedScreen::Screensaver(_))
+}
+
 fn prepared_screen_shows_startup_splash(screen: &PreparedScreen) -> bool {
     matches!(screen, PreparedScreen::StartupSplash(_))
 }
@@ -947,13 +1487,16 @@ fn prepared_screen_shows_dashboard_sync(screen: &PreparedScreen) -> bool {
 fn prepared_screen_drives_ui_ticks(screen: &PreparedScreen) -> bool {
     match screen {
         PreparedScreen::StartupSplash(_) => true,
+        PreparedScreen::Onboarding(shell) => shell.demo_word.is_some(),
         PreparedScreen::Dashboard(_) => true,
+        PreparedScreen::Collection(shell) => shell.jump_letter.is_some(),
         PreparedScreen::Reader(shell) => matches!(
             shell.modal,
             Some(app_runtime::components::ReaderModal::Loading(_))
         ),
         PreparedScreen::Settings(shell) => {
             matches!(shell.mode, domain::ui::SettingsMode::RefreshLoading)
+                || shell.preview_word.is_some()
         }
         _ => false,
     }
@@ -995,7 +1538,11 @@ fn flush_tick_drop_logs() {
     }
 }
 
-fn next_sleep_deadline(model: &SleepModel, suppress_inactivity_sleep: bool) -> Instant {
+fn next_sleep_deadline(
+    model: &SleepModel,
+    suppress_inactivity_sleep: bool,
+    screensaver_eligible: bool,
+) -> Instant {
     if matches!(model.state, SleepState::SleepRequested) {
         return Instant::now();
     }
@@ -1007,10 +1554,60 @@ fn next_sleep_deadline(model: &SleepModel, suppress_inactivity_sleep: bool) -> I
     Instant::from_millis(
         model
             .last_activity_ms
-            .saturating_add(model.config.inactivity_timeout_ms),
+            .saturating_add(model.idle_sleep_after_ms(screensaver_eligible)),
     )
 }
 
+// While the screensaver is up it is redrawn on each whole minute since it appeared. Before that,
+// an eligible screen asks the app task for it once per idle stretch; the app may still decline.
+fn next_screensaver_deadline(
+    model: &SleepModel,
+    screen: Option<&PreparedScreen>,
+    requested_at_activity_ms: Option<u64>,
+    now_ms: u64,
+) -> Instant {
+    let (Some(screen), Some(due_ms)) = (screen, model.screensaver_due_ms()) else {
+        return Instant::from_millis(u64::MAX);
+    };
+
+    if prepared_screen_is_screensaver(screen) {
+        let shown_ms = now_ms.saturating_sub(due_ms);
+        let refresh = domain::sleep::SCREENSAVER_REFRESH_MS;
+        return Instant::from_millis(due_ms + (shown_ms / refresh + 1) * refresh);
+    }
+
+    if !prepared_screen_allows_screensaver(screen)
+        || requested_at_activity_ms == Some(model.last_activity_ms)
+    {
+        return Instant::from_millis(u64::MAX);
+    }
+
+    Instant::from_millis(due_ms)
+}
+
+// Light sleep only while a word is on screen and nothing else needs the CPU: no pending input,
+// nothing queued for either task and the radio down, since Wi-Fi misses beacons while asleep.
+#[cfg(feature = "reader-light-sleep")]
+fn reader_light_sleep_window_ms(
+    input: &PlatformInputService<'_>,
+    reader_ticks_active: bool,
+    now_ms: u64,
+    platform_deadline: Instant,
+) -> Option<u64> {
+    if !reader_ticks_active
+        || !input.is_idle()
+        || !APP_EVENT_CH.is_empty()
+        || !PLATFORM_CMD_CH.is_empty()
+        || SCREEN_SIGNAL.signaled()
+        || !internet::radio_is_idle()
+    {
+        return None;
+    }
+
+    let reader_due_ms = READER_DUE_AT_MS.lock(|due| due.get())?;
+    domain::sleep::light_sleep_window_ms(now_ms, reader_due_ms.min(platform_deadline.as_millis()))
+}
+
 fn next_display_deadline(
     next_animation_deadline: Option<Instant>,
     next_heartbeat_deadline: Instant,
@@ -1033,7 +1630,7 @@ fn present_prepared_screen<SPI, DISP, EMD, CS, D>(
     committed: &mut FrameBuffer,
     working: &mut FrameBuffer,
     delay: &mut D,
-    screen: &PreparedScreen,
+    update: &ScreenUpdate,
 ) where
     SPI: embedded_hal::spi::SpiBus<u8>,
     DISP: embedded_hal::digital::OutputPin,
@@ -1041,7 +1638,11 @@ fn present_prepared_screen<SPI, DISP, EMD, CS, D>(
     CS: embedded_hal::digital::OutputPin,
     D: DelayNs,
 {
-    renderer::draw_prepared_screen(working, screen);
+    let _full_speed = power::full_speed(CpuDemand::Render);
+    let started = Instant::now();
+    renderer::draw_screen_update(working, update);
+    let elapsed_us = started.elapsed().as_micros() as u32;
+    record_perf(|perf| perf.render.record(elapsed_us));
     let dirty_rows = diff_dirty_rows(committed, working);
     present_frame(display, committed, working, &dirty_rows, delay);
 }
@@ -1059,7 +1660,11 @@ fn present_transition_frame<SPI, DISP, EMD, CS, D>(
     CS: embedded_hal::digital::OutputPin,
     D: DelayNs,
 {
+    let _full_speed = power::full_speed(CpuDemand::Render);
+    let started = Instant::now();
     renderer::draw_transition_frame(working, animation);
+    let elapsed_us = started.elapsed().as_micros() as u32;
+    record_perf(|perf| perf.render.record(elapsed_us));
     let dirty_rows = diff_dirty_rows(committed, working);
     present_frame(display, committed, working, &dirty_rows, delay);
 }
@@ -1077,8 +1682,14 @@ fn present_frame<SPI, DISP, EMD, CS, D>(
     CS: embedded_hal::digital::OutputPin,
     D: DelayNs,
 {
+    let started = Instant::now();
     match display.present(committed, working, dirty_rows, delay) {
-        Ok(_stats) => {}
+        Ok(stats) => {
+            if stats.dirty_rows > 0 {
+                let elapsed_us = started.elapsed().as_micros() as u32;
+                record_perf(|perf| perf.flush.record(elapsed_us));
+            }
+        }
         Err(err) => {
             info!("display flush failed: {:?}", err);
             let _ = display.disable_output();
@@ -1116,7 +1727,35 @@ where
     enter_deep_sleep_with_button(sleep, rtc, wake_button);
 }
 
+// Feeds the status bar clock and night mode, and keeps the SD task's file timestamps current.
+fn sample_clock<C: Clock>(clock: &mut Option<C>, now_ms: u64) {
+    let Some(clock) = clock.as_mut() else {
+        return;
+    };
+    match clock.now() {
+        Ok(time) => {
+            crate::clock::record_time(time);
+            publish_event(Event::LocalTimeChanged(time), now_ms);
+        }
+        Err(ClockError::Unsupported) => {}
+        Err(err) => info!("clock sample failed: {:?}", err),
+    }
+}
+
+// Feeds the Light Sensor setting; the store decides whether the reading changes the appearance.
+fn sample_light<S: AmbientLightSensor>(sensor: &mut Option<S>, now_ms: u64) {
+    let Some(sensor) = sensor.as_mut() else {
+        return;
+    };
+    match sensor.lux() {
+        Ok(lux) => publish_event(Event::AmbientLightChanged(lux), now_ms),
+        Err(LightSensorError::Unsupported) => {}
+        Err(err) => info!("light sample failed: {:?}", err),
+    }
+}
+
 fn log_gpio_contract(board: &BoardConfig, sd_spi_clock: SdSpiClockConfig) {
+    info!("board preset={}", board.name);
     info!(
         "display gpio clk={} di={} cs={} disp={} emd={}",
         board.display_clk_gpio,
@@ -1125,6 +1764,10 @@ fn log_gpio_contract(board: &BoardConfig, sd_spi_clock: SdSpiClockConfig) {
         board.display_disp_gpio,
         board.display_emd_gpio
     );
+    #[cfg(feature = "extcomin-ledc")]
+    info!("display extcomin ledc hz={}", board.display_extcomin_hz);
+    #[cfg(feature = "buzzer-ledc")]
+    info!("buzzer gpio={}", board.buzzer_gpio);
     info!(
         "sd gpio cs={} sck={} mosi={} miso={}",
         board.sd_cs_gpio, board.sd_sck_gpio, board.sd_mosi_gpio, board.sd_miso_gpio
@@ -1141,6 +1784,10 @@ fn log_gpio_contract(board: &BoardConfig, sd_spi_clock: SdSpiClockConfig) {
         "sleep wake gpio={} inactivity_ms=30000",
         board.sleep_wake_gpio
     );
+    info!(
+        "power gpio battery_sense={} charge={} i2c_sda={} i2c_scl={}",
+        board.battery_sense_gpio, board.battery_charge_gpio, board.i2c_sda_gpio, board.i2c_scl_gpio
+    );
 }
 
 fn log_static_inventory() {
@@ -1205,13 +1852,11 @@ fn log_heap(label: &str) {
 }
 
 fn hold_display_sleep_pins(board: &BoardConfig) {
-    unsafe {
-        let disp_pin = AnyPin::steal(board.display_disp_gpio);
-        disp_pin.rtcio_pad_hold(true);
+    #[cfg(feature = "extcomin-ledc")]
+    crate::extcomin::park_low(board.display_emd_gpio);
 
-        let emd_pin = AnyPin::steal(board.display_emd_gpio);
-        emd_pin.rtcio_pad_hold(true);
-    }
+    board::pin(board.display_disp_gpio).rtcio_pad_hold(true);
+    board::pin(board.display_emd_gpio).rtcio_pad_hold(true);
 }
 
 #[cfg(test)]
@@ -1219,6 +1864,21 @@ mod tests {
     use super::*;
     use domain::sleep::{SleepConfig, WakeReason};
 
+    fn pause_modal() -> app_runtime::components::ReaderModal {
+        app_runtime::components::ReaderModal::Pause(app_runtime::components::PauseModal {
+            title: "PAUSED",
+            rows: core::array::from_fn(|index| app_runtime::components::PauseModalRow {
+                label: "ROW",
+                action: "",
+                selected: index == 0,
+                enabled: true,
+            }),
+            session_label: domain::text::InlineText::new(),
+            remaining_label: domain::text::InlineText::new(),
+            goal_permille: None,
+        })
+    }
+
     fn reader_shell(
         modal: Option<app_runtime::components::ReaderModal>,
     ) -> app_runtime::components::ReaderShell {
@@ -1231,6 +1891,12 @@ mod tests {
                 right_word: domain::text::InlineText::new(),
                 preview: domain::text::InlineText::new(),
                 font: domain::formatter::StageFont::Large,
+                wrap_index: 0,
+                highlight_anchor: false,
+                pivot_style: domain::formatter::FontStyle::Regular,
+                bold_lead_chars: 0,
+                rtl_word: false,
+                rtl_paragraph: false,
                 progress_width: 0,
             },
             badge: None,
@@ -1243,9 +1909,12 @@ mod tests {
     ) -> app_runtime::components::DashboardShell {
         app_runtime::components::DashboardShell {
             appearance: domain::settings::AppearanceMode::Light,
-            status: app_runtime::components::StatusCluster {
+            status: app_runtime::components::StatusBar {
                 battery_percent: 82,
+                charging: false,
                 wifi_online: true,
+                sd_card_ready: true,
+                clock: None,
             },
             sync_indicator,
             rail: app_runtime::components::VerticalRail { text: "HOME" },
@@ -1331,7 +2000,7 @@ mod tests {
         model.mark_woke(WakeReason::ColdBoot, 1_000);
 
         assert_eq!(
-            next_sleep_deadline(&model, false),
+            next_sleep_deadline(&model, false, false),
             Instant::from_millis(31_000)
         );
     }
@@ -1342,9 +2011,44 @@ mod tests {
         model.mark_woke(WakeReason::ColdBoot, 1_000);
 
         assert_eq!(
-            next_sleep_deadline(&model, true),
+            next_sleep_deadline(&model, true, false),
+            Instant::from_millis(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn screensaver_is_requested_once_then_refreshed_each_minute() {
+        let mut model = SleepModel::new(SleepConfig::new(30_000));
+        model.mark_woke(WakeReason::ColdBoot, 1_000);
+        model.config.screensaver_after_ms = 120_000;
+        let library = PreparedScreen::Dashboard(dashboard_shell(None));
+
+        assert_eq!(
+            next_sleep_deadline(&model, false, true),
+            Instant::from_millis(1_000 + 120_000 + domain::sleep::SCREENSAVER_SLEEP_AFTER_MS)
+        );
+        assert_eq!(
+            next_screensaver_deadline(&model, Some(&library), None, 5_000),
+            Instant::from_millis(121_000)
+        );
+        assert_eq!(
+            next_screensaver_deadline(&model, Some(&library), Some(1_000), 5_000),
             Instant::from_millis(u64::MAX)
         );
+
+        let screensaver = PreparedScreen::Screensaver(app_runtime::ScreensaverShell {
+            appearance: domain::settings::AppearanceMode::Light,
+            clock: None,
+            title: domain::text::InlineText::new(),
+            progress_percent: 0,
+            help: app_runtime::components::HelpHint {
+                text: "press to wake",
+            },
+        });
+        assert_eq!(
+            next_screensaver_deadline(&model, Some(&screensaver), Some(1_000), 150_000),
+            Instant::from_millis(181_000)
+        );
     }
 
     #[test]
@@ -1368,38 +2072,23 @@ mod tests {
         assert!(prepared_screen_drives_ui_ticks(&screen));
     }
 
+    #[test]
+    fn showing_toasts_drive_ui_ticks() {
+        let mut committed = ScreenUpdate {
+            screen: Screen::Reader,
+            prepared: PreparedScreen::Reader(reader_shell(None)),
+            transition: TransitionPlan::none(),
+            toasts: app_runtime::ToastStack::empty(),
+        };
+        assert!(!toasts_are_showing(None, Some(committed)));
+
+        committed.toasts.labels[0] = Some("Wi-Fi lost");
+        assert!(toasts_are_showing(None, Some(committed)));
+    }
+
     #[test]
     fn paused_reader_does_not_suppress_sleep() {
-        let screen =
-            PreparedScreen::Reader(reader_shell(Some(app_runtime::components::PauseModal {
-                title: "PAUSED",
-                rows: [
-                    app_runtime::components::PauseModalRow {
-                        label: "A",
-                        action: "A",
-                        selected: true,
-                        enabled: true,
-                    },
-                    app_runtime::components::PauseModalRow {
-                        label: "B",
-                        action: "B",
-                        selected: false,
-                        enabled: true,
-                    },
-                    app_runtime::components::PauseModalRow {
-                        label: "C",
-                        action: "C",
-                        selected: false,
-                        enabled: true,
-                    },
-                    app_runtime::components::PauseModalRow {
-                        label: "D",
-                        action: "D",
-                        selected: false,
-                        enabled: true,
-                    },
-                ],
-            })));
+        let screen = PreparedScreen::Reader(reader_shell(Some(pause_modal())));
 
         assert!(!prepared_screen_suppresses_sleep(&screen));
     }
@@ -1410,6 +2099,7 @@ mod tests {
             screen: Screen::Reader,
             prepared: PreparedScreen::Reader(reader_shell(None)),
             transition: TransitionPlan::none(),
+            toasts: app_runtime::ToastStack::empty(),
         };
 
         assert!(reader_ticks_are_active(None, Some(committed)));
@@ -1421,9 +2111,12 @@ mod tests {
             screen: Screen::Saved,
             prepared: PreparedScreen::Collection(app_runtime::components::ContentListShell {
                 appearance: domain::settings::AppearanceMode::Light,
-                status: app_runtime::components::StatusCluster {
+                status: app_runtime::components::StatusBar {
                     battery_percent: 82,
+                    charging: false,
                     wifi_online: true,
+                    sd_card_ready: true,
+                    clock: None,
                 },
                 rail: app_runtime::components::VerticalRail { text: "SAVED" },
                 large_rail: true,
@@ -1433,6 +2126,8 @@ mod tests {
                         meta: "A",
                         title: "A",
                         progress_badge: None,
+                        progress_percent: 0,
+                        finished: false,
                         is_fetching: false,
                         selected: false,
                     },
@@ -1440,6 +2135,8 @@ mod tests {
                         meta: "B",
                         title: "B",
                         progress_badge: None,
+                        progress_percent: 0,
+                        finished: false,
                         is_fetching: false,
                         selected: true,
                     },
@@ -1447,14 +2144,19 @@ mod tests {
                         meta: "C",
                         title: "C",
                         progress_badge: None,
+                        progress_percent: 0,
+                        finished: false,
                         is_fetching: false,
                         selected: false,
                     },
                 ],
+                grid: None,
                 band: app_runtime::components::SelectionBand { y: 106, height: 68 },
                 help: app_runtime::components::HelpHint { text: "BACK" },
+                jump_letter: None,
             }),
             transition: TransitionPlan::none(),
+            toasts: app_runtime::ToastStack::empty(),
         };
         let animation = AnimationPlayback::new(
             committed.prepared,
@@ -1466,6 +2168,7 @@ mod tests {
                     3,
                     50,
                 ),
+                toasts: app_runtime::ToastStack::empty(),
             },
         );
 
@@ -1474,39 +2177,12 @@ mod tests {
 
     #[test]
     fn modal_hide_animation_blocks_reader_ticks_until_commit() {
-        let paused = reader_shell(Some(app_runtime::components::PauseModal {
-            title: "PAUSED",
-            rows: [
-                app_runtime::components::PauseModalRow {
-                    label: "A",
-                    action: "A",
-                    selected: true,
-                    enabled: true,
-                },
-                app_runtime::components::PauseModalRow {
-                    label: "B",
-                    action: "B",
-                    selected: false,
-                    enabled: true,
-                },
-                app_runtime::components::PauseModalRow {
-                    label: "C",
-                    action: "C",
-                    selected: false,
-                    enabled: true,
-                },
-                app_runtime::components::PauseModalRow {
-                    label: "D",
-                    action: "D",
-                    selected: false,
-                    enabled: true,
-                },
-            ],
-        }));
+        let paused = reader_shell(Some(pause_modal()));
         let committed = ScreenUpdate {
             screen: Screen::Reader,
             prepared: PreparedScreen::Reader(paused),
             transition: TransitionPlan::none(),
+            toasts: app_runtime::ToastStack::empty(),
         };
         let animation = AnimationPlayback::new(
             committed.prepared,
@@ -1514,6 +2190,7 @@ mod tests {
                 screen: Screen::Reader,
                 prepared: PreparedScreen::Reader(reader_shell(None)),
                 transition: TransitionPlan::new(app_runtime::AnimationDescriptor::ModalHide, 3, 55),
+                toasts: app_runtime::ToastStack::empty(),
             },
         );
 
@@ -1543,9 +2220,12 @@ mod tests {
     fn collection_with_fetching_row_suppresses_sleep() {
         let screen = PreparedScreen::Collection(app_runtime::components::ContentListShell {
             appearance: domain::settings::AppearanceMode::Light,
-            status: app_runtime::components::StatusCluster {
+            status: app_runtime::components::StatusBar {
                 battery_percent: 82,
+                charging: false,
                 wifi_online: true,
+                sd_card_ready: true,
+                clock: None,
             },
             rail: app_runtime::components::VerticalRail { text: "SAVED" },
             large_rail: true,
@@ -1555,6 +2235,8 @@ mod tests {
                     meta: "SOURCE",
                     title: "Previous",
                     progress_badge: None,
+                    progress_percent: 0,
+                    finished: false,
                     is_fetching: false,
                     selected: false,
                 },
@@ -1562,6 +2244,8 @@ mod tests {
                     meta: "SOURCE",
                     title: "Fetching",
                     progress_badge: None,
+                    progress_percent: 0,
+                    finished: false,
                     is_fetching: true,
                     selected: true,
                 },
@@ -1569,14 +2253,18 @@ mod tests {
                     meta: "SOURCE",
                     title: "Next",
                     progress_badge: None,
+                    progress_percent: 0,
+                    finished: false,
                     is_fetching: false,
                     selected: false,
                 },
             ],
+            grid: None,
             band: app_runtime::components::SelectionBand { y: 106, height: 68 },
             help: app_runtime::components::HelpHint {
                 text: "long press_",
             },
+            jump_letter: None,
         });
 
         assert!(prepared_screen_suppresses_sleep(&screen));
@@ -1586,9 +2274,12 @@ mod tests {
     fn collection_without_fetching_row_does_not_suppress_sleep() {
         let screen = PreparedScreen::Collection(app_runtime::components::ContentListShell {
             appearance: domain::settings::AppearanceMode::Light,
-            status: app_runtime::components::StatusCluster {
+            status: app_runtime::components::StatusBar {
                 battery_percent: 82,
+                charging: false,
                 wifi_online: true,
+                sd_card_ready: true,
+                clock: None,
             },
             rail: app_runtime::components::VerticalRail { text: "SAVED" },
             large_rail: true,
@@ -1598,6 +2289,8 @@ mod tests {
                     meta: "SOURCE",
                     title: "Previous",
                     progress_badge: None,
+                    progress_percent: 0,
+                    finished: false,
                     is_fetching: false,
                     selected: false,
                 },
@@ -1605,6 +2298,8 @@ mod tests {
                     meta: "SOURCE",
                     title: "Current",
                     progress_badge: None,
+                    progress_percent: 0,
+                    finished: false,
                     is_fetching: false,
                     selected: true,
                 },
@@ -1612,14 +2307,18 @@ mod tests {
                     meta: "SOURCE",
                     title: "Next",
                     progress_badge: None,
+                    progress_percent: 0,
+                    finished: false,
                     is_fetching: false,
                     selected: false,
                 },
             ],
+            grid: None,
             band: app_runtime::components::SelectionBand { y: 106, height: 68 },
             help: app_runtime::components::HelpHint {
                 text: "long press_",
             },
+            jump_letter: None,
         });
 
         assert!(!prepared_screen_suppresses_sleep(&screen));
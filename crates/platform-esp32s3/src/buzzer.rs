@@ -0,0 +1,89 @@
+use ::domain::audio::{AudioCue, AudioVolume};
+use ::services::audio::{AudioError, AudioOutput};
+use embassy_sync::{blocking_mutex::raw::CriticalSectionRawMutex, channel::Channel};
+use embassy_time::{Duration, Timer};
+use esp_hal::{
+    gpio::{AnyPin, DriveMode},
+    ledc::{
+        Ledc, LowSpeed,
+        channel::{self, ChannelIFace},
+        timer::{self, TimerIFace},
+    },
+    time::Rate,
+};
+use log::info;
+
+// Cues are short, so two waiting is plenty; a third is dropped rather than played late.
+static AUDIO_CUE_CH: Channel<CriticalSectionRawMutex, (AudioCue, AudioVolume), 2> = Channel::new();
+
+pub(crate) fn queue_cue(cue: AudioCue, volume: AudioVolume) {
+    if AUDIO_CUE_CH.try_send((cue, volume)).is_err() {
+        info!("audio cue dropped cue={:?}", cue);
+    }
+}
+
+// A piezo on an LEDC channel. EXTCOMIN holds timer 0 and channel 0 when it is enabled, so the
+// buzzer takes timer 1 and channel 1. Volume is the duty cycle: a piezo is loudest at 50%.
+pub struct LedcBuzzer {
+    ledc: &'static Ledc<'static>,
+    pin: AnyPin<'static>,
+}
+
+impl LedcBuzzer {
+    pub fn new(ledc: &'static Ledc<'static>, pin: AnyPin<'static>) -> Self {
+        Self { ledc, pin }
+    }
+
+    async fn sound(&mut self, hz: u16, ms: u16, duty_pct: u8) -> Result<(), AudioError> {
+        let mut timer = self.ledc.timer::<LowSpeed>(timer::Number::Timer1);
+        timer
+            .configure(timer::config::Config {
+                duty: timer::config::Duty::Duty10Bit,
+                clock_source: timer::LSClockSource::APBClk,
+                frequency: Rate::from_hz(u32::from(hz)),
+            })
+            .map_err(|_| AudioError::Driver)?;
+
+        let mut channel = self
+            .ledc
+            .channel(channel::Number::Channel1, self.pin.reborrow());
+        channel
+            .configure(channel::config::Config {
+                timer: &timer,
+                duty_pct,
+                drive_mode: DriveMode::PushPull,
+            })
+            .map_err(|_| AudioError::Driver)?;
+        Timer::after(Duration::from_millis(u64::from(ms))).await;
+        channel.set_duty(0).map_err(|_| AudioError::Driver)
+    }
+}
+
+impl AudioOutput for LedcBuzzer {
+    async fn play(&mut self, cue: AudioCue, volume: AudioVolume) -> Result<(), AudioError> {
+        let duty_pct = match volume {
+            AudioVolume::Off => return Ok(()),
+            AudioVolume::Low => 5,
+            AudioVolume::High => 50,
+        };
+        for tone in cue.tones() {
+            if tone.hz == 0 {
+                Timer::after(Duration::from_millis(u64::from(tone.ms))).await;
+            } else {
+                self.sound(tone.hz, tone.ms, duty_pct).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Plays cues off the UI loop, one after another.
+#[embassy_executor::task]
+pub async fn audio_task(mut buzzer: LedcBuzzer) {
+    loop {
+        let (cue, volume) = AUDIO_CUE_CH.receive().await;
+        if let Err(err) = buzzer.play(cue, volume).await {
+            info!("audio cue failed cue={:?} err={:?}", cue, err);
+        }
+    }
+}
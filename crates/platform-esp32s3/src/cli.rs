@@ -0,0 +1,142 @@
+extern crate alloc;
+
+use alloc::string::String;
+use core::fmt::Write as _;
+
+use ::domain::{
+    cli::{self, CLI_LINE_MAX_BYTES, CliCommand, CliError},
+    runtime::{Effect, Event},
+    store::Store,
+};
+use embassy_executor::Spawner;
+use embassy_sync::{blocking_mutex::raw::CriticalSectionRawMutex, pipe::Pipe};
+use embassy_time::{Duration, Instant, with_timeout};
+use embedded_io_async::{Read, Write};
+use esp_hal::{
+    Async,
+    peripherals::USB_DEVICE,
+    usb_serial_jtag::{UsbSerialJtag, UsbSerialJtagRx, UsbSerialJtagTx},
+};
+use heapless::String as LineBuffer;
+use log::info;
+use ls027b7dh01::{FrameBuffer, HEIGHT, LINE_BYTES, WIDTH};
+
+use crate::bootstrap::{publish_event, request_cli_screenshot};
+
+const CLI_REPLY_BYTES: usize = 1024;
+// Nobody may be listening on the other end of the cable. Replies that cannot drain in time are
+// dropped rather than holding up the UI task.
+const CLI_REPLY_TIMEOUT_MS: u64 = 1_000;
+const PROMPT: &str = "> ";
+
+static CLI_REPLY: Pipe<CriticalSectionRawMutex, CLI_REPLY_BYTES> = Pipe::new();
+
+pub fn install(spawner: Spawner, usb: USB_DEVICE<'static>) {
+    let (rx, tx) = UsbSerialJtag::new(usb).into_async().split();
+    spawner.spawn(cli_rx_task(rx)).unwrap();
+    spawner.spawn(cli_tx_task(tx)).unwrap();
+    info!("usb cli installed");
+}
+
+// Runs on the app task so the command sees the same store the UI does; the returned effect is
+// applied by the caller like any other.
+pub(crate) async fn run_command(store: &mut Store, command: CliCommand) -> Effect {
+    let mut reply = String::new();
+    let effect = cli::execute(store, command, &mut reply);
+    reply.push_str(PROMPT);
+    write_reply(reply.as_bytes()).await;
+    effect
+}
+
+// Framebuffer rows as hex, top line first, with a set bit meaning ink as in PBM. Framed so a host
+// script can pick the rows out of the log stream.
+pub(crate) async fn write_screenshot(frame: &FrameBuffer) {
+    let mut header = LineBuffer::<32>::new();
+    let _ = writeln!(header, "screenshot {} {}", WIDTH, HEIGHT);
+    write_reply(header.as_bytes()).await;
+
+    let mut line = [0u8; LINE_BYTES * 2 + 1];
+    for row in 0..HEIGHT {
+        let Some(bytes) = frame.row(row) else {
+            break;
+        };
+        for (index, byte) in bytes.iter().enumerate() {
+            line[index * 2] = hex_digit(byte >> 4);
+            line[index * 2 + 1] = hex_digit(byte & 0x0F);
+        }
+        line[LINE_BYTES * 2] = b'\n';
+        write_reply(&line).await;
+    }
+    write_reply(b"screenshot end\n> ").await;
+}
+
+async fn write_reply(bytes: &[u8]) {
+    let _ = with_timeout(
+        Duration::from_millis(CLI_REPLY_TIMEOUT_MS),
+        CLI_REPLY.write_all(bytes),
+    )
+    .await;
+}
+
+const fn hex_digit(nibble: u8) -> u8 {
+    match nibble {
+        0..=9 => b'0' + nibble,
+        _ => b'a' + nibble - 10,
+    }
+}
+
+#[embassy_executor::task]
+async fn cli_rx_task(mut rx: UsbSerialJtagRx<'static, Async>) {
+    let mut line = LineBuffer::<CLI_LINE_MAX_BYTES>::new();
+    let mut overflowed = false;
+    let mut chunk = [0u8; 32];
+
+    loop {
+        let Ok(read) = rx.read(&mut chunk).await else {
+            continue;
+        };
+
+        for &byte in &chunk[..read] {
+            match byte {
+                b'\r' | b'\n' => {
+                    if overflowed {
+                        write_reply(b"line too long\n> ").await;
+                    } else if !line.is_empty() {
+                        dispatch_line(&line).await;
+                    }
+                    line.clear();
+                    overflowed = false;
+                }
+                0x08 | 0x7F => {
+                    line.pop();
+                }
+                byte if byte.is_ascii() && !byte.is_ascii_control() => {
+                    overflowed |= line.push(byte as char).is_err();
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+async fn dispatch_line(line: &str) {
+    match cli::parse_line(line) {
+        Ok(CliCommand::Screenshot) => request_cli_screenshot().await,
+        Ok(command) => publish_event(Event::CliCommand(command), Instant::now().as_millis()),
+        Err(CliError::Empty) => {}
+        Err(err) => {
+            write_reply(err.message().as_bytes()).await;
+            write_reply(b"\n> ").await;
+        }
+    }
+}
+
+#[embassy_executor::task]
+async fn cli_tx_task(mut tx: UsbSerialJtagTx<'static, Async>) {
+    let mut chunk = [0u8; 64];
+    loop {
+        let read = CLI_REPLY.read(&mut chunk).await;
+        let _ = tx.write_all(&chunk[..read]).await;
+        let _ = tx.flush().await;
+    }
+}
@@ -0,0 +1,131 @@
+use core::cell::Cell;
+
+use ::domain::clock::DateTime;
+use ::services::clock::{Clock, ClockError, ds3231_date_time, pcf8563_date_time};
+use embassy_sync::blocking_mutex::{Mutex, raw::CriticalSectionRawMutex};
+use embedded_sdmmc::{TimeSource, Timestamp};
+
+// Polled once a minute, which is as fine as the status bar clock and night mode need.
+pub const CLOCK_SAMPLE_INTERVAL_MS: u64 = 60_000;
+
+const DS3231_ADDRESS: u8 = 0x68;
+const DS3231_TIME_REG: u8 = 0x00;
+const DS3231_STATUS_REG: u8 = 0x0F;
+// Oscillator stop flag: set at first power-up and whenever the backup cell ran out.
+const DS3231_STATUS_OSF: u8 = 0x80;
+
+const PCF8563_ADDRESS: u8 = 0x51;
+const PCF8563_CONTROL_REG: u8 = 0x00;
+const PCF8563_TIME_REG: u8 = 0x02;
+
+// The last time read from the RTC, for anything outside the platform loop that wants the date
+// (the SD task stamps files with it).
+static LATEST_TIME: Mutex<CriticalSectionRawMutex, Cell<Option<DateTime>>> =
+    Mutex::new(Cell::new(None));
+
+pub(crate) fn record_time(time: DateTime) {
+    LATEST_TIME.lock(|latest| latest.set(Some(time)));
+}
+
+pub(crate) fn latest_time() -> Option<DateTime> {
+    LATEST_TIME.lock(Cell::get)
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum RtcChip {
+    Ds3231,
+    Pcf8563,
+}
+
+pub struct RtcClock<I2C> {
+    i2c: I2C,
+    chip: RtcChip,
+}
+
+impl<I2C> RtcClock<I2C>
+where
+    I2C: embedded_hal::i2c::I2c,
+{
+    // Tries the DS3231 address, then the PCF8563; `None` when neither answers.
+    pub fn probe(mut i2c: I2C) -> Option<Self> {
+        let mut byte = [0u8; 1];
+        let chip = if i2c
+            .write_read(DS3231_ADDRESS, &[DS3231_STATUS_REG], &mut byte)
+            .is_ok()
+        {
+            RtcChip::Ds3231
+        } else if i2c
+            .write_read(PCF8563_ADDRESS, &[PCF8563_CONTROL_REG], &mut byte)
+            .is_ok()
+        {
+            RtcChip::Pcf8563
+        } else {
+            return None;
+        };
+
+        Some(Self { i2c, chip })
+    }
+
+    pub const fn chip(&self) -> RtcChip {
+        self.chip
+    }
+
+    fn read(&mut self, address: u8, register: u8, out: &mut [u8]) -> Result<(), ClockError> {
+        self.i2c
+            .write_read(address, &[register], out)
+            .map_err(|_| ClockError::Bus)
+    }
+}
+
+impl<I2C> Clock for RtcClock<I2C>
+where
+    I2C: embedded_hal::i2c::I2c,
+{
+    fn now(&mut self) -> Result<DateTime, ClockError> {
+        let mut registers = [0u8; 7];
+        match self.chip {
+            RtcChip::Ds3231 => {
+                let mut status = [0u8; 1];
+                self.read(DS3231_ADDRESS, DS3231_STATUS_REG, &mut status)?;
+                if status[0] & DS3231_STATUS_OSF != 0 {
+                    return Err(ClockError::NotSet);
+                }
+                self.read(DS3231_ADDRESS, DS3231_TIME_REG, &mut registers)?;
+                ds3231_date_time(&registers).ok_or(ClockError::NotSet)
+            }
+            RtcChip::Pcf8563 => {
+                self.read(PCF8563_ADDRESS, PCF8563_TIME_REG, &mut registers)?;
+                pcf8563_date_time(&registers).ok_or(ClockError::NotSet)
+            }
+        }
+    }
+}
+
+// FAT timestamps for files the SD task writes: the last RTC reading, or 2026-01-01 before there
+// is one.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct WallTimeSource;
+
+impl TimeSource for WallTimeSource {
+    fn get_timestamp(&self) -> Timestamp {
+        let Some(time) = latest_time() else {
+            return Timestamp {
+                year_since_1970: 56,
+                zero_indexed_month: 0,
+                zero_indexed_day: 0,
+                hours: 0,
+                minutes: 0,
+                seconds: 0,
+            };
+        };
+
+        Timestamp {
+            year_since_1970: (time.year() - 1970) as u8,
+            zero_indexed_month: time.month() - 1,
+            zero_indexed_day: time.day() - 1,
+            hours: time.local_time().hour(),
+            minutes: time.local_time().minute(),
+            seconds: time.second(),
+        }
+    }
+}
@@ -11,37 +11,54 @@ extern crate alloc;
 use alloc::{boxed::Box, string::String, vec::Vec};
 use core::{cmp::Ordering, mem::size_of, ptr::addr_of_mut};
 
-use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering as AtomicOrdering};
+use core::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering as AtomicOrdering};
 use domain::{
+    book_stats::{BOOK_STATS_CAPACITY, BookStat, BookStatsState},
+    bookmark::{BOOKMARK_CAPACITY, Bookmark, BookmarkState, QUOTE_LINE_MAX_BYTES, Quote},
     content::{
         CONTENT_ID_MAX_BYTES, CONTENT_META_MAX_BYTES, CONTENT_TITLE_MAX_BYTES, CollectionKind,
-        CollectionManifestItem, CollectionManifestState, ContentState, DetailLocator,
-        MANIFEST_ITEM_CAPACITY, PackageState, READING_PROGRESS_CAPACITY,
-        RECOMMENDATION_SERVE_ID_MAX_BYTES, REMOTE_ITEM_ID_MAX_BYTES, ReadingProgressEntry,
-        ReadingProgressState, RecommendationSubtopicsState, RemoteContentStatus,
+        CollectionManifestItem, CollectionManifestState, DetailLocator, MANIFEST_ITEM_CAPACITY,
+        PackageState, READING_PROGRESS_CAPACITY, RECOMMENDATION_SERVE_ID_MAX_BYTES,
+        REMOTE_ITEM_ID_MAX_BYTES, ReadingProgressEntry, ReadingProgressState,
+        RecommendationSubtopicsState, RemoteContentStatus,
     },
-    formatter::{
-        MAX_PARAGRAPH_PREVIEW_BYTES, MAX_READING_PARAGRAPHS, MAX_READING_TOKEN_BYTES,
-        ReadingDocument, StageFont, UnitFlags,
+    formatter::{FormatPreferences, ReadingDocument},
+    history::{
+        HISTORY_CAPACITY, HISTORY_TITLE_MAX_BYTES, HistoryEntry, HistoryStamp, HistoryState,
     },
-    reader::{READER_WINDOW_MAX_UNITS, ReaderParagraphInfo, ReaderWindow},
+    reader::{ReaderParagraphInfo, ReaderWindow},
+    runtime::Event,
+    settings::PROFILE_COUNT,
     storage::StorageRecoveryStatus,
     text::InlineText,
 };
 use embassy_executor::Spawner;
 use embassy_sync::{
-    blocking_mutex::raw::CriticalSectionRawMutex, channel::Channel, signal::Signal,
+    blocking_mutex::raw::CriticalSectionRawMutex, channel::Channel, mutex::Mutex, signal::Signal,
 };
 use embassy_time::Instant;
 use embedded_hal_bus::spi::{ExclusiveDevice, NoDelay};
 use embedded_sdmmc::{
     Block, BlockDevice, BlockIdx, Directory, Error as SdError, File, Mode, RawFile, RawVolume,
-    SdCard, ShortFileName, TimeSource, Timestamp, VolumeIdx, VolumeManager,
+    SdCard, ShortFileName, VolumeIdx, VolumeManager,
 };
+use embedded_storage::nor_flash::{NorFlash, ReadNorFlash};
 use esp_hal::{Blocking, delay::Delay, gpio::Output, spi::master::Spi, time::Rate};
 use log::{info, warn};
-use services::storage::StorageError;
+use ls027b7dh01::{FrameBuffer, HEIGHT, WIDTH};
+use reader_package::ReaderPackageHeader;
+use services::{
+    book_flash::BookPartition,
+    reader_content::{
+        MAX_PARSED_BLOCK_TEXT_BYTES, MAX_PARSED_LIST_ITEMS, MAX_PARSED_LIST_TOTAL_BYTES,
+        PACKAGE_READ_BUFFER_LEN, parse_opened_reader_content,
+    },
+    storage::{FileSource, StorageError},
+};
 
+pub use services::reader_content::OpenedReaderContent;
+
+use crate::clock::WallTimeSource;
 use crate::telemetry::{TraceContext, bool_flag, collection_label};
 
 const MAX_DIRS: usize = 8;
@@ -54,19 +71,20 @@ const STORAGE_CMD_QUEUE_CAPACITY: usize = 8;
 const MANIFEST_MAGIC: u32 = 0x4D43_4F4C;
 const CACHE_INDEX_MAGIC: u32 = 0x4D43_4944;
 const READING_PROGRESS_MAGIC: u32 = 0x4D43_5250;
+const BOOKMARKS_MAGIC: u32 = 0x4D43_424B;
+const HISTORY_MAGIC: u32 = 0x4D43_4853;
+const BOOK_STATS_MAGIC: u32 = 0x4D43_5354;
 const RECOMMENDATION_SUBTOPICS_MAGIC: u32 = 0x4D43_5254;
 const PACKAGE_META_MAGIC: u32 = 0x4D43_504D;
-const READER_PACKAGE_MAGIC: u32 = u32::from_le_bytes(*b"MTRP");
-const READER_PACKAGE_FORMAT_VERSION: u16 = 1;
 const FORMAT_VERSION: u16 = 1;
 const MAX_MANIFEST_SNAPSHOT_LEN: usize = 4096;
 const MAX_CACHE_INDEX_LEN: usize = 4096;
 const MAX_READING_PROGRESS_INDEX_LEN: usize = 4096;
+const MAX_BOOKMARKS_LEN: usize = 4096;
+const MAX_HISTORY_LEN: usize = 16 + HISTORY_CAPACITY * history_entry_encoded_len();
+const MAX_BOOK_STATS_LEN: usize = 16 + BOOK_STATS_CAPACITY * book_stat_encoded_len();
 const MAX_RECOMMENDATION_SUBTOPICS_LEN: usize = 1024;
 const MAX_PACKAGE_META_LEN: usize = 128;
-const READER_PACKAGE_HEADER_LEN: usize = 32;
-const READER_PACKAGE_PARAGRAPH_ENTRY_LEN: usize = 72;
-const READER_PACKAGE_UNIT_ENTRY_LEN: usize = 40;
 const PACKAGE_COPY_BUFFER_LEN: usize = 8 * 1024;
 const STAGE_WRITE_CHUNK_LEN: usize =
     crate::transfer_tuning::PACKAGE_TRANSFER_STORAGE_HANDOFF_CHUNK_LEN;
@@ -75,15 +93,6 @@ const STAGE_FLUSH_INTERVAL_BYTES: u32 =
 const STAGE_PROGRESS_LOG_INTERVAL_BYTES: u32 = 16 * 1024;
 const CACHE_ENTRY_CAPACITY: usize = 48;
 const CACHE_SIZE_BUDGET_BYTES: u64 = 32 * 1024 * 1024;
-const PACKAGE_READ_BUFFER_LEN: usize = 512;
-const MAX_JSON_KEY_BYTES: usize = 16;
-const MAX_PARSED_TITLE_BYTES: usize = CONTENT_TITLE_MAX_BYTES * 4;
-// Keep per-block scratch bounded independently from the whole-document capacity.
-// We want much larger articles overall without allowing a single paragraph parse
-// to balloon peak heap usage in lockstep with MAX_READING_UNITS.
-const MAX_PARSED_BLOCK_TEXT_BYTES: usize = 8 * 1024;
-const MAX_PARSED_LIST_ITEMS: usize = MAX_READING_PARAGRAPHS;
-const MAX_PARSED_LIST_TOTAL_BYTES: usize = 16 * 1024;
 
 // Dev-time content storage reset. Use a fresh top-level root while storage evolves.
 const ROOT_DIR_NAME: &str = "MTDV0003";
@@ -92,23 +101,44 @@ const MANIFEST_DIR_NAME: &str = "MANIF";
 const PACKAGE_DIR_NAME: &str = "PKG";
 const STAGING_DIR_NAME: &str = "STAGE";
 const CACHE_DIR_NAME: &str = "CACHE";
+// Font packs sit at the card root next to the books so they survive a wipe of the motif data.
+const FONT_DIR_NAME: &str = "FONTS";
+const FONT_PACK_EXTENSION: &[u8] = b"MFN";
+const MAX_FONT_PACK_BYTES: u32 = 512 * 1024;
+// Screenshots go beside the fonts, numbered SHOT0000.PBM upward.
+const SCREENSHOT_DIR_NAME: &str = "SHOTS";
+const SCREENSHOT_FILE_PREFIX: &[u8] = b"SHOT";
+// Exported quotes append to one text file in a directory of their own at the card root.
+const QUOTE_DIR_NAME: &str = "QUOTES";
+const QUOTE_FILE_NAME: &str = "QUOTES.TXT";
+const SCREENSHOT_EXTENSION: &[u8] = b"PBM";
+const MAX_SCREENSHOT_NUMBER: u16 = 9_999;
 const ACTIVE_STAGE_FILE_NAME: &str = "ACTIVE.PRT";
 const SAVED_MANIFEST_FILE_NAME: &str = "SAVED.BIN";
 const INBOX_MANIFEST_FILE_NAME: &str = "INBOX.BIN";
 const RECOMMENDATION_MANIFEST_FILE_NAME: &str = "RECS.BIN";
 const CACHE_INDEX_FILE_NAME: &str = "PKGIDX.BIN";
-const READING_PROGRESS_FILE_NAME: &str = "READPOS.BIN";
+// One reading-position file per profile; the first keeps the pre-profile name.
+const READING_PROGRESS_FILE_NAMES: [&str; PROFILE_COUNT as usize] =
+    ["READPOS.BIN", "READPOS1.BIN", "READPOS2.BIN"];
+const BOOKMARK_FILE_NAMES: [&str; PROFILE_COUNT as usize] =
+    ["BOOKMK.BIN", "BOOKMK1.BIN", "BOOKMK2.BIN"];
+// A fixed-size ring: the store keeps the newest `HISTORY_CAPACITY` sessions and the whole file is
+// rewritten on each change, so it never grows.
+const HISTORY_FILE_NAMES: [&str; PROFILE_COUNT as usize] =
+    ["HISTORY.BIN", "HISTORY1.BIN", "HISTORY2.BIN"];
+const BOOK_STATS_FILE_NAMES: [&str; PROFILE_COUNT as usize] =
+    ["STATS.BIN", "STATS1.BIN", "STATS2.BIN"];
 const RECOMMENDATION_SUBTOPICS_FILE_NAME: &str = "TOPICS.BIN";
 
 type SdBus<'d> = Spi<'d, Blocking>;
 type SdSpiDevice<'d> = ExclusiveDevice<SdBus<'d>, Output<'d>, NoDelay>;
 type SdBlockDevice<'d> = SdCard<SdSpiDevice<'d>, Delay>;
 type SdVolumeManager<'d> =
-    VolumeManager<SdBlockDevice<'d>, FixedTimeSource, MAX_DIRS, MAX_FILES, MAX_VOLUMES>;
+    VolumeManager<SdBlockDevice<'d>, WallTimeSource, MAX_DIRS, MAX_FILES, MAX_VOLUMES>;
 type SdDirectory<'a, 'd> =
-    Directory<'a, SdBlockDevice<'d>, FixedTimeSource, MAX_DIRS, MAX_FILES, MAX_VOLUMES>;
-type SdFile<'a, 'd> =
-    File<'a, SdBlockDevice<'d>, FixedTimeSource, MAX_DIRS, MAX_FILES, MAX_VOLUMES>;
+    Directory<'a, SdBlockDevice<'d>, WallTimeSource, MAX_DIRS, MAX_FILES, MAX_VOLUMES>;
+type SdFile<'a, 'd> = File<'a, SdBlockDevice<'d>, WallTimeSource, MAX_DIRS, MAX_FILES, MAX_VOLUMES>;
 
 static STORAGE_CMD_CH: Channel<
     CriticalSectionRawMutex,
@@ -116,12 +146,44 @@ static STORAGE_CMD_CH: Channel<
     STORAGE_CMD_QUEUE_CAPACITY,
 > = Channel::new();
 static STORAGE_RESP_SIG: Signal<CriticalSectionRawMutex, StorageResponse> = Signal::new();
+// Replies come back on one signal, so only one caller may wait on it at a time; otherwise the
+// reader's window loads and the backend's stage commits could take each other's responses.
+static STORAGE_REQUEST_LOCK: Mutex<CriticalSectionRawMutex, ()> = Mutex::new(());
 static STORAGE_AVAILABLE: AtomicBool = AtomicBool::new(false);
+static ACTIVE_PROFILE: AtomicU8 = AtomicU8::new(0);
 static STORAGE_CMD_DEPTH: AtomicUsize = AtomicUsize::new(0);
 static STORAGE_CMD_DEPTH_PEAK: AtomicUsize = AtomicUsize::new(0);
 static STORAGE_CMD_PAYLOAD_BYTES: AtomicUsize = AtomicUsize::new(0);
 static STORAGE_CMD_PAYLOAD_BYTES_PEAK: AtomicUsize = AtomicUsize::new(0);
 
+// Card init, layout check and font packs. The library itself is read by the storage task once
+// the UI is already running.
+const BOOT_SCAN_TOTAL_STEPS: u8 = 3;
+
+// One step of the boot-time card mount, reported just before its SD work starts.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BootScanProgress {
+    pub completed: u8,
+    pub total: u8,
+    pub label: &'static str,
+}
+
+struct BootScan<'a> {
+    completed: u8,
+    report: &'a mut dyn FnMut(BootScanProgress),
+}
+
+impl BootScan<'_> {
+    fn step(&mut self, label: &'static str) {
+        self.completed = self.completed.saturating_add(1).min(BOOT_SCAN_TOTAL_STEPS);
+        (self.report)(BootScanProgress {
+            completed: self.completed,
+            total: BOOT_SCAN_TOTAL_STEPS,
+            label,
+        });
+    }
+}
+
 pub struct ContentStorageMount<'d> {
     pub storage: Option<Box<SdContentStorage<'d>>>,
     pub sd_card_ready: bool,
@@ -139,18 +201,14 @@ pub struct SdContentStorage<'d> {
     snapshots: [Option<Box<CollectionManifestState>>; 3],
     cache_index: CacheIndex,
     reading_progress: ReadingProgressState,
+    bookmarks: BookmarkState,
+    history: HistoryState,
+    book_stats: BookStatsState,
     recommendation_subtopics: RecommendationSubtopicsState,
     pending_stage: Option<PendingStage>,
     pending_stage_error: Option<StorageError>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
-pub struct OpenedReaderContent {
-    pub title: InlineText<CONTENT_TITLE_MAX_BYTES>,
-    pub document: Box<ReadingDocument>,
-    pub truncated: bool,
-}
-
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct OpenedReaderPackage {
     pub title: InlineText<CONTENT_TITLE_MAX_BYTES>,
@@ -165,15 +223,6 @@ pub struct CommitAndOpenPackageResult {
     pub opened: Result<Box<OpenedReaderPackage>, StorageError>,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-struct ReaderPackageHeader {
-    title_len: u16,
-    paragraph_count: u16,
-    unit_count: u32,
-    paragraph_table_offset: u32,
-    unit_table_offset: u32,
-}
-
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 struct PendingStage {
     trace: TraceContext,
@@ -360,22 +409,6 @@ impl CacheIndex {
     }
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-struct FixedTimeSource;
-
-impl TimeSource for FixedTimeSource {
-    fn get_timestamp(&self) -> Timestamp {
-        Timestamp {
-            year_since_1970: 56,
-            zero_indexed_month: 0,
-            zero_indexed_day: 0,
-            hours: 0,
-            minutes: 0,
-            seconds: 0,
-        }
-    }
-}
-
 #[derive(Debug)]
 enum StageChunkBytes<const N: usize> {
     External(crate::memory_policy::ExternalBox<[u8; N]>),
@@ -423,6 +456,22 @@ enum StorageCommand {
         trace: TraceContext,
         entry: ReadingProgressEntry,
     },
+    PersistBookmarks {
+        trace: TraceContext,
+        bookmarks: Box<BookmarkState>,
+    },
+    PersistHistory {
+        trace: TraceContext,
+        history: Box<HistoryState>,
+    },
+    PersistBookStats {
+        trace: TraceContext,
+        book_stats: Box<BookStatsState>,
+    },
+    AppendQuote {
+        trace: TraceContext,
+        quote: Box<Quote>,
+    },
     BeginPackageStage {
         trace: TraceContext,
         content_id: InlineText<CONTENT_ID_MAX_BYTES>,
@@ -465,6 +514,25 @@ enum StorageCommand {
     OpenCachedReaderContent {
         trace: TraceContext,
         content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+        preferences: FormatPreferences,
+    },
+    KeepOnDevice {
+        trace: TraceContext,
+        content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+    },
+    DeleteBook {
+        trace: TraceContext,
+        content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+    },
+    ClearDeviceData {
+        trace: TraceContext,
+    },
+    SaveScreenshot {
+        trace: TraceContext,
+        pixels: Box<[u8]>,
+    },
+    Flush {
+        trace: TraceContext,
     },
 }
 
@@ -554,6 +622,10 @@ fn storage_command_label(command: &StorageCommand) -> &'static str {
         StorageCommand::PersistSnapshot { .. } => "persist_snapshot",
         StorageCommand::PersistRecommendationSubtopics { .. } => "persist_recommendation_subtopics",
         StorageCommand::PersistReadingProgress { .. } => "persist_reading_progress",
+        StorageCommand::PersistBookmarks { .. } => "persist_bookmarks",
+        StorageCommand::PersistHistory { .. } => "persist_history",
+        StorageCommand::PersistBookStats { .. } => "persist_book_stats",
+        StorageCommand::AppendQuote { .. } => "append_quote",
         StorageCommand::BeginPackageStage { .. } => "begin_stage",
         StorageCommand::WritePackageChunk { .. } => "write_chunk",
         StorageCommand::CommitPackageStage { .. } => "commit_stage",
@@ -563,6 +635,11 @@ fn storage_command_label(command: &StorageCommand) -> &'static str {
         StorageCommand::OpenCachedReaderPackage { .. } => "open_cached_reader_package",
         StorageCommand::LoadReaderWindow { .. } => "load_reader_window",
         StorageCommand::OpenCachedReaderContent { .. } => "open_cached_reader_content",
+        StorageCommand::KeepOnDevice { .. } => "keep_on_device",
+        StorageCommand::DeleteBook { .. } => "delete_book",
+        StorageCommand::ClearDeviceData { .. } => "clear_device_data",
+        StorageCommand::SaveScreenshot { .. } => "save_screenshot",
+        StorageCommand::Flush { .. } => "flush",
     }
 }
 
@@ -571,6 +648,8 @@ fn storage_command_trace(command: &StorageCommand) -> TraceContext {
         StorageCommand::PersistSnapshot { trace, .. }
         | StorageCommand::PersistRecommendationSubtopics { trace, .. }
         | StorageCommand::PersistReadingProgress { trace, .. }
+        | StorageCommand::PersistBookmarks { trace, .. }
+        | StorageCommand::AppendQuote { trace, .. }
         | StorageCommand::BeginPackageStage { trace, .. }
         | StorageCommand::WritePackageChunk { trace, .. }
         | StorageCommand::CommitPackageStage { trace, .. }
@@ -579,10 +658,26 @@ fn storage_command_trace(command: &StorageCommand) -> TraceContext {
         | StorageCommand::UpdatePackageState { trace, .. }
         | StorageCommand::OpenCachedReaderPackage { trace, .. }
         | StorageCommand::LoadReaderWindow { trace, .. }
-        | StorageCommand::OpenCachedReaderContent { trace, .. } => *trace,
+        | StorageCommand::OpenCachedReaderContent { trace, .. }
+        | StorageCommand::KeepOnDevice { trace, .. }
+        | StorageCommand::DeleteBook { trace, .. }
+        | StorageCommand::ClearDeviceData { trace }
+        | StorageCommand::SaveScreenshot { trace, .. }
+        | StorageCommand::Flush { trace } => *trace,
     }
 }
 
+async fn storage_request(
+    trace: TraceContext,
+    operation: &str,
+    command: StorageCommand,
+) -> StorageResponse {
+    let _request = STORAGE_REQUEST_LOCK.lock().await;
+    STORAGE_CMD_CH.send(command).await;
+    storage_queue_on_enqueue(trace, operation, 0);
+    STORAGE_RESP_SIG.wait().await
+}
+
 fn storage_queue_on_enqueue(trace: TraceContext, operation: &str, payload_bytes: usize) {
     let depth = STORAGE_CMD_DEPTH.fetch_add(1, AtomicOrdering::Relaxed) + 1;
     let payload_inflight =
@@ -659,6 +754,27 @@ fn emit_storage_queue_event(
     );
 }
 
+// Must run before `mount`, which loads the profile's reading positions.
+pub fn set_active_profile(profile: u8) {
+    ACTIVE_PROFILE.store(profile.min(PROFILE_COUNT - 1), AtomicOrdering::Relaxed);
+}
+
+fn reading_progress_file_name() -> &'static str {
+    READING_PROGRESS_FILE_NAMES[ACTIVE_PROFILE.load(AtomicOrdering::Relaxed) as usize]
+}
+
+fn bookmarks_file_name() -> &'static str {
+    BOOKMARK_FILE_NAMES[ACTIVE_PROFILE.load(AtomicOrdering::Relaxed) as usize]
+}
+
+fn history_file_name() -> &'static str {
+    HISTORY_FILE_NAMES[ACTIVE_PROFILE.load(AtomicOrdering::Relaxed) as usize]
+}
+
+fn book_stats_file_name() -> &'static str {
+    BOOK_STATS_FILE_NAMES[ACTIVE_PROFILE.load(AtomicOrdering::Relaxed) as usize]
+}
+
 fn fetch_max(cell: &AtomicUsize, candidate: usize) -> usize {
     let mut current = cell.load(AtomicOrdering::Relaxed);
     while candidate > current {
@@ -680,7 +796,12 @@ pub fn mount<'d>(
     cs: Output<'d>,
     run_spi_hz: u32,
     run_spi_source: &'static str,
+    on_progress: &mut dyn FnMut(BootScanProgress),
 ) -> ContentStorageMount<'d> {
+    let mut scan = BootScan {
+        completed: 0,
+        report: on_progress,
+    };
     let device = match ExclusiveDevice::new_no_delay(spi, cs) {
         Ok(device) => device,
         Err(_) => {
@@ -698,6 +819,7 @@ pub fn mount<'d>(
         }
     };
 
+    scan.step("Card");
     let delay = Delay::new();
     let card = SdCard::new(device, delay);
     let total_bytes = match card.num_bytes() {
@@ -750,7 +872,7 @@ pub fn mount<'d>(
 
     let volume_mgr = VolumeManager::<_, _, MAX_DIRS, MAX_FILES, MAX_VOLUMES>::new_with_limits(
         card,
-        FixedTimeSource,
+        WallTimeSource,
         0,
     );
     let mut storage = Box::<SdContentStorage<'d>>::new_uninit();
@@ -761,6 +883,9 @@ pub fn mount<'d>(
         addr_of_mut!((*storage_ptr).snapshots).write([None, None, None]);
         addr_of_mut!((*storage_ptr).cache_index).write(CacheIndex::empty());
         addr_of_mut!((*storage_ptr).reading_progress).write(ReadingProgressState::empty());
+        addr_of_mut!((*storage_ptr).bookmarks).write(BookmarkState::empty());
+        addr_of_mut!((*storage_ptr).history).write(HistoryState::empty());
+        addr_of_mut!((*storage_ptr).book_stats).write(BookStatsState::empty());
         addr_of_mut!((*storage_ptr).recommendation_subtopics)
             .write(RecommendationSubtopicsState::empty());
         addr_of_mut!((*storage_ptr).pending_stage).write(None);
@@ -769,6 +894,7 @@ pub fn mount<'d>(
     let mut storage = unsafe { storage.assume_init() };
     let mut last_recovery = StorageRecoveryStatus::Clean;
 
+    scan.step("Layout");
     if let Err(err) = storage.initialize_layout() {
         warn!("content storage layout init failed: {:?}", err);
         if matches!(err, StorageError::CorruptData) {
@@ -807,41 +933,11 @@ pub fn mount<'d>(
                 last_recovery: StorageRecoveryStatus::Failed,
             };
         }
-    } else if let Err(err) = storage.load_state() {
-        warn!("content storage state load failed: {:?}", err);
-        if matches!(err, StorageError::CorruptData) {
-            info!(
-                "content storage state corrupt: wiping motif sd data root={} version={}",
-                ROOT_DIR_NAME, VERSION_DIR_NAME
-            );
-            match storage.reset_dev_data() {
-                Ok(()) => {
-                    last_recovery = StorageRecoveryStatus::Recovered;
-                    info!("content storage state recovered by wiping motif sd data");
-                }
-                Err(recovery_err) => {
-                    warn!("content storage state recovery failed: {:?}", recovery_err);
-                    return ContentStorageMount {
-                        storage: None,
-                        sd_card_ready: false,
-                        sd_total_bytes: total_bytes,
-                        sd_free_bytes: 0,
-                        sd_run_hz: run_spi_hz,
-                        sd_run_hz_source: run_spi_source,
-                        sd_speed_switch_ok,
-                        last_recovery: StorageRecoveryStatus::Failed,
-                    };
-                }
-            }
-        } else {
-            storage.snapshots = [None, None, None];
-            storage.cache_index = CacheIndex::empty();
-            storage.reading_progress = ReadingProgressState::empty();
-            storage.recommendation_subtopics = RecommendationSubtopicsState::empty();
-            let _ = storage.cleanup_active_stage_file();
-        };
     }
 
+    scan.step("Fonts");
+    storage.load_font_packs();
+
     let sd_free_bytes = storage
         .storage_space_metrics()
         .map(|metrics| metrics.sd_free_bytes)
@@ -871,61 +967,40 @@ pub fn install(spawner: Spawner, storage: Option<Box<SdContentStorage<'static>>>
     }
 }
 
-pub(crate) fn bootstrap_content_state(
-    storage: Option<&mut SdContentStorage<'_>>,
-) -> Option<Box<ContentState>> {
-    let storage = storage?;
-    let saved = storage.snapshot(CollectionKind::Saved);
-    let inbox = storage.snapshot(CollectionKind::Inbox);
-
-    if saved.is_empty() && inbox.is_empty() {
-        return None;
-    }
-
-    info!(
-        "content storage bootstrap collections saved_count={} inbox_count={}",
-        saved.len(),
-        inbox.len(),
-    );
-
-    bootstrap_content_state_from_snapshots(saved, inbox)
-}
-
-pub(crate) fn bootstrap_reading_progress_state(
-    storage: Option<&mut SdContentStorage<'_>>,
-) -> Option<Box<ReadingProgressState>> {
-    let progress = storage?.reading_progress;
-    (!progress.is_empty()).then_some(Box::new(progress))
-}
-
-pub(crate) fn bootstrap_recommendation_subtopics_state(
-    storage: Option<&mut SdContentStorage<'_>>,
-) -> Option<Box<RecommendationSubtopicsState>> {
-    let subtopics = storage?.recommendation_subtopics;
-    (!subtopics.is_empty()).then_some(Box::new(subtopics))
-}
-
-fn bootstrap_content_state_from_snapshots(
+// Saved and Inbox only: recommendation manifests wait for the backend, since holding them as well
+// adds heap pressure ahead of the first auth/TLS exchange.
+fn library_scan_events(
     saved: CollectionManifestState,
     inbox: CollectionManifestState,
-) -> Option<Box<ContentState>> {
-    if saved.is_empty() && inbox.is_empty() {
-        return None;
+    reading_progress: ReadingProgressState,
+    bookmarks: BookmarkState,
+    history: HistoryState,
+    book_stats: BookStatsState,
+    recommendation_subtopics: RecommendationSubtopicsState,
+) -> Vec<Event> {
+    let mut events = Vec::new();
+    events.push(Event::StoredReadingStateLoaded {
+        reading_progress: Box::new(reading_progress),
+        bookmarks: Box::new(bookmarks),
+        history: Box::new(history),
+        book_stats: Box::new(book_stats),
+        recommendation_subtopics: Box::new(recommendation_subtopics),
+    });
+    for (kind, collection) in [
+        (CollectionKind::Saved, saved),
+        (CollectionKind::Inbox, inbox),
+    ] {
+        if collection.is_empty() {
+            continue;
+        }
+        info!(
+            "content storage library scan collection={} item_count={}",
+            collection_label(kind),
+            collection.len(),
+        );
+        events.push(Event::CollectionContentUpdated(kind, Box::new(collection)));
     }
-
-    Some(Box::new(ContentState {
-        saved: if saved.is_empty() {
-            None
-        } else {
-            Some(Box::new(saved))
-        },
-        inbox: if inbox.is_empty() {
-            None
-        } else {
-            Some(Box::new(inbox))
-        },
-        recommendations: None,
-    }))
+    events
 }
 
 pub async fn persist_snapshot(
@@ -948,10 +1023,7 @@ pub async fn persist_snapshot_traced(
         kind,
         snapshot: Box::new(snapshot),
     };
-    STORAGE_CMD_CH.send(command).await;
-    storage_queue_on_enqueue(trace, "persist_snapshot", 0);
-
-    match STORAGE_RESP_SIG.wait().await {
+    match storage_request(trace, "persist_snapshot", command).await {
         StorageResponse::Snapshot(result) => result.map(|snapshot| *snapshot),
         StorageResponse::CommitAndOpenPackage(_)
         | StorageResponse::Opened(_)
@@ -961,6 +1033,99 @@ pub async fn persist_snapshot_traced(
     }
 }
 
+// Empties the collection manifests, every profile's reading progress, and recommendation subtopics
+// ahead of a factory reset. Cached packages are left for eviction once nothing references them.
+pub async fn clear_device_data() -> Result<(), StorageError> {
+    if !STORAGE_AVAILABLE.load(AtomicOrdering::Relaxed) {
+        return Err(StorageError::Unavailable);
+    }
+    let trace = TraceContext::none();
+    let command = StorageCommand::ClearDeviceData { trace };
+    match storage_request(trace, "clear_device_data", command).await {
+        StorageResponse::Unit(result) => result,
+        StorageResponse::CommitAndOpenPackage(_)
+        | StorageResponse::Opened(_)
+        | StorageResponse::OpenedPackage(_)
+        | StorageResponse::LoadedWindow(_)
+        | StorageResponse::Snapshot(_) => Err(StorageError::Unavailable),
+    }
+}
+
+// Copies a package cached on the SD card into the internal flash book partition, so it can still
+// be read with the card removed.
+pub async fn keep_on_device(
+    content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+) -> Result<(), StorageError> {
+    if !STORAGE_AVAILABLE.load(AtomicOrdering::Relaxed) {
+        return Err(StorageError::Unavailable);
+    }
+    let trace = TraceContext::none();
+    let command = StorageCommand::KeepOnDevice { trace, content_id };
+    match storage_request(trace, "keep_on_device", command).await {
+        StorageResponse::Unit(result) => result,
+        StorageResponse::CommitAndOpenPackage(_)
+        | StorageResponse::Opened(_)
+        | StorageResponse::OpenedPackage(_)
+        | StorageResponse::LoadedWindow(_)
+        | StorageResponse::Snapshot(_) => Err(StorageError::Unavailable),
+    }
+}
+
+// Writes `frame` to the card as a binary PBM, which shares the framebuffer's row layout.
+pub async fn save_screenshot(frame: &FrameBuffer) -> Result<(), StorageError> {
+    if !STORAGE_AVAILABLE.load(AtomicOrdering::Relaxed) {
+        return Err(StorageError::Unavailable);
+    }
+    let trace = TraceContext::none();
+    let command = StorageCommand::SaveScreenshot {
+        trace,
+        pixels: Box::from(frame.bytes()),
+    };
+    match storage_request(trace, "save_screenshot", command).await {
+        StorageResponse::Unit(result) => result,
+        StorageResponse::CommitAndOpenPackage(_)
+        | StorageResponse::Opened(_)
+        | StorageResponse::OpenedPackage(_)
+        | StorageResponse::LoadedWindow(_)
+        | StorageResponse::Snapshot(_) => Err(StorageError::Unavailable),
+    }
+}
+
+// Removes a book from the card (package and its meta sidecar) and from the flash partition. With
+// no card mounted only the flash copy can go.
+pub async fn delete_book(content_id: InlineText<CONTENT_ID_MAX_BYTES>) -> Result<(), StorageError> {
+    if !STORAGE_AVAILABLE.load(AtomicOrdering::Relaxed) {
+        return crate::book_storage::remove_book(content_id).await;
+    }
+    let trace = TraceContext::none();
+    let command = StorageCommand::DeleteBook { trace, content_id };
+    match storage_request(trace, "delete_book", command).await {
+        StorageResponse::Unit(result) => result,
+        StorageResponse::CommitAndOpenPackage(_)
+        | StorageResponse::Opened(_)
+        | StorageResponse::OpenedPackage(_)
+        | StorageResponse::LoadedWindow(_)
+        | StorageResponse::Snapshot(_) => Err(StorageError::Unavailable),
+    }
+}
+
+// The storage task drains commands in order, so the reply to this lands only after every write
+// queued before it has reached the card.
+pub async fn flush_writes() -> Result<(), StorageError> {
+    if !STORAGE_AVAILABLE.load(AtomicOrdering::Relaxed) {
+        return Err(StorageError::Unavailable);
+    }
+    let trace = TraceContext::none();
+    match storage_request(trace, "flush", StorageCommand::Flush { trace }).await {
+        StorageResponse::Unit(result) => result,
+        StorageResponse::CommitAndOpenPackage(_)
+        | StorageResponse::Opened(_)
+        | StorageResponse::OpenedPackage(_)
+        | StorageResponse::LoadedWindow(_)
+        | StorageResponse::Snapshot(_) => Err(StorageError::Unavailable),
+    }
+}
+
 pub async fn queue_reading_progress_write(entry: ReadingProgressEntry) -> Result<(), StorageError> {
     queue_reading_progress_write_traced(TraceContext::none(), entry).await
 }
@@ -988,6 +1153,66 @@ pub async fn queue_recommendation_subtopics_write_traced(
     Ok(())
 }
 
+pub async fn queue_bookmarks_write(bookmarks: BookmarkState) -> Result<(), StorageError> {
+    if !STORAGE_AVAILABLE.load(AtomicOrdering::Relaxed) {
+        return Err(StorageError::Unavailable);
+    }
+
+    let trace = TraceContext::none();
+    let command = StorageCommand::PersistBookmarks {
+        trace,
+        bookmarks: Box::new(bookmarks),
+    };
+    STORAGE_CMD_CH.send(command).await;
+    storage_queue_on_enqueue(trace, "persist_bookmarks", 0);
+    Ok(())
+}
+
+pub async fn queue_history_write(history: HistoryState) -> Result<(), StorageError> {
+    if !STORAGE_AVAILABLE.load(AtomicOrdering::Relaxed) {
+        return Err(StorageError::Unavailable);
+    }
+
+    let trace = TraceContext::none();
+    let command = StorageCommand::PersistHistory {
+        trace,
+        history: Box::new(history),
+    };
+    STORAGE_CMD_CH.send(command).await;
+    storage_queue_on_enqueue(trace, "persist_history", 0);
+    Ok(())
+}
+
+pub async fn queue_book_stats_write(book_stats: BookStatsState) -> Result<(), StorageError> {
+    if !STORAGE_AVAILABLE.load(AtomicOrdering::Relaxed) {
+        return Err(StorageError::Unavailable);
+    }
+
+    let trace = TraceContext::none();
+    let command = StorageCommand::PersistBookStats {
+        trace,
+        book_stats: Box::new(book_stats),
+    };
+    STORAGE_CMD_CH.send(command).await;
+    storage_queue_on_enqueue(trace, "persist_book_stats", 0);
+    Ok(())
+}
+
+pub async fn queue_quote_append(quote: Quote) -> Result<(), StorageError> {
+    if !STORAGE_AVAILABLE.load(AtomicOrdering::Relaxed) {
+        return Err(StorageError::Unavailable);
+    }
+
+    let trace = TraceContext::none();
+    let command = StorageCommand::AppendQuote {
+        trace,
+        quote: Box::new(quote),
+    };
+    STORAGE_CMD_CH.send(command).await;
+    storage_queue_on_enqueue(trace, "append_quote", 0);
+    Ok(())
+}
+
 pub async fn queue_reading_progress_write_traced(
     trace: TraceContext,
     entry: ReadingProgressEntry,
@@ -1018,10 +1243,7 @@ pub async fn commit_package_stage_and_open_cached_reader_package_traced(
         remote_item_id,
         content_id,
     };
-    STORAGE_CMD_CH.send(command).await;
-    storage_queue_on_enqueue(trace, "commit_and_open_stage", 0);
-
-    let result = match STORAGE_RESP_SIG.wait().await {
+    let result = match storage_request(trace, "commit_and_open_stage", command).await {
         StorageResponse::CommitAndOpenPackage(result) => result.map(|result| *result),
         StorageResponse::Snapshot(_)
         | StorageResponse::Opened(_)
@@ -1071,10 +1293,7 @@ pub async fn begin_package_stage_traced(
         content_id,
         remote_revision,
     };
-    STORAGE_CMD_CH.send(command).await;
-    storage_queue_on_enqueue(trace, "begin_stage", 0);
-
-    match STORAGE_RESP_SIG.wait().await {
+    match storage_request(trace, "begin_stage", command).await {
         StorageResponse::Unit(result) => result,
         StorageResponse::CommitAndOpenPackage(_)
         | StorageResponse::Opened(_)
@@ -1131,10 +1350,7 @@ pub async fn commit_package_stage_traced(
         collection,
         remote_item_id,
     };
-    STORAGE_CMD_CH.send(command).await;
-    storage_queue_on_enqueue(trace, "commit_stage", 0);
-
-    match STORAGE_RESP_SIG.wait().await {
+    match storage_request(trace, "commit_stage", command).await {
         StorageResponse::Snapshot(result) => result.map(|snapshot| *snapshot),
         StorageResponse::CommitAndOpenPackage(_)
         | StorageResponse::Opened(_)
@@ -1153,10 +1369,7 @@ pub async fn abort_package_stage_traced(trace: TraceContext) -> Result<(), Stora
         return Err(StorageError::Unavailable);
     }
     let command = StorageCommand::AbortPackageStage { trace };
-    STORAGE_CMD_CH.send(command).await;
-    storage_queue_on_enqueue(trace, "abort_stage", 0);
-
-    match STORAGE_RESP_SIG.wait().await {
+    match storage_request(trace, "abort_stage", command).await {
         StorageResponse::Unit(result) => result,
         StorageResponse::CommitAndOpenPackage(_)
         | StorageResponse::Opened(_)
@@ -1195,10 +1408,7 @@ pub async fn update_package_state_traced(
         remote_item_id,
         package_state,
     };
-    STORAGE_CMD_CH.send(command).await;
-    storage_queue_on_enqueue(trace, "update_package_state", 0);
-
-    match STORAGE_RESP_SIG.wait().await {
+    match storage_request(trace, "update_package_state", command).await {
         StorageResponse::Snapshot(result) => result.map(|snapshot| *snapshot),
         StorageResponse::CommitAndOpenPackage(_)
         | StorageResponse::Opened(_)
@@ -1219,14 +1429,11 @@ pub async fn open_cached_reader_package_traced(
     content_id: InlineText<CONTENT_ID_MAX_BYTES>,
 ) -> Result<Box<OpenedReaderPackage>, StorageError> {
     if !STORAGE_AVAILABLE.load(AtomicOrdering::Relaxed) {
-        return Err(StorageError::Unavailable);
+        return crate::book_storage::open_reader_package(content_id).await;
     }
     let started_at = Instant::now();
     let command = StorageCommand::OpenCachedReaderPackage { trace, content_id };
-    STORAGE_CMD_CH.send(command).await;
-    storage_queue_on_enqueue(trace, "open_cached_reader_package", 0);
-
-    let result = match STORAGE_RESP_SIG.wait().await {
+    let result = match storage_request(trace, "open_cached_reader_package", command).await {
         StorageResponse::OpenedPackage(result) => result,
         StorageResponse::CommitAndOpenPackage(_)
         | StorageResponse::Snapshot(_)
@@ -1234,6 +1441,13 @@ pub async fn open_cached_reader_package_traced(
         | StorageResponse::LoadedWindow(_)
         | StorageResponse::Unit(_) => Err(StorageError::Unavailable),
     };
+    // A package evicted from the card may still be kept in flash.
+    let result = match result {
+        Err(StorageError::Unavailable) => {
+            crate::book_storage::open_reader_package(content_id).await
+        }
+        result => result,
+    };
 
     let total_ms = Instant::now().duration_since(started_at).as_millis();
     match &result {
@@ -1269,17 +1483,17 @@ pub async fn load_reader_window_traced(
     window_start_unit_index: u32,
 ) -> Result<Box<ReaderWindow>, StorageError> {
     if !STORAGE_AVAILABLE.load(AtomicOrdering::Relaxed) {
-        return Err(StorageError::Unavailable);
+        return crate::book_storage::load_reader_window(content_id, window_start_unit_index).await;
     }
     let command = StorageCommand::LoadReaderWindow {
         trace,
         content_id,
         window_start_unit_index,
     };
-    STORAGE_CMD_CH.send(command).await;
-    storage_queue_on_enqueue(trace, "load_reader_window", 0);
-
-    match STORAGE_RESP_SIG.wait().await {
+    match storage_request(trace, "load_reader_window", command).await {
+        StorageResponse::LoadedWindow(Err(StorageError::Unavailable)) => {
+            crate::book_storage::load_reader_window(content_id, window_start_unit_index).await
+        }
         StorageResponse::LoadedWindow(result) => result,
         StorageResponse::CommitAndOpenPackage(_)
         | StorageResponse::Snapshot(_)
@@ -1405,23 +1619,26 @@ fn read_le_u32(bytes: &[u8], offset: usize) -> Option<u32> {
 
 pub async fn open_cached_reader_content(
     content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+    preferences: FormatPreferences,
 ) -> Result<Box<OpenedReaderContent>, StorageError> {
-    open_cached_reader_content_traced(TraceContext::none(), content_id).await
+    open_cached_reader_content_traced(TraceContext::none(), content_id, preferences).await
 }
 
 pub async fn open_cached_reader_content_traced(
     trace: TraceContext,
     content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+    preferences: FormatPreferences,
 ) -> Result<Box<OpenedReaderContent>, StorageError> {
     if !STORAGE_AVAILABLE.load(AtomicOrdering::Relaxed) {
         return Err(StorageError::Unavailable);
     }
     let started_at = Instant::now();
-    let command = StorageCommand::OpenCachedReaderContent { trace, content_id };
-    STORAGE_CMD_CH.send(command).await;
-    storage_queue_on_enqueue(trace, "open_cached_reader_content", 0);
-
-    let result = match STORAGE_RESP_SIG.wait().await {
+    let command = StorageCommand::OpenCachedReaderContent {
+        trace,
+        content_id,
+        preferences,
+    };
+    let result = match storage_request(trace, "open_cached_reader_content", command).await {
         StorageResponse::Opened(result) => result,
         StorageResponse::CommitAndOpenPackage(_)
         | StorageResponse::Snapshot(_)
@@ -1451,21 +1668,18 @@ pub async fn open_cached_reader_content_traced(
     result
 }
 
-pub(crate) fn parse_reader_content_bytes(
-    bytes: &[u8],
-) -> Result<OpenedReaderContent, StorageError> {
-    let source = SliceJsonSource::new(bytes);
-    parse_opened_reader_content(source)
-}
-
 #[embassy_executor::task]
 async fn content_storage_task(mut storage: Box<SdContentStorage<'static>>) {
-    // Defer snapshot publication until backend sync updates the app store. Preloading
-    // non-empty SD manifests at boot materially increases heap pressure before the first
-    // auth/TLS exchange.
+    {
+        let _full_speed = crate::power::full_speed(domain::power::CpuDemand::Storage);
+        let _busy = crate::watchdog::busy(crate::watchdog::WatchedTask::Storage);
+        storage.scan_library();
+    }
     loop {
         let command = STORAGE_CMD_CH.receive().await;
         storage_queue_on_dequeue(&command);
+        let _full_speed = crate::power::full_speed(domain::power::CpuDemand::Storage);
+        let _busy = crate::watchdog::busy(crate::watchdog::WatchedTask::Storage);
         let response = match command {
             StorageCommand::PersistSnapshot {
                 trace,
@@ -1499,17 +1713,56 @@ async fn content_storage_task(mut storage: Box<SdContentStorage<'static>>) {
                 }
                 continue;
             }
-            StorageCommand::BeginPackageStage {
-                trace,
-                content_id,
-                remote_revision,
-            } => StorageResponse::Unit(storage.begin_stage(trace, content_id, remote_revision)),
-            StorageCommand::WritePackageChunk { trace, len, bytes } => {
-                storage.queue_stage_chunk(trace, bytes.as_slice(len));
+            StorageCommand::PersistBookmarks { trace, bookmarks } => {
+                if let Err(err) = storage.persist_bookmarks(trace, *bookmarks) {
+                    info!(
+                        "content storage persist bookmarks failed count={} err={:?}",
+                        bookmarks.len(),
+                        err,
+                    );
+                }
                 continue;
             }
-            StorageCommand::CommitPackageStage {
-                trace,
+            StorageCommand::PersistHistory { trace, history } => {
+                if let Err(err) = storage.persist_history(trace, *history) {
+                    info!(
+                        "content storage persist history failed count={} err={:?}",
+                        history.len(),
+                        err,
+                    );
+                }
+                continue;
+            }
+            StorageCommand::PersistBookStats { trace, book_stats } => {
+                if let Err(err) = storage.persist_book_stats(trace, *book_stats) {
+                    info!(
+                        "content storage persist book stats failed count={} err={:?}",
+                        book_stats.len(),
+                        err,
+                    );
+                }
+                continue;
+            }
+            StorageCommand::AppendQuote { quote, .. } => {
+                if let Err(err) = storage.append_quote(&quote) {
+                    info!(
+                        "content storage append quote failed paragraph_index={} err={:?}",
+                        quote.paragraph_index, err,
+                    );
+                }
+                continue;
+            }
+            StorageCommand::BeginPackageStage {
+                trace,
+                content_id,
+                remote_revision,
+            } => StorageResponse::Unit(storage.begin_stage(trace, content_id, remote_revision)),
+            StorageCommand::WritePackageChunk { trace, len, bytes } => {
+                storage.queue_stage_chunk(trace, bytes.as_slice(len));
+                continue;
+            }
+            StorageCommand::CommitPackageStage {
+                trace,
                 collection,
                 remote_item_id,
             } => StorageResponse::Snapshot(
@@ -1564,13 +1817,33 @@ async fn content_storage_task(mut storage: Box<SdContentStorage<'static>>) {
                 content_id,
                 window_start_unit_index,
             )),
-            StorageCommand::OpenCachedReaderContent { trace, content_id } => {
-                StorageResponse::Opened(
-                    storage
-                        .open_cached_reader_content(trace, content_id)
-                        .map(Box::new),
-                )
+            StorageCommand::OpenCachedReaderContent {
+                trace,
+                content_id,
+                preferences,
+            } => StorageResponse::Opened(
+                storage
+                    .open_cached_reader_content(trace, content_id, preferences)
+                    .map(Box::new),
+            ),
+            StorageCommand::KeepOnDevice { trace, content_id } => {
+                let mut books = crate::book_storage::lock().await;
+                StorageResponse::Unit(match books.as_mut() {
+                    Some(books) => storage.keep_on_device(trace, content_id, books).await,
+                    None => Err(StorageError::PartitionMissing),
+                })
             }
+            StorageCommand::DeleteBook { trace, content_id } => {
+                let mut books = crate::book_storage::lock().await;
+                StorageResponse::Unit(storage.delete_book(trace, content_id, books.as_mut()))
+            }
+            StorageCommand::ClearDeviceData { trace } => {
+                StorageResponse::Unit(storage.clear_device_data(trace))
+            }
+            StorageCommand::SaveScreenshot { pixels, .. } => {
+                StorageResponse::Unit(storage.save_screenshot(&pixels))
+            }
+            StorageCommand::Flush { .. } => StorageResponse::Unit(Ok(())),
         };
 
         STORAGE_RESP_SIG.signal(response);
@@ -1650,16 +1923,225 @@ impl<'d> SdContentStorage<'d> {
         Ok(())
     }
 
+    // Reads every `FONTS/*.MFN` pack once at boot and hands them to the renderer. The buffers live
+    // for the rest of the run, so a missing or broken pack only costs a log line.
+    fn load_font_packs(&mut self) {
+        let mut library = renderer::fonts::FontLibrary::new();
+        if let Err(err) = self.read_font_packs(&mut library) {
+            warn!("content storage font packs failed: {:?}", err);
+        }
+        if library.is_empty() {
+            return;
+        }
+
+        info!(
+            "content storage font packs installed packs={}",
+            library.len()
+        );
+        renderer::fonts::install(Box::leak(Box::new(library)));
+    }
+
+    fn save_screenshot(&mut self, pixels: &[u8]) -> Result<(), StorageError> {
+        let volume = self
+            .volume_mgr
+            .open_volume(VolumeIdx(0))
+            .map_err(map_sd_error)?;
+        let root = volume.open_root_dir().map_err(map_sd_error)?;
+        let dir = open_or_create_dir(&root, SCREENSHOT_DIR_NAME)?;
+
+        let mut next_number = 0u16;
+        dir.iterate_dir(|entry| {
+            if entry.name.extension() == SCREENSHOT_EXTENSION
+                && let Some(number) = screenshot_number(entry.name.base_name())
+            {
+                next_number = next_number.max(number.saturating_add(1));
+            }
+        })
+        .map_err(map_sd_error)?;
+        if next_number > MAX_SCREENSHOT_NUMBER {
+            return Err(StorageError::PartitionFull);
+        }
+
+        let file_name = screenshot_file_name(next_number);
+        let file = dir
+            .open_file_in_dir(file_name.as_str(), Mode::ReadWriteCreateOrTruncate)
+            .map_err(map_sd_error)?;
+        let mut header = heapless::String::<16>::new();
+        let _ = core::fmt::write(&mut header, format_args!("P4\n{} {}\n", WIDTH, HEIGHT));
+        file.write(header.as_bytes()).map_err(map_sd_error)?;
+        file.write(pixels).map_err(map_sd_error)?;
+        file.flush().map_err(map_sd_error)?;
+        info!(
+            "content storage screenshot saved name={}/{} bytes={}",
+            SCREENSHOT_DIR_NAME,
+            file_name.as_str(),
+            header.len() + pixels.len()
+        );
+        Ok(())
+    }
+
+    fn append_quote(&mut self, quote: &Quote) -> Result<(), StorageError> {
+        let mut line = heapless::String::<QUOTE_LINE_MAX_BYTES>::new();
+        quote
+            .write_line(&mut line)
+            .map_err(|_| StorageError::PayloadTooLarge)?;
+
+        let volume = self
+            .volume_mgr
+            .open_volume(VolumeIdx(0))
+            .map_err(map_sd_error)?;
+        let root = volume.open_root_dir().map_err(map_sd_error)?;
+        let dir = open_or_create_dir(&root, QUOTE_DIR_NAME)?;
+        let file = dir
+            .open_file_in_dir(QUOTE_FILE_NAME, Mode::ReadWriteCreateOrAppend)
+            .map_err(map_sd_error)?;
+        file.write(line.as_bytes()).map_err(map_sd_error)?;
+        file.flush().map_err(map_sd_error)?;
+        info!(
+            "content storage quote appended name={}/{} bytes={}",
+            QUOTE_DIR_NAME,
+            QUOTE_FILE_NAME,
+            line.len()
+        );
+        Ok(())
+    }
+
+    fn read_font_packs(
+        &mut self,
+        library: &mut renderer::fonts::FontLibrary,
+    ) -> Result<(), StorageError> {
+        let volume = self
+            .volume_mgr
+            .open_volume(VolumeIdx(0))
+            .map_err(map_sd_error)?;
+        let root = volume.open_root_dir().map_err(map_sd_error)?;
+        let dir = match root.open_dir(FONT_DIR_NAME) {
+            Ok(dir) => dir,
+            Err(SdError::NotFound) => return Ok(()),
+            Err(err) => return Err(map_sd_error(err)),
+        };
+
+        let mut candidates = Vec::<(ShortFileName, u32)>::new();
+        dir.iterate_dir(|entry| {
+            if !entry.attributes.is_directory()
+                && entry.name.extension() == FONT_PACK_EXTENSION
+                && candidates.len() < renderer::fonts::MAX_FONT_PACKS
+            {
+                candidates.push((entry.name.clone(), entry.size));
+            }
+        })
+        .map_err(map_sd_error)?;
+
+        for (file_name, size) in candidates {
+            if size > MAX_FONT_PACK_BYTES {
+                warn!(
+                    "content storage font pack skipped name={} size_bytes={} max_bytes={}",
+                    file_name, size, MAX_FONT_PACK_BYTES
+                );
+                continue;
+            }
+
+            let file = dir
+                .open_file_in_dir(&file_name, Mode::ReadOnly)
+                .map_err(map_sd_error)?;
+            let mut bytes = alloc::vec![0u8; size as usize];
+            let mut total = 0usize;
+            while total < bytes.len() {
+                let read = file.read(&mut bytes[total..]).map_err(map_sd_error)?;
+                if read == 0 {
+                    break;
+                }
+                total += read;
+            }
+            bytes.truncate(total);
+            drop(file);
+
+            match renderer::fonts::FontPack::parse(bytes.leak()) {
+                Ok(pack) => {
+                    info!(
+                        "content storage font pack loaded name={} cell={}x{} glyphs={}",
+                        file_name,
+                        pack.width(),
+                        pack.height(),
+                        pack.glyph_count()
+                    );
+                    library.try_push(pack);
+                }
+                Err(err) => warn!(
+                    "content storage font pack rejected name={} err={:?}",
+                    file_name, err
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    // Runs first in the storage task, while the app already shows an empty library. Manifests and
+    // progress are published as soon as they are read; the per-slot orphan sweep, the slow part,
+    // follows afterwards. Commands queued meanwhile wait for the scan, so a sync that persists a
+    // fresher manifest always lands after the stored one.
+    fn scan_library(&mut self) {
+        if let Err(err) = self.load_state() {
+            warn!("content storage state load failed: {:?}", err);
+            let recovered = matches!(err, StorageError::CorruptData) && {
+                info!(
+                    "content storage state corrupt: wiping motif sd data root={} version={}",
+                    ROOT_DIR_NAME, VERSION_DIR_NAME
+                );
+                match self.reset_dev_data() {
+                    Ok(()) => {
+                        info!("content storage state recovered by wiping motif sd data");
+                        true
+                    }
+                    Err(recovery_err) => {
+                        warn!("content storage state recovery failed: {:?}", recovery_err);
+                        false
+                    }
+                }
+            };
+            if !recovered {
+                self.snapshots = [None, None, None];
+                self.cache_index = CacheIndex::empty();
+                self.reading_progress = ReadingProgressState::empty();
+                self.bookmarks = BookmarkState::empty();
+                self.history = HistoryState::empty();
+                self.book_stats = BookStatsState::empty();
+                self.recommendation_subtopics = RecommendationSubtopicsState::empty();
+                let _ = self.cleanup_active_stage_file();
+            }
+        }
+
+        let at_ms = storage_now_ms();
+        for event in library_scan_events(
+            self.snapshot(CollectionKind::Saved),
+            self.snapshot(CollectionKind::Inbox),
+            self.reading_progress,
+            self.bookmarks,
+            self.history,
+            self.book_stats,
+            self.recommendation_subtopics,
+        ) {
+            crate::bootstrap::publish_event(event, at_ms);
+        }
+
+        if let Err(err) = self.cleanup_orphan_package_slots() {
+            warn!("content storage orphan slot sweep failed: {:?}", err);
+        }
+    }
+
     fn load_state(&mut self) -> Result<(), StorageError> {
         self.cleanup_active_stage_file()?;
         self.cache_index = self.read_cache_index()?.unwrap_or(CacheIndex::empty());
         self.reading_progress = self
             .read_reading_progress()?
             .unwrap_or(ReadingProgressState::empty());
+        self.bookmarks = self.read_bookmarks()?.unwrap_or(BookmarkState::empty());
+        self.history = self.read_history()?.unwrap_or(HistoryState::empty());
+        self.book_stats = self.read_book_stats()?.unwrap_or(BookStatsState::empty());
         self.recommendation_subtopics = self
             .read_recommendation_subtopics()?
             .unwrap_or(RecommendationSubtopicsState::empty());
-        self.cleanup_orphan_package_slots()?;
 
         let saved_snapshot = self.read_manifest_snapshot(CollectionKind::Saved)?;
         let (saved, saved_source) =
@@ -1719,6 +2201,9 @@ impl<'d> SdContentStorage<'d> {
         self.snapshots = [None, None, None];
         self.cache_index = CacheIndex::empty();
         self.reading_progress = ReadingProgressState::empty();
+        self.bookmarks = BookmarkState::empty();
+        self.history = HistoryState::empty();
+        self.book_stats = BookStatsState::empty();
         self.pending_stage = None;
         Ok(())
     }
@@ -1776,6 +2261,41 @@ impl<'d> SdContentStorage<'d> {
         Ok(())
     }
 
+    fn clear_device_data(&mut self, trace: TraceContext) -> Result<(), StorageError> {
+        for kind in [CollectionKind::Saved, CollectionKind::Inbox] {
+            self.set_snapshot(kind, CollectionManifestState::empty());
+            self.reconcile_snapshot(kind);
+            self.write_manifest_snapshot(kind)?;
+        }
+        self.refresh_collection_flags();
+        self.reading_progress = ReadingProgressState::empty();
+        for file_name in READING_PROGRESS_FILE_NAMES {
+            self.write_reading_progress_to(file_name)?;
+        }
+        self.bookmarks = BookmarkState::empty();
+        for file_name in BOOKMARK_FILE_NAMES {
+            self.write_bookmarks_to(file_name)?;
+        }
+        self.history = HistoryState::empty();
+        for file_name in HISTORY_FILE_NAMES {
+            self.write_history_to(file_name)?;
+        }
+        self.book_stats = BookStatsState::empty();
+        for file_name in BOOK_STATS_FILE_NAMES {
+            self.write_book_stats_to(file_name)?;
+        }
+        self.recommendation_subtopics = RecommendationSubtopicsState::empty();
+        self.write_recommendation_subtopics()?;
+        crate::memtrace!(
+            "storage_clear_device_data",
+            "component" = "storage",
+            "at_ms" = storage_now_ms(),
+            "sync_id" = trace.sync_id,
+            "req_id" = trace.req_id,
+        );
+        Ok(())
+    }
+
     fn persist_reading_progress(
         &mut self,
         trace: TraceContext,
@@ -1800,6 +2320,60 @@ impl<'d> SdContentStorage<'d> {
         Ok(())
     }
 
+    fn persist_bookmarks(
+        &mut self,
+        trace: TraceContext,
+        bookmarks: BookmarkState,
+    ) -> Result<(), StorageError> {
+        self.bookmarks = bookmarks;
+        self.write_bookmarks_to(bookmarks_file_name())?;
+        crate::memtrace!(
+            "storage_bookmarks",
+            "component" = "storage",
+            "at_ms" = storage_now_ms(),
+            "sync_id" = trace.sync_id,
+            "req_id" = trace.req_id,
+            "count" = self.bookmarks.len(),
+        );
+        Ok(())
+    }
+
+    fn persist_history(
+        &mut self,
+        trace: TraceContext,
+        history: HistoryState,
+    ) -> Result<(), StorageError> {
+        self.history = history;
+        self.write_history_to(history_file_name())?;
+        crate::memtrace!(
+            "storage_history",
+            "component" = "storage",
+            "at_ms" = storage_now_ms(),
+            "sync_id" = trace.sync_id,
+            "req_id" = trace.req_id,
+            "count" = self.history.len(),
+        );
+        Ok(())
+    }
+
+    fn persist_book_stats(
+        &mut self,
+        trace: TraceContext,
+        book_stats: BookStatsState,
+    ) -> Result<(), StorageError> {
+        self.book_stats = book_stats;
+        self.write_book_stats_to(book_stats_file_name())?;
+        crate::memtrace!(
+            "storage_book_stats",
+            "component" = "storage",
+            "at_ms" = storage_now_ms(),
+            "sync_id" = trace.sync_id,
+            "req_id" = trace.req_id,
+            "count" = self.book_stats.len(),
+        );
+        Ok(())
+    }
+
     fn begin_stage(
         &mut self,
         trace: TraceContext,
@@ -2214,6 +2788,7 @@ impl<'d> SdContentStorage<'d> {
         &mut self,
         trace: TraceContext,
         content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+        preferences: FormatPreferences,
     ) -> Result<OpenedReaderContent, StorageError> {
         let started_at = Instant::now();
         let entry = self
@@ -2250,7 +2825,7 @@ impl<'d> SdContentStorage<'d> {
 
             let mut source = SdPackageSource::new(file);
             let parse_started_at = Instant::now();
-            let opened = match parse_opened_reader_content(&mut source) {
+            let opened = match parse_opened_reader_content(&mut source, preferences) {
                 Ok(opened) => opened,
                 Err(err) => {
                     let _ = source.finish();
@@ -2374,8 +2949,8 @@ impl<'d> SdContentStorage<'d> {
                 return Err(StorageError::CorruptData);
             }
 
-            let header = read_reader_package_header(&mut file)?;
-            let title = read_reader_package_title(&mut file, header)?;
+            let header = reader_package::read_header(&mut file)?;
+            let title = reader_package::read_title(&mut file, header)?;
             let paragraphs = read_reader_package_paragraphs(&mut file, header)?;
             let window = read_reader_package_window(&mut file, header, 0)?;
             info!(
@@ -2420,10 +2995,16 @@ impl<'d> SdContentStorage<'d> {
         Ok(opened)
     }
 
-    fn read_cached_package_title(
+    async fn keep_on_device<F: NorFlash + ReadNorFlash>(
         &mut self,
-        entry: CacheEntry,
-    ) -> Result<InlineText<CONTENT_TITLE_MAX_BYTES>, StorageError> {
+        trace: TraceContext,
+        content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+        books: &mut BookPartition<F>,
+    ) -> Result<(), StorageError> {
+        let entry = self
+            .cache_index
+            .find_by_content_id(&content_id)
+            .ok_or(StorageError::Unavailable)?;
         let meta = self.read_package_meta(entry.slot_id)?;
         if meta.remote_revision != entry.remote_revision {
             return Err(StorageError::CorruptData);
@@ -2447,20 +3028,99 @@ impl<'d> SdContentStorage<'d> {
             return Err(StorageError::CorruptData);
         }
 
-        let header = read_reader_package_header(&mut file)?;
-        read_reader_package_title(&mut file, header)
+        let header = reader_package::read_header(&mut file)?;
+        let title = reader_package::read_title(&mut file, header)?;
+        let started_at_ms = storage_now_ms();
+        let mut write = books.begin_store(
+            content_id.as_str(),
+            title.as_str(),
+            entry.remote_revision,
+            meta.size_bytes,
+        )?;
+        // A whole slot takes seconds to erase and fill; yield between steps so the watchdog and
+        // the UI keep running.
+        let book = loop {
+            if let Some(book) = books.step(&mut write, &mut file)? {
+                break book;
+            }
+            crate::watchdog::checkpoint(crate::watchdog::WatchedTask::Storage);
+            embassy_futures::yield_now().await;
+        };
+        info!(
+            "content storage kept package on device content_id={} flash_slot={} size_bytes={} elapsed_ms={}",
+            content_id.as_str(),
+            book.slot,
+            book.size_bytes,
+            storage_elapsed_since_ms(started_at_ms),
+        );
+        crate::memtrace!(
+            "reader_package",
+            "component" = "storage",
+            "at_ms" = storage_now_ms(),
+            "action" = "keep_on_device",
+            "sync_id" = trace.sync_id,
+            "req_id" = trace.req_id,
+            "content_id" = content_id.as_str(),
+            "flash_slot" = book.slot,
+            "size_bytes" = book.size_bytes,
+        );
+        Ok(())
     }
 
-    fn load_reader_window(
+    fn delete_book<F: NorFlash + ReadNorFlash>(
         &mut self,
         trace: TraceContext,
         content_id: InlineText<CONTENT_ID_MAX_BYTES>,
-        window_start_unit_index: u32,
-    ) -> Result<Box<ReaderWindow>, StorageError> {
-        let entry = self
-            .cache_index
-            .find_by_content_id(&content_id)
-            .ok_or(StorageError::Unavailable)?;
+        books: Option<&mut BookPartition<F>>,
+    ) -> Result<(), StorageError> {
+        let cached = self.cache_index.find_by_content_id(&content_id);
+        if let Some(entry) = cached {
+            self.delete_package_slot(entry.slot_id)?;
+            let _ = self.cache_index.remove_slot(entry.slot_id);
+            self.reconcile_all_snapshots();
+            self.write_cache_index()?;
+            for kind in CollectionKind::ALL {
+                self.write_manifest_snapshot(kind)?;
+            }
+        }
+
+        let flash_slot = match books {
+            Some(books) => match books.find(content_id.as_str()) {
+                Some(book) => {
+                    books.remove(book.slot)?;
+                    Some(book.slot)
+                }
+                None => None,
+            },
+            None => None,
+        };
+        if cached.is_none() && flash_slot.is_none() {
+            return Err(StorageError::Unavailable);
+        }
+
+        info!(
+            "content storage deleted book content_id={} sd_slot={:?} flash_slot={:?}",
+            content_id.as_str(),
+            cached.map(|entry| entry.slot_id),
+            flash_slot,
+        );
+        crate::memtrace!(
+            "reader_package",
+            "component" = "storage",
+            "at_ms" = storage_now_ms(),
+            "action" = "delete_book",
+            "sync_id" = trace.sync_id,
+            "req_id" = trace.req_id,
+            "content_id" = content_id.as_str(),
+            "deleted_bytes" = cached.map(|entry| entry.size_bytes).unwrap_or(0),
+        );
+        Ok(())
+    }
+
+    fn read_cached_package_title(
+        &mut self,
+        entry: CacheEntry,
+    ) -> Result<InlineText<CONTENT_TITLE_MAX_BYTES>, StorageError> {
         let meta = self.read_package_meta(entry.slot_id)?;
         if meta.remote_revision != entry.remote_revision {
             return Err(StorageError::CorruptData);
@@ -2484,19 +3144,56 @@ impl<'d> SdContentStorage<'d> {
             return Err(StorageError::CorruptData);
         }
 
-        let header = read_reader_package_header(&mut file)?;
-        let window = read_reader_package_window(&mut file, header, window_start_unit_index)?;
-        info!(
-            "content storage window load content_id={} slot={} requested_start={} loaded_start={} unit_count={} total_units={} total_paragraphs={}",
-            content_id.as_str(),
-            entry.slot_id,
-            window_start_unit_index,
-            window.start_unit_index,
-            window.unit_count,
-            header.unit_count,
-            header.paragraph_count,
-        );
-        crate::memtrace!(
+        let header = reader_package::read_header(&mut file)?;
+        reader_package::read_title(&mut file, header)
+    }
+
+    fn load_reader_window(
+        &mut self,
+        trace: TraceContext,
+        content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+        window_start_unit_index: u32,
+    ) -> Result<Box<ReaderWindow>, StorageError> {
+        let entry = self
+            .cache_index
+            .find_by_content_id(&content_id)
+            .ok_or(StorageError::Unavailable)?;
+        let meta = self.read_package_meta(entry.slot_id)?;
+        if meta.remote_revision != entry.remote_revision {
+            return Err(StorageError::CorruptData);
+        }
+
+        let volume = self
+            .volume_mgr
+            .open_volume(VolumeIdx(0))
+            .map_err(map_sd_error)?;
+        let root = volume.open_root_dir().map_err(map_sd_error)?;
+        let motif = root.open_dir(ROOT_DIR_NAME).map_err(map_sd_error)?;
+        let v1 = motif.open_dir(VERSION_DIR_NAME).map_err(map_sd_error)?;
+        let pkg_dir = v1.open_dir(PACKAGE_DIR_NAME).map_err(map_sd_error)?;
+        let mut file = pkg_dir
+            .open_file_in_dir(
+                package_payload_file_name(entry.slot_id).as_str(),
+                Mode::ReadOnly,
+            )
+            .map_err(map_sd_error)?;
+        if file.length() != meta.size_bytes {
+            return Err(StorageError::CorruptData);
+        }
+
+        let header = reader_package::read_header(&mut file)?;
+        let window = read_reader_package_window(&mut file, header, window_start_unit_index)?;
+        info!(
+            "content storage window load content_id={} slot={} requested_start={} loaded_start={} unit_count={} total_units={} total_paragraphs={}",
+            content_id.as_str(),
+            entry.slot_id,
+            window_start_unit_index,
+            window.start_unit_index,
+            window.unit_count,
+            header.unit_count,
+            header.paragraph_count,
+        );
+        crate::memtrace!(
             "reader_window",
             "component" = "storage",
             "at_ms" = storage_now_ms(),
@@ -2682,15 +3379,19 @@ impl<'d> SdContentStorage<'d> {
     }
 
     fn write_reading_progress(&mut self) -> Result<(), StorageError> {
+        self.write_reading_progress_to(reading_progress_file_name())
+    }
+
+    fn write_reading_progress_to(&mut self, file_name: &str) -> Result<(), StorageError> {
         let mut bytes = Box::new([0u8; MAX_READING_PROGRESS_INDEX_LEN]);
         let encoded_len = encode_reading_progress(&self.reading_progress, &mut bytes[..])?;
-        self.write_named_file_in_manif_dir(READING_PROGRESS_FILE_NAME, &bytes[..encoded_len])
+        self.write_named_file_in_manif_dir(file_name, &bytes[..encoded_len])
     }
 
     fn read_reading_progress(&mut self) -> Result<Option<ReadingProgressState>, StorageError> {
         let mut bytes = Box::new([0u8; MAX_READING_PROGRESS_INDEX_LEN]);
         let Some(read_len) =
-            self.read_named_file_in_manif_dir(READING_PROGRESS_FILE_NAME, &mut bytes[..])?
+            self.read_named_file_in_manif_dir(reading_progress_file_name(), &mut bytes[..])?
         else {
             return Ok(None);
         };
@@ -2698,6 +3399,57 @@ impl<'d> SdContentStorage<'d> {
         decode_reading_progress(&bytes[..read_len]).map(Some)
     }
 
+    fn write_bookmarks_to(&mut self, file_name: &str) -> Result<(), StorageError> {
+        let mut bytes = Box::new([0u8; MAX_BOOKMARKS_LEN]);
+        let encoded_len = encode_bookmarks(&self.bookmarks, &mut bytes[..])?;
+        self.write_named_file_in_manif_dir(file_name, &bytes[..encoded_len])
+    }
+
+    fn read_bookmarks(&mut self) -> Result<Option<BookmarkState>, StorageError> {
+        let mut bytes = Box::new([0u8; MAX_BOOKMARKS_LEN]);
+        let Some(read_len) =
+            self.read_named_file_in_manif_dir(bookmarks_file_name(), &mut bytes[..])?
+        else {
+            return Ok(None);
+        };
+
+        decode_bookmarks(&bytes[..read_len]).map(Some)
+    }
+
+    fn write_history_to(&mut self, file_name: &str) -> Result<(), StorageError> {
+        let mut bytes = Box::new([0u8; MAX_HISTORY_LEN]);
+        let encoded_len = encode_history(&self.history, &mut bytes[..])?;
+        self.write_named_file_in_manif_dir(file_name, &bytes[..encoded_len])
+    }
+
+    fn read_history(&mut self) -> Result<Option<HistoryState>, StorageError> {
+        let mut bytes = Box::new([0u8; MAX_HISTORY_LEN]);
+        let Some(read_len) =
+            self.read_named_file_in_manif_dir(history_file_name(), &mut bytes[..])?
+        else {
+            return Ok(None);
+        };
+
+        decode_history(&bytes[..read_len]).map(Some)
+    }
+
+    fn write_book_stats_to(&mut self, file_name: &str) -> Result<(), StorageError> {
+        let mut bytes = Box::new([0u8; MAX_BOOK_STATS_LEN]);
+        let encoded_len = encode_book_stats(&self.book_stats, &mut bytes[..])?;
+        self.write_named_file_in_manif_dir(file_name, &bytes[..encoded_len])
+    }
+
+    fn read_book_stats(&mut self) -> Result<Option<BookStatsState>, StorageError> {
+        let mut bytes = Box::new([0u8; MAX_BOOK_STATS_LEN]);
+        let Some(read_len) =
+            self.read_named_file_in_manif_dir(book_stats_file_name(), &mut bytes[..])?
+        else {
+            return Ok(None);
+        };
+
+        decode_book_stats(&bytes[..read_len]).map(Some)
+    }
+
     fn write_recommendation_subtopics(&mut self) -> Result<(), StorageError> {
         let mut bytes = Box::new([0u8; MAX_RECOMMENDATION_SUBTOPICS_LEN]);
         let encoded_len =
@@ -2816,7 +3568,7 @@ impl<'d> SdContentStorage<'d> {
         if file.length() != expected_size {
             return Err(StorageError::CorruptData);
         }
-        let header = read_reader_package_header(&mut file)?;
+        let header = reader_package::read_header(&mut file)?;
         info!(
             "content storage stage validate size_bytes={} title_len={} total_units={} paragraphs={} paragraph_table_offset={} unit_table_offset={}",
             expected_size,
@@ -3043,7 +3795,7 @@ impl<'d> SdContentStorage<'d> {
         let mut result = None;
         let _ = self.volume_mgr.device(|device| {
             result = Some(read_fat_volume_free_bytes(device));
-            FixedTimeSource
+            WallTimeSource
         });
         result.unwrap_or(Err(StorageError::Unavailable))
     }
@@ -3325,6 +4077,20 @@ fn minimal_bootstrap_manifest_item(
     item
 }
 
+fn screenshot_file_name(number: u16) -> heapless::String<12> {
+    let mut name = heapless::String::<12>::new();
+    let _ = core::fmt::write(&mut name, format_args!("SHOT{:04}.PBM", number));
+    name
+}
+
+fn screenshot_number(base_name: &[u8]) -> Option<u16> {
+    let digits = base_name.strip_prefix(SCREENSHOT_FILE_PREFIX)?;
+    if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    core::str::from_utf8(digits).ok()?.parse().ok()
+}
+
 fn package_payload_file_name(slot_id: u8) -> heapless::String<12> {
     let mut name = heapless::String::<12>::new();
     let _ = core::fmt::write(&mut name, format_args!("P{:07}.PKG", slot_id));
@@ -3625,6 +4391,48 @@ fn decode_reading_progress(bytes: &[u8]) -> Result<ReadingProgressState, Storage
     Ok(progress)
 }
 
+fn encode_bookmarks(bookmarks: &BookmarkState, out: &mut [u8]) -> Result<usize, StorageError> {
+    if out.len() < 16 {
+        return Err(StorageError::PayloadTooLarge);
+    }
+
+    out.fill(0);
+    write_u32(out, 0, BOOKMARKS_MAGIC);
+    write_u16(out, 4, FORMAT_VERSION);
+    out[6] = bookmarks.len() as u8;
+
+    let mut offset = 16usize;
+    for bookmark in &bookmarks.entries[..bookmarks.len()] {
+        offset += encode_bookmark(bookmark, &mut out[offset..])?;
+    }
+
+    Ok(offset)
+}
+
+fn decode_bookmarks(bytes: &[u8]) -> Result<BookmarkState, StorageError> {
+    if bytes.len() < 16 {
+        return Err(StorageError::CorruptData);
+    }
+    if read_u32(bytes, 0) != BOOKMARKS_MAGIC || read_u16(bytes, 4) != FORMAT_VERSION {
+        return Err(StorageError::CorruptData);
+    }
+
+    let len = bytes[6] as usize;
+    if len > BOOKMARK_CAPACITY {
+        return Err(StorageError::CorruptData);
+    }
+
+    let mut bookmarks = BookmarkState::empty();
+    let mut offset = 16usize;
+    for _ in 0..len {
+        let (bookmark, consumed) = decode_bookmark(&bytes[offset..])?;
+        bookmarks.insert(bookmark);
+        offset += consumed;
+    }
+
+    Ok(bookmarks)
+}
+
 fn encode_recommendation_subtopics(
     subtopics: &RecommendationSubtopicsState,
     out: &mut [u8],
@@ -3811,6 +4619,249 @@ fn decode_reading_progress_entry(
     Ok((entry, needed))
 }
 
+// Oldest first, so decoding pushes them back into the ring in order.
+fn encode_history(history: &HistoryState, out: &mut [u8]) -> Result<usize, StorageError> {
+    if out.len() < 16 {
+        return Err(StorageError::PayloadTooLarge);
+    }
+
+    out.fill(0);
+    write_u32(out, 0, HISTORY_MAGIC);
+    write_u16(out, 4, FORMAT_VERSION);
+    out[6] = history.len() as u8;
+
+    let mut offset = 16usize;
+    for index in 0..history.len() {
+        if let Some(entry) = history.oldest(index) {
+            offset += encode_history_entry(entry, &mut out[offset..])?;
+        }
+    }
+
+    Ok(offset)
+}
+
+fn decode_history(bytes: &[u8]) -> Result<HistoryState, StorageError> {
+    if bytes.len() < 16 {
+        return Err(StorageError::CorruptData);
+    }
+    if read_u32(bytes, 0) != HISTORY_MAGIC || read_u16(bytes, 4) != FORMAT_VERSION {
+        return Err(StorageError::CorruptData);
+    }
+
+    let len = bytes[6] as usize;
+    if len > HISTORY_CAPACITY {
+        return Err(StorageError::CorruptData);
+    }
+
+    let mut history = HistoryState::empty();
+    let mut offset = 16usize;
+    for _ in 0..len {
+        let (entry, consumed) = decode_history_entry(&bytes[offset..])?;
+        history.push(entry);
+        offset += consumed;
+    }
+
+    Ok(history)
+}
+
+fn encode_history_entry(entry: &HistoryEntry, out: &mut [u8]) -> Result<usize, StorageError> {
+    let needed = history_entry_encoded_len();
+    if out.len() < needed {
+        return Err(StorageError::PayloadTooLarge);
+    }
+
+    out[..needed].fill(0);
+    out[0] = entry.content_id.len() as u8;
+    write_inline_text(&mut out[1..1 + CONTENT_ID_MAX_BYTES], &entry.content_id);
+    let mut offset = 1 + CONTENT_ID_MAX_BYTES;
+    out[offset] = entry.title.len() as u8;
+    write_inline_text(
+        &mut out[offset + 1..offset + 1 + HISTORY_TITLE_MAX_BYTES],
+        &entry.title,
+    );
+    offset += 1 + HISTORY_TITLE_MAX_BYTES;
+    write_u16(out, offset, entry.paragraph_index);
+    write_u32(out, offset + 2, entry.words);
+    offset += 6;
+    for stamp in [entry.started_at, entry.ended_at] {
+        if let Some(stamp) = stamp {
+            out[offset] = 1;
+            write_u32(out, offset + 1, stamp.day);
+            write_u16(out, offset + 5, stamp.minute);
+        }
+        offset += 7;
+    }
+    Ok(needed)
+}
+
+fn decode_history_entry(bytes: &[u8]) -> Result<(HistoryEntry, usize), StorageError> {
+    let needed = history_entry_encoded_len();
+    if bytes.len() < needed {
+        return Err(StorageError::CorruptData);
+    }
+
+    let mut entry = HistoryEntry::empty();
+    read_inline_text(
+        &mut entry.content_id,
+        bytes[0] as usize,
+        &bytes[1..1 + CONTENT_ID_MAX_BYTES],
+    );
+    let mut offset = 1 + CONTENT_ID_MAX_BYTES;
+    read_inline_text(
+        &mut entry.title,
+        bytes[offset] as usize,
+        &bytes[offset + 1..offset + 1 + HISTORY_TITLE_MAX_BYTES],
+    );
+    offset += 1 + HISTORY_TITLE_MAX_BYTES;
+    entry.paragraph_index = read_u16(bytes, offset);
+    entry.words = read_u32(bytes, offset + 2);
+    offset += 6;
+    let mut stamps = [None; 2];
+    for stamp in &mut stamps {
+        if bytes[offset] != 0 {
+            *stamp = Some(HistoryStamp {
+                day: read_u32(bytes, offset + 1),
+                minute: read_u16(bytes, offset + 5),
+            });
+        }
+        offset += 7;
+    }
+    [entry.started_at, entry.ended_at] = stamps;
+    if entry.content_id.is_empty() {
+        return Err(StorageError::CorruptData);
+    }
+
+    Ok((entry, needed))
+}
+
+const fn history_entry_encoded_len() -> usize {
+    1 + CONTENT_ID_MAX_BYTES + 1 + HISTORY_TITLE_MAX_BYTES + 2 + 4 + 2 * 7
+}
+
+fn encode_book_stats(book_stats: &BookStatsState, out: &mut [u8]) -> Result<usize, StorageError> {
+    if out.len() < 16 {
+        return Err(StorageError::PayloadTooLarge);
+    }
+
+    out.fill(0);
+    write_u32(out, 0, BOOK_STATS_MAGIC);
+    write_u16(out, 4, FORMAT_VERSION);
+    out[6] = book_stats.len() as u8;
+
+    let mut offset = 16usize;
+    for stat in &book_stats.entries[..book_stats.len()] {
+        offset += encode_book_stat(stat, &mut out[offset..])?;
+    }
+
+    Ok(offset)
+}
+
+fn decode_book_stats(bytes: &[u8]) -> Result<BookStatsState, StorageError> {
+    if bytes.len() < 16 {
+        return Err(StorageError::CorruptData);
+    }
+    if read_u32(bytes, 0) != BOOK_STATS_MAGIC || read_u16(bytes, 4) != FORMAT_VERSION {
+        return Err(StorageError::CorruptData);
+    }
+
+    let len = bytes[6] as usize;
+    if len > BOOK_STATS_CAPACITY {
+        return Err(StorageError::CorruptData);
+    }
+
+    let mut book_stats = BookStatsState::empty();
+    let mut offset = 16usize;
+    for _ in 0..len {
+        let (stat, consumed) = decode_book_stat(&bytes[offset..])?;
+        book_stats.insert(stat);
+        offset += consumed;
+    }
+
+    Ok(book_stats)
+}
+
+fn encode_book_stat(stat: &BookStat, out: &mut [u8]) -> Result<usize, StorageError> {
+    let needed = book_stat_encoded_len();
+    if out.len() < needed {
+        return Err(StorageError::PayloadTooLarge);
+    }
+
+    out[..needed].fill(0);
+    out[0] = stat.content_id.len() as u8;
+    write_inline_text(&mut out[1..1 + CONTENT_ID_MAX_BYTES], &stat.content_id);
+    let offset = 1 + CONTENT_ID_MAX_BYTES;
+    out[offset] = u8::from(stat.finished);
+    write_u16(out, offset + 1, stat.reads);
+    Ok(needed)
+}
+
+fn decode_book_stat(bytes: &[u8]) -> Result<(BookStat, usize), StorageError> {
+    let needed = book_stat_encoded_len();
+    if bytes.len() < needed {
+        return Err(StorageError::CorruptData);
+    }
+
+    let mut stat = BookStat::empty();
+    read_inline_text(
+        &mut stat.content_id,
+        bytes[0] as usize,
+        &bytes[1..1 + CONTENT_ID_MAX_BYTES],
+    );
+    let offset = 1 + CONTENT_ID_MAX_BYTES;
+    stat.finished = bytes[offset] != 0;
+    stat.reads = read_u16(bytes, offset + 1);
+    if stat.content_id.is_empty() {
+        return Err(StorageError::CorruptData);
+    }
+
+    Ok((stat, needed))
+}
+
+const fn book_stat_encoded_len() -> usize {
+    1 + CONTENT_ID_MAX_BYTES + 1 + 2
+}
+
+fn encode_bookmark(bookmark: &Bookmark, out: &mut [u8]) -> Result<usize, StorageError> {
+    let needed = bookmark_encoded_len();
+    if out.len() < needed {
+        return Err(StorageError::PayloadTooLarge);
+    }
+
+    out[..needed].fill(0);
+    out[0] = bookmark.content_id.len() as u8;
+    write_inline_text(&mut out[1..1 + CONTENT_ID_MAX_BYTES], &bookmark.content_id);
+    let offset = 1 + CONTENT_ID_MAX_BYTES;
+    write_u64(out, offset, bookmark.remote_revision);
+    write_u16(out, offset + 8, bookmark.paragraph_index);
+    Ok(needed)
+}
+
+fn decode_bookmark(bytes: &[u8]) -> Result<(Bookmark, usize), StorageError> {
+    let needed = bookmark_encoded_len();
+    if bytes.len() < needed {
+        return Err(StorageError::CorruptData);
+    }
+
+    let offset = 1 + CONTENT_ID_MAX_BYTES;
+    let mut bookmark = Bookmark::empty();
+    read_inline_text(
+        &mut bookmark.content_id,
+        bytes[0] as usize,
+        &bytes[1..1 + CONTENT_ID_MAX_BYTES],
+    );
+    bookmark.remote_revision = read_u64(bytes, offset);
+    bookmark.paragraph_index = read_u16(bytes, offset + 8);
+    if bookmark.is_empty() {
+        return Err(StorageError::CorruptData);
+    }
+
+    Ok((bookmark, needed))
+}
+
+const fn bookmark_encoded_len() -> usize {
+    1 + CONTENT_ID_MAX_BYTES + 8 + 2
+}
+
 const fn cache_entry_encoded_len() -> usize {
     1 + 1 + CONTENT_ID_MAX_BYTES + 8 + 4 + 4 + 4 + 1
 }
@@ -3942,244 +4993,34 @@ fn read_u64(buffer: &[u8], offset: usize) -> u64 {
     ])
 }
 
-fn read_exact_file(file: &mut SdFile<'_, '_>, out: &mut [u8]) -> Result<(), StorageError> {
-    let mut offset = 0usize;
-    while offset < out.len() {
-        let read = file.read(&mut out[offset..]).map_err(map_sd_error)?;
-        if read == 0 {
-            return Err(StorageError::CorruptData);
-        }
-        offset += read;
-    }
-    Ok(())
-}
-
-fn decode_reader_package_header(
-    bytes: &[u8],
-    file_len: u32,
-) -> Result<ReaderPackageHeader, StorageError> {
-    if bytes.len() < READER_PACKAGE_HEADER_LEN {
-        return Err(StorageError::CorruptData);
-    }
-    if read_u32(bytes, 0) != READER_PACKAGE_MAGIC
-        || read_u16(bytes, 4) != READER_PACKAGE_FORMAT_VERSION
-    {
-        return Err(StorageError::CorruptData);
-    }
-
-    let title_len = read_u16(bytes, 8);
-    let paragraph_count = read_u16(bytes, 10);
-    let unit_count = read_u32(bytes, 12);
-    let paragraph_table_offset = read_u32(bytes, 16);
-    let unit_table_offset = read_u32(bytes, 20);
-    let expected_paragraph_offset = (READER_PACKAGE_HEADER_LEN as u32)
-        .checked_add(title_len as u32)
-        .ok_or(StorageError::CorruptData)?;
-    let expected_unit_offset = expected_paragraph_offset
-        .checked_add(
-            (paragraph_count as u32)
-                .checked_mul(READER_PACKAGE_PARAGRAPH_ENTRY_LEN as u32)
-                .ok_or(StorageError::CorruptData)?,
-        )
-        .ok_or(StorageError::CorruptData)?;
-    let expected_file_len = expected_unit_offset
-        .checked_add(
-            unit_count
-                .checked_mul(READER_PACKAGE_UNIT_ENTRY_LEN as u32)
-                .ok_or(StorageError::CorruptData)?,
-        )
-        .ok_or(StorageError::CorruptData)?;
-
-    if paragraph_count == 0
-        || unit_count == 0
-        || paragraph_table_offset != expected_paragraph_offset
-        || unit_table_offset != expected_unit_offset
-        || expected_file_len != file_len
-    {
-        return Err(StorageError::CorruptData);
-    }
-
-    Ok(ReaderPackageHeader {
-        title_len,
-        paragraph_count,
-        unit_count,
-        paragraph_table_offset,
-        unit_table_offset,
-    })
-}
-
-fn read_reader_package_header(
-    file: &mut SdFile<'_, '_>,
-) -> Result<ReaderPackageHeader, StorageError> {
-    file.seek_from_start(0).map_err(map_sd_error)?;
-    let mut bytes = [0u8; READER_PACKAGE_HEADER_LEN];
-    read_exact_file(file, &mut bytes)?;
-    decode_reader_package_header(&bytes, file.length())
-}
-
-fn read_reader_package_title(
-    file: &mut SdFile<'_, '_>,
-    header: ReaderPackageHeader,
-) -> Result<InlineText<CONTENT_TITLE_MAX_BYTES>, StorageError> {
-    let mut title = InlineText::new();
-    let title_len = header.title_len as usize;
-    if title_len == 0 {
-        title.set_truncated("UNTITLED ARTICLE");
-        return Ok(title);
+impl FileSource for SdFile<'_, '_> {
+    fn byte_len(&self) -> u32 {
+        self.length()
     }
 
-    let mut bytes = alloc::vec![0u8; title_len];
-    file.seek_from_start(READER_PACKAGE_HEADER_LEN as u32)
-        .map_err(map_sd_error)?;
-    read_exact_file(file, &mut bytes)?;
-    let value = str::from_utf8(&bytes).map_err(|_| StorageError::CorruptData)?;
-    title.set_truncated(value);
-    if title.is_empty() {
-        title.set_truncated("UNTITLED ARTICLE");
+    fn read_at(&mut self, offset: u32, out: &mut [u8]) -> Result<usize, StorageError> {
+        self.seek_from_start(offset).map_err(map_sd_error)?;
+        self.read(out).map_err(map_sd_error)
     }
-    Ok(title)
 }
 
-fn decode_reader_package_paragraph_entry(
-    bytes: &[u8],
-) -> Result<ReaderParagraphInfo, StorageError> {
-    if bytes.len() < READER_PACKAGE_PARAGRAPH_ENTRY_LEN {
-        return Err(StorageError::CorruptData);
-    }
-
-    let preview_len = bytes[4] as usize;
-    if preview_len > MAX_PARAGRAPH_PREVIEW_BYTES || 8 + preview_len > bytes.len() {
-        return Err(StorageError::CorruptData);
-    }
-
-    let preview_text =
-        str::from_utf8(&bytes[8..8 + preview_len]).map_err(|_| StorageError::CorruptData)?;
-    let mut preview = InlineText::new();
-    preview.set_truncated(preview_text);
-    Ok(ReaderParagraphInfo {
-        start_unit_index: read_u32(bytes, 0),
-        preview,
-    })
-}
-
-fn read_reader_package_paragraphs(
-    file: &mut SdFile<'_, '_>,
+pub(crate) fn read_reader_package_paragraphs<S: FileSource + ?Sized>(
+    file: &mut S,
     header: ReaderPackageHeader,
 ) -> Result<Box<[ReaderParagraphInfo]>, StorageError> {
-    file.seek_from_start(header.paragraph_table_offset)
-        .map_err(map_sd_error)?;
-    let mut paragraphs = Vec::with_capacity(header.paragraph_count as usize);
-    let mut bytes = [0u8; READER_PACKAGE_PARAGRAPH_ENTRY_LEN];
-    let mut index = 0usize;
-    let mut previous_start = None;
-    while index < header.paragraph_count as usize {
-        read_exact_file(file, &mut bytes)?;
-        let paragraph = decode_reader_package_paragraph_entry(&bytes)?;
-        if paragraph.start_unit_index >= header.unit_count
-            || previous_start.is_some_and(|previous| paragraph.start_unit_index < previous)
-        {
-            return Err(StorageError::CorruptData);
-        }
-        previous_start = Some(paragraph.start_unit_index);
-        paragraphs.push(paragraph);
-        index += 1;
-    }
+    let paragraphs = reader_package::read_paragraphs(file, header)?;
     Ok(crate::memory_policy::external_or_global_boxed_slice(
         paragraphs,
     ))
 }
 
-fn font_from_byte(byte: u8) -> Result<StageFont, StorageError> {
-    match byte {
-        0 => Ok(StageFont::Large),
-        1 => Ok(StageFont::Medium),
-        2 => Ok(StageFont::Small),
-        _ => Err(StorageError::CorruptData),
-    }
-}
-
-fn flags_from_byte(byte: u8) -> UnitFlags {
-    UnitFlags {
-        clause_pause: (byte & 0b0001) != 0,
-        sentence_pause: (byte & 0b0010) != 0,
-        paragraph_start: (byte & 0b0100) != 0,
-        paragraph_end: (byte & 0b1000) != 0,
-    }
-}
-
-fn decode_reader_package_unit_entry(
-    bytes: &[u8],
-    paragraph_count: u16,
-) -> Result<domain::formatter::ReadingUnit, StorageError> {
-    if bytes.len() < READER_PACKAGE_UNIT_ENTRY_LEN {
-        return Err(StorageError::CorruptData);
-    }
-
-    let paragraph_index = read_u16(bytes, 0);
-    let display_len = bytes[6] as usize;
-    if paragraph_index == 0
-        || paragraph_index > paragraph_count
-        || display_len == 0
-        || display_len > MAX_READING_TOKEN_BYTES
-        || 8 + display_len > bytes.len()
-    {
-        return Err(StorageError::CorruptData);
-    }
-
-    let display_text =
-        str::from_utf8(&bytes[8..8 + display_len]).map_err(|_| StorageError::CorruptData)?;
-    let mut display = InlineText::new();
-    display.set_truncated(display_text);
-    if display.is_empty() {
-        return Err(StorageError::CorruptData);
-    }
-
-    let char_count = bytes[3];
-    if char_count == 0 {
-        return Err(StorageError::CorruptData);
-    }
-
-    Ok(domain::formatter::ReadingUnit {
-        display,
-        paragraph_index: paragraph_index.min(u8::MAX as u16) as u8,
-        anchor_index: bytes[2].min(char_count.saturating_sub(1)),
-        char_count,
-        font: font_from_byte(bytes[4])?,
-        flags: flags_from_byte(bytes[5]),
-    })
-}
-
-fn read_reader_package_window(
-    file: &mut SdFile<'_, '_>,
+pub(crate) fn read_reader_package_window<S: FileSource + ?Sized>(
+    file: &mut S,
     header: ReaderPackageHeader,
     window_start_unit_index: u32,
 ) -> Result<Box<ReaderWindow>, StorageError> {
-    if window_start_unit_index >= header.unit_count {
-        return Err(StorageError::CorruptData);
-    }
-
     let mut window = ReaderWindow::empty();
-    let remaining = header.unit_count.saturating_sub(window_start_unit_index);
-    let unit_count = remaining.min(READER_WINDOW_MAX_UNITS as u32) as usize;
-    let start_offset = header
-        .unit_table_offset
-        .checked_add(
-            window_start_unit_index
-                .checked_mul(READER_PACKAGE_UNIT_ENTRY_LEN as u32)
-                .ok_or(StorageError::CorruptData)?,
-        )
-        .ok_or(StorageError::CorruptData)?;
-    file.seek_from_start(start_offset).map_err(map_sd_error)?;
-
-    let mut bytes = [0u8; READER_PACKAGE_UNIT_ENTRY_LEN];
-    let mut index = 0usize;
-    while index < unit_count {
-        read_exact_file(file, &mut bytes)?;
-        window.units[index] = decode_reader_package_unit_entry(&bytes, header.paragraph_count)?;
-        index += 1;
-    }
-    window.start_unit_index = window_start_unit_index;
-    window.unit_count = unit_count as u16;
+    reader_package::read_window(file, header, window_start_unit_index, &mut window)?;
     Ok(crate::memory_policy::external_or_global_box(window))
 }
 
@@ -4281,44 +5122,6 @@ fn crc32_continue(current: u32, bytes: &[u8]) -> u32 {
     crc
 }
 
-trait JsonSource {
-    fn read_chunk(&mut self, out: &mut [u8]) -> Result<usize, StorageError>;
-}
-
-impl<T> JsonSource for &mut T
-where
-    T: JsonSource + ?Sized,
-{
-    fn read_chunk(&mut self, out: &mut [u8]) -> Result<usize, StorageError> {
-        (**self).read_chunk(out)
-    }
-}
-
-struct SliceJsonSource<'a> {
-    bytes: &'a [u8],
-    offset: usize,
-}
-
-impl<'a> SliceJsonSource<'a> {
-    fn new(bytes: &'a [u8]) -> Self {
-        Self { bytes, offset: 0 }
-    }
-}
-
-impl JsonSource for SliceJsonSource<'_> {
-    fn read_chunk(&mut self, out: &mut [u8]) -> Result<usize, StorageError> {
-        let remaining = &self.bytes[self.offset..];
-        if remaining.is_empty() {
-            return Ok(0);
-        }
-
-        let read = remaining.len().min(out.len());
-        out[..read].copy_from_slice(&remaining[..read]);
-        self.offset += read;
-        Ok(read)
-    }
-}
-
 struct SdPackageSource<'a, 'd> {
     file: SdFile<'a, 'd>,
     bytes_read: usize,
@@ -4337,7 +5140,7 @@ impl<'a, 'd> SdPackageSource<'a, 'd> {
     fn finish(&mut self) -> Result<(), StorageError> {
         let mut buffer = [0u8; PACKAGE_READ_BUFFER_LEN];
         loop {
-            let read = self.read_chunk(&mut buffer)?;
+            let read = self.read_at(self.bytes_read as u32, &mut buffer)?;
             if read == 0 {
                 break;
             }
@@ -4354,637 +5157,22 @@ impl<'a, 'd> SdPackageSource<'a, 'd> {
     }
 }
 
-impl JsonSource for SdPackageSource<'_, '_> {
-    fn read_chunk(&mut self, out: &mut [u8]) -> Result<usize, StorageError> {
-        let read = self.file.read(out).map_err(map_sd_error)?;
+// The checksum only holds while the payload is read front to back, which is how the JSON parser
+// consumes it.
+impl FileSource for SdPackageSource<'_, '_> {
+    fn byte_len(&self) -> u32 {
+        self.file.byte_len()
+    }
+
+    fn read_at(&mut self, offset: u32, out: &mut [u8]) -> Result<usize, StorageError> {
+        debug_assert_eq!(offset as usize, self.bytes_read);
+        let read = self.file.read_at(offset, out)?;
         self.bytes_read = self.bytes_read.saturating_add(read);
         self.crc32 = crc32_continue(self.crc32, &out[..read]);
         Ok(read)
     }
 }
 
-#[derive(Debug, Default)]
-struct ParsedString {
-    value: String,
-    truncated: bool,
-}
-
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
-enum BlockKind {
-    Text,
-    List,
-    #[default]
-    Other,
-}
-
-#[derive(Debug, Default)]
-struct BlockDraft {
-    kind: BlockKind,
-    text: Option<String>,
-    ordered: bool,
-    items: Vec<String>,
-    truncated: bool,
-    list_bytes: usize,
-}
-
-impl BlockDraft {
-    fn parse<S: JsonSource>(stream: &mut JsonStream<S>) -> Result<Self, StorageError> {
-        let mut draft = Self::default();
-        stream.parse_object_fields(|stream, key| match key.as_str() {
-            "t" => {
-                let parsed = stream.parse_string_limited(8)?;
-                if parsed.truncated {
-                    draft.truncated = true;
-                }
-                draft.kind = match parsed.value.as_str() {
-                    "h" | "p" | "q" | "c" => BlockKind::Text,
-                    "l" => BlockKind::List,
-                    _ => BlockKind::Other,
-                };
-                Ok(())
-            }
-            "x" => {
-                let parsed = stream.parse_string_limited(MAX_PARSED_BLOCK_TEXT_BYTES)?;
-                if parsed.truncated {
-                    draft.truncated = true;
-                }
-                draft.text = Some(parsed.value);
-                Ok(())
-            }
-            "o" => {
-                draft.ordered = stream.parse_bool()?;
-                Ok(())
-            }
-            "i" => draft.parse_list_items(stream),
-            _ => stream.skip_value(),
-        })?;
-        Ok(draft)
-    }
-
-    fn parse_list_items<S: JsonSource>(
-        &mut self,
-        stream: &mut JsonStream<S>,
-    ) -> Result<(), StorageError> {
-        stream.parse_array_values(|stream, first| {
-            if first != b'"' {
-                return Err(StorageError::CorruptData);
-            }
-
-            let parsed = stream.parse_string_body_limited(MAX_PARSED_BLOCK_TEXT_BYTES)?;
-            if parsed.truncated {
-                self.truncated = true;
-            }
-
-            let trimmed = parsed.value.trim();
-            if trimmed.is_empty() {
-                return Ok(());
-            }
-
-            if self.items.len() >= MAX_PARSED_LIST_ITEMS
-                || self.list_bytes.saturating_add(trimmed.len()) > MAX_PARSED_LIST_TOTAL_BYTES
-            {
-                self.truncated = true;
-                return Ok(());
-            }
-
-            self.list_bytes = self.list_bytes.saturating_add(trimmed.len());
-            self.items.push(String::from(trimmed));
-            Ok(())
-        })
-    }
-
-    fn apply(self, document: &mut ReadingDocument) -> bool {
-        let mut truncated = self.truncated;
-
-        match self.kind {
-            BlockKind::Text => {
-                if let Some(text) = self.text {
-                    let trimmed = text.trim();
-                    if !trimmed.is_empty() && !document.push_paragraph_text(trimmed) {
-                        truncated = true;
-                    }
-                }
-            }
-            BlockKind::List => {
-                let mut item_index = 0usize;
-                while item_index < self.items.len() {
-                    let line = format_list_line(&self.items[item_index], self.ordered, item_index);
-                    if !document.push_paragraph_text(line.as_str()) {
-                        truncated = true;
-                        break;
-                    }
-                    item_index += 1;
-                }
-            }
-            BlockKind::Other => {}
-        }
-
-        truncated
-    }
-}
-
-struct JsonStream<S> {
-    source: S,
-    buffer: [u8; PACKAGE_READ_BUFFER_LEN],
-    cursor: usize,
-    buffered: usize,
-    unread: Option<u8>,
-}
-
-impl<S: JsonSource> JsonStream<S> {
-    fn new(source: S) -> Self {
-        Self {
-            source,
-            buffer: [0u8; PACKAGE_READ_BUFFER_LEN],
-            cursor: 0,
-            buffered: 0,
-            unread: None,
-        }
-    }
-
-    fn next_byte(&mut self) -> Result<Option<u8>, StorageError> {
-        if let Some(byte) = self.unread.take() {
-            return Ok(Some(byte));
-        }
-
-        if self.cursor == self.buffered {
-            self.buffered = self.source.read_chunk(&mut self.buffer)?;
-            self.cursor = 0;
-            if self.buffered == 0 {
-                return Ok(None);
-            }
-        }
-
-        let byte = self.buffer[self.cursor];
-        self.cursor += 1;
-        Ok(Some(byte))
-    }
-
-    fn unread_byte(&mut self, byte: u8) {
-        debug_assert!(self.unread.is_none());
-        self.unread = Some(byte);
-    }
-
-    fn next_significant_byte(&mut self) -> Result<Option<u8>, StorageError> {
-        loop {
-            let Some(byte) = self.next_byte()? else {
-                return Ok(None);
-            };
-            if !byte.is_ascii_whitespace() {
-                return Ok(Some(byte));
-            }
-        }
-    }
-
-    fn expect_byte(&mut self, expected: u8) -> Result<(), StorageError> {
-        let byte = self
-            .next_significant_byte()?
-            .ok_or(StorageError::CorruptData)?;
-        if byte != expected {
-            return Err(StorageError::CorruptData);
-        }
-        Ok(())
-    }
-
-    fn parse_bool(&mut self) -> Result<bool, StorageError> {
-        let first = self
-            .next_significant_byte()?
-            .ok_or(StorageError::CorruptData)?;
-        self.parse_bool_from(first)
-    }
-
-    fn parse_bool_from(&mut self, first: u8) -> Result<bool, StorageError> {
-        match first {
-            b't' => {
-                self.expect_literal(b"rue")?;
-                Ok(true)
-            }
-            b'f' => {
-                self.expect_literal(b"alse")?;
-                Ok(false)
-            }
-            _ => Err(StorageError::CorruptData),
-        }
-    }
-
-    fn parse_string_limited(&mut self, max_bytes: usize) -> Result<ParsedString, StorageError> {
-        let opening = self
-            .next_significant_byte()?
-            .ok_or(StorageError::CorruptData)?;
-        if opening != b'"' {
-            return Err(StorageError::CorruptData);
-        }
-        self.parse_string_body_limited(max_bytes)
-    }
-
-    fn parse_string_body_limited(
-        &mut self,
-        max_bytes: usize,
-    ) -> Result<ParsedString, StorageError> {
-        let mut parsed = ParsedString {
-            value: String::new(),
-            truncated: false,
-        };
-
-        loop {
-            let byte = self.next_byte()?.ok_or(StorageError::CorruptData)?;
-            match byte {
-                b'"' => return Ok(parsed),
-                b'\\' => {
-                    let escaped = self.next_byte()?.ok_or(StorageError::CorruptData)?;
-                    match escaped {
-                        b'"' | b'\\' | b'/' => {
-                            push_limited_char(
-                                &mut parsed.value,
-                                escaped as char,
-                                max_bytes,
-                                &mut parsed.truncated,
-                            );
-                        }
-                        b'b' | b'f' | b'n' | b'r' | b't' => {
-                            push_limited_char(
-                                &mut parsed.value,
-                                ' ',
-                                max_bytes,
-                                &mut parsed.truncated,
-                            );
-                        }
-                        b'u' => {
-                            let codepoint = self.parse_unicode_escape()?;
-                            push_limited_char(
-                                &mut parsed.value,
-                                char::from_u32(codepoint).unwrap_or('?'),
-                                max_bytes,
-                                &mut parsed.truncated,
-                            );
-                        }
-                        _ => return Err(StorageError::CorruptData),
-                    }
-                }
-                byte if byte.is_ascii() => {
-                    push_limited_char(
-                        &mut parsed.value,
-                        byte as char,
-                        max_bytes,
-                        &mut parsed.truncated,
-                    );
-                }
-                byte => {
-                    let continuation = utf8_continuation_len(byte)?;
-                    let mut utf8 = [0u8; 4];
-                    utf8[0] = byte;
-                    let mut index = 0usize;
-                    while index < continuation {
-                        let next = self.next_byte()?.ok_or(StorageError::CorruptData)?;
-                        if next & 0b1100_0000 != 0b1000_0000 {
-                            return Err(StorageError::CorruptData);
-                        }
-                        utf8[index + 1] = next;
-                        index += 1;
-                    }
-                    let text = core::str::from_utf8(&utf8[..continuation + 1])
-                        .map_err(|_| StorageError::CorruptData)?;
-                    push_limited_str(&mut parsed.value, text, max_bytes, &mut parsed.truncated);
-                }
-            }
-        }
-    }
-
-    fn parse_object_fields<F>(&mut self, mut handler: F) -> Result<(), StorageError>
-    where
-        F: FnMut(&mut Self, String) -> Result<(), StorageError>,
-    {
-        self.expect_byte(b'{')?;
-        let mut first = true;
-
-        loop {
-            let next = self
-                .next_significant_byte()?
-                .ok_or(StorageError::CorruptData)?;
-            match next {
-                b'}' => return Ok(()),
-                b',' if !first => {}
-                _ if first => self.unread_byte(next),
-                _ => return Err(StorageError::CorruptData),
-            }
-
-            let opening = self
-                .next_significant_byte()?
-                .ok_or(StorageError::CorruptData)?;
-            if opening != b'"' {
-                return Err(StorageError::CorruptData);
-            }
-
-            let key = self.parse_string_body_limited(MAX_JSON_KEY_BYTES)?.value;
-            self.expect_byte(b':')?;
-            handler(self, key)?;
-            first = false;
-        }
-    }
-
-    fn parse_array_values<F>(&mut self, mut handler: F) -> Result<(), StorageError>
-    where
-        F: FnMut(&mut Self, u8) -> Result<(), StorageError>,
-    {
-        self.expect_byte(b'[')?;
-        let mut first = true;
-
-        loop {
-            let next = self
-                .next_significant_byte()?
-                .ok_or(StorageError::CorruptData)?;
-            match next {
-                b']' => return Ok(()),
-                b',' if !first => {}
-                _ if first => self.unread_byte(next),
-                _ => return Err(StorageError::CorruptData),
-            }
-
-            let first_byte = self
-                .next_significant_byte()?
-                .ok_or(StorageError::CorruptData)?;
-            handler(self, first_byte)?;
-            first = false;
-        }
-    }
-
-    fn skip_value(&mut self) -> Result<(), StorageError> {
-        let first = self
-            .next_significant_byte()?
-            .ok_or(StorageError::CorruptData)?;
-        self.skip_value_from(first)
-    }
-
-    fn skip_value_from(&mut self, first: u8) -> Result<(), StorageError> {
-        match first {
-            b'{' => self.skip_object_body(),
-            b'[' => self.skip_array_body(),
-            b'"' => {
-                let _ = self.parse_string_body_limited(0)?;
-                Ok(())
-            }
-            b't' => self.expect_literal(b"rue"),
-            b'f' => self.expect_literal(b"alse"),
-            b'n' => self.expect_literal(b"ull"),
-            b'-' | b'0'..=b'9' => self.skip_number_from(first),
-            _ => Err(StorageError::CorruptData),
-        }
-    }
-
-    fn skip_object_body(&mut self) -> Result<(), StorageError> {
-        let mut first = true;
-        loop {
-            let next = self
-                .next_significant_byte()?
-                .ok_or(StorageError::CorruptData)?;
-            match next {
-                b'}' => return Ok(()),
-                b',' if !first => {}
-                _ if first => self.unread_byte(next),
-                _ => return Err(StorageError::CorruptData),
-            }
-
-            let opening = self
-                .next_significant_byte()?
-                .ok_or(StorageError::CorruptData)?;
-            if opening != b'"' {
-                return Err(StorageError::CorruptData);
-            }
-            let _ = self.parse_string_body_limited(0)?;
-            self.expect_byte(b':')?;
-            self.skip_value()?;
-            first = false;
-        }
-    }
-
-    fn skip_array_body(&mut self) -> Result<(), StorageError> {
-        let mut first = true;
-        loop {
-            let next = self
-                .next_significant_byte()?
-                .ok_or(StorageError::CorruptData)?;
-            match next {
-                b']' => return Ok(()),
-                b',' if !first => {}
-                _ if first => self.unread_byte(next),
-                _ => return Err(StorageError::CorruptData),
-            }
-
-            let first_byte = self
-                .next_significant_byte()?
-                .ok_or(StorageError::CorruptData)?;
-            self.skip_value_from(first_byte)?;
-            first = false;
-        }
-    }
-
-    fn skip_number_from(&mut self, first: u8) -> Result<(), StorageError> {
-        if !matches!(first, b'-' | b'0'..=b'9') {
-            return Err(StorageError::CorruptData);
-        }
-
-        loop {
-            let Some(byte) = self.next_byte()? else {
-                return Ok(());
-            };
-            if byte.is_ascii_whitespace() || matches!(byte, b',' | b']' | b'}') {
-                self.unread_byte(byte);
-                return Ok(());
-            }
-            if !matches!(byte, b'0'..=b'9' | b'.' | b'e' | b'E' | b'+' | b'-') {
-                return Err(StorageError::CorruptData);
-            }
-        }
-    }
-
-    fn expect_literal(&mut self, literal: &[u8]) -> Result<(), StorageError> {
-        let mut index = 0usize;
-        while index < literal.len() {
-            let byte = self.next_byte()?.ok_or(StorageError::CorruptData)?;
-            if byte != literal[index] {
-                return Err(StorageError::CorruptData);
-            }
-            index += 1;
-        }
-        Ok(())
-    }
-
-    fn parse_unicode_escape(&mut self) -> Result<u32, StorageError> {
-        let mut value = 0u32;
-        let mut index = 0usize;
-        while index < 4 {
-            let byte = self.next_byte()?.ok_or(StorageError::CorruptData)?;
-            value = (value << 4) | hex_value(byte)? as u32;
-            index += 1;
-        }
-        Ok(value)
-    }
-}
-
-fn parse_opened_reader_content<S: JsonSource>(
-    source: S,
-) -> Result<OpenedReaderContent, StorageError> {
-    let mut stream = JsonStream::new(source);
-    let mut title = InlineText::new();
-    let mut document = ReadingDocument::boxed_empty();
-    let mut truncated = false;
-    let mut content_found = false;
-    let mut body_found = false;
-    let mut blocks_found = false;
-    let mut body_kind_supported = false;
-
-    let parse_result = stream.parse_object_fields(|stream, key| match key.as_str() {
-        "content" => {
-            content_found = true;
-            stream.parse_object_fields(|stream, key| match key.as_str() {
-                "title" => {
-                    let parsed = stream.parse_string_limited(MAX_PARSED_TITLE_BYTES)?;
-                    if parsed.truncated {
-                        truncated = true;
-                    }
-                    title.set_truncated(parsed.value.as_str());
-                    Ok(())
-                }
-                "body" => {
-                    body_found = true;
-                    stream.parse_object_fields(|stream, key| match key.as_str() {
-                        "kind" => {
-                            let parsed = stream.parse_string_limited(16)?;
-                            if parsed.truncated {
-                                truncated = true;
-                            }
-                            body_kind_supported =
-                                is_supported_reader_body_kind(parsed.value.as_str());
-                            Ok(())
-                        }
-                        "blocks" => {
-                            blocks_found = true;
-                            stream.parse_array_values(|stream, first| {
-                                if first != b'{' {
-                                    return Err(StorageError::CorruptData);
-                                }
-                                stream.unread_byte(first);
-                                let block = BlockDraft::parse(stream)?;
-                                if block.apply(&mut document) {
-                                    truncated = true;
-                                }
-                                Ok(())
-                            })
-                        }
-                        _ => stream.skip_value(),
-                    })
-                }
-                _ => stream.skip_value(),
-            })
-        }
-        _ => stream.skip_value(),
-    });
-    if let Err(err) = parse_result {
-        info!(
-            "content storage reader parse stream error content_found={} body_found={} blocks_found={} body_kind_supported={} title_empty={} document_empty={} truncated={} err={:?}",
-            content_found,
-            body_found,
-            blocks_found,
-            body_kind_supported,
-            title.is_empty(),
-            document.is_empty(),
-            truncated,
-            err,
-        );
-        return Err(err);
-    }
-
-    if !content_found || !body_found || !blocks_found || !body_kind_supported || document.is_empty()
-    {
-        info!(
-            "content storage reader parse rejected content_found={} body_found={} blocks_found={} body_kind_supported={} title_empty={} document_empty={} truncated={}",
-            content_found,
-            body_found,
-            blocks_found,
-            body_kind_supported,
-            title.is_empty(),
-            document.is_empty(),
-            truncated,
-        );
-        return Err(StorageError::CorruptData);
-    }
-
-    if title.is_empty() {
-        title.set_truncated("UNTITLED ARTICLE");
-    }
-
-    Ok(OpenedReaderContent {
-        title,
-        document,
-        truncated,
-    })
-}
-
-fn is_supported_reader_body_kind(kind: &str) -> bool {
-    matches!(
-        kind,
-        "compact" | "article" | "thread" | "post" | "website" | "video" | "podcast" | "pdf"
-    )
-}
-
-fn push_limited_char(target: &mut String, ch: char, max_bytes: usize, truncated: &mut bool) {
-    let mut utf8 = [0u8; 4];
-    let encoded = ch.encode_utf8(&mut utf8);
-    push_limited_str(target, encoded, max_bytes, truncated);
-}
-
-fn push_limited_str(target: &mut String, value: &str, max_bytes: usize, truncated: &mut bool) {
-    if *truncated || value.is_empty() {
-        return;
-    }
-
-    if target.len().saturating_add(value.len()) > max_bytes {
-        *truncated = true;
-        return;
-    }
-
-    target.push_str(value);
-}
-
-fn utf8_continuation_len(first: u8) -> Result<usize, StorageError> {
-    match first {
-        0xC2..=0xDF => Ok(1),
-        0xE0..=0xEF => Ok(2),
-        0xF0..=0xF4 => Ok(3),
-        _ => Err(StorageError::CorruptData),
-    }
-}
-
-fn hex_value(byte: u8) -> Result<u8, StorageError> {
-    match byte {
-        b'0'..=b'9' => Ok(byte - b'0'),
-        b'a'..=b'f' => Ok(byte - b'a' + 10),
-        b'A'..=b'F' => Ok(byte - b'A' + 10),
-        _ => Err(StorageError::CorruptData),
-    }
-}
-
-fn format_list_line(item: &str, ordered: bool, index: usize) -> String {
-    let mut line = String::new();
-    if ordered {
-        let number = index + 1;
-        if number >= 10 {
-            let hundreds = (number / 100) % 10;
-            if hundreds > 0 {
-                line.push((b'0' + hundreds as u8) as char);
-            }
-            let tens = (number / 10) % 10;
-            line.push((b'0' + tens as u8) as char);
-        }
-        line.push((b'0' + (number % 10) as u8) as char);
-        line.push_str(". ");
-    } else {
-        line.push_str("- ");
-    }
-    line.push_str(item);
-    line
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5073,6 +5261,70 @@ mod tests {
         assert_eq!(decoded, progress);
     }
 
+    #[test]
+    fn bookmarks_round_trip() {
+        let mut bookmarks = BookmarkState::empty();
+        for paragraph_index in [4, 9] {
+            let mut bookmark = Bookmark::empty();
+            bookmark.content_id.set_truncated("content-1");
+            bookmark.remote_revision = 42;
+            bookmark.paragraph_index = paragraph_index;
+            bookmarks.insert(bookmark);
+        }
+
+        let mut encoded = [0u8; MAX_BOOKMARKS_LEN];
+        let encoded_len = encode_bookmarks(&bookmarks, &mut encoded).unwrap();
+        let decoded = decode_bookmarks(&encoded[..encoded_len]).unwrap();
+
+        assert_eq!(decoded, bookmarks);
+    }
+
+    #[test]
+    fn history_round_trip() {
+        let mut history = HistoryState::empty();
+        for (words, ended_at) in [
+            (320, None),
+            (
+                1_450,
+                Some(HistoryStamp {
+                    day: 9_785,
+                    minute: 1_290,
+                }),
+            ),
+        ] {
+            let mut entry = HistoryEntry::empty();
+            entry.content_id.set_truncated("content-1");
+            entry.title.set_truncated("Rust for embedded systems");
+            entry.paragraph_index = 12;
+            entry.ended_at = ended_at;
+            entry.words = words;
+            history.push(entry);
+        }
+
+        let mut encoded = [0u8; MAX_HISTORY_LEN];
+        let encoded_len = encode_history(&history, &mut encoded).unwrap();
+        let decoded = decode_history(&encoded[..encoded_len]).unwrap();
+
+        assert_eq!(decoded, history);
+    }
+
+    #[test]
+    fn book_stats_round_trip() {
+        let mut book_stats = BookStatsState::empty();
+        let mut content_id = InlineText::new();
+        content_id.set_truncated("content-1");
+        book_stats.record_finish(content_id);
+        book_stats.record_finish(content_id);
+        content_id.set_truncated("content-2");
+        book_stats.toggle_finished(content_id);
+
+        let mut encoded = [0u8; MAX_BOOK_STATS_LEN];
+        let encoded_len = encode_book_stats(&book_stats, &mut encoded).unwrap();
+        let decoded = decode_book_stats(&encoded[..encoded_len]).unwrap();
+
+        assert_eq!(decoded, book_stats);
+    }
+
     #[test]
     fn recommendation_subtopics_round_trip() {
         let subtopics = make_recommendation_subtopics();
@@ -5160,7 +5412,7 @@ mod tests {
     }
 
     #[test]
-    fn bootstrap_content_state_keeps_inbox_when_saved_is_empty() {
+    fn library_scan_events_keep_inbox_when_saved_is_empty() {
         let saved = CollectionManifestState::empty();
         let mut inbox = CollectionManifestState::empty();
         let mut item = CollectionManifestItem::empty();
@@ -5169,14 +5421,23 @@ mod tests {
         item.title.set_truncated("Inbox article");
         assert!(inbox.try_push(item));
 
-        let state = bootstrap_content_state_from_snapshots(saved, inbox).unwrap();
-
-        assert!(state.saved.is_none());
-        assert_eq!(
-            state.inbox.as_ref().map(|collection| collection.len()),
-            Some(1)
+        let events = library_scan_events(
+            saved,
+            inbox,
+            ReadingProgressState::empty(),
+            BookmarkState::empty(),
+            HistoryState::empty(),
+            BookStatsState::empty(),
+            RecommendationSubtopicsState::empty(),
         );
-        assert!(state.recommendations.is_none());
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], Event::StoredReadingStateLoaded { .. }));
+        assert!(matches!(
+            &events[1],
+            Event::CollectionContentUpdated(CollectionKind::Inbox, collection)
+                if collection.len() == 1
+        ));
     }
 
     #[test]
@@ -5211,95 +5472,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn reader_content_parser_opens_backend_article_payload() {
-        let payload = br#"{
-            "content": {
-                "title": "Example article",
-                "body": {
-                    "kind": "article",
-                    "blocks": [
-                        {"x": "First paragraph for Motif.", "t": "p"},
-                        {"i": ["Alpha", "Beta"], "o": true, "t": "l"}
-                    ]
-                }
-            }
-        }"#;
-
-        let opened = parse_reader_content_bytes(payload).unwrap();
-
-        assert_eq!(opened.title.as_str(), "Example article");
-        assert!(!opened.truncated);
-        assert_eq!(opened.document.paragraph_count, 3);
-        assert_eq!(
-            opened.document.preview_for_paragraph(1).as_str(),
-            "First paragraph for Motif."
-        );
-    }
-
-    #[test]
-    fn reader_content_parser_keeps_legacy_compact_kind_compatibility() {
-        let payload = br#"{
-            "content": {
-                "title": "Legacy article",
-                "body": {
-                    "kind": "compact",
-                    "blocks": [{"x": "Legacy paragraph.", "t": "p"}]
-                }
-            }
-        }"#;
-
-        let opened = parse_reader_content_bytes(payload).unwrap();
-
-        assert_eq!(opened.title.as_str(), "Legacy article");
-        assert_eq!(opened.document.paragraph_count, 1);
-        assert_eq!(
-            opened.document.preview_for_paragraph(1).as_str(),
-            "Legacy paragraph."
-        );
-    }
-
-    #[test]
-    fn reader_content_parser_rejects_invalid_kind() {
-        let payload = br#"{
-            "content": {
-                "title": "Broken",
-                "body": {
-                    "kind": "full",
-                    "blocks": [{"t": "p", "x": "ignored"}]
-                }
-            }
-        }"#;
-
-        assert_eq!(
-            parse_reader_content_bytes(payload).unwrap_err(),
-            StorageError::CorruptData
-        );
-    }
-
-    #[test]
-    fn reader_content_parser_truncates_oversized_payloads_safely() {
-        let mut payload =
-            String::from(r#"{"content":{"title":"Oversized","body":{"kind":"compact","blocks":["#);
-
-        let mut index = 0usize;
-        while index < 40 {
-            if index > 0 {
-                payload.push(',');
-            }
-            payload.push_str(r#"{"t":"p","x":"alpha beta gamma delta epsilon zeta eta theta iota kappa lambda mu nu xi omicron pi rho sigma tau upsilon phi chi psi omega alpha beta gamma delta epsilon zeta eta theta iota kappa lambda mu nu xi omicron pi rho sigma tau upsilon phi chi psi omega"}"#);
-            index += 1;
-        }
-
-        payload.push_str("]}}}");
-        let opened = parse_reader_content_bytes(payload.as_bytes()).unwrap();
-
-        assert!(opened.truncated);
-        assert!(opened.document.unit_count as usize <= MAX_READING_UNITS);
-        assert!(opened.document.paragraph_count as usize <= MAX_READING_PARAGRAPHS);
-        assert!(!opened.document.is_empty());
-    }
-
     #[test]
     fn reader_content_and_session_sizes_stay_bounded() {
         assert!(size_of::<OpenedReaderContent>() < 256);
@@ -5318,4 +5490,13 @@ mod tests {
             StorageError::CorruptData
         );
     }
+
+    #[test]
+    fn screenshot_names_count_up_and_parse_back() {
+        assert_eq!(screenshot_file_name(7).as_str(), "SHOT0007.PBM");
+        assert_eq!(screenshot_number(b"SHOT0007"), Some(7));
+        assert_eq!(screenshot_number(b"SHOT"), None);
+        assert_eq!(screenshot_number(b"SHOTX1"), None);
+        assert_eq!(screenshot_number(b"P0000001"), None);
+    }
 }
@@ -0,0 +1,156 @@
+use core::{fmt::Write, panic::PanicInfo, ptr::addr_of_mut};
+
+use ::domain::{device::CRASH_REPORT_MAX_BYTES, text::InlineText};
+use esp_hal::{
+    delay::Delay,
+    gpio::{Level, Output, OutputConfig},
+    peripherals::Peripherals,
+    spi::master::Spi,
+    time::Rate,
+};
+use ls027b7dh01::FrameBuffer;
+
+use crate::{
+    board::{self, BoardConfig},
+    bootstrap::DISPLAY_SPI_HZ,
+    display::PlatformDisplay,
+};
+
+const CRASH_RECORD_MAGIC: u32 = 0x4D43_5253;
+const PANIC_DISPLAY_HOLD_MS: u32 = 5_000;
+
+struct CrashRecord {
+    magic: u32,
+    len: u16,
+    bytes: [u8; CRASH_REPORT_MAX_BYTES],
+}
+
+// RTC fast memory survives the software reset issued after a panic, so the next boot can read
+// the record before anything else touches it. A power loss clears it along with the magic.
+#[esp_hal::ram(unstable(rtc_fast, persistent))]
+static mut CRASH_RECORD: CrashRecord = CrashRecord {
+    magic: 0,
+    len: 0,
+    bytes: [0; CRASH_REPORT_MAX_BYTES],
+};
+
+// Kept out of the panicking stack; the app frame buffers may be mid-draw.
+static mut PANIC_FRAME: FrameBuffer = FrameBuffer::new();
+
+struct CrashWriter {
+    bytes: [u8; CRASH_REPORT_MAX_BYTES],
+    len: usize,
+}
+
+impl Write for CrashWriter {
+    fn write_str(&mut self, text: &str) -> core::fmt::Result {
+        for ch in text.chars() {
+            let mut encoded = [0u8; 4];
+            let encoded = ch.encode_utf8(&mut encoded).as_bytes();
+            if self.len + encoded.len() > self.bytes.len() {
+                return Err(core::fmt::Error);
+            }
+            self.bytes[self.len..self.len + encoded.len()].copy_from_slice(encoded);
+            self.len += encoded.len();
+        }
+        Ok(())
+    }
+}
+
+impl CrashWriter {
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+fn format_panic(info: &PanicInfo) -> CrashWriter {
+    let mut writer = CrashWriter {
+        bytes: [0; CRASH_REPORT_MAX_BYTES],
+        len: 0,
+    };
+    if let Some(location) = info.location() {
+        let _ = write!(writer, "{}:{}: ", location.file(), location.line());
+    }
+    let _ = write!(writer, "{}", info.message());
+    writer
+}
+
+pub fn record_panic(info: &PanicInfo) {
+    let writer = format_panic(info);
+
+    // Single-core at this point: the panic handler never returns, so nothing races the record.
+    // The magic is written last so a panic inside this function leaves no half-written report.
+    unsafe {
+        let record = addr_of_mut!(CRASH_RECORD);
+        (*record).magic = 0;
+        (*record).bytes = writer.bytes;
+        (*record).len = writer.len as u16;
+        (*record).magic = CRASH_RECORD_MAGIC;
+    }
+}
+
+pub fn take_previous_crash() -> Option<InlineText<CRASH_REPORT_MAX_BYTES>> {
+    let (magic, len, bytes) = unsafe {
+        let record = addr_of_mut!(CRASH_RECORD);
+        let snapshot = ((*record).magic, (*record).len, (*record).bytes);
+        (*record).magic = 0;
+        snapshot
+    };
+    if magic != CRASH_RECORD_MAGIC {
+        return None;
+    }
+
+    let len = usize::from(len).min(CRASH_REPORT_MAX_BYTES);
+    let text = match core::str::from_utf8(&bytes[..len]) {
+        Ok(text) => text,
+        Err(err) => core::str::from_utf8(&bytes[..err.valid_up_to()]).unwrap_or_default(),
+    };
+    Some(InlineText::from_slice(text))
+}
+
+// Last-gasp render before the reset: steals the display pins and SPI2 from whatever task owned
+// them, draws the report with the fixed fonts and holds it long enough to read. Every failure is
+// ignored because the reset follows regardless.
+pub fn render_panic(info: &PanicInfo) {
+    let report = format_panic(info);
+    let peripherals = unsafe { Peripherals::steal() };
+
+    let board = BoardConfig::new();
+    let disp = Output::new(
+        board::pin(board.display_disp_gpio),
+        Level::Low,
+        OutputConfig::default(),
+    );
+    let emd = Output::new(
+        board::pin(board.display_emd_gpio),
+        Level::Low,
+        OutputConfig::default(),
+    );
+    let cs = Output::new(
+        board::pin(board.display_cs_gpio),
+        Level::Low,
+        OutputConfig::default(),
+    );
+    let spi_config = esp_hal::spi::master::Config::default()
+        .with_frequency(Rate::from_hz(DISPLAY_SPI_HZ))
+        .with_mode(esp_hal::spi::Mode::_1);
+    let Ok(spi) = Spi::new(peripherals.SPI2, spi_config) else {
+        return;
+    };
+    let spi = spi
+        .with_sck(board::pin(board.display_clk_gpio))
+        .with_mosi(board::pin(board.display_di_gpio));
+
+    let mut delay = Delay::new();
+    let mut display = PlatformDisplay::new(spi, disp, emd, cs);
+    let frame = unsafe { &mut *addr_of_mut!(PANIC_FRAME) };
+    renderer::draw_panic_message(frame, report.as_str());
+
+    if display.initialize(&mut delay).is_err() {
+        return;
+    }
+    if display.present_full(frame, &mut delay).is_err() {
+        return;
+    }
+    delay.delay_millis(PANIC_DISPLAY_HOLD_MS);
+}
@@ -31,18 +31,6 @@ pub struct DisplayPresentStats {
     pub full_refresh: bool,
 }
 
-pub fn diff_dirty_rows(committed: &FrameBuffer, working: &FrameBuffer) -> DirtyRows {
-    let mut dirty_rows = DirtyRows::new();
-
-    for row in 0..HEIGHT {
-        if committed.row(row) != working.row(row) {
-            let _ = dirty_rows.mark_row(row);
-        }
-    }
-
-    dirty_rows
-}
-
 pub struct PlatformDisplay<SPI, DISP, EMD, CS> {
     spi: SPI,
     disp: DISP,
@@ -156,6 +144,17 @@ where
         })
     }
 
+    pub fn present_full<D>(
+        &mut self,
+        frame: &FrameBuffer,
+        delay: &mut D,
+    ) -> DisplayResult<SPI::Error, DISP::Error, EMD::Error, CS::Error>
+    where
+        D: DelayNs,
+    {
+        self.flush_full_frame(frame, delay)
+    }
+
     pub fn disable_output(
         &mut self,
     ) -> DisplayResult<SPI::Error, DISP::Error, EMD::Error, CS::Error> {
@@ -255,6 +254,7 @@ where
 mod tests {
     use super::*;
     use core::convert::Infallible;
+    use ls027b7dh01::diff_dirty_rows;
     use std::{cell::RefCell, rc::Rc, vec::Vec};
 
     #[derive(Clone, Default)]
@@ -320,20 +320,6 @@ mod tests {
         fn delay_ns(&mut self, _ns: u32) {}
     }
 
-    #[test]
-    fn diff_dirty_rows_marks_only_changed_rows() {
-        let committed = FrameBuffer::new();
-        let mut working = FrameBuffer::new();
-        working.fill_rect(0, 12, 16, 2, true);
-
-        let dirty = diff_dirty_rows(&committed, &working);
-
-        assert_eq!(dirty.count(), 2);
-        assert!(dirty.is_dirty_row(12));
-        assert!(dirty.is_dirty_row(13));
-        assert!(!dirty.is_dirty_row(11));
-    }
-
     #[test]
     fn partial_present_writes_only_dirty_rows_and_updates_committed() {
         let spi = MockSpi::default();
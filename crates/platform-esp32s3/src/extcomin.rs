@@ -0,0 +1,65 @@
+extern crate alloc;
+
+use alloc::boxed::Box;
+
+use esp_hal::{
+    gpio::{AnyPin, DriveMode, Level, Output, OutputConfig},
+    ledc::{
+        Ledc, LowSpeed,
+        channel::{self, ChannelIFace},
+        timer::{self, TimerIFace},
+    },
+    time::Rate,
+};
+
+#[derive(Debug)]
+pub enum ExtcominError {
+    Timer(timer::Error),
+    Channel(channel::Error),
+}
+
+// Drives EXTCOMIN as a 50% square wave from an LEDC channel, so COM inversion keeps going while
+// the main loop is blocked on SD I/O. The panel must have EXTMODE tied high for it to listen to
+// this pin instead of the VCOM bit in the SPI header. LEDC runs off APB, so the wave pauses
+// during light sleep; the sleep window is capped by the display heartbeat deadline.
+pub struct LedcExtcomin {
+    _channel: channel::Channel<'static, LowSpeed>,
+}
+
+impl LedcExtcomin {
+    pub fn start(
+        ledc: &'static Ledc<'static>,
+        pin: AnyPin<'static>,
+        hz: u32,
+    ) -> Result<Self, ExtcominError> {
+        // The channel borrows its timer and the square wave runs until the next deep sleep, so
+        // the timer is leaked rather than threaded through.
+        let timer = Box::leak(Box::new(ledc.timer::<LowSpeed>(timer::Number::Timer0)));
+        timer
+            .configure(timer::config::Config {
+                duty: timer::config::Duty::Duty14Bit,
+                clock_source: timer::LSClockSource::APBClk,
+                frequency: Rate::from_hz(hz),
+            })
+            .map_err(ExtcominError::Timer)?;
+
+        let mut channel = ledc.channel(channel::Number::Channel0, pin);
+        channel
+            .configure(channel::config::Config {
+                timer: &*timer,
+                duty_pct: 50,
+                drive_mode: DriveMode::PushPull,
+            })
+            .map_err(ExtcominError::Channel)?;
+
+        Ok(Self { _channel: channel })
+    }
+}
+
+// Hands the pin back to plain GPIO at a low level so the deep-sleep pad hold latches low rather
+// than whatever phase the square wave was in.
+pub fn park_low(gpio: u8) {
+    let pin = unsafe { AnyPin::steal(gpio) };
+    let output = Output::new(pin, Level::Low, OutputConfig::default());
+    core::mem::forget(output);
+}
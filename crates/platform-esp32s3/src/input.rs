@@ -1,11 +1,12 @@
-use ::domain::input::{InputGesture, RotationDirection};
+use ::domain::input::{EncoderSensitivity, InputGesture, RotationDirection};
 use ::services::input::InputService;
-use esp_hal::gpio::{AnyPin, Event as GpioEvent, Input, InputConfig, Pull};
+use esp_hal::gpio::{
+    AnyPin, Event as GpioEvent, Input, InputConfig, Pull, WakeConfigError, WakeEvent,
+};
 
 const INPUT_QUEUE_CAPACITY: usize = 16;
 const BUTTON_DEBOUNCE_MS: u64 = 20;
 const LONG_PRESS_MS: u64 = 600;
-const DETENT_DELTA: i8 = 2;
 const ROTARY_TRANSITIONS: [i8; 16] = [0, -1, 1, 0, 1, 0, 0, -1, -1, 0, 0, 1, 0, 1, -1, 0];
 
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
@@ -65,6 +66,13 @@ impl<'d> PlatformInputService<'d> {
         self.sample_button(now_ms);
     }
 
+    // Applied when settings load and on every settings write; a half-counted step is dropped so
+    // the new threshold starts from a detent.
+    pub fn configure_encoder(&mut self, sensitivity: EncoderSensitivity) {
+        self.encoder_state.transitions_per_step = sensitivity.transitions_per_step();
+        self.encoder_state.accumulated_delta = 0;
+    }
+
     pub fn take_dropped_gesture_count(&mut self) -> u32 {
         let dropped = self.dropped_gestures;
         self.dropped_gestures = 0;
@@ -79,6 +87,40 @@ impl<'d> PlatformInputService<'d> {
             .expect("wake button pin can only be taken once")
     }
 
+    pub fn is_idle(&self) -> bool {
+        self.queue.len == 0 && self.button_state.is_settled()
+    }
+
+    // Light sleep stops the 2 ms poll, so every input pin wakes the CPU on the level it is not
+    // at now. The first sample after waking then sees the edge the poll would have caught.
+    pub fn arm_light_sleep_wake(&mut self) -> Result<(), WakeConfigError> {
+        for pin in [
+            &mut self.encoder_clk,
+            &mut self.encoder_dt,
+            &mut self.button,
+        ] {
+            let event = if pin.is_high() {
+                WakeEvent::LowLevel
+            } else {
+                WakeEvent::HighLevel
+            };
+            pin.wakeup_enable(true, event)?;
+        }
+        Ok(())
+    }
+
+    pub fn disarm_light_sleep_wake(&mut self) {
+        for pin in [
+            &mut self.encoder_clk,
+            &mut self.encoder_dt,
+            &mut self.button,
+        ] {
+            let _ = pin.wakeup_enable(false, WakeEvent::LowLevel);
+        }
+        self.button.clear_interrupt();
+        self.button.listen(GpioEvent::AnyEdge);
+    }
+
     pub fn reset_after_reader_open(&mut self) -> InputResetSummary {
         let button_was_pressed = self.button.is_low();
         self.button.clear_interrupt();
@@ -91,8 +133,11 @@ impl<'d> PlatformInputService<'d> {
         };
 
         self.dropped_gestures = 0;
-        self.encoder_state =
-            EncoderState::new(sample_encoder_inputs(&self.encoder_clk, &self.encoder_dt));
+        self.encoder_state = EncoderState {
+            previous_sample: sample_encoder_inputs(&self.encoder_clk, &self.encoder_dt),
+            accumulated_delta: 0,
+            ..self.encoder_state
+        };
         self.button_state
             .reset_after_reader_open(button_was_pressed);
 
@@ -198,6 +243,7 @@ impl GestureQueue {
 struct EncoderState {
     previous_sample: u8,
     accumulated_delta: i8,
+    transitions_per_step: i8,
 }
 
 impl EncoderState {
@@ -205,6 +251,7 @@ impl EncoderState {
         Self {
             previous_sample: initial_sample,
             accumulated_delta: 0,
+            transitions_per_step: EncoderSensitivity::Normal.transitions_per_step(),
         }
     }
 
@@ -219,12 +266,12 @@ impl EncoderState {
 
         self.accumulated_delta += delta;
 
-        if self.accumulated_delta <= -DETENT_DELTA {
+        if self.accumulated_delta <= -self.transitions_per_step {
             self.accumulated_delta = 0;
             return Some(RotationDirection::CounterClockwise);
         }
 
-        if self.accumulated_delta >= DETENT_DELTA {
+        if self.accumulated_delta >= self.transitions_per_step {
             self.accumulated_delta = 0;
             return Some(RotationDirection::Clockwise);
         }
@@ -255,6 +302,10 @@ impl ButtonState {
         }
     }
 
+    const fn is_settled(&self) -> bool {
+        self.candidate_pressed.is_none() && !self.stable_pressed
+    }
+
     fn needs_resync(&self, current_pressed: bool) -> bool {
         self.candidate_pressed.is_none() && current_pressed != self.stable_pressed
     }
@@ -371,4 +422,16 @@ mod tests {
             Some(RotationDirection::CounterClockwise)
         );
     }
+    #[test]
+    fn sensitivity_sets_the_transitions_per_step() {
+        let mut state = EncoderState::new(0b00);
+        state.transitions_per_step = EncoderSensitivity::High.transitions_per_step();
+        assert_eq!(state.sample(0b10), Some(RotationDirection::Clockwise));
+
+        state.transitions_per_step = EncoderSensitivity::Low.transitions_per_step();
+        assert_eq!(state.sample(0b11), None);
+        assert_eq!(state.sample(0b01), None);
+        assert_eq!(state.sample(0b00), None);
+        assert_eq!(state.sample(0b10), Some(RotationDirection::Clockwise));
+    }
 }
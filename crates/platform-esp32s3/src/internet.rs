@@ -5,8 +5,10 @@ use core::net::{IpAddr, Ipv4Addr};
 use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 use embassy_executor::Spawner;
+use embassy_futures::select::{Either, select};
 use embassy_net::{Runner, Stack, StackResources, dns::DnsSocket, tcp::TcpSocket};
-use embassy_time::{Duration, Instant, Timer};
+use embassy_sync::{blocking_mutex::raw::CriticalSectionRawMutex, signal::Signal};
+use embassy_time::{Duration, Instant, Timer, with_timeout};
 use embedded_nal_async::{AddrType, Dns as _};
 use esp_hal::{peripherals::WIFI, rng::Rng};
 use esp_radio::wifi::{
@@ -31,6 +33,7 @@ const RECONNECT_BACKOFF_MS: u64 = 5_000;
 const NETWORK_STACK_SOCKET_CAPACITY: usize = 4;
 const WIFI_COUNTRY_CODE: [u8; 2] = *b"ES";
 const WIFI_POWER_SAVE_MODE: PowerSaveMode = PowerSaveMode::None;
+const WIFI_SHUTDOWN_TIMEOUT_MS: u64 = 1_000;
 
 static PROBE_SUSPENDED: AtomicBool = AtomicBool::new(false);
 static BACKEND_PATH_READY: AtomicBool = AtomicBool::new(false);
@@ -40,6 +43,9 @@ static BACKEND_ENDPOINT_CACHE_VALID: AtomicBool = AtomicBool::new(false);
 static BACKEND_ENDPOINT_CACHE_IP: AtomicU32 = AtomicU32::new(0);
 static BACKEND_ENDPOINT_CACHE_SESSION_EPOCH: AtomicU32 = AtomicU32::new(0);
 static BACKEND_ENDPOINT_CACHE_SET_AT_MS: AtomicU32 = AtomicU32::new(0);
+static CONNECTION_TASK_RUNNING: AtomicBool = AtomicBool::new(false);
+static WIFI_SHUTDOWN_REQUEST: Signal<CriticalSectionRawMutex, ()> = Signal::new();
+static WIFI_SHUTDOWN_DONE: Signal<CriticalSectionRawMutex, ()> = Signal::new();
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub(crate) struct CachedBackendEndpoint {
@@ -291,7 +297,51 @@ async fn connection_task(mut controller: WifiController<'static>, credentials: W
         credentials.passphrase.len()
     );
     info!("internet wifi capabilities={:?}", controller.capabilities());
+    CONNECTION_TASK_RUNNING.store(true, Ordering::Relaxed);
 
+    if let Either::First(()) = select(
+        WIFI_SHUTDOWN_REQUEST.wait(),
+        maintain_connection(&mut controller, credentials),
+    )
+    .await
+    {
+        info!("internet stopping wifi for sleep");
+        if let Err(err) = controller.stop_async().await {
+            warn!("internet wifi stop failed: {:?}", err);
+        }
+        invalidate_backend_path("wifi_stopped_for_sleep");
+        CONNECTION_TASK_RUNNING.store(false, Ordering::Relaxed);
+        WIFI_SHUTDOWN_DONE.signal(());
+    }
+}
+
+pub(crate) fn radio_is_idle() -> bool {
+    !CONNECTION_TASK_RUNNING.load(Ordering::Relaxed)
+}
+
+// Stops the radio before deep sleep so the access point sees a clean disconnect. Returns at once
+// when Wi-Fi never started, and gives up after a second so a wedged driver cannot block sleep.
+pub(crate) async fn shutdown_for_sleep() {
+    if !CONNECTION_TASK_RUNNING.load(Ordering::Relaxed) {
+        return;
+    }
+
+    WIFI_SHUTDOWN_REQUEST.signal(());
+    if with_timeout(
+        Duration::from_millis(WIFI_SHUTDOWN_TIMEOUT_MS),
+        WIFI_SHUTDOWN_DONE.wait(),
+    )
+    .await
+    .is_err()
+    {
+        warn!("internet wifi stop timed out before sleep");
+    }
+}
+
+async fn maintain_connection(
+    controller: &mut WifiController<'static>,
+    credentials: WifiCredentials,
+) -> ! {
     loop {
         if matches!(esp_radio::wifi::sta_state(), WifiStaState::Connected) {
             controller.wait_for_event(WifiEvent::StaDisconnected).await;
@@ -3,15 +3,26 @@
 
 pub mod backend;
 pub mod board;
+pub mod book_storage;
 pub mod bootstrap;
+#[cfg(feature = "buzzer-ledc")]
+pub mod buzzer;
+#[cfg(feature = "usb-cli")]
+pub mod cli;
+pub mod clock;
 pub mod content_storage;
+pub mod crash_log;
 pub mod display;
+#[cfg(feature = "extcomin-ledc")]
+pub mod extcomin;
 pub mod input;
 pub mod internet;
+pub mod light;
 pub mod memory_policy;
-pub mod renderer;
+pub mod power;
 pub mod services;
 pub mod sleep;
 pub mod storage;
 pub mod telemetry;
 pub mod transfer_tuning;
+pub mod watchdog;
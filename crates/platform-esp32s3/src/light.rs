@@ -0,0 +1,41 @@
+use ::services::light::{AmbientLightSensor, LightSensorError, VEML7700_CONFIG, veml7700_lux};
+
+// Every five seconds: quick enough to follow a lamp switched on, and each read is one short
+// I2C transfer.
+pub const LIGHT_SAMPLE_INTERVAL_MS: u64 = 5_000;
+
+const VEML7700_ADDRESS: u8 = 0x10;
+const VEML7700_CONFIG_REG: u8 = 0x00;
+const VEML7700_ALS_REG: u8 = 0x04;
+
+pub struct Veml7700Sensor<I2C> {
+    i2c: I2C,
+}
+
+impl<I2C> Veml7700Sensor<I2C>
+where
+    I2C: embedded_hal::i2c::I2c,
+{
+    // Writes the configuration, which also powers the sensor on; `None` when nothing answers.
+    pub fn probe(mut i2c: I2C) -> Option<Self> {
+        let [low, high] = VEML7700_CONFIG.to_le_bytes();
+        i2c.write(VEML7700_ADDRESS, &[VEML7700_CONFIG_REG, low, high])
+            .ok()?;
+
+        Some(Self { i2c })
+    }
+}
+
+impl<I2C> AmbientLightSensor for Veml7700Sensor<I2C>
+where
+    I2C: embedded_hal::i2c::I2c,
+{
+    fn lux(&mut self) -> Result<u32, LightSensorError> {
+        let mut counts = [0u8; 2];
+        self.i2c
+            .write_read(VEML7700_ADDRESS, &[VEML7700_ALS_REG], &mut counts)
+            .map_err(|_| LightSensorError::Bus)?;
+
+        Ok(veml7700_lux(u16::from_le_bytes(counts)))
+    }
+}
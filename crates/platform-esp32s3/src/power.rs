@@ -0,0 +1,168 @@
+use core::cell::Cell;
+
+use ::domain::power::{
+    CpuDemand, CpuSpeed, PowerPolicy, PowerStatus, battery_percent_from_millivolts,
+};
+use ::services::power::{PowerMonitor, PowerMonitorError};
+use embassy_sync::blocking_mutex::{Mutex, raw::CriticalSectionRawMutex};
+use esp_hal::peripherals::SYSTEM;
+use log::info;
+
+pub const POWER_SAMPLE_INTERVAL_MS: u64 = 30_000;
+
+static CPU_POLICY: Mutex<CriticalSectionRawMutex, Cell<PowerPolicy>> =
+    Mutex::new(Cell::new(PowerPolicy::new()));
+
+const MAX17048_ADDRESS: u8 = 0x36;
+const MAX17048_SOC_REG: u8 = 0x04;
+const MAX17048_CRATE_REG: u8 = 0x16;
+
+// The cell sits behind two equal resistors so the ADC never sees more than half of 4.2 V.
+const BATTERY_DIVIDER_RATIO: u32 = 2;
+const BATTERY_ADC_SAMPLES: u32 = 8;
+
+#[cfg(feature = "battery-adc")]
+pub use adc::AdcBatteryMonitor;
+
+#[cfg(feature = "battery-adc")]
+mod adc {
+    use super::*;
+    use esp_hal::{
+        Blocking,
+        analog::adc::{Adc, AdcCalCurve, AdcConfig, AdcPin, Attenuation},
+        gpio::{Input, InputConfig, Pull},
+        peripherals::{ADC2, GPIO7, GPIO16},
+    };
+
+    type BatteryPin = AdcPin<GPIO16<'static>, ADC2<'static>, AdcCalCurve<ADC2<'static>>>;
+
+    // Voltage divider on GPIO16 plus the charger's open-drain status line on GPIO7, which the
+    // charger pulls low while current flows into the cell. ADC1 already seeds the TRNG, so this
+    // shares ADC2 with the radio; a sample the radio blocks comes back as a bus error and the
+    // next interval tries again.
+    pub struct AdcBatteryMonitor {
+        adc: Adc<'static, ADC2<'static>, Blocking>,
+        pin: BatteryPin,
+        charge_status: Input<'static>,
+    }
+
+    impl AdcBatteryMonitor {
+        pub fn mount(adc: ADC2<'static>, sense: GPIO16<'static>, charge: GPIO7<'static>) -> Self {
+            let mut config = AdcConfig::new();
+            let pin = config
+                .enable_pin_with_cal::<_, AdcCalCurve<ADC2<'static>>>(sense, Attenuation::_11dB);
+            Self {
+                adc: Adc::new(adc, config),
+                pin,
+                charge_status: Input::new(charge, InputConfig::default().with_pull(Pull::Up)),
+            }
+        }
+
+        fn read_millivolts(&mut self) -> Result<u32, PowerMonitorError> {
+            let mut total = 0u32;
+            for _ in 0..BATTERY_ADC_SAMPLES {
+                let sample = nb::block!(self.adc.read_oneshot(&mut self.pin))
+                    .map_err(|_| PowerMonitorError::Bus)?;
+                total += u32::from(sample);
+            }
+            Ok(total / BATTERY_ADC_SAMPLES * BATTERY_DIVIDER_RATIO)
+        }
+    }
+
+    impl PowerMonitor for AdcBatteryMonitor {
+        fn read(&mut self) -> Result<PowerStatus, PowerMonitorError> {
+            let millivolts = self.read_millivolts()?;
+            if millivolts == 0 {
+                return Err(PowerMonitorError::InvalidReading);
+            }
+
+            let percent = battery_percent_from_millivolts(millivolts.min(u16::MAX as u32) as u16);
+            Ok(PowerStatus::new(percent).with_charging(self.charge_status.is_low()))
+        }
+    }
+}
+
+pub struct Max17048Monitor<I2C> {
+    i2c: I2C,
+}
+
+impl<I2C> Max17048Monitor<I2C>
+where
+    I2C: embedded_hal::i2c::I2c,
+{
+    pub const fn new(i2c: I2C) -> Self {
+        Self { i2c }
+    }
+
+    fn read_register(&mut self, register: u8) -> Result<u16, PowerMonitorError> {
+        let mut bytes = [0u8; 2];
+        self.i2c
+            .write_read(MAX17048_ADDRESS, &[register], &mut bytes)
+            .map_err(|_| PowerMonitorError::Bus)?;
+        Ok(u16::from_be_bytes(bytes))
+    }
+}
+
+impl<I2C> PowerMonitor for Max17048Monitor<I2C>
+where
+    I2C: embedded_hal::i2c::I2c,
+{
+    fn read(&mut self) -> Result<PowerStatus, PowerMonitorError> {
+        // SOC is 1/256 % per bit; CRATE is a signed 0.208 %/h rate that goes positive on charge.
+        let soc = self.read_register(MAX17048_SOC_REG)?;
+        let charge_rate = self.read_register(MAX17048_CRATE_REG)? as i16;
+        let percent = (soc >> 8).min(100) as u8;
+        Ok(PowerStatus::new(percent).with_charging(charge_rate > 0))
+    }
+}
+
+// Holds the CPU at full speed until dropped. The render path and the SD task each take one
+// around their work; the clock drops back to idle once neither does.
+pub(crate) struct FullSpeed {
+    demand: CpuDemand,
+}
+
+pub(crate) fn full_speed(demand: CpuDemand) -> FullSpeed {
+    CPU_POLICY.lock(|policy| {
+        let mut next = policy.get();
+        if let Some(speed) = next.raise(demand) {
+            set_cpu_speed(speed);
+        }
+        policy.set(next);
+    });
+    FullSpeed { demand }
+}
+
+impl Drop for FullSpeed {
+    fn drop(&mut self) {
+        CPU_POLICY.lock(|policy| {
+            let mut next = policy.get();
+            if let Some(speed) = next.release(self.demand) {
+                set_cpu_speed(speed);
+            }
+            policy.set(next);
+        });
+    }
+}
+
+// Boot runs at the full clock; this settles it to whatever the policy wants right now.
+pub fn apply_cpu_policy() {
+    let speed = CPU_POLICY.lock(|policy| {
+        let speed = policy.get().speed();
+        set_cpu_speed(speed);
+        speed
+    });
+    info!("cpu clock policy applied mhz={}", speed.mhz());
+}
+
+// CPUPERIOD_SEL divides the 480 MHz PLL down to 80 or 240 MHz. APB stays at 80 MHz either way,
+// so SPI clocks and the SYSTIMER behind embassy time are unaffected by the switch.
+fn set_cpu_speed(speed: CpuSpeed) {
+    let period_sel = match speed {
+        CpuSpeed::Idle => 0,
+        CpuSpeed::Full => 2,
+    };
+    SYSTEM::regs()
+        .cpu_per_conf()
+        .modify(|_, w| unsafe { w.cpuperiod_sel().bits(period_sel) });
+}
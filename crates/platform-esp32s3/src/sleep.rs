@@ -1,5 +1,12 @@
-use ::domain::sleep::{DEFAULT_INACTIVITY_TIMEOUT_MS, SleepConfig, SleepModel, WakeReason};
+use core::ptr::addr_of_mut;
+
+use ::domain::sleep::{
+    DEFAULT_INACTIVITY_TIMEOUT_MS, SLEEP_RESUME_ENCODED_LEN, SleepConfig, SleepModel, SleepResume,
+    WakeReason,
+};
 use ::services::sleep::SleepService;
+#[cfg(feature = "reader-light-sleep")]
+use esp_hal::rtc_cntl::sleep::{GpioWakeupSource, RtcSleepConfig, TimerWakeupSource};
 use esp_hal::{
     gpio::RtcPinWithResistors,
     rtc_cntl::{
@@ -9,6 +16,20 @@ use esp_hal::{
 };
 use log::info;
 
+const SLEEP_RESUME_MAGIC: u32 = 0x534C_5052;
+
+struct SleepResumeRecord {
+    magic: u32,
+    bytes: [u8; SLEEP_RESUME_ENCODED_LEN],
+}
+
+// RTC slow memory stays powered through deep sleep; a cold boot finds no magic and ignores it.
+#[esp_hal::ram(unstable(rtc_slow, persistent))]
+static mut SLEEP_RESUME_RECORD: SleepResumeRecord = SleepResumeRecord {
+    magic: 0,
+    bytes: [0; SLEEP_RESUME_ENCODED_LEN],
+};
+
 #[derive(Debug, Default)]
 pub struct PlatformSleepService {
     model: SleepModel,
@@ -33,6 +54,10 @@ impl PlatformSleepService {
     pub fn configure_inactivity_timeout(&mut self, inactivity_timeout_ms: u64) {
         self.model.config.inactivity_timeout_ms = inactivity_timeout_ms;
     }
+
+    pub fn configure_screensaver(&mut self, screensaver_minutes: u8) {
+        self.model.config.screensaver_after_ms = u64::from(screensaver_minutes) * 60_000;
+    }
 }
 
 impl SleepService for PlatformSleepService {
@@ -64,3 +89,48 @@ where
     let ext0 = Ext0WakeupSource::new(wake_pin, WakeupLevel::Low);
     rtc.sleep_deep(&[&ext0]);
 }
+
+// Sleeps between reader words. XTAL stays powered so SYSTIMER, and with it embassy time, keeps
+// counting; otherwise every word would land late by the time spent asleep. The timer source caps
+// the sleep at the next word or display heartbeat, and the input pins end it early on any touch.
+#[cfg(feature = "reader-light-sleep")]
+pub fn enter_light_sleep(
+    rtc: &mut Rtc<'_>,
+    input: &mut crate::input::PlatformInputService<'_>,
+    window_ms: u64,
+) {
+    if let Err(err) = input.arm_light_sleep_wake() {
+        info!("light sleep wake arm failed: {:?}", err);
+        input.disarm_light_sleep_wake();
+        return;
+    }
+
+    let timer = TimerWakeupSource::new(core::time::Duration::from_millis(window_ms));
+    let gpio = GpioWakeupSource::new();
+    let mut config = RtcSleepConfig::default();
+    config.set_xtal_fpu(true);
+    rtc.sleep(&config, &[&timer, &gpio]);
+
+    input.disarm_light_sleep_wake();
+}
+
+pub fn store_sleep_resume(resume: SleepResume) {
+    unsafe {
+        let record = addr_of_mut!(SLEEP_RESUME_RECORD);
+        (*record).bytes = resume.encode();
+        (*record).magic = SLEEP_RESUME_MAGIC;
+    }
+}
+
+pub fn take_sleep_resume() -> Option<SleepResume> {
+    let (magic, bytes) = unsafe {
+        let record = addr_of_mut!(SLEEP_RESUME_RECORD);
+        let snapshot = ((*record).magic, (*record).bytes);
+        (*record).magic = 0;
+        snapshot
+    };
+    if magic != SLEEP_RESUME_MAGIC {
+        return None;
+    }
+    SleepResume::decode(&bytes)
+}
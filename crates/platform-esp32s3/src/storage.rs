@@ -1,14 +1,20 @@
 use core::cmp::Ordering;
 
 use ::domain::{
-    settings::{AppearanceMode, PersistedSettings, TopicPreferences},
+    audio::AudioVolume,
+    formatter::{
+        ChunkingMode, DEFAULT_MAX_DWELL_MS, DEFAULT_MIN_DWELL_MS, DwellMode, ReferenceMode,
+    },
+    input::EncoderSensitivity,
+    settings::{AppearanceMode, PAUSE_MS_STEP, PROFILE_COUNT, PersistedSettings, TopicPreferences},
     storage::{
         QueueKind, QueueSeq, RecordKey, RecordNamespace, StorageHealth, StorageRecoveryStatus,
         StorageStatus,
     },
 };
-use ::services::storage::{
-    QueueCodec, RecordCodec, StorageCodecError, StorageError, StorageService,
+use ::services::{
+    book_flash::BookPartition,
+    storage::{QueueCodec, RecordCodec, StorageCodecError, StorageError, StorageService},
 };
 use embedded_storage::nor_flash::{ErrorType, NorFlash, ReadNorFlash};
 use esp_bootloader_esp_idf::partitions::{PARTITION_TABLE_MAX_LEN, read_partition_table};
@@ -18,7 +24,10 @@ use log::info;
 
 pub const STATE_PARTITION_LABEL: &str = "motif_state";
 pub const OUTBOX_PARTITION_LABEL: &str = "motif_outbox";
+pub const BOOKS_PARTITION_LABEL: &str = "motif_books";
 pub const SETTINGS_RECORD_KEY: RecordKey = RecordKey::new(RecordNamespace::Settings, 1);
+pub const ACTIVE_PROFILE_RECORD_KEY: RecordKey = RecordKey::new(RecordNamespace::Settings, 0x10);
+pub const BOOT_COUNT_RECORD_KEY: RecordKey = RecordKey::new(RecordNamespace::Settings, 0x11);
 pub const BACKEND_CREDENTIAL_RECORD_KEY: RecordKey = RecordKey::new(RecordNamespace::Backend, 1);
 pub const BACKEND_REFRESH_TOKEN_MAX_LEN: usize = 320;
 
@@ -34,6 +43,22 @@ const BANK_HEADER_COMMIT_OFFSET: usize = BANK_HEADER_LEN - 4;
 const BANK_MAGIC: u32 = 0x4D54_424B;
 const BANK_COMMIT_MAGIC: u32 = 0xB16B_C0DE;
 const FORMAT_VERSION: u16 = 1;
+// Settings payloads carry their own version byte after the original 24-byte layout. The slot
+// header's schema version stays at 1 because reads reject any other value, which would drop the
+// settings of every upgraded device.
+const SETTINGS_PAYLOAD_VERSION: u8 = 2;
+const SETTINGS_VERSION_OFFSET: usize = 24;
+const SETTINGS_ENCODER_REVERSED: u8 = 1;
+// Bits 1-2 of the input-flags byte.
+const SETTINGS_ENCODER_SENSITIVITY_SHIFT: u8 = 1;
+const SETTINGS_NIGHT_MODE: u8 = 1;
+// Stored inverted so records written before the walk-through existed read as already onboarded.
+const SETTINGS_ONBOARDING_PENDING: u8 = 1 << 1;
+const SETTINGS_LIGHT_SENSOR: u8 = 1 << 2;
+const SETTINGS_QUOTE_EXPORT: u8 = 1 << 3;
+const SETTINGS_FUNCTION_WORD_CHUNKS: u8 = 1;
+const SETTINGS_KEEP_REFERENCES: u8 = 1 << 1;
+const SETTINGS_DIGIT_GROUPING: u8 = 1 << 2;
 const MAX_STATE_KEYS: usize = 32;
 
 #[derive(Debug)]
@@ -77,15 +102,46 @@ impl<'d> PlatformStorageService<'d> {
 
     pub fn read_persisted_settings_sync(
         &mut self,
+        profile: u8,
     ) -> Result<Option<PersistedSettings>, StorageError> {
-        self.read_record_sync::<PersistedSettingsCodec>()
+        self.inner.read_persisted_settings_sync(profile)
     }
 
     pub fn write_persisted_settings_sync(
         &mut self,
+        profile: u8,
         settings: &PersistedSettings,
     ) -> Result<(), StorageError> {
-        self.write_record_sync::<PersistedSettingsCodec>(settings)
+        self.inner
+            .write_record_at_sync::<PersistedSettingsCodec>(settings_record_key(profile), settings)
+    }
+
+    pub fn read_active_profile_sync(&mut self) -> Result<u8, StorageError> {
+        Ok(self
+            .read_record_sync::<ActiveProfileCodec>()?
+            .unwrap_or(0)
+            .min(PROFILE_COUNT - 1))
+    }
+
+    pub fn write_active_profile_sync(&mut self, profile: u8) -> Result<(), StorageError> {
+        self.write_record_sync::<ActiveProfileCodec>(&profile)
+    }
+
+    pub fn read_boot_count_sync(&mut self) -> Result<u32, StorageError> {
+        Ok(self.read_record_sync::<BootCountCodec>()?.unwrap_or(0))
+    }
+
+    pub fn write_boot_count_sync(&mut self, boot_count: u32) -> Result<(), StorageError> {
+        self.write_record_sync::<BootCountCodec>(&boot_count)
+    }
+
+    pub fn erase_device_records_sync(&mut self) -> Result<(), StorageError> {
+        for profile in 0..PROFILE_COUNT {
+            self.inner
+                .delete_record_at_sync::<PersistedSettingsCodec>(settings_record_key(profile))?;
+        }
+        self.delete_record_sync::<ActiveProfileCodec>()?;
+        self.delete_record_sync::<BackendCredentialCodec>()
     }
 
     pub fn read_backend_credential_sync(
@@ -134,6 +190,16 @@ impl<'d> PlatformStorageService<'d> {
     }
 }
 
+// Books get their own flash handle so a long copy never sits behind settings or outbox writes.
+pub fn mount_book_partition<'d>(
+    flash: Flash<'d>,
+) -> Result<BookPartition<FlashStorage<'d>>, StorageError> {
+    let mut flash = FlashStorage::new(flash).multicore_auto_park();
+    let mut partition_table = [0u8; PARTITION_TABLE_MAX_LEN];
+    let books = find_partition_geometry(&mut flash, &mut partition_table, BOOKS_PARTITION_LABEL)?;
+    BookPartition::new(flash, books.offset, books.length)
+}
+
 impl StorageService for PlatformStorageService<'_> {
     fn health(&self) -> StorageHealth {
         self.inner.health()
@@ -221,17 +287,56 @@ where
     }
 
     fn read_record_sync<C: RecordCodec>(&mut self) -> Result<Option<C::Value>, StorageError> {
-        let latest = self.with_partition(PartitionSelector::State, |flash, mounted| {
-            read_latest_state_record::<_, C>(flash, mounted)
-        })?;
-
-        match latest {
+        match self.read_payload_at_sync::<C>(C::KEY)? {
             Some(payload) => C::decode(payload.as_slice()).map(Some).map_err(codec_error),
             None => Ok(None),
         }
     }
 
+    fn read_payload_at_sync<C: RecordCodec>(
+        &mut self,
+        key: RecordKey,
+    ) -> Result<Option<PayloadBuffer>, StorageError> {
+        self.with_partition(PartitionSelector::State, |flash, mounted| {
+            read_latest_state_record::<_, C>(flash, mounted, key)
+        })
+    }
+
+    // Older payloads decode with defaults for the missing fields and are rewritten in the current
+    // layout, so the migration runs once per device.
+    fn read_persisted_settings_sync(
+        &mut self,
+        profile: u8,
+    ) -> Result<Option<PersistedSettings>, StorageError> {
+        let key = settings_record_key(profile);
+        let Some(payload) = self.read_payload_at_sync::<PersistedSettingsCodec>(key)? else {
+            return Ok(None);
+        };
+        let settings = PersistedSettingsCodec::decode(payload.as_slice()).map_err(codec_error)?;
+
+        let version = PersistedSettingsCodec::payload_version(payload.as_slice());
+        if version < SETTINGS_PAYLOAD_VERSION {
+            match self.write_record_at_sync::<PersistedSettingsCodec>(key, &settings) {
+                Ok(()) => info!(
+                    "settings migrated from payload version {} to {}",
+                    version, SETTINGS_PAYLOAD_VERSION
+                ),
+                Err(err) => info!("settings migration write failed: {:?}", err),
+            }
+        }
+
+        Ok(Some(settings))
+    }
+
     fn write_record_sync<C: RecordCodec>(&mut self, value: &C::Value) -> Result<(), StorageError> {
+        self.write_record_at_sync::<C>(C::KEY, value)
+    }
+
+    fn write_record_at_sync<C: RecordCodec>(
+        &mut self,
+        key: RecordKey,
+        value: &C::Value,
+    ) -> Result<(), StorageError> {
         let mut encoded = [0xFF; SLOT_PAYLOAD_MAX];
         let encoded_len = C::encode(value, &mut encoded).map_err(codec_error)?;
         if encoded_len > SLOT_PAYLOAD_MAX || C::MAX_ENCODED_LEN > SLOT_PAYLOAD_MAX {
@@ -242,7 +347,7 @@ where
             append_state_record(
                 flash,
                 mounted,
-                C::KEY,
+                key,
                 C::SCHEMA_VERSION,
                 EntryKind::StatePut,
                 &encoded[..encoded_len],
@@ -253,11 +358,18 @@ where
     }
 
     fn delete_record_sync<C: RecordCodec>(&mut self) -> Result<(), StorageError> {
+        self.delete_record_at_sync::<C>(C::KEY)
+    }
+
+    fn delete_record_at_sync<C: RecordCodec>(
+        &mut self,
+        key: RecordKey,
+    ) -> Result<(), StorageError> {
         self.with_partition(PartitionSelector::State, |flash, mounted| {
             append_state_record(
                 flash,
                 mounted,
-                C::KEY,
+                key,
                 C::SCHEMA_VERSION,
                 EntryKind::StateDelete,
                 &[],
@@ -927,6 +1039,7 @@ where
 fn read_latest_state_record<F, C: RecordCodec>(
     flash: &mut FlashSlice<'_, F>,
     mounted: &MountedPartition,
+    key: RecordKey,
 ) -> Result<Option<PayloadBuffer>, StorageError>
 where
     F: NorFlash + ReadNorFlash,
@@ -946,7 +1059,7 @@ where
             continue;
         }
 
-        if entry.namespace != C::KEY.namespace as u8 || entry.object_id != C::KEY.object_id {
+        if entry.namespace != key.namespace as u8 || entry.object_id != key.object_id {
             continue;
         }
 
@@ -1463,37 +1576,81 @@ impl Default for BackendCredential {
     }
 }
 
-pub struct PersistedSettingsCodec;
+// Profile 0 keeps the original settings key, so records written before profiles existed become
+// the first profile's settings.
+pub const fn settings_record_key(profile: u8) -> RecordKey {
+    RecordKey::new(RecordNamespace::Settings, 1 + profile as u16)
+}
 
-impl RecordCodec for PersistedSettingsCodec {
-    type Value = PersistedSettings;
+pub struct ActiveProfileCodec;
 
-    const KEY: RecordKey = SETTINGS_RECORD_KEY;
+impl RecordCodec for ActiveProfileCodec {
+    type Value = u8;
+
+    const KEY: RecordKey = ACTIVE_PROFILE_RECORD_KEY;
     const SCHEMA_VERSION: u16 = 1;
-    const MAX_ENCODED_LEN: usize = 16;
+    const MAX_ENCODED_LEN: usize = 4;
 
     fn encode(value: &Self::Value, out: &mut [u8]) -> Result<usize, StorageCodecError> {
         if out.len() < Self::MAX_ENCODED_LEN {
             return Err(StorageCodecError::BufferTooSmall);
         }
 
-        let topic_bits = value.topics.to_bits();
-        out[..8].copy_from_slice(&value.inactivity_timeout_ms.to_le_bytes());
-        out[8..10].copy_from_slice(&value.reading_speed_wpm.to_le_bytes());
-        out[10] = value.appearance.to_byte();
-        out[11] = 0;
-        out[12..16].copy_from_slice(&topic_bits.to_le_bytes());
-        Ok(16)
+        out[..4].copy_from_slice(&[*value, 0, 0, 0]);
+        Ok(4)
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self::Value, StorageCodecError> {
+        if bytes.len() != 4 {
+            return Err(StorageCodecError::InvalidData);
+        }
+
+        Ok(bytes[0])
+    }
+}
+
+pub struct BootCountCodec;
+
+impl RecordCodec for BootCountCodec {
+    type Value = u32;
+
+    const KEY: RecordKey = BOOT_COUNT_RECORD_KEY;
+    const SCHEMA_VERSION: u16 = 1;
+    const MAX_ENCODED_LEN: usize = 4;
+
+    fn encode(value: &Self::Value, out: &mut [u8]) -> Result<usize, StorageCodecError> {
+        if out.len() < Self::MAX_ENCODED_LEN {
+            return Err(StorageCodecError::BufferTooSmall);
+        }
+
+        out[..4].copy_from_slice(&value.to_le_bytes());
+        Ok(4)
     }
 
     fn decode(bytes: &[u8]) -> Result<Self::Value, StorageCodecError> {
+        let bytes: [u8; 4] = bytes
+            .try_into()
+            .map_err(|_| StorageCodecError::InvalidData)?;
+        Ok(u32::from_le_bytes(bytes))
+    }
+}
+
+pub struct PersistedSettingsCodec;
+
+impl PersistedSettingsCodec {
+    // Payloads from before the version byte are identified by length and count as version 1.
+    pub fn payload_version(bytes: &[u8]) -> u8 {
+        bytes.get(SETTINGS_VERSION_OFFSET).copied().unwrap_or(1)
+    }
+
+    fn decode_v1(bytes: &[u8]) -> Result<PersistedSettings, StorageCodecError> {
         if bytes.len() == 8 {
             let mut raw = [0u8; 8];
             raw.copy_from_slice(bytes);
             return Ok(PersistedSettings::new(u64::from_le_bytes(raw)));
         }
 
-        if bytes.len() != 16 {
+        if !matches!(bytes.len(), 16 | 20 | 24) {
             return Err(StorageCodecError::InvalidData);
         }
 
@@ -1506,15 +1663,161 @@ impl RecordCodec for PersistedSettingsCodec {
         let mut topic_bits_raw = [0u8; 4];
         topic_bits_raw.copy_from_slice(&bytes[12..16]);
 
+        let mut reading_flags_raw = [0u8; 4];
+        if bytes.len() >= 20 {
+            reading_flags_raw.copy_from_slice(&bytes[16..20]);
+        }
+        let reading_flags = u32::from_le_bytes(reading_flags_raw);
+
+        let mut goal_progress_raw = [0u8; 4];
+        if bytes.len() == 24 {
+            goal_progress_raw.copy_from_slice(&bytes[20..24]);
+        }
+
         Ok(PersistedSettings::with_preferences(
             u64::from_le_bytes(timeout_raw),
             u16::from_le_bytes(speed_raw),
             AppearanceMode::from_byte(bytes[10]),
             TopicPreferences::from_bits(u32::from_le_bytes(topic_bits_raw)),
+        )
+        .with_dwell_mode(DwellMode::from_byte(bytes[11]))
+        .with_section_pauses(reading_flags & 1 != 0)
+        .with_orp_highlight(reading_flags & 0b10 != 0)
+        .with_bionic_reading(reading_flags & 0b100 != 0)
+        .with_library_grid(reading_flags & 0b1000 != 0)
+        .with_adaptive_speed(reading_flags & 0b1_0000 != 0)
+        .with_rewind_words((reading_flags >> 8) as u8)
+        .with_reading_goal(
+            (reading_flags >> 16) as u8,
+            u32::from_le_bytes(goal_progress_raw),
         ))
     }
 }
 
+impl RecordCodec for PersistedSettingsCodec {
+    type Value = PersistedSettings;
+
+    const KEY: RecordKey = SETTINGS_RECORD_KEY;
+    const SCHEMA_VERSION: u16 = 1;
+    const MAX_ENCODED_LEN: usize = 36;
+
+    fn encode(value: &Self::Value, out: &mut [u8]) -> Result<usize, StorageCodecError> {
+        if out.len() < Self::MAX_ENCODED_LEN {
+            return Err(StorageCodecError::BufferTooSmall);
+        }
+
+        let topic_bits = value.topics.to_bits();
+        let reading_flags = u32::from(value.section_pauses)
+            | (u32::from(value.orp_highlight) << 1)
+            | (u32::from(value.bionic_reading) << 2)
+            | (u32::from(value.library_grid) << 3)
+            | (u32::from(value.adaptive_speed) << 4)
+            | (u32::from(value.rewind_words) << 8)
+            | (u32::from(value.reading_goal_minutes) << 16);
+        let mut input_flags =
+            value.encoder_sensitivity.to_byte() << SETTINGS_ENCODER_SENSITIVITY_SHIFT;
+        if value.encoder_reversed {
+            input_flags |= SETTINGS_ENCODER_REVERSED;
+        }
+        let mut device_flags = 0;
+        if value.night_mode {
+            device_flags |= SETTINGS_NIGHT_MODE;
+        }
+        if value.light_sensor {
+            device_flags |= SETTINGS_LIGHT_SENSOR;
+        }
+        if value.quote_export {
+            device_flags |= SETTINGS_QUOTE_EXPORT;
+        }
+        if !value.onboarded {
+            device_flags |= SETTINGS_ONBOARDING_PENDING;
+        }
+        let mut format_flags = 0;
+        if value.chunking == ChunkingMode::FunctionWords {
+            format_flags |= SETTINGS_FUNCTION_WORD_CHUNKS;
+        }
+        if value.references == ReferenceMode::Keep {
+            format_flags |= SETTINGS_KEEP_REFERENCES;
+        }
+        if value.digit_grouping {
+            format_flags |= SETTINGS_DIGIT_GROUPING;
+        }
+        out[..8].copy_from_slice(&value.inactivity_timeout_ms.to_le_bytes());
+        out[8..10].copy_from_slice(&value.reading_speed_wpm.to_le_bytes());
+        out[10] = value.appearance.to_byte();
+        out[11] = value.dwell_mode.to_byte();
+        out[12..16].copy_from_slice(&topic_bits.to_le_bytes());
+        out[16..20].copy_from_slice(&reading_flags.to_le_bytes());
+        out[20..24].copy_from_slice(&value.goal_progress_s.to_le_bytes());
+        out[SETTINGS_VERSION_OFFSET] = SETTINGS_PAYLOAD_VERSION;
+        out[25] = value.countdown_seconds;
+        out[26] = value.orp_anchor_percent;
+        out[27] = input_flags;
+        out[28] = value.screensaver_minutes;
+        out[29] = device_flags;
+        out[30] = value.audio_volume.to_byte();
+        out[31] = (value.sentence_pause_ms / PAUSE_MS_STEP) as u8;
+        out[32] = (value.clause_pause_ms / PAUSE_MS_STEP) as u8;
+        out[33] = (value.min_dwell_ms / 10) as u8;
+        out[34] = (value.max_dwell_ms / 100) as u8;
+        out[35] = format_flags;
+        Ok(36)
+    }
+
+    // Newer firmware may append fields after the ones read here; they are ignored rather than
+    // rejected so a downgrade keeps the shared settings.
+    fn decode(bytes: &[u8]) -> Result<Self::Value, StorageCodecError> {
+        if bytes.len() <= SETTINGS_VERSION_OFFSET {
+            return Self::decode_v1(bytes).map(|settings| settings.with_onboarded(true));
+        }
+
+        if Self::payload_version(bytes) < SETTINGS_PAYLOAD_VERSION || bytes.len() < 28 {
+            return Err(StorageCodecError::InvalidData);
+        }
+
+        let device_flags = bytes.get(29).copied().unwrap_or(0);
+        let format_flags = bytes.get(35).copied().unwrap_or(0);
+        Ok(Self::decode_v1(&bytes[..SETTINGS_VERSION_OFFSET])?
+            .with_countdown_seconds(bytes[25])
+            .with_orp_anchor_percent(bytes[26])
+            .with_encoder_reversed(bytes[27] & SETTINGS_ENCODER_REVERSED != 0)
+            .with_encoder_sensitivity(EncoderSensitivity::from_byte(
+                bytes[27] >> SETTINGS_ENCODER_SENSITIVITY_SHIFT,
+            ))
+            .with_screensaver_minutes(bytes.get(28).copied().unwrap_or(0))
+            .with_night_mode(device_flags & SETTINGS_NIGHT_MODE != 0)
+            .with_light_sensor(device_flags & SETTINGS_LIGHT_SENSOR != 0)
+            .with_audio_volume(AudioVolume::from_byte(bytes.get(30).copied().unwrap_or(0)))
+            .with_pause_ms(
+                u16::from(bytes.get(31).copied().unwrap_or(0)) * PAUSE_MS_STEP,
+                u16::from(bytes.get(32).copied().unwrap_or(0)) * PAUSE_MS_STEP,
+            )
+            .with_dwell_bounds(
+                bytes
+                    .get(33)
+                    .filter(|steps| **steps != 0)
+                    .map_or(DEFAULT_MIN_DWELL_MS, |steps| u16::from(*steps) * 10),
+                bytes
+                    .get(34)
+                    .filter(|steps| **steps != 0)
+                    .map_or(DEFAULT_MAX_DWELL_MS, |steps| u16::from(*steps) * 100),
+            )
+            .with_chunking(if format_flags & SETTINGS_FUNCTION_WORD_CHUNKS != 0 {
+                ChunkingMode::FunctionWords
+            } else {
+                ChunkingMode::SingleWord
+            })
+            .with_references(if format_flags & SETTINGS_KEEP_REFERENCES != 0 {
+                ReferenceMode::Keep
+            } else {
+                ReferenceMode::Skip
+            })
+            .with_digit_grouping(format_flags & SETTINGS_DIGIT_GROUPING != 0)
+            .with_quote_export(device_flags & SETTINGS_QUOTE_EXPORT != 0)
+            .with_onboarded(device_flags & SETTINGS_ONBOARDING_PENDING == 0))
+    }
+}
+
 pub struct BackendCredentialCodec;
 
 impl RecordCodec for BackendCredentialCodec {
@@ -1886,7 +2189,30 @@ mod tests {
         topics.toggle_chip(0, 1);
         topics.toggle_chip(3, 6);
         let settings =
-            PersistedSettings::with_preferences(45_000, 320, AppearanceMode::Dark, topics);
+            PersistedSettings::with_preferences(45_000, 320, AppearanceMode::Dark, topics)
+                .with_dwell_mode(DwellMode::Weighted)
+                .with_section_pauses(true)
+                .with_rewind_words(5)
+                .with_orp_highlight(true)
+                .with_bionic_reading(true)
+                .with_library_grid(true)
+                .with_adaptive_speed(true)
+                .with_reading_goal(20, 754)
+                .with_pause_ms(240, 80)
+                .with_dwell_bounds(160, 3_000)
+                .with_chunking(ChunkingMode::FunctionWords)
+                .with_references(ReferenceMode::Keep)
+                .with_digit_grouping(true)
+                .with_countdown_seconds(3)
+                .with_orp_anchor_percent(35)
+                .with_encoder_reversed(true)
+                .with_encoder_sensitivity(EncoderSensitivity::Low)
+                .with_screensaver_minutes(5)
+                .with_night_mode(true)
+                .with_light_sensor(true)
+                .with_audio_volume(AudioVolume::High)
+                .with_quote_export(true)
+                .with_onboarded(true);
         let mut encoded = [0u8; PersistedSettingsCodec::MAX_ENCODED_LEN];
 
         let len = PersistedSettingsCodec::encode(&settings, &mut encoded).unwrap();
@@ -1906,5 +2232,110 @@ mod tests {
         );
         assert_eq!(decoded.appearance, AppearanceMode::Light);
         assert_eq!(decoded.topics, TopicPreferences::new());
+        assert_eq!(decoded.dwell_mode, DwellMode::Standard);
+    }
+
+    #[test]
+    fn persisted_settings_codec_reads_payload_without_reading_flags() {
+        let settings = PersistedSettings::new(45_000).with_section_pauses(true);
+        let mut encoded = [0u8; PersistedSettingsCodec::MAX_ENCODED_LEN];
+
+        let len = PersistedSettingsCodec::encode(&settings, &mut encoded).unwrap();
+        let decoded = PersistedSettingsCodec::decode(&encoded[..16]).unwrap();
+
+        assert_eq!(len, 36);
+        assert_eq!(decoded.inactivity_timeout_ms, 45_000);
+        assert!(!decoded.section_pauses);
+        assert!(decoded.onboarded);
+    }
+
+    #[test]
+    fn persisted_settings_codec_reads_a_clear_flags_byte_as_onboarded() {
+        let fresh = PersistedSettings::new(45_000);
+        let mut encoded = [0u8; PersistedSettingsCodec::MAX_ENCODED_LEN];
+
+        let len = PersistedSettingsCodec::encode(&fresh, &mut encoded).unwrap();
+        assert_eq!(
+            PersistedSettingsCodec::decode(&encoded[..len]).unwrap(),
+            fresh
+        );
+
+        encoded[29] = 0;
+        assert!(
+            PersistedSettingsCodec::decode(&encoded[..len])
+                .unwrap()
+                .onboarded
+        );
+    }
+
+    #[test]
+    fn legacy_settings_payload_migrates_to_the_versioned_layout() {
+        struct LegacySettingsCodec;
+        impl RecordCodec for LegacySettingsCodec {
+            type Value = PersistedSettings;
+
+            const KEY: RecordKey = SETTINGS_RECORD_KEY;
+            const SCHEMA_VERSION: u16 = 1;
+            const MAX_ENCODED_LEN: usize = 32;
+
+            fn encode(value: &Self::Value, out: &mut [u8]) -> Result<usize, StorageCodecError> {
+                PersistedSettingsCodec::encode(value, out).map(|_| SETTINGS_VERSION_OFFSET)
+            }
+
+            fn decode(bytes: &[u8]) -> Result<Self::Value, StorageCodecError> {
+                PersistedSettingsCodec::decode(bytes)
+            }
+        }
+
+        let mut storage = new_storage();
+        let legacy = PersistedSettings::new(45_000)
+            .with_rewind_words(5)
+            .with_countdown_seconds(3);
+        storage
+            .write_record_sync::<LegacySettingsCodec>(&legacy)
+            .unwrap();
+
+        let migrated = storage.read_persisted_settings_sync(0).unwrap().unwrap();
+        let payload = storage
+            .read_payload_at_sync::<PersistedSettingsCodec>(SETTINGS_RECORD_KEY)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(migrated.rewind_words, 5);
+        assert_eq!(
+            migrated.countdown_seconds,
+            domain::settings::DEFAULT_COUNTDOWN_SECONDS
+        );
+        assert_eq!(
+            PersistedSettingsCodec::payload_version(payload.as_slice()),
+            SETTINGS_PAYLOAD_VERSION
+        );
+        assert_eq!(
+            storage.read_persisted_settings_sync(0).unwrap(),
+            Some(migrated)
+        );
+    }
+
+    #[test]
+    fn profile_settings_are_stored_under_separate_keys() {
+        let mut storage = new_storage();
+        let first = PersistedSettings::new(45_000).with_rewind_words(3);
+        let second = PersistedSettings::new(60_000).with_rewind_words(10);
+        storage
+            .write_record_at_sync::<PersistedSettingsCodec>(settings_record_key(0), &first)
+            .unwrap();
+        storage
+            .write_record_at_sync::<PersistedSettingsCodec>(settings_record_key(1), &second)
+            .unwrap();
+
+        assert_eq!(
+            storage.read_persisted_settings_sync(0).unwrap(),
+            Some(first)
+        );
+        assert_eq!(
+            storage.read_persisted_settings_sync(1).unwrap(),
+            Some(second)
+        );
+        assert_eq!(storage.read_persisted_settings_sync(2).unwrap(), None);
     }
 }
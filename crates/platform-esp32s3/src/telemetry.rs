@@ -1,7 +1,14 @@
 use core::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
 
-use domain::content::CollectionKind;
+use core::cell::Cell;
+
+use domain::{content::CollectionKind, diagnostics::DiagnosticsSnapshot, perf::PerfCounters};
+use embassy_sync::blocking_mutex::{
+    Mutex,
+    raw::{CriticalSectionRawMutex, RawMutex},
+};
 use esp_alloc::{MemoryCapability, RegionStats};
+use log::info;
 
 pub const MEMTRACE_VERSION: u32 = 1;
 
@@ -14,6 +21,22 @@ static EXTERNAL_PEAK_USED: AtomicUsize = AtomicUsize::new(0);
 static EXTERNAL_MIN_FREE: AtomicUsize = AtomicUsize::new(usize::MAX);
 static REGION_PEAK_USED: [AtomicUsize; 3] = [const { AtomicUsize::new(0) }; 3];
 static REGION_MIN_FREE: [AtomicUsize; 3] = [const { AtomicUsize::new(usize::MAX) }; 3];
+static SD_CLOCK_HZ: AtomicU32 = AtomicU32::new(0);
+// Shared by the UI task (render, flush) and the storage paths (refill).
+static PERF: Mutex<CriticalSectionRawMutex, Cell<PerfCounters>> =
+    Mutex::new(Cell::new(PerfCounters::new()));
+
+const STACK_PAINT: u32 = 0x5354_4b21;
+// esp-hal keeps its stack-guard canary near the bottom of the stack and watches it for writes.
+const STACK_GUARD_SKIP_BYTES: usize = 8 * 1024;
+// Headroom left below the caller's frame so painting never touches live stack.
+const STACK_PAINT_MARGIN_BYTES: usize = 1024;
+
+// Bounds of the main stack from the esp-hal linker script; it grows down from `start` to `end`.
+unsafe extern "C" {
+    safe static _stack_start_cpu0: u32;
+    safe static _stack_end_cpu0: u32;
+}
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct TraceContext {
@@ -177,6 +200,103 @@ pub fn capture_heap() -> HeapTelemetry {
     }
 }
 
+pub fn set_sd_clock_hz(hz: u32) {
+    SD_CLOCK_HZ.store(hz, Ordering::Relaxed);
+}
+
+pub fn record_perf(update: impl FnOnce(&mut PerfCounters)) {
+    PERF.lock(|perf| {
+        let mut counters = perf.get();
+        update(&mut counters);
+        perf.set(counters);
+    });
+}
+
+pub fn perf_snapshot() -> PerfCounters {
+    PERF.lock(Cell::get)
+}
+
+pub fn log_perf() {
+    let perf = perf_snapshot();
+    info!(
+        "perf frames={} render_us={}/{} flush_us={}/{} refills={} refill_us={}/{} words_per_s={}",
+        perf.render.samples(),
+        perf.render.mean_us(),
+        perf.render.max_us,
+        perf.flush.mean_us(),
+        perf.flush.max_us,
+        perf.refill.samples(),
+        perf.refill.mean_us(),
+        perf.refill.max_us,
+        perf.words_per_second,
+    );
+}
+
+// Fills the unused part of the main stack with a known word, so `stack_peak_bytes` can later find
+// the deepest point any frame reached. Called once, early in boot.
+pub fn paint_stack() {
+    let marker = 0u32;
+    let here = core::ptr::addr_of!(marker) as usize;
+    let bottom = stack_paint_bottom();
+    let top = here.saturating_sub(STACK_PAINT_MARGIN_BYTES) & !3;
+
+    CriticalSectionRawMutex::new().lock(|| {
+        let mut address = bottom;
+        while address < top {
+            // SAFETY: the range lies inside the main stack, below every live frame.
+            unsafe { core::ptr::write_volatile(address as *mut u32, STACK_PAINT) };
+            address += 4;
+        }
+    });
+}
+
+pub fn capture_diagnostics() -> DiagnosticsSnapshot {
+    let heap = capture_heap();
+    let largest_free_region = heap
+        .regions
+        .iter()
+        .map(|region| region.free)
+        .max()
+        .unwrap_or(0);
+
+    DiagnosticsSnapshot {
+        firmware_version: env!("CARGO_PKG_VERSION"),
+        heap_free_bytes: heap.free as u32,
+        heap_min_free_bytes: heap.size.saturating_sub(heap.peak) as u32,
+        largest_free_region_bytes: largest_free_region as u32,
+        stack_peak_bytes: stack_peak_bytes() as u32,
+        stack_size_bytes: stack_size_bytes() as u32,
+        sd_clock_hz: SD_CLOCK_HZ.load(Ordering::Relaxed),
+        perf: perf_snapshot(),
+    }
+}
+
+fn stack_top() -> usize {
+    core::ptr::addr_of!(_stack_start_cpu0) as usize
+}
+
+fn stack_paint_bottom() -> usize {
+    core::ptr::addr_of!(_stack_end_cpu0) as usize + STACK_GUARD_SKIP_BYTES
+}
+
+fn stack_size_bytes() -> usize {
+    stack_top().saturating_sub(core::ptr::addr_of!(_stack_end_cpu0) as usize)
+}
+
+// The lowest word that no longer holds the paint marks the deepest the stack has been.
+fn stack_peak_bytes() -> usize {
+    let top = stack_top();
+    let mut address = stack_paint_bottom();
+    while address < top {
+        // SAFETY: reads stay inside the main stack.
+        if unsafe { core::ptr::read_volatile(address as *const u32) } != STACK_PAINT {
+            break;
+        }
+        address += 4;
+    }
+    top.saturating_sub(address)
+}
+
 fn map_region(index: usize, region: &RegionStats) -> RegionTelemetry {
     RegionTelemetry {
         kind: region_kind(region),
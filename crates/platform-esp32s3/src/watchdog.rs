@@ -0,0 +1,120 @@
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use embassy_executor::Spawner;
+use embassy_time::{Duration, Instant, Timer};
+use esp_hal::{
+    peripherals::TIMG1,
+    timer::timg::{MwdtStage, TimerGroup, Wdt},
+};
+use log::{info, warn};
+
+const SUPERVISOR_INTERVAL_MS: u64 = 1_000;
+const HARDWARE_TIMEOUT_MS: u64 = 8_000;
+const IDLE: u32 = u32::MAX;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum WatchedTask {
+    Ui,
+    Network,
+    Storage,
+}
+
+impl WatchedTask {
+    const ALL: [Self; 3] = [Self::Ui, Self::Network, Self::Storage];
+
+    const fn slot(self) -> usize {
+        match self {
+            Self::Ui => 0,
+            Self::Network => 1,
+            Self::Storage => 2,
+        }
+    }
+
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Ui => "ui",
+            Self::Network => "network",
+            Self::Storage => "storage",
+        }
+    }
+
+    // Longest a single unit of work may take. Network requests carry their own socket timeouts,
+    // so this only fires when those fail to.
+    const fn stall_timeout_ms(self) -> u32 {
+        match self {
+            Self::Ui => 5_000,
+            Self::Network => 180_000,
+            Self::Storage => 30_000,
+        }
+    }
+}
+
+// When each task started its current unit of work, or IDLE while it waits for the next one.
+// Waiting on a channel is never a stall; only work that does not finish is.
+static BUSY_SINCE_MS: [AtomicU32; 3] = [const { AtomicU32::new(IDLE) }; 3];
+
+pub(crate) struct Busy {
+    task: WatchedTask,
+}
+
+pub(crate) fn busy(task: WatchedTask) -> Busy {
+    BUSY_SINCE_MS[task.slot()].store(now_ms(), Ordering::Relaxed);
+    Busy { task }
+}
+
+// Work that yields between steps restarts its clock at each one, so only a step that does not
+// finish counts as a stall.
+pub(crate) fn checkpoint(task: WatchedTask) {
+    BUSY_SINCE_MS[task.slot()].store(now_ms(), Ordering::Relaxed);
+}
+
+impl Drop for Busy {
+    fn drop(&mut self) {
+        BUSY_SINCE_MS[self.task.slot()].store(IDLE, Ordering::Relaxed);
+    }
+}
+
+// The supervisor feeds TIMG1's hardware watchdog only while every task is idle or within its
+// stall budget. A task stuck in an await trips the panic below, which records the crash and
+// restarts; a task spinning without yielding starves the supervisor and the hardware bites.
+pub fn install(spawner: Spawner, timg1: TIMG1<'static>) {
+    let mut wdt = TimerGroup::new(timg1).wdt;
+    wdt.set_timeout(
+        MwdtStage::Stage0,
+        esp_hal::time::Duration::from_millis(HARDWARE_TIMEOUT_MS),
+    );
+    wdt.enable();
+
+    if spawner.spawn(watchdog_task(wdt)).is_err() {
+        warn!("watchdog task spawn failed; hardware watchdog left disabled");
+        return;
+    }
+    info!(
+        "watchdog armed hardware_timeout_ms={} interval_ms={}",
+        HARDWARE_TIMEOUT_MS, SUPERVISOR_INTERVAL_MS
+    );
+}
+
+#[embassy_executor::task]
+async fn watchdog_task(mut wdt: Wdt<TIMG1<'static>>) {
+    loop {
+        Timer::after(Duration::from_millis(SUPERVISOR_INTERVAL_MS)).await;
+
+        let now_ms = now_ms();
+        for task in WatchedTask::ALL {
+            let since_ms = BUSY_SINCE_MS[task.slot()].load(Ordering::Relaxed);
+            if since_ms == IDLE {
+                continue;
+            }
+            let busy_ms = now_ms.wrapping_sub(since_ms);
+            if busy_ms > task.stall_timeout_ms() {
+                panic!("watchdog: {} task stalled {} ms", task.label(), busy_ms);
+            }
+        }
+        wdt.feed();
+    }
+}
+
+fn now_ms() -> u32 {
+    Instant::now().as_millis() as u32
+}
@@ -0,0 +1,385 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use domain::{
+    content::CONTENT_TITLE_MAX_BYTES,
+    formatter::{
+        MAX_PARAGRAPH_PREVIEW_BYTES, MAX_READING_TOKEN_BYTES, ReadingUnit, StageFont, UnitFlags,
+    },
+    reader::{READER_WINDOW_MAX_UNITS, ReaderParagraphInfo, ReaderWindow},
+    text::InlineText,
+};
+use services::storage::{FileSource, StorageError};
+
+// Reader packages (`MTRP`) are the prepared, random-access form of an article on the SD card: a
+// fixed header, the title, a paragraph table, then a unit table. Nothing here knows about the card;
+// the storage layer hands in a `FileSource` over the package file.
+
+pub const READER_PACKAGE_MAGIC: u32 = u32::from_le_bytes(*b"MTRP");
+pub const READER_PACKAGE_FORMAT_VERSION: u16 = 1;
+pub const READER_PACKAGE_HEADER_LEN: usize = 32;
+pub const READER_PACKAGE_PARAGRAPH_ENTRY_LEN: usize = 72;
+pub const READER_PACKAGE_UNIT_ENTRY_LEN: usize = 40;
+const UNTITLED_TITLE: &str = "UNTITLED ARTICLE";
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ReaderPackageHeader {
+    pub title_len: u16,
+    pub paragraph_count: u16,
+    pub unit_count: u32,
+    pub paragraph_table_offset: u32,
+    pub unit_table_offset: u32,
+}
+
+pub fn read_header<R: FileSource + ?Sized>(
+    source: &mut R,
+) -> Result<ReaderPackageHeader, StorageError> {
+    let mut bytes = [0u8; READER_PACKAGE_HEADER_LEN];
+    source.read_exact_at(0, &mut bytes)?;
+    decode_reader_package_header(&bytes, source.byte_len())
+}
+
+pub fn read_title<R: FileSource + ?Sized>(
+    source: &mut R,
+    header: ReaderPackageHeader,
+) -> Result<InlineText<CONTENT_TITLE_MAX_BYTES>, StorageError> {
+    let mut title = InlineText::new();
+    let title_len = header.title_len as usize;
+    if title_len == 0 {
+        title.set_truncated(UNTITLED_TITLE);
+        return Ok(title);
+    }
+
+    let mut bytes = alloc::vec![0u8; title_len];
+    source.read_exact_at(READER_PACKAGE_HEADER_LEN as u32, &mut bytes)?;
+    let value = core::str::from_utf8(&bytes).map_err(|_| StorageError::CorruptData)?;
+    title.set_truncated(value);
+    if title.is_empty() {
+        title.set_truncated(UNTITLED_TITLE);
+    }
+    Ok(title)
+}
+
+pub fn read_paragraphs<R: FileSource + ?Sized>(
+    source: &mut R,
+    header: ReaderPackageHeader,
+) -> Result<Vec<ReaderParagraphInfo>, StorageError> {
+    let mut paragraphs = Vec::with_capacity(header.paragraph_count as usize);
+    let mut bytes = [0u8; READER_PACKAGE_PARAGRAPH_ENTRY_LEN];
+    let mut offset = header.paragraph_table_offset;
+    let mut previous_start = None;
+    for _ in 0..header.paragraph_count {
+        source.read_exact_at(offset, &mut bytes)?;
+        let paragraph = decode_reader_package_paragraph_entry(&bytes)?;
+        if paragraph.start_unit_index >= header.unit_count
+            || previous_start.is_some_and(|previous| paragraph.start_unit_index < previous)
+        {
+            return Err(StorageError::CorruptData);
+        }
+        previous_start = Some(paragraph.start_unit_index);
+        paragraphs.push(paragraph);
+        offset += READER_PACKAGE_PARAGRAPH_ENTRY_LEN as u32;
+    }
+    Ok(paragraphs)
+}
+
+// Fills `window` with up to `READER_WINDOW_MAX_UNITS` units starting at `window_start_unit_index`.
+// The caller owns the allocation so large windows can live wherever the platform prefers. Unit
+// entries are fixed width, so a window behind the reader is one seek away; the paragraph table read
+// at open gives the start unit of every paragraph, which is all a backward jump needs.
+pub fn read_window<R: FileSource + ?Sized>(
+    source: &mut R,
+    header: ReaderPackageHeader,
+    window_start_unit_index: u32,
+    window: &mut ReaderWindow,
+) -> Result<(), StorageError> {
+    if window_start_unit_index >= header.unit_count {
+        return Err(StorageError::CorruptData);
+    }
+
+    let remaining = header.unit_count.saturating_sub(window_start_unit_index);
+    let unit_count = remaining.min(READER_WINDOW_MAX_UNITS as u32) as usize;
+    let mut offset = header
+        .unit_table_offset
+        .checked_add(
+            window_start_unit_index
+                .checked_mul(READER_PACKAGE_UNIT_ENTRY_LEN as u32)
+                .ok_or(StorageError::CorruptData)?,
+        )
+        .ok_or(StorageError::CorruptData)?;
+
+    let mut bytes = [0u8; READER_PACKAGE_UNIT_ENTRY_LEN];
+    for unit in window.units.iter_mut().take(unit_count) {
+        source.read_exact_at(offset, &mut bytes)?;
+        *unit = decode_reader_package_unit_entry(&bytes, header.paragraph_count)?;
+        offset += READER_PACKAGE_UNIT_ENTRY_LEN as u32;
+    }
+    window.start_unit_index = window_start_unit_index;
+    window.unit_count = unit_count as u16;
+    Ok(())
+}
+
+fn read_u16(buffer: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([buffer[offset], buffer[offset + 1]])
+}
+
+fn read_u32(buffer: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        buffer[offset],
+        buffer[offset + 1],
+        buffer[offset + 2],
+        buffer[offset + 3],
+    ])
+}
+
+pub fn decode_reader_package_header(
+    bytes: &[u8],
+    file_len: u32,
+) -> Result<ReaderPackageHeader, StorageError> {
+    if bytes.len() < READER_PACKAGE_HEADER_LEN {
+        return Err(StorageError::CorruptData);
+    }
+    if read_u32(bytes, 0) != READER_PACKAGE_MAGIC
+        || read_u16(bytes, 4) != READER_PACKAGE_FORMAT_VERSION
+    {
+        return Err(StorageError::CorruptData);
+    }
+
+    let title_len = read_u16(bytes, 8);
+    let paragraph_count = read_u16(bytes, 10);
+    let unit_count = read_u32(bytes, 12);
+    let paragraph_table_offset = read_u32(bytes, 16);
+    let unit_table_offset = read_u32(bytes, 20);
+    let expected_paragraph_offset = (READER_PACKAGE_HEADER_LEN as u32)
+        .checked_add(title_len as u32)
+        .ok_or(StorageError::CorruptData)?;
+    let expected_unit_offset = expected_paragraph_offset
+        .checked_add(
+            (paragraph_count as u32)
+                .checked_mul(READER_PACKAGE_PARAGRAPH_ENTRY_LEN as u32)
+                .ok_or(StorageError::CorruptData)?,
+        )
+        .ok_or(StorageError::CorruptData)?;
+    let expected_file_len = expected_unit_offset
+        .checked_add(
+            unit_count
+                .checked_mul(READER_PACKAGE_UNIT_ENTRY_LEN as u32)
+                .ok_or(StorageError::CorruptData)?,
+        )
+        .ok_or(StorageError::CorruptData)?;
+
+    if paragraph_count == 0
+        || unit_count == 0
+        || paragraph_table_offset != expected_paragraph_offset
+        || unit_table_offset != expected_unit_offset
+        || expected_file_len != file_len
+    {
+        return Err(StorageError::CorruptData);
+    }
+
+    Ok(ReaderPackageHeader {
+        title_len,
+        paragraph_count,
+        unit_count,
+        paragraph_table_offset,
+        unit_table_offset,
+    })
+}
+pub fn decode_reader_package_paragraph_entry(
+    bytes: &[u8],
+) -> Result<ReaderParagraphInfo, StorageError> {
+    if bytes.len() < READER_PACKAGE_PARAGRAPH_ENTRY_LEN {
+        return Err(StorageError::CorruptData);
+    }
+
+    let preview_len = bytes[4] as usize;
+    if preview_len > MAX_PARAGRAPH_PREVIEW_BYTES || 8 + preview_len > bytes.len() {
+        return Err(StorageError::CorruptData);
+    }
+
+    let preview_text =
+        core::str::from_utf8(&bytes[8..8 + preview_len]).map_err(|_| StorageError::CorruptData)?;
+    let mut preview = InlineText::new();
+    preview.set_truncated(preview_text);
+    Ok(ReaderParagraphInfo {
+        start_unit_index: read_u32(bytes, 0),
+        preview,
+    })
+}
+
+fn font_from_byte(byte: u8) -> Result<StageFont, StorageError> {
+    match byte {
+        0 => Ok(StageFont::Large),
+        1 => Ok(StageFont::Medium),
+        2 => Ok(StageFont::Small),
+        _ => Err(StorageError::CorruptData),
+    }
+}
+
+fn flags_from_byte(byte: u8) -> UnitFlags {
+    UnitFlags {
+        clause_pause: (byte & 0b0001) != 0,
+        sentence_pause: (byte & 0b0010) != 0,
+        paragraph_start: (byte & 0b0100) != 0,
+        paragraph_end: (byte & 0b1000) != 0,
+        section_start: (byte & 0b1_0000) != 0,
+    }
+}
+
+pub fn decode_reader_package_unit_entry(
+    bytes: &[u8],
+    paragraph_count: u16,
+) -> Result<ReadingUnit, StorageError> {
+    if bytes.len() < READER_PACKAGE_UNIT_ENTRY_LEN {
+        return Err(StorageError::CorruptData);
+    }
+
+    let paragraph_index = read_u16(bytes, 0);
+    let display_len = bytes[6] as usize;
+    if paragraph_index == 0
+        || paragraph_index > paragraph_count
+        || display_len == 0
+        || display_len > MAX_READING_TOKEN_BYTES
+        || 8 + display_len > bytes.len()
+    {
+        return Err(StorageError::CorruptData);
+    }
+
+    let display_text =
+        core::str::from_utf8(&bytes[8..8 + display_len]).map_err(|_| StorageError::CorruptData)?;
+    let mut display = InlineText::new();
+    display.set_truncated(display_text);
+    if display.is_empty() {
+        return Err(StorageError::CorruptData);
+    }
+
+    let char_count = bytes[3];
+    if char_count == 0 {
+        return Err(StorageError::CorruptData);
+    }
+
+    Ok(ReadingUnit {
+        display,
+        paragraph_index: paragraph_index.min(u8::MAX as u16) as u8,
+        anchor_index: bytes[2].min(char_count.saturating_sub(1)),
+        char_count,
+        font: font_from_byte(bytes[4])?,
+        flags: flags_from_byte(bytes[5]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package_bytes(title: &str, paragraphs: &[(u32, &str)], units: &[(u16, &str)]) -> Vec<u8> {
+        let paragraph_table_offset = READER_PACKAGE_HEADER_LEN + title.len();
+        let unit_table_offset =
+            paragraph_table_offset + paragraphs.len() * READER_PACKAGE_PARAGRAPH_ENTRY_LEN;
+
+        let mut bytes = alloc::vec![0u8; READER_PACKAGE_HEADER_LEN];
+        bytes[0..4].copy_from_slice(&READER_PACKAGE_MAGIC.to_le_bytes());
+        bytes[4..6].copy_from_slice(&READER_PACKAGE_FORMAT_VERSION.to_le_bytes());
+        bytes[8..10].copy_from_slice(&(title.len() as u16).to_le_bytes());
+        bytes[10..12].copy_from_slice(&(paragraphs.len() as u16).to_le_bytes());
+        bytes[12..16].copy_from_slice(&(units.len() as u32).to_le_bytes());
+        bytes[16..20].copy_from_slice(&(paragraph_table_offset as u32).to_le_bytes());
+        bytes[20..24].copy_from_slice(&(unit_table_offset as u32).to_le_bytes());
+        bytes.extend_from_slice(title.as_bytes());
+
+        for (start_unit_index, preview) in paragraphs {
+            let mut entry = [0u8; READER_PACKAGE_PARAGRAPH_ENTRY_LEN];
+            entry[0..4].copy_from_slice(&start_unit_index.to_le_bytes());
+            entry[4] = preview.len() as u8;
+            entry[8..8 + preview.len()].copy_from_slice(preview.as_bytes());
+            bytes.extend_from_slice(&entry);
+        }
+
+        for (paragraph_index, display) in units {
+            let mut entry = [0u8; READER_PACKAGE_UNIT_ENTRY_LEN];
+            entry[0..2].copy_from_slice(&paragraph_index.to_le_bytes());
+            entry[2] = 1;
+            entry[3] = display.chars().count() as u8;
+            entry[5] = 0b0010;
+            entry[6] = display.len() as u8;
+            entry[8..8 + display.len()].copy_from_slice(display.as_bytes());
+            bytes.extend_from_slice(&entry);
+        }
+        bytes
+    }
+
+    #[test]
+    fn reads_a_package_through_a_random_access_source() {
+        let bytes = package_bytes(
+            "Short story",
+            &[(0, "Once upon"), (2, "The end")],
+            &[(1, "Once"), (1, "upon."), (2, "The"), (2, "end.")],
+        );
+        let mut source = bytes.as_slice();
+
+        let header = read_header(&mut source).unwrap();
+        assert_eq!(header.unit_count, 4);
+        assert_eq!(
+            read_title(&mut source, header).unwrap().as_str(),
+            "Short story"
+        );
+
+        let paragraphs = read_paragraphs(&mut source, header).unwrap();
+        assert_eq!(paragraphs.len(), 2);
+        assert_eq!(paragraphs[1].start_unit_index, 2);
+        assert_eq!(paragraphs[1].preview.as_str(), "The end");
+
+        let mut window = ReaderWindow::empty();
+        read_window(&mut source, header, 1, &mut window).unwrap();
+        assert_eq!(window.start_unit_index, 1);
+        assert_eq!(window.unit_count, 3);
+        assert_eq!(window.units[0].display.as_str(), "upon.");
+        assert_eq!(window.units[2].paragraph_index, 2);
+        assert!(window.units[2].flags.sentence_pause);
+    }
+
+    #[test]
+    fn truncated_package_is_corrupt() {
+        let bytes = package_bytes("Cut", &[(0, "Cut")], &[(1, "Cut")]);
+        let mut source = &bytes[..bytes.len() - 1];
+
+        assert_eq!(
+            read_header(&mut source).unwrap_err(),
+            StorageError::CorruptData
+        );
+    }
+
+    #[test]
+    fn paragraphs_out_of_order_are_corrupt() {
+        let bytes = package_bytes(
+            "Order",
+            &[(1, "Second"), (0, "First")],
+            &[(1, "First"), (2, "Second")],
+        );
+        let mut source = bytes.as_slice();
+        let header = read_header(&mut source).unwrap();
+
+        assert_eq!(
+            read_paragraphs(&mut source, header).unwrap_err(),
+            StorageError::CorruptData
+        );
+    }
+
+    #[test]
+    fn reader_package_header_rejects_tables_past_the_file_end() {
+        let mut bytes = [0u8; READER_PACKAGE_HEADER_LEN];
+        bytes[0..4].copy_from_slice(&READER_PACKAGE_MAGIC.to_le_bytes());
+        bytes[4..6].copy_from_slice(&READER_PACKAGE_FORMAT_VERSION.to_le_bytes());
+        bytes[10..12].copy_from_slice(&1u16.to_le_bytes());
+        bytes[12..16].copy_from_slice(&u32::MAX.to_le_bytes());
+        bytes[16..20].copy_from_slice(&(READER_PACKAGE_HEADER_LEN as u32).to_le_bytes());
+
+        assert_eq!(
+            decode_reader_package_header(&bytes, 4096).unwrap_err(),
+            StorageError::CorruptData
+        );
+    }
+}
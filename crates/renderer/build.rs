@@ -0,0 +1,398 @@
+// Generates the stage rasters: the UI fonts redrawn at twice their size with Scale2x, which rounds
+// diagonals and curves instead of turning every pixel into a 2x2 block, plus bold and italic faces
+// of every stage size. The atlases land in OUT_DIR as `ImageRaw` data and `stage_rasters.rs` wraps
+// them in `MonoFont`s.
+//
+// It also measures the UI fonts for proportional layout: each glyph's ink columns and the ASCII
+// pairs that can sit a pixel closer, written to `text_metrics.rs`.
+
+use std::{env, fmt::Write as _, fs, path::PathBuf};
+
+use embedded_graphics::{
+    image::GetPixel,
+    mono_font::{
+        MonoFont,
+        iso_8859_1::{FONT_6X10, FONT_8X13, FONT_8X13_BOLD, FONT_10X20},
+    },
+    pixelcolor::BinaryColor,
+    prelude::*,
+};
+
+#[derive(Clone, Copy)]
+enum Face {
+    Regular,
+    Bold,
+    Italic,
+}
+
+struct StageRaster {
+    name: &'static str,
+    source_name: &'static str,
+    source: &'static MonoFont<'static>,
+    doubled: bool,
+    face: Face,
+}
+
+const fn raster(
+    name: &'static str,
+    source_name: &'static str,
+    source: &'static MonoFont<'static>,
+    doubled: bool,
+    face: Face,
+) -> StageRaster {
+    StageRaster {
+        name,
+        source_name,
+        source,
+        doubled,
+        face,
+    }
+}
+
+// FONT_10X20 is drawn as-is for the regular small face, so it has no entry here.
+const STAGE_RASTERS: [StageRaster; 8] = [
+    raster(
+        "STAGE_FONT_10X20_BOLD",
+        "FONT_10X20",
+        &FONT_10X20,
+        false,
+        Face::Bold,
+    ),
+    raster(
+        "STAGE_FONT_10X20_ITALIC",
+        "FONT_10X20",
+        &FONT_10X20,
+        false,
+        Face::Italic,
+    ),
+    raster(
+        "STAGE_FONT_16X26",
+        "FONT_8X13_BOLD",
+        &FONT_8X13_BOLD,
+        true,
+        Face::Regular,
+    ),
+    raster(
+        "STAGE_FONT_16X26_BOLD",
+        "FONT_8X13_BOLD",
+        &FONT_8X13_BOLD,
+        true,
+        Face::Bold,
+    ),
+    raster(
+        "STAGE_FONT_16X26_ITALIC",
+        "FONT_8X13_BOLD",
+        &FONT_8X13_BOLD,
+        true,
+        Face::Italic,
+    ),
+    raster(
+        "STAGE_FONT_20X40",
+        "FONT_10X20",
+        &FONT_10X20,
+        true,
+        Face::Regular,
+    ),
+    raster(
+        "STAGE_FONT_20X40_BOLD",
+        "FONT_10X20",
+        &FONT_10X20,
+        true,
+        Face::Bold,
+    ),
+    raster(
+        "STAGE_FONT_20X40_ITALIC",
+        "FONT_10X20",
+        &FONT_10X20,
+        true,
+        Face::Italic,
+    ),
+];
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR is set for build scripts"));
+    let mut module = String::new();
+    for raster in &STAGE_RASTERS {
+        let mut atlas = Atlas::from_font(raster.source);
+        if raster.doubled {
+            atlas = atlas.scale2x();
+        }
+        match raster.face {
+            Face::Regular => {}
+            Face::Bold => atlas = atlas.emboldened(),
+            Face::Italic => atlas = atlas.slanted(),
+        }
+        let (bytes, width) = atlas.to_raw();
+        let file_name = format!("{}.raw", raster.name.to_lowercase());
+        fs::write(out_dir.join(&file_name), bytes).expect("stage raster is writable");
+
+        let source = raster.source;
+        let size = source.character_size;
+        let factor = if raster.doubled { 2 } else { 1 };
+        writeln!(
+            module,
+            "pub(crate) const {name}: MonoFont<'static> = MonoFont {{\n    \
+             image: ImageRaw::new(include_bytes!(concat!(env!(\"OUT_DIR\"), \"/{file_name}\")), {width}),\n    \
+             character_size: Size::new({w}, {h}),\n    \
+             character_spacing: {spacing},\n    \
+             baseline: {baseline},\n    \
+             strikethrough: DecorationDimensions::new({so}, {sh}),\n    \
+             underline: DecorationDimensions::new({uo}, {uh}),\n    \
+             glyph_mapping: {source_name}.glyph_mapping,\n}};",
+            name = raster.name,
+            w = size.width * factor,
+            h = size.height * factor,
+            spacing = source.character_spacing * factor,
+            baseline = source.baseline * factor + factor - 1,
+            so = source.strikethrough.offset * factor,
+            sh = source.strikethrough.height * factor,
+            uo = source.underline.offset * factor,
+            uh = source.underline.height * factor,
+            source_name = raster.source_name,
+        )
+        .unwrap();
+    }
+    fs::write(out_dir.join("stage_rasters.rs"), module).expect("stage raster module is writable");
+
+    let mut metrics = String::new();
+    for (name, font) in TEXT_FONTS {
+        write_text_metrics(&mut metrics, name, font);
+    }
+    fs::write(out_dir.join("text_metrics.rs"), metrics).expect("text metrics module is writable");
+}
+
+const TEXT_FONTS: [(&str, &MonoFont<'static>); 2] = [("6X10", &FONT_6X10), ("8X13", &FONT_8X13)];
+// Blank pixels kept between the ink of neighbouring glyphs, before kerning.
+const TEXT_GLYPH_GAP: u32 = 1;
+
+// Per glyph `(left, advance)`: the first inked column and the width the glyph takes in a line,
+// indexed like the font's glyph mapping. Blank glyphs advance half a cell.
+fn write_text_metrics(out: &mut String, name: &str, font: &MonoFont<'_>) {
+    let atlas = Atlas::from_font(font);
+    let glyphs = atlas.cell_count();
+    let columns: Vec<Vec<Option<(u32, u32)>>> =
+        (0..glyphs).map(|glyph| atlas.row_ink(glyph)).collect();
+
+    writeln!(
+        out,
+        "const GLYPH_METRICS_{name}: [GlyphMetrics; {glyphs}] = ["
+    )
+    .unwrap();
+    for rows in &columns {
+        let (left, advance) = match ink_extent(rows) {
+            Some((left, right)) => (left, right - left + 1 + TEXT_GLYPH_GAP),
+            None => (0, font.character_size.width.div_ceil(2)),
+        };
+        writeln!(out, "    GlyphMetrics::new({left}, {advance}),").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    // A pair kerns when, row by row (and one row either side, so diagonals never touch), the ink
+    // of the two glyphs stays at least two pixels further apart than the usual gap.
+    let mut pairs = Vec::new();
+    for left in '!'..='~' {
+        for right in '!'..='~' {
+            let left_rows = &columns[font.glyph_mapping.index(left)];
+            let right_rows = &columns[font.glyph_mapping.index(right)];
+            if kerning_gap(left_rows, right_rows).is_some_and(|gap| gap >= TEXT_GLYPH_GAP + 2) {
+                pairs.push(u16::from(left as u8) << 8 | u16::from(right as u8));
+            }
+        }
+    }
+    writeln!(
+        out,
+        "const KERN_PAIRS_{name}: [u16; {}] = {pairs:?};",
+        pairs.len()
+    )
+    .unwrap();
+}
+
+fn ink_extent(rows: &[Option<(u32, u32)>]) -> Option<(u32, u32)> {
+    let left = rows.iter().flatten().map(|(left, _)| *left).min()?;
+    let right = rows.iter().flatten().map(|(_, right)| *right).max()?;
+    Some((left, right))
+}
+
+// The narrowest gap between two glyphs set side by side with the usual spacing.
+fn kerning_gap(left: &[Option<(u32, u32)>], right: &[Option<(u32, u32)>]) -> Option<u32> {
+    let (_, left_edge) = ink_extent(left)?;
+    let (right_edge, _) = ink_extent(right)?;
+    let mut narrowest = None::<u32>;
+    for (row, left_row) in left.iter().enumerate() {
+        let Some((_, left_right)) = left_row else {
+            continue;
+        };
+        let nearby = &right[row.saturating_sub(1)..(row + 2).min(right.len())];
+        for (right_left, _) in nearby.iter().flatten() {
+            let gap = TEXT_GLYPH_GAP + (left_edge - left_right) + (right_left - right_edge);
+            narrowest = Some(narrowest.map_or(gap, |narrowest| narrowest.min(gap)));
+        }
+    }
+    narrowest
+}
+
+// A font atlas as a pixel grid, cut into glyph cells of `cell` size. No transform lets ink cross a
+// cell edge, so glyphs never bleed into each other.
+struct Atlas {
+    width: u32,
+    height: u32,
+    cell: Size,
+    ink: Vec<bool>,
+}
+
+impl Atlas {
+    fn from_font(font: &MonoFont<'_>) -> Self {
+        let size = font.image.size();
+        let ink = (0..size.height as i32)
+            .flat_map(|y| (0..size.width as i32).map(move |x| Point::new(x, y)))
+            .map(|point| font.image.pixel(point) == Some(BinaryColor::On))
+            .collect();
+        Self {
+            width: size.width,
+            height: size.height,
+            cell: font.character_size,
+            ink,
+        }
+    }
+
+    fn blank(&self, width: u32, height: u32, cell: Size) -> Self {
+        Self {
+            width,
+            height,
+            cell,
+            ink: vec![false; (width * height) as usize],
+        }
+    }
+
+    // Ink at `(x, y)` as seen from the cell holding `(from_x, from_y)`; anything outside is blank.
+    fn ink_in_cell(&self, x: i32, y: i32, from_x: i32, from_y: i32) -> bool {
+        let (cell_w, cell_h) = (self.cell.width as i32, self.cell.height as i32);
+        let same_cell =
+            x >= 0 && y >= 0 && x / cell_w == from_x / cell_w && y / cell_h == from_y / cell_h;
+        same_cell && (x as u32) < self.width && (y as u32) < self.height && self.get(x, y)
+    }
+
+    fn get(&self, x: i32, y: i32) -> bool {
+        self.ink[(y as u32 * self.width + x as u32) as usize]
+    }
+
+    fn set(&mut self, x: i32, y: i32) {
+        self.ink[(y as u32 * self.width + x as u32) as usize] = true;
+    }
+
+    // Scale2x (EPX): each pixel becomes four, and a corner takes its neighbours' colour where two
+    // of them agree, which rounds diagonals and curves instead of leaving 2x2 steps.
+    fn scale2x(&self) -> Self {
+        let mut out = self.blank(
+            self.width * 2,
+            self.height * 2,
+            Size::new(self.cell.width * 2, self.cell.height * 2),
+        );
+        for y in 0..self.height as i32 {
+            for x in 0..self.width as i32 {
+                let p = self.get(x, y);
+                let a = self.ink_in_cell(x, y - 1, x, y);
+                let b = self.ink_in_cell(x + 1, y, x, y);
+                let c = self.ink_in_cell(x - 1, y, x, y);
+                let d = self.ink_in_cell(x, y + 1, x, y);
+
+                let top_left = if c == a && c != d && a != b { a } else { p };
+                let top_right = if a == b && a != c && b != d { b } else { p };
+                let bottom_left = if d == c && d != b && c != a { c } else { p };
+                let bottom_right = if b == d && b != a && d != c { d } else { p };
+
+                for (dx, dy, on) in [
+                    (0, 0, top_left),
+                    (1, 0, top_right),
+                    (0, 1, bottom_left),
+                    (1, 1, bottom_right),
+                ] {
+                    if on {
+                        out.set(x * 2 + dx, y * 2 + dy);
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    // Thickens vertical strokes by one pixel per 10 px of cell width, spreading ink rightwards.
+    fn emboldened(&self) -> Self {
+        let spread = (self.cell.width / 10).max(1) as i32;
+        self.remapped(|x, _| (0..=spread).map(move |dx| x + dx))
+    }
+
+    // Shears each row right in proportion to its height above the cell's middle row.
+    fn slanted(&self) -> Self {
+        let cell_h = self.cell.height as i32;
+        self.remapped(|x, y| {
+            let shift = (cell_h / 2 - y % cell_h) / 5;
+            core::iter::once(x + shift)
+        })
+    }
+
+    fn remapped<I>(&self, targets: impl Fn(i32, i32) -> I) -> Self
+    where
+        I: Iterator<Item = i32>,
+    {
+        let mut out = self.blank(self.width, self.height, self.cell);
+        for y in 0..self.height as i32 {
+            for x in 0..self.width as i32 {
+                if !self.get(x, y) {
+                    continue;
+                }
+                for target_x in targets(x, y) {
+                    if self.in_cell(target_x, y, x, y) {
+                        out.set(target_x, y);
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    fn in_cell(&self, x: i32, y: i32, from_x: i32, from_y: i32) -> bool {
+        let (cell_w, cell_h) = (self.cell.width as i32, self.cell.height as i32);
+        x >= 0
+            && (x as u32) < self.width
+            && x / cell_w == from_x / cell_w
+            && y / cell_h == from_y / cell_h
+    }
+
+    fn cell_count(&self) -> usize {
+        ((self.width / self.cell.width) * (self.height / self.cell.height)) as usize
+    }
+
+    // The first and last inked column of each row of one glyph, relative to its cell.
+    fn row_ink(&self, glyph: usize) -> Vec<Option<(u32, u32)>> {
+        let per_row = (self.width / self.cell.width) as usize;
+        let origin_x = ((glyph % per_row) as u32 * self.cell.width) as i32;
+        let origin_y = ((glyph / per_row) as u32 * self.cell.height) as i32;
+        (0..self.cell.height as i32)
+            .map(|y| {
+                let inked =
+                    (0..self.cell.width as i32).filter(|x| self.get(origin_x + x, origin_y + y));
+                let left = inked.clone().min()?;
+                let right = inked.max()?;
+                Some((left as u32, right as u32))
+            })
+            .collect()
+    }
+
+    // `ImageRaw` layout: rows MSB-first, padded to whole bytes.
+    fn to_raw(&self) -> (Vec<u8>, u32) {
+        let stride = self.width.div_ceil(8) as usize;
+        let mut bytes = vec![0u8; stride * self.height as usize];
+        for y in 0..self.height as i32 {
+            for x in 0..self.width as i32 {
+                if self.get(x, y) {
+                    let (x, y) = (x as usize, y as usize);
+                    bytes[y * stride + x / 8] |= 0x80 >> (x % 8);
+                }
+            }
+        }
+        (bytes, self.width)
+    }
+}
@@ -0,0 +1,164 @@
+// Arabic letters join their neighbours, and a font pack holds one bitmap per code point, so the
+// stage draws the Presentation Forms-B glyph for each letter's position instead of the base
+// letter. Only the basic letters (U+0621..U+064A) and the four lam-alef ligatures are covered;
+// Persian and Urdu additions draw in their isolated form.
+
+const ARABIC_LETTERS_START: u32 = 0x0621;
+const TATWEEL: char = '\u{0640}';
+const LAM: char = '\u{0644}';
+
+#[derive(Clone, Copy)]
+enum Joining {
+    // Joins on neither side (hamza).
+    None,
+    // Joins only to the letter before it (alef, dal, reh, waw, ...).
+    Right,
+    // Joins on both sides.
+    Dual,
+}
+
+// Isolated presentation form and joining class for U+0621..U+064A; 0 marks a code point with no
+// form (the unassigned U+063B..U+063F and tatweel).
+#[rustfmt::skip]
+const LETTERS: [(u16, Joining); 42] = [
+    (0xFE80, Joining::None),  (0xFE81, Joining::Right), (0xFE83, Joining::Right),
+    (0xFE85, Joining::Right), (0xFE87, Joining::Right), (0xFE89, Joining::Dual),
+    (0xFE8D, Joining::Right), (0xFE8F, Joining::Dual),  (0xFE93, Joining::Right),
+    (0xFE95, Joining::Dual),  (0xFE99, Joining::Dual),  (0xFE9D, Joining::Dual),
+    (0xFEA1, Joining::Dual),  (0xFEA5, Joining::Dual),  (0xFEA9, Joining::Right),
+    (0xFEAB, Joining::Right), (0xFEAD, Joining::Right), (0xFEAF, Joining::Right),
+    (0xFEB1, Joining::Dual),  (0xFEB5, Joining::Dual),  (0xFEB9, Joining::Dual),
+    (0xFEBD, Joining::Dual),  (0xFEC1, Joining::Dual),  (0xFEC5, Joining::Dual),
+    (0xFEC9, Joining::Dual),  (0xFECD, Joining::Dual),  (0, Joining::None),
+    (0, Joining::None),       (0, Joining::None),       (0, Joining::None),
+    (0, Joining::None),       (0, Joining::Dual),       (0xFED1, Joining::Dual),
+    (0xFED5, Joining::Dual),  (0xFED9, Joining::Dual),  (0xFEDD, Joining::Dual),
+    (0xFEE1, Joining::Dual),  (0xFEE5, Joining::Dual),  (0xFEE9, Joining::Dual),
+    (0xFEED, Joining::Right), (0xFEEF, Joining::Right), (0xFEF1, Joining::Dual),
+];
+
+fn letter(ch: char) -> Option<(u16, Joining)> {
+    (ch as u32)
+        .checked_sub(ARABIC_LETTERS_START)
+        .and_then(|offset| LETTERS.get(offset as usize))
+        .copied()
+}
+
+// Harakat and other marks sit on a letter without breaking the join across it.
+fn is_transparent(ch: char) -> bool {
+    matches!(ch as u32, 0x064B..=0x065F | 0x0670)
+}
+
+fn joins_forward(ch: char) -> bool {
+    ch == TATWEEL || matches!(letter(ch), Some((_, Joining::Dual)))
+}
+
+fn joins_backward(ch: char) -> bool {
+    ch == TATWEEL || matches!(letter(ch), Some((_, Joining::Dual | Joining::Right)))
+}
+
+// Lam followed by one of these alefs becomes a single ligature glyph.
+fn lam_alef_ligature(alef: char) -> Option<u32> {
+    match alef {
+        '\u{0622}' => Some(0xFEF5),
+        '\u{0623}' => Some(0xFEF7),
+        '\u{0625}' => Some(0xFEF9),
+        '\u{0627}' => Some(0xFEFB),
+        _ => None,
+    }
+}
+
+// Calls `emit` with each shaped character and the index of the first source character it stands
+// for, still in reading order. Text without Arabic letters comes through unchanged.
+pub(crate) fn shape(text: &str, mut emit: impl FnMut(usize, char)) {
+    let mut letters = text.chars().enumerate();
+    let mut previous_joins = false;
+
+    while let Some((index, ch)) = letters.next() {
+        if is_transparent(ch) {
+            emit(index, ch);
+            continue;
+        }
+
+        let mut rest = letters
+            .clone()
+            .map(|(_, next)| next)
+            .skip_while(|next| is_transparent(*next));
+        let next = rest.next();
+
+        if ch == LAM
+            && let Some(ligature) = next.and_then(lam_alef_ligature)
+        {
+            let form = ligature + u32::from(previous_joins);
+            emit(index, char::from_u32(form).unwrap_or(ch));
+            for (_, skipped) in letters.by_ref() {
+                if !is_transparent(skipped) {
+                    break;
+                }
+            }
+            // Lam-alef ends in an alef, which never joins forward.
+            previous_joins = false;
+            continue;
+        }
+
+        let Some((isolated, joining)) = letter(ch).filter(|(isolated, _)| *isolated != 0) else {
+            emit(index, ch);
+            previous_joins = joins_forward(ch);
+            continue;
+        };
+
+        let joins_previous = previous_joins;
+        let joins_next = matches!(joining, Joining::Dual) && next.is_some_and(joins_backward);
+        let offset = match (joins_previous, joins_next) {
+            (false, false) => 0,
+            (true, false) => 1,
+            (false, true) => 2,
+            (true, true) => 3,
+        };
+        emit(
+            index,
+            char::from_u32(u32::from(isolated) + offset).unwrap_or(ch),
+        );
+        previous_joins = joins_next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shaped(text: &str) -> heapless::Vec<(usize, char), 16> {
+        let mut out = heapless::Vec::new();
+        shape(text, |index, ch| {
+            let _ = out.push((index, ch));
+        });
+        out
+    }
+
+    #[test]
+    fn letters_take_their_joined_forms_and_lam_alef_ligates() {
+        // beh, teh, beh: initial, medial, final.
+        let chars: heapless::Vec<char, 16> = shaped("\u{0628}\u{062A}\u{0628}")
+            .iter()
+            .map(|(_, ch)| *ch)
+            .collect();
+        assert_eq!(chars, ['\u{FE91}', '\u{FE98}', '\u{FE90}']);
+
+        // dal does not join forward, so the beh after it starts a new group.
+        let chars: heapless::Vec<char, 16> = shaped("\u{0628}\u{062F}\u{0628}")
+            .iter()
+            .map(|(_, ch)| *ch)
+            .collect();
+        assert_eq!(chars, ['\u{FE91}', '\u{FEAA}', '\u{FE8F}']);
+
+        // beh, lam, alef: the ligature takes its final form and covers two source letters.
+        assert_eq!(
+            shaped("\u{0628}\u{0644}\u{0627}").as_slice(),
+            [(0, '\u{FE91}'), (1, '\u{FEFC}')]
+        );
+        assert_eq!(
+            shaped("שלום").as_slice(),
+            [(0, 'ש'), (1, 'ל'), (2, 'ו'), (3, 'ם')]
+        );
+    }
+}
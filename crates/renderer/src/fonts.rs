@@ -0,0 +1,299 @@
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use embedded_graphics::prelude::Size;
+
+// Font packs supply glyphs the built-in ISO-8859-1 fonts lack (CJK, Cyrillic, Greek, ...). A pack
+// is a fixed-cell bitmap font in this layout, integers little-endian:
+//
+//    0  magic "MFNT"
+//    4  version (1)
+//    5  glyph width in pixels
+//    6  glyph height in pixels
+//    7  reserved, zero
+//    8  glyph count (u32)
+//   12  code points (u32 each), strictly ascending
+//   ..  bitmaps in the same order, rows MSB-first padded to whole bytes, 1 = ink
+//
+// Each glyph is drawn centred in one text cell, so a pack rasterized at a UI font's cell size
+// (6x10, 8x13 or 10x20) lines up with the text around it.
+
+pub const FONT_PACK_MAGIC: [u8; 4] = *b"MFNT";
+pub const FONT_PACK_VERSION: u8 = 1;
+pub const MAX_FONT_PACKS: usize = 4;
+const FONT_PACK_HEADER_LEN: usize = 12;
+// Anything up to here draws from the built-in fonts.
+const BUILT_IN_LAST_CODE_POINT: u32 = 0xFF;
+
+static INSTALLED: AtomicPtr<FontLibrary> = AtomicPtr::new(core::ptr::null_mut());
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FontPackError {
+    BadMagic,
+    UnsupportedVersion,
+    EmptyCell,
+    Truncated,
+    Unsorted,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Glyph<'a> {
+    pub width: u8,
+    pub height: u8,
+    rows: &'a [u8],
+}
+
+impl Glyph<'_> {
+    pub fn is_ink(&self, x: u32, y: u32) -> bool {
+        let stride = usize::from(self.width).div_ceil(8);
+        let Some(byte) = self.rows.get(y as usize * stride + x as usize / 8) else {
+            return false;
+        };
+        byte & (0x80 >> (x % 8)) != 0
+    }
+}
+
+// Where the renderer asks for glyphs it cannot draw itself; `cell` is the text cell to fill.
+pub trait GlyphProvider {
+    fn glyph(&self, ch: char, cell: Size) -> Option<Glyph<'_>>;
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct FontPack<'a> {
+    width: u8,
+    height: u8,
+    glyph_count: usize,
+    code_points: &'a [u8],
+    bitmaps: &'a [u8],
+}
+
+impl<'a> FontPack<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, FontPackError> {
+        let header = bytes
+            .get(..FONT_PACK_HEADER_LEN)
+            .ok_or(FontPackError::Truncated)?;
+        if header[..4] != FONT_PACK_MAGIC {
+            return Err(FontPackError::BadMagic);
+        }
+        if header[4] != FONT_PACK_VERSION {
+            return Err(FontPackError::UnsupportedVersion);
+        }
+        let (width, height) = (header[5], header[6]);
+        if width == 0 || height == 0 {
+            return Err(FontPackError::EmptyCell);
+        }
+
+        let glyph_count =
+            u32::from_le_bytes([header[8], header[9], header[10], header[11]]) as usize;
+        let glyph_len = usize::from(width).div_ceil(8) * usize::from(height);
+        let table_end = glyph_count
+            .checked_mul(4)
+            .and_then(|len| len.checked_add(FONT_PACK_HEADER_LEN))
+            .ok_or(FontPackError::Truncated)?;
+        let bitmaps_end = glyph_count
+            .checked_mul(glyph_len)
+            .and_then(|len| len.checked_add(table_end))
+            .ok_or(FontPackError::Truncated)?;
+        let code_points = bytes
+            .get(FONT_PACK_HEADER_LEN..table_end)
+            .ok_or(FontPackError::Truncated)?;
+        let bitmaps = bytes
+            .get(table_end..bitmaps_end)
+            .ok_or(FontPackError::Truncated)?;
+
+        let pack = Self {
+            width,
+            height,
+            glyph_count,
+            code_points,
+            bitmaps,
+        };
+        if (1..glyph_count).any(|index| pack.code_point(index - 1) >= pack.code_point(index)) {
+            return Err(FontPackError::Unsorted);
+        }
+        Ok(pack)
+    }
+
+    pub const fn width(&self) -> u8 {
+        self.width
+    }
+
+    pub const fn height(&self) -> u8 {
+        self.height
+    }
+
+    pub const fn glyph_count(&self) -> usize {
+        self.glyph_count
+    }
+
+    pub fn glyph(&self, ch: char) -> Option<Glyph<'a>> {
+        let wanted = u32::from(ch);
+        let (mut low, mut high) = (0, self.glyph_count);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match self.code_point(middle).cmp(&wanted) {
+                core::cmp::Ordering::Less => low = middle + 1,
+                core::cmp::Ordering::Greater => high = middle,
+                core::cmp::Ordering::Equal => {
+                    let glyph_len = usize::from(self.width).div_ceil(8) * usize::from(self.height);
+                    let start = middle * glyph_len;
+                    return Some(Glyph {
+                        width: self.width,
+                        height: self.height,
+                        rows: &self.bitmaps[start..start + glyph_len],
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    fn code_point(&self, index: usize) -> u32 {
+        let bytes = &self.code_points[index * 4..index * 4 + 4];
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+}
+
+impl GlyphProvider for FontPack<'_> {
+    fn glyph(&self, ch: char, _cell: Size) -> Option<Glyph<'_>> {
+        FontPack::glyph(self, ch)
+    }
+}
+
+// Every pack found at boot. Packs of several sizes can cover the same characters; each lookup takes
+// the tallest glyph that still fits the cell, or the smallest one when none does.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct FontLibrary {
+    packs: [Option<FontPack<'static>>; MAX_FONT_PACKS],
+}
+
+impl FontLibrary {
+    pub const fn new() -> Self {
+        Self {
+            packs: [None; MAX_FONT_PACKS],
+        }
+    }
+
+    pub fn try_push(&mut self, pack: FontPack<'static>) -> bool {
+        let Some(slot) = self.packs.iter_mut().find(|slot| slot.is_none()) else {
+            return false;
+        };
+        *slot = Some(pack);
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.packs.iter().flatten().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl GlyphProvider for FontLibrary {
+    fn glyph(&self, ch: char, cell: Size) -> Option<Glyph<'_>> {
+        self.packs
+            .iter()
+            .flatten()
+            .filter_map(|pack| pack.glyph(ch))
+            .max_by_key(|glyph| {
+                let fits = u32::from(glyph.height) <= cell.height;
+                (fits, if fits { glyph.height } else { !glyph.height })
+            })
+    }
+}
+
+// Called once at boot; text drawn afterwards falls back to these packs.
+pub fn install(library: &'static FontLibrary) {
+    INSTALLED.store(core::ptr::from_ref(library).cast_mut(), Ordering::Release);
+}
+
+pub(crate) fn installed() -> Option<&'static FontLibrary> {
+    // SAFETY: only `install` stores here, and it takes a `'static` shared reference.
+    unsafe { INSTALLED.load(Ordering::Acquire).as_ref() }
+}
+
+pub(crate) const fn needs_provider(ch: char) -> bool {
+    ch as u32 > BUILT_IN_LAST_CODE_POINT
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pack_bytes(width: u8, height: u8, glyphs: &[(char, &[u8])]) -> Vec<u8> {
+        let mut bytes = Vec::from(FONT_PACK_MAGIC);
+        bytes.extend_from_slice(&[FONT_PACK_VERSION, width, height, 0]);
+        bytes.extend_from_slice(&(glyphs.len() as u32).to_le_bytes());
+        for (ch, _) in glyphs {
+            bytes.extend_from_slice(&u32::from(*ch).to_le_bytes());
+        }
+        for (_, rows) in glyphs {
+            bytes.extend_from_slice(rows);
+        }
+        bytes
+    }
+
+    #[test]
+    fn packs_parse_and_look_up_glyphs_by_code_point() {
+        let bytes = pack_bytes(
+            9,
+            2,
+            &[
+                ('Ж', &[0x80, 0x00, 0x00, 0x80]),
+                ('中', &[0xFF, 0x80, 0x00, 0x00]),
+            ],
+        );
+        let pack = FontPack::parse(&bytes).unwrap();
+        assert_eq!((pack.width(), pack.height(), pack.glyph_count()), (9, 2, 2));
+
+        let zhe = pack.glyph('Ж').unwrap();
+        assert!(zhe.is_ink(0, 0));
+        assert!(!zhe.is_ink(1, 0));
+        assert!(zhe.is_ink(8, 1));
+        let zhong = pack.glyph('中').unwrap();
+        assert!((0..9).all(|x| zhong.is_ink(x, 0)));
+        assert!(pack.glyph('a').is_none());
+    }
+
+    #[test]
+    fn malformed_packs_are_rejected() {
+        let good = pack_bytes(8, 1, &[('a', &[1]), ('b', &[2])]);
+        assert_eq!(
+            FontPack::parse(&good[..good.len() - 1]),
+            Err(FontPackError::Truncated)
+        );
+        assert_eq!(FontPack::parse(&good[..8]), Err(FontPackError::Truncated));
+
+        let mut bad_magic = good.clone();
+        bad_magic[0] = b'X';
+        assert_eq!(FontPack::parse(&bad_magic), Err(FontPackError::BadMagic));
+
+        let mut bad_version = good.clone();
+        bad_version[4] = 2;
+        assert_eq!(
+            FontPack::parse(&bad_version),
+            Err(FontPackError::UnsupportedVersion)
+        );
+
+        let unsorted = pack_bytes(8, 1, &[('b', &[1]), ('a', &[2])]);
+        assert_eq!(FontPack::parse(&unsorted), Err(FontPackError::Unsorted));
+
+        let empty_cell = pack_bytes(0, 1, &[]);
+        assert_eq!(FontPack::parse(&empty_cell), Err(FontPackError::EmptyCell));
+    }
+
+    #[test]
+    fn library_prefers_the_tallest_glyph_that_fits_the_cell() {
+        let small = pack_bytes(8, 10, &[('中', &[0; 10])]).leak();
+        let large = pack_bytes(8, 20, &[('中', &[0; 20])]).leak();
+        let mut library = FontLibrary::new();
+        assert!(library.try_push(FontPack::parse(small).unwrap()));
+        assert!(library.try_push(FontPack::parse(large).unwrap()));
+
+        assert_eq!(library.glyph('中', Size::new(10, 20)).unwrap().height, 20);
+        assert_eq!(library.glyph('中', Size::new(6, 13)).unwrap().height, 10);
+        assert_eq!(library.glyph('中', Size::new(6, 8)).unwrap().height, 10);
+        assert!(library.glyph('Ж', Size::new(6, 10)).is_none());
+    }
+}
@@ -0,0 +1,37 @@
+// The UI and stage fonts are ISO-8859-1, so Latin-1 Supplement draws as-is but Latin Extended-A
+// (Polish, Czech, Hungarian, Turkish, ...) would fall back to `?`. Each of those letters is folded
+// onto the closest Latin-1 glyph instead: usually the bare letter, an umlaut for the Hungarian
+// double acute, and two letters for the ligatures.
+
+const LATIN_EXTENDED_A_START: u32 = 0x0100;
+#[rustfmt::skip]
+const LATIN_EXTENDED_A_FOLDS: [char; 128] = [
+    'A', 'a', 'A', 'a', 'A', 'a', 'C', 'c', 'C', 'c', 'C', 'c', 'C', 'c', 'D', 'd',
+    'Ð', 'd', 'E', 'e', 'E', 'e', 'E', 'e', 'E', 'e', 'E', 'e', 'G', 'g', 'G', 'g',
+    'G', 'g', 'G', 'g', 'H', 'h', 'H', 'h', 'I', 'i', 'I', 'i', 'I', 'i', 'I', 'i',
+    'I', 'i', 'I', 'i', 'J', 'j', 'K', 'k', 'k', 'L', 'l', 'L', 'l', 'L', 'l', 'L',
+    'l', 'L', 'l', 'N', 'n', 'N', 'n', 'N', 'n', 'n', 'N', 'n', 'O', 'o', 'O', 'o',
+    'Ö', 'ö', 'O', 'o', 'R', 'r', 'R', 'r', 'R', 'r', 'S', 's', 'S', 's', 'S', 's',
+    'S', 's', 'T', 't', 'T', 't', 'T', 't', 'U', 'u', 'U', 'u', 'U', 'u', 'U', 'u',
+    'Ü', 'ü', 'U', 'u', 'W', 'w', 'Y', 'y', 'Y', 'Z', 'z', 'Z', 'z', 'Z', 'z', 's',
+];
+
+// Letters that need two glyphs; checked before `fold_char`.
+pub(crate) fn fold_ligature(ch: char) -> Option<&'static str> {
+    match ch {
+        'Ĳ' => Some("IJ"),
+        'ĳ' => Some("ij"),
+        'Œ' => Some("OE"),
+        'œ' => Some("oe"),
+        'ŉ' => Some("'n"),
+        _ => None,
+    }
+}
+
+pub(crate) fn fold_char(ch: char) -> char {
+    (ch as u32)
+        .checked_sub(LATIN_EXTENDED_A_START)
+        .and_then(|offset| LATIN_EXTENDED_A_FOLDS.get(offset as usize))
+        .copied()
+        .unwrap_or(ch)
+}
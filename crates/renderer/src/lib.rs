@@ -1,27 +1,49 @@
+#![cfg_attr(not(test), no_std)]
+#![allow(dead_code)]
+
+#[cfg(feature = "rtl")]
+mod arabic_shaping;
+pub mod fonts;
+mod glyph_fold;
+mod list_view;
+pub mod qr;
+mod stage_font;
+mod status_bar;
+mod text_layout;
+
 use core::{convert::Infallible, fmt::Write};
 
 use app_runtime::{
-    AnimationDescriptor, MotionDirection, PreparedScreen, Screen, ScreenUpdate, TransitionPlan,
+    AnimationDescriptor, AnimationLayer, Easing, LayerAnimation, LayerMotion, MotionDirection,
+    PreparedScreen, Screen, ScreenUpdate, ToastStack, TransitionPlan,
     components::{
-        ContentListShell, ContentRow, DashboardShell, LoadingModal, ParagraphNavigationShell,
-        PauseModal, ReaderModal, ReaderShell, RecommendationBar, SettingsShell, StartupSplashShell,
-        TopicPreferenceGrid,
+        BookDetailShell, ConfirmShell, ContentListShell, ContentRow, ContentsShell,
+        CrashReportShell, DashboardShell, DefinitionShell, DiagnosticsShell, HistoryShell,
+        LoadingModal, LowBatteryShell, OnboardingShell, PageShell, ParagraphNavigationShell,
+        PauseModal, ReaderModal, ReaderShell, RecommendationBar, ScreensaverShell, SearchShell,
+        SectionBreakShell, SettingsShell, StartupSplashShell, TopicPreferenceGrid,
     },
 };
-use domain::formatter::StageFont;
+use domain::formatter::{FontStyle, StageFont};
+use domain::selectors::{
+    CONTENTS_VISIBLE_ROWS, ContentsMarker, DIAGNOSTICS_ROW_COUNT, HISTORY_VISIBLE_ROWS,
+};
 use domain::settings::AppearanceMode;
 use domain::ui::TopicRegion;
 use embedded_graphics::{
     mono_font::{
-        MonoFont, MonoTextStyleBuilder,
-        iso_8859_1::{FONT_6X10, FONT_8X13, FONT_8X13_BOLD, FONT_10X20},
+        MonoFont, MonoTextStyle, MonoTextStyleBuilder,
+        iso_8859_1::{FONT_6X10, FONT_8X13, FONT_10X20},
     },
     pixelcolor::BinaryColor,
     prelude::*,
-    text::{Alignment, Baseline, Text, TextStyleBuilder},
+    text::{Alignment, Baseline, Text, TextStyle, TextStyleBuilder},
 };
+use fonts::GlyphProvider;
 use heapless::String as HeaplessString;
+use list_view::{ListIcon, ListItem, ListScroll, ListView};
 use ls027b7dh01::FrameBuffer;
+use stage_font::StageFamily;
 
 pub const UI_TICK_MS: u64 = 160;
 const NORMALIZED_TEXT_MAX_BYTES: usize = 192;
@@ -29,8 +51,10 @@ const ELLIPSIS: &str = "...";
 const RSVP_STAGE_CENTER_X: i32 = 170;
 const RSVP_STAGE_LEFT_ANCHOR_X: i32 = 169;
 const RSVP_STAGE_RIGHT_ANCHOR_X: i32 = 173;
-const RSVP_STAGE_SCALED_LEFT_ANCHOR_X: i32 = 168;
-const RSVP_STAGE_SCALED_RIGHT_ANCHOR_X: i32 = 172;
+const RSVP_ANCHOR_TICK_GAP_Y: i32 = 2;
+const RSVP_STAGE_WRAP_GAP_Y: i32 = 6;
+const RSVP_STAGE_WIDTH: i32 = ls027b7dh01::protocol::WIDTH as i32;
+const RSVP_ANCHOR_TICK_HEIGHT: i32 = 3;
 const LIST_REGION_X: i32 = 16;
 const LIST_REGION_WIDTH: i32 = 368;
 const DASHBOARD_TEXT_RIGHT_EDGE_X: i32 = 380;
@@ -60,11 +84,52 @@ const RECOMMENDATION_COLLECTION_SELECTED_SLOT_HEIGHT: i32 = 64;
 const RECOMMENDATION_COLLECTION_BOTTOM_SLOT_Y: i32 = 167;
 const RECOMMENDATION_COLLECTION_BOTTOM_SLOT_HEIGHT: i32 = 42;
 const COLLECTION_BADGE_RIGHT_EDGE_X: i32 = 380;
+const COLLECTION_JUMP_LETTER_BOX_X: i32 = 148;
+const COLLECTION_JUMP_LETTER_BOX_Y: i32 = 64;
+const COLLECTION_JUMP_LETTER_BOX_WIDTH: i32 = 104;
+const COLLECTION_JUMP_LETTER_BOX_HEIGHT: i32 = 112;
+const COLLECTION_JUMP_LETTER_SCALE: u32 = 4;
+const SEARCH_QUERY_BOX_Y: i32 = 40;
+const SEARCH_QUERY_BOX_HEIGHT: i32 = 36;
+const SEARCH_KEY_STRIP_X: i32 = 20;
+const SEARCH_KEY_STRIP_Y: i32 = 88;
+const SEARCH_KEY_WIDTH: i32 = 40;
+const SEARCH_KEY_HEIGHT: i32 = 32;
+const SEARCH_MATCH_LABEL_Y: i32 = 134;
+const SEARCH_MATCHES: ListView = ListView {
+    x: LIST_REGION_X,
+    y: 150,
+    width: COLLECTION_TEXT_RIGHT_EDGE_X - LIST_REGION_X + 12,
+    row_pitch: 24,
+    row_height: 22,
+    inset: 12,
+    icon_column: 0,
+    detail_column: 0,
+};
+const BOOK_DETAIL_TEXT_X: i32 = 28;
+const BOOK_DETAIL_TITLE_Y: i32 = 40;
+const BOOK_DETAIL_META_Y: i32 = 68;
+const BOOK_DETAIL_STATE_Y: i32 = 86;
+const BOOK_DETAIL_PROGRESS_Y: i32 = 106;
+const BOOK_DETAIL_PROGRESS_HEIGHT: i32 = 6;
+const BOOK_DETAIL_ACTIONS: ListView = ListView {
+    x: LIST_REGION_X,
+    y: 118,
+    width: LIST_REGION_WIDTH,
+    row_pitch: 20,
+    row_height: 18,
+    inset: 12,
+    icon_column: 0,
+    detail_column: 0,
+};
 const COLLECTION_SELECTED_BADGE_RIGHT_EDGE_X: i32 = 372;
 const COLLECTION_BADGE_GAP_PX: i32 = 8;
 const COLLECTION_BADGE_MIN_WIDTH: i32 = 22;
 const COLLECTION_BADGE_HEIGHT: i32 = 15;
 const COLLECTION_BADGE_TEXT_Y_OFFSET: i32 = 2;
+const COLLECTION_PROGRESS_BAR_WIDTH: i32 = 96;
+const COLLECTION_PROGRESS_BAR_GAP_PX: i32 = 2;
+const COLLECTION_FINISHED_CHECK_GAP_PX: i32 = 4;
 const RECOMMENDATION_BAR_LEFT_BUTTON_X: i32 = 18;
 const RECOMMENDATION_BAR_RIGHT_BUTTON_X: i32 = 356;
 const RECOMMENDATION_BAR_BUTTON_Y: i32 = 28;
@@ -95,13 +160,51 @@ const PARAGRAPH_CARD_HINT_Y: i32 = 146;
 const PARAGRAPH_CARD_HINT_WIDTH: i32 = 72;
 const PARAGRAPH_CARD_HINT_HEIGHT: i32 = 16;
 const PARAGRAPH_FOOTER_Y: i32 = 231;
+const DEFINITION_DIVIDER_Y: i32 = 48;
+const DEFINITION_BODY_Y: i32 = 62;
+const DEFINITION_LINE_HEIGHT_PX: i32 = 24;
+const DEFINITION_STATUS_Y: i32 = 120;
+const PANIC_TITLE: &str = "MOTIF STOPPED";
+const PANIC_FOOTER: &str = "restarting";
+const PANIC_LINE_COLUMNS: usize = 45;
+const PANIC_MAX_LINES: usize = 6;
+const DEFINITION_SCROLL_X: i32 = 388;
+const SECTION_BREAK_LABEL_Y: i32 = 96;
+const SECTION_BREAK_HEADING_Y: i32 = 124;
+const PAGE_BODY_Y: i32 = 56;
+const PAGE_LINE_HEIGHT_PX: i32 = 15;
+const PAGE_PROGRESS_Y: i32 = 224;
+const CONTENTS_LIST: ListView = ListView {
+    x: READER_TEXT_LEFT_X - 8,
+    y: 56,
+    width: READER_TITLE_MAX_WIDTH_PX + 16,
+    row_pitch: 24,
+    row_height: 22,
+    inset: 8,
+    icon_column: 26,
+    detail_column: 32,
+};
+const DIAGNOSTICS_LIST: ListView = ListView {
+    row_pitch: 17,
+    row_height: 15,
+    icon_column: 0,
+    detail_column: 0,
+    ..CONTENTS_LIST
+};
+const HISTORY_LIST: ListView = ListView {
+    icon_column: 0,
+    detail_column: 56,
+    ..CONTENTS_LIST
+};
 const PAUSE_MODAL_CENTER_X: i32 = 200;
 const PAUSE_MODAL_CENTER_Y: i32 = 118;
 const PAUSE_MODAL_MIN_WIDTH: u32 = 112;
 const PAUSE_MODAL_MIN_HEIGHT: u32 = 52;
 const PAUSE_MODAL_MAX_WIDTH: u32 = 286;
-const PAUSE_MODAL_MAX_HEIGHT: u32 = 188;
+const PAUSE_MODAL_MAX_HEIGHT: u32 = 210;
 const PAUSE_MODAL_CONTENT_OFFSET_PX: i32 = 8;
+const PAUSE_GOAL_BAR_HEIGHT: i32 = 2;
+const PAUSE_SESSION_LABEL_MAX_WIDTH_PX: i32 = 84;
 const READER_TEXT_LEFT_X: i32 = 20;
 const READER_TEXT_RIGHT_X: i32 = 380;
 const READER_TITLE_MAX_WIDTH_PX: i32 = READER_TEXT_RIGHT_X - READER_TEXT_LEFT_X;
@@ -117,14 +220,96 @@ const STARTUP_LOADING_BAR_Y: i32 = 168;
 const STARTUP_LOADING_BAR_WIDTH: i32 = 244;
 const STARTUP_LOADING_BAR_HEIGHT: i32 = 16;
 const STARTUP_SKIP_HINT_Y: i32 = 205;
-
-#[derive(Debug, Clone, Copy, PartialEq)]
+const CONFIRM_BOX_X: i32 = 50;
+const CONFIRM_BOX_Y: i32 = 44;
+const CONFIRM_BOX_WIDTH: i32 = 300;
+const CONFIRM_BOX_HEIGHT: i32 = 148;
+const CONFIRM_BODY_PADDING_X: i32 = 16;
+const CONFIRM_BUTTON_Y: i32 = 146;
+const CONFIRM_BUTTON_WIDTH: i32 = 112;
+const CONFIRM_BUTTON_HEIGHT: i32 = 26;
+const CONFIRM_BUTTON_GAP_X: i32 = 20;
+const CONFIRM_HELP_Y: i32 = 208;
+const LIBRARY_GRID_COLUMNS: usize = 3;
+const LIBRARY_GRID_CELL_X: i32 = 16;
+const LIBRARY_GRID_CELL_WIDTH: i32 = 112;
+const LIBRARY_GRID_CELL_PITCH_X: i32 = 128;
+const LIBRARY_GRID_ROW_Y: i32 = 40;
+const LIBRARY_GRID_ROW_PITCH_Y: i32 = 98;
+const LIBRARY_GRID_COVER_SIZE: i32 = 72;
+const LIBRARY_GRID_COVER_INSET: i32 = 4;
+const LIBRARY_GRID_LETTER_SCALE: u32 = 2;
+const LIBRARY_GRID_LETTER_Y_OFFSET: i32 = 16;
+const LIBRARY_GRID_TITLE_Y_OFFSET: i32 = 78;
+const LIBRARY_GRID_PROGRESS_Y_OFFSET: i32 = 88;
+const LAYER_HEADER_BOTTOM_Y: i32 = 40;
+const LAYER_STAGE_TOP_Y: i32 = 84;
+const LAYER_STAGE_BOTTOM_Y: i32 = 160;
+const SCREENSAVER_COVER_X: i32 = 40;
+const SCREENSAVER_COVER_Y: i32 = 52;
+const SCREENSAVER_COVER_WIDTH: i32 = 96;
+const SCREENSAVER_COVER_HEIGHT: i32 = 132;
+const SCREENSAVER_COVER_INSET: i32 = 6;
+const SCREENSAVER_COVER_LETTER_SCALE: u32 = 3;
+const SCREENSAVER_TEXT_X: i32 = 160;
+const SCREENSAVER_TEXT_WIDTH: i32 = 200;
+const SCREENSAVER_CLOCK_SCALE: u32 = 3;
+// Scaled text must start on a multiple of its scale.
+const SCREENSAVER_CLOCK_X: i32 = 159;
+const SCREENSAVER_CLOCK_Y: i32 = 51;
+const SCREENSAVER_COVER_LETTER_X: i32 = 87;
+const SCREENSAVER_COVER_LETTER_Y: i32 = 87;
+const SCREENSAVER_CLOCK_ALONE_SCALE: u32 = 4;
+const SCREENSAVER_BAR_HEIGHT: i32 = 10;
+const SCREENSAVER_HELP_Y: i32 = 212;
+const ONBOARDING_DOT_SIZE: i32 = 8;
+const ONBOARDING_DOT_PITCH: i32 = 16;
+const ONBOARDING_DOT_Y: i32 = 18;
+const ONBOARDING_STAGE_X: i32 = 60;
+const ONBOARDING_STAGE_WIDTH: i32 = 280;
+const ONBOARDING_STAGE_TOP_Y: i32 = 100;
+const ONBOARDING_STAGE_BOTTOM_Y: i32 = 160;
+const ONBOARDING_DEMO_SCALE: u32 = 2;
+// The speed editor's sample sentence covers the last three rows, between their separators.
+const SETTINGS_PREVIEW_TOP_Y: i32 = 152;
+const SETTINGS_PREVIEW_BOTTOM_Y: i32 = 224;
+const ONBOARDING_VALUE_X: i32 = 110;
+const ONBOARDING_VALUE_Y: i32 = 106;
+const ONBOARDING_VALUE_WIDTH: i32 = 180;
+const ONBOARDING_VALUE_HEIGHT: i32 = 40;
+const ONBOARDING_HELP_Y: i32 = 212;
+const TOAST_CENTER_X: i32 = 200;
+const TOAST_STACK_BOTTOM_Y: i32 = 226;
+const TOAST_HEIGHT: i32 = 22;
+const TOAST_GAP_Y: i32 = 4;
+const TOAST_PADDING_X: i32 = 12;
+const TOAST_HALO_PX: i32 = 2;
+const TOAST_FONT_WIDTH: i32 = 8;
+
+#[derive(Clone, Copy)]
 struct StageTextSpec {
-    font: &'static MonoFont<'static>,
+    family: StageFamily,
     y: i32,
-    scale: u32,
-    left_anchor_x: i32,
-    right_anchor_x: i32,
+}
+
+// Which letters of a stage word leave the regular face, counted across both segments.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct StageEmphasis {
+    pivot_index: usize,
+    pivot_style: FontStyle,
+    bold_lead_chars: usize,
+}
+
+impl StageEmphasis {
+    fn style_at(self, index: usize) -> FontStyle {
+        if index == self.pivot_index && self.pivot_style != FontStyle::Regular {
+            self.pivot_style
+        } else if index < self.bold_lead_chars {
+            FontStyle::Bold
+        } else {
+            FontStyle::Regular
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -182,25 +367,16 @@ fn ui_font_title() -> &'static MonoFont<'static> {
 fn stage_font_spec(font: StageFont) -> StageTextSpec {
     match font {
         StageFont::Large => StageTextSpec {
-            font: ui_font_title(),
+            family: stage_font::closest_stage_family(40),
             y: 102,
-            scale: 2,
-            left_anchor_x: RSVP_STAGE_SCALED_LEFT_ANCHOR_X,
-            right_anchor_x: RSVP_STAGE_SCALED_RIGHT_ANCHOR_X,
         },
         StageFont::Medium => StageTextSpec {
-            font: &FONT_8X13_BOLD,
+            family: stage_font::closest_stage_family(26),
             y: 108,
-            scale: 2,
-            left_anchor_x: RSVP_STAGE_SCALED_LEFT_ANCHOR_X,
-            right_anchor_x: RSVP_STAGE_SCALED_RIGHT_ANCHOR_X,
         },
         StageFont::Small => StageTextSpec {
-            font: ui_font_title(),
+            family: stage_font::closest_stage_family(20),
             y: 112,
-            scale: 1,
-            left_anchor_x: RSVP_STAGE_LEFT_ANCHOR_X,
-            right_anchor_x: RSVP_STAGE_RIGHT_ANCHOR_X,
         },
     }
 }
@@ -212,6 +388,7 @@ pub struct AnimationPlayback {
     pub screen: Screen,
     pub plan: TransitionPlan,
     pub step: u8,
+    pub toasts: ToastStack,
 }
 
 impl AnimationPlayback {
@@ -222,6 +399,7 @@ impl AnimationPlayback {
             screen: update.screen,
             plan: update.transition,
             step: 1,
+            toasts: update.toasts,
         }
     }
 
@@ -240,7 +418,16 @@ impl AnimationPlayback {
 }
 
 pub fn draw_prepared_screen(frame: &mut FrameBuffer, screen: &PreparedScreen) {
+    draw_screen_update_parts(frame, screen, &ToastStack::empty());
+}
+
+pub fn draw_screen_update(frame: &mut FrameBuffer, update: &ScreenUpdate) {
+    draw_screen_update_parts(frame, &update.prepared, &update.toasts);
+}
+
+fn draw_screen_update_parts(frame: &mut FrameBuffer, screen: &PreparedScreen, toasts: &ToastStack) {
     draw_prepared_screen_base(frame, screen);
+    draw_toasts(frame, toasts);
     apply_theme(frame, screen.appearance());
 }
 
@@ -248,13 +435,121 @@ fn draw_prepared_screen_base(frame: &mut FrameBuffer, screen: &PreparedScreen) {
     frame.clear(false);
 
     match screen {
+        PreparedScreen::CrashReport(shell) => draw_crash_report(frame, shell),
+        PreparedScreen::LowBattery(shell) => draw_low_battery(frame, shell),
+        PreparedScreen::Onboarding(shell) => draw_onboarding(frame, shell),
+        PreparedScreen::Screensaver(shell) => draw_screensaver(frame, shell),
         PreparedScreen::StartupSplash(shell) => draw_startup_splash(frame, shell),
         PreparedScreen::Dashboard(shell) => draw_dashboard(frame, shell, 1, 1),
         PreparedScreen::Collection(shell) => draw_collection(frame, shell, 1, 1, 0),
+        PreparedScreen::Search(shell) => draw_search(frame, shell),
+        PreparedScreen::BookDetail(shell) => draw_book_detail(frame, shell),
         PreparedScreen::Reader(shell) => draw_reader(frame, shell, 1, 1),
         PreparedScreen::ParagraphNavigation(shell) => draw_paragraph_navigation(frame, shell, 1, 1),
+        PreparedScreen::Definition(shell) => draw_definition(frame, shell),
+        PreparedScreen::SectionBreak(shell) => draw_section_break(frame, shell),
+        PreparedScreen::Page(shell) => draw_page(frame, shell),
+        PreparedScreen::Contents(shell) => draw_contents(frame, shell),
         PreparedScreen::Settings(shell) => draw_settings(frame, shell, 1, 1),
+        PreparedScreen::Diagnostics(shell) => draw_diagnostics(frame, shell),
+        PreparedScreen::History(shell) => draw_history(frame, shell),
+        PreparedScreen::Confirm(shell) => draw_confirm(frame, shell),
+    }
+}
+
+// Drawn straight from the boot path while the card is read, before the app runtime exists. It
+// keeps the startup splash's wordmark and bar so the splash takes over without a jump.
+pub fn draw_boot_scan(
+    frame: &mut FrameBuffer,
+    appearance: AppearanceMode,
+    completed: u8,
+    total: u8,
+    label: &str,
+) {
+    frame.clear(false);
+    draw_startup_wordmark(frame);
+    stroke_rect(
+        frame,
+        STARTUP_LOADING_BAR_X,
+        STARTUP_LOADING_BAR_Y,
+        STARTUP_LOADING_BAR_WIDTH,
+        STARTUP_LOADING_BAR_HEIGHT,
+        BinaryColor::On,
+    );
+    let inner_width = STARTUP_LOADING_BAR_WIDTH - 8;
+    let filled = inner_width * i32::from(completed.min(total)) / i32::from(total.max(1));
+    fill_rect(
+        frame,
+        STARTUP_LOADING_BAR_X + 4,
+        STARTUP_LOADING_BAR_Y + 4,
+        filled,
+        STARTUP_LOADING_BAR_HEIGHT - 8,
+        BinaryColor::On,
+    );
+    let mut status: HeaplessString<48> = HeaplessString::new();
+    let _ = write!(status, "Scanning {completed}/{total}: {label}");
+    draw_text(
+        frame,
+        status.as_str(),
+        Point::new(200, STARTUP_SKIP_HINT_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Center,
+    );
+    apply_theme(frame, appearance);
+}
+
+pub fn draw_panic_message(frame: &mut FrameBuffer, message: &str) {
+    frame.clear(false);
+    draw_text(
+        frame,
+        PANIC_TITLE,
+        Point::new(READER_TEXT_LEFT_X, 18),
+        ui_font_title(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+    fill_rect(
+        frame,
+        READER_TEXT_LEFT_X,
+        DEFINITION_DIVIDER_Y,
+        READER_TITLE_MAX_WIDTH_PX,
+        1,
+        BinaryColor::On,
+    );
+
+    let mut rest = message;
+    for index in 0..PANIC_MAX_LINES {
+        if rest.is_empty() {
+            break;
+        }
+        let split = rest
+            .char_indices()
+            .nth(PANIC_LINE_COLUMNS)
+            .map_or(rest.len(), |(offset, _)| offset);
+        let (line, tail) = rest.split_at(split);
+        draw_text(
+            frame,
+            line,
+            Point::new(
+                READER_TEXT_LEFT_X,
+                DEFINITION_BODY_Y + index as i32 * DEFINITION_LINE_HEIGHT_PX,
+            ),
+            ui_font_body(),
+            BinaryColor::On,
+            Alignment::Left,
+        );
+        rest = tail;
     }
+
+    draw_text(
+        frame,
+        PANIC_FOOTER,
+        Point::new(READER_TEXT_LEFT_X, PARAGRAPH_FOOTER_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
 }
 
 pub fn draw_transition_frame(frame: &mut FrameBuffer, playback: &AnimationPlayback) {
@@ -391,9 +686,108 @@ pub fn draw_transition_frame(frame: &mut FrameBuffer, playback: &AnimationPlayba
         }
     }
 
+    for layer in playback.plan.layers.iter().flatten() {
+        draw_layer_animation(frame, layer, playback.step, playback.plan.steps);
+    }
+    draw_toasts(frame, &playback.toasts);
     apply_theme(frame, playback.to.appearance());
 }
 
+// Layers post-process rows the screen has already drawn, so they compose with any descriptor.
+fn draw_layer_animation(
+    frame: &mut FrameBuffer,
+    layer: &LayerAnimation,
+    step: u8,
+    total_steps: u8,
+) {
+    let (top, bottom) = match layer.layer {
+        AnimationLayer::Header => (0, LAYER_HEADER_BOTTOM_Y),
+        AnimationLayer::Stage => (LAYER_STAGE_TOP_Y, LAYER_STAGE_BOTTOM_Y),
+    };
+    let progress = layer.easing.progress_permille(step, total_steps);
+    match layer.motion {
+        LayerMotion::SlideDown => {
+            let lag = (bottom - top) * (1000 - progress) / 1000;
+            shift_rows_down(frame, top, bottom, lag);
+        }
+        LayerMotion::Fade => fade_rows(frame, top, bottom, progress),
+    }
+}
+
+// Moves rows `top..bottom` down by `offset` (up when negative), blanking what is uncovered and
+// dropping what leaves the band.
+fn shift_rows_down(frame: &mut FrameBuffer, top: i32, bottom: i32, offset: i32) {
+    let height = bottom - top;
+    if offset == 0 || height <= 0 {
+        return;
+    }
+    let offset = offset.clamp(-height, height);
+    let line_bytes = ls027b7dh01::protocol::LINE_BYTES;
+    let bytes = frame.bytes_mut();
+    let kept = (height - offset.abs()) as usize * line_bytes;
+    let (from, to, blank) = if offset > 0 {
+        (top, top + offset, top)
+    } else {
+        (top - offset, top, bottom + offset)
+    };
+    let from = from as usize * line_bytes;
+    bytes.copy_within(from..from + kept, to as usize * line_bytes);
+    let blank = blank as usize * line_bytes;
+    bytes[blank..blank + offset.unsigned_abs() as usize * line_bytes].fill(0);
+}
+
+// Thins the ink in rows `top..bottom` through a 4x4 ordered dither, keeping `progress` permille.
+fn fade_rows(frame: &mut FrameBuffer, top: i32, bottom: i32, progress: i32) {
+    const BAYER_4X4: [[i32; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+    if progress >= 1000 {
+        return;
+    }
+    let line_bytes = ls027b7dh01::protocol::LINE_BYTES;
+    let bytes = frame.bytes_mut();
+    for y in top.max(0)..bottom.min(ls027b7dh01::protocol::HEIGHT as i32) {
+        let thresholds = BAYER_4X4[y as usize % 4];
+        let mut mask = 0u8;
+        for bit in 0..8 {
+            if thresholds[bit % 4] * 1000 < progress * 16 {
+                mask |= 0x80 >> bit;
+            }
+        }
+        let start = y as usize * line_bytes;
+        for byte in &mut bytes[start..start + line_bytes] {
+            *byte &= mask;
+        }
+    }
+}
+
+// Centred banners stacked up from the footer, newest lowest. The halo keeps a banner readable
+// over ink-heavy screens such as the reader stage.
+fn draw_toasts(frame: &mut FrameBuffer, toasts: &ToastStack) {
+    let count = toasts.labels.iter().flatten().count() as i32;
+    for (slot, label) in toasts.labels.iter().flatten().enumerate() {
+        let width = label.len() as i32 * TOAST_FONT_WIDTH + TOAST_PADDING_X * 2;
+        let x = TOAST_CENTER_X - width / 2;
+        let y = TOAST_STACK_BOTTOM_Y - (count - slot as i32) * (TOAST_HEIGHT + TOAST_GAP_Y)
+            + TOAST_GAP_Y;
+        fill_rect(
+            frame,
+            x - TOAST_HALO_PX,
+            y - TOAST_HALO_PX,
+            width + TOAST_HALO_PX * 2,
+            TOAST_HEIGHT + TOAST_HALO_PX * 2,
+            BinaryColor::Off,
+        );
+        draw_pill(frame, x, y, width, TOAST_HEIGHT, true);
+        draw_text(
+            frame,
+            label,
+            Point::new(TOAST_CENTER_X, y + (TOAST_HEIGHT - 13) / 2),
+            ui_font_body(),
+            BinaryColor::Off,
+            Alignment::Center,
+        );
+    }
+}
+
 fn draw_startup_splash(frame: &mut FrameBuffer, shell: &StartupSplashShell) {
     draw_startup_wordmark(frame);
     stroke_rect(
@@ -519,11 +913,7 @@ fn draw_dashboard_band_transition(
 }
 
 fn draw_dashboard_chrome(frame: &mut FrameBuffer, shell: &DashboardShell) {
-    draw_status_cluster(
-        frame,
-        shell.status.battery_percent,
-        shell.status.wifi_online,
-    );
+    status_bar::draw(frame, &shell.status);
 
     if let Some(sync_indicator) = shell.sync_indicator {
         draw_dashboard_sync_indicator(frame, sync_indicator.label, sync_indicator.spinner_phase);
@@ -796,6 +1186,15 @@ fn draw_collection(
     total_steps: u8,
     slide_offset: i32,
 ) {
+    if let Some(cells) = &shell.grid {
+        draw_collection_chrome(frame, shell);
+        draw_collection_grid(frame, cells);
+        if let Some(letter) = shell.jump_letter {
+            draw_collection_jump_letter(frame, letter);
+        }
+        return;
+    }
+
     let top_slot = collection_top_slot_for(shell);
     let selected_slot = collection_selected_slot_for(shell);
     let bottom_slot = collection_bottom_slot_for(shell);
@@ -835,31 +1234,100 @@ fn draw_collection(
         Point::new(bottom_slot.text_x, bottom_slot.title_y + slide_offset),
         bottom_slot.color,
     );
-}
 
-fn draw_collection_list_step(
-    frame: &mut FrameBuffer,
-    from: &ContentListShell,
-    to: &ContentListShell,
-    direction: MotionDirection,
-    step: u8,
-    total_steps: u8,
-) {
-    if step >= total_steps {
-        draw_collection(frame, to, 1, 1, 0);
-        return;
+    if let Some(letter) = shell.jump_letter {
+        draw_collection_jump_letter(frame, letter);
     }
+}
 
-    draw_collection_chrome(frame, to);
-    draw_collection_list_step_slots(frame, from, to, direction, step, total_steps);
+// Three covers by two, each an initial-letter stand-in with its title beneath. The selected cover
+// is drawn inverted.
+fn draw_collection_grid(frame: &mut FrameBuffer, cells: &[ContentRow]) {
+    for (index, cell) in cells.iter().enumerate() {
+        if cell.title.is_empty() {
+            continue;
+        }
+        let cell_x =
+            LIBRARY_GRID_CELL_X + (index % LIBRARY_GRID_COLUMNS) as i32 * LIBRARY_GRID_CELL_PITCH_X;
+        let cover_x = cell_x + (LIBRARY_GRID_CELL_WIDTH - LIBRARY_GRID_COVER_SIZE) / 2;
+        let cover_y =
+            LIBRARY_GRID_ROW_Y + (index / LIBRARY_GRID_COLUMNS) as i32 * LIBRARY_GRID_ROW_PITCH_Y;
+        let (cover_color, ink) = if cell.selected {
+            (BinaryColor::On, BinaryColor::Off)
+        } else {
+            (BinaryColor::Off, BinaryColor::On)
+        };
+
+        fill_rect(
+            frame,
+            cover_x,
+            cover_y,
+            LIBRARY_GRID_COVER_SIZE,
+            LIBRARY_GRID_COVER_SIZE,
+            cover_color,
+        );
+        stroke_rect(
+            frame,
+            cover_x,
+            cover_y,
+            LIBRARY_GRID_COVER_SIZE,
+            LIBRARY_GRID_COVER_SIZE,
+            BinaryColor::On,
+        );
+        stroke_rect(
+            frame,
+            cover_x + LIBRARY_GRID_COVER_INSET,
+            cover_y + LIBRARY_GRID_COVER_INSET,
+            LIBRARY_GRID_COVER_SIZE - LIBRARY_GRID_COVER_INSET * 2,
+            LIBRARY_GRID_COVER_SIZE - LIBRARY_GRID_COVER_INSET * 2,
+            ink,
+        );
+        if let Some(initial) = cell.title.as_str().chars().find(|ch| ch.is_alphanumeric()) {
+            let mut buffer = [0u8; 4];
+            draw_text_scaled(
+                frame,
+                initial.to_ascii_uppercase().encode_utf8(&mut buffer),
+                Point::new(
+                    cover_x + LIBRARY_GRID_COVER_SIZE / 2,
+                    cover_y + LIBRARY_GRID_LETTER_Y_OFFSET,
+                ),
+                ui_font_title(),
+                ink,
+                Alignment::Center,
+                LIBRARY_GRID_LETTER_SCALE,
+            );
+        }
+
+        let title = text_layout::ellipsized_str(
+            cell.title.as_str(),
+            &text_layout::TEXT_FONT_SMALL,
+            LIBRARY_GRID_CELL_WIDTH - 4,
+        );
+        text_layout::draw_str(
+            frame,
+            title.as_str(),
+            Point::new(
+                cell_x + LIBRARY_GRID_CELL_WIDTH / 2,
+                cover_y + LIBRARY_GRID_TITLE_Y_OFFSET,
+            ),
+            &text_layout::TEXT_FONT_SMALL,
+            BinaryColor::On,
+            Alignment::Center,
+        );
+        draw_collection_progress_bar(
+            frame,
+            cell.progress_percent,
+            cell.finished,
+            Point::new(cover_x, cover_y + LIBRARY_GRID_PROGRESS_Y_OFFSET),
+            LIBRARY_GRID_COVER_SIZE,
+            BinaryColor::On,
+            None,
+        );
+    }
 }
 
-fn draw_collection_chrome(frame: &mut FrameBuffer, shell: &ContentListShell) {
-    draw_status_cluster(
-        frame,
-        shell.status.battery_percent,
-        shell.status.wifi_online,
-    );
+fn draw_search(frame: &mut FrameBuffer, shell: &SearchShell) {
+    status_bar::draw(frame, &shell.status);
     draw_back_chevron(frame, 20, 12);
     draw_text(
         frame,
@@ -869,84 +1337,276 @@ fn draw_collection_chrome(frame: &mut FrameBuffer, shell: &ContentListShell) {
         BinaryColor::On,
         Alignment::Left,
     );
-    if let Some(bar) = shell.recommendations_bar {
-        draw_recommendation_bar(frame, &bar);
-    }
-}
-
-fn draw_recommendation_bar(frame: &mut FrameBuffer, bar: &RecommendationBar) {
-    if bar.show_left_more {
-        draw_recommendation_more_button(frame, RECOMMENDATION_BAR_LEFT_BUTTON_X, false);
-    }
 
-    let mut x = RECOMMENDATION_BAR_X;
-    let mut index = 0usize;
-    while index < bar.visible_count {
-        let tab = bar.tabs[index];
-        let width = recommendation_tab_width(tab.label.as_str());
-        if x + width > RECOMMENDATION_BAR_RIGHT_EDGE_X {
-            break;
-        }
+    stroke_rect(
+        frame,
+        LIST_REGION_X,
+        SEARCH_QUERY_BOX_Y,
+        LIST_REGION_WIDTH,
+        SEARCH_QUERY_BOX_HEIGHT,
+        BinaryColor::On,
+    );
+    let mut query: HeaplessString<32> = HeaplessString::new();
+    let _ = write!(query, "{}_", shell.query.as_str());
+    draw_text(
+        frame,
+        query.as_str(),
+        Point::new(LIST_REGION_X + 12, SEARCH_QUERY_BOX_Y + 8),
+        ui_font_title(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
 
-        if tab.active {
+    let focused_slot = shell.keys.len() / 2;
+    for (slot, key) in shell.keys.iter().enumerate() {
+        let x = SEARCH_KEY_STRIP_X + slot as i32 * SEARCH_KEY_WIDTH;
+        let focused = slot == focused_slot;
+        if focused {
             draw_pill(
                 frame,
                 x,
-                RECOMMENDATION_BAR_Y,
-                width,
-                RECOMMENDATION_BAR_HEIGHT,
+                SEARCH_KEY_STRIP_Y,
+                SEARCH_KEY_WIDTH,
+                SEARCH_KEY_HEIGHT,
                 true,
             );
-        } else {
-            draw_pill(
-                frame,
-                x,
-                RECOMMENDATION_BAR_Y,
-                width,
-                RECOMMENDATION_BAR_HEIGHT,
-                false,
-            );
-        }
-
-        if tab.focused {
-            draw_recommendation_focus_ring(
-                frame,
-                x,
-                RECOMMENDATION_BAR_Y,
-                width,
-                RECOMMENDATION_BAR_HEIGHT,
-                tab.flash,
-            );
         }
-
-        draw_text_ellipsized(
+        let mut buffer = [0u8; 4];
+        draw_text(
             frame,
-            tab.label.as_str(),
-            Point::new(
-                x + 10,
-                RECOMMENDATION_BAR_Y + RECOMMENDATION_BAR_TEXT_Y_OFFSET,
-            ),
-            ui_font_small(),
-            if tab.active {
+            key.label(&mut buffer),
+            Point::new(x + SEARCH_KEY_WIDTH / 2, SEARCH_KEY_STRIP_Y + 6),
+            ui_font_title(),
+            if focused {
                 BinaryColor::Off
             } else {
                 BinaryColor::On
             },
-            Alignment::Left,
-            width - 16,
+            Alignment::Center,
         );
-
-        x += width + RECOMMENDATION_BAR_GAP_X;
-        index += 1;
-    }
-
-    if bar.show_right_more {
-        draw_recommendation_more_button(frame, RECOMMENDATION_BAR_RIGHT_BUTTON_X, true);
     }
-}
 
-fn recommendation_tab_width(label: &str) -> i32 {
-    (mono_text_width_px(label, ui_font_small(), 1) + 24).max(RECOMMENDATION_BAR_MIN_WIDTH)
+    draw_text(
+        frame,
+        shell.match_label.as_str(),
+        Point::new(LIST_REGION_X + 12, SEARCH_MATCH_LABEL_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+    let matches = shell.matches.each_ref().map(|title| ListItem {
+        label: title.as_str(),
+        ..ListItem::default()
+    });
+    SEARCH_MATCHES.draw(frame, &matches, None);
+}
+
+fn draw_book_detail(frame: &mut FrameBuffer, shell: &BookDetailShell) {
+    status_bar::draw(frame, &shell.status);
+    draw_back_chevron(frame, 20, 12);
+    draw_text(
+        frame,
+        shell.help.text,
+        Point::new(38, 13),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+
+    let text_width = COLLECTION_TEXT_RIGHT_EDGE_X - BOOK_DETAIL_TEXT_X;
+    draw_text_ellipsized(
+        frame,
+        shell.title.as_str(),
+        Point::new(BOOK_DETAIL_TEXT_X, BOOK_DETAIL_TITLE_Y),
+        ui_font_title(),
+        BinaryColor::On,
+        Alignment::Left,
+        text_width,
+    );
+    draw_text_ellipsized(
+        frame,
+        shell.meta.as_str(),
+        Point::new(BOOK_DETAIL_TEXT_X, BOOK_DETAIL_META_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+        text_width,
+    );
+    let mut state: HeaplessString<48> = HeaplessString::new();
+    let _ = write!(
+        state,
+        "{} / {}",
+        shell.state_label,
+        shell.progress_label.as_str()
+    );
+    draw_text(
+        frame,
+        state.as_str(),
+        Point::new(BOOK_DETAIL_TEXT_X, BOOK_DETAIL_STATE_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+
+    stroke_rect(
+        frame,
+        BOOK_DETAIL_TEXT_X,
+        BOOK_DETAIL_PROGRESS_Y,
+        text_width,
+        BOOK_DETAIL_PROGRESS_HEIGHT,
+        BinaryColor::On,
+    );
+    let filled = text_width * i32::from(shell.progress_percent.min(100)) / 100;
+    if filled > 0 {
+        fill_rect(
+            frame,
+            BOOK_DETAIL_TEXT_X,
+            BOOK_DETAIL_PROGRESS_Y,
+            filled,
+            BOOK_DETAIL_PROGRESS_HEIGHT,
+            BinaryColor::On,
+        );
+    }
+
+    let actions = shell.actions.map(|action| ListItem {
+        label: action.label,
+        selected: action.selected,
+        ..ListItem::default()
+    });
+    BOOK_DETAIL_ACTIONS.draw(frame, &actions, None);
+}
+
+fn draw_collection_jump_letter(frame: &mut FrameBuffer, letter: char) {
+    fill_rect(
+        frame,
+        COLLECTION_JUMP_LETTER_BOX_X,
+        COLLECTION_JUMP_LETTER_BOX_Y,
+        COLLECTION_JUMP_LETTER_BOX_WIDTH,
+        COLLECTION_JUMP_LETTER_BOX_HEIGHT,
+        BinaryColor::On,
+    );
+    let mut buffer = [0u8; 4];
+    draw_text_scaled(
+        frame,
+        letter.encode_utf8(&mut buffer),
+        Point::new(
+            COLLECTION_JUMP_LETTER_BOX_X + COLLECTION_JUMP_LETTER_BOX_WIDTH / 2,
+            COLLECTION_JUMP_LETTER_BOX_Y + 16,
+        ),
+        ui_font_title(),
+        BinaryColor::Off,
+        Alignment::Center,
+        COLLECTION_JUMP_LETTER_SCALE,
+    );
+}
+
+fn draw_collection_list_step(
+    frame: &mut FrameBuffer,
+    from: &ContentListShell,
+    to: &ContentListShell,
+    direction: MotionDirection,
+    step: u8,
+    total_steps: u8,
+) {
+    // The grid moves its highlight in place rather than scrolling rows.
+    if step >= total_steps || from.grid.is_some() || to.grid.is_some() {
+        draw_collection(frame, to, 1, 1, 0);
+        return;
+    }
+
+    draw_collection_chrome(frame, to);
+    draw_collection_list_step_slots(frame, from, to, direction, step, total_steps);
+}
+
+fn draw_collection_chrome(frame: &mut FrameBuffer, shell: &ContentListShell) {
+    status_bar::draw(frame, &shell.status);
+    draw_back_chevron(frame, 20, 12);
+    draw_text(
+        frame,
+        shell.help.text,
+        Point::new(38, 13),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+    if let Some(bar) = shell.recommendations_bar {
+        draw_recommendation_bar(frame, &bar);
+    }
+}
+
+fn draw_recommendation_bar(frame: &mut FrameBuffer, bar: &RecommendationBar) {
+    if bar.show_left_more {
+        draw_recommendation_more_button(frame, RECOMMENDATION_BAR_LEFT_BUTTON_X, false);
+    }
+
+    let mut x = RECOMMENDATION_BAR_X;
+    let mut index = 0usize;
+    while index < bar.visible_count {
+        let tab = bar.tabs[index];
+        let width = recommendation_tab_width(tab.label.as_str());
+        if x + width > RECOMMENDATION_BAR_RIGHT_EDGE_X {
+            break;
+        }
+
+        if tab.active {
+            draw_pill(
+                frame,
+                x,
+                RECOMMENDATION_BAR_Y,
+                width,
+                RECOMMENDATION_BAR_HEIGHT,
+                true,
+            );
+        } else {
+            draw_pill(
+                frame,
+                x,
+                RECOMMENDATION_BAR_Y,
+                width,
+                RECOMMENDATION_BAR_HEIGHT,
+                false,
+            );
+        }
+
+        if tab.focused {
+            draw_recommendation_focus_ring(
+                frame,
+                x,
+                RECOMMENDATION_BAR_Y,
+                width,
+                RECOMMENDATION_BAR_HEIGHT,
+                tab.flash,
+            );
+        }
+
+        draw_text_ellipsized(
+            frame,
+            tab.label.as_str(),
+            Point::new(
+                x + 10,
+                RECOMMENDATION_BAR_Y + RECOMMENDATION_BAR_TEXT_Y_OFFSET,
+            ),
+            ui_font_small(),
+            if tab.active {
+                BinaryColor::Off
+            } else {
+                BinaryColor::On
+            },
+            Alignment::Left,
+            width - 16,
+        );
+
+        x += width + RECOMMENDATION_BAR_GAP_X;
+        index += 1;
+    }
+
+    if bar.show_right_more {
+        draw_recommendation_more_button(frame, RECOMMENDATION_BAR_RIGHT_BUTTON_X, true);
+    }
+}
+
+fn recommendation_tab_width(label: &str) -> i32 {
+    (mono_text_width_px(label, ui_font_small(), 1) + 24).max(RECOMMENDATION_BAR_MIN_WIDTH)
 }
 
 fn draw_recommendation_focus_ring(
@@ -1102,6 +1762,18 @@ fn draw_collection_row_at(
     if let Some(label) = row.progress_badge {
         draw_collection_progress_badge(frame, label.as_str(), title_position, row, None);
     }
+    draw_collection_progress_bar(
+        frame,
+        row.progress_percent,
+        row.finished,
+        Point::new(
+            title_position.x,
+            title_position.y + title_font.character_size.height as i32,
+        ),
+        COLLECTION_PROGRESS_BAR_WIDTH,
+        color,
+        None,
+    );
 }
 
 fn draw_collection_selected_band_accent(_frame: &mut FrameBuffer) {}
@@ -1150,6 +1822,60 @@ fn draw_collection_row_at_clipped(
             Some(collection_badge_clip(clip)),
         );
     }
+    draw_collection_progress_bar(
+        frame,
+        row.progress_percent,
+        row.finished,
+        Point::new(
+            title_position.x,
+            title_position.y + title_font.character_size.height as i32,
+        ),
+        COLLECTION_PROGRESS_BAR_WIDTH,
+        color,
+        Some(clip),
+    );
+}
+
+// A hairline track with the read share thickened over it, just under the title it belongs to.
+fn draw_collection_progress_bar(
+    frame: &mut FrameBuffer,
+    percent: u8,
+    finished: bool,
+    below: Point,
+    width: i32,
+    color: BinaryColor,
+    clip: Option<ClipRect>,
+) {
+    let y = below.y + COLLECTION_PROGRESS_BAR_GAP_PX;
+    if finished {
+        draw_finished_check(
+            frame,
+            below.x + width + COLLECTION_FINISHED_CHECK_GAP_PX,
+            y - 2,
+            color,
+            clip,
+        );
+    }
+    if percent == 0 {
+        return;
+    }
+
+    fill_rect_clipped(frame, below.x, y + 1, width, 1, color, clip);
+    let filled = width * i32::from(percent.min(100)) / 100;
+    fill_rect_clipped(frame, below.x, y, filled.max(1), 3, color, clip);
+}
+
+// A 7x6 tick, two pixels thick, beside the bar of a finished book.
+fn draw_finished_check(
+    frame: &mut FrameBuffer,
+    x: i32,
+    y: i32,
+    color: BinaryColor,
+    clip: Option<ClipRect>,
+) {
+    for (dx, dy) in [(0, 2), (1, 3), (2, 4), (3, 3), (4, 2), (5, 1), (6, 0)] {
+        fill_rect_clipped(frame, x + dx, y + dy, 1, 2, color, clip);
+    }
 }
 
 fn collection_meta_font(_row: &ContentRow) -> &'static MonoFont<'static> {
@@ -1271,12 +1997,10 @@ fn draw_reader_base(frame: &mut FrameBuffer, shell: &ReaderShell, step: u8, tota
 
     let stage_ready = step.saturating_mul(2) >= total_steps;
     if stage_ready {
-        draw_stage_token(
-            frame,
-            shell.stage.left_word.as_str(),
-            shell.stage.right_word.as_str(),
-            shell.stage.font,
-        );
+        draw_stage_token(frame, &shell.stage);
+        if shell.stage.highlight_anchor {
+            draw_stage_anchor_tick(frame, &shell.stage);
+        }
         fill_rect(frame, RSVP_STAGE_CENTER_X, 84, 1, 76, BinaryColor::On);
     }
 
@@ -1299,14 +2023,18 @@ fn draw_reader_base(frame: &mut FrameBuffer, shell: &ReaderShell, step: u8, tota
         }
     }
 
-    draw_text_ellipsized(
-        frame,
+    let preview = text_layout::ellipsized_str(
         shell.stage.preview.as_str(),
+        &text_layout::TEXT_FONT_BODY,
+        reader_preview_max_width_px(shell.stage.wpm),
+    );
+    text_layout::draw_str(
+        frame,
+        preview.as_str(),
         Point::new(READER_TEXT_LEFT_X, READER_PREVIEW_Y),
-        ui_font_body(),
+        &text_layout::TEXT_FONT_BODY,
         BinaryColor::On,
         Alignment::Left,
-        reader_preview_max_width_px(shell.stage.wpm),
     );
     let wpm = wpm_label(shell.stage.wpm);
     draw_text_right(
@@ -1316,14 +2044,14 @@ fn draw_reader_base(frame: &mut FrameBuffer, shell: &ReaderShell, step: u8, tota
         ui_font_body(),
         BinaryColor::On,
     );
-    fill_rect(
-        frame,
-        0,
-        232,
-        shell.stage.progress_width.into(),
-        8,
-        BinaryColor::On,
-    );
+    // A right-to-left book fills the bar from the right edge.
+    let progress_width = i32::from(shell.stage.progress_width);
+    let progress_x = if cfg!(feature = "rtl") && shell.stage.rtl_paragraph {
+        RSVP_STAGE_WIDTH - progress_width
+    } else {
+        0
+    };
+    fill_rect(frame, progress_x, 232, progress_width, 8, BinaryColor::On);
 }
 
 fn draw_reader_modal(frame: &mut FrameBuffer, modal: &ReaderModal, step: u8, total_steps: u8) {
@@ -1443,6 +2171,43 @@ fn draw_pause_modal_transition(
             1,
             BinaryColor::Off,
         );
+        if let Some(goal_permille) = modal.goal_permille {
+            fill_rect(
+                frame,
+                PAUSE_MODAL_CENTER_X - (divider_width / 2),
+                y + 47,
+                (divider_width * i32::from(goal_permille.min(1_000))) / 1_000,
+                PAUSE_GOAL_BAR_HEIGHT,
+                BinaryColor::Off,
+            );
+        }
+    }
+
+    if content_phase >= 1 {
+        draw_text_ellipsized_clipped(
+            frame,
+            modal.session_label.as_str(),
+            ui_font_small(),
+            ClippedTextSpec {
+                position: Point::new(x + 18, y + 32 + content_offset),
+                color: BinaryColor::Off,
+                alignment: Alignment::Left,
+                max_width_px: PAUSE_SESSION_LABEL_MAX_WIDTH_PX,
+            },
+            clip,
+        );
+        draw_text_ellipsized_clipped(
+            frame,
+            modal.remaining_label.as_str(),
+            ui_font_small(),
+            ClippedTextSpec {
+                position: Point::new(x + width as i32 - 18, y + 32 + content_offset),
+                color: BinaryColor::Off,
+                alignment: Alignment::Right,
+                max_width_px: PAUSE_SESSION_LABEL_MAX_WIDTH_PX,
+            },
+            clip,
+        );
     }
 
     if content_phase >= 1 {
@@ -1455,7 +2220,7 @@ fn draw_pause_modal_transition(
         draw_pause_modal_row(
             frame,
             &modal.rows[1],
-            Point::new(x + 18, y + 86 + content_offset),
+            Point::new(x + 18, y + 82 + content_offset),
             clip,
         );
     }
@@ -1463,7 +2228,7 @@ fn draw_pause_modal_transition(
         draw_pause_modal_row(
             frame,
             &modal.rows[2],
-            Point::new(x + 18, y + 112 + content_offset),
+            Point::new(x + 18, y + 104 + content_offset),
             clip,
         );
     }
@@ -1471,7 +2236,25 @@ fn draw_pause_modal_transition(
         draw_pause_modal_row(
             frame,
             &modal.rows[3],
-            Point::new(x + 18, y + 138 + content_offset),
+            Point::new(x + 18, y + 126 + content_offset),
+            clip,
+        );
+        draw_pause_modal_row(
+            frame,
+            &modal.rows[4],
+            Point::new(x + 18, y + 148 + content_offset),
+            clip,
+        );
+        draw_pause_modal_row(
+            frame,
+            &modal.rows[5],
+            Point::new(x + 18, y + 170 + content_offset),
+            clip,
+        );
+        draw_pause_modal_row(
+            frame,
+            &modal.rows[6],
+            Point::new(x + 18, y + 192 + content_offset),
             clip,
         );
     }
@@ -1646,112 +2429,1048 @@ fn draw_loading_modal_transition(
         );
     }
 
-    if content_phase >= 1 {
-        stroke_rect(
-            frame,
-            bar_x,
-            y + 82 + content_offset,
-            track_width,
-            16,
-            BinaryColor::Off,
-        );
-        if bar_width > 0 {
-            draw_barberpole_fill(
-                frame,
-                bar_x + 4,
-                y + 86 + content_offset,
-                bar_width,
-                8,
-                modal.stripe_phase,
-                clip,
-            );
-        }
+    if content_phase >= 1 {
+        stroke_rect(
+            frame,
+            bar_x,
+            y + 82 + content_offset,
+            track_width,
+            16,
+            BinaryColor::Off,
+        );
+        if bar_width > 0 {
+            draw_barberpole_fill(
+                frame,
+                bar_x + 4,
+                y + 86 + content_offset,
+                bar_width,
+                8,
+                modal.stripe_phase,
+                clip,
+            );
+        }
+    }
+}
+
+fn draw_barberpole_fill(
+    frame: &mut FrameBuffer,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    stripe_phase: u8,
+    clip: ClipRect,
+) {
+    if width <= 0 || height <= 0 {
+        return;
+    }
+
+    let fill_clip = ClipRect {
+        x,
+        y,
+        width,
+        height,
+    };
+    let clipped = intersect_clip_rects(clip, fill_clip);
+    fill_rect_clipped(frame, x, y, width, height, BinaryColor::Off, clipped);
+
+    let stripe_spacing = 10;
+    let stripe_width = 3;
+    let phase_offset = ((stripe_phase % 8) as i32) * 2;
+    let mut stripe_origin = -height - phase_offset;
+    while stripe_origin < width + height {
+        let mut offset = 0;
+        while offset < height + stripe_width {
+            let stripe_x = x + stripe_origin + offset;
+            let stripe_y = y + height - 1 - offset;
+            fill_rect_clipped(
+                frame,
+                stripe_x,
+                stripe_y,
+                stripe_width,
+                1,
+                BinaryColor::On,
+                clipped,
+            );
+            offset += 1;
+        }
+        stripe_origin += stripe_spacing;
+    }
+}
+
+// A stage word too wide for one line breaks at its wrap point: the half holding the pivot keeps the
+// usual anchors, and the other half is centred on the pivot letter one line above or below.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct StageWrap {
+    split_index: usize,
+    pivot_on_first_line: bool,
+}
+
+fn stage_wrap(
+    stage: &app_runtime::components::RsvpStage,
+    spec: StageTextSpec,
+) -> Option<StageWrap> {
+    let left_chars = normalized_text(stage.left_word.as_str()).chars().count() as i32;
+    let right_chars = normalized_text(stage.right_word.as_str()).chars().count() as i32;
+    let cell_width = spec.family.cell().width as i32;
+    let fits = RSVP_STAGE_LEFT_ANCHOR_X + 1 >= left_chars * cell_width
+        && RSVP_STAGE_RIGHT_ANCHOR_X + right_chars * cell_width <= RSVP_STAGE_WIDTH;
+    let split_index = usize::from(stage.wrap_index);
+    if fits || split_index == 0 || split_index >= (left_chars + right_chars) as usize {
+        return None;
+    }
+
+    Some(StageWrap {
+        split_index,
+        pivot_on_first_line: split_index > left_chars as usize,
+    })
+}
+
+// Baselines of the pivot line and the other line when a word wraps.
+fn stage_wrap_lines(spec: StageTextSpec, wrap: StageWrap) -> (i32, i32) {
+    let pitch = spec.family.cell().height as i32 + RSVP_STAGE_WRAP_GAP_Y;
+    let first_y = spec.y - pitch / 2;
+    if wrap.pivot_on_first_line {
+        (first_y, first_y + pitch)
+    } else {
+        (first_y + pitch, first_y)
+    }
+}
+
+fn draw_stage_token(frame: &mut FrameBuffer, stage: &app_runtime::components::RsvpStage) {
+    #[cfg(feature = "rtl")]
+    if stage.rtl_word {
+        draw_stage_token_rtl(frame, stage);
+        return;
+    }
+
+    let spec = stage_font_spec(stage.font);
+    let left = normalized_text(stage.left_word.as_str());
+    let right = normalized_text(stage.right_word.as_str());
+    let left_chars = left.chars().count();
+    let emphasis = StageEmphasis {
+        pivot_index: left_chars,
+        pivot_style: stage.pivot_style,
+        bold_lead_chars: usize::from(stage.bold_lead_chars),
+    };
+
+    let Some(wrap) = stage_wrap(stage, spec) else {
+        draw_stage_line(frame, left.as_str(), right.as_str(), spec, 0, emphasis);
+        return;
+    };
+
+    let (pivot_y, other_y) = stage_wrap_lines(spec, wrap);
+    let mut word = left.clone();
+    let _ = word.push_str(right.as_str());
+    let split_byte = word
+        .char_indices()
+        .nth(wrap.split_index)
+        .map_or(word.len(), |(byte, _)| byte);
+    let (head, tail) = word.split_at(split_byte);
+    let pivot_spec = StageTextSpec { y: pivot_y, ..spec };
+    let other_spec = StageTextSpec { y: other_y, ..spec };
+    let cell_width = spec.family.cell().width as i32;
+    let pivot_center_x = RSVP_STAGE_RIGHT_ANCHOR_X + cell_width / 2;
+
+    if wrap.pivot_on_first_line {
+        let (before, after) = head.split_at(left.len());
+        draw_stage_line(frame, before, after, pivot_spec, 0, emphasis);
+        let tail_width = tail.chars().count() as i32 * cell_width;
+        draw_stage_segment(
+            frame,
+            tail,
+            pivot_center_x - tail_width / 2,
+            other_spec,
+            wrap.split_index,
+            emphasis,
+        );
+    } else {
+        let (before, after) = tail.split_at(left.len() - head.len());
+        draw_stage_line(frame, before, after, pivot_spec, wrap.split_index, emphasis);
+        let head_width = head.chars().count() as i32 * cell_width;
+        draw_stage_segment(
+            frame,
+            head,
+            pivot_center_x - head_width / 2,
+            other_spec,
+            0,
+            emphasis,
+        );
+    }
+}
+
+// Mirrors the word around the anchors: the pivot keeps the cell after the right anchor, the
+// letters read before it run rightwards from there and the rest leftwards from the left anchor.
+// Letters are shaped in reading order first, and a mirrored word stays on one line.
+#[cfg(feature = "rtl")]
+fn draw_stage_token_rtl(frame: &mut FrameBuffer, stage: &app_runtime::components::RsvpStage) {
+    let spec = stage_font_spec(stage.font);
+    let cell_width = spec.family.cell().width as i32;
+    let pivot_index = stage.left_word.as_str().chars().count();
+    let emphasis = StageEmphasis {
+        pivot_index,
+        pivot_style: stage.pivot_style,
+        bold_lead_chars: usize::from(stage.bold_lead_chars),
+    };
+    let mut word = HeaplessString::<NORMALIZED_TEXT_MAX_BYTES>::new();
+    let _ = word.push_str(stage.left_word.as_str());
+    let _ = word.push_str(stage.right_word.as_str());
+
+    let mut letters =
+        heapless::Vec::<(usize, char), { domain::formatter::MAX_READING_TOKEN_BYTES }>::new();
+    arabic_shaping::shape(word.as_str(), |index, ch| {
+        let _ = letters.push((index, normalize_display_char(ch)));
+    });
+    let pivot = letters
+        .iter()
+        .position(|(index, _)| *index >= pivot_index)
+        .unwrap_or(letters.len());
+
+    let mut letter = [0u8; 4];
+    for (position, (index, ch)) in letters.iter().enumerate() {
+        let x = if position > pivot {
+            RSVP_STAGE_LEFT_ANCHOR_X + 1 - (position - pivot) as i32 * cell_width
+        } else {
+            RSVP_STAGE_RIGHT_ANCHOR_X + (pivot - position) as i32 * cell_width
+        };
+        draw_text(
+            frame,
+            ch.encode_utf8(&mut letter),
+            Point::new(x, spec.y),
+            spec.family.face(emphasis.style_at(*index)),
+            BinaryColor::On,
+            Alignment::Left,
+        );
+    }
+}
+
+// One stage line split around the anchors; `first_index` counts the letters on earlier lines.
+fn draw_stage_line(
+    frame: &mut FrameBuffer,
+    left: &str,
+    right: &str,
+    spec: StageTextSpec,
+    first_index: usize,
+    emphasis: StageEmphasis,
+) {
+    // The left segment ends at the left anchor, as right-aligned text would.
+    let left_chars = left.chars().count();
+    let cell_width = spec.family.cell().width as i32;
+    let left_x = RSVP_STAGE_LEFT_ANCHOR_X - (left_chars as i32 * cell_width - 1);
+    draw_stage_segment(frame, left, left_x, spec, first_index, emphasis);
+    draw_stage_segment(
+        frame,
+        right,
+        RSVP_STAGE_RIGHT_ANCHOR_X,
+        spec,
+        first_index + left_chars,
+        emphasis,
+    );
+}
+
+// Draws letter by letter so each can take its own face; the faces share a cell size.
+fn draw_stage_segment(
+    frame: &mut FrameBuffer,
+    text: &str,
+    x: i32,
+    spec: StageTextSpec,
+    first_index: usize,
+    emphasis: StageEmphasis,
+) {
+    let cell_width = spec.family.cell().width as i32;
+    let mut letter = [0u8; 4];
+    for (offset, ch) in text.chars().enumerate() {
+        draw_text(
+            frame,
+            ch.encode_utf8(&mut letter),
+            Point::new(x + offset as i32 * cell_width, spec.y),
+            spec.family.face(emphasis.style_at(first_index + offset)),
+            BinaryColor::On,
+            Alignment::Left,
+        );
+    }
+}
+
+// The anchor letter always opens the right-hand segment, so the tick sits under the first cell
+// after the right anchor, on whichever line holds it.
+fn draw_stage_anchor_tick(frame: &mut FrameBuffer, stage: &app_runtime::components::RsvpStage) {
+    if stage.right_word.is_empty() {
+        return;
+    }
+
+    let spec = stage_font_spec(stage.font);
+    let pivot_y = stage_wrap(stage, spec)
+        .filter(|_| !(cfg!(feature = "rtl") && stage.rtl_word))
+        .map_or(spec.y, |wrap| stage_wrap_lines(spec, wrap).0);
+    let cell_width = spec.family.cell().width as i32;
+    let glyph_bottom = pivot_y + spec.family.cell().height as i32;
+    fill_rect(
+        frame,
+        RSVP_STAGE_RIGHT_ANCHOR_X,
+        glyph_bottom + RSVP_ANCHOR_TICK_GAP_Y,
+        cell_width,
+        RSVP_ANCHOR_TICK_HEIGHT,
+        BinaryColor::On,
+    );
+}
+
+fn draw_definition(frame: &mut FrameBuffer, shell: &DefinitionShell) {
+    let part_of_speech_width =
+        mono_text_width_px(shell.part_of_speech.as_str(), ui_font_small(), 1);
+    draw_text_ellipsized(
+        frame,
+        shell.word.as_str(),
+        Point::new(READER_TEXT_LEFT_X, 18),
+        ui_font_title(),
+        BinaryColor::On,
+        Alignment::Left,
+        READER_TITLE_MAX_WIDTH_PX - part_of_speech_width - 12,
+    );
+    draw_text_right(
+        frame,
+        shell.part_of_speech.as_str(),
+        Point::new(READER_TEXT_RIGHT_X, 24),
+        ui_font_small(),
+        BinaryColor::On,
+    );
+    fill_rect(
+        frame,
+        READER_TEXT_LEFT_X,
+        DEFINITION_DIVIDER_Y,
+        READER_TITLE_MAX_WIDTH_PX,
+        1,
+        BinaryColor::On,
+    );
+
+    if let Some(status_label) = shell.status_label {
+        draw_text(
+            frame,
+            status_label,
+            Point::new(PAUSE_MODAL_CENTER_X, DEFINITION_STATUS_Y),
+            ui_font_body(),
+            BinaryColor::On,
+            Alignment::Center,
+        );
+    } else {
+        for (index, line) in shell.lines.iter().enumerate() {
+            draw_text(
+                frame,
+                line.as_str(),
+                Point::new(
+                    READER_TEXT_LEFT_X,
+                    DEFINITION_BODY_Y + index as i32 * DEFINITION_LINE_HEIGHT_PX,
+                ),
+                ui_font_body(),
+                BinaryColor::On,
+                Alignment::Left,
+            );
+        }
+    }
+
+    if shell.show_scroll_up {
+        draw_vertical_chevron(frame, DEFINITION_SCROLL_X, DEFINITION_BODY_Y, true);
+    }
+    if shell.show_scroll_down {
+        draw_vertical_chevron(
+            frame,
+            DEFINITION_SCROLL_X,
+            DEFINITION_BODY_Y + (shell.lines.len() as i32 - 1) * DEFINITION_LINE_HEIGHT_PX + 8,
+            false,
+        );
+    }
+
+    draw_text(
+        frame,
+        shell.help.text,
+        Point::new(READER_TEXT_LEFT_X, PARAGRAPH_FOOTER_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+}
+
+fn draw_section_break(frame: &mut FrameBuffer, shell: &SectionBreakShell) {
+    draw_text_ellipsized(
+        frame,
+        shell.title.as_str(),
+        Point::new(READER_TEXT_LEFT_X, 18),
+        ui_font_body(),
+        BinaryColor::On,
+        Alignment::Left,
+        READER_TITLE_MAX_WIDTH_PX,
+    );
+    fill_rect(
+        frame,
+        READER_TEXT_LEFT_X,
+        DEFINITION_DIVIDER_Y,
+        READER_TITLE_MAX_WIDTH_PX,
+        1,
+        BinaryColor::On,
+    );
+    draw_text(
+        frame,
+        shell.label,
+        Point::new(PAUSE_MODAL_CENTER_X, SECTION_BREAK_LABEL_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Center,
+    );
+    draw_text_ellipsized(
+        frame,
+        shell.heading.as_str(),
+        Point::new(PAUSE_MODAL_CENTER_X, SECTION_BREAK_HEADING_Y),
+        ui_font_title(),
+        BinaryColor::On,
+        Alignment::Center,
+        READER_TITLE_MAX_WIDTH_PX,
+    );
+    draw_text(
+        frame,
+        shell.help.text,
+        Point::new(READER_TEXT_LEFT_X, PARAGRAPH_FOOTER_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+}
+
+fn draw_low_battery(frame: &mut FrameBuffer, shell: &LowBatteryShell) {
+    status_bar::draw(frame, &shell.status);
+    stroke_rect(frame, 58, 62, 268, 106, BinaryColor::On);
+    draw_text(
+        frame,
+        shell.title,
+        Point::new(192, 82),
+        ui_font_title(),
+        BinaryColor::On,
+        Alignment::Center,
+    );
+    draw_text(
+        frame,
+        shell.body.as_str(),
+        Point::new(192, 118),
+        ui_font_body(),
+        BinaryColor::On,
+        Alignment::Center,
+    );
+    draw_text(
+        frame,
+        shell.help.text,
+        Point::new(192, 146),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Center,
+    );
+}
+
+// Filled dots count the steps done so far. The speed step swaps the value box for a stage that plays
+// the demo sentence at the chosen speed, with the speed itself underneath.
+fn draw_onboarding(frame: &mut FrameBuffer, shell: &OnboardingShell) {
+    let count = i32::from(shell.step_count);
+    let dots_x =
+        200 - (count * ONBOARDING_DOT_PITCH - (ONBOARDING_DOT_PITCH - ONBOARDING_DOT_SIZE)) / 2;
+    for index in 0..count {
+        let x = dots_x + index * ONBOARDING_DOT_PITCH;
+        if index < i32::from(shell.step_number) {
+            fill_rect(
+                frame,
+                x,
+                ONBOARDING_DOT_Y,
+                ONBOARDING_DOT_SIZE,
+                ONBOARDING_DOT_SIZE,
+                BinaryColor::On,
+            );
+        } else {
+            stroke_rect(
+                frame,
+                x,
+                ONBOARDING_DOT_Y,
+                ONBOARDING_DOT_SIZE,
+                ONBOARDING_DOT_SIZE,
+                BinaryColor::On,
+            );
+        }
+    }
+    draw_text(
+        frame,
+        shell.title,
+        Point::new(200, 40),
+        ui_font_title(),
+        BinaryColor::On,
+        Alignment::Center,
+    );
+    draw_text(
+        frame,
+        shell.prompt,
+        Point::new(200, 72),
+        ui_font_body(),
+        BinaryColor::On,
+        Alignment::Center,
+    );
+
+    if let Some(word) = shell.demo_word {
+        for y in [ONBOARDING_STAGE_TOP_Y, ONBOARDING_STAGE_BOTTOM_Y] {
+            fill_rect(
+                frame,
+                ONBOARDING_STAGE_X,
+                y,
+                ONBOARDING_STAGE_WIDTH,
+                1,
+                BinaryColor::On,
+            );
+        }
+        draw_text_scaled(
+            frame,
+            word,
+            Point::new(200, 110),
+            ui_font_title(),
+            BinaryColor::On,
+            Alignment::Center,
+            ONBOARDING_DEMO_SCALE,
+        );
+        draw_text(
+            frame,
+            shell.value,
+            Point::new(200, 176),
+            ui_font_body(),
+            BinaryColor::On,
+            Alignment::Center,
+        );
+    } else if !shell.value.is_empty() {
+        stroke_rect(
+            frame,
+            ONBOARDING_VALUE_X,
+            ONBOARDING_VALUE_Y,
+            ONBOARDING_VALUE_WIDTH,
+            ONBOARDING_VALUE_HEIGHT,
+            BinaryColor::On,
+        );
+        draw_text(
+            frame,
+            shell.value,
+            Point::new(200, ONBOARDING_VALUE_Y + 10),
+            ui_font_title(),
+            BinaryColor::On,
+            Alignment::Center,
+        );
+    }
+
+    draw_text(
+        frame,
+        shell.help.text,
+        Point::new(200, ONBOARDING_HELP_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Center,
+    );
+}
+
+// Drawn once a minute at most, so it favours large static shapes over detail. Without a book the
+// clock takes the whole screen; without a clock either, only the wordmark remains.
+fn draw_screensaver(frame: &mut FrameBuffer, shell: &ScreensaverShell) {
+    draw_text(
+        frame,
+        shell.help.text,
+        Point::new(200, SCREENSAVER_HELP_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Center,
+    );
+
+    if shell.title.is_empty() {
+        match shell.clock {
+            Some(clock) => draw_text_scaled(
+                frame,
+                clock.as_str(),
+                Point::new(200, 80),
+                ui_font_title(),
+                BinaryColor::On,
+                Alignment::Center,
+                SCREENSAVER_CLOCK_ALONE_SCALE,
+            ),
+            None => draw_text(
+                frame,
+                "MOTIF",
+                Point::new(200, 108),
+                ui_font_title(),
+                BinaryColor::On,
+                Alignment::Center,
+            ),
+        }
+        return;
+    }
+
+    draw_screensaver_cover(frame, shell.title.as_str());
+
+    let mut text_y = 84;
+    if let Some(clock) = shell.clock {
+        draw_text_scaled(
+            frame,
+            clock.as_str(),
+            Point::new(SCREENSAVER_CLOCK_X, SCREENSAVER_CLOCK_Y),
+            ui_font_title(),
+            BinaryColor::On,
+            Alignment::Left,
+            SCREENSAVER_CLOCK_SCALE,
+        );
+        text_y = 128;
+    }
+    draw_text_ellipsized(
+        frame,
+        shell.title.as_str(),
+        Point::new(SCREENSAVER_TEXT_X, text_y),
+        ui_font_body(),
+        BinaryColor::On,
+        Alignment::Left,
+        SCREENSAVER_TEXT_WIDTH,
+    );
+
+    let bar_y = text_y + 24;
+    stroke_rect(
+        frame,
+        SCREENSAVER_TEXT_X,
+        bar_y,
+        SCREENSAVER_TEXT_WIDTH,
+        SCREENSAVER_BAR_HEIGHT,
+        BinaryColor::On,
+    );
+    fill_rect(
+        frame,
+        SCREENSAVER_TEXT_X + 2,
+        bar_y + 2,
+        (SCREENSAVER_TEXT_WIDTH - 4) * i32::from(shell.progress_percent.min(100)) / 100,
+        SCREENSAVER_BAR_HEIGHT - 4,
+        BinaryColor::On,
+    );
+    let mut label: HeaplessString<12> = HeaplessString::new();
+    let _ = write!(label, "{}% READ", shell.progress_percent.min(100));
+    draw_text(
+        frame,
+        label.as_str(),
+        Point::new(SCREENSAVER_TEXT_X, bar_y + SCREENSAVER_BAR_HEIGHT + 6),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+}
+
+// There is no cover art yet, so the book's initial stands in for it.
+fn draw_screensaver_cover(frame: &mut FrameBuffer, title: &str) {
+    fill_rect(
+        frame,
+        SCREENSAVER_COVER_X,
+        SCREENSAVER_COVER_Y,
+        SCREENSAVER_COVER_WIDTH,
+        SCREENSAVER_COVER_HEIGHT,
+        BinaryColor::On,
+    );
+    stroke_rect(
+        frame,
+        SCREENSAVER_COVER_X + SCREENSAVER_COVER_INSET,
+        SCREENSAVER_COVER_Y + SCREENSAVER_COVER_INSET,
+        SCREENSAVER_COVER_WIDTH - SCREENSAVER_COVER_INSET * 2,
+        SCREENSAVER_COVER_HEIGHT - SCREENSAVER_COVER_INSET * 2,
+        BinaryColor::Off,
+    );
+    let Some(initial) = title.chars().find(|ch| ch.is_alphanumeric()) else {
+        return;
+    };
+    let mut buffer = [0u8; 4];
+    draw_text_scaled(
+        frame,
+        initial.to_ascii_uppercase().encode_utf8(&mut buffer),
+        Point::new(SCREENSAVER_COVER_LETTER_X, SCREENSAVER_COVER_LETTER_Y),
+        ui_font_title(),
+        BinaryColor::Off,
+        Alignment::Center,
+        SCREENSAVER_COVER_LETTER_SCALE,
+    );
+}
+
+// Keep sits left and starts focused; the accepting choice is one detent away.
+fn draw_confirm(frame: &mut FrameBuffer, shell: &ConfirmShell) {
+    let center_x = CONFIRM_BOX_X + CONFIRM_BOX_WIDTH / 2;
+    stroke_rect(
+        frame,
+        CONFIRM_BOX_X,
+        CONFIRM_BOX_Y,
+        CONFIRM_BOX_WIDTH,
+        CONFIRM_BOX_HEIGHT,
+        BinaryColor::On,
+    );
+    stroke_rect(
+        frame,
+        CONFIRM_BOX_X + 2,
+        CONFIRM_BOX_Y + 2,
+        CONFIRM_BOX_WIDTH - 4,
+        CONFIRM_BOX_HEIGHT - 4,
+        BinaryColor::On,
+    );
+    draw_text(
+        frame,
+        shell.title,
+        Point::new(center_x, CONFIRM_BOX_Y + 20),
+        ui_font_title(),
+        BinaryColor::On,
+        Alignment::Center,
+    );
+    draw_text_ellipsized(
+        frame,
+        shell.body.as_str(),
+        Point::new(center_x, CONFIRM_BOX_Y + 62),
+        ui_font_body(),
+        BinaryColor::On,
+        Alignment::Center,
+        CONFIRM_BOX_WIDTH - CONFIRM_BODY_PADDING_X * 2,
+    );
+
+    let cancel_x = center_x - CONFIRM_BUTTON_GAP_X / 2 - CONFIRM_BUTTON_WIDTH;
+    let accept_x = center_x + CONFIRM_BUTTON_GAP_X / 2;
+    for (x, label, focused) in [
+        (cancel_x, shell.cancel_label, !shell.accept_focused),
+        (accept_x, shell.accept_label, shell.accept_focused),
+    ] {
+        draw_pill(
+            frame,
+            x,
+            CONFIRM_BUTTON_Y,
+            CONFIRM_BUTTON_WIDTH,
+            CONFIRM_BUTTON_HEIGHT,
+            focused,
+        );
+        draw_text(
+            frame,
+            label,
+            Point::new(
+                x + CONFIRM_BUTTON_WIDTH / 2,
+                CONFIRM_BUTTON_Y + (CONFIRM_BUTTON_HEIGHT - 13) / 2,
+            ),
+            ui_font_body(),
+            if focused {
+                BinaryColor::Off
+            } else {
+                BinaryColor::On
+            },
+            Alignment::Center,
+        );
+    }
+
+    draw_text(
+        frame,
+        shell.help.text,
+        Point::new(center_x, CONFIRM_HELP_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Center,
+    );
+}
+
+fn draw_crash_report(frame: &mut FrameBuffer, shell: &CrashReportShell) {
+    draw_text(
+        frame,
+        shell.title,
+        Point::new(READER_TEXT_LEFT_X, 18),
+        ui_font_title(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+    fill_rect(
+        frame,
+        READER_TEXT_LEFT_X,
+        DEFINITION_DIVIDER_Y,
+        READER_TITLE_MAX_WIDTH_PX,
+        1,
+        BinaryColor::On,
+    );
+
+    for (index, line) in shell.lines.iter().enumerate() {
+        draw_text(
+            frame,
+            line.as_str(),
+            Point::new(
+                READER_TEXT_LEFT_X,
+                DEFINITION_BODY_Y + index as i32 * DEFINITION_LINE_HEIGHT_PX,
+            ),
+            ui_font_body(),
+            BinaryColor::On,
+            Alignment::Left,
+        );
+    }
+
+    draw_text(
+        frame,
+        shell.help.text,
+        Point::new(READER_TEXT_LEFT_X, PARAGRAPH_FOOTER_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+    draw_text_right(
+        frame,
+        shell.boot_label.as_str(),
+        Point::new(READER_TEXT_RIGHT_X, PARAGRAPH_FOOTER_Y),
+        ui_font_small(),
+        BinaryColor::On,
+    );
+}
+
+fn draw_page(frame: &mut FrameBuffer, shell: &PageShell) {
+    draw_text_ellipsized(
+        frame,
+        shell.title.as_str(),
+        Point::new(READER_TEXT_LEFT_X, 18),
+        ui_font_body(),
+        BinaryColor::On,
+        Alignment::Left,
+        READER_TITLE_MAX_WIDTH_PX,
+    );
+    fill_rect(
+        frame,
+        READER_TEXT_LEFT_X,
+        DEFINITION_DIVIDER_Y,
+        READER_TITLE_MAX_WIDTH_PX,
+        1,
+        BinaryColor::On,
+    );
+
+    let mut line_y = PAGE_BODY_Y;
+    for line in shell.lines.iter() {
+        draw_text_ellipsized(
+            frame,
+            line.as_str(),
+            Point::new(READER_TEXT_LEFT_X, line_y),
+            ui_font_body(),
+            BinaryColor::On,
+            Alignment::Left,
+            READER_TITLE_MAX_WIDTH_PX,
+        );
+        line_y += PAGE_LINE_HEIGHT_PX;
+    }
+
+    fill_rect(
+        frame,
+        0,
+        PAGE_PROGRESS_Y,
+        shell.progress_width.into(),
+        2,
+        BinaryColor::On,
+    );
+    draw_text(
+        frame,
+        shell.help.text,
+        Point::new(READER_TEXT_LEFT_X, PARAGRAPH_FOOTER_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+}
+
+fn draw_diagnostics(frame: &mut FrameBuffer, shell: &DiagnosticsShell) {
+    draw_text(
+        frame,
+        shell.title,
+        Point::new(READER_TEXT_LEFT_X, 18),
+        ui_font_body(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+    fill_rect(
+        frame,
+        READER_TEXT_LEFT_X,
+        DEFINITION_DIVIDER_Y,
+        READER_TITLE_MAX_WIDTH_PX,
+        1,
+        BinaryColor::On,
+    );
+
+    let mut items = [ListItem::default(); DIAGNOSTICS_ROW_COUNT];
+    for (item, row) in items.iter_mut().zip(shell.rows.iter()) {
+        *item = ListItem {
+            label: row.label,
+            value: row.value.as_str(),
+            ..ListItem::default()
+        };
+    }
+    DIAGNOSTICS_LIST.draw(frame, &items, None);
+
+    draw_text(
+        frame,
+        shell.help.text,
+        Point::new(READER_TEXT_LEFT_X, PARAGRAPH_FOOTER_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+}
+
+fn draw_history(frame: &mut FrameBuffer, shell: &HistoryShell) {
+    draw_text(
+        frame,
+        shell.title,
+        Point::new(READER_TEXT_LEFT_X, 18),
+        ui_font_body(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+    fill_rect(
+        frame,
+        READER_TEXT_LEFT_X,
+        DEFINITION_DIVIDER_Y,
+        READER_TITLE_MAX_WIDTH_PX,
+        1,
+        BinaryColor::On,
+    );
+
+    if shell.total == 0 {
+        draw_text(
+            frame,
+            "No reading sessions yet",
+            Point::new(READER_TEXT_LEFT_X, HISTORY_LIST.y + 4),
+            ui_font_body(),
+            BinaryColor::On,
+            Alignment::Left,
+        );
+    }
+
+    let mut words = [const { HeaplessString::<12>::new() }; HISTORY_VISIBLE_ROWS];
+    for (label, row) in words.iter_mut().zip(shell.rows.iter()) {
+        let _ = write!(label, "{} w", row.words);
+    }
+    let mut items = [ListItem::default(); HISTORY_VISIBLE_ROWS];
+    let count = usize::from(shell.total)
+        .saturating_sub(usize::from(shell.first))
+        .min(HISTORY_VISIBLE_ROWS);
+    for ((item, row), words) in items.iter_mut().zip(shell.rows.iter()).zip(words.iter()) {
+        *item = ListItem {
+            detail: row.when.as_str(),
+            label: row.title.as_str(),
+            value: words.as_str(),
+            selected: row.selected,
+            ..ListItem::default()
+        };
     }
-}
+    HISTORY_LIST.draw(
+        frame,
+        &items[..count],
+        Some(ListScroll {
+            first: usize::from(shell.first),
+            total: usize::from(shell.total),
+        }),
+    );
 
-fn draw_barberpole_fill(
-    frame: &mut FrameBuffer,
-    x: i32,
-    y: i32,
-    width: i32,
-    height: i32,
-    stripe_phase: u8,
-    clip: ClipRect,
-) {
-    if width <= 0 || height <= 0 {
-        return;
+    draw_text(
+        frame,
+        shell.help.text,
+        Point::new(READER_TEXT_LEFT_X, PARAGRAPH_FOOTER_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+    let mut summary: HeaplessString<32> = HeaplessString::new();
+    if let Some(streak_days) = shell.streak_days {
+        let _ = write!(summary, "{} day streak, ", streak_days);
     }
+    let _ = write!(summary, "{} words", shell.total_words);
+    draw_text(
+        frame,
+        summary.as_str(),
+        Point::new(READER_TEXT_RIGHT_X, PARAGRAPH_FOOTER_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Right,
+    );
+}
 
-    let fill_clip = ClipRect {
-        x,
-        y,
-        width,
-        height,
-    };
-    let clipped = intersect_clip_rects(clip, fill_clip);
-    fill_rect_clipped(frame, x, y, width, height, BinaryColor::Off, clipped);
+fn draw_contents(frame: &mut FrameBuffer, shell: &ContentsShell) {
+    draw_text_ellipsized(
+        frame,
+        shell.title.as_str(),
+        Point::new(READER_TEXT_LEFT_X, 18),
+        ui_font_body(),
+        BinaryColor::On,
+        Alignment::Left,
+        READER_TITLE_MAX_WIDTH_PX,
+    );
+    fill_rect(
+        frame,
+        READER_TEXT_LEFT_X,
+        DEFINITION_DIVIDER_Y,
+        READER_TITLE_MAX_WIDTH_PX,
+        1,
+        BinaryColor::On,
+    );
 
-    let stripe_spacing = 10;
-    let stripe_width = 3;
-    let phase_offset = ((stripe_phase % 8) as i32) * 2;
-    let mut stripe_origin = -height - phase_offset;
-    while stripe_origin < width + height {
-        let mut offset = 0;
-        while offset < height + stripe_width {
-            let stripe_x = x + stripe_origin + offset;
-            let stripe_y = y + height - 1 - offset;
-            fill_rect_clipped(
-                frame,
-                stripe_x,
-                stripe_y,
-                stripe_width,
-                1,
-                BinaryColor::On,
-                clipped,
-            );
-            offset += 1;
+    let mut numbers = [const { HeaplessString::<8>::new() }; CONTENTS_VISIBLE_ROWS];
+    let mut durations = [const { HeaplessString::<12>::new() }; CONTENTS_VISIBLE_ROWS];
+    for ((number, duration), row) in numbers
+        .iter_mut()
+        .zip(durations.iter_mut())
+        .zip(shell.rows.iter())
+    {
+        let _ = write!(number, "{}", row.number);
+        if row.minutes > 0 {
+            let _ = write!(duration, "~{} min", row.minutes);
         }
-        stripe_origin += stripe_spacing;
     }
-}
+    let mut items = [ListItem::default(); CONTENTS_VISIBLE_ROWS];
+    let mut count = 0;
+    for ((row, number), duration) in shell.rows.iter().zip(numbers.iter()).zip(durations.iter()) {
+        if row.number == 0 {
+            continue;
+        }
+        items[count] = ListItem {
+            icon: match row.marker {
+                ContentsMarker::Read => ListIcon::Filled,
+                ContentsMarker::Current => ListIcon::Arrow,
+                ContentsMarker::Unread => ListIcon::Outline,
+            },
+            detail: number.as_str(),
+            label: row.preview.as_str(),
+            value: duration.as_str(),
+            selected: row.selected,
+        };
+        count += 1;
+    }
+    CONTENTS_LIST.draw(
+        frame,
+        &items[..count],
+        Some(ListScroll {
+            first: usize::from(shell.first),
+            total: usize::from(shell.total),
+        }),
+    );
 
-fn draw_stage_token(frame: &mut FrameBuffer, left: &str, right: &str, font: StageFont) {
-    let spec = stage_font_spec(font);
+    draw_text(
+        frame,
+        shell.help.text,
+        Point::new(READER_TEXT_LEFT_X, PARAGRAPH_FOOTER_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+    let mut position: HeaplessString<16> = HeaplessString::new();
+    let _ = write!(position, "{} / {}", shell.selected_index, shell.total);
+    draw_text(
+        frame,
+        position.as_str(),
+        Point::new(READER_TEXT_RIGHT_X, PARAGRAPH_FOOTER_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Right,
+    );
+}
 
-    if spec.scale == 1 {
-        draw_text_right(
-            frame,
-            left,
-            Point::new(spec.left_anchor_x, spec.y),
-            spec.font,
-            BinaryColor::On,
-        );
-        draw_text(
-            frame,
-            right,
-            Point::new(spec.right_anchor_x, spec.y),
-            spec.font,
-            BinaryColor::On,
-            Alignment::Left,
-        );
-    } else {
-        draw_text_right_scaled(
-            frame,
-            left,
-            Point::new(spec.left_anchor_x, spec.y),
-            spec.font,
-            BinaryColor::On,
-            spec.scale,
-        );
-        draw_text_scaled(
-            frame,
-            right,
-            Point::new(spec.right_anchor_x, spec.y),
-            spec.font,
-            BinaryColor::On,
-            Alignment::Left,
-            spec.scale,
-        );
+fn draw_vertical_chevron(frame: &mut FrameBuffer, x: i32, y: i32, pointing_up: bool) {
+    let mut offset = 0;
+    while offset < 5 {
+        let row_y = if pointing_up {
+            y + offset
+        } else {
+            y + 4 - offset
+        };
+        set_pixel(frame, x - offset, row_y);
+        set_pixel(frame, x + offset, row_y);
+        offset += 1;
     }
 }
 
@@ -2071,6 +3790,7 @@ fn push_padded_decimal<const N: usize>(target: &mut HeaplessString<N>, value: u1
 }
 
 fn draw_settings(frame: &mut FrameBuffer, shell: &SettingsShell, step: u8, total_steps: u8) {
+    status_bar::draw(frame, &shell.status);
     if let Some(topic_grid) = shell.topic_preferences {
         draw_topic_preferences(frame, &topic_grid);
         return;
@@ -2141,6 +3861,29 @@ fn draw_settings(frame: &mut FrameBuffer, shell: &SettingsShell, step: u8, total
         index += 1;
     }
 
+    if let Some(word) = shell.preview_word {
+        fill_rect(
+            frame,
+            20,
+            SETTINGS_PREVIEW_TOP_Y,
+            320,
+            SETTINGS_PREVIEW_BOTTOM_Y - SETTINGS_PREVIEW_TOP_Y + 1,
+            BinaryColor::Off,
+        );
+        for y in [SETTINGS_PREVIEW_TOP_Y, SETTINGS_PREVIEW_BOTTOM_Y] {
+            fill_rect(frame, 20, y, 320, 1, BinaryColor::On);
+        }
+        draw_text_scaled(
+            frame,
+            word,
+            Point::new(180, SETTINGS_PREVIEW_TOP_Y + 16),
+            ui_font_title(),
+            BinaryColor::On,
+            Alignment::Center,
+            ONBOARDING_DEMO_SCALE,
+        );
+    }
+
     if let (Some(title), Some(body)) = (shell.refresh_title, shell.refresh_body) {
         stroke_rect(frame, 58, 62, 268, 106, BinaryColor::On);
         draw_text(
@@ -2262,28 +4005,6 @@ fn draw_topic_preferences(frame: &mut FrameBuffer, grid: &TopicPreferenceGrid) {
     }
 }
 
-fn draw_status_cluster(frame: &mut FrameBuffer, battery_percent: u8, wifi_online: bool) {
-    draw_wifi_icon(frame, 298, 12, wifi_online);
-    stroke_rect(frame, 319, 14, 18, 10, BinaryColor::On);
-    fill_rect(
-        frame,
-        321,
-        16,
-        ((battery_percent.min(100) as u32 * 11) / 100) as i32,
-        6,
-        BinaryColor::On,
-    );
-    fill_rect(frame, 337, 17, 2, 4, BinaryColor::On);
-    draw_text(
-        frame,
-        battery_label(battery_percent),
-        Point::new(353, 13),
-        ui_font_small(),
-        BinaryColor::On,
-        Alignment::Left,
-    );
-}
-
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 struct SlotTransitionOffsets {
     incoming: i32,
@@ -2763,17 +4484,6 @@ fn invert_frame(frame: &mut FrameBuffer) {
     frame.invert();
 }
 
-fn draw_wifi_icon(frame: &mut FrameBuffer, x: i32, y: i32, online: bool) {
-    if online {
-        fill_rect(frame, x + 7, y + 10, 2, 2, BinaryColor::On);
-        fill_rect(frame, x + 5, y + 7, 6, 2, BinaryColor::On);
-        fill_rect(frame, x + 3, y + 4, 10, 2, BinaryColor::On);
-    } else {
-        fill_rect(frame, x + 2, y + 2, 12, 2, BinaryColor::On);
-        fill_rect(frame, x + 6, y + 4, 2, 10, BinaryColor::On);
-    }
-}
-
 fn draw_back_chevron(frame: &mut FrameBuffer, x: i32, y: i32) {
     let mut offset = 0;
     while offset < 6 {
@@ -2947,9 +4657,7 @@ fn draw_text(
         .baseline(Baseline::Top)
         .build();
 
-    Text::with_text_style(normalized.as_str(), position, style, text_style)
-        .draw(frame)
-        .ok();
+    draw_text_run(frame, normalized.as_str(), position, style, text_style);
 }
 
 fn draw_text_clipped(
@@ -2977,9 +4685,13 @@ fn draw_text_clipped(
         .build();
     let mut clipped_frame = ClippedFrameBuffer::new(frame, clip);
 
-    Text::with_text_style(normalized.as_str(), position, style, text_style)
-        .draw(&mut clipped_frame)
-        .ok();
+    draw_text_run(
+        &mut clipped_frame,
+        normalized.as_str(),
+        position,
+        style,
+        text_style,
+    );
 }
 
 fn draw_text_ellipsized(
@@ -3013,9 +4725,13 @@ fn draw_text_ellipsized_clipped(
         .build();
     let mut clipped_frame = ClippedFrameBuffer::new(frame, clip);
 
-    Text::with_text_style(clipped.as_str(), spec.position, style, text_style)
-        .draw(&mut clipped_frame)
-        .ok();
+    draw_text_run(
+        &mut clipped_frame,
+        clipped.as_str(),
+        spec.position,
+        style,
+        text_style,
+    );
 }
 
 fn draw_text_scaled(
@@ -3039,37 +4755,106 @@ fn draw_text_scaled(
     let logical_position = logical_text_position(position, scale);
     let mut scaled_frame = ScaledFrameBuffer::new(frame, scale);
 
-    Text::with_text_style(normalized.as_str(), logical_position, style, text_style)
-        .draw(&mut scaled_frame)
-        .ok();
+    draw_text_run(
+        &mut scaled_frame,
+        normalized.as_str(),
+        logical_position,
+        style,
+        text_style,
+    );
 }
 
-fn draw_text_right(
-    frame: &mut FrameBuffer,
+// Draws a run of already normalized text. Characters the built-in fonts lack are left as gaps and
+// then stamped from the installed font packs, one text cell each.
+fn draw_text_run<D>(
+    target: &mut D,
     text: &str,
     position: Point,
-    font: &embedded_graphics::mono_font::MonoFont<'static>,
+    style: MonoTextStyle<'_, BinaryColor>,
+    text_style: TextStyle,
+) where
+    D: DrawTarget<Color = BinaryColor>,
+{
+    let provider = fonts::installed().filter(|_| text.chars().any(fonts::needs_provider));
+    let (Some(provider), Some(color)) = (provider, style.text_color) else {
+        Text::with_text_style(text, position, style, text_style)
+            .draw(target)
+            .ok();
+        return;
+    };
+
+    let cell = style.font.character_size;
+    let provided = |ch: char| {
+        fonts::needs_provider(ch)
+            .then(|| provider.glyph(ch, cell))
+            .flatten()
+    };
+    let mut blanked = HeaplessString::<NORMALIZED_TEXT_MAX_BYTES>::new();
+    for ch in text.chars() {
+        let _ = blanked.push(if provided(ch).is_some() { ' ' } else { ch });
+    }
+    Text::with_text_style(blanked.as_str(), position, style, text_style)
+        .draw(target)
+        .ok();
+
+    let cell_width = cell.width as i32;
+    let run_width = text.chars().count() as i32 * cell_width;
+    let start_x = match text_style.alignment {
+        Alignment::Left => position.x,
+        Alignment::Center => position.x - (run_width - 1) / 2,
+        Alignment::Right => position.x - (run_width - 1),
+    };
+    for (index, ch) in text.chars().enumerate() {
+        let Some(glyph) = provided(ch) else {
+            continue;
+        };
+        let cell_origin = Point::new(start_x + index as i32 * cell_width, position.y);
+        stamp_glyph(target, glyph, cell_origin, cell, color);
+    }
+}
+
+fn stamp_glyph<D>(
+    target: &mut D,
+    glyph: fonts::Glyph<'_>,
+    cell_origin: Point,
+    cell: Size,
     color: BinaryColor,
-) {
-    draw_text(frame, text, position, font, color, Alignment::Right);
+) where
+    D: DrawTarget<Color = BinaryColor>,
+{
+    let (glyph_width, glyph_height) = (u32::from(glyph.width), u32::from(glyph.height));
+    let offset_x = (cell.width as i32 - glyph_width as i32) / 2;
+    let offset_y = (cell.height as i32 - glyph_height as i32) / 2;
+    let pixels = (0..glyph_height)
+        .flat_map(|y| (0..glyph_width).map(move |x| (x, y)))
+        .filter(|&(x, y)| glyph.is_ink(x, y))
+        .map(|(x, y)| Point::new(x as i32 + offset_x, y as i32 + offset_y))
+        .filter(|point| {
+            (0..cell.width as i32).contains(&point.x) && (0..cell.height as i32).contains(&point.y)
+        })
+        .map(|point| Pixel(cell_origin + point, color));
+    target.draw_iter(pixels).ok();
 }
 
-fn draw_text_right_scaled(
+fn draw_text_right(
     frame: &mut FrameBuffer,
     text: &str,
     position: Point,
     font: &embedded_graphics::mono_font::MonoFont<'static>,
     color: BinaryColor,
-    scale: u32,
 ) {
-    draw_text_scaled(frame, text, position, font, color, Alignment::Right, scale);
+    draw_text(frame, text, position, font, color, Alignment::Right);
 }
 
 fn normalized_text(text: &str) -> HeaplessString<NORMALIZED_TEXT_MAX_BYTES> {
     let mut normalized = HeaplessString::new();
 
     for ch in text.chars() {
-        if normalized.push(normalize_display_char(ch)).is_err() {
+        let pushed = match glyph_fold::fold_ligature(ch) {
+            Some(letters) => normalized.push_str(letters),
+            None => normalized.push(normalize_display_char(ch)),
+        };
+        if pushed.is_err() {
             break;
         }
     }
@@ -3125,7 +4910,7 @@ fn normalize_display_char(ch: char) -> char {
         '“' | '”' => '"',
         '–' | '—' => '-',
         '\u{00A0}' => ' ',
-        _ => ch,
+        _ => glyph_fold::fold_char(ch),
     }
 }
 
@@ -3250,22 +5035,6 @@ impl OriginDimensions for ClippedFrameBuffer<'_> {
     }
 }
 
-fn battery_label(percent: u8) -> &'static str {
-    match percent {
-        0..=9 => "09%",
-        10..=19 => "18%",
-        20..=29 => "24%",
-        30..=39 => "32%",
-        40..=49 => "48%",
-        50..=59 => "56%",
-        60..=69 => "64%",
-        70..=79 => "74%",
-        80..=89 => "82%",
-        90..=99 => "96%",
-        _ => "100%",
-    }
-}
-
 fn wpm_label(wpm: u16) -> HeaplessString<8> {
     let mut label = HeaplessString::new();
     let _ = write!(label, "{} WPM", wpm);
@@ -3303,7 +5072,7 @@ fn paired_slide_offsets(
 }
 
 fn slide_offset(direction: MotionDirection, step: u8, total_steps: u8, amplitude: i32) -> i32 {
-    let progress = ease_out_quad_permille(step, total_steps) as i32;
+    let progress = Easing::EaseOut.progress_permille(step, total_steps);
     let remaining = 1000 - progress;
     let offset = (amplitude * remaining) / 1000;
 
@@ -3313,22 +5082,6 @@ fn slide_offset(direction: MotionDirection, step: u8, total_steps: u8, amplitude
     }
 }
 
-const fn ease_out_quad_permille(step: u8, total_steps: u8) -> u16 {
-    if total_steps == 0 {
-        return 1000;
-    }
-
-    let clamped_step = if step > total_steps {
-        total_steps
-    } else {
-        step
-    } as u32;
-    let total = total_steps as u32;
-    let t = (clamped_step * 1000) / total;
-    let inv = 1000 - t;
-    (1000 - ((inv * inv) / 1000)) as u16
-}
-
 const fn lerp_u32(start: u32, end: u32, step: u8, total_steps: u8) -> u32 {
     if total_steps == 0 {
         return end;
@@ -3387,12 +5140,12 @@ fn set_pixel_color(frame: &mut FrameBuffer, x: i32, y: i32, color: BinaryColor)
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::display::diff_dirty_rows;
     use app_runtime::components::{
         ContentListShell, ContentRow, DashboardItem, HelpHint, ParagraphMapRail, PauseModalRow,
-        SelectionBand, StatusCluster, SyncIndicator, VerticalRail,
+        SelectionBand, StatusBar, SyncIndicator, VerticalRail,
     };
     use domain::text::InlineText;
+    use ls027b7dh01::diff_dirty_rows;
 
     fn make_reader_shell(progress_width: u16) -> ReaderShell {
         make_reader_shell_with_modal(progress_width, None)
@@ -3420,10 +5173,16 @@ mod tests {
                 right_word: InlineText::from_slice("RIGHT"),
                 preview: InlineText::from_slice("preview"),
                 font: StageFont::Large,
+                wrap_index: 0,
+                highlight_anchor: false,
+                pivot_style: domain::formatter::FontStyle::Regular,
+                bold_lead_chars: 0,
+                rtl_word: false,
+                rtl_paragraph: false,
                 progress_width,
             },
             badge: None,
-            pause_modal,
+            modal: pause_modal.map(ReaderModal::Pause),
         }
     }
 
@@ -3434,34 +5193,26 @@ mod tests {
     }
 
     fn make_pause_modal() -> PauseModal {
+        let labels = [
+            "RESUME RSVP",
+            "PARAGRAPH VIEW",
+            "PAGE VIEW",
+            "SCRUB WORDS",
+            "ARTICLE",
+            "SOURCE",
+            "DEFINE WORD",
+        ];
         PauseModal {
             title: "PAUSED",
-            rows: [
-                PauseModalRow {
-                    label: "RESUME RSVP",
-                    action: "",
-                    selected: true,
-                    enabled: true,
-                },
-                PauseModalRow {
-                    label: "PARAGRAPH VIEW",
-                    action: "",
-                    selected: false,
-                    enabled: true,
-                },
-                PauseModalRow {
-                    label: "ARTICLE",
-                    action: "SAVE",
-                    selected: false,
-                    enabled: true,
-                },
-                PauseModalRow {
-                    label: "SOURCE",
-                    action: "SUBSCRIBE",
-                    selected: false,
-                    enabled: true,
-                },
-            ],
+            rows: core::array::from_fn(|index| PauseModalRow {
+                label: labels[index],
+                action: "",
+                selected: index == 0,
+                enabled: true,
+            }),
+            session_label: InlineText::from_slice("READ 4 MIN"),
+            remaining_label: InlineText::from_slice("~12 MIN LEFT"),
+            goal_permille: Some(400),
         }
     }
 
@@ -3513,9 +5264,12 @@ mod tests {
     ) -> DashboardShell {
         DashboardShell {
             appearance: AppearanceMode::Light,
-            status: StatusCluster {
+            status: StatusBar {
                 battery_percent: 64,
+                charging: false,
                 wifi_online: true,
+                sd_card_ready: true,
+                clock: None,
             },
             sync_indicator: Some(SyncIndicator {
                 label: "SYNC",
@@ -3548,9 +5302,12 @@ mod tests {
     fn make_collection_shell(rows: [(&str, &str); 3]) -> ContentListShell {
         ContentListShell {
             appearance: AppearanceMode::Light,
-            status: StatusCluster {
+            status: StatusBar {
                 battery_percent: 64,
+                charging: false,
                 wifi_online: true,
+                sd_card_ready: true,
+                clock: None,
             },
             rail: VerticalRail {
                 text: "S\nA\nV\nE\nD",
@@ -3562,6 +5319,8 @@ mod tests {
                     meta: InlineText::from_slice(rows[0].0),
                     title: InlineText::from_slice(rows[0].1),
                     progress_badge: None,
+                    progress_percent: 0,
+                    finished: false,
                     is_fetching: false,
                     selected: false,
                 },
@@ -3569,6 +5328,8 @@ mod tests {
                     meta: InlineText::from_slice(rows[1].0),
                     title: InlineText::from_slice(rows[1].1),
                     progress_badge: None,
+                    progress_percent: 0,
+                    finished: false,
                     is_fetching: false,
                     selected: true,
                 },
@@ -3576,12 +5337,16 @@ mod tests {
                     meta: InlineText::from_slice(rows[2].0),
                     title: InlineText::from_slice(rows[2].1),
                     progress_badge: None,
+                    progress_percent: 0,
+                    finished: false,
                     is_fetching: false,
                     selected: false,
                 },
             ],
+            grid: None,
             band: SelectionBand { y: 106, height: 68 },
             help: HelpHint { text: "BACK" },
+            jump_letter: None,
         }
     }
 
@@ -3604,6 +5369,43 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "rtl")]
+    #[test]
+    fn right_to_left_reader_fills_progress_from_the_right() {
+        let mut frame = FrameBuffer::new();
+        let mut shell = make_reader_shell(80);
+        shell.stage.left_word = InlineText::from_slice("ש");
+        shell.stage.right_word = InlineText::from_slice("לום");
+        shell.stage.rtl_word = true;
+        shell.stage.rtl_paragraph = true;
+        draw_prepared_screen(&mut frame, &PreparedScreen::Reader(shell));
+
+        assert_eq!(frame.pixel(399, 236), Some(true));
+        assert_eq!(frame.pixel(0, 236), Some(false));
+    }
+
+    #[test]
+    fn reader_word_change_only_dirties_the_stage_band() {
+        let mut committed = FrameBuffer::new();
+        let mut working = FrameBuffer::new();
+        let mut next = make_reader_shell(80);
+        next.stage.left_word = InlineText::from_slice("wo");
+        next.stage.right_word = InlineText::from_slice("rds");
+
+        draw_prepared_screen(
+            &mut committed,
+            &PreparedScreen::Reader(make_reader_shell(80)),
+        );
+        draw_prepared_screen(&mut working, &PreparedScreen::Reader(next));
+
+        let dirty = diff_dirty_rows(&committed, &working);
+
+        assert!(!dirty.is_empty());
+        for row in dirty.iter() {
+            assert!((84..160).contains(&row), "unexpected dirty row {row}");
+        }
+    }
+
     #[test]
     fn startup_splash_draws_center_wordmark() {
         let mut frame = FrameBuffer::new();
@@ -3630,6 +5432,7 @@ mod tests {
             screen: Screen::Saved,
             plan: TransitionPlan::new(AnimationDescriptor::ReaderExit, 3, 50),
             step: 1,
+            toasts: ToastStack::empty(),
         };
         let mut transition = FrameBuffer::new();
         let mut expected = FrameBuffer::new();
@@ -3640,6 +5443,52 @@ mod tests {
         assert_eq!(transition.bytes(), expected.bytes());
     }
 
+    #[test]
+    fn reader_enter_layers_slide_the_title_and_fade_the_stage() {
+        let reader = make_reader_shell(64);
+        let plan = TransitionPlan::new(AnimationDescriptor::ReaderEnter, 3, 50)
+            .with_layer(LayerAnimation::new(
+                AnimationLayer::Header,
+                LayerMotion::SlideDown,
+                Easing::Linear,
+            ))
+            .with_layer(LayerAnimation::new(
+                AnimationLayer::Stage,
+                LayerMotion::Fade,
+                Easing::Linear,
+            ));
+        let ink = |frame: &FrameBuffer, rows: core::ops::Range<usize>| -> u32 {
+            rows.flat_map(|row| frame.row(row).unwrap().iter())
+                .map(|byte| byte.count_ones())
+                .sum()
+        };
+        let frame_at = |step: u8| {
+            let mut frame = FrameBuffer::new();
+            let animation = AnimationPlayback {
+                from: PreparedScreen::Collection(make_collection_shell([("", ""); 3])),
+                to: PreparedScreen::Reader(reader),
+                screen: Screen::Reader,
+                plan,
+                step,
+                toasts: ToastStack::empty(),
+            };
+            draw_transition_frame(&mut frame, &animation);
+            frame
+        };
+        let mut settled = FrameBuffer::new();
+        draw_prepared_screen(&mut settled, &PreparedScreen::Reader(reader));
+
+        let midway = frame_at(2);
+        let stage = LAYER_STAGE_TOP_Y as usize..LAYER_STAGE_BOTTOM_Y as usize;
+        assert!(ink(&midway, stage.clone()) > 0);
+        assert!(ink(&midway, stage) < ink(&settled, 84..160));
+        // The title still lags a third of the header's height, 13 rows, behind its place.
+        assert!(ink(&settled, 0..31) > 0);
+        assert_eq!(ink(&midway, 0..31), 0);
+        assert!(ink(&midway, 31..40) > 0);
+        assert_eq!(frame_at(3).bytes(), settled.bytes());
+    }
+
     #[test]
     fn reader_footer_reserves_gap_for_wpm_label() {
         let wpm = wpm_label(300);
@@ -3653,6 +5502,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn latin_extended_a_folds_onto_latin_1_glyphs() {
+        assert_eq!(
+            normalized_text("Łódź, œuvre, Dvořák, Erdős, café").as_str(),
+            "Lódz, oeuvre, Dvorák, Erdös, café"
+        );
+    }
+
+    #[test]
+    fn installed_font_packs_fill_in_glyphs_the_built_in_fonts_lack() {
+        // A 6x10 pack whose only glyph inks the top-left pixel of its cell.
+        let mut bytes = Vec::from(fonts::FONT_PACK_MAGIC);
+        bytes.extend_from_slice(&[fonts::FONT_PACK_VERSION, 6, 10, 0]);
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&u32::from('中').to_le_bytes());
+        bytes.extend_from_slice(&[0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        let mut library = fonts::FontLibrary::new();
+        assert!(library.try_push(fonts::FontPack::parse(bytes.leak()).unwrap()));
+        fonts::install(Box::leak(Box::new(library)));
+
+        let mut blank = FrameBuffer::new();
+        let mut drawn = FrameBuffer::new();
+        for (frame, text) in [(&mut blank, "a  b"), (&mut drawn, "a中 b")] {
+            draw_text(
+                frame,
+                text,
+                Point::new(60, 20),
+                &FONT_6X10,
+                BinaryColor::On,
+                Alignment::Right,
+            );
+        }
+        let differing: Vec<_> = (0..400)
+            .flat_map(|x| (0..240).map(move |y| (x, y)))
+            .filter(|&(x, y)| blank.pixel(x, y) != drawn.pixel(x, y))
+            .collect();
+        assert_eq!(differing, [(60 - 23 + 6, 20)]);
+    }
+
     #[test]
     fn wpm_label_formats_intermediate_ramp_values() {
         assert_eq!(wpm_label(288).as_str(), "288 WPM");
@@ -3669,6 +5557,7 @@ mod tests {
             screen: Screen::Reader,
             plan: TransitionPlan::new(AnimationDescriptor::ModalReveal, 3, 55),
             step: 1,
+            toasts: ToastStack::empty(),
         };
         let step_2 = step_1.advance();
         let step_3 = step_2.advance();
@@ -3705,6 +5594,7 @@ mod tests {
             screen: Screen::Reader,
             plan: TransitionPlan::new(AnimationDescriptor::ModalHide, 3, 55),
             step: 1,
+            toasts: ToastStack::empty(),
         };
         let step_2 = step_1.advance();
         let step_3 = step_2.advance();
@@ -3777,6 +5667,7 @@ mod tests {
                 50,
             ),
             step: 1,
+            toasts: ToastStack::empty(),
         };
         let step_2 = step_1.advance();
         let step_3 = step_2.advance();
@@ -3816,6 +5707,7 @@ mod tests {
                 42,
             ),
             step: 2,
+            toasts: ToastStack::empty(),
         };
         let mut frame = FrameBuffer::new();
 
@@ -3840,6 +5732,7 @@ mod tests {
                 55,
             ),
             step: 1,
+            toasts: ToastStack::empty(),
         };
         let mut committed = FrameBuffer::new();
         let mut frame_1 = FrameBuffer::new();
@@ -3869,6 +5762,7 @@ mod tests {
                 46,
             ),
             step: 2,
+            toasts: ToastStack::empty(),
         };
         let mut frame = FrameBuffer::new();
 
@@ -3901,6 +5795,7 @@ mod tests {
                 55,
             ),
             step: 1,
+            toasts: ToastStack::empty(),
         };
         let step_2 = step_1.advance();
         let mut frame_1 = FrameBuffer::new();
@@ -3931,6 +5826,7 @@ mod tests {
                 55,
             ),
             step: 1,
+            toasts: ToastStack::empty(),
         };
         let step_2 = step_1.advance();
         let step_3 = step_2.advance();
@@ -3980,6 +5876,7 @@ mod tests {
                 55,
             ),
             step: 1,
+            toasts: ToastStack::empty(),
         };
         let step_2 = step_1.advance();
         let step_3 = step_2.advance();
@@ -4004,4 +5901,6 @@ mod tests {
             }
         }
     }
+
+    mod snapshots;
 }
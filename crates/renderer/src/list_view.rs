@@ -0,0 +1,188 @@
+use embedded_graphics::{pixelcolor::BinaryColor, prelude::Point, text::Alignment};
+use ls027b7dh01::FrameBuffer;
+
+use super::{
+    draw_pill, draw_text, draw_text_ellipsized, fill_rect, stroke_rect, ui_font_body, ui_font_small,
+};
+
+const BODY_FONT_HEIGHT: i32 = 13;
+const SMALL_FONT_HEIGHT: i32 = 10;
+const SMALL_FONT_WIDTH: i32 = 6;
+const ICON_SIZE: i32 = 8;
+const VALUE_GAP: i32 = 8;
+const SCROLLBAR_WIDTH: i32 = 3;
+const SCROLLBAR_GAP: i32 = 6;
+const SCROLLBAR_MIN_THUMB: i32 = 6;
+
+// A column of rows with a cursor pill, optional icon and detail columns, a right-aligned value,
+// and a scrollbar once the list holds more items than rows. Screens keep their own scrolling
+// state and pass only the rows in view, so the widget never needs the whole list.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct ListView {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub row_pitch: i32,
+    pub row_height: i32,
+    pub inset: i32,
+    pub icon_column: i32,
+    pub detail_column: i32,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub(crate) enum ListIcon {
+    #[default]
+    None,
+    Filled,
+    Outline,
+    Arrow,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub(crate) struct ListItem<'a> {
+    pub icon: ListIcon,
+    pub detail: &'a str,
+    pub label: &'a str,
+    pub value: &'a str,
+    pub selected: bool,
+}
+
+// Where the rows in view sit in the full list; `None` hides the scrollbar.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct ListScroll {
+    pub first: usize,
+    pub total: usize,
+}
+
+impl ListView {
+    pub(crate) fn draw(
+        &self,
+        frame: &mut FrameBuffer,
+        items: &[ListItem<'_>],
+        scroll: Option<ListScroll>,
+    ) {
+        let scroll = scroll.filter(|scroll| scroll.total > items.len());
+        let content_width = if scroll.is_some() {
+            self.width - SCROLLBAR_WIDTH - SCROLLBAR_GAP
+        } else {
+            self.width
+        };
+
+        for (slot, item) in items.iter().enumerate() {
+            self.draw_row(frame, self.row_y(slot), content_width, item);
+        }
+        if let Some(scroll) = scroll {
+            self.draw_scrollbar(frame, items.len(), scroll);
+        }
+    }
+
+    fn row_y(&self, slot: usize) -> i32 {
+        self.y + slot as i32 * self.row_pitch
+    }
+
+    fn draw_row(&self, frame: &mut FrameBuffer, y: i32, content_width: i32, item: &ListItem<'_>) {
+        let color = if item.selected {
+            draw_pill(frame, self.x, y, content_width, self.row_height, true);
+            BinaryColor::Off
+        } else {
+            BinaryColor::On
+        };
+
+        let icon_x = self.x + self.inset;
+        draw_icon(
+            frame,
+            icon_x,
+            y + (self.row_height - ICON_SIZE) / 2,
+            item.icon,
+            color,
+        );
+        if !item.detail.is_empty() {
+            draw_text(
+                frame,
+                item.detail,
+                Point::new(
+                    icon_x + self.icon_column,
+                    y + (self.row_height - SMALL_FONT_HEIGHT) / 2,
+                ),
+                ui_font_small(),
+                color,
+                Alignment::Left,
+            );
+        }
+
+        let right_x = self.x + content_width - self.inset;
+        let mut label_right_x = right_x;
+        if !item.value.is_empty() {
+            draw_text(
+                frame,
+                item.value,
+                Point::new(right_x, y + (self.row_height - SMALL_FONT_HEIGHT) / 2),
+                ui_font_small(),
+                color,
+                Alignment::Right,
+            );
+            label_right_x -= item.value.len() as i32 * SMALL_FONT_WIDTH + VALUE_GAP;
+        }
+
+        let label_x = icon_x + self.icon_column + self.detail_column;
+        draw_text_ellipsized(
+            frame,
+            item.label,
+            Point::new(label_x, y + (self.row_height - BODY_FONT_HEIGHT) / 2),
+            ui_font_body(),
+            color,
+            Alignment::Left,
+            label_right_x - label_x,
+        );
+    }
+
+    fn draw_scrollbar(&self, frame: &mut FrameBuffer, rows: usize, scroll: ListScroll) {
+        let x = self.x + self.width - SCROLLBAR_WIDTH;
+        let track_height = self.row_y(rows.max(1) - 1) + self.row_height - self.y;
+        fill_rect(
+            frame,
+            x + SCROLLBAR_WIDTH / 2,
+            self.y,
+            1,
+            track_height,
+            BinaryColor::On,
+        );
+
+        let thumb_height =
+            (track_height * rows as i32 / scroll.total as i32).max(SCROLLBAR_MIN_THUMB);
+        let last_first = scroll.total.saturating_sub(rows).max(1);
+        let thumb_y = self.y
+            + (track_height - thumb_height) * scroll.first.min(last_first) as i32
+                / last_first as i32;
+        fill_rect(
+            frame,
+            x,
+            thumb_y,
+            SCROLLBAR_WIDTH,
+            thumb_height,
+            BinaryColor::On,
+        );
+    }
+}
+
+fn draw_icon(frame: &mut FrameBuffer, x: i32, y: i32, icon: ListIcon, color: BinaryColor) {
+    match icon {
+        ListIcon::None => {}
+        ListIcon::Filled => fill_rect(frame, x, y, ICON_SIZE, ICON_SIZE, color),
+        ListIcon::Outline => stroke_rect(frame, x, y, ICON_SIZE, ICON_SIZE, color),
+        ListIcon::Arrow => {
+            let mut offset = 0;
+            while offset < ICON_SIZE / 2 + 1 {
+                fill_rect(
+                    frame,
+                    x + offset,
+                    y - 1 + offset,
+                    1,
+                    ICON_SIZE + 2 - offset * 2,
+                    color,
+                );
+                offset += 1;
+            }
+        }
+    }
+}
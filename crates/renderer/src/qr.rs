@@ -0,0 +1,491 @@
+use embedded_graphics::{pixelcolor::BinaryColor, prelude::Point};
+use ls027b7dh01::FrameBuffer;
+
+use crate::fill_rect;
+
+// A byte-mode QR encoder for short links meant for a phone (provisioning, the web dashboard,
+// release notes). No screen draws one yet; the widget waits for the screens that show those links.
+// It covers versions 1 to 6 at error correction level L, which holds up to 134 bytes without the
+// version information blocks larger symbols need.
+
+pub const QR_MAX_VERSION: u8 = 6;
+pub const QR_MAX_BYTES: usize = 134;
+pub const QR_QUIET_ZONE_MODULES: i32 = 4;
+const QR_MAX_SIZE: usize = 17 + 4 * QR_MAX_VERSION as usize;
+const QR_MAX_CODEWORDS: usize = 172;
+const QR_MAX_ECC_CODEWORDS: usize = 26;
+
+// Level L error correction per version: total codewords, ECC codewords per block, block count.
+// Every block of these versions holds the same number of data codewords.
+const QR_VERSIONS: [(usize, usize, usize); QR_MAX_VERSION as usize] = [
+    (26, 7, 1),
+    (44, 10, 1),
+    (70, 15, 1),
+    (100, 20, 1),
+    (134, 26, 1),
+    (172, 18, 2),
+];
+
+const MODE_BYTE: u32 = 0b0100;
+const FORMAT_LEVEL_L: u32 = 0b01;
+const PENALTY_RUN: u32 = 3;
+const PENALTY_BLOCK: u32 = 3;
+const PENALTY_FINDER_LIKE: u32 = 40;
+const PENALTY_BALANCE: u32 = 10;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct QrCode {
+    version: u8,
+    // One bit per module, column `x` at bit `x`; set is dark.
+    modules: [u64; QR_MAX_SIZE],
+    function: [u64; QR_MAX_SIZE],
+}
+
+impl QrCode {
+    // The smallest symbol holding `data`, or `None` when it is longer than `QR_MAX_BYTES`.
+    pub fn encode(data: &[u8]) -> Option<Self> {
+        let version = (1..=QR_MAX_VERSION).find(|version| {
+            let (total, ecc, blocks) = QR_VERSIONS[usize::from(*version) - 1];
+            // Mode indicator and an 8-bit length ahead of the bytes.
+            12 + data.len() * 8 <= (total - ecc * blocks) * 8
+        })?;
+
+        let mut code = Self {
+            version,
+            modules: [0; QR_MAX_SIZE],
+            function: [0; QR_MAX_SIZE],
+        };
+        let mut codewords = [0u8; QR_MAX_CODEWORDS];
+        let codeword_count = code.codewords(data, &mut codewords);
+        code.draw_function_patterns();
+        code.draw_codewords(&codewords[..codeword_count]);
+
+        let mut best = (u32::MAX, 0u8);
+        for mask in 0..8 {
+            code.apply_mask(mask);
+            code.draw_format_bits(mask);
+            let penalty = code.penalty();
+            if penalty < best.0 {
+                best = (penalty, mask);
+            }
+            code.apply_mask(mask);
+        }
+        code.apply_mask(best.1);
+        code.draw_format_bits(best.1);
+        Some(code)
+    }
+
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    // Modules per side, without the quiet zone.
+    pub const fn size(&self) -> usize {
+        17 + 4 * self.version as usize
+    }
+
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        x < self.size() && y < self.size() && self.modules[y] & (1 << x) != 0
+    }
+
+    // Width of the drawn symbol in pixels, quiet zone included.
+    pub fn drawn_size(&self, module_px: i32) -> i32 {
+        (self.size() as i32 + QR_QUIET_ZONE_MODULES * 2) * module_px
+    }
+
+    fn set(&mut self, x: usize, y: usize, dark: bool) {
+        if dark {
+            self.modules[y] |= 1 << x;
+        } else {
+            self.modules[y] &= !(1 << x);
+        }
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        self.set(x, y, dark);
+        self.function[y] |= 1 << x;
+    }
+
+    fn is_function(&self, x: usize, y: usize) -> bool {
+        self.function[y] & (1 << x) != 0
+    }
+
+    // Data plus error correction, interleaved across blocks. Returns the codeword count.
+    fn codewords(&self, data: &[u8], out: &mut [u8; QR_MAX_CODEWORDS]) -> usize {
+        let (total, ecc_len, blocks) = QR_VERSIONS[usize::from(self.version) - 1];
+        let data_len = total - ecc_len * blocks;
+
+        let mut bits = BitWriter::new(&mut out[..data_len]);
+        bits.push(MODE_BYTE, 4);
+        bits.push(data.len() as u32, 8);
+        for byte in data {
+            bits.push(u32::from(*byte), 8);
+        }
+        // Terminator, then byte alignment, then the alternating pad codewords.
+        let capacity = data_len * 8;
+        bits.push(0, (capacity - bits.len).min(4));
+        bits.push(0, (8 - bits.len % 8) % 8);
+        let mut pad = 0xEC;
+        while bits.len < capacity {
+            bits.push(pad, 8);
+            pad ^= 0xEC ^ 0x11;
+        }
+
+        let mut data_codewords = [0u8; QR_MAX_CODEWORDS];
+        data_codewords[..data_len].copy_from_slice(&out[..data_len]);
+        let block_len = data_len / blocks;
+        let divisor = reed_solomon_divisor(ecc_len);
+        let mut written = 0;
+        for index in 0..block_len {
+            for block in 0..blocks {
+                out[written] = data_codewords[block * block_len + index];
+                written += 1;
+            }
+        }
+        let mut eccs = [[0u8; QR_MAX_ECC_CODEWORDS]; 2];
+        for (block, ecc) in eccs.iter_mut().enumerate().take(blocks) {
+            let start = block * block_len;
+            reed_solomon_remainder(
+                &data_codewords[start..start + block_len],
+                &divisor[..ecc_len],
+                &mut ecc[..ecc_len],
+            );
+        }
+        for index in 0..ecc_len {
+            for ecc in eccs.iter().take(blocks) {
+                out[written] = ecc[index];
+                written += 1;
+            }
+        }
+        written
+    }
+
+    fn draw_function_patterns(&mut self) {
+        let size = self.size();
+        for index in 0..size {
+            self.set_function(6, index, index % 2 == 0);
+            self.set_function(index, 6, index % 2 == 0);
+        }
+
+        self.draw_finder(3, 3);
+        self.draw_finder(size - 4, 3);
+        self.draw_finder(3, size - 4);
+        if self.version >= 2 {
+            self.draw_alignment(size - 7, size - 7);
+        }
+        // Reserve the format areas; the real bits go in once the mask is chosen.
+        self.draw_format_bits(0);
+    }
+
+    // The 7x7 target and its light separator, clipped at the symbol edge.
+    fn draw_finder(&mut self, center_x: usize, center_y: usize) {
+        for dy in -4i32..=4 {
+            for dx in -4i32..=4 {
+                let (x, y) = (center_x as i32 + dx, center_y as i32 + dy);
+                if x < 0 || y < 0 || x >= self.size() as i32 || y >= self.size() as i32 {
+                    continue;
+                }
+                let distance = dx.abs().max(dy.abs());
+                self.set_function(x as usize, y as usize, distance != 2 && distance != 4);
+            }
+        }
+    }
+
+    fn draw_alignment(&mut self, center_x: usize, center_y: usize) {
+        for dy in -2i32..=2 {
+            for dx in -2i32..=2 {
+                let (x, y) = (center_x as i32 + dx, center_y as i32 + dy);
+                self.set_function(x as usize, y as usize, dx.abs().max(dy.abs()) != 1);
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, mask: u8) {
+        let bits = format_bits(mask);
+        let bit = |index: u32| (bits >> index) & 1 != 0;
+        let size = self.size();
+
+        for index in 0..=5 {
+            self.set_function(8, index, bit(index as u32));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for index in 9..15 {
+            self.set_function(14 - index, 8, bit(index as u32));
+        }
+
+        for index in 0..8 {
+            self.set_function(size - 1 - index, 8, bit(index as u32));
+        }
+        for index in 8..15 {
+            self.set_function(8, size - 15 + index, bit(index as u32));
+        }
+        // The module that is always dark.
+        self.set_function(8, size - 8, true);
+    }
+
+    // Codeword bits, most significant first, in the two-column zigzag from the bottom right.
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let size = self.size();
+        let bit_count = codewords.len() * 8;
+        let mut index = 0;
+        let mut right = size - 1;
+        loop {
+            if right == 6 {
+                right = 5;
+            }
+            let upward = (right + 1) & 2 == 0;
+            for vertical in 0..size {
+                let y = if upward {
+                    size - 1 - vertical
+                } else {
+                    vertical
+                };
+                for x in [right, right - 1] {
+                    if !self.is_function(x, y) && index < bit_count {
+                        let byte = codewords[index / 8];
+                        self.set(x, y, (byte >> (7 - index % 8)) & 1 != 0);
+                        index += 1;
+                    }
+                }
+            }
+            if right < 2 {
+                break;
+            }
+            right -= 2;
+        }
+    }
+
+    // XORs the mask over the data modules, so calling it twice undoes it.
+    fn apply_mask(&mut self, mask: u8) {
+        let size = self.size();
+        for y in 0..size {
+            for x in 0..size {
+                if !self.is_function(x, y) && mask_flips(mask, x, y) {
+                    self.modules[y] ^= 1 << x;
+                }
+            }
+        }
+    }
+
+    fn penalty(&self) -> u32 {
+        let size = self.size();
+        let mut penalty = 0;
+
+        for (horizontal, line) in [true, false]
+            .into_iter()
+            .flat_map(|horizontal| (0..size).map(move |line| (horizontal, line)))
+        {
+            let module = |along: usize| {
+                if horizontal {
+                    self.is_dark(along, line)
+                } else {
+                    self.is_dark(line, along)
+                }
+            };
+
+            let mut run = 1;
+            for along in 1..=size {
+                if along < size && module(along) == module(along - 1) {
+                    run += 1;
+                    continue;
+                }
+                if run >= 5 {
+                    penalty += PENALTY_RUN + (run - 5);
+                }
+                run = 1;
+            }
+
+            // Dark-light-dark x3-light-dark with four light modules on one side.
+            for start in 0..size.saturating_sub(10) {
+                let window: [bool; 11] = core::array::from_fn(|offset| module(start + offset));
+                let core = [true, false, true, true, true, false, true];
+                if (window[..7] == core && window[7..].iter().all(|dark| !dark))
+                    || (window[4..] == core && window[..4].iter().all(|dark| !dark))
+                {
+                    penalty += PENALTY_FINDER_LIKE;
+                }
+            }
+        }
+
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let dark = self.is_dark(x, y);
+                if dark == self.is_dark(x + 1, y)
+                    && dark == self.is_dark(x, y + 1)
+                    && dark == self.is_dark(x + 1, y + 1)
+                {
+                    penalty += PENALTY_BLOCK;
+                }
+            }
+        }
+
+        let total = (size * size) as u32;
+        let dark = self.modules[..size]
+            .iter()
+            .map(|row| row.count_ones())
+            .sum::<u32>();
+        let balance = (dark * 20).abs_diff(total * 10).div_ceil(total);
+        penalty + balance.saturating_sub(1) * PENALTY_BALANCE
+    }
+}
+
+// Draws `code` with its top-left quiet zone corner at `origin`, `module_px` pixels per module.
+pub fn draw_qr_code(frame: &mut FrameBuffer, code: &QrCode, origin: Point, module_px: i32) {
+    let drawn = code.drawn_size(module_px);
+    fill_rect(frame, origin.x, origin.y, drawn, drawn, BinaryColor::Off);
+
+    let inset = QR_QUIET_ZONE_MODULES * module_px;
+    for y in 0..code.size() {
+        for x in 0..code.size() {
+            if code.is_dark(x, y) {
+                fill_rect(
+                    frame,
+                    origin.x + inset + x as i32 * module_px,
+                    origin.y + inset + y as i32 * module_px,
+                    module_px,
+                    module_px,
+                    BinaryColor::On,
+                );
+            }
+        }
+    }
+}
+
+struct BitWriter<'a> {
+    bytes: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    fn new(bytes: &'a mut [u8]) -> Self {
+        bytes.fill(0);
+        Self { bytes, len: 0 }
+    }
+
+    fn push(&mut self, value: u32, bit_count: usize) {
+        for shift in (0..bit_count).rev() {
+            if (value >> shift) & 1 != 0 {
+                self.bytes[self.len / 8] |= 0x80 >> (self.len % 8);
+            }
+            self.len += 1;
+        }
+    }
+}
+
+// Level and mask with their BCH(15,5) check bits, XORed with the fixed format mask.
+fn format_bits(mask: u8) -> u32 {
+    let data = FORMAT_LEVEL_L << 3 | u32::from(mask);
+    let mut remainder = data;
+    for _ in 0..10 {
+        remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+    }
+    (data << 10 | remainder) ^ 0x5412
+}
+
+fn mask_flips(mask: u8, x: usize, y: usize) -> bool {
+    match mask {
+        0 => (x + y).is_multiple_of(2),
+        1 => y.is_multiple_of(2),
+        2 => x.is_multiple_of(3),
+        3 => (x + y).is_multiple_of(3),
+        4 => (x / 3 + y / 2).is_multiple_of(2),
+        5 => x * y % 2 + x * y % 3 == 0,
+        6 => (x * y % 2 + x * y % 3).is_multiple_of(2),
+        _ => ((x + y) % 2 + x * y % 3).is_multiple_of(2),
+    }
+}
+
+// Multiplication in GF(2^8) modulo x^8 + x^4 + x^3 + x^2 + 1.
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut product: u16 = 0;
+    for shift in (0..8).rev() {
+        product = (product << 1) ^ ((product >> 7) * 0x11D);
+        product ^= u16::from((y >> shift) & 1) * u16::from(x);
+    }
+    product as u8
+}
+
+// Coefficients of the generator polynomial of `degree`, highest power first, leading 1 dropped.
+fn reed_solomon_divisor(degree: usize) -> [u8; QR_MAX_ECC_CODEWORDS] {
+    let mut divisor = [0u8; QR_MAX_ECC_CODEWORDS];
+    divisor[degree - 1] = 1;
+    let mut root = 1u8;
+    for _ in 0..degree {
+        for index in 0..degree {
+            divisor[index] = gf_multiply(divisor[index], root);
+            if index + 1 < degree {
+                divisor[index] ^= divisor[index + 1];
+            }
+        }
+        root = gf_multiply(root, 0x02);
+    }
+    divisor
+}
+
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8], remainder: &mut [u8]) {
+    remainder.fill(0);
+    for byte in data {
+        let factor = byte ^ remainder[0];
+        remainder.rotate_left(1);
+        let last = remainder.len() - 1;
+        remainder[last] = 0;
+        for (value, coefficient) in remainder.iter_mut().zip(divisor) {
+            *value ^= gf_multiply(*coefficient, factor);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_bits_match_the_published_level_l_table() {
+        let table = [
+            0x77C4, 0x72F3, 0x7DAA, 0x789D, 0x662F, 0x6318, 0x6C41, 0x6976,
+        ];
+        for (mask, expected) in table.into_iter().enumerate() {
+            assert_eq!(format_bits(mask as u8), expected);
+        }
+    }
+
+    #[test]
+    fn error_correction_matches_the_hello_world_worked_example() {
+        // "HELLO WORLD" as version 1-M, the usual worked example of the standard.
+        let data = [
+            32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17,
+        ];
+        let divisor = reed_solomon_divisor(10);
+        let mut ecc = [0u8; 10];
+        reed_solomon_remainder(&data, &divisor[..10], &mut ecc);
+        assert_eq!(ecc, [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]);
+    }
+
+    #[test]
+    fn encoding_picks_the_smallest_version_and_draws_the_finders() {
+        assert_eq!(QrCode::encode(b"motif").unwrap().version(), 1);
+        assert_eq!(QrCode::encode(&[b'a'; 17]).unwrap().version(), 1);
+        assert_eq!(QrCode::encode(&[b'a'; 18]).unwrap().version(), 2);
+        assert_eq!(
+            QrCode::encode(&[b'a'; QR_MAX_BYTES]).unwrap().version(),
+            QR_MAX_VERSION
+        );
+        assert!(QrCode::encode(&[b'a'; QR_MAX_BYTES + 1]).is_none());
+
+        let code = QrCode::encode(b"http://192.168.4.1/").unwrap();
+        let size = code.size();
+        for (x, y) in [(0, 0), (size - 7, 0), (0, size - 7)] {
+            assert!(code.is_dark(x, y) && code.is_dark(x + 6, y + 6));
+            assert!(!code.is_dark(x + 1, y + 1) && code.is_dark(x + 3, y + 3));
+        }
+        assert!(code.is_dark(8, size - 8));
+
+        let mut frame = FrameBuffer::new();
+        draw_qr_code(&mut frame, &code, Point::new(10, 10), 3);
+        let inset = 10 + QR_QUIET_ZONE_MODULES as usize * 3;
+        assert_eq!(frame.pixel(inset, inset), Some(true));
+        assert_eq!(frame.pixel(inset - 1, inset), Some(false));
+    }
+}
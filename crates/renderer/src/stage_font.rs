@@ -0,0 +1,65 @@
+use domain::formatter::FontStyle;
+use embedded_graphics::{
+    image::ImageRaw,
+    mono_font::{
+        DecorationDimensions, MonoFont,
+        iso_8859_1::{FONT_8X13_BOLD, FONT_10X20},
+    },
+    prelude::Size,
+};
+
+// The `STAGE_FONT_*` rasters, generated by build.rs.
+include!(concat!(env!("OUT_DIR"), "/stage_rasters.rs"));
+
+#[derive(Clone, Copy)]
+pub(crate) struct StageFamily {
+    regular: &'static MonoFont<'static>,
+    bold: &'static MonoFont<'static>,
+    italic: &'static MonoFont<'static>,
+}
+
+impl StageFamily {
+    pub(crate) const fn face(&self, style: FontStyle) -> &'static MonoFont<'static> {
+        match style {
+            FontStyle::Regular => self.regular,
+            FontStyle::Bold => self.bold,
+            FontStyle::Italic => self.italic,
+        }
+    }
+
+    pub(crate) const fn cell(&self) -> Size {
+        self.regular.character_size
+    }
+}
+
+// Every native raster the stage can draw with, smallest first. The faces of one family share a
+// cell size, so a word can switch face letter by letter without moving.
+const STAGE_FAMILIES: [StageFamily; 3] = [
+    StageFamily {
+        regular: &FONT_10X20,
+        bold: &STAGE_FONT_10X20_BOLD,
+        italic: &STAGE_FONT_10X20_ITALIC,
+    },
+    StageFamily {
+        regular: &STAGE_FONT_16X26,
+        bold: &STAGE_FONT_16X26_BOLD,
+        italic: &STAGE_FONT_16X26_ITALIC,
+    },
+    StageFamily {
+        regular: &STAGE_FONT_20X40,
+        bold: &STAGE_FONT_20X40_BOLD,
+        italic: &STAGE_FONT_20X40_ITALIC,
+    },
+];
+
+// The stage draws words at their raster's own size, so a size picks the family whose cell height is
+// closest rather than scaling a smaller one up.
+pub(crate) fn closest_stage_family(height_px: u32) -> StageFamily {
+    let mut closest = STAGE_FAMILIES[0];
+    for family in STAGE_FAMILIES {
+        if family.cell().height.abs_diff(height_px) < closest.cell().height.abs_diff(height_px) {
+            closest = family;
+        }
+    }
+    closest
+}
@@ -0,0 +1,150 @@
+use core::fmt::Write;
+
+use app_runtime::components::StatusBar;
+use embedded_graphics::{pixelcolor::BinaryColor, prelude::Point, text::Alignment};
+use heapless::String as HeaplessString;
+use ls027b7dh01::FrameBuffer;
+
+use super::{draw_text, fill_rect, stroke_rect, ui_font_small};
+
+const TEXT_Y: i32 = 13;
+const BATTERY_X: i32 = 319;
+const BATTERY_Y: i32 = 14;
+const BATTERY_WIDTH: i32 = 18;
+const BATTERY_HEIGHT: i32 = 10;
+const BATTERY_FILL_MAX: u32 = 11;
+const CHARGING_BOLT_X: i32 = 342;
+const BATTERY_LABEL_X: i32 = 353;
+const INDICATOR_GAP_X: i32 = 5;
+const WIFI_ICON_WIDTH: i32 = 16;
+const WIFI_ICON_Y: i32 = 12;
+const SD_ICON_WIDTH: i32 = 9;
+const SD_ICON_HEIGHT: i32 = 12;
+const SD_ICON_Y: i32 = 13;
+
+// The right-hand header every menu screen shares. The battery is pinned to the right edge and the
+// other indicators pack leftwards from it, so a new one only needs a field on `StatusBar` and a
+// slot here. Missing hardware (no card, no clock) leaves its slot out instead of drawing a blank.
+pub(crate) fn draw(frame: &mut FrameBuffer, status: &StatusBar) {
+    draw_battery(frame, status.battery_percent, status.charging);
+
+    let mut left_x = BATTERY_X;
+    left_x = next_slot(left_x, WIFI_ICON_WIDTH);
+    draw_wifi_icon(frame, left_x, WIFI_ICON_Y, status.wifi_online);
+    if status.sd_card_ready {
+        left_x = next_slot(left_x, SD_ICON_WIDTH);
+        draw_sd_icon(frame, left_x, SD_ICON_Y);
+    }
+    if let Some(clock) = status.clock {
+        draw_text(
+            frame,
+            clock.as_str(),
+            Point::new(left_x - INDICATOR_GAP_X, TEXT_Y),
+            ui_font_small(),
+            BinaryColor::On,
+            Alignment::Right,
+        );
+    }
+}
+
+const fn next_slot(left_x: i32, width: i32) -> i32 {
+    left_x - INDICATOR_GAP_X - width
+}
+
+fn draw_battery(frame: &mut FrameBuffer, percent: u8, charging: bool) {
+    stroke_rect(
+        frame,
+        BATTERY_X,
+        BATTERY_Y,
+        BATTERY_WIDTH,
+        BATTERY_HEIGHT,
+        BinaryColor::On,
+    );
+    fill_rect(
+        frame,
+        BATTERY_X + 2,
+        BATTERY_Y + 2,
+        ((percent.min(100) as u32 * BATTERY_FILL_MAX) / 100) as i32,
+        BATTERY_HEIGHT - 4,
+        BinaryColor::On,
+    );
+    fill_rect(
+        frame,
+        BATTERY_X + BATTERY_WIDTH,
+        BATTERY_Y + 3,
+        2,
+        4,
+        BinaryColor::On,
+    );
+    if charging {
+        draw_charging_bolt(frame, CHARGING_BOLT_X, TEXT_Y);
+    }
+    draw_text(
+        frame,
+        battery_label(percent).as_str(),
+        Point::new(BATTERY_LABEL_X, TEXT_Y),
+        ui_font_small(),
+        BinaryColor::On,
+        Alignment::Left,
+    );
+}
+
+fn draw_charging_bolt(frame: &mut FrameBuffer, x: i32, y: i32) {
+    fill_rect(frame, x + 3, y, 2, 3, BinaryColor::On);
+    fill_rect(frame, x + 2, y + 3, 2, 2, BinaryColor::On);
+    fill_rect(frame, x + 1, y + 5, 5, 1, BinaryColor::On);
+    fill_rect(frame, x + 3, y + 6, 2, 2, BinaryColor::On);
+    fill_rect(frame, x + 2, y + 8, 2, 3, BinaryColor::On);
+}
+
+fn draw_wifi_icon(frame: &mut FrameBuffer, x: i32, y: i32, online: bool) {
+    if online {
+        fill_rect(frame, x + 7, y + 10, 2, 2, BinaryColor::On);
+        fill_rect(frame, x + 5, y + 7, 6, 2, BinaryColor::On);
+        fill_rect(frame, x + 3, y + 4, 10, 2, BinaryColor::On);
+    } else {
+        fill_rect(frame, x + 2, y + 2, 12, 2, BinaryColor::On);
+        fill_rect(frame, x + 6, y + 4, 2, 10, BinaryColor::On);
+    }
+}
+
+// A card outline with the notched top-right corner and three contact stripes.
+fn draw_sd_icon(frame: &mut FrameBuffer, x: i32, y: i32) {
+    fill_rect(frame, x, y, SD_ICON_WIDTH - 3, 1, BinaryColor::On);
+    fill_rect(frame, x, y, 1, SD_ICON_HEIGHT, BinaryColor::On);
+    fill_rect(
+        frame,
+        x,
+        y + SD_ICON_HEIGHT - 1,
+        SD_ICON_WIDTH,
+        1,
+        BinaryColor::On,
+    );
+    fill_rect(
+        frame,
+        x + SD_ICON_WIDTH - 1,
+        y + 3,
+        1,
+        SD_ICON_HEIGHT - 3,
+        BinaryColor::On,
+    );
+    let mut step = 0;
+    while step < 3 {
+        fill_rect(
+            frame,
+            x + SD_ICON_WIDTH - 3 + step,
+            y + step,
+            1,
+            1,
+            BinaryColor::On,
+        );
+        fill_rect(frame, x + 2 + step * 2, y + 2, 1, 3, BinaryColor::On);
+        step += 1;
+    }
+}
+
+fn battery_label(percent: u8) -> HeaplessString<4> {
+    let mut label = HeaplessString::new();
+    let _ = write!(label, "{}%", percent.min(100));
+    label
+}
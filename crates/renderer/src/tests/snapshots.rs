@@ -0,0 +1,647 @@
+// Golden-frame checks for every prepared screen. Each fixture renders into a FrameBuffer and is
+// compared against `snapshots/<name>.pbm`; a missing golden is recorded on first run, and
+// `UPDATE_SNAPSHOTS=1 cargo test -p renderer` re-records them after an intended layout change.
+// A mismatch leaves `<name>.actual.pbm` beside the golden for viewing.
+
+use std::{env, format, fs, path::PathBuf, string::String, vec::Vec};
+
+use super::*;
+use app_runtime::components::{BookDetailActionRow, ContentsRow, SettingsRow};
+use domain::{
+    pagination::PAGE_LINES,
+    picker::PickerKey,
+    reader::DEFINITION_VISIBLE_LINES,
+    selectors::{ContentsMarker, DiagnosticsRowModel, HistoryRowModel},
+    ui::SettingsMode,
+};
+use ls027b7dh01::protocol::{BUFFER_SIZE, HEIGHT, WIDTH};
+
+const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+
+fn snapshot_path(name: &str, suffix: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(format!("{name}{suffix}.pbm"))
+}
+
+// PBM P4 rows are MSB-first with black at 1, the same layout as the framebuffer.
+fn encode_pbm(frame: &FrameBuffer) -> Vec<u8> {
+    let mut bytes = format!("P4\n{WIDTH} {HEIGHT}\n").into_bytes();
+    bytes.extend_from_slice(frame.bytes());
+    bytes
+}
+
+fn decode_pbm(bytes: &[u8]) -> Option<FrameBuffer> {
+    let header = format!("P4\n{WIDTH} {HEIGHT}\n");
+    let pixels = bytes.strip_prefix(header.as_bytes())?;
+    if pixels.len() != BUFFER_SIZE {
+        return None;
+    }
+
+    let mut frame = FrameBuffer::new();
+    frame.bytes_mut().copy_from_slice(pixels);
+    Some(frame)
+}
+
+fn assert_snapshot(name: &str, screen: PreparedScreen) {
+    assert_snapshot_with_toasts(name, screen, ToastStack::empty());
+}
+
+fn assert_snapshot_with_toasts(name: &str, screen: PreparedScreen, toasts: ToastStack) {
+    let mut frame = FrameBuffer::new();
+    draw_screen_update(
+        &mut frame,
+        &ScreenUpdate {
+            screen: Screen::Reader,
+            prepared: screen,
+            transition: TransitionPlan::none(),
+            toasts,
+        },
+    );
+    assert_frame_snapshot(name, &frame);
+}
+
+fn assert_frame_snapshot(name: &str, frame: &FrameBuffer) {
+    let golden_path = snapshot_path(name, "");
+    let actual_path = snapshot_path(name, ".actual");
+    let update = env::var_os(UPDATE_ENV).is_some();
+    let golden = fs::read(&golden_path).ok();
+
+    if update || golden.is_none() {
+        fs::create_dir_all(golden_path.parent().unwrap()).unwrap();
+        fs::write(&golden_path, encode_pbm(frame)).unwrap();
+        let _ = fs::remove_file(&actual_path);
+        return;
+    }
+
+    let Some(expected) = golden.as_deref().and_then(decode_pbm) else {
+        panic!("snapshot {name} is not a {WIDTH}x{HEIGHT} P4 file");
+    };
+    let dirty = diff_dirty_rows(&expected, frame);
+    if dirty.is_empty() {
+        let _ = fs::remove_file(&actual_path);
+        return;
+    }
+
+    fs::write(&actual_path, encode_pbm(frame)).unwrap();
+    let rows: Vec<String> = dirty.iter().map(|row| format!("{row}")).collect();
+    panic!(
+        "snapshot {name} differs in {} rows ({}); see {} or rerun with {UPDATE_ENV}=1",
+        dirty.count(),
+        rows.join(","),
+        actual_path.display()
+    );
+}
+
+fn status() -> StatusBar {
+    StatusBar {
+        battery_percent: 64,
+        charging: false,
+        wifi_online: true,
+        sd_card_ready: true,
+        clock: Some(InlineText::from_slice("09:41")),
+    }
+}
+
+fn lines<const N: usize, const L: usize>(text: [&str; L]) -> [InlineText<N>; L] {
+    text.map(InlineText::from_slice)
+}
+
+fn settings_row(label: &'static str, value: Option<&'static str>, selected: bool) -> SettingsRow {
+    SettingsRow {
+        label,
+        value,
+        selected,
+        show_arrow: value.is_some(),
+    }
+}
+
+#[test]
+fn crash_report_snapshot() {
+    assert_snapshot(
+        "crash_report",
+        PreparedScreen::CrashReport(CrashReportShell {
+            appearance: AppearanceMode::Light,
+            title: "SOMETHING WENT WRONG",
+            lines: lines::<_, DEFINITION_VISIBLE_LINES>([
+                "src/reader.rs:412:",
+                "index out of bounds:",
+                "the len is 3 but",
+                "the index is 7",
+                "",
+            ]),
+            boot_label: InlineText::from_slice("BOOT 12"),
+            help: HelpHint { text: "CLICK" },
+        }),
+    );
+}
+
+#[test]
+fn low_battery_snapshot() {
+    assert_snapshot(
+        "low_battery",
+        PreparedScreen::LowBattery(LowBatteryShell {
+            appearance: AppearanceMode::Light,
+            status: StatusBar {
+                battery_percent: 9,
+                ..status()
+            },
+            title: "LOW BATTERY",
+            body: InlineText::from_slice("Charge soon to keep reading"),
+            help: HelpHint { text: "CLICK" },
+        }),
+    );
+}
+
+#[test]
+fn startup_splash_snapshot() {
+    assert_snapshot(
+        "startup_splash",
+        PreparedScreen::StartupSplash(make_startup_splash_shell()),
+    );
+}
+
+#[test]
+fn boot_scan_snapshot() {
+    let mut frame = FrameBuffer::new();
+    draw_boot_scan(&mut frame, AppearanceMode::Light, 3, 12, "Saved");
+    assert_frame_snapshot("boot_scan", &frame);
+}
+
+#[test]
+fn dashboard_snapshot() {
+    assert_snapshot(
+        "dashboard",
+        PreparedScreen::Dashboard(make_dashboard_shell(0)),
+    );
+}
+
+#[test]
+fn collection_snapshot() {
+    assert_snapshot(
+        "collection",
+        PreparedScreen::Collection(make_collection_shell([
+            ("EXAMPLE.COM", "First saved article"),
+            ("NEWS.SITE", "Second saved article with a long title"),
+            ("BLOG.NET", "Third saved article"),
+        ])),
+    );
+}
+
+#[test]
+fn collection_progress_snapshot() {
+    let mut shell = make_collection_shell([
+        ("EXAMPLE.COM", "First saved article"),
+        ("NEWS.SITE", "Second saved article with a long title"),
+        ("BLOG.NET", "Third saved article"),
+    ]);
+    for (row, percent) in shell.rows.iter_mut().zip([100, 42, 7]) {
+        row.progress_percent = percent;
+    }
+    shell.rows[0].finished = true;
+    shell.rows[1].progress_badge = Some(InlineText::from_slice("42%"));
+    assert_snapshot("collection_progress", PreparedScreen::Collection(shell));
+}
+
+#[test]
+fn collection_grid_snapshot() {
+    let mut shell = make_collection_shell([("", ""); 3]);
+    let titles = [
+        "Rapid serial visual presentation",
+        "Notes on reading",
+        "The quiet screen",
+        "Memory palaces",
+        "42 ways to focus",
+    ];
+    let mut cells = [shell.rows[0]; domain::selectors::LIBRARY_GRID_CELLS];
+    for (cell, title) in cells.iter_mut().zip(titles) {
+        cell.title = InlineText::from_slice(title);
+    }
+    cells[4].selected = true;
+    cells[1].progress_percent = 100;
+    cells[1].finished = true;
+    shell.grid = Some(cells);
+    assert_snapshot("collection_grid", PreparedScreen::Collection(shell));
+}
+
+#[test]
+fn reader_snapshot() {
+    assert_snapshot("reader", PreparedScreen::Reader(make_reader_shell(120)));
+}
+
+#[test]
+fn reader_dark_snapshot() {
+    assert_snapshot(
+        "reader_dark",
+        PreparedScreen::Reader(make_dark_reader_shell(120)),
+    );
+}
+
+#[test]
+fn reader_emphasis_snapshot() {
+    let mut shell = make_reader_shell(120);
+    shell.stage.highlight_anchor = true;
+    shell.stage.pivot_style = domain::formatter::FontStyle::Bold;
+    shell.stage.bold_lead_chars = 2;
+    shell.stage.preview = domain::text::InlineText::from_slice(
+        "Typography at small sizes: \"fairly\" tight; AV To Wo. Lorem ipsum",
+    );
+    assert_snapshot("reader_emphasis", PreparedScreen::Reader(shell));
+}
+
+#[test]
+fn reader_wrapped_word_snapshot() {
+    let mut shell = make_reader_shell(120);
+    shell.stage.left_word = InlineText::from_slice("https://en.");
+    shell.stage.right_word = InlineText::from_slice("wikipedia.org/wiki/Rapid");
+    shell.stage.font = StageFont::Small;
+    shell.stage.wrap_index = 20;
+    shell.stage.highlight_anchor = true;
+    assert_snapshot("reader_wrapped_word", PreparedScreen::Reader(shell));
+}
+
+#[test]
+fn confirm_snapshot() {
+    assert_snapshot(
+        "confirm",
+        PreparedScreen::Confirm(ConfirmShell {
+            appearance: AppearanceMode::Light,
+            title: "DELETE BOOK?",
+            body: InlineText::from_slice("Rust for embedded systems"),
+            accept_label: "DELETE",
+            cancel_label: "KEEP",
+            accept_focused: true,
+            help: HelpHint {
+                text: "turn to choose",
+            },
+        }),
+    );
+}
+
+#[test]
+fn onboarding_speed_snapshot() {
+    assert_snapshot(
+        "onboarding_speed",
+        PreparedScreen::Onboarding(OnboardingShell {
+            appearance: AppearanceMode::Light,
+            step_number: 2,
+            step_count: 4,
+            title: "READING SPEED",
+            prompt: "Turn to set words per minute",
+            value: "260 WPM",
+            demo_word: Some("word"),
+            help: HelpHint {
+                text: "turn to adjust, press to continue",
+            },
+        }),
+    );
+}
+
+#[test]
+fn diagnostics_snapshot() {
+    let row = |label, value| DiagnosticsRowModel {
+        label,
+        value: InlineText::from_slice(value),
+    };
+    assert_snapshot(
+        "diagnostics",
+        PreparedScreen::Diagnostics(DiagnosticsShell {
+            appearance: AppearanceMode::Light,
+            title: "DIAGNOSTICS",
+            rows: [
+                row("Firmware", "0.1.0"),
+                row("Free heap", "96 KB"),
+                row("Min free heap", "71 KB"),
+                row("Largest region", "64 KB"),
+                row("Stack peak", "9 / 64 KB"),
+                row("SD clock", "20 MHz"),
+                row("Render", "4210 / 9870 us"),
+                row("Frame flush", "812 / 2400 us"),
+                row("SD refill", "--"),
+                row("Words/s", "5"),
+            ],
+            help: HelpHint {
+                text: "long press to go back",
+            },
+        }),
+    );
+}
+
+#[test]
+fn history_snapshot() {
+    let row = |title, when, words, selected| HistoryRowModel {
+        title: InlineText::from_slice(title),
+        when: InlineText::from_slice(when),
+        words,
+        selected,
+    };
+    assert_snapshot(
+        "history",
+        PreparedScreen::History(HistoryShell {
+            appearance: AppearanceMode::Light,
+            title: "READING HISTORY",
+            streak_days: Some(3),
+            total_words: 48_210,
+            selected_index: 1,
+            total: 9,
+            first: 0,
+            rows: [
+                row("Rust for embedded systems", "TODAY", 1_840, false),
+                row("The Pragmatic Programmer", "TODAY", 620, true),
+                row("Rust for embedded systems", "YDAY", 3_015, false),
+                row(
+                    "Designing Data-Intensive Applications",
+                    "2D AGO",
+                    4_400,
+                    false,
+                ),
+                row("Rust for embedded systems", "2D AGO", 150, false),
+                row("The Pragmatic Programmer", "12D AGO", 2_270, false),
+                row("Notes on the motif firmware", "--", 380, false),
+            ],
+            help: HelpHint {
+                text: "turn to scroll",
+            },
+        }),
+    );
+}
+
+#[test]
+fn screensaver_snapshot() {
+    assert_snapshot(
+        "screensaver",
+        PreparedScreen::Screensaver(ScreensaverShell {
+            appearance: AppearanceMode::Light,
+            clock: Some(InlineText::from_slice("09:41")),
+            title: InlineText::from_slice("Rust for embedded systems"),
+            progress_percent: 42,
+            help: HelpHint {
+                text: "press to wake",
+            },
+        }),
+    );
+}
+
+#[test]
+fn reader_toasts_snapshot() {
+    assert_snapshot_with_toasts(
+        "reader_toasts",
+        PreparedScreen::Reader(make_reader_shell(120)),
+        ToastStack {
+            labels: [Some("Settings saved"), Some("Wi-Fi connected"), None],
+        },
+    );
+}
+
+#[test]
+fn reader_pause_modal_snapshot() {
+    assert_snapshot(
+        "reader_pause_modal",
+        PreparedScreen::Reader(make_reader_shell_with_modal(120, Some(make_pause_modal()))),
+    );
+}
+
+#[test]
+fn paragraph_navigation_snapshot() {
+    assert_snapshot(
+        "paragraph_navigation",
+        PreparedScreen::ParagraphNavigation(make_paragraph_shell(
+            4,
+            12,
+            2,
+            "A previous top preview",
+            "P4",
+            "Selected paragraph excerpt",
+            "A previous bottom preview",
+            "Final excerpt for paragraph",
+        )),
+    );
+}
+
+#[test]
+fn definition_snapshot() {
+    assert_snapshot(
+        "definition",
+        PreparedScreen::Definition(DefinitionShell {
+            appearance: AppearanceMode::Light,
+            word: InlineText::from_slice("ephemeral"),
+            part_of_speech: InlineText::from_slice("adjective"),
+            status_label: None,
+            lines: lines::<_, DEFINITION_VISIBLE_LINES>([
+                "lasting for a very short time",
+                "",
+                "fashions are ephemeral",
+                "",
+                "",
+            ]),
+            show_scroll_up: false,
+            show_scroll_down: true,
+            help: HelpHint { text: "BACK" },
+        }),
+    );
+}
+
+#[test]
+fn section_break_snapshot() {
+    assert_snapshot(
+        "section_break",
+        PreparedScreen::SectionBreak(SectionBreakShell {
+            appearance: AppearanceMode::Light,
+            title: InlineText::from_slice("TITLE"),
+            label: "SECTION",
+            heading: InlineText::from_slice("The second part begins"),
+            help: HelpHint { text: "CLICK" },
+        }),
+    );
+}
+
+#[test]
+fn page_snapshot() {
+    let mut page_lines: [InlineText<_>; PAGE_LINES] = Default::default();
+    for (line, text) in page_lines.iter_mut().zip([
+        "The quick brown fox jumps over the",
+        "lazy dog while the reader pages",
+        "through a full screen of text.",
+    ]) {
+        *line = InlineText::from_slice(text);
+    }
+
+    assert_snapshot(
+        "page",
+        PreparedScreen::Page(PageShell {
+            appearance: AppearanceMode::Light,
+            title: InlineText::from_slice("TITLE"),
+            lines: page_lines,
+            progress_width: 80,
+            help: HelpHint { text: "BACK" },
+        }),
+    );
+}
+
+#[test]
+fn search_snapshot() {
+    assert_snapshot(
+        "search",
+        PreparedScreen::Search(SearchShell {
+            appearance: AppearanceMode::Light,
+            status: status(),
+            rail: VerticalRail {
+                text: "S\nA\nV\nE\nD",
+            },
+            query: InlineText::from_slice("RUST"),
+            keys: [
+                PickerKey::Char('A'),
+                PickerKey::Char('B'),
+                PickerKey::Char('C'),
+                PickerKey::Char('D'),
+                PickerKey::Char('E'),
+                PickerKey::Char('F'),
+                PickerKey::Char('G'),
+                PickerKey::Char('H'),
+                PickerKey::Char('I'),
+            ],
+            match_label: InlineText::from_slice("2 MATCHES"),
+            matches: lines(["Rust for embedded systems", "Trusting trust", ""]),
+            help: HelpHint {
+                text: "press to type_",
+            },
+        }),
+    );
+}
+
+#[test]
+fn book_detail_snapshot() {
+    assert_snapshot(
+        "book_detail",
+        PreparedScreen::BookDetail(BookDetailShell {
+            appearance: AppearanceMode::Light,
+            status: status(),
+            title: InlineText::from_slice("Rust for embedded systems"),
+            meta: InlineText::from_slice("ferrous.systems"),
+            state_label: "DOWNLOADED",
+            progress_label: InlineText::from_slice("42% READ"),
+            progress_percent: 42,
+            actions: [
+                BookDetailActionRow {
+                    label: "READ",
+                    selected: false,
+                },
+                BookDetailActionRow {
+                    label: "READ FROM START",
+                    selected: true,
+                },
+                BookDetailActionRow {
+                    label: "CONTENTS",
+                    selected: false,
+                },
+                BookDetailActionRow {
+                    label: "BOOKMARKS",
+                    selected: false,
+                },
+                BookDetailActionRow {
+                    label: "MARK FINISHED",
+                    selected: false,
+                },
+                BookDetailActionRow {
+                    label: "DELETE FROM DEVICE",
+                    selected: false,
+                },
+            ],
+            help: HelpHint {
+                text: "long press_",
+            },
+        }),
+    );
+}
+
+#[test]
+fn contents_snapshot() {
+    let previews = [
+        "It was a bright cold day in April",
+        "Outside, even through the shut window-pane",
+        "The Ministry of Truth was startlingly different",
+        "Winston turned round abruptly",
+        "He went back to the living-room",
+        "The thing that he was about to do",
+        "For some reason the telescreen was in an odd position",
+    ];
+    let rows = core::array::from_fn(|slot| {
+        let number = slot as u16 + 8;
+        ContentsRow {
+            number,
+            preview: InlineText::from_slice(previews[slot]),
+            minutes: [2, 1, 4, 1, 3, 12, 2][slot],
+            marker: match number {
+                ..11 => ContentsMarker::Read,
+                11 => ContentsMarker::Current,
+                _ => ContentsMarker::Unread,
+            },
+            selected: number == 12,
+        }
+    });
+
+    assert_snapshot(
+        "contents",
+        PreparedScreen::Contents(ContentsShell {
+            appearance: AppearanceMode::Light,
+            title: InlineText::from_slice("Nineteen Eighty-Four"),
+            selected_index: 12,
+            total: 14,
+            first: 7,
+            rows,
+            help: HelpHint {
+                text: "press to jump",
+            },
+        }),
+    );
+}
+
+#[test]
+fn settings_snapshot() {
+    assert_snapshot(
+        "settings",
+        PreparedScreen::Settings(SettingsShell {
+            appearance: AppearanceMode::Light,
+            status: status(),
+            title: "SETTINGS",
+            mode: SettingsMode::Master,
+            rows: [
+                settings_row("SPEED", Some("260 WPM"), true),
+                settings_row("APPEARANCE", Some("LIGHT"), false),
+                settings_row("TOPICS", None, false),
+                settings_row("REFRESH", None, false),
+                settings_row("PROFILE", Some("1"), false),
+                settings_row("RESET", None, false),
+            ],
+            refresh_title: None,
+            refresh_body: None,
+            topic_preferences: None,
+            preview_word: None,
+        }),
+    );
+}
+
+#[test]
+fn settings_speed_preview_snapshot() {
+    assert_snapshot(
+        "settings_speed_preview",
+        PreparedScreen::Settings(SettingsShell {
+            appearance: AppearanceMode::Light,
+            status: status(),
+            title: "READING SETTINGS",
+            mode: SettingsMode::SpeedEdit,
+            rows: [
+                settings_row("Reading Speed", Some("300 WPM"), true),
+                settings_row("Section Pauses", Some("OFF"), false),
+                settings_row("Dot Pause", Some("AUTO"), false),
+                settings_row("Comma Pause", Some("AUTO"), false),
+                settings_row("Resume Rewind", Some("OFF"), false),
+                settings_row("Countdown", Some("OFF"), false),
+            ],
+            refresh_title: None,
+            refresh_body: None,
+            topic_preferences: None,
+            preview_word: Some("word"),
+        }),
+    );
+}
@@ -0,0 +1,207 @@
+use embedded_graphics::{
+    mono_font::{
+        MonoFont,
+        iso_8859_1::{FONT_6X10, FONT_8X13},
+    },
+    pixelcolor::BinaryColor,
+    prelude::Point,
+    text::Alignment,
+};
+use heapless::String as HeaplessString;
+use ls027b7dh01::FrameBuffer;
+
+use crate::{ELLIPSIS, NORMALIZED_TEXT_MAX_BYTES, draw_text, fonts, normalized_text};
+
+// Proportional layout over the UI fonts. The glyphs are still the mono rasters, but each one takes
+// only its ink width plus a one pixel gap, and ASCII pairs with room to spare sit a pixel closer.
+// Characters drawn from a font pack keep the full cell.
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct GlyphMetrics {
+    left: u8,
+    advance: u8,
+}
+
+impl GlyphMetrics {
+    const fn new(left: u8, advance: u8) -> Self {
+        Self { left, advance }
+    }
+}
+
+// `GLYPH_METRICS_*` and `KERN_PAIRS_*`, measured by build.rs.
+include!(concat!(env!("OUT_DIR"), "/text_metrics.rs"));
+
+pub(crate) struct ProportionalFont {
+    pub(crate) mono: &'static MonoFont<'static>,
+    metrics: &'static [GlyphMetrics],
+    kern_pairs: &'static [u16],
+}
+
+pub(crate) const TEXT_FONT_SMALL: ProportionalFont = ProportionalFont {
+    mono: &FONT_6X10,
+    metrics: &GLYPH_METRICS_6X10,
+    kern_pairs: &KERN_PAIRS_6X10,
+};
+pub(crate) const TEXT_FONT_BODY: ProportionalFont = ProportionalFont {
+    mono: &FONT_8X13,
+    metrics: &GLYPH_METRICS_8X13,
+    kern_pairs: &KERN_PAIRS_8X13,
+};
+
+impl ProportionalFont {
+    fn metrics(&self, ch: char) -> GlyphMetrics {
+        if fonts::needs_provider(ch) && fonts::installed().is_some() {
+            return GlyphMetrics::new(0, self.mono.character_size.width as u8);
+        }
+        self.metrics
+            .get(self.mono.glyph_mapping.index(ch))
+            .copied()
+            .unwrap_or(GlyphMetrics::new(0, self.mono.character_size.width as u8))
+    }
+
+    fn kerning(&self, left: char, right: char) -> i32 {
+        if !left.is_ascii() || !right.is_ascii() {
+            return 0;
+        }
+        let pair = u16::from(left as u8) << 8 | u16::from(right as u8);
+        if self.kern_pairs.binary_search(&pair).is_ok() {
+            -1
+        } else {
+            0
+        }
+    }
+
+    // The pen step from `ch` to `next`, including the gap and any kerning.
+    fn step(&self, ch: char, next: Option<char>) -> i32 {
+        let advance = i32::from(self.metrics(ch).advance);
+        advance + next.map_or(0, |next| self.kerning(ch, next))
+    }
+}
+
+// Width of `text` in pixels, from the first inked column to the last.
+pub(crate) fn measure_str(text: &str, font: &ProportionalFont) -> i32 {
+    let normalized = normalized_text(text);
+    let mut chars = normalized.chars().peekable();
+    let mut width = 0;
+    while let Some(ch) = chars.next() {
+        width += font.step(ch, chars.peek().copied());
+    }
+    // The last glyph needs no gap after it.
+    (width - 1).max(0)
+}
+
+pub(crate) fn draw_str(
+    frame: &mut FrameBuffer,
+    text: &str,
+    position: Point,
+    font: &ProportionalFont,
+    color: BinaryColor,
+    alignment: Alignment,
+) {
+    let normalized = normalized_text(text);
+    let width = measure_str(normalized.as_str(), font);
+    let mut pen_x = match alignment {
+        Alignment::Left => position.x,
+        Alignment::Center => position.x - (width - 1) / 2,
+        Alignment::Right => position.x - (width - 1),
+    };
+
+    let mut chars = normalized.chars().peekable();
+    let mut glyph = [0u8; 4];
+    while let Some(ch) = chars.next() {
+        let metrics = font.metrics(ch);
+        if !ch.is_whitespace() {
+            draw_text(
+                frame,
+                ch.encode_utf8(&mut glyph),
+                Point::new(pen_x - i32::from(metrics.left), position.y),
+                font.mono,
+                color,
+                Alignment::Left,
+            );
+        }
+        pen_x += font.step(ch, chars.peek().copied());
+    }
+}
+
+// `text` cut to fit `max_width_px`, ending in an ellipsis when anything was dropped.
+pub(crate) fn ellipsized_str(
+    text: &str,
+    font: &ProportionalFont,
+    max_width_px: i32,
+) -> HeaplessString<NORMALIZED_TEXT_MAX_BYTES> {
+    let normalized = normalized_text(text);
+    if measure_str(normalized.as_str(), font) <= max_width_px {
+        return normalized;
+    }
+
+    let mut clipped = HeaplessString::new();
+    for ch in normalized.chars() {
+        let mut candidate = clipped.clone();
+        if candidate.push(ch).is_err() || candidate.push_str(ELLIPSIS).is_err() {
+            break;
+        }
+        if measure_str(candidate.as_str(), font) > max_width_px {
+            break;
+        }
+        let _ = clipped.push(ch);
+    }
+    let trimmed_len = clipped.trim_end().len();
+    clipped.truncate(trimmed_len);
+    let _ = clipped.push_str(ELLIPSIS);
+    clipped
+}
+
+// Greedy word wrap: breaks at spaces, and mid-word only when a word is wider than the line.
+// Returns the byte length of the first line of `text` and where the next line starts.
+pub(crate) fn wrap_line(text: &str, font: &ProportionalFont, max_width_px: i32) -> (usize, usize) {
+    let mut width = 0;
+    let mut last_break = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, ch)) = chars.next() {
+        if ch == ' ' {
+            last_break = Some(index);
+        }
+        let next = chars.peek().map(|(_, next)| *next);
+        let glyph_right = width + i32::from(font.metrics(ch).advance) - 1;
+        if glyph_right > max_width_px && index > 0 {
+            return match last_break {
+                Some(space) => (space, space + 1),
+                None => (index, index),
+            };
+        }
+        width += font.step(ch, next);
+    }
+    (text.len(), text.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proportional_text_is_narrower_than_fixed_stride_and_wraps_at_spaces() {
+        let cell = TEXT_FONT_BODY.mono.character_size.width as i32;
+        assert_eq!(measure_str("", &TEXT_FONT_BODY), 0);
+        assert!(measure_str("i", &TEXT_FONT_BODY) < cell);
+        assert!(measure_str("illicit", &TEXT_FONT_BODY) < 7 * cell);
+        assert!(measure_str("mW", &TEXT_FONT_BODY) > measure_str("il", &TEXT_FONT_BODY));
+        let (t, o, h) = (
+            measure_str("T", &TEXT_FONT_BODY),
+            measure_str("o", &TEXT_FONT_BODY),
+            measure_str("H", &TEXT_FONT_BODY),
+        );
+        assert_eq!(measure_str("HH", &TEXT_FONT_BODY), 2 * h + 1);
+        assert_eq!(measure_str("To", &TEXT_FONT_BODY), t + o);
+
+        let text = "small screens need hierarchy";
+        let (line_end, next) = wrap_line(text, &TEXT_FONT_BODY, 100);
+        assert_eq!(&text[..line_end], "small screens");
+        assert_eq!(&text[next..], "need hierarchy");
+        assert!(measure_str(&text[..line_end], &TEXT_FONT_BODY) <= 100);
+
+        let clipped = ellipsized_str(text, &TEXT_FONT_BODY, 100);
+        assert!(clipped.ends_with(ELLIPSIS));
+        assert!(measure_str(clipped.as_str(), &TEXT_FONT_BODY) <= 100);
+    }
+}
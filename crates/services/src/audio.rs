@@ -0,0 +1,21 @@
+use domain::audio::{AudioCue, AudioVolume};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AudioError {
+    Unsupported,
+    Driver,
+}
+
+// Plays a cue to the end; the caller runs it off the UI path since cues last a few hundred ms.
+pub trait AudioOutput {
+    async fn play(&mut self, cue: AudioCue, volume: AudioVolume) -> Result<(), AudioError>;
+}
+
+#[derive(Debug, Default)]
+pub struct NoopAudioOutput;
+
+impl AudioOutput for NoopAudioOutput {
+    async fn play(&mut self, _cue: AudioCue, _volume: AudioVolume) -> Result<(), AudioError> {
+        Err(AudioError::Unsupported)
+    }
+}
@@ -0,0 +1,595 @@
+use domain::{
+    content::{CONTENT_ID_MAX_BYTES, CONTENT_TITLE_MAX_BYTES},
+    text::InlineText,
+};
+use embedded_storage::nor_flash::{NorFlash, ReadNorFlash};
+
+use crate::storage::{FileSource, StorageError};
+
+// A handful of reader packages kept in internal flash so the device still has something to read
+// without an SD card. The partition is cut into equal slots; each slot starts with one erase
+// sector holding the book header and the package bytes follow it. A slot counts only once its
+// commit word is written, which happens after the payload and header, so a copy torn by power
+// loss reads as an empty slot.
+//
+// Erasing and copying a slot takes seconds, longer than the executor may go without running the
+// watchdog, so a store runs as a `BookWrite` the caller steps through, yielding between steps.
+
+pub const BOOK_SLOT_COUNT: usize = 3;
+const BOOK_MAGIC: u32 = u32::from_le_bytes(*b"MTBK");
+const BOOK_COMMIT_MAGIC: u32 = 0xB00C_C0DE;
+const BOOK_FORMAT_VERSION: u16 = 1;
+const BOOK_HEADER_BODY_LEN: usize = 28 + CONTENT_ID_MAX_BYTES + CONTENT_TITLE_MAX_BYTES;
+const BOOK_HEADER_COMMIT_OFFSET: usize = BOOK_HEADER_BODY_LEN.next_multiple_of(4);
+const BOOK_HEADER_LEN: usize = BOOK_HEADER_COMMIT_OFFSET + 4;
+const BOOK_COPY_CHUNK_LEN: usize = 4096;
+// Flash one `BookWrite::step` may erase or copy before handing control back.
+const BOOK_STEP_BYTES: u32 = 64 * 1024;
+const BOOK_READ_BOUNCE_LEN: usize = 256;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct FlashBook {
+    pub slot: u8,
+    pub content_id: InlineText<CONTENT_ID_MAX_BYTES>,
+    pub title: InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub remote_revision: u64,
+    pub size_bytes: u32,
+    pub crc32: u32,
+    stored_seq: u32,
+}
+
+// A store in progress: the slot's header sector and the sectors the payload needs are erased a
+// batch at a time, the payload copied after them, and the header committed last.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BookWrite {
+    book: FlashBook,
+    erased_to: u32,
+    erase_end: u32,
+    copied: u32,
+    crc: u32,
+}
+
+#[derive(Debug)]
+pub struct BookPartition<F> {
+    flash: F,
+    offset: u32,
+    slot_len: u32,
+}
+
+impl<F> BookPartition<F>
+where
+    F: NorFlash + ReadNorFlash,
+{
+    pub fn new(flash: F, offset: u32, length: u32) -> Result<Self, StorageError> {
+        let sector = F::ERASE_SIZE as u32;
+        let slot_len = (length / BOOK_SLOT_COUNT as u32) / sector * sector;
+        if !offset.is_multiple_of(sector) || slot_len <= sector {
+            return Err(StorageError::InvalidPartition);
+        }
+        Ok(Self {
+            flash,
+            offset,
+            slot_len,
+        })
+    }
+
+    // Largest package a slot can hold once its header sector is set aside.
+    pub fn capacity_bytes(&self) -> u32 {
+        self.slot_len - F::ERASE_SIZE as u32
+    }
+
+    pub fn books(&mut self) -> [Option<FlashBook>; BOOK_SLOT_COUNT] {
+        let mut books = [None; BOOK_SLOT_COUNT];
+        for (slot, book) in books.iter_mut().enumerate() {
+            *book = self.read_header(slot as u8).ok().flatten();
+        }
+        books
+    }
+
+    pub fn find(&mut self, content_id: &str) -> Option<FlashBook> {
+        self.books()
+            .into_iter()
+            .flatten()
+            .find(|book| book.content_id.as_str() == content_id)
+    }
+
+    // Rereads the whole payload against the CRC taken while it was copied in.
+    pub fn verify(&mut self, book: &FlashBook) -> Result<(), StorageError> {
+        let mut file = self.open(book);
+        let mut chunk = [0u8; BOOK_READ_BOUNCE_LEN];
+        let mut crc = 0xFFFF_FFFF;
+        let mut offset = 0u32;
+        while offset < book.size_bytes {
+            let read = file.read_at(offset, &mut chunk)?;
+            if read == 0 {
+                return Err(StorageError::CorruptData);
+            }
+            crc = crc32_continue(crc, &chunk[..read]);
+            offset += read as u32;
+        }
+
+        if !crc == book.crc32 {
+            Ok(())
+        } else {
+            Err(StorageError::CorruptData)
+        }
+    }
+
+    pub fn open(&mut self, book: &FlashBook) -> FlashBookFile<'_, F> {
+        FlashBookFile {
+            start: self.payload_offset(book.slot),
+            len: book.size_bytes,
+            flash: &mut self.flash,
+        }
+    }
+
+    // Starts copying a package of `size_bytes` into flash, replacing an older copy of the same
+    // content, then an empty slot, then whichever book was stored longest ago. Nothing is erased
+    // until the first `step`.
+    pub fn begin_store(
+        &mut self,
+        content_id: &str,
+        title: &str,
+        remote_revision: u64,
+        size_bytes: u32,
+    ) -> Result<BookWrite, StorageError> {
+        if size_bytes == 0 || size_bytes > self.capacity_bytes() {
+            return Err(StorageError::PayloadTooLarge);
+        }
+
+        let books = self.books();
+        let slot = choose_slot(&books, content_id);
+        let stored_seq = books
+            .iter()
+            .flatten()
+            .map(|book| book.stored_seq)
+            .max()
+            .map_or(0, |seq| seq.wrapping_add(1));
+
+        let mut book = FlashBook {
+            slot,
+            content_id: InlineText::new(),
+            title: InlineText::new(),
+            remote_revision,
+            size_bytes,
+            crc32: 0,
+            stored_seq,
+        };
+        book.content_id.set_truncated(content_id);
+        book.title.set_truncated(title);
+
+        let slot_start = self.slot_offset(slot);
+        Ok(BookWrite {
+            book,
+            erased_to: slot_start,
+            erase_end: self.payload_offset(slot)
+                + size_bytes.next_multiple_of(F::ERASE_SIZE as u32),
+            copied: 0,
+            crc: 0xFFFF_FFFF,
+        })
+    }
+
+    // Erases or copies up to `BOOK_STEP_BYTES`, or commits the header once the payload is in.
+    // Returns the stored book after the commit and `None` while there is more to do.
+    pub fn step<S: FileSource + ?Sized>(
+        &mut self,
+        write: &mut BookWrite,
+        source: &mut S,
+    ) -> Result<Option<FlashBook>, StorageError> {
+        if write.erased_to < write.erase_end {
+            let to = write.erase_end.min(write.erased_to + BOOK_STEP_BYTES);
+            self.flash
+                .erase(write.erased_to, to)
+                .map_err(|_| StorageError::FlashFailure)?;
+            write.erased_to = to;
+            return Ok(None);
+        }
+
+        let size_bytes = write.book.size_bytes;
+        if write.copied < size_bytes {
+            let payload_start = self.payload_offset(write.book.slot);
+            let step_end = size_bytes.min(write.copied + BOOK_STEP_BYTES);
+            let mut chunk = [0u8; BOOK_COPY_CHUNK_LEN];
+            while write.copied < step_end {
+                let len = ((step_end - write.copied) as usize).min(BOOK_COPY_CHUNK_LEN);
+                source.read_exact_at(write.copied, &mut chunk[..len])?;
+                write.crc = crc32_continue(write.crc, &chunk[..len]);
+                let padded = len.next_multiple_of(F::WRITE_SIZE);
+                chunk[len..padded].fill(0xFF);
+                self.flash
+                    .write(payload_start + write.copied, &chunk[..padded])
+                    .map_err(|_| StorageError::FlashFailure)?;
+                write.copied += len as u32;
+            }
+            return Ok(None);
+        }
+
+        let mut book = write.book;
+        book.crc32 = !write.crc;
+        let slot_start = self.slot_offset(book.slot);
+        let mut header = [0u8; BOOK_HEADER_LEN];
+        encode_header(&book, &mut header);
+        self.flash
+            .write(slot_start, &header[..BOOK_HEADER_COMMIT_OFFSET])
+            .map_err(|_| StorageError::FlashFailure)?;
+        self.flash
+            .write(
+                slot_start + BOOK_HEADER_COMMIT_OFFSET as u32,
+                &BOOK_COMMIT_MAGIC.to_le_bytes(),
+            )
+            .map_err(|_| StorageError::FlashFailure)?;
+        Ok(Some(book))
+    }
+
+    pub fn remove(&mut self, slot: u8) -> Result<(), StorageError> {
+        if slot as usize >= BOOK_SLOT_COUNT {
+            return Err(StorageError::InvalidPartition);
+        }
+        let slot_start = self.slot_offset(slot);
+        self.flash
+            .erase(slot_start, slot_start + F::ERASE_SIZE as u32)
+            .map_err(|_| StorageError::FlashFailure)
+    }
+
+    // Factory reset: drops every slot's header as `remove` does. The payloads stay until the slot
+    // is next written, but with no header nothing reads them back.
+    pub fn clear(&mut self) -> Result<(), StorageError> {
+        for slot in 0..BOOK_SLOT_COUNT as u8 {
+            self.remove(slot)?;
+        }
+        Ok(())
+    }
+
+    fn read_header(&mut self, slot: u8) -> Result<Option<FlashBook>, StorageError> {
+        let mut header = [0u8; BOOK_HEADER_LEN];
+        self.flash
+            .read(self.slot_offset(slot), &mut header)
+            .map_err(|_| StorageError::FlashFailure)?;
+        Ok(decode_header(slot, &header, self.capacity_bytes()))
+    }
+
+    fn slot_offset(&self, slot: u8) -> u32 {
+        self.offset + slot as u32 * self.slot_len
+    }
+
+    fn payload_offset(&self, slot: u8) -> u32 {
+        self.slot_offset(slot) + F::ERASE_SIZE as u32
+    }
+}
+
+// Reads one stored package. Flash reads must be aligned, so arbitrary offsets go through a small
+// bounce buffer.
+pub struct FlashBookFile<'a, F> {
+    flash: &'a mut F,
+    start: u32,
+    len: u32,
+}
+
+impl<F: ReadNorFlash> FileSource for FlashBookFile<'_, F> {
+    fn byte_len(&self) -> u32 {
+        self.len
+    }
+
+    fn read_at(&mut self, offset: u32, out: &mut [u8]) -> Result<usize, StorageError> {
+        if offset >= self.len {
+            return Ok(0);
+        }
+
+        let wanted = out.len().min((self.len - offset) as usize);
+        let absolute = self.start + offset;
+        let aligned = absolute - absolute % F::READ_SIZE as u32;
+        let lead = (absolute - aligned) as usize;
+        let span = (lead + wanted).min(BOOK_READ_BOUNCE_LEN);
+        let mut bounce = [0u8; BOOK_READ_BOUNCE_LEN];
+        self.flash
+            .read(aligned, &mut bounce[..span.next_multiple_of(F::READ_SIZE)])
+            .map_err(|_| StorageError::FlashFailure)?;
+
+        let read = span - lead;
+        out[..read].copy_from_slice(&bounce[lead..span]);
+        Ok(read)
+    }
+}
+
+fn choose_slot(books: &[Option<FlashBook>; BOOK_SLOT_COUNT], content_id: &str) -> u8 {
+    if let Some(book) = books
+        .iter()
+        .flatten()
+        .find(|book| book.content_id.as_str() == content_id)
+    {
+        return book.slot;
+    }
+    if let Some(slot) = books.iter().position(Option::is_none) {
+        return slot as u8;
+    }
+    books
+        .iter()
+        .flatten()
+        .min_by_key(|book| book.stored_seq)
+        .map_or(0, |book| book.slot)
+}
+
+fn encode_header(book: &FlashBook, out: &mut [u8; BOOK_HEADER_LEN]) {
+    out.fill(0xFF);
+    out[0..4].copy_from_slice(&BOOK_MAGIC.to_le_bytes());
+    out[4..6].copy_from_slice(&BOOK_FORMAT_VERSION.to_le_bytes());
+    out[6] = book.content_id.len() as u8;
+    out[7] = book.title.len() as u8;
+    out[8..16].copy_from_slice(&book.remote_revision.to_le_bytes());
+    out[16..20].copy_from_slice(&book.size_bytes.to_le_bytes());
+    out[20..24].copy_from_slice(&book.crc32.to_le_bytes());
+    out[24..28].copy_from_slice(&book.stored_seq.to_le_bytes());
+    let id_start = 28;
+    out[id_start..id_start + book.content_id.len()]
+        .copy_from_slice(book.content_id.as_str().as_bytes());
+    let title_start = id_start + CONTENT_ID_MAX_BYTES;
+    out[title_start..title_start + book.title.len()]
+        .copy_from_slice(book.title.as_str().as_bytes());
+}
+
+fn decode_header(slot: u8, bytes: &[u8; BOOK_HEADER_LEN], capacity: u32) -> Option<FlashBook> {
+    let word = |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+    if word(0) != BOOK_MAGIC
+        || word(BOOK_HEADER_COMMIT_OFFSET) != BOOK_COMMIT_MAGIC
+        || u16::from_le_bytes([bytes[4], bytes[5]]) != BOOK_FORMAT_VERSION
+    {
+        return None;
+    }
+
+    let id_len = bytes[6] as usize;
+    let title_len = bytes[7] as usize;
+    let size_bytes = word(16);
+    if id_len == 0
+        || id_len > CONTENT_ID_MAX_BYTES
+        || title_len > CONTENT_TITLE_MAX_BYTES
+        || size_bytes == 0
+        || size_bytes > capacity
+    {
+        return None;
+    }
+
+    let id_start = 28;
+    let title_start = id_start + CONTENT_ID_MAX_BYTES;
+    let content_id = core::str::from_utf8(&bytes[id_start..id_start + id_len]).ok()?;
+    let title = core::str::from_utf8(&bytes[title_start..title_start + title_len]).ok()?;
+    let mut book = FlashBook {
+        slot,
+        content_id: InlineText::new(),
+        title: InlineText::new(),
+        remote_revision: u64::from_le_bytes(bytes[8..16].try_into().unwrap()),
+        size_bytes,
+        crc32: word(20),
+        stored_seq: word(24),
+    };
+    book.content_id.set_truncated(content_id);
+    book.title.set_truncated(title);
+    Some(book)
+}
+
+fn crc32_continue(current: u32, bytes: &[u8]) -> u32 {
+    let mut crc = current;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg() & 0xEDB8_8320;
+            crc = (crc >> 1) ^ mask;
+        }
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::{vec, vec::Vec};
+    use embedded_storage::nor_flash::ErrorType;
+
+    const SECTOR: usize = 4096;
+    const PARTITION_LEN: u32 = 3 * 4 * SECTOR as u32;
+
+    struct EmuFlash {
+        bytes: Vec<u8>,
+        erases: Vec<(u32, u32)>,
+    }
+
+    impl EmuFlash {
+        fn new() -> Self {
+            Self {
+                bytes: vec![0xFF; PARTITION_LEN as usize],
+                erases: Vec::new(),
+            }
+        }
+    }
+
+    impl ErrorType for EmuFlash {
+        type Error = StorageError;
+    }
+
+    impl ReadNorFlash for EmuFlash {
+        const READ_SIZE: usize = 4;
+
+        fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+            let offset = offset as usize;
+            if !offset.is_multiple_of(Self::READ_SIZE)
+                || !bytes.len().is_multiple_of(Self::READ_SIZE)
+            {
+                return Err(StorageError::InvalidPartition);
+            }
+            bytes.copy_from_slice(&self.bytes[offset..offset + bytes.len()]);
+            Ok(())
+        }
+
+        fn capacity(&self) -> usize {
+            self.bytes.len()
+        }
+    }
+
+    impl NorFlash for EmuFlash {
+        const WRITE_SIZE: usize = 4;
+        const ERASE_SIZE: usize = SECTOR;
+
+        fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+            self.bytes[from as usize..to as usize].fill(0xFF);
+            self.erases.push((from, to));
+            Ok(())
+        }
+
+        fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+            let offset = offset as usize;
+            if !offset.is_multiple_of(Self::WRITE_SIZE)
+                || !bytes.len().is_multiple_of(Self::WRITE_SIZE)
+            {
+                return Err(StorageError::InvalidPartition);
+            }
+            for (index, byte) in bytes.iter().enumerate() {
+                self.bytes[offset + index] &= *byte;
+            }
+            Ok(())
+        }
+    }
+
+    fn store(
+        books: &mut BookPartition<EmuFlash>,
+        content_id: &str,
+        title: &str,
+        remote_revision: u64,
+        bytes: &[u8],
+    ) -> Result<FlashBook, StorageError> {
+        let mut write =
+            books.begin_store(content_id, title, remote_revision, bytes.len() as u32)?;
+        let mut source = bytes;
+        loop {
+            if let Some(book) = books.step(&mut write, &mut source)? {
+                return Ok(book);
+            }
+        }
+    }
+
+    fn payload(len: usize, seed: u8) -> Vec<u8> {
+        (0..len)
+            .map(|index| (index as u8).wrapping_add(seed))
+            .collect()
+    }
+
+    #[test]
+    fn stored_book_reads_back_at_unaligned_offsets() {
+        let mut books = BookPartition::new(EmuFlash::new(), 0, PARTITION_LEN).unwrap();
+        let bytes = payload(5_001, 7);
+
+        let book = store(&mut books, "content-1", "First book", 3, &bytes).unwrap();
+        assert_eq!(books.find("content-1"), Some(book));
+        assert_eq!(book.title.as_str(), "First book");
+        assert_eq!(books.verify(&book), Ok(()));
+
+        let mut file = books.open(&book);
+        let mut out = [0u8; 301];
+        file.read_exact_at(4_699, &mut out).unwrap();
+        assert_eq!(&out[..], &bytes[4_699..5_000]);
+        assert_eq!(file.read_at(5_001, &mut out).unwrap(), 0);
+    }
+
+    #[test]
+    fn full_partition_replaces_the_oldest_book() {
+        let mut books = BookPartition::new(EmuFlash::new(), 0, PARTITION_LEN).unwrap();
+        for (index, id) in ["a", "b", "c", "d"].into_iter().enumerate() {
+            store(&mut books, id, id, 1, &payload(64, index as u8)).unwrap();
+        }
+
+        let ids: Vec<_> = books
+            .books()
+            .into_iter()
+            .flatten()
+            .map(|book| book.content_id)
+            .collect();
+        assert_eq!(ids.len(), BOOK_SLOT_COUNT);
+        assert!(ids.iter().all(|id| id.as_str() != "a"));
+        assert!(books.find("d").is_some());
+    }
+
+    #[test]
+    fn uncommitted_or_removed_slots_read_as_empty() {
+        let mut books = BookPartition::new(EmuFlash::new(), 0, PARTITION_LEN).unwrap();
+        let book = store(&mut books, "torn", "Torn", 1, &payload(64, 0)).unwrap();
+        let commit = book.slot as usize * books.slot_len as usize + BOOK_HEADER_COMMIT_OFFSET;
+        books.flash.bytes[commit..commit + 4].fill(0xFF);
+        assert!(books.find("torn").is_none());
+
+        let book = store(&mut books, "kept", "Kept", 1, &payload(64, 0)).unwrap();
+        books.remove(book.slot).unwrap();
+        assert!(books.books().iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn clear_drops_every_slot_header_and_nothing_more() {
+        let mut books = BookPartition::new(EmuFlash::new(), 0, PARTITION_LEN).unwrap();
+        for (index, content_id) in ["a", "b", "c"].into_iter().enumerate() {
+            store(
+                &mut books,
+                content_id,
+                "Book",
+                1,
+                &payload(6_000, index as u8),
+            )
+            .unwrap();
+        }
+        books.flash.erases.clear();
+
+        books.clear().unwrap();
+
+        assert!(books.books().iter().all(Option::is_none));
+        let sector = SECTOR as u32;
+        let headers: Vec<_> = (0..BOOK_SLOT_COUNT as u8)
+            .map(|slot| (books.slot_offset(slot), books.slot_offset(slot) + sector))
+            .collect();
+        assert_eq!(books.flash.erases, headers);
+    }
+
+    #[test]
+    fn store_erases_only_the_sectors_the_payload_needs_in_bounded_steps() {
+        let partition_len = 3 * 64 * SECTOR as u32;
+        let flash = EmuFlash {
+            bytes: vec![0xFF; partition_len as usize],
+            erases: Vec::new(),
+        };
+        let mut books = BookPartition::new(flash, 0, partition_len).unwrap();
+        let bytes = payload(150_000, 3);
+
+        let book = store(&mut books, "long", "Long", 1, &bytes).unwrap();
+
+        let erases = &books.flash.erases;
+        let slot_start = books.slot_offset(book.slot);
+        let payload_end =
+            books.payload_offset(book.slot) + 150_000u32.next_multiple_of(SECTOR as u32);
+        assert!(erases.len() > 1);
+        assert!(erases.iter().all(|(from, to)| to - from <= BOOK_STEP_BYTES));
+        assert_eq!(erases.first().map(|(from, _)| *from), Some(slot_start));
+        assert!(erases.windows(2).all(|pair| pair[0].1 == pair[1].0));
+        assert_eq!(erases.last().map(|(_, to)| *to), Some(payload_end));
+        assert!(payload_end < books.slot_offset(book.slot + 1));
+        assert_eq!(books.verify(&book), Ok(()));
+    }
+
+    #[test]
+    fn verify_rejects_a_flipped_payload_bit() {
+        let mut books = BookPartition::new(EmuFlash::new(), 0, PARTITION_LEN).unwrap();
+        let book = store(&mut books, "content", "Content", 1, &payload(300, 1)).unwrap();
+        let payload_start = books.payload_offset(book.slot) as usize;
+        books.flash.bytes[payload_start + 299] = 0;
+
+        assert_eq!(books.verify(&book), Err(StorageError::CorruptData));
+    }
+
+    #[test]
+    fn oversized_package_is_rejected_before_erasing() {
+        let mut books = BookPartition::new(EmuFlash::new(), 0, PARTITION_LEN).unwrap();
+        let too_big = vec![0u8; books.capacity_bytes() as usize + 1];
+
+        assert_eq!(
+            store(&mut books, "big", "Big", 1, &too_big).unwrap_err(),
+            StorageError::PayloadTooLarge
+        );
+        assert!(books.flash.erases.is_empty());
+    }
+}
@@ -0,0 +1,101 @@
+use domain::clock::DateTime;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ClockError {
+    Unsupported,
+    Bus,
+    // The chip answered but lost power or was never set, so its registers are not a real time.
+    NotSet,
+}
+
+pub trait Clock {
+    fn now(&mut self) -> Result<DateTime, ClockError>;
+}
+
+#[derive(Debug, Default)]
+pub struct NoopClock;
+
+impl Clock for NoopClock {
+    fn now(&mut self) -> Result<DateTime, ClockError> {
+        Err(ClockError::Unsupported)
+    }
+}
+
+// DS3231 registers 0x00 to 0x06: seconds, minutes, hours, weekday, date, month (bit 7 is the
+// century flag), year. The hours register may be in 12-hour mode (bit 6) with bit 5 as PM.
+pub fn ds3231_date_time(registers: &[u8; 7]) -> Option<DateTime> {
+    let hours = registers[2];
+    let hour = if hours & 0x40 != 0 {
+        let hour = bcd(hours & 0x1F)? % 12;
+        if hours & 0x20 != 0 { hour + 12 } else { hour }
+    } else {
+        bcd(hours & 0x3F)?
+    };
+
+    DateTime::new(
+        2000 + u16::from(bcd(registers[6])?),
+        bcd(registers[5] & 0x1F)?,
+        bcd(registers[4] & 0x3F)?,
+        hour,
+        bcd(registers[1] & 0x7F)?,
+        bcd(registers[0] & 0x7F)?,
+    )
+}
+
+// PCF8563 registers 0x02 to 0x08: seconds (bit 7 is VL, set when the oscillator stopped), minutes,
+// hours, days, weekdays, century and month, years.
+pub fn pcf8563_date_time(registers: &[u8; 7]) -> Option<DateTime> {
+    if registers[0] & 0x80 != 0 {
+        return None;
+    }
+
+    DateTime::new(
+        2000 + u16::from(bcd(registers[6])?),
+        bcd(registers[5] & 0x1F)?,
+        bcd(registers[3] & 0x3F)?,
+        bcd(registers[2] & 0x3F)?,
+        bcd(registers[1] & 0x7F)?,
+        bcd(registers[0] & 0x7F)?,
+    )
+}
+
+fn bcd(value: u8) -> Option<u8> {
+    let (tens, ones) = (value >> 4, value & 0x0F);
+    (tens <= 9 && ones <= 9).then_some(tens * 10 + ones)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ds3231_registers_decode_in_both_hour_modes() {
+        let time = ds3231_date_time(&[0x45, 0x30, 0x21, 0x05, 0x16, 0x10, 0x26]).unwrap();
+        assert_eq!((time.year(), time.month(), time.day()), (2026, 10, 16));
+        assert_eq!(time.local_time().label().as_str(), "21:30");
+        assert_eq!(time.second(), 45);
+
+        // 12-hour mode: 12 AM is midnight and 9 PM is 21.
+        let midnight = ds3231_date_time(&[0, 0, 0x52, 1, 0x01, 0x01, 0x26]).unwrap();
+        assert_eq!(midnight.local_time().hour(), 0);
+        let evening = ds3231_date_time(&[0, 0, 0x69, 1, 0x01, 0x01, 0x26]).unwrap();
+        assert_eq!(evening.local_time().hour(), 21);
+
+        assert_eq!(
+            ds3231_date_time(&[0x00, 0x00, 0x00, 0x01, 0x31, 0x02, 0x26]),
+            None
+        );
+    }
+
+    #[test]
+    fn pcf8563_reports_a_stopped_oscillator_as_unset() {
+        let time = pcf8563_date_time(&[0x07, 0x59, 0x06, 0x29, 0x02, 0x82, 0x28]).unwrap();
+        assert_eq!((time.year(), time.month(), time.day()), (2028, 2, 29));
+        assert_eq!(time.local_time().label().as_str(), "06:59");
+
+        assert_eq!(
+            pcf8563_date_time(&[0x87, 0x59, 0x06, 0x29, 0x02, 0x02, 0x28]),
+            None
+        );
+    }
+}
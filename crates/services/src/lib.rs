@@ -2,11 +2,16 @@
 #![allow(dead_code)]
 #![allow(async_fn_in_trait)]
 
+pub mod audio;
 pub mod backend_sync;
+pub mod book_flash;
+pub mod clock;
 pub mod formatter;
 pub mod input;
+pub mod light;
 pub mod power;
 pub mod provisioning;
+pub mod reader_content;
 pub mod sleep;
 pub mod storage;
 pub mod wifi;
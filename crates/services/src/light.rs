@@ -0,0 +1,40 @@
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LightSensorError {
+    Unsupported,
+    Bus,
+}
+
+pub trait AmbientLightSensor {
+    fn lux(&mut self) -> Result<u32, LightSensorError>;
+}
+
+#[derive(Debug, Default)]
+pub struct NoopAmbientLightSensor;
+
+impl AmbientLightSensor for NoopAmbientLightSensor {
+    fn lux(&mut self) -> Result<u32, LightSensorError> {
+        Err(LightSensorError::Unsupported)
+    }
+}
+
+// VEML7700 configuration word: gain 1/8 (bits 12:11 = 0b10), 100 ms integration (bits 9:6 = 0),
+// powered on (bit 0 clear). That gain covers direct sunlight without switching ranges.
+pub const VEML7700_CONFIG: u16 = 0x1000;
+
+// ALS counts to lux at the configuration above: 0.4608 lx per count. Only the low end matters
+// for the appearance switch, so the non-linearity correction above 1000 lx is left out.
+pub const fn veml7700_lux(counts: u16) -> u32 {
+    counts as u32 * 4_608 / 10_000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn veml7700_counts_scale_at_the_low_gain() {
+        assert_eq!(veml7700_lux(0), 0);
+        assert_eq!(veml7700_lux(22), 10);
+        assert_eq!(veml7700_lux(u16::MAX), 30_198);
+    }
+}
@@ -17,3 +17,23 @@ impl PowerService for NoopPowerService {
         PowerStatus::Unsupported
     }
 }
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PowerMonitorError {
+    Unsupported,
+    Bus,
+    InvalidReading,
+}
+
+pub trait PowerMonitor {
+    fn read(&mut self) -> Result<domain::power::PowerStatus, PowerMonitorError>;
+}
+
+#[derive(Debug, Default)]
+pub struct NoopPowerMonitor;
+
+impl PowerMonitor for NoopPowerMonitor {
+    fn read(&mut self) -> Result<domain::power::PowerStatus, PowerMonitorError> {
+        Err(PowerMonitorError::Unsupported)
+    }
+}
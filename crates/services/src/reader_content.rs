@@ -0,0 +1,1019 @@
+extern crate alloc;
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use domain::{
+    content::CONTENT_TITLE_MAX_BYTES,
+    formatter::{FormatPreferences, FormatRules, MAX_READING_PARAGRAPHS, ReadingDocument},
+    text::{HtmlTextFilter, InlineText},
+};
+use log::info;
+
+use crate::storage::{FileSource, StorageError};
+
+// Parser for reader-content JSON cached on the SD card. The payload came off the network and sits
+// on a removable card, so it stays free of hardware types and runs on the host under `fuzz/`.
+
+pub const PACKAGE_READ_BUFFER_LEN: usize = 512;
+const MAX_JSON_KEY_BYTES: usize = 16;
+// Values the reader ignores are skipped recursively; bound the nesting so a hostile payload
+// cannot walk the storage task off its stack.
+const MAX_JSON_SKIP_DEPTH: usize = 32;
+const MAX_PARSED_TITLE_BYTES: usize = CONTENT_TITLE_MAX_BYTES * 4;
+// Long enough for any tag the pause rules key on; longer private-use tags are cut.
+const MAX_LANGUAGE_TAG_BYTES: usize = 16;
+// Keep per-block scratch bounded independently from the whole-document capacity.
+// We want much larger articles overall without allowing a single paragraph parse
+// to balloon peak heap usage in lockstep with MAX_READING_UNITS.
+pub const MAX_PARSED_BLOCK_TEXT_BYTES: usize = 8 * 1024;
+pub const MAX_PARSED_LIST_ITEMS: usize = MAX_READING_PARAGRAPHS;
+pub const MAX_PARSED_LIST_TOTAL_BYTES: usize = 16 * 1024;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OpenedReaderContent {
+    pub title: InlineText<CONTENT_TITLE_MAX_BYTES>,
+    pub document: Box<ReadingDocument>,
+    pub truncated: bool,
+}
+
+pub fn parse_reader_content_bytes(bytes: &[u8]) -> Result<OpenedReaderContent, StorageError> {
+    parse_opened_reader_content(bytes, FormatPreferences::default())
+}
+
+#[derive(Debug, Default)]
+struct ParsedString {
+    value: String,
+    truncated: bool,
+}
+
+impl ParsedString {
+    fn push_char(&mut self, ch: char, max_bytes: usize, html: Option<&mut HtmlTextFilter>) {
+        let value = &mut self.value;
+        let truncated = &mut self.truncated;
+        match html {
+            Some(filter) => filter.push(ch, &mut |decoded| {
+                push_limited_char(value, decoded, max_bytes, truncated)
+            }),
+            None => push_limited_char(value, ch, max_bytes, truncated),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+enum BlockKind {
+    Heading,
+    Text,
+    List,
+    #[default]
+    Other,
+}
+
+#[derive(Debug, Default)]
+struct BlockDraft {
+    kind: BlockKind,
+    text: Option<String>,
+    ordered: bool,
+    items: Vec<String>,
+    truncated: bool,
+    list_bytes: usize,
+}
+
+impl BlockDraft {
+    fn parse<S: FileSource>(stream: &mut JsonStream<S>) -> Result<Self, StorageError> {
+        let mut draft = Self::default();
+        stream.parse_object_fields(|stream, key| match key.as_str() {
+            "t" => {
+                let parsed = stream.parse_string_limited(8)?;
+                if parsed.truncated {
+                    draft.truncated = true;
+                }
+                draft.kind = match parsed.value.as_str() {
+                    "h" => BlockKind::Heading,
+                    "p" | "q" | "c" => BlockKind::Text,
+                    "l" => BlockKind::List,
+                    _ => BlockKind::Other,
+                };
+                Ok(())
+            }
+            "x" => {
+                let parsed = stream.parse_text_string_limited(MAX_PARSED_BLOCK_TEXT_BYTES)?;
+                if parsed.truncated {
+                    draft.truncated = true;
+                }
+                draft.text = Some(parsed.value);
+                Ok(())
+            }
+            "o" => {
+                draft.ordered = stream.parse_bool()?;
+                Ok(())
+            }
+            "i" => draft.parse_list_items(stream),
+            _ => stream.skip_value(),
+        })?;
+        Ok(draft)
+    }
+
+    fn parse_list_items<S: FileSource>(
+        &mut self,
+        stream: &mut JsonStream<S>,
+    ) -> Result<(), StorageError> {
+        stream.parse_array_values(|stream, first| {
+            if first != b'"' {
+                return Err(StorageError::CorruptData);
+            }
+
+            let parsed = stream.parse_text_string_body_limited(MAX_PARSED_BLOCK_TEXT_BYTES)?;
+            if parsed.truncated {
+                self.truncated = true;
+            }
+
+            let trimmed = parsed.value.trim();
+            if trimmed.is_empty() {
+                return Ok(());
+            }
+
+            if self.items.len() >= MAX_PARSED_LIST_ITEMS
+                || self.list_bytes.saturating_add(trimmed.len()) > MAX_PARSED_LIST_TOTAL_BYTES
+            {
+                self.truncated = true;
+                return Ok(());
+            }
+
+            self.list_bytes = self.list_bytes.saturating_add(trimmed.len());
+            self.items.push(String::from(trimmed));
+            Ok(())
+        })
+    }
+
+    fn apply(self, document: &mut ReadingDocument, rules: &FormatRules<'_>) -> bool {
+        let mut truncated = self.truncated;
+
+        match self.kind {
+            BlockKind::Heading => {
+                if let Some(text) = self.text {
+                    let trimmed = text.trim();
+                    if !trimmed.is_empty() && !document.push_heading_text_with_rules(trimmed, rules)
+                    {
+                        truncated = true;
+                    }
+                }
+            }
+            BlockKind::Text => {
+                if let Some(text) = self.text {
+                    let trimmed = text.trim();
+                    if !trimmed.is_empty()
+                        && !document.push_paragraph_text_with_rules(trimmed, rules)
+                    {
+                        truncated = true;
+                    }
+                }
+            }
+            BlockKind::List => {
+                let mut item_index = 0usize;
+                while item_index < self.items.len() {
+                    let line = format_list_line(&self.items[item_index], self.ordered, item_index);
+                    if !document.push_paragraph_text_with_rules(line.as_str(), rules) {
+                        truncated = true;
+                        break;
+                    }
+                    item_index += 1;
+                }
+            }
+            BlockKind::Other => {}
+        }
+
+        truncated
+    }
+}
+
+struct JsonStream<S> {
+    source: S,
+    offset: u32,
+    buffer: [u8; PACKAGE_READ_BUFFER_LEN],
+    cursor: usize,
+    buffered: usize,
+    unread: Option<u8>,
+}
+
+impl<S: FileSource> JsonStream<S> {
+    fn new(source: S) -> Self {
+        Self {
+            source,
+            offset: 0,
+            buffer: [0u8; PACKAGE_READ_BUFFER_LEN],
+            cursor: 0,
+            buffered: 0,
+            unread: None,
+        }
+    }
+
+    fn next_byte(&mut self) -> Result<Option<u8>, StorageError> {
+        if let Some(byte) = self.unread.take() {
+            return Ok(Some(byte));
+        }
+
+        if self.cursor == self.buffered {
+            self.buffered = self.source.read_at(self.offset, &mut self.buffer)?;
+            self.offset = self.offset.saturating_add(self.buffered as u32);
+            self.cursor = 0;
+            if self.buffered == 0 {
+                return Ok(None);
+            }
+        }
+
+        let byte = self.buffer[self.cursor];
+        self.cursor += 1;
+        Ok(Some(byte))
+    }
+
+    fn unread_byte(&mut self, byte: u8) {
+        debug_assert!(self.unread.is_none());
+        self.unread = Some(byte);
+    }
+
+    fn next_significant_byte(&mut self) -> Result<Option<u8>, StorageError> {
+        loop {
+            let Some(byte) = self.next_byte()? else {
+                return Ok(None);
+            };
+            if !byte.is_ascii_whitespace() {
+                return Ok(Some(byte));
+            }
+        }
+    }
+
+    fn expect_byte(&mut self, expected: u8) -> Result<(), StorageError> {
+        let byte = self
+            .next_significant_byte()?
+            .ok_or(StorageError::CorruptData)?;
+        if byte != expected {
+            return Err(StorageError::CorruptData);
+        }
+        Ok(())
+    }
+
+    fn parse_bool(&mut self) -> Result<bool, StorageError> {
+        let first = self
+            .next_significant_byte()?
+            .ok_or(StorageError::CorruptData)?;
+        self.parse_bool_from(first)
+    }
+
+    fn parse_bool_from(&mut self, first: u8) -> Result<bool, StorageError> {
+        match first {
+            b't' => {
+                self.expect_literal(b"rue")?;
+                Ok(true)
+            }
+            b'f' => {
+                self.expect_literal(b"alse")?;
+                Ok(false)
+            }
+            _ => Err(StorageError::CorruptData),
+        }
+    }
+
+    fn parse_string_limited(&mut self, max_bytes: usize) -> Result<ParsedString, StorageError> {
+        self.expect_string_opening()?;
+        self.parse_string_body_limited(max_bytes)
+    }
+
+    fn parse_text_string_limited(
+        &mut self,
+        max_bytes: usize,
+    ) -> Result<ParsedString, StorageError> {
+        self.expect_string_opening()?;
+        self.parse_text_string_body_limited(max_bytes)
+    }
+
+    fn expect_string_opening(&mut self) -> Result<(), StorageError> {
+        let opening = self
+            .next_significant_byte()?
+            .ok_or(StorageError::CorruptData)?;
+        if opening != b'"' {
+            return Err(StorageError::CorruptData);
+        }
+        Ok(())
+    }
+
+    fn parse_string_body_limited(
+        &mut self,
+        max_bytes: usize,
+    ) -> Result<ParsedString, StorageError> {
+        self.parse_string_body(max_bytes, None)
+    }
+
+    // Reader-visible block text also strips markup and decodes HTML entities
+    // left in the source; the filter state lives across `next_byte` refills.
+    fn parse_text_string_body_limited(
+        &mut self,
+        max_bytes: usize,
+    ) -> Result<ParsedString, StorageError> {
+        self.parse_string_body(max_bytes, Some(HtmlTextFilter::new()))
+    }
+
+    fn parse_string_body(
+        &mut self,
+        max_bytes: usize,
+        mut html: Option<HtmlTextFilter>,
+    ) -> Result<ParsedString, StorageError> {
+        let mut parsed = ParsedString {
+            value: String::new(),
+            truncated: false,
+        };
+
+        loop {
+            let byte = self.next_byte()?.ok_or(StorageError::CorruptData)?;
+            match byte {
+                b'"' => {
+                    if let Some(filter) = html.as_mut() {
+                        filter.finish(&mut |pending| {
+                            push_limited_char(
+                                &mut parsed.value,
+                                pending,
+                                max_bytes,
+                                &mut parsed.truncated,
+                            )
+                        });
+                    }
+                    return Ok(parsed);
+                }
+                b'\\' => {
+                    let escaped = self.next_byte()?.ok_or(StorageError::CorruptData)?;
+                    match escaped {
+                        b'"' | b'\\' | b'/' => {
+                            parsed.push_char(escaped as char, max_bytes, html.as_mut());
+                        }
+                        b'b' | b'f' | b'n' | b'r' | b't' => {
+                            parsed.push_char(' ', max_bytes, html.as_mut());
+                        }
+                        b'u' => {
+                            let codepoint = self.parse_unicode_escape()?;
+                            parsed.push_char(
+                                char::from_u32(codepoint).unwrap_or('?'),
+                                max_bytes,
+                                html.as_mut(),
+                            );
+                        }
+                        _ => return Err(StorageError::CorruptData),
+                    }
+                }
+                byte if byte.is_ascii() => {
+                    parsed.push_char(byte as char, max_bytes, html.as_mut());
+                }
+                byte => {
+                    let continuation = utf8_continuation_len(byte)?;
+                    let mut utf8 = [0u8; 4];
+                    utf8[0] = byte;
+                    let mut index = 0usize;
+                    while index < continuation {
+                        let next = self.next_byte()?.ok_or(StorageError::CorruptData)?;
+                        if next & 0b1100_0000 != 0b1000_0000 {
+                            return Err(StorageError::CorruptData);
+                        }
+                        utf8[index + 1] = next;
+                        index += 1;
+                    }
+                    let text = core::str::from_utf8(&utf8[..continuation + 1])
+                        .map_err(|_| StorageError::CorruptData)?;
+                    for ch in text.chars() {
+                        parsed.push_char(ch, max_bytes, html.as_mut());
+                    }
+                }
+            }
+        }
+    }
+
+    fn parse_object_fields<F>(&mut self, mut handler: F) -> Result<(), StorageError>
+    where
+        F: FnMut(&mut Self, String) -> Result<(), StorageError>,
+    {
+        self.expect_byte(b'{')?;
+        let mut first = true;
+
+        loop {
+            let next = self
+                .next_significant_byte()?
+                .ok_or(StorageError::CorruptData)?;
+            match next {
+                b'}' => return Ok(()),
+                b',' if !first => {}
+                _ if first => self.unread_byte(next),
+                _ => return Err(StorageError::CorruptData),
+            }
+
+            let opening = self
+                .next_significant_byte()?
+                .ok_or(StorageError::CorruptData)?;
+            if opening != b'"' {
+                return Err(StorageError::CorruptData);
+            }
+
+            let key = self.parse_string_body_limited(MAX_JSON_KEY_BYTES)?.value;
+            self.expect_byte(b':')?;
+            handler(self, key)?;
+            first = false;
+        }
+    }
+
+    fn parse_array_values<F>(&mut self, mut handler: F) -> Result<(), StorageError>
+    where
+        F: FnMut(&mut Self, u8) -> Result<(), StorageError>,
+    {
+        self.expect_byte(b'[')?;
+        let mut first = true;
+
+        loop {
+            let next = self
+                .next_significant_byte()?
+                .ok_or(StorageError::CorruptData)?;
+            match next {
+                b']' => return Ok(()),
+                b',' if !first => {}
+                _ if first => self.unread_byte(next),
+                _ => return Err(StorageError::CorruptData),
+            }
+
+            let first_byte = self
+                .next_significant_byte()?
+                .ok_or(StorageError::CorruptData)?;
+            handler(self, first_byte)?;
+            first = false;
+        }
+    }
+
+    fn skip_value(&mut self) -> Result<(), StorageError> {
+        self.skip_nested_value(0)
+    }
+
+    fn skip_nested_value(&mut self, depth: usize) -> Result<(), StorageError> {
+        let first = self
+            .next_significant_byte()?
+            .ok_or(StorageError::CorruptData)?;
+        self.skip_value_from(first, depth)
+    }
+
+    fn skip_value_from(&mut self, first: u8, depth: usize) -> Result<(), StorageError> {
+        match first {
+            b'{' | b'[' if depth >= MAX_JSON_SKIP_DEPTH => Err(StorageError::CorruptData),
+            b'{' => self.skip_object_body(depth + 1),
+            b'[' => self.skip_array_body(depth + 1),
+            b'"' => {
+                let _ = self.parse_string_body_limited(0)?;
+                Ok(())
+            }
+            b't' => self.expect_literal(b"rue"),
+            b'f' => self.expect_literal(b"alse"),
+            b'n' => self.expect_literal(b"ull"),
+            b'-' | b'0'..=b'9' => self.skip_number_from(first),
+            _ => Err(StorageError::CorruptData),
+        }
+    }
+
+    fn skip_object_body(&mut self, depth: usize) -> Result<(), StorageError> {
+        let mut first = true;
+        loop {
+            let next = self
+                .next_significant_byte()?
+                .ok_or(StorageError::CorruptData)?;
+            match next {
+                b'}' => return Ok(()),
+                b',' if !first => {}
+                _ if first => self.unread_byte(next),
+                _ => return Err(StorageError::CorruptData),
+            }
+
+            let opening = self
+                .next_significant_byte()?
+                .ok_or(StorageError::CorruptData)?;
+            if opening != b'"' {
+                return Err(StorageError::CorruptData);
+            }
+            let _ = self.parse_string_body_limited(0)?;
+            self.expect_byte(b':')?;
+            self.skip_nested_value(depth)?;
+            first = false;
+        }
+    }
+
+    fn skip_array_body(&mut self, depth: usize) -> Result<(), StorageError> {
+        let mut first = true;
+        loop {
+            let next = self
+                .next_significant_byte()?
+                .ok_or(StorageError::CorruptData)?;
+            match next {
+                b']' => return Ok(()),
+                b',' if !first => {}
+                _ if first => self.unread_byte(next),
+                _ => return Err(StorageError::CorruptData),
+            }
+
+            let first_byte = self
+                .next_significant_byte()?
+                .ok_or(StorageError::CorruptData)?;
+            self.skip_value_from(first_byte, depth)?;
+            first = false;
+        }
+    }
+
+    fn skip_number_from(&mut self, first: u8) -> Result<(), StorageError> {
+        if !matches!(first, b'-' | b'0'..=b'9') {
+            return Err(StorageError::CorruptData);
+        }
+
+        loop {
+            let Some(byte) = self.next_byte()? else {
+                return Ok(());
+            };
+            if byte.is_ascii_whitespace() || matches!(byte, b',' | b']' | b'}') {
+                self.unread_byte(byte);
+                return Ok(());
+            }
+            if !matches!(byte, b'0'..=b'9' | b'.' | b'e' | b'E' | b'+' | b'-') {
+                return Err(StorageError::CorruptData);
+            }
+        }
+    }
+
+    fn expect_literal(&mut self, literal: &[u8]) -> Result<(), StorageError> {
+        let mut index = 0usize;
+        while index < literal.len() {
+            let byte = self.next_byte()?.ok_or(StorageError::CorruptData)?;
+            if byte != literal[index] {
+                return Err(StorageError::CorruptData);
+            }
+            index += 1;
+        }
+        Ok(())
+    }
+
+    fn parse_unicode_escape(&mut self) -> Result<u32, StorageError> {
+        let mut value = 0u32;
+        let mut index = 0usize;
+        while index < 4 {
+            let byte = self.next_byte()?.ok_or(StorageError::CorruptData)?;
+            value = (value << 4) | hex_value(byte)? as u32;
+            index += 1;
+        }
+        Ok(value)
+    }
+}
+
+// `preferences` come from the reader's settings and override the book's language rules where
+// they overlap.
+pub fn parse_opened_reader_content<S: FileSource>(
+    source: S,
+    preferences: FormatPreferences,
+) -> Result<OpenedReaderContent, StorageError> {
+    let mut stream = JsonStream::new(source);
+    let mut title = InlineText::new();
+    // Blocks are formatted as they stream in, so `language` only applies when the package lists
+    // it before `body`, as the backend does.
+    let mut rules = FormatRules::default().with_preferences(preferences);
+    let mut document = ReadingDocument::boxed_empty();
+    let mut truncated = false;
+    let mut content_found = false;
+    let mut body_found = false;
+    let mut blocks_found = false;
+    let mut body_kind_supported = false;
+
+    let parse_result = stream.parse_object_fields(|stream, key| match key.as_str() {
+        "content" => {
+            content_found = true;
+            stream.parse_object_fields(|stream, key| match key.as_str() {
+                "title" => {
+                    let parsed = stream.parse_text_string_limited(MAX_PARSED_TITLE_BYTES)?;
+                    if parsed.truncated {
+                        truncated = true;
+                    }
+                    title.set_truncated(parsed.value.as_str());
+                    Ok(())
+                }
+                "language" => {
+                    let parsed = stream.parse_string_limited(MAX_LANGUAGE_TAG_BYTES)?;
+                    rules = FormatRules::for_language(parsed.value.as_str())
+                        .with_preferences(preferences);
+                    Ok(())
+                }
+                "body" => {
+                    body_found = true;
+                    stream.parse_object_fields(|stream, key| match key.as_str() {
+                        "kind" => {
+                            let parsed = stream.parse_string_limited(16)?;
+                            if parsed.truncated {
+                                truncated = true;
+                            }
+                            body_kind_supported =
+                                is_supported_reader_body_kind(parsed.value.as_str());
+                            Ok(())
+                        }
+                        "blocks" => {
+                            blocks_found = true;
+                            stream.parse_array_values(|stream, first| {
+                                if first != b'{' {
+                                    return Err(StorageError::CorruptData);
+                                }
+                                stream.unread_byte(first);
+                                let block = BlockDraft::parse(stream)?;
+                                if block.apply(&mut document, &rules) {
+                                    truncated = true;
+                                }
+                                Ok(())
+                            })
+                        }
+                        _ => stream.skip_value(),
+                    })
+                }
+                _ => stream.skip_value(),
+            })
+        }
+        _ => stream.skip_value(),
+    });
+    if let Err(err) = parse_result {
+        info!(
+            "content storage reader parse stream error content_found={} body_found={} blocks_found={} body_kind_supported={} title_empty={} document_empty={} truncated={} err={:?}",
+            content_found,
+            body_found,
+            blocks_found,
+            body_kind_supported,
+            title.is_empty(),
+            document.is_empty(),
+            truncated,
+            err,
+        );
+        return Err(err);
+    }
+
+    if !content_found || !body_found || !blocks_found || !body_kind_supported || document.is_empty()
+    {
+        info!(
+            "content storage reader parse rejected content_found={} body_found={} blocks_found={} body_kind_supported={} title_empty={} document_empty={} truncated={}",
+            content_found,
+            body_found,
+            blocks_found,
+            body_kind_supported,
+            title.is_empty(),
+            document.is_empty(),
+            truncated,
+        );
+        return Err(StorageError::CorruptData);
+    }
+
+    if title.is_empty() {
+        title.set_truncated("UNTITLED ARTICLE");
+    }
+
+    Ok(OpenedReaderContent {
+        title,
+        document,
+        truncated,
+    })
+}
+
+fn is_supported_reader_body_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "compact" | "article" | "thread" | "post" | "website" | "video" | "podcast" | "pdf"
+    )
+}
+
+fn push_limited_char(target: &mut String, ch: char, max_bytes: usize, truncated: &mut bool) {
+    let mut utf8 = [0u8; 4];
+    let encoded = ch.encode_utf8(&mut utf8);
+    push_limited_str(target, encoded, max_bytes, truncated);
+}
+
+fn push_limited_str(target: &mut String, value: &str, max_bytes: usize, truncated: &mut bool) {
+    if *truncated || value.is_empty() {
+        return;
+    }
+
+    if target.len().saturating_add(value.len()) > max_bytes {
+        *truncated = true;
+        return;
+    }
+
+    target.push_str(value);
+}
+
+fn utf8_continuation_len(first: u8) -> Result<usize, StorageError> {
+    match first {
+        0xC2..=0xDF => Ok(1),
+        0xE0..=0xEF => Ok(2),
+        0xF0..=0xF4 => Ok(3),
+        _ => Err(StorageError::CorruptData),
+    }
+}
+
+fn hex_value(byte: u8) -> Result<u8, StorageError> {
+    match byte {
+        b'0'..=b'9' => Ok(byte - b'0'),
+        b'a'..=b'f' => Ok(byte - b'a' + 10),
+        b'A'..=b'F' => Ok(byte - b'A' + 10),
+        _ => Err(StorageError::CorruptData),
+    }
+}
+
+fn format_list_line(item: &str, ordered: bool, index: usize) -> String {
+    let mut line = String::new();
+    if ordered {
+        let number = index + 1;
+        if number >= 10 {
+            let hundreds = (number / 100) % 10;
+            if hundreds > 0 {
+                line.push((b'0' + hundreds as u8) as char);
+            }
+            let tens = (number / 10) % 10;
+            line.push((b'0' + tens as u8) as char);
+        }
+        line.push((b'0' + (number % 10) as u8) as char);
+        line.push_str(". ");
+    } else {
+        line.push_str("- ");
+    }
+    line.push_str(item);
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::formatter::{ChunkingMode, MAX_READING_UNITS, ReferenceMode};
+
+    #[test]
+    fn reader_content_parser_opens_backend_article_payload() {
+        let payload = br#"{
+            "content": {
+                "title": "Example article",
+                "body": {
+                    "kind": "article",
+                    "blocks": [
+                        {"x": "First paragraph for Motif.", "t": "p"},
+                        {"i": ["Alpha", "Beta"], "o": true, "t": "l"}
+                    ]
+                }
+            }
+        }"#;
+
+        let opened = parse_reader_content_bytes(payload).unwrap();
+
+        assert_eq!(opened.title.as_str(), "Example article");
+        assert!(!opened.truncated);
+        assert_eq!(opened.document.paragraph_count, 3);
+        assert_eq!(
+            opened.document.preview_for_paragraph(1).as_str(),
+            "First paragraph for Motif."
+        );
+    }
+
+    #[test]
+    fn reader_content_parser_decodes_html_entities_in_block_text() {
+        let payload = br#"{
+            "content": {
+                "title": "Q&amp;A",
+                "body": {
+                    "kind": "article",
+                    "blocks": [
+                        {"x": "Fish &amp; chips &mdash; it&#8217;s caf&eacute;.", "t": "p"},
+                        {"i": ["&lt;tag&gt;"], "o": false, "t": "l"}
+                    ]
+                }
+            }
+        }"#;
+
+        let opened = parse_reader_content_bytes(payload).unwrap();
+
+        assert_eq!(opened.title.as_str(), "Q&A");
+        assert_eq!(
+            opened.document.preview_for_paragraph(1).as_str(),
+            "Fish & chips — it’s café."
+        );
+        assert!(
+            opened
+                .document
+                .preview_for_paragraph(2)
+                .as_str()
+                .contains("<tag>")
+        );
+    }
+
+    #[test]
+    fn reader_content_parser_strips_leftover_markup_from_block_text() {
+        let payload = br#"{
+            "content": {
+                "title": "Markup",
+                "body": {
+                    "kind": "article",
+                    "blocks": [
+                        {"x": "Call <span class=\"calibre\">me</span><br>Ishmael.", "t": "p"}
+                    ]
+                }
+            }
+        }"#;
+
+        let opened = parse_reader_content_bytes(payload).unwrap();
+
+        assert_eq!(
+            opened.document.preview_for_paragraph(1).as_str(),
+            "Call me Ishmael."
+        );
+    }
+
+    #[test]
+    fn reader_content_parser_marks_heading_blocks_as_section_starts() {
+        let payload = br#"{
+            "content": {
+                "title": "Sections",
+                "body": {
+                    "kind": "article",
+                    "blocks": [
+                        {"x": "Opening words.", "t": "p"},
+                        {"x": "Part Two", "t": "h"}
+                    ]
+                }
+            }
+        }"#;
+
+        let opened = parse_reader_content_bytes(payload).unwrap();
+        let document = &opened.document;
+
+        assert!(!document.units[0].flags.section_start);
+        assert!(document.units[2].flags.section_start);
+        assert!(!document.units[3].flags.section_start);
+    }
+
+    #[test]
+    fn reader_content_parser_groups_function_words_when_the_reader_asks() {
+        let payload = br#"{
+            "content": {
+                "title": "Groups",
+                "language": "en",
+                "body": {
+                    "kind": "article",
+                    "blocks": [{"x": "Out of the house.", "t": "p"}]
+                }
+            }
+        }"#;
+        let preferences = FormatPreferences {
+            chunking: ChunkingMode::FunctionWords,
+            ..FormatPreferences::default()
+        };
+
+        let single = parse_reader_content_bytes(payload).unwrap();
+        let grouped = parse_opened_reader_content(&payload[..], preferences).unwrap();
+
+        assert_eq!(single.document.unit_count, 4);
+        assert_eq!(grouped.document.unit_count, 2);
+        assert_eq!(grouped.document.units[1].display.as_str(), "of the house.");
+    }
+
+    #[test]
+    fn reader_content_parser_keeps_reference_markers_when_the_reader_asks() {
+        let payload = br#"{
+            "content": {
+                "title": "Notes",
+                "body": {
+                    "kind": "article",
+                    "blocks": [
+                        {"x": "Rain fell[12].", "t": "p"},
+                        {"x": "[12] Smith, p. 4.", "t": "p"}
+                    ]
+                }
+            }
+        }"#;
+        let preferences = FormatPreferences {
+            references: ReferenceMode::Keep,
+            ..FormatPreferences::default()
+        };
+
+        let skipped = parse_reader_content_bytes(payload).unwrap();
+        let kept = parse_opened_reader_content(&payload[..], preferences).unwrap();
+
+        assert_eq!(skipped.document.paragraph_count, 1);
+        assert_eq!(kept.document.paragraph_count, 2);
+        assert_eq!(kept.document.units[1].display.as_str(), "fell[12].");
+    }
+
+    #[test]
+    fn reader_content_parser_groups_long_numbers_when_the_reader_asks() {
+        let payload = br#"{
+            "content": {
+                "title": "Figures",
+                "body": {
+                    "kind": "article",
+                    "blocks": [{"x": "About 1000000 people.", "t": "p"}]
+                }
+            }
+        }"#;
+        let preferences = FormatPreferences {
+            digit_grouping: true,
+            ..FormatPreferences::default()
+        };
+
+        let plain = parse_reader_content_bytes(payload).unwrap();
+        let grouped = parse_opened_reader_content(&payload[..], preferences).unwrap();
+
+        assert_eq!(plain.document.units[1].display.as_str(), "1000000");
+        assert_eq!(
+            grouped.document.units[1].display.as_str(),
+            "1\u{00A0}000\u{00A0}000"
+        );
+    }
+
+    #[test]
+    fn reader_content_parser_applies_the_language_pause_rules() {
+        let payload = r#"{
+            "content": {
+                "title": "Diálogo",
+                "language": "es-MX",
+                "body": {
+                    "kind": "article",
+                    "blocks": [{"x": "Sí —dijo— claro.", "t": "p"}]
+                }
+            }
+        }"#;
+
+        let opened = parse_reader_content_bytes(payload.as_bytes()).unwrap();
+        let units = &opened.document.units[..opened.document.unit_count as usize];
+
+        assert!(units.iter().all(|unit| !unit.flags.clause_pause));
+        assert!(units[2].flags.sentence_pause);
+    }
+
+    #[test]
+    fn reader_content_parser_keeps_legacy_compact_kind_compatibility() {
+        let payload = br#"{
+            "content": {
+                "title": "Legacy article",
+                "body": {
+                    "kind": "compact",
+                    "blocks": [{"x": "Legacy paragraph.", "t": "p"}]
+                }
+            }
+        }"#;
+
+        let opened = parse_reader_content_bytes(payload).unwrap();
+
+        assert_eq!(opened.title.as_str(), "Legacy article");
+        assert_eq!(opened.document.paragraph_count, 1);
+        assert_eq!(
+            opened.document.preview_for_paragraph(1).as_str(),
+            "Legacy paragraph."
+        );
+    }
+
+    #[test]
+    fn reader_content_parser_rejects_invalid_kind() {
+        let payload = br#"{
+            "content": {
+                "title": "Broken",
+                "body": {
+                    "kind": "full",
+                    "blocks": [{"t": "p", "x": "ignored"}]
+                }
+            }
+        }"#;
+
+        assert_eq!(
+            parse_reader_content_bytes(payload).unwrap_err(),
+            StorageError::CorruptData
+        );
+    }
+
+    #[test]
+    fn reader_content_parser_truncates_oversized_payloads_safely() {
+        let mut payload =
+            String::from(r#"{"content":{"title":"Oversized","body":{"kind":"compact","blocks":["#);
+
+        let mut index = 0usize;
+        while index < 40 {
+            if index > 0 {
+                payload.push(',');
+            }
+            payload.push_str(r#"{"t":"p","x":"alpha beta gamma delta epsilon zeta eta theta iota kappa lambda mu nu xi omicron pi rho sigma tau upsilon phi chi psi omega alpha beta gamma delta epsilon zeta eta theta iota kappa lambda mu nu xi omicron pi rho sigma tau upsilon phi chi psi omega"}"#);
+            index += 1;
+        }
+
+        payload.push_str("]}}}");
+        let opened = parse_reader_content_bytes(payload.as_bytes()).unwrap();
+
+        assert!(opened.truncated);
+        assert!(opened.document.unit_count as usize <= MAX_READING_UNITS);
+        assert!(opened.document.paragraph_count as usize <= MAX_READING_PARAGRAPHS);
+        assert!(!opened.document.is_empty());
+    }
+
+    #[test]
+    fn reader_content_parser_rejects_deeply_nested_skipped_values() {
+        let mut payload = String::from(r#"{"extra":"#);
+        payload.push_str(&"[".repeat(MAX_JSON_SKIP_DEPTH + 1));
+        payload.push_str(&"]".repeat(MAX_JSON_SKIP_DEPTH + 1));
+        payload.push('}');
+
+        assert_eq!(
+            parse_reader_content_bytes(payload.as_bytes()).unwrap_err(),
+            StorageError::CorruptData
+        );
+    }
+}
@@ -57,6 +57,58 @@ pub trait QueueCodec {
     fn decode(bytes: &[u8]) -> Result<Self::Value, StorageCodecError>;
 }
 
+// A readable file on whatever medium holds content: an SD card file, a region of the internal
+// flash book partition, or a plain byte slice on the host. Readers take this instead of a
+// concrete file type so every backend feeds the same parsers.
+pub trait FileSource {
+    fn byte_len(&self) -> u32;
+
+    // Reads up to `out.len()` bytes at `offset`; returns 0 only at the end of the file.
+    fn read_at(&mut self, offset: u32, out: &mut [u8]) -> Result<usize, StorageError>;
+
+    fn read_exact_at(&mut self, offset: u32, out: &mut [u8]) -> Result<(), StorageError> {
+        let mut filled = 0usize;
+        while filled < out.len() {
+            let position = u32::try_from(filled)
+                .ok()
+                .and_then(|filled| offset.checked_add(filled))
+                .ok_or(StorageError::CorruptData)?;
+            let read = self.read_at(position, &mut out[filled..])?;
+            if read == 0 {
+                return Err(StorageError::CorruptData);
+            }
+            filled += read;
+        }
+        Ok(())
+    }
+}
+
+impl<T> FileSource for &mut T
+where
+    T: FileSource + ?Sized,
+{
+    fn byte_len(&self) -> u32 {
+        (**self).byte_len()
+    }
+
+    fn read_at(&mut self, offset: u32, out: &mut [u8]) -> Result<usize, StorageError> {
+        (**self).read_at(offset, out)
+    }
+}
+
+impl FileSource for &[u8] {
+    fn byte_len(&self) -> u32 {
+        u32::try_from(self.len()).unwrap_or(u32::MAX)
+    }
+
+    fn read_at(&mut self, offset: u32, out: &mut [u8]) -> Result<usize, StorageError> {
+        let remaining = self.get(offset as usize..).unwrap_or(&[]);
+        let read = remaining.len().min(out.len());
+        out[..read].copy_from_slice(&remaining[..read]);
+        Ok(read)
+    }
+}
+
 pub trait StorageService {
     fn health(&self) -> StorageHealth;
 
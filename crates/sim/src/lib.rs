@@ -0,0 +1,188 @@
+use app_runtime::{AppRuntime, ScreenUpdate, TransitionPlan};
+use domain::{
+    input::InputGesture,
+    runtime::{Effect, Event},
+    store::Store,
+};
+use ls027b7dh01::FrameBuffer;
+use renderer::{AnimationPlayback, UI_TICK_MS};
+
+#[cfg(feature = "web")]
+pub mod web;
+
+const READER_TICK_MS: u64 = 20;
+
+// Memory LCD reflective white and its dark pixel, close enough to judge contrast on a monitor.
+pub const LIT_PIXEL_RGB: u32 = 0x00E8_E6DC;
+pub const DARK_PIXEL_RGB: u32 = 0x0020_2020;
+
+// Runs the store, app runtime and renderer the way the firmware's app task and display loop do,
+// minus the hardware. Effects that would reach storage or the network are handed back to the
+// caller instead.
+pub struct Simulator {
+    store: Box<Store>,
+    app: AppRuntime,
+    committed: Option<ScreenUpdate>,
+    animation: Option<AnimationPlayback>,
+    next_animation_at_ms: u64,
+    next_ui_tick_at_ms: u64,
+    next_reader_tick_at_ms: u64,
+    frame: FrameBuffer,
+    dirty: bool,
+}
+
+impl Simulator {
+    pub fn new(now_ms: u64) -> Self {
+        let mut simulator = Self {
+            store: Box::new(Store::new()),
+            app: AppRuntime::new(),
+            committed: None,
+            animation: None,
+            next_animation_at_ms: 0,
+            next_ui_tick_at_ms: now_ms,
+            next_reader_tick_at_ms: now_ms,
+            frame: FrameBuffer::new(),
+            dirty: false,
+        };
+        simulator.publish(Event::BootCompleted, now_ms);
+        simulator
+    }
+
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+
+    pub fn frame(&self) -> &FrameBuffer {
+        &self.frame
+    }
+
+    // True once after each redraw, so a window only blits frames that changed.
+    pub fn take_dirty(&mut self) -> bool {
+        core::mem::take(&mut self.dirty)
+    }
+
+    pub fn gesture(&mut self, gesture: InputGesture, now_ms: u64) -> Effect {
+        let mut effect = self.handle(Event::InputGestureReceived(gesture), now_ms);
+        let command = self.app.handle_input_gesture(gesture);
+        let command_effect = self.store.dispatch(command).unwrap_or(Effect::Noop);
+        if !matches!(command_effect, Effect::Noop) {
+            effect = command_effect;
+        }
+        self.present(now_ms);
+        effect
+    }
+
+    pub fn publish(&mut self, event: Event, now_ms: u64) -> Effect {
+        let effect = self.handle(event, now_ms);
+        self.present(now_ms);
+        effect
+    }
+
+    // Fires whichever ticks and animation frames are due, returning any effects they raised.
+    pub fn advance(&mut self, now_ms: u64) -> Vec<Effect> {
+        let mut effects = Vec::new();
+        if now_ms >= self.next_reader_tick_at_ms {
+            self.next_reader_tick_at_ms = now_ms + READER_TICK_MS;
+            effects.push(self.publish(Event::ReaderTick(now_ms), now_ms));
+        }
+        if now_ms >= self.next_ui_tick_at_ms {
+            self.next_ui_tick_at_ms = now_ms + UI_TICK_MS;
+            effects.push(self.publish(Event::UiTick(now_ms), now_ms));
+        }
+        if let Some(animation) = self.animation
+            && now_ms >= self.next_animation_at_ms
+        {
+            self.play(animation.advance(), now_ms);
+        }
+        effects.retain(|effect| !matches!(effect, Effect::Noop));
+        effects
+    }
+
+    fn handle(&mut self, event: Event, now_ms: u64) -> Effect {
+        self.store
+            .handle_event(event, now_ms)
+            .unwrap_or(Effect::Noop)
+    }
+
+    fn present(&mut self, now_ms: u64) {
+        let update = self.app.tick(&self.store);
+        let previous = self
+            .animation
+            .map(|active| active.target_screen())
+            .or(self.committed.map(|committed| committed.prepared));
+        let previous_toasts = self
+            .animation
+            .map(|active| active.toasts)
+            .or(self.committed.map(|committed| committed.toasts));
+        if previous == Some(update.prepared) && previous_toasts == Some(update.toasts) {
+            return;
+        }
+
+        match previous {
+            Some(previous) if update.transition != TransitionPlan::none() => {
+                self.play(AnimationPlayback::new(previous, update), now_ms);
+            }
+            _ => {
+                self.animation = None;
+                self.committed = Some(update);
+                renderer::draw_screen_update(&mut self.frame, &update);
+                self.dirty = true;
+            }
+        }
+    }
+
+    fn play(&mut self, animation: AnimationPlayback, now_ms: u64) {
+        renderer::draw_transition_frame(&mut self.frame, &animation);
+        self.dirty = true;
+
+        if animation.is_complete() {
+            self.committed = Some(ScreenUpdate {
+                screen: animation.screen,
+                prepared: animation.target_screen(),
+                transition: TransitionPlan::none(),
+                toasts: animation.toasts,
+            });
+            self.animation = None;
+        } else {
+            self.animation = Some(animation);
+            self.next_animation_at_ms = now_ms + u64::from(animation.plan.frame_ms);
+        }
+    }
+}
+
+// A set framebuffer bit is ink; the panel driver handles the wire polarity.
+pub fn pixel_rgb(frame: &FrameBuffer, x: usize, y: usize) -> u32 {
+    match frame.pixel(x, y) {
+        Some(true) => DARK_PIXEL_RGB,
+        _ => LIT_PIXEL_RGB,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::input::RotationDirection;
+
+    #[test]
+    fn boot_draws_a_frame_and_input_redraws_it() {
+        let mut simulator = Simulator::new(0);
+        assert!(simulator.take_dirty());
+        assert!(simulator.frame().bytes().iter().any(|byte| *byte != 0));
+
+        for now_ms in (0..2_000).step_by(20) {
+            simulator.advance(now_ms);
+        }
+        let _ = simulator.take_dirty();
+        let _ = simulator.gesture(InputGesture::LongPress, 2_000);
+        let _ = simulator.gesture(
+            InputGesture::Rotate {
+                direction: RotationDirection::Clockwise,
+            },
+            2_100,
+        );
+        for now_ms in (2_100..3_000).step_by(20) {
+            simulator.advance(now_ms);
+        }
+        assert!(simulator.take_dirty());
+    }
+}
@@ -0,0 +1,78 @@
+use std::time::Instant;
+
+use domain::{
+    input::{InputGesture, RotationDirection},
+    runtime::Effect,
+};
+use ls027b7dh01::protocol::{HEIGHT, WIDTH};
+use minifb::{Key, KeyRepeat, Scale, Window, WindowOptions};
+use sim::{LIT_PIXEL_RGB, Simulator};
+
+const TARGET_FPS: usize = 60;
+
+fn main() {
+    let mut window = Window::new(
+        "motif sim - arrows rotate, enter clicks, space long-presses",
+        WIDTH,
+        HEIGHT,
+        WindowOptions {
+            scale: Scale::X2,
+            ..WindowOptions::default()
+        },
+    )
+    .unwrap_or_else(|err| panic!("sim window failed: {err}"));
+    window.set_target_fps(TARGET_FPS);
+
+    let started = Instant::now();
+    let now_ms = || started.elapsed().as_millis() as u64;
+    let mut simulator = Simulator::new(now_ms());
+    let mut pixels = vec![LIT_PIXEL_RGB; WIDTH * HEIGHT];
+
+    while window.is_open() && !window.is_key_down(Key::Escape) {
+        for key in window.get_keys_pressed(KeyRepeat::Yes) {
+            if let Some(gesture) = gesture_for_key(key) {
+                log_effect(simulator.gesture(gesture, now_ms()));
+            }
+        }
+        for effect in simulator.advance(now_ms()) {
+            log_effect(effect);
+        }
+
+        if simulator.take_dirty() {
+            blit(simulator.frame(), &mut pixels);
+        }
+        if let Err(err) = window.update_with_buffer(&pixels, WIDTH, HEIGHT) {
+            eprintln!("sim window update failed: {err}");
+            break;
+        }
+    }
+}
+
+fn gesture_for_key(key: Key) -> Option<InputGesture> {
+    match key {
+        Key::Right | Key::Down => Some(InputGesture::Rotate {
+            direction: RotationDirection::Clockwise,
+        }),
+        Key::Left | Key::Up => Some(InputGesture::Rotate {
+            direction: RotationDirection::CounterClockwise,
+        }),
+        Key::Enter => Some(InputGesture::Click),
+        Key::Space => Some(InputGesture::LongPress),
+        _ => None,
+    }
+}
+
+fn blit(frame: &ls027b7dh01::FrameBuffer, pixels: &mut [u32]) {
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            pixels[y * WIDTH + x] = sim::pixel_rgb(frame, x, y);
+        }
+    }
+}
+
+// Nothing behind these exists in the sim, so they are only reported.
+fn log_effect(effect: Effect) {
+    if !matches!(effect, Effect::Noop) {
+        println!("effect {effect:?}");
+    }
+}
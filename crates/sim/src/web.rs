@@ -0,0 +1,85 @@
+use domain::input::{InputGesture, RotationDirection};
+use ls027b7dh01::protocol::{HEIGHT, WIDTH};
+use wasm_bindgen::prelude::*;
+
+use crate::{Simulator, pixel_rgb};
+
+// Browser face of the simulator. The page owns the clock and the canvas: it feeds
+// `performance.now()` in and copies `rgba()` into an ImageData whenever `advance` reports a
+// redraw. Effects are dropped, as there is nothing behind them in a browser.
+#[wasm_bindgen]
+pub struct WebSimulator {
+    simulator: Simulator,
+    rgba: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl WebSimulator {
+    #[wasm_bindgen(constructor)]
+    pub fn new(now_ms: f64) -> Self {
+        let mut web = Self {
+            simulator: Simulator::new(now_ms as u64),
+            rgba: vec![0; WIDTH * HEIGHT * 4],
+        };
+        web.repaint();
+        web
+    }
+
+    pub fn width() -> usize {
+        WIDTH
+    }
+
+    pub fn height() -> usize {
+        HEIGHT
+    }
+
+    pub fn rotate(&mut self, clockwise: bool, now_ms: f64) {
+        let direction = if clockwise {
+            RotationDirection::Clockwise
+        } else {
+            RotationDirection::CounterClockwise
+        };
+        self.gesture(InputGesture::Rotate { direction }, now_ms);
+    }
+
+    pub fn click(&mut self, now_ms: f64) {
+        self.gesture(InputGesture::Click, now_ms);
+    }
+
+    #[wasm_bindgen(js_name = longPress)]
+    pub fn long_press(&mut self, now_ms: f64) {
+        self.gesture(InputGesture::LongPress, now_ms);
+    }
+
+    // Returns true when the frame changed since the last call.
+    pub fn advance(&mut self, now_ms: f64) -> bool {
+        let _ = self.simulator.advance(now_ms as u64);
+        self.repaint()
+    }
+
+    pub fn rgba(&self) -> Vec<u8> {
+        self.rgba.clone()
+    }
+
+    #[wasm_bindgen(js_name = readingSpeedWpm)]
+    pub fn reading_speed_wpm(&self) -> u16 {
+        self.simulator.store().settings.reading_speed_wpm
+    }
+
+    fn gesture(&mut self, gesture: InputGesture, now_ms: f64) {
+        let _ = self.simulator.gesture(gesture, now_ms as u64);
+    }
+
+    fn repaint(&mut self) -> bool {
+        if !self.simulator.take_dirty() {
+            return false;
+        }
+
+        let frame = self.simulator.frame();
+        for (index, pixel) in self.rgba.chunks_exact_mut(4).enumerate() {
+            let [_, r, g, b] = pixel_rgb(frame, index % WIDTH, index / WIDTH).to_be_bytes();
+            pixel.copy_from_slice(&[r, g, b, 0xFF]);
+        }
+        true
+    }
+}
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use services::reader_content::parse_reader_content_bytes;
+
+fuzz_target!(|bytes: &[u8]| {
+    if let Ok(opened) = parse_reader_content_bytes(bytes) {
+        assert!(!opened.document.is_empty());
+        assert!(!opened.title.is_empty());
+    }
+});
@@ -0,0 +1,21 @@
+#![no_main]
+
+use domain::reader::ReaderWindow;
+use libfuzzer_sys::fuzz_target;
+
+// Treats the input as a whole package file and opens it the way the storage task does.
+fuzz_target!(|bytes: &[u8]| {
+    let mut source = bytes;
+    let Ok(header) = reader_package::read_header(&mut source) else {
+        return;
+    };
+    let _ = reader_package::read_title(&mut source, header);
+    let _ = reader_package::read_paragraphs(&mut source, header);
+
+    let mut window = ReaderWindow::empty();
+    if reader_package::read_window(&mut source, header, 0, &mut window).is_ok() {
+        for unit in &window.units[..window.unit_count as usize] {
+            assert!(unit.anchor_index < unit.char_count);
+        }
+    }
+});
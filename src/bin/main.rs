@@ -10,9 +10,9 @@
 #[panic_handler]
 fn panic(info: &core::panic::PanicInfo) -> ! {
     esp_println::println!("panic: {}", info);
-    loop {
-        core::hint::spin_loop();
-    }
+    platform_esp32s3::crash_log::record_panic(info);
+    platform_esp32s3::crash_log::render_panic(info);
+    esp_hal::system::software_reset()
 }
 
 esp_bootloader_esp_idf::esp_app_desc!();